@@ -3,10 +3,17 @@
 use color_eyre::Result;
 use serde_json::Value;
 
+use std::collections::HashMap;
+use std::sync::PoisonError;
+
+use tokio::task::JoinSet;
+
 use super::AlgoClient;
+use crate::client::cache::Expiry;
+use crate::constants::{ACCOUNT_LIST_PAGE_LIMIT, ASSET_LOOKUP_CONCURRENCY, CACHE_MUTABLE_TTL};
 use crate::domain::{
-    AccountAssetHolding, AccountDetails, AccountInfo, AlgoError, AppLocalState, CreatedAppInfo,
-    CreatedAssetInfo, ParticipationInfo,
+    AccountAssetHolding, AccountDetails, AccountInfo, AlgoError, AppLocalState, AssetInfo,
+    CreatedAppInfo, CreatedAssetInfo, Page, ParticipationInfo, Transaction, TxnType,
 };
 
 impl AlgoClient {
@@ -52,15 +59,15 @@ impl AlgoClient {
 
     /// Search for an address directly (after validation or NFD resolution)
     async fn search_address_direct(&self, address: &str) -> Result<Option<AccountInfo>> {
-        let indexer_result = self.search_address_via_indexer(address).await;
-
-        match indexer_result {
-            Ok(Some(account)) => {
-                return Ok(Some(account));
-            }
-            Ok(None) => {}
-            Err(e) => {
-                tracing::debug!("Indexer lookup failed, falling back to algod: {e}");
+        if !self.is_algod_only() {
+            match self.search_address_via_indexer(address).await {
+                Ok(Some(account)) => {
+                    return Ok(Some(account));
+                }
+                Ok(None) => {}
+                Err(e) => {
+                    tracing::debug!("Indexer lookup failed, falling back to algod: {e}");
+                }
             }
         }
 
@@ -178,7 +185,35 @@ impl AlgoClient {
             return Err(AlgoError::invalid_input("Invalid Algorand address format").into_report());
         }
 
-        let account_url = format!("{}/v2/accounts/{}", self.algod_url, address);
+        // Holdings and apps are paged separately from the indexer, so skip them here;
+        // algod rejects unexcluded requests for accounts with many resources.
+        let cache_key = format!("account:{address}");
+        let account_data = match self.cache_get(&cache_key).await {
+            Some(account_data) => account_data,
+            None => {
+                let account_data = self.fetch_account_json(address).await?;
+                self.cache_put(&cache_key, &account_data, Expiry::After(CACHE_MUTABLE_TTL))
+                    .await;
+                account_data
+            }
+        };
+        let mut account_details = Self::parse_account_details(&account_data, address);
+
+        // Fetch NFD info if supported on this network
+        if self.supports_nfd() {
+            account_details.nfd = self.get_nfd_for_address(address).await.unwrap_or(None);
+        }
+
+        Ok(account_details)
+    }
+
+    /// Fetch an account's algod JSON, without its holdings and apps.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the account is not found or the request fails.
+    pub(super) async fn fetch_account_json(&self, address: &str) -> Result<Value> {
+        let account_url = format!("{}/v2/accounts/{}?exclude=all", self.algod_url, address);
         let response = self.build_algod_request(&account_url).send().await?;
 
         if !response.status().is_success() {
@@ -197,15 +232,195 @@ impl AlgoClient {
             ));
         }
 
-        let account_data: Value = response.json().await?;
-        let mut account_details = Self::parse_account_details(&account_data, address);
+        Ok(response.json().await?)
+    }
 
-        // Fetch NFD info if supported on this network
-        if self.supports_nfd() {
-            account_details.nfd = self.get_nfd_for_address(address).await.unwrap_or(None);
+    /// Fetch one page of an account's transaction history from the indexer.
+    ///
+    /// # Arguments
+    ///
+    /// * `address` - The account address
+    /// * `txn_type` - Optional transaction type filter
+    /// * `next_token` - Token from the previous page, or `None` for the first page
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the indexer request fails.
+    pub async fn get_account_transactions_page(
+        &self,
+        address: &str,
+        txn_type: Option<TxnType>,
+        next_token: Option<&str>,
+    ) -> Result<Page<Transaction>> {
+        let mut url = format!("{}/v2/accounts/{}/transactions", self.indexer_url, address);
+        if let Some(code) = txn_type.and_then(|t| t.api_code()) {
+            url.push_str("?tx-type=");
+            url.push_str(code);
         }
+        self.fetch_transactions_page(&url, next_token, "Transaction history")
+            .await
+    }
 
-        Ok(account_details)
+    /// Fetch one page of an account's asset holdings, with each asset's name,
+    /// unit and decimals resolved.
+    ///
+    /// # Arguments
+    ///
+    /// * `address` - The account address
+    /// * `next_token` - Token from the previous page, or `None` for the first page
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the indexer request fails. Failed asset lookups
+    /// leave that holding's name empty instead.
+    pub async fn get_account_assets_page(
+        &self,
+        address: &str,
+        next_token: Option<&str>,
+    ) -> Result<Page<AccountAssetHolding>> {
+        let json = self
+            .fetch_account_list_json(address, "assets", next_token)
+            .await?;
+        let mut page = parse_account_assets_page(&json);
+
+        let ids: Vec<u64> = page.items.iter().map(|h| h.asset_id).collect();
+        let params = self.lookup_assets(&ids).await;
+        for holding in &mut page.items {
+            if let Some(info) = params.get(&holding.asset_id) {
+                holding.name = info.name.clone();
+                holding.unit_name = info.unit_name.clone();
+                holding.decimals = info.decimals;
+            }
+        }
+        Ok(page)
+    }
+
+    /// Fetch one page of the assets created by an account.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the indexer request fails.
+    pub async fn get_account_created_assets_page(
+        &self,
+        address: &str,
+        next_token: Option<&str>,
+    ) -> Result<Page<CreatedAssetInfo>> {
+        let json = self
+            .fetch_account_list_json(address, "created-assets", next_token)
+            .await?;
+        Ok(parse_account_created_assets_page(&json))
+    }
+
+    /// Fetch one page of the applications an account has opted into.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the indexer request fails.
+    pub async fn get_account_apps_page(
+        &self,
+        address: &str,
+        next_token: Option<&str>,
+    ) -> Result<Page<AppLocalState>> {
+        let json = self
+            .fetch_account_list_json(address, "apps-local-state", next_token)
+            .await?;
+        Ok(parse_account_apps_page(&json))
+    }
+
+    /// Fetch one page of the applications created by an account.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the indexer request fails.
+    pub async fn get_account_created_apps_page(
+        &self,
+        address: &str,
+        next_token: Option<&str>,
+    ) -> Result<Page<CreatedAppInfo>> {
+        let json = self
+            .fetch_account_list_json(address, "created-applications", next_token)
+            .await?;
+        Ok(parse_account_created_apps_page(&json))
+    }
+
+    /// Fetch one page of an indexer `/v2/accounts/{address}/{resource}` list.
+    async fn fetch_account_list_json(
+        &self,
+        address: &str,
+        resource: &str,
+        next_token: Option<&str>,
+    ) -> Result<Value> {
+        self.require_indexer("Account holdings and apps")?;
+        let url = format!("{}/v2/accounts/{}/{}", self.indexer_url, address, resource);
+        let page_url = Self::paginated_url(&url, ACCOUNT_LIST_PAGE_LIMIT, next_token);
+        let response = self.build_indexer_request(&page_url).send().await?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let error_text = response
+                .text()
+                .await
+                .unwrap_or_else(|_| "Unknown error".to_string());
+            return Err(color_eyre::eyre::eyre!(
+                "Failed to fetch {} for account {}: HTTP {} - {}",
+                resource,
+                address,
+                status,
+                error_text
+            ));
+        }
+
+        Ok(response.json().await?)
+    }
+
+    /// Look up the parameters of several assets, a few requests at a time.
+    ///
+    /// Assets resolved before are answered from memory, so later pages and
+    /// exports only fetch assets not seen yet. Assets that can't be fetched
+    /// are left out of the result.
+    pub(super) async fn lookup_assets(&self, asset_ids: &[u64]) -> HashMap<u64, AssetInfo> {
+        let mut found = HashMap::new();
+        let mut missing = Vec::new();
+        {
+            let known = self
+                .asset_params
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner);
+            for &asset_id in asset_ids {
+                match known.get(&asset_id) {
+                    Some(info) => {
+                        found.insert(asset_id, info.clone());
+                    }
+                    None => missing.push(asset_id),
+                }
+            }
+        }
+        missing.sort_unstable();
+        missing.dedup();
+
+        for chunk in missing.chunks(ASSET_LOOKUP_CONCURRENCY) {
+            let mut lookups = JoinSet::new();
+            for &asset_id in chunk {
+                let client = self.clone();
+                lookups.spawn(async move { client.search_asset(&asset_id.to_string()).await });
+            }
+            while let Some(result) = lookups.join_next().await {
+                if let Ok(Ok(Some(info))) = result {
+                    found.insert(info.id, info);
+                }
+            }
+        }
+
+        let mut known = self
+            .asset_params
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        for asset_id in missing {
+            if let Some(info) = found.get(&asset_id) {
+                known.insert(asset_id, info.clone());
+            }
+        }
+        found
     }
 
     #[must_use]
@@ -241,67 +456,6 @@ impl AlgoClient {
             state_proof_key: part["state-proof-key"].as_str().map(String::from),
         });
 
-        // Parse asset holdings (limited to first 10)
-        let assets = account["assets"]
-            .as_array()
-            .map(|arr| {
-                arr.iter()
-                    .take(10)
-                    .map(|a| AccountAssetHolding {
-                        asset_id: a["asset-id"].as_u64().unwrap_or(0),
-                        amount: a["amount"].as_u64().unwrap_or(0),
-                        is_frozen: a["is-frozen"].as_bool().unwrap_or(false),
-                    })
-                    .collect()
-            })
-            .unwrap_or_default();
-
-        // Parse created assets (limited to first 10)
-        let created_assets = account["created-assets"]
-            .as_array()
-            .map(|arr| {
-                arr.iter()
-                    .take(10)
-                    .map(|a| {
-                        let params = &a["params"];
-                        CreatedAssetInfo {
-                            asset_id: a["index"].as_u64().unwrap_or(0),
-                            name: params["name"].as_str().unwrap_or("").to_string(),
-                            unit_name: params["unit-name"].as_str().unwrap_or("").to_string(),
-                        }
-                    })
-                    .collect()
-            })
-            .unwrap_or_default();
-
-        // Parse app local states (limited to first 10)
-        let apps_local_state = account["apps-local-state"]
-            .as_array()
-            .map(|arr| {
-                arr.iter()
-                    .take(10)
-                    .map(|a| AppLocalState {
-                        app_id: a["id"].as_u64().unwrap_or(0),
-                        schema_num_uint: a["schema"]["num-uint"].as_u64().unwrap_or(0),
-                        schema_num_byte_slice: a["schema"]["num-byte-slice"].as_u64().unwrap_or(0),
-                    })
-                    .collect()
-            })
-            .unwrap_or_default();
-
-        // Parse created apps (limited to first 10)
-        let created_apps = account["created-apps"]
-            .as_array()
-            .map(|arr| {
-                arr.iter()
-                    .take(10)
-                    .map(|a| CreatedAppInfo {
-                        app_id: a["id"].as_u64().unwrap_or(0),
-                    })
-                    .collect()
-            })
-            .unwrap_or_default();
-
         AccountDetails {
             address: address.to_string(),
             balance,
@@ -317,11 +471,89 @@ impl AlgoClient {
             total_boxes,
             auth_addr,
             participation,
-            assets,
-            created_assets,
-            apps_local_state,
-            created_apps,
             nfd: None, // NFD is set separately after fetching
         }
     }
 }
+
+/// Parse an indexer `/v2/accounts/{address}/assets` response.
+///
+/// Names, units and decimals are left empty for the caller to resolve.
+#[must_use]
+pub(crate) fn parse_account_assets_page(json: &Value) -> Page<AccountAssetHolding> {
+    let assets = json["assets"]
+        .as_array()
+        .map(|arr| {
+            arr.iter()
+                .filter(|a| !a["deleted"].as_bool().unwrap_or(false))
+                .map(|a| AccountAssetHolding {
+                    asset_id: a["asset-id"].as_u64().unwrap_or(0),
+                    amount: a["amount"].as_u64().unwrap_or(0),
+                    is_frozen: a["is-frozen"].as_bool().unwrap_or(false),
+                    ..AccountAssetHolding::default()
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    Page::new(assets, json["next-token"].as_str().map(String::from))
+}
+
+/// Parse an indexer `/v2/accounts/{address}/created-assets` response.
+#[must_use]
+pub(crate) fn parse_account_created_assets_page(json: &Value) -> Page<CreatedAssetInfo> {
+    let assets = json["assets"]
+        .as_array()
+        .map(|arr| {
+            arr.iter()
+                .filter(|a| !a["deleted"].as_bool().unwrap_or(false))
+                .map(|a| {
+                    let params = &a["params"];
+                    CreatedAssetInfo {
+                        asset_id: a["index"].as_u64().unwrap_or(0),
+                        name: params["name"].as_str().unwrap_or("").to_string(),
+                        unit_name: params["unit-name"].as_str().unwrap_or("").to_string(),
+                        decimals: params["decimals"].as_u64().unwrap_or(0),
+                        total: params["total"].as_u64().unwrap_or(0),
+                    }
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    Page::new(assets, json["next-token"].as_str().map(String::from))
+}
+
+/// Parse an indexer `/v2/accounts/{address}/apps-local-state` response.
+#[must_use]
+pub(crate) fn parse_account_apps_page(json: &Value) -> Page<AppLocalState> {
+    let apps = json["apps-local-states"]
+        .as_array()
+        .map(|arr| {
+            arr.iter()
+                .filter(|a| !a["deleted"].as_bool().unwrap_or(false))
+                .map(|a| AppLocalState {
+                    app_id: a["id"].as_u64().unwrap_or(0),
+                    schema_num_uint: a["schema"]["num-uint"].as_u64().unwrap_or(0),
+                    schema_num_byte_slice: a["schema"]["num-byte-slice"].as_u64().unwrap_or(0),
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    Page::new(apps, json["next-token"].as_str().map(String::from))
+}
+
+/// Parse an indexer `/v2/accounts/{address}/created-applications` response.
+#[must_use]
+pub(crate) fn parse_account_created_apps_page(json: &Value) -> Page<CreatedAppInfo> {
+    let apps = json["applications"]
+        .as_array()
+        .map(|arr| {
+            arr.iter()
+                .filter(|a| !a["deleted"].as_bool().unwrap_or(false))
+                .map(|a| CreatedAppInfo {
+                    app_id: a["id"].as_u64().unwrap_or(0),
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    Page::new(apps, json["next-token"].as_str().map(String::from))
+}
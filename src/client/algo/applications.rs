@@ -4,7 +4,11 @@ use color_eyre::Result;
 use serde_json::Value;
 
 use super::AlgoClient;
-use crate::domain::{AlgoError, AppStateValue, ApplicationDetails, ApplicationInfo};
+use crate::client::cache::Expiry;
+use crate::constants::{CACHE_MUTABLE_TTL, INDEXER_PAGE_LIMIT};
+use crate::domain::account::encode_address;
+use crate::domain::application::{decode_state_key, format_state_bytes};
+use crate::domain::{AlgoError, AppBox, AppStateValue, ApplicationDetails, ApplicationInfo, Page};
 
 impl AlgoClient {
     /// Search for an application by ID.
@@ -20,30 +24,9 @@ impl AlgoClient {
             .into_report()
         })?;
 
-        let app_url = format!("{}/v2/applications/{}", self.indexer_url, app_id);
-
-        let response = self.build_indexer_request(&app_url).send().await?;
-
-        if !response.status().is_success() {
-            let status = response.status();
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|_| "Unknown error".to_string());
-
-            if status.as_u16() == 404 {
-                return Ok(None);
-            } else {
-                return Err(color_eyre::eyre::eyre!(
-                    "Failed to fetch application #{}: HTTP {} - {}",
-                    app_id,
-                    status,
-                    error_text
-                ));
-            }
-        }
-
-        let app_data: Value = response.json().await?;
+        let Some(app_data) = self.fetch_application_json(app_id).await? else {
+            return Ok(None);
+        };
         let app = &app_data["application"];
 
         let creator = app["params"]["creator"]
@@ -65,53 +48,264 @@ impl AlgoClient {
     ///
     /// Returns an error if the application doesn't exist or network request fails.
     pub async fn get_application_details(&self, app_id: u64) -> Result<ApplicationDetails> {
-        let app_url = format!("{}/v2/applications/{}", self.indexer_url, app_id);
-        let response = self.build_indexer_request(&app_url).send().await?;
+        let app_data = self
+            .fetch_application_json(app_id)
+            .await?
+            .ok_or_else(|| AlgoError::not_found("application", app_id.to_string()).into_report())?;
+        Ok(Self::parse_application_details(&app_data, app_id))
+    }
+
+    /// Get basic application info for search results.
+    #[allow(dead_code)] // Public API for future use
+    pub async fn get_application_info(&self, app_id: u64) -> Result<Option<ApplicationInfo>> {
+        let Some(app_data) = self.fetch_application_json(app_id).await? else {
+            return Ok(None);
+        };
+        let app = &app_data["application"];
+
+        Ok(Some(ApplicationInfo {
+            app_id,
+            creator: app["params"]["creator"].as_str().unwrap_or("").to_string(),
+            deleted: app["deleted"].as_bool().unwrap_or(false),
+        }))
+    }
+
+    /// Fetch an application's indexer JSON, through the response cache.
+    ///
+    /// Deleted applications are cached indefinitely; live ones, whose global
+    /// state changes, for [`CACHE_MUTABLE_TTL`].
+    ///
+    /// # Returns
+    ///
+    /// The `/v2/applications/{id}` response, or `None` if the application
+    /// does not exist.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the network request fails.
+    async fn fetch_application_json(&self, app_id: u64) -> Result<Option<Value>> {
+        let cache_key = format!("app:{app_id}");
+        if let Some(app_data) = self.cache_get(&cache_key).await {
+            return Ok(Some(app_data));
+        }
+
+        // Algod serves the same application object, without the indexer's wrapper
+        let response = if self.is_algod_only() {
+            let app_url = format!("{}/v2/applications/{}", self.algod_url, app_id);
+            self.build_algod_request(&app_url).send().await?
+        } else {
+            let app_url = format!("{}/v2/applications/{}", self.indexer_url, app_id);
+            self.build_indexer_request(&app_url).send().await?
+        };
 
         if !response.status().is_success() {
             let status = response.status();
             if status.as_u16() == 404 {
-                return Err(AlgoError::not_found("application", app_id.to_string()).into_report());
+                return Ok(None);
             }
             let error_text = response
                 .text()
                 .await
                 .unwrap_or_else(|_| "Unknown error".to_string());
             return Err(color_eyre::eyre::eyre!(
-                "Failed to fetch application details: HTTP {} - {}",
+                "Failed to fetch application #{}: HTTP {} - {}",
+                app_id,
                 status,
                 error_text
             ));
         }
 
-        let app_data: Value = response.json().await?;
-        Ok(Self::parse_application_details(&app_data, app_id))
+        let mut app_data: Value = response.json().await?;
+        if self.is_algod_only() {
+            app_data = serde_json::json!({ "application": app_data });
+        }
+        let expiry = if app_data["application"]["deleted"]
+            .as_bool()
+            .unwrap_or(false)
+        {
+            Expiry::Never
+        } else {
+            Expiry::After(CACHE_MUTABLE_TTL)
+        };
+        self.cache_put(&cache_key, &app_data, expiry).await;
+        Ok(Some(app_data))
     }
 
-    /// Get basic application info for search results.
-    #[allow(dead_code)] // Public API for future use
-    pub async fn get_application_info(&self, app_id: u64) -> Result<Option<ApplicationInfo>> {
-        let app_url = format!("{}/v2/applications/{}", self.indexer_url, app_id);
-        let response = self.build_indexer_request(&app_url).send().await?;
+    /// Fetch one page of an application's box names from the indexer.
+    ///
+    /// Values are not included; use [`Self::get_application_box_value`] to
+    /// fetch a single box's contents.
+    ///
+    /// # Arguments
+    ///
+    /// * `app_id` - The application ID
+    /// * `next_token` - Token from the previous page, or `None` for the first page
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the indexer responds with a
+    /// non-success status.
+    pub async fn get_application_boxes_page(
+        &self,
+        app_id: u64,
+        next_token: Option<&str>,
+    ) -> Result<Page<AppBox>> {
+        self.require_indexer("Box listing")?;
+        let url = format!("{}/v2/applications/{}/boxes", self.indexer_url, app_id);
+        let page_url = Self::paginated_url(&url, INDEXER_PAGE_LIMIT, next_token);
+        let response = self.build_indexer_request(&page_url).send().await?;
 
         if !response.status().is_success() {
-            if response.status().as_u16() == 404 {
-                return Ok(None);
+            let status = response.status();
+            let error_text = response
+                .text()
+                .await
+                .unwrap_or_else(|_| "Unknown error".to_string());
+            return Err(color_eyre::eyre::eyre!(
+                "Failed to fetch boxes for application #{}: HTTP {} - {}",
+                app_id,
+                status,
+                error_text
+            ));
+        }
+
+        let json: Value = response.json().await?;
+        Ok(parse_boxes_page(&json))
+    }
+
+    /// Fetch the value of a single application box.
+    ///
+    /// Algod is asked first since it reflects the latest round; the indexer is
+    /// used as a fallback.
+    ///
+    /// # Arguments
+    ///
+    /// * `app_id` - The application ID
+    /// * `name` - The box name (Base64)
+    ///
+    /// # Returns
+    ///
+    /// The box value as Base64.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if neither algod nor the indexer can return the box.
+    pub async fn get_application_box_value(&self, app_id: u64, name: &str) -> Result<String> {
+        let query = box_name_query(name);
+
+        let algod_url = format!(
+            "{}/v2/applications/{}/box?{}",
+            self.algod_url, app_id, query
+        );
+        if let Ok(response) = self.build_algod_request(&algod_url).send().await
+            && response.status().is_success()
+            && let Ok(json) = response.json::<Value>().await
+            && let Some(value) = json["value"].as_str()
+        {
+            return Ok(value.to_string());
+        }
+        if self.is_algod_only() {
+            return Err(AlgoError::not_found("box", name.to_string()).into_report());
+        }
+
+        let indexer_url = format!(
+            "{}/v2/applications/{}/box?{}",
+            self.indexer_url, app_id, query
+        );
+        let response = self.build_indexer_request(&indexer_url).send().await?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            if status.as_u16() == 404 {
+                return Err(AlgoError::not_found("box", name.to_string()).into_report());
             }
             return Err(color_eyre::eyre::eyre!(
-                "Failed to fetch application: HTTP {}",
-                response.status()
+                "Failed to fetch box value: HTTP {}",
+                status
             ));
         }
 
-        let app_data: Value = response.json().await?;
-        let app = &app_data["application"];
+        let json: Value = response.json().await?;
+        json["value"]
+            .as_str()
+            .map(String::from)
+            .ok_or_else(|| AlgoError::parse("Box response is missing a value").into_report())
+    }
 
-        Ok(Some(ApplicationInfo {
-            app_id,
-            creator: app["params"]["creator"].as_str().unwrap_or("").to_string(),
-            deleted: app["deleted"].as_bool().unwrap_or(false),
-        }))
+    /// Fetch an account's local state for one application.
+    ///
+    /// Algod's `/v2/accounts/{addr}/applications/{id}` is asked first; the
+    /// indexer's `apps-local-state` listing is used as a fallback.
+    ///
+    /// # Arguments
+    ///
+    /// * `address` - The account address
+    /// * `app_id` - The application ID
+    ///
+    /// # Returns
+    ///
+    /// The decoded key/value pairs (empty if the account is opted in but
+    /// stores nothing).
+    ///
+    /// # Errors
+    ///
+    /// Returns a not-found error if the account has not opted into the
+    /// application, or an error if both requests fail.
+    pub async fn get_account_app_local_state(
+        &self,
+        address: &str,
+        app_id: u64,
+    ) -> Result<Vec<AppStateValue>> {
+        let cache_key = format!("local-state:{address}:{app_id}");
+        if let Some(json) = self.cache_get(&cache_key).await
+            && let Some(entries) = parse_algod_local_state(&json)
+        {
+            return Ok(entries);
+        }
+
+        let algod_url = format!(
+            "{}/v2/accounts/{}/applications/{}",
+            self.algod_url, address, app_id
+        );
+        if let Ok(response) = self.build_algod_request(&algod_url).send().await
+            && response.status().is_success()
+            && let Ok(json) = response.json::<Value>().await
+        {
+            self.cache_put(&cache_key, &json, Expiry::After(CACHE_MUTABLE_TTL))
+                .await;
+            return parse_algod_local_state(&json).ok_or_else(|| {
+                AlgoError::not_found("local state", format!("{address} in app #{app_id}"))
+                    .into_report()
+            });
+        }
+        if self.is_algod_only() {
+            return Err(
+                AlgoError::not_found("local state", format!("{address} in app #{app_id}"))
+                    .into_report(),
+            );
+        }
+
+        let indexer_url = format!(
+            "{}/v2/accounts/{}/apps-local-state?application-id={}&include-all=true",
+            self.indexer_url, address, app_id
+        );
+        let response = self.build_indexer_request(&indexer_url).send().await?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            if status.as_u16() == 404 {
+                return Err(AlgoError::not_found("account", address.to_string()).into_report());
+            }
+            return Err(color_eyre::eyre::eyre!(
+                "Failed to fetch local state: HTTP {}",
+                status
+            ));
+        }
+
+        let json: Value = response.json().await?;
+        parse_indexer_local_state(&json, app_id).ok_or_else(|| {
+            AlgoError::not_found("local state", format!("{address} in app #{app_id}")).into_report()
+        })
     }
 
     #[must_use]
@@ -124,7 +318,7 @@ impl AlgoClient {
         let local_schema = &params["local-state-schema"];
 
         // Parse global state key-value pairs
-        let global_state = Self::parse_global_state(&params["global-state"]);
+        let global_state = parse_state_key_values(&params["global-state"]);
 
         // Compute application address from app_id
         let app_address = Self::compute_application_address(app_id);
@@ -146,71 +340,11 @@ impl AlgoClient {
         }
     }
 
-    #[must_use]
-    fn parse_global_state(state_json: &Value) -> Vec<AppStateValue> {
-        let Some(state_array) = state_json.as_array() else {
-            return Vec::new();
-        };
-
-        state_array
-            .iter()
-            .filter_map(|entry| {
-                let key_b64 = entry["key"].as_str()?;
-                let key = Self::decode_base64_to_string(key_b64);
-                let value = &entry["value"];
-
-                let (value_type, decoded_value) = if value["type"].as_u64() == Some(1) {
-                    // Bytes type
-                    let bytes_b64 = value["bytes"].as_str().unwrap_or("");
-                    let decoded = Self::decode_base64_to_string(bytes_b64);
-                    // Display as-is if it's printable, otherwise show truncated hex
-                    let display_value = if decoded.chars().all(|c| c.is_ascii_graphic() || c == ' ')
-                    {
-                        decoded
-                    } else {
-                        // Show truncated hex for binary data
-                        let hex = bytes_b64.chars().take(20).collect::<String>();
-                        if bytes_b64.len() > 20 {
-                            format!("{}...", hex)
-                        } else {
-                            hex
-                        }
-                    };
-                    ("Bytes".to_string(), display_value)
-                } else {
-                    // Uint type
-                    let uint_val = value["uint"].as_u64().unwrap_or(0);
-                    ("Uint".to_string(), uint_val.to_string())
-                };
-
-                Some(AppStateValue {
-                    key,
-                    value_type,
-                    value: decoded_value,
-                })
-            })
-            .collect()
-    }
-
-    /// Decode Base64 to UTF-8 string, falling back to raw bytes display.
-    #[must_use]
-    fn decode_base64_to_string(b64: &str) -> String {
-        use base64::Engine;
-        let engine = base64::engine::general_purpose::STANDARD;
-        match engine.decode(b64) {
-            Ok(bytes) => {
-                let len = bytes.len();
-                String::from_utf8(bytes).unwrap_or_else(|_| format!("<binary:{len} bytes>"))
-            }
-            Err(_) => b64.to_string(),
-        }
-    }
-
     /// Compute the application address from app ID.
     ///
     /// Application addresses are derived by hashing "appID" prefix + app_id bytes.
     #[must_use]
-    fn compute_application_address(app_id: u64) -> String {
+    pub(super) fn compute_application_address(app_id: u64) -> String {
         use sha2::{Digest, Sha512_256};
 
         // "appID" prefix as bytes
@@ -221,26 +355,87 @@ impl AlgoClient {
         let hash = hasher.finalize();
 
         // Encode as base32 (Algorand address format)
-        Self::encode_address(&hash)
+        encode_address(&hash)
     }
+}
 
-    /// Encode 32-byte hash as Algorand address (base32 with checksum).
-    #[must_use]
-    fn encode_address(public_key: &[u8]) -> String {
-        use sha2::{Digest, Sha512_256};
+// ============================================================================
+// Helper Functions
+// ============================================================================
+
+/// Parse a TEAL key/value array (global or local state) into display values.
+#[must_use]
+pub(crate) fn parse_state_key_values(state_json: &Value) -> Vec<AppStateValue> {
+    let Some(state_array) = state_json.as_array() else {
+        return Vec::new();
+    };
+
+    state_array
+        .iter()
+        .filter_map(|entry| {
+            let key_b64 = entry["key"].as_str()?;
+            let key = decode_state_key(key_b64);
+            let value = &entry["value"];
+
+            let (value_type, decoded_value) = if value["type"].as_u64() == Some(1) {
+                // Bytes type
+                let bytes_b64 = value["bytes"].as_str().unwrap_or("");
+                let display_value = format_state_bytes(bytes_b64);
+                ("Bytes".to_string(), display_value)
+            } else {
+                // Uint type
+                let uint_val = value["uint"].as_u64().unwrap_or(0);
+                ("Uint".to_string(), uint_val.to_string())
+            };
+
+            Some(AppStateValue {
+                key,
+                value_type,
+                value: decoded_value,
+            })
+        })
+        .collect()
+}
 
-        // Compute checksum (last 4 bytes of hash of public key)
-        let mut hasher = Sha512_256::new();
-        hasher.update(public_key);
-        let hash = hasher.finalize();
-        let checksum = &hash[28..32];
+/// Extract local state from an algod account-application response.
+///
+/// Returns `None` if the account has not opted into the application.
+#[must_use]
+pub(crate) fn parse_algod_local_state(json: &Value) -> Option<Vec<AppStateValue>> {
+    let local_state = json.get("app-local-state")?;
+    Some(parse_state_key_values(&local_state["key-value"]))
+}
 
-        // Concatenate public key + checksum
-        let mut addr_bytes = Vec::with_capacity(36);
-        addr_bytes.extend_from_slice(public_key);
-        addr_bytes.extend_from_slice(checksum);
+/// Extract one application's local state from an indexer `apps-local-state` response.
+///
+/// Returns `None` if the account has not opted into the application.
+#[must_use]
+pub(crate) fn parse_indexer_local_state(json: &Value, app_id: u64) -> Option<Vec<AppStateValue>> {
+    json["apps-local-states"]
+        .as_array()?
+        .iter()
+        .find(|entry| {
+            entry["id"].as_u64() == Some(app_id) && !entry["deleted"].as_bool().unwrap_or(false)
+        })
+        .map(|entry| parse_state_key_values(&entry["key-value"]))
+}
 
-        // Encode as base32 (no padding)
-        data_encoding::BASE32_NOPAD.encode(&addr_bytes)
-    }
+/// Parse a page of box names from an indexer `/boxes` response.
+#[must_use]
+pub(crate) fn parse_boxes_page(json: &Value) -> Page<AppBox> {
+    let boxes = json["boxes"]
+        .as_array()
+        .map(|arr| {
+            arr.iter()
+                .filter_map(|entry| entry["name"].as_str().map(AppBox::new))
+                .collect()
+        })
+        .unwrap_or_default();
+    Page::new(boxes, json["next-token"].as_str().map(String::from))
+}
+
+/// Build the `name=b64:...` query parameter for a box lookup.
+#[must_use]
+pub(crate) fn box_name_query(name: &str) -> String {
+    format!("name=b64:{}", AlgoClient::encode_base64_param(name))
 }
@@ -4,7 +4,9 @@ use color_eyre::Result;
 use serde_json::Value;
 
 use super::AlgoClient;
-use crate::domain::{AlgoError, AssetDetails, AssetInfo};
+use crate::client::cache::Expiry;
+use crate::constants::{CACHE_ASSET_TTL, INDEXER_PAGE_LIMIT};
+use crate::domain::{AlgoError, AssetDetails, AssetHolder, AssetInfo, Page};
 
 impl AlgoClient {
     /// Search for an asset by ID.
@@ -17,30 +19,9 @@ impl AlgoClient {
             .into_report()
         })?;
 
-        let asset_url = format!("{}/v2/assets/{}", self.indexer_url, asset_id);
-
-        let response = self.build_indexer_request(&asset_url).send().await?;
-
-        if !response.status().is_success() {
-            let status = response.status();
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|_| "Unknown error".to_string());
-
-            if status.as_u16() == 404 {
-                return Ok(None);
-            } else {
-                return Err(color_eyre::eyre::eyre!(
-                    "Failed to fetch asset #{}: HTTP {} - {}",
-                    asset_id,
-                    status,
-                    error_text
-                ));
-            }
-        }
-
-        let asset_data: Value = response.json().await?;
+        let Some(asset_data) = self.fetch_asset_json(asset_id).await? else {
+            return Ok(None);
+        };
         let params = &asset_data["asset"]["params"];
 
         let name = params["name"].as_str().unwrap_or("").to_string();
@@ -67,27 +48,111 @@ impl AlgoClient {
     ///
     /// Returns an error if the asset doesn't exist or network request fails.
     pub async fn get_asset_details(&self, asset_id: u64) -> Result<AssetDetails> {
-        let asset_url = format!("{}/v2/assets/{}", self.indexer_url, asset_id);
-        let response = self.build_indexer_request(&asset_url).send().await?;
+        let asset_data = self
+            .fetch_asset_json(asset_id)
+            .await?
+            .ok_or_else(|| AlgoError::not_found("asset", asset_id.to_string()).into_report())?;
+        Ok(Self::parse_asset_details(&asset_data, asset_id))
+    }
+
+    /// Fetch an asset's indexer JSON, through the response cache.
+    ///
+    /// Deleted assets are cached indefinitely; live assets for
+    /// [`CACHE_ASSET_TTL`], since their parameters rarely change.
+    ///
+    /// # Returns
+    ///
+    /// The `/v2/assets/{id}` response, or `None` if the asset does not exist.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the network request fails.
+    async fn fetch_asset_json(&self, asset_id: u64) -> Result<Option<Value>> {
+        let cache_key = format!("asset:{asset_id}");
+        if let Some(asset_data) = self.cache_get(&cache_key).await {
+            return Ok(Some(asset_data));
+        }
+
+        // Algod serves the same asset object, without the indexer's wrapper
+        let response = if self.is_algod_only() {
+            let asset_url = format!("{}/v2/assets/{}", self.algod_url, asset_id);
+            self.build_algod_request(&asset_url).send().await?
+        } else {
+            let asset_url = format!("{}/v2/assets/{}", self.indexer_url, asset_id);
+            self.build_indexer_request(&asset_url).send().await?
+        };
 
         if !response.status().is_success() {
             let status = response.status();
             if status.as_u16() == 404 {
-                return Err(AlgoError::not_found("asset", asset_id.to_string()).into_report());
+                return Ok(None);
             }
             let error_text = response
                 .text()
                 .await
                 .unwrap_or_else(|_| "Unknown error".to_string());
             return Err(color_eyre::eyre::eyre!(
-                "Failed to fetch asset details: HTTP {} - {}",
+                "Failed to fetch asset #{}: HTTP {} - {}",
+                asset_id,
                 status,
                 error_text
             ));
         }
 
-        let asset_data: Value = response.json().await?;
-        Ok(Self::parse_asset_details(&asset_data, asset_id))
+        let mut asset_data: Value = response.json().await?;
+        if self.is_algod_only() {
+            asset_data = serde_json::json!({ "asset": asset_data });
+        }
+        let expiry = if asset_data["asset"]["deleted"].as_bool().unwrap_or(false) {
+            Expiry::Never
+        } else {
+            Expiry::After(CACHE_ASSET_TTL)
+        };
+        self.cache_put(&cache_key, &asset_data, expiry).await;
+        Ok(Some(asset_data))
+    }
+
+    /// Fetch a page of an asset's holders from the indexer.
+    ///
+    /// # Arguments
+    ///
+    /// * `asset_id` - The asset ID
+    /// * `min_balance` - Only include holders with more than this many base units
+    /// * `next_token` - Token from the previous page, if continuing the list
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the network request fails.
+    pub async fn get_asset_holders_page(
+        &self,
+        asset_id: u64,
+        min_balance: Option<u64>,
+        next_token: Option<&str>,
+    ) -> Result<Page<AssetHolder>> {
+        self.require_indexer("Asset holders")?;
+        let mut url = format!("{}/v2/assets/{}/balances", self.indexer_url, asset_id);
+        if let Some(min) = min_balance {
+            url.push_str(&format!("?currency-greater-than={min}"));
+        }
+        let page_url = Self::paginated_url(&url, INDEXER_PAGE_LIMIT, next_token);
+        let response = self.build_indexer_request(&page_url).send().await?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let error_text = response
+                .text()
+                .await
+                .unwrap_or_else(|_| "Unknown error".to_string());
+            return Err(color_eyre::eyre::eyre!(
+                "Failed to fetch holders for asset #{}: HTTP {} - {}",
+                asset_id,
+                status,
+                error_text
+            ));
+        }
+
+        let json: Value = response.json().await?;
+        Ok(parse_asset_holders_page(&json))
     }
 
     #[must_use]
@@ -114,3 +179,25 @@ impl AlgoClient {
         }
     }
 }
+
+/// Parse an indexer `/v2/assets/{id}/balances` response into a page of holders.
+#[must_use]
+pub(crate) fn parse_asset_holders_page(json: &Value) -> Page<AssetHolder> {
+    let holders = json["balances"]
+        .as_array()
+        .map(|arr| {
+            arr.iter()
+                .filter(|entry| !entry["deleted"].as_bool().unwrap_or(false))
+                .filter_map(|entry| {
+                    Some(AssetHolder {
+                        address: entry["address"].as_str()?.to_string(),
+                        amount: entry["amount"].as_u64().unwrap_or(0),
+                        is_frozen: entry["is-frozen"].as_bool().unwrap_or(false),
+                        opted_in_at_round: entry["opted-in-at-round"].as_u64(),
+                    })
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    Page::new(holders, json["next-token"].as_str().map(String::from))
+}
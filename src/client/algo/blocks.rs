@@ -5,8 +5,12 @@ use serde_json::Value;
 use tokio::task::JoinSet;
 
 use super::AlgoClient;
+use super::transactions::parse_transactions_page;
+use crate::client::cache::Expiry;
+use crate::constants::WAIT_FOR_BLOCK_TIMEOUT;
 use crate::domain::{
-    AlgoBlock, AlgoError, BlockDetails, BlockInfo, count_transactions, format_timestamp,
+    AlgoBlock, AlgoError, BlockDetails, BlockInfo, MsgpBlock, Page, Transaction,
+    count_transactions, format_timestamp,
 };
 
 impl AlgoClient {
@@ -33,32 +37,21 @@ impl AlgoClient {
         let num_blocks = limit.min(latest_round as usize);
 
         for round in (0..num_blocks).map(|i| latest_round - i as u64) {
-            let block_url = format!("{}/v2/blocks/{}", self.algod_url, round);
-            let request = self.build_algod_request(&block_url).send();
+            let client = self.clone();
 
             join_set.spawn(async move {
-                let response = request
+                let bytes = client
+                    .get_raw_block(round)
                     .await
                     .inspect_err(|e| tracing::debug!("Block {round} fetch failed: {e}"))
-                    .ok()?
-                    .error_for_status()
-                    .inspect_err(|e| tracing::debug!("Block {round} HTTP error: {e}"))
+                    .ok()??;
+                let block = MsgpBlock::from_bytes(&bytes)
+                    .inspect_err(|e| tracing::debug!("Block {round} msgpack decode error: {e}"))
                     .ok()?;
-                let block_data: Value = response
-                    .json()
-                    .await
-                    .inspect_err(|e| tracing::debug!("Block {round} JSON parse error: {e}"))
-                    .ok()?;
-
-                let block = block_data.get("block").unwrap_or(&block_data);
-                let timestamp_secs = block["ts"].as_u64().unwrap_or(0);
-                let formatted_time = format_timestamp(timestamp_secs);
-                let txn_count = count_transactions(block);
 
                 Some(AlgoBlock {
                     id: round,
-                    txn_count,
-                    timestamp: formatted_time,
+                    ..block.to_algo_block()
                 })
             });
         }
@@ -71,6 +64,141 @@ impl AlgoClient {
         Ok(blocks)
     }
 
+    /// Fetch a block as the canonical msgpack bytes algod produced.
+    ///
+    /// Returns `Ok(None)` if the round does not exist yet.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    pub async fn get_raw_block(&self, round: u64) -> Result<Option<Vec<u8>>> {
+        let url = format!("{}/v2/blocks/{}", self.algod_url, round);
+        self.fetch_algod_msgpack(&url).await
+    }
+
+    /// Wait until algod has committed a round after `round`.
+    ///
+    /// algod holds the request open until the next round lands, or returns
+    /// early after its own timeout. Waiting after round 0 returns immediately.
+    ///
+    /// # Returns
+    ///
+    /// The latest round, which may be several rounds ahead after a reconnect
+    /// or equal to `round` if algod timed out.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    pub async fn wait_for_block_after(&self, round: u64) -> Result<u64> {
+        let url = format!(
+            "{}/v2/status/wait-for-block-after/{}",
+            self.algod_url, round
+        );
+        let response = self
+            .build_algod_request(&url)
+            .timeout(WAIT_FOR_BLOCK_TIMEOUT)
+            .send()
+            .await?
+            .error_for_status()?;
+
+        let status: Value = response.json().await?;
+        status["last-round"].as_u64().ok_or_else(|| {
+            AlgoError::parse("algod status response missing 'last-round'").into_report()
+        })
+    }
+
+    /// Fetch rounds `first..=last` for the live blocks and transactions panels.
+    ///
+    /// Both lists come from the same algod block bodies, newest first, so
+    /// every round is fetched once and doesn't wait for the indexer.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any of the rounds can't be fetched, so the caller
+    /// can retry the whole range without leaving a gap.
+    pub async fn get_rounds(
+        &self,
+        first: u64,
+        last: u64,
+    ) -> Result<(Vec<AlgoBlock>, Vec<Transaction>)> {
+        let mut blocks = Vec::new();
+        for (round, result) in self.fetch_rounds(first, last).await {
+            let block = result?
+                .ok_or_else(|| AlgoError::not_found("block", round.to_string()).into_report())?;
+            blocks.push(block);
+        }
+
+        blocks.sort_by_key(|block| std::cmp::Reverse(block.round()));
+        let transactions = blocks
+            .iter()
+            .flat_map(|block| block.transactions().into_iter().rev())
+            .collect();
+        let blocks = blocks.iter().map(MsgpBlock::to_algo_block).collect();
+        Ok((blocks, transactions))
+    }
+
+    /// Decode the transactions of rounds `first..=last` from algod block bodies.
+    ///
+    /// Rounds that can't be fetched are skipped. Transactions are returned
+    /// newest first.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if every block request fails.
+    pub(crate) async fn get_rounds_transactions(
+        &self,
+        first: u64,
+        last: u64,
+    ) -> Result<Vec<Transaction>> {
+        let mut blocks = Vec::new();
+        let mut last_error = None;
+        for (round, result) in self.fetch_rounds(first, last).await {
+            match result {
+                Ok(Some(block)) => blocks.push(block),
+                Ok(None) => {}
+                Err(e) => {
+                    tracing::debug!("Block {round} fetch failed: {e}");
+                    last_error = Some(e);
+                }
+            }
+        }
+        if blocks.is_empty()
+            && let Some(e) = last_error
+        {
+            return Err(e);
+        }
+
+        blocks.sort_by_key(|block| std::cmp::Reverse(block.round()));
+        Ok(blocks
+            .iter()
+            .flat_map(|block| block.transactions().into_iter().rev())
+            .collect())
+    }
+
+    /// Fetch and decode rounds `first..=last` in parallel, in no particular order.
+    async fn fetch_rounds(&self, first: u64, last: u64) -> Vec<(u64, Result<Option<MsgpBlock>>)> {
+        let mut join_set = JoinSet::new();
+        for round in first..=last {
+            let client = self.clone();
+            join_set.spawn(async move { (round, client.get_block_msgpack(round).await) });
+        }
+        join_set.join_all().await
+    }
+
+    /// Fetch and decode a block from algod's msgpack encoding.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the block cannot be decoded.
+    pub async fn get_block_msgpack(&self, round: u64) -> Result<Option<MsgpBlock>> {
+        let Some(bytes) = self.get_raw_block(round).await? else {
+            return Ok(None);
+        };
+        MsgpBlock::from_bytes(&bytes)
+            .map(Some)
+            .map_err(AlgoError::into_report)
+    }
+
     /// Search for a block by round number.
     pub(crate) async fn search_block(&self, round_str: &str) -> Result<Option<BlockInfo>> {
         let round = round_str.parse::<u64>().map_err(|_| {
@@ -81,6 +209,16 @@ impl AlgoClient {
             .into_report()
         })?;
 
+        // Blocks never change once they exist
+        let cache_key = format!("block:{round}");
+        if let Some(info) = self
+            .cache_get(&cache_key)
+            .await
+            .and_then(|json| serde_json::from_value::<BlockInfo>(json).ok())
+        {
+            return Ok(Some(info));
+        }
+
         let block_url = format!("{}/v2/blocks/{}", self.algod_url, round);
 
         let response = self.build_algod_request(&block_url).send().await?;
@@ -121,22 +259,37 @@ impl AlgoClient {
 
         let seed = block_val["seed"].as_str().unwrap_or("unknown").to_string();
 
-        Ok(Some(BlockInfo {
+        let info = BlockInfo {
             id: round,
             timestamp: formatted_time,
             txn_count,
             proposer,
             seed,
-        }))
+        };
+        if let Ok(json) = serde_json::to_value(&info) {
+            self.cache_put(&cache_key, &json, Expiry::Never).await;
+        }
+        Ok(Some(info))
     }
 
-    /// Get detailed block information including all transactions
+    /// Get detailed block information with the first page of transactions
+    ///
+    /// Busy blocks can hold more transactions than one indexer page; the
+    /// returned details keep the `next-token` so the rest can be fetched
+    /// with [`AlgoClient::get_block_transactions_page`].
     ///
     /// # Errors
     ///
-    /// Returns an error if the network request fails or parsing fails.
+    /// Returns an error if the network request fails or parsing fails, or if
+    /// the block has transactions that neither the indexer nor algod returned.
     pub async fn get_block_details(&self, round: u64) -> Result<Option<BlockDetails>> {
-        use super::transactions::parse_transactions_array;
+        if self.is_algod_only() {
+            let Some(block) = self.get_block_msgpack(round).await? else {
+                return Ok(None);
+            };
+            let page = Page::new(block.transactions(), None);
+            return Ok(Some(BlockDetails::from_page(block.to_block_info(), page)));
+        }
 
         // First, get the basic block info
         let block_info = match self.search_block(&round.to_string()).await? {
@@ -144,29 +297,69 @@ impl AlgoClient {
             None => return Ok(None),
         };
 
-        // Fetch transactions for this round using the indexer
-        let txns_url = format!("{}/v2/transactions?round={}", self.indexer_url, round);
+        let page = match self.get_block_transactions_page(round, None).await {
+            Ok(page) if !page.items.is_empty() || block_info.txn_count == 0 => page,
+            indexer_result => {
+                // The indexer is lagging or unavailable: decode the payset from algod instead
+                match (indexer_result, self.get_block_msgpack(round).await) {
+                    (_, Ok(Some(block))) => Page::new(block.transactions(), None),
+                    (Err(e), algod_result) => {
+                        if let Err(algod_error) = algod_result {
+                            tracing::debug!("Block {round} msgpack fetch failed: {algod_error}");
+                        }
+                        return Err(e);
+                    }
+                    // The indexer hasn't caught up and algod can't fill in
+                    (Ok(_), Err(e)) => return Err(e),
+                    (Ok(page), Ok(None)) => page,
+                }
+            }
+        };
 
-        let response = self.build_indexer_request(&txns_url).send().await?;
+        Ok(Some(BlockDetails::from_page(block_info, page)))
+    }
 
-        let transactions = if response.status().is_success() {
-            let json: Value = response.json().await?;
-            parse_transactions_array(&json)?
-        } else {
-            // If we can't get transactions, return empty list
-            Vec::new()
-        };
+    /// Fetch one page of the transactions confirmed in a round.
+    ///
+    /// # Arguments
+    ///
+    /// * `round` - The block round
+    /// * `next_token` - Token from the previous page, or `None` for the first page
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the indexer request fails.
+    pub async fn get_block_transactions_page(
+        &self,
+        round: u64,
+        next_token: Option<&str>,
+    ) -> Result<Page<Transaction>> {
+        // Without an indexer the whole payset comes from the block in one page
+        if self.is_algod_only() {
+            let transactions = self
+                .get_block_msgpack(round)
+                .await?
+                .map(|block| block.transactions())
+                .unwrap_or_default();
+            return Ok(Page::new(transactions, None));
+        }
 
-        // Compute transaction type counts
-        let mut txn_type_counts = std::collections::HashMap::new();
-        for txn in &transactions {
-            *txn_type_counts.entry(txn.txn_type).or_insert(0) += 1;
+        let cache_key = format!("block-txns:{round}:{}", next_token.unwrap_or_default());
+        if let Some(json) = self.cache_get(&cache_key).await {
+            return parse_transactions_page(&json);
         }
 
-        Ok(Some(BlockDetails {
-            info: block_info,
-            transactions,
-            txn_type_counts,
-        }))
+        let txns_url = format!("{}/v2/transactions?round={}", self.indexer_url, round);
+        let json = self
+            .fetch_transactions_page_json(&txns_url, next_token, "Block transactions")
+            .await?;
+        // An empty page may only mean the indexer has not caught up with the round yet
+        if json["transactions"]
+            .as_array()
+            .is_some_and(|txns| !txns.is_empty())
+        {
+            self.cache_put(&cache_key, &json, Expiry::Never).await;
+        }
+        parse_transactions_page(&json)
     }
 }
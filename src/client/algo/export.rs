@@ -0,0 +1,82 @@
+//! List export paging for AlgoClient.
+
+use color_eyre::Result;
+
+use std::collections::HashMap;
+
+use super::AlgoClient;
+use crate::domain::export::{ExportValue, holder_record, transaction_record};
+use crate::domain::{ExportSource, Page, TxnType};
+
+impl AlgoClient {
+    /// Fetch one page of an export as records.
+    ///
+    /// # Arguments
+    ///
+    /// * `source` - The list being exported
+    /// * `next_token` - Token from the previous page, or `None` for the first page
+    /// * `asset_decimals` - Decimals of assets seen so far; transferred assets
+    ///   missing from it are looked up and added
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the indexer request fails. Assets that can't be
+    /// looked up only fill the base unit amount column.
+    pub async fn get_export_page(
+        &self,
+        source: &ExportSource,
+        next_token: Option<&str>,
+        asset_decimals: &mut HashMap<u64, u64>,
+    ) -> Result<Page<Vec<ExportValue>>> {
+        let page = match source {
+            ExportSource::AssetHolders {
+                asset_id,
+                decimals,
+                min_balance,
+            } => {
+                let page = self
+                    .get_asset_holders_page(*asset_id, *min_balance, next_token)
+                    .await?;
+                let records = page
+                    .items
+                    .iter()
+                    .map(|holder| holder_record(holder, *decimals))
+                    .collect();
+                return Ok(Page::new(records, page.next_token));
+            }
+            ExportSource::BlockTransactions { round } => {
+                self.get_block_transactions_page(*round, next_token).await?
+            }
+            ExportSource::AccountHistory { address, txn_type } => {
+                self.get_account_transactions_page(address, *txn_type, next_token)
+                    .await?
+            }
+            ExportSource::TxnSearch(query) => {
+                self.search_transactions_page(query, next_token).await?
+            }
+        };
+
+        let mut missing: Vec<u64> = page
+            .items
+            .iter()
+            .filter(|txn| txn.txn_type == TxnType::AssetTransfer)
+            .filter_map(|txn| txn.asset_id)
+            .filter(|asset_id| !asset_decimals.contains_key(asset_id))
+            .collect();
+        missing.sort_unstable();
+        missing.dedup();
+        for (asset_id, info) in self.lookup_assets(&missing).await {
+            asset_decimals.insert(asset_id, info.decimals);
+        }
+
+        let records = page
+            .items
+            .iter()
+            .map(|txn| {
+                let decimals = txn.asset_id.and_then(|id| asset_decimals.get(&id).copied());
+                transaction_record(txn, decimals)
+            })
+            .collect();
+        Ok(Page::new(records, page.next_token))
+    }
+}
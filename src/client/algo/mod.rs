@@ -16,17 +16,28 @@
 //! ```
 
 use reqwest::Client;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
 use std::time::Duration;
+use tokio::sync::OnceCell;
+use tokio::task::spawn_blocking;
 
-use crate::domain::{AlgoError, Network};
+use super::cache::{self, Expiry, ResponseCache};
+use crate::domain::{AlgoError, AssetInfo, DataSource, Network};
 
 mod accounts;
 mod applications;
 mod assets;
 mod blocks;
+mod export;
 mod nfd;
+mod pending;
 mod search;
+mod simulate;
 mod transactions;
+mod watchlist;
 
 #[cfg(test)]
 mod tests;
@@ -53,6 +64,17 @@ pub struct AlgoClient {
     pub(crate) is_localnet: bool,
     /// HTTP client for requests.
     pub(crate) client: Client,
+    /// Genesis hash of the network, resolved on first cache access.
+    genesis_hash: Arc<OnceCell<String>>,
+    /// Whether the network is configured without an indexer.
+    configured_algod_only: bool,
+    /// Whether reads skip the indexer, either configured or because
+    /// [`Self::get_network_status`] found it unreachable. Shared by clones.
+    algod_only: Arc<AtomicBool>,
+    /// Assets resolved for holdings lists and exports, shared by clones.
+    /// Names, units and decimals never change, so entries live as long as
+    /// the client.
+    asset_params: Arc<Mutex<HashMap<u64, AssetInfo>>>,
 }
 
 impl AlgoClient {
@@ -75,6 +97,10 @@ impl AlgoClient {
             allow_nfd: matches!(network, Network::MainNet | Network::TestNet),
             is_localnet: network == Network::LocalNet,
             client,
+            genesis_hash: Arc::default(),
+            configured_algod_only: false,
+            algod_only: Arc::default(),
+            asset_params: Arc::default(),
         })
     }
 
@@ -102,6 +128,10 @@ impl AlgoClient {
                 ),
                 is_localnet: *network == Network::LocalNet,
                 client,
+                genesis_hash: Arc::default(),
+                configured_algod_only: false,
+                algod_only: Arc::default(),
+                asset_params: Arc::default(),
             },
             NetworkConfig::Custom(custom) => Self {
                 indexer_url: custom.indexer_url.clone(),
@@ -112,6 +142,10 @@ impl AlgoClient {
                 allow_nfd: false,
                 is_localnet: false,
                 client,
+                genesis_hash: Arc::default(),
+                configured_algod_only: custom.algod_only,
+                algod_only: Arc::new(AtomicBool::new(custom.algod_only)),
+                asset_params: Arc::default(),
             },
         })
     }
@@ -138,6 +172,41 @@ impl AlgoClient {
         &self.algod_url
     }
 
+    /// Returns where chain history is currently read from.
+    #[must_use]
+    pub fn data_source(&self) -> DataSource {
+        if self.is_algod_only() {
+            DataSource::AlgodOnly
+        } else {
+            DataSource::Indexer
+        }
+    }
+
+    /// Switches between the indexer and algod-only reads.
+    ///
+    /// Networks configured as algod-only stay algod-only.
+    pub fn set_data_source(&self, source: DataSource) {
+        let algod_only = self.configured_algod_only || source == DataSource::AlgodOnly;
+        self.algod_only.store(algod_only, Ordering::Relaxed);
+    }
+
+    #[must_use]
+    pub(crate) fn is_algod_only(&self) -> bool {
+        self.algod_only.load(Ordering::Relaxed)
+    }
+
+    /// Fails with [`AlgoError::IndexerUnavailable`] in algod-only mode.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error naming `feature` if there is no indexer to serve it.
+    pub(crate) fn require_indexer(&self, feature: &'static str) -> color_eyre::Result<()> {
+        if self.is_algod_only() {
+            return Err(AlgoError::indexer_unavailable(feature).into_report());
+        }
+        Ok(())
+    }
+
     #[must_use]
     pub fn nfd_api_url(&self) -> Option<&str> {
         self.nfd_api_url.as_deref()
@@ -149,18 +218,68 @@ impl AlgoClient {
     }
 
     pub(crate) fn build_algod_request(&self, url: &str) -> reqwest::RequestBuilder {
-        let mut request = self.client.get(url).header("accept", "application/json");
+        self.algod_request_accepting(url, "application/json")
+    }
+
+    /// Builds an algod GET request with the auth header and the given `accept` type.
+    fn algod_request_accepting(&self, url: &str, accept: &str) -> reqwest::RequestBuilder {
+        self.with_algod_token(self.client.get(url).header("accept", accept))
+    }
 
+    /// Builds an algod POST request sending and accepting canonical msgpack.
+    pub(crate) fn build_algod_msgpack_post(
+        &self,
+        url: &str,
+        body: Vec<u8>,
+    ) -> reqwest::RequestBuilder {
+        self.with_algod_token(
+            self.client
+                .post(url)
+                .header("accept", "application/msgpack")
+                .header("content-type", "application/msgpack")
+                .body(body),
+        )
+    }
+
+    /// Adds the algod auth header, if the network needs one.
+    fn with_algod_token(&self, request: reqwest::RequestBuilder) -> reqwest::RequestBuilder {
         if let Some(token) = &self.algod_token {
-            request = request.header("X-Algo-API-Token", token);
+            request.header("X-Algo-API-Token", token)
         } else if self.is_localnet {
-            request = request.header(
+            request.header(
                 "X-Algo-API-Token",
                 "aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa",
-            );
+            )
+        } else {
+            request
         }
+    }
 
-        request
+    /// Fetches an algod endpoint as canonical msgpack (`?format=msgpack`).
+    ///
+    /// Returns `Ok(None)` when algod answers 404.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or algod returns another error status.
+    pub(crate) async fn fetch_algod_msgpack(
+        &self,
+        url: &str,
+    ) -> color_eyre::Result<Option<Vec<u8>>> {
+        let separator = if url.contains('?') { '&' } else { '?' };
+        let response = self
+            .algod_request_accepting(
+                &format!("{url}{separator}format=msgpack"),
+                "application/msgpack",
+            )
+            .send()
+            .await?;
+
+        if response.status().as_u16() == 404 {
+            return Ok(None);
+        }
+        let response = response.error_for_status()?;
+        Ok(Some(response.bytes().await?.to_vec()))
     }
 
     pub(crate) fn build_indexer_request(&self, url: &str) -> reqwest::RequestBuilder {
@@ -178,19 +297,135 @@ impl AlgoClient {
         request
     }
 
+    /// Appends the indexer `limit` and `next` pagination parameters to a URL.
+    ///
+    /// # Arguments
+    ///
+    /// * `url` - Indexer list endpoint URL, with or without a query string
+    /// * `limit` - Maximum number of items to request
+    /// * `next_token` - Token from the previous page, if continuing a list
+    #[must_use]
+    pub(crate) fn paginated_url(url: &str, limit: usize, next_token: Option<&str>) -> String {
+        let separator = if url.contains('?') { '&' } else { '?' };
+        let mut paged = format!("{url}{separator}limit={limit}");
+        if let Some(token) = next_token {
+            paged.push_str("&next=");
+            paged.push_str(token);
+        }
+        paged
+    }
+
+    /// Percent-encodes a Base64 value for use in a query parameter.
+    ///
+    /// Base64 may contain `+`, `/` and `=`, which are reserved in URLs.
+    #[must_use]
+    pub(crate) fn encode_base64_param(value: &str) -> String {
+        value
+            .replace('+', "%2B")
+            .replace('/', "%2F")
+            .replace('=', "%3D")
+    }
+
+    /// Returns the cached response for `key` on this network, if the response
+    /// cache is installed and holds a fresh entry.
+    pub(crate) async fn cache_get(&self, key: &str) -> Option<Value> {
+        let (cache, genesis_hash) = self.cache_scope().await?;
+        let (genesis_hash, key) = (genesis_hash.to_string(), key.to_string());
+        // Cache files are read off the async worker threads
+        spawn_blocking(move || cache.get(&genesis_hash, &key))
+            .await
+            .ok()
+            .flatten()
+    }
+
+    /// Stores a response for `key` on this network in the response cache.
+    pub(crate) async fn cache_put(&self, key: &str, value: &Value, expiry: Expiry) {
+        if let Some((cache, genesis_hash)) = self.cache_scope().await {
+            let (genesis_hash, key, value) =
+                (genesis_hash.to_string(), key.to_string(), value.clone());
+            let _ = spawn_blocking(move || cache.put(&genesis_hash, &key, &value, expiry)).await;
+        }
+    }
+
+    /// Returns the installed cache and this network's genesis hash.
+    ///
+    /// The hash is asked from algod once per client. If algod cannot be
+    /// reached, the hash it served last time is used so that cached data
+    /// stays available offline.
+    async fn cache_scope(&self) -> Option<(&'static ResponseCache, &str)> {
+        let cache = cache::global()?;
+        let genesis_hash = self
+            .genesis_hash
+            .get_or_try_init(|| async {
+                let algod_url = self.algod_url.clone();
+                match self.fetch_genesis_hash().await {
+                    Some(genesis_hash) => {
+                        let remembered = genesis_hash.clone();
+                        let _ = spawn_blocking(move || {
+                            cache.remember_genesis(&algod_url, &remembered);
+                        })
+                        .await;
+                        Ok(genesis_hash)
+                    }
+                    None => spawn_blocking(move || cache.remembered_genesis(&algod_url))
+                        .await
+                        .ok()
+                        .flatten()
+                        .ok_or(()),
+                }
+            })
+            .await
+            .ok()?;
+        Some((cache, genesis_hash.as_str()))
+    }
+
+    /// Fetches the network's genesis hash from algod.
+    async fn fetch_genesis_hash(&self) -> Option<String> {
+        let url = format!("{}/v2/transactions/params", self.algod_url);
+        let response = self
+            .build_algod_request(&url)
+            .timeout(Duration::from_secs(2))
+            .send()
+            .await
+            .ok()?
+            .error_for_status()
+            .ok()?;
+        let json: Value = response.json().await.ok()?;
+        json["genesis-hash"].as_str().map(String::from)
+    }
+
     /// Check the health status of the network's algod and indexer services.
     ///
+    /// An unreachable indexer is not an error: the client switches to
+    /// algod-only reads until the indexer answers again.
+    ///
+    /// # Returns
+    ///
+    /// Where chain history will be read from.
+    ///
     /// # Errors
     ///
-    /// Returns an error if algod or indexer (LocalNet only) is unreachable.
-    pub async fn get_network_status(&self) -> std::result::Result<(), String> {
+    /// Returns an error if algod is unreachable.
+    pub async fn get_network_status(&self) -> std::result::Result<DataSource, String> {
         let algod_url = format!("{}/health", self.algod_url);
-        let indexer_url = format!("{}/health", self.indexer_url);
+        let algod_request = self
+            .build_algod_request(&algod_url)
+            .timeout(Duration::from_secs(2))
+            .send();
+
+        if self.configured_algod_only {
+            algod_request.await.map_err(|e| {
+                format!(
+                    "Unable to connect to algod at {}. Error: {}",
+                    self.algod_url, e
+                )
+            })?;
+            return Ok(DataSource::AlgodOnly);
+        }
 
+        let indexer_url = format!("{}/health", self.indexer_url);
         let (algod_result, indexer_result) = tokio::join!(
-            self.build_algod_request(&algod_url)
-                .timeout(Duration::from_secs(2))
-                .send(),
+            algod_request,
             self.build_indexer_request(&indexer_url)
                 .timeout(Duration::from_secs(2))
                 .send()
@@ -203,13 +438,18 @@ impl AlgoClient {
             ));
         }
 
-        if self.is_localnet && indexer_result.is_err() {
-            return Err(format!(
-                "Unable to connect to indexer at {}. Algod is running but indexer is not available.",
-                self.indexer_url
-            ));
-        }
-
-        Ok(())
+        let source = match indexer_result {
+            Ok(response) if response.status().is_success() => DataSource::Indexer,
+            Ok(response) => {
+                tracing::debug!("Indexer health check returned {}", response.status());
+                DataSource::AlgodOnly
+            }
+            Err(e) => {
+                tracing::debug!("Indexer at {} unreachable: {e}", self.indexer_url);
+                DataSource::AlgodOnly
+            }
+        };
+        self.set_data_source(source);
+        Ok(source)
     }
 }
@@ -0,0 +1,141 @@
+//! Transaction pool methods for AlgoClient.
+//!
+//! algod only serves its pool as canonical msgpack here, decoded with the
+//! types in [`crate::domain::msgpack`].
+
+use color_eyre::Result;
+use tokio::task::JoinSet;
+
+use super::AlgoClient;
+use crate::constants::PENDING_POOL_LIMIT;
+use crate::domain::{AlgoError, PendingTransaction, PendingTransactions, Transaction};
+
+impl AlgoClient {
+    /// Fetch the transactions waiting in algod's pool.
+    ///
+    /// # Arguments
+    ///
+    /// * `max` - Maximum number of transactions to return (0 for all)
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the response cannot be decoded.
+    pub async fn get_pending_transactions(&self, max: u64) -> Result<PendingTransactions> {
+        let url = format!("{}/v2/transactions/pending?max={max}", self.algod_url);
+        self.fetch_pending_list(&url).await
+    }
+
+    /// Fetch the pool transactions sent by `address`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the response cannot be decoded.
+    pub async fn get_account_pending_transactions(
+        &self,
+        address: &str,
+        max: u64,
+    ) -> Result<PendingTransactions> {
+        let url = format!(
+            "{}/v2/accounts/{address}/transactions/pending?max={max}",
+            self.algod_url
+        );
+        self.fetch_pending_list(&url).await
+    }
+
+    /// Fetch the raw msgpack bytes algod holds for a pending or recently
+    /// confirmed transaction, exactly as the node encoded them.
+    ///
+    /// Returns `Ok(None)` if algod no longer knows the transaction.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    pub async fn get_raw_pending_transaction(&self, txid: &str) -> Result<Option<Vec<u8>>> {
+        let url = format!("{}/v2/transactions/pending/{txid}", self.algod_url);
+        self.fetch_algod_msgpack(&url).await
+    }
+
+    /// Fetch a pending or recently confirmed transaction by ID.
+    ///
+    /// Returns `Ok(None)` if algod no longer knows the transaction.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the response cannot be decoded.
+    pub async fn get_pending_transaction(&self, txid: &str) -> Result<Option<PendingTransaction>> {
+        let Some(bytes) = self.get_raw_pending_transaction(txid).await? else {
+            return Ok(None);
+        };
+        PendingTransaction::from_bytes(&bytes)
+            .map(Some)
+            .map_err(AlgoError::into_report)
+    }
+
+    /// Fetch the pool, or only the transactions sent by `address`, as domain
+    /// transactions in algod's priority order.
+    ///
+    /// Transactions that can't be converted are skipped.
+    ///
+    /// # Returns
+    ///
+    /// The first [`PENDING_POOL_LIMIT`] transactions and the total number in
+    /// the pool.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the response cannot be decoded.
+    pub async fn get_pool_transactions(
+        &self,
+        address: Option<&str>,
+    ) -> Result<(Vec<Transaction>, u64)> {
+        let pool = match address {
+            Some(address) => {
+                self.get_account_pending_transactions(address, PENDING_POOL_LIMIT)
+                    .await?
+            }
+            None => self.get_pending_transactions(PENDING_POOL_LIMIT).await?,
+        };
+        let transactions = pool
+            .top
+            .iter()
+            .filter_map(|stxn| {
+                stxn.to_transaction()
+                    .inspect_err(|e| tracing::debug!("Pending transaction decode error: {e}"))
+                    .ok()
+            })
+            .collect();
+        Ok((transactions, pool.total))
+    }
+
+    /// Look up transactions that left the pool and return the ones that
+    /// were confirmed, with their round.
+    ///
+    /// Transactions algod rejected or already forgot are left out.
+    pub async fn get_confirmed_pool_transactions(&self, txids: Vec<String>) -> Vec<Transaction> {
+        let mut join_set = JoinSet::new();
+        for txid in txids {
+            let client = self.clone();
+            join_set.spawn(async move {
+                let pending = client
+                    .get_pending_transaction(&txid)
+                    .await
+                    .inspect_err(|e| tracing::debug!("Pending lookup for {txid} failed: {e}"))
+                    .ok()??;
+                pending.confirmed_round?;
+                pending.to_transaction().ok()
+            });
+        }
+        join_set.join_all().await.into_iter().flatten().collect()
+    }
+
+    async fn fetch_pending_list(&self, url: &str) -> Result<PendingTransactions> {
+        let bytes = self.fetch_algod_msgpack(url).await?.unwrap_or_default();
+        if bytes.is_empty() {
+            return Ok(PendingTransactions {
+                top: Vec::new(),
+                total: 0,
+            });
+        }
+        PendingTransactions::from_bytes(&bytes).map_err(AlgoError::into_report)
+    }
+}
@@ -0,0 +1,63 @@
+//! Transaction simulation methods for AlgoClient.
+//!
+//! Requests and responses are canonical msgpack, so signed transactions are
+//! sent exactly as they were read from the file.
+
+use color_eyre::Result;
+
+use super::AlgoClient;
+use crate::domain::msgpack;
+use crate::domain::{AlgoError, SignedTxn, Simulation};
+
+impl AlgoClient {
+    /// Simulate a transaction group against the latest round.
+    ///
+    /// Transactions may be unsigned; algod evaluates them as if they were
+    /// signed by their sender, or by its auth address if rekeyed.
+    ///
+    /// # Errors
+    ///
+    /// Returns `AlgoError::InvalidInput` with algod's message if it refuses
+    /// to simulate the group, or an error if the request fails or the
+    /// response cannot be decoded.
+    pub async fn simulate_transactions(&self, txns: &[SignedTxn]) -> Result<Simulation> {
+        let url = format!("{}/v2/transactions/simulate?format=msgpack", self.algod_url);
+        let response = self
+            .build_algod_msgpack_post(&url, msgpack::simulate_request(txns))
+            .send()
+            .await?;
+
+        let status = response.status();
+        let bytes = response.bytes().await?;
+        if status.is_client_error() {
+            return Err(AlgoError::invalid_input(simulate_error_message(&bytes)).into_report());
+        }
+        if !status.is_success() {
+            return Err(AlgoError::parse(format!(
+                "Simulate request failed with status {status}: {}",
+                simulate_error_message(&bytes)
+            ))
+            .into_report());
+        }
+        Simulation::from_bytes(&bytes).map_err(AlgoError::into_report)
+    }
+}
+
+/// Extracts algod's `message` from an error body, which is msgpack or JSON
+/// depending on the node version.
+pub(crate) fn simulate_error_message(body: &[u8]) -> String {
+    let from_msgpack = msgpack::decode(body).ok().and_then(|value| {
+        value.as_map().and_then(|map| {
+            map.iter()
+                .find(|(k, _)| k.as_str() == Some("message"))
+                .and_then(|(_, v)| v.as_str().map(String::from))
+        })
+    });
+    from_msgpack
+        .or_else(|| {
+            serde_json::from_slice::<serde_json::Value>(body)
+                .ok()
+                .and_then(|json| json["message"].as_str().map(String::from))
+        })
+        .unwrap_or_else(|| String::from_utf8_lossy(body).trim().to_string())
+}
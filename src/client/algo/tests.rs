@@ -281,6 +281,106 @@ fn test_app_call_details_parsing() {
     }
 }
 
+/// Test that app call logs are parsed, including on inner transactions.
+#[test]
+fn test_app_call_logs_parsing() {
+    use crate::domain::DecodedLog;
+    use crate::domain::logs::LogValue;
+
+    let txn = Transaction::from_json(&JsonMother::app_call_with_logs()).unwrap();
+    let TransactionDetails::AppCall(details) = &txn.details else {
+        panic!("Expected AppCall details");
+    };
+    assert_eq!(details.logs, vec!["aGVsbG8=", "FR98dQAAAAAAAAAq"]);
+    assert_eq!(
+        DecodedLog::from_base64(&details.logs[1]),
+        DecodedLog::Return(LogValue::Uint64(42))
+    );
+
+    let TransactionDetails::AppCall(inner) = &txn.inner_transactions[0].details else {
+        panic!("Expected inner AppCall details");
+    };
+    assert_eq!(inner.logs, vec!["AAAAAAAAA+g="]);
+
+    let paths: Vec<(String, usize)> = txn
+        .logs_by_path()
+        .into_iter()
+        .map(|(path, details)| (path, details.logs.len()))
+        .collect();
+    assert_eq!(paths, vec![(String::new(), 2), ("1".to_string(), 1)]);
+}
+
+/// Test global and local state delta parsing.
+#[test]
+fn test_app_call_state_delta_parsing() {
+    use crate::domain::{StateDelta, StateDeltaAction};
+
+    let txn = Transaction::from_json(&JsonMother::app_call_with_state_delta()).unwrap();
+    let TransactionDetails::AppCall(details) = &txn.details else {
+        panic!("Expected AppCall details");
+    };
+
+    assert_eq!(
+        details.global_state_delta,
+        vec![
+            StateDelta {
+                key: "counter".to_string(),
+                action: StateDeltaAction::SetUint(7),
+            },
+            StateDelta {
+                key: "name".to_string(),
+                action: StateDeltaAction::SetBytes("lazylora".to_string()),
+            },
+            StateDelta {
+                key: "stale".to_string(),
+                action: StateDeltaAction::Delete,
+            },
+        ]
+    );
+    assert_eq!(details.local_state_delta.len(), 1);
+    assert_eq!(details.local_state_delta[0].address, "CALLER_ADDRESS");
+    assert_eq!(
+        details.local_state_delta[0].deltas[0].action.describe(),
+        "set uint 100"
+    );
+}
+
+/// Test signature parsing for single, multisig and logicsig transactions.
+#[test]
+fn test_signature_parsing() {
+    use crate::domain::TransactionSignature;
+    use crate::domain::signature::LogicSigDelegation;
+
+    let mut single_json = JsonMother::payment();
+    single_json["signature"] = serde_json::json!({"sig": "c2ln"});
+    let single = Transaction::from_json(&single_json).unwrap();
+    assert_eq!(single.signature, Some(TransactionSignature::Single));
+
+    let msig_txn = Transaction::from_json(&JsonMother::multisig_payment()).unwrap();
+    let msig = msig_txn
+        .signature
+        .as_ref()
+        .and_then(|s| s.multisig())
+        .unwrap();
+    assert_eq!(msig.threshold, 1);
+    assert_eq!(msig.signed_count(), 1);
+    assert_eq!(msig.subsignatures.len(), 2);
+
+    let lsig_txn = Transaction::from_json(&JsonMother::logicsig_payment()).unwrap();
+    match lsig_txn.signature {
+        Some(TransactionSignature::LogicSig(lsig)) => {
+            assert_eq!(lsig.logic, "CIEB");
+            assert_eq!(lsig.args, vec!["AQ=="]);
+            assert_eq!(lsig.delegation, LogicSigDelegation::ContractAccount);
+        }
+        other => panic!("Expected logicsig, got {other:?}"),
+    }
+
+    // No signature object (e.g. inner transactions)
+    let unsigned = Transaction::from_json(&JsonMother::payment()).unwrap();
+    assert_eq!(unsigned.signature, None);
+}
+
 /// Test on-complete variants using rstest parametrization.
 #[rstest]
 #[case::noop("noop", OnComplete::NoOp)]
@@ -367,3 +467,266 @@ fn test_empty_json_defaults() {
     assert_eq!(txn.fee, 0);
     assert_eq!(txn.details, TransactionDetails::None);
 }
+
+// ========================================================================
+// Pagination Tests
+// ========================================================================
+
+#[rstest]
+#[case::no_query(
+    "https://idx/v2/transactions",
+    None,
+    "https://idx/v2/transactions?limit=1000"
+)]
+#[case::with_query(
+    "https://idx/v2/transactions?round=5",
+    None,
+    "https://idx/v2/transactions?round=5&limit=1000"
+)]
+#[case::with_token(
+    "https://idx/v2/transactions?round=5",
+    Some("abc"),
+    "https://idx/v2/transactions?round=5&limit=1000&next=abc"
+)]
+fn test_paginated_url(#[case] url: &str, #[case] token: Option<&str>, #[case] expected: &str) {
+    assert_eq!(AlgoClient::paginated_url(url, 1000, token), expected);
+}
+
+#[test]
+fn test_parse_transactions_page_keeps_next_token() {
+    use super::transactions::parse_transactions_page;
+
+    let json = serde_json::json!({
+        "current-round": 100,
+        "next-token": "page-2",
+        "transactions": [JsonMother::payment(), JsonMother::asset_transfer()]
+    });
+    let page = parse_transactions_page(&json).unwrap();
+    assert_eq!(page.items.len(), 2);
+    assert_eq!(page.next_token.as_deref(), Some("page-2"));
+
+    let last = serde_json::json!({ "transactions": [JsonMother::payment()] });
+    let page = parse_transactions_page(&last).unwrap();
+    assert!(!page.has_more());
+}
+
+#[test]
+fn test_parse_boxes_page() {
+    use super::applications::parse_boxes_page;
+
+    let json = serde_json::json!({
+        "application-id": 42,
+        "boxes": [{ "name": "bmFtZQ==" }, { "name": "YgAB" }],
+        "next-token": "b64:YgAB"
+    });
+    let page = parse_boxes_page(&json);
+    assert_eq!(page.items.len(), 2);
+    assert_eq!(page.items[0].display_name(), "name");
+    assert!(page.items.iter().all(|b| b.value.is_none()));
+    assert_eq!(page.next_token.as_deref(), Some("b64:YgAB"));
+
+    let empty = parse_boxes_page(&serde_json::json!({ "boxes": [] }));
+    assert!(empty.items.is_empty());
+    assert!(!empty.has_more());
+}
+
+#[test]
+fn test_parse_account_list_pages() {
+    use super::accounts::{
+        parse_account_apps_page, parse_account_assets_page, parse_account_created_apps_page,
+        parse_account_created_assets_page,
+    };
+
+    let assets = parse_account_assets_page(&serde_json::json!({
+        "assets": [
+            { "asset-id": 31566704, "amount": 1500000, "is-frozen": false },
+            { "asset-id": 312769, "amount": 0, "is-frozen": true },
+            { "asset-id": 1, "amount": 0, "deleted": true }
+        ],
+        "next-token": "abc"
+    }));
+    assert_eq!(assets.items.len(), 2);
+    assert_eq!(assets.items[0].asset_id, 31566704);
+    assert_eq!(assets.items[0].amount, 1_500_000);
+    assert!(assets.items[1].is_frozen);
+    assert!(assets.items[0].name.is_empty());
+    assert_eq!(assets.next_token.as_deref(), Some("abc"));
+
+    let created = parse_account_created_assets_page(&serde_json::json!({
+        "assets": [{
+            "index": 42,
+            "params": { "name": "Token", "unit-name": "TKN", "decimals": 2, "total": 1000 }
+        }]
+    }));
+    assert_eq!(created.items[0].asset_id, 42);
+    assert_eq!(created.items[0].unit_name, "TKN");
+    assert_eq!(created.items[0].decimals, 2);
+    assert_eq!(created.items[0].total, 1000);
+    assert!(!created.has_more());
+
+    let apps = parse_account_apps_page(&serde_json::json!({
+        "apps-local-states": [
+            { "id": 7, "schema": { "num-uint": 2, "num-byte-slice": 1 } }
+        ]
+    }));
+    assert_eq!(apps.items[0].app_id, 7);
+    assert_eq!(apps.items[0].total_entries(), 3);
+
+    let created_apps = parse_account_created_apps_page(&serde_json::json!({
+        "applications": [{ "id": 9 }, { "id": 10, "deleted": true }]
+    }));
+    assert_eq!(created_apps.items.len(), 1);
+    assert_eq!(created_apps.items[0].app_id, 9);
+}
+
+#[test]
+fn test_parse_asset_holders_page() {
+    use super::assets::parse_asset_holders_page;
+
+    let json = serde_json::json!({
+        "balances": [
+            { "address": "HOLDER1", "amount": 500, "is-frozen": false, "opted-in-at-round": 10 },
+            { "address": "HOLDER2", "amount": 0, "is-frozen": true },
+            { "address": "GONE", "amount": 0, "deleted": true }
+        ],
+        "current-round": 100,
+        "next-token": "HOLDER2"
+    });
+    let page = parse_asset_holders_page(&json);
+    assert_eq!(page.items.len(), 2);
+    assert_eq!(page.items[0].amount, 500);
+    assert_eq!(page.items[0].opted_in_at_round, Some(10));
+    assert!(page.items[1].is_frozen);
+    assert_eq!(page.next_token.as_deref(), Some("HOLDER2"));
+
+    let empty = parse_asset_holders_page(&serde_json::json!({ "balances": [] }));
+    assert!(empty.items.is_empty());
+    assert!(!empty.has_more());
+}
+
+#[rstest]
+#[case::plain("bmFtZQ", "name=b64:bmFtZQ")]
+#[case::escaped("a+b/c==", "name=b64:a%2Bb%2Fc%3D%3D")]
+fn test_box_name_query(#[case] name: &str, #[case] expected: &str) {
+    assert_eq!(super::applications::box_name_query(name), expected);
+}
+
+#[test]
+fn test_parse_local_state_responses() {
+    use super::applications::{parse_algod_local_state, parse_indexer_local_state};
+
+    let key_value = serde_json::json!([
+        { "key": "Y291bnQ=", "value": { "type": 2, "uint": 7, "bytes": "" } },
+        { "key": "bmFtZQ==", "value": { "type": 1, "bytes": "YWxpY2U=", "uint": 0 } }
+    ]);
+
+    let algod = serde_json::json!({
+        "round": 100,
+        "app-local-state": { "id": 42, "key-value": key_value }
+    });
+    let state = parse_algod_local_state(&algod).unwrap();
+    assert_eq!(state.len(), 2);
+    assert_eq!(state[0].key, "count");
+    assert_eq!(state[0].value, "7");
+    assert_eq!(state[1].value_type, "Bytes");
+    assert_eq!(state[1].value, "alice");
+    // Not opted in: algod omits app-local-state
+    assert!(parse_algod_local_state(&serde_json::json!({ "round": 100 })).is_none());
+
+    let indexer = serde_json::json!({
+        "apps-local-states": [
+            { "id": 7, "deleted": true, "key-value": [] },
+            { "id": 42, "key-value": key_value }
+        ]
+    });
+    assert_eq!(parse_indexer_local_state(&indexer, 42).unwrap().len(), 2);
+    assert!(parse_indexer_local_state(&indexer, 7).is_none());
+    assert!(parse_indexer_local_state(&indexer, 99).is_none());
+}
+
+#[test]
+fn test_data_source_switching() {
+    use crate::domain::{CustomNetwork, DataSource, NetworkConfig};
+
+    let client = AlgoClient::new(Network::TestNet).unwrap();
+    assert_eq!(client.data_source(), DataSource::Indexer);
+    assert!(client.require_indexer("Asset holders").is_ok());
+
+    // Clones share the detected source
+    let clone = client.clone();
+    client.set_data_source(DataSource::AlgodOnly);
+    assert_eq!(clone.data_source(), DataSource::AlgodOnly);
+    let err = clone.require_indexer("Asset holders").unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Asset holders requires an indexer, which is unavailable in algod-only mode"
+    );
+    client.set_data_source(DataSource::Indexer);
+    assert_eq!(clone.data_source(), DataSource::Indexer);
+
+    // A configured algod-only network never switches to the indexer
+    let network = CustomNetwork::new("node", "", "http://localhost:8080").algod_only();
+    let client = AlgoClient::from_config(&NetworkConfig::Custom(network)).unwrap();
+    assert_eq!(client.data_source(), DataSource::AlgodOnly);
+    client.set_data_source(DataSource::Indexer);
+    assert_eq!(client.data_source(), DataSource::AlgodOnly);
+}
+
+#[tokio::test]
+async fn test_indexer_lists_name_the_unavailable_feature() {
+    use crate::domain::{DataSource, TxnQuery};
+
+    let client = AlgoClient::new(Network::TestNet).unwrap();
+    client.set_data_source(DataSource::AlgodOnly);
+
+    let err = client
+        .search_transactions_page(&TxnQuery::default(), None)
+        .await
+        .unwrap_err();
+    assert!(err.to_string().starts_with("Transaction search requires"));
+    let err = client
+        .get_account_transactions_page("ADDR", None, None)
+        .await
+        .unwrap_err();
+    assert!(err.to_string().starts_with("Transaction history requires"));
+}
+
+#[rstest]
+#[case::json(br#"{"message":"transaction group too large"}"#.to_vec())]
+#[case::msgpack(crate::domain::msgpack::encode(&rmpv::Value::Map(vec![(
+    rmpv::Value::from("message"),
+    rmpv::Value::from("transaction group too large"),
+)])))]
+#[case::text(b"transaction group too large\n".to_vec())]
+fn test_simulate_error_message(#[case] body: Vec<u8>) {
+    use super::simulate::simulate_error_message;
+
+    assert_eq!(simulate_error_message(&body), "transaction group too large");
+}
+
+#[tokio::test]
+async fn test_lookup_assets_reuses_resolved_assets() {
+    use crate::domain::AssetInfo;
+
+    let client = AlgoClient::new(Network::TestNet).unwrap();
+    let usdc = AssetInfo {
+        id: 10458941,
+        name: "USDC".to_string(),
+        unit_name: "USDC".to_string(),
+        creator: "CREATOR".to_string(),
+        total: 1_000_000,
+        decimals: 6,
+        url: String::new(),
+    };
+    client
+        .clone()
+        .asset_params
+        .lock()
+        .unwrap()
+        .insert(usdc.id, usdc.clone());
+
+    // Answered from memory without a request, repeated IDs included
+    let found = client.lookup_assets(&[usdc.id, usdc.id]).await;
+    assert_eq!(found.len(), 1);
+    assert_eq!(found.get(&usdc.id), Some(&usdc));
+}
@@ -4,15 +4,37 @@ use color_eyre::Result;
 use serde_json::Value;
 
 use super::AlgoClient;
-use crate::domain::{AlgoError, Transaction};
+use crate::client::cache::Expiry;
+use crate::constants::{
+    ALGOD_ONLY_SCAN_ROUNDS, FILTER_BACKFILL_ROUNDS, FILTER_FEED_LIMIT, FILTER_FIRST_WINDOW_ROUNDS,
+    INDEXER_PAGE_LIMIT, MAX_EAGER_PAGES,
+};
+use crate::domain::{AlgoError, Page, Transaction, TxnFilter, TxnGroup, TxnQuery};
 
 impl AlgoClient {
     /// Fetch a single transaction by ID
     ///
+    /// The indexer only serves confirmed transactions, which never change, so
+    /// found transactions are kept in the response cache indefinitely.
+    ///
+    /// Returns `Ok(None)` if the indexer doesn't know the transaction.
+    ///
     /// # Errors
     ///
-    /// Returns an error if the network request fails or JSON parsing fails.
+    /// Returns an error if the network request fails, the indexer answers
+    /// with an error status other than 404, or JSON parsing fails.
     pub async fn get_transaction_by_id(&self, txid: &str) -> Result<Option<Transaction>> {
+        if self.is_algod_only() {
+            return self.get_algod_transaction(txid).await;
+        }
+
+        let cache_key = format!("txn:{txid}");
+        if let Some(txn_json) = self.cache_get(&cache_key).await {
+            return Transaction::from_json(&txn_json)
+                .map(Some)
+                .map_err(AlgoError::into_report);
+        }
+
         let url = format!("{}/v2/transactions/{}", self.indexer_url, txid);
         let response = self
             .build_indexer_request(&url)
@@ -21,7 +43,20 @@ impl AlgoClient {
             .map_err(AlgoError::Network)?;
 
         if !response.status().is_success() {
-            return Ok(None);
+            let status = response.status();
+            if status.as_u16() == 404 {
+                return Ok(None);
+            }
+            let error_text = response
+                .text()
+                .await
+                .unwrap_or_else(|_| "Unknown error".to_string());
+            return Err(color_eyre::eyre::eyre!(
+                "Failed to fetch transaction {}: HTTP {} - {}",
+                txid,
+                status,
+                error_text
+            ));
         }
 
         let json: Value = response
@@ -34,9 +69,9 @@ impl AlgoClient {
             None => return Ok(None),
         };
 
-        Transaction::from_json(txn_json)
-            .map(Some)
-            .map_err(AlgoError::into_report)
+        let transaction = Transaction::from_json(txn_json).map_err(AlgoError::into_report)?;
+        self.cache_put(&cache_key, txn_json, Expiry::Never).await;
+        Ok(Some(transaction))
     }
 
     /// Fetch the latest transactions from the network
@@ -45,18 +80,20 @@ impl AlgoClient {
     ///
     /// Returns an error if the network request fails.
     pub async fn get_latest_transactions(&self, limit: usize) -> Result<Vec<Transaction>> {
-        let status_url = format!("{}/v2/status", self.algod_url);
-        let status_response = self.build_algod_request(&status_url).send().await?;
-
-        let status: Value = status_response.json().await?;
-
-        let latest_round = status["last-round"].as_u64().ok_or_else(|| {
-            AlgoError::parse("algod status response missing 'last-round'").into_report()
-        })?;
+        let latest_round = self.latest_round().await?;
         if latest_round == 0 {
             return Ok(Vec::new());
         }
 
+        if self.is_algod_only() {
+            let first = latest_round
+                .saturating_sub(ALGOD_ONLY_SCAN_ROUNDS - 1)
+                .max(1);
+            let mut transactions = self.get_rounds_transactions(first, latest_round).await?;
+            transactions.truncate(limit);
+            return Ok(transactions);
+        }
+
         let min_round = latest_round.saturating_sub(20);
         let url = format!(
             "{}/v2/transactions?limit={}&min-round={}&max-round={}&order=desc",
@@ -72,6 +109,180 @@ impl AlgoClient {
         Ok(transactions)
     }
 
+    /// Fetch transactions matching a live feed filter.
+    ///
+    /// The filter is pushed down into indexer query parameters and applied
+    /// again client-side for the terms the indexer can't express. Up to the
+    /// last [`FILTER_BACKFILL_ROUNDS`] rounds are searched so matches older
+    /// than the unfiltered 20-round window still show up.
+    ///
+    /// The indexer lists a round range oldest first, so windows are searched
+    /// from the latest round backwards, each twice as long as the one before,
+    /// until [`FILTER_FEED_LIMIT`] matches are found.
+    ///
+    /// Returns the matches, newest first.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the network request fails.
+    pub async fn get_filtered_transactions(&self, filter: &TxnFilter) -> Result<Vec<Transaction>> {
+        if self.is_algod_only() {
+            return self.get_algod_filtered_transactions(filter).await;
+        }
+
+        let params: String = filter
+            .query_params()
+            .into_iter()
+            // Encoded like the advanced search, in case a value holds `+`, `/` or `=`
+            .map(|(key, value)| format!("&{key}={}", Self::encode_base64_param(&value)))
+            .collect();
+        let latest_round = self.latest_round().await?;
+        let oldest_round = latest_round
+            .saturating_sub(FILTER_BACKFILL_ROUNDS - 1)
+            .max(1);
+
+        let mut transactions = Vec::new();
+        let mut max_round = latest_round;
+        let mut window = FILTER_FIRST_WINDOW_ROUNDS;
+        while max_round >= oldest_round && transactions.len() < FILTER_FEED_LIMIT {
+            let min_round = max_round.saturating_sub(window - 1).max(oldest_round);
+            let url = format!(
+                "{}/v2/transactions?min-round={min_round}&max-round={max_round}{params}",
+                self.indexer_url
+            );
+            let mut next_token = None;
+            for _ in 0..MAX_EAGER_PAGES {
+                let page = self
+                    .fetch_transactions_page(&url, next_token.as_deref(), "Transaction filters")
+                    .await?;
+                // The indexer may hand out a token with an empty final page
+                let done = page.items.is_empty() || !page.has_more();
+                transactions.extend(page.items.into_iter().filter(|txn| filter.matches(txn)));
+                if done {
+                    break;
+                }
+                next_token = page.next_token;
+            }
+            max_round = min_round - 1;
+            window *= 2;
+        }
+
+        transactions.sort_by(|a, b| b.block.cmp(&a.block).then_with(|| b.id.cmp(&a.id)));
+        transactions.truncate(FILTER_FEED_LIMIT);
+        Ok(transactions)
+    }
+
+    /// Fetch the members of an atomic transaction group.
+    ///
+    /// Groups are confirmed within a single round, so the search is limited
+    /// to `round`. Members are returned in group order.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the group has no members.
+    pub async fn get_group_transactions(&self, group_id: &str, round: u64) -> Result<TxnGroup> {
+        if self.is_algod_only() {
+            let transactions: Vec<Transaction> = self
+                .get_rounds_transactions(round, round)
+                .await?
+                .into_iter()
+                .rev()
+                .filter(|txn| txn.group.as_deref() == Some(group_id))
+                .collect();
+            if transactions.is_empty() {
+                return Err(AlgoError::not_found("transaction group", group_id).into_report());
+            }
+            return Ok(TxnGroup::new(group_id.to_string(), round, transactions));
+        }
+
+        let url = format!(
+            "{}/v2/transactions?group-id={}&round={round}",
+            self.indexer_url,
+            Self::encode_base64_param(group_id)
+        );
+        let transactions = self
+            .fetch_transactions_from_url(&url, "Transaction groups")
+            .await?;
+        if transactions.is_empty() {
+            return Err(AlgoError::not_found("transaction group", group_id).into_report());
+        }
+        Ok(TxnGroup::new(group_id.to_string(), round, transactions))
+    }
+
+    /// Fetch one page of an advanced transaction search.
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - Search criteria, all evaluated by the indexer
+    /// * `next_token` - Token from the previous page, or `None` for the first page
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the indexer request fails.
+    pub async fn search_transactions_page(
+        &self,
+        query: &TxnQuery,
+        next_token: Option<&str>,
+    ) -> Result<Page<Transaction>> {
+        let params: Vec<String> = query
+            .query_params()
+            .into_iter()
+            // Note prefixes (Base64) and time zone offsets may contain `+`, `/` or `=`
+            .map(|(key, value)| format!("{key}={}", Self::encode_base64_param(&value)))
+            .collect();
+        let url = format!("{}/v2/transactions?{}", self.indexer_url, params.join("&"));
+        self.fetch_transactions_page(&url, next_token, "Transaction search")
+            .await
+    }
+
+    /// Algod-only counterpart of [`Self::get_filtered_transactions`].
+    ///
+    /// The filter is applied to transactions decoded from block bodies,
+    /// scanning the last [`ALGOD_ONLY_SCAN_ROUNDS`] rounds.
+    async fn get_algod_filtered_transactions(
+        &self,
+        filter: &TxnFilter,
+    ) -> Result<Vec<Transaction>> {
+        let latest_round = self.latest_round().await?;
+        let first = latest_round
+            .saturating_sub(ALGOD_ONLY_SCAN_ROUNDS - 1)
+            .max(1);
+        if first > latest_round {
+            return Ok(Vec::new());
+        }
+
+        Ok(self
+            .get_rounds_transactions(first, latest_round)
+            .await?
+            .into_iter()
+            .filter(|txn| filter.matches(txn))
+            .collect())
+    }
+
+    /// Look a transaction up in algod, which knows pending and recently
+    /// confirmed transactions only.
+    async fn get_algod_transaction(&self, txid: &str) -> Result<Option<Transaction>> {
+        let Some(pending) = self.get_pending_transaction(txid).await? else {
+            return Ok(None);
+        };
+        pending
+            .to_transaction()
+            .map(Some)
+            .map_err(AlgoError::into_report)
+    }
+
+    /// Fetch the latest round from algod.
+    async fn latest_round(&self) -> Result<u64> {
+        let status_url = format!("{}/v2/status", self.algod_url);
+        let status_response = self.build_algod_request(&status_url).send().await?;
+
+        let status: Value = status_response.json().await?;
+
+        status["last-round"].as_u64().ok_or_else(|| {
+            AlgoError::parse("algod status response missing 'last-round'").into_report()
+        })
+    }
+
     /// Search for a transaction by ID.
     pub(crate) async fn search_transaction(&self, txid: &str) -> Result<Vec<Transaction>> {
         if txid.is_empty() {
@@ -85,6 +296,13 @@ impl AlgoClient {
             .into_report());
         }
 
+        if self.is_algod_only() {
+            return match self.get_algod_transaction(txid).await? {
+                Some(transaction) => Ok(vec![transaction]),
+                None => Err(AlgoError::not_found("transaction", txid).into_report()),
+            };
+        }
+
         let url = format!("{}/v2/transactions/{}", self.indexer_url, txid);
 
         let response = self.build_indexer_request(&url).send().await;
@@ -110,12 +328,11 @@ impl AlgoClient {
             }
         }
 
-        let search_url = format!(
-            "{}/v2/transactions?txid={}&limit=10",
-            self.indexer_url, txid
-        );
+        let search_url = format!("{}/v2/transactions?txid={}", self.indexer_url, txid);
 
-        let search_results = self.fetch_transactions_from_url(&search_url).await?;
+        let search_results = self
+            .fetch_transactions_from_url(&search_url, "Transaction lookup")
+            .await?;
 
         if search_results.is_empty() {
             return Err(AlgoError::not_found("transaction", txid).into_report());
@@ -124,18 +341,101 @@ impl AlgoClient {
         Ok(search_results)
     }
 
-    pub(crate) async fn fetch_transactions_from_url(&self, url: &str) -> Result<Vec<Transaction>> {
-        let response = match self.build_indexer_request(url).send().await {
-            Ok(resp) if resp.status().is_success() => resp,
-            _ => return Ok(Vec::new()),
-        };
+    /// Fetch transactions from an indexer list URL, following `next-token`.
+    ///
+    /// Pages are fetched eagerly up to `MAX_EAGER_PAGES`; request failures
+    /// end the list early rather than failing the whole fetch. `feature`
+    /// names the list in the error returned without an indexer.
+    pub(crate) async fn fetch_transactions_from_url(
+        &self,
+        url: &str,
+        feature: &'static str,
+    ) -> Result<Vec<Transaction>> {
+        self.require_indexer(feature)?;
+        let mut transactions = Vec::new();
+        let mut next_token: Option<String> = None;
 
-        let json: Value = match response.json().await {
-            Ok(data) => data,
-            Err(_) => return Ok(Vec::new()),
-        };
+        for _ in 0..MAX_EAGER_PAGES {
+            let page = match self
+                .fetch_transactions_page(url, next_token.as_deref(), feature)
+                .await
+            {
+                Ok(page) => page,
+                Err(e) => {
+                    tracing::debug!("Transaction page fetch failed: {e}");
+                    break;
+                }
+            };
+            let has_more = page.has_more();
+            transactions.extend(page.items);
+            next_token = page.next_token;
+            if !has_more {
+                break;
+            }
+        }
+
+        Ok(transactions)
+    }
+
+    /// Fetch a single page of transactions from an indexer list URL.
+    ///
+    /// # Arguments
+    ///
+    /// * `url` - Indexer list endpoint URL without `limit`/`next` parameters
+    /// * `next_token` - Token from the previous page, or `None` for the first page
+    /// * `feature` - Name of the list, for the error returned without an indexer
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails, the indexer responds with a
+    /// non-success status, or the response is not valid JSON.
+    pub(crate) async fn fetch_transactions_page(
+        &self,
+        url: &str,
+        next_token: Option<&str>,
+        feature: &'static str,
+    ) -> Result<Page<Transaction>> {
+        let json = self
+            .fetch_transactions_page_json(url, next_token, feature)
+            .await?;
+        parse_transactions_page(&json)
+    }
 
-        parse_transactions_array(&json)
+    /// Fetch the raw JSON of a single page from an indexer transaction list URL.
+    ///
+    /// `feature` names the list in the error returned without an indexer.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails, the indexer responds with a
+    /// non-success status, or the response is not valid JSON.
+    pub(crate) async fn fetch_transactions_page_json(
+        &self,
+        url: &str,
+        next_token: Option<&str>,
+        feature: &'static str,
+    ) -> Result<Value> {
+        self.require_indexer(feature)?;
+        let page_url = Self::paginated_url(url, INDEXER_PAGE_LIMIT, next_token);
+        let response = self.build_indexer_request(&page_url).send().await?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let error_text = response
+                .text()
+                .await
+                .unwrap_or_else(|_| "Unknown error".to_string());
+            return Err(color_eyre::eyre::eyre!(
+                "Indexer request failed with status {}: {}",
+                status,
+                error_text
+            ));
+        }
+
+        response
+            .json()
+            .await
+            .map_err(|_| AlgoError::parse("Failed to parse transactions JSON").into_report())
     }
 }
 
@@ -164,3 +464,13 @@ pub(crate) fn parse_transactions_array(json: &Value) -> Result<Vec<Transaction>>
 
     Ok(transactions)
 }
+
+/// Parse a page of transactions from JSON response
+///
+/// Like `parse_transactions_array()`, but also keeps the indexer's
+/// `next-token` so the caller can continue the list.
+pub(crate) fn parse_transactions_page(json: &Value) -> Result<Page<Transaction>> {
+    let transactions = parse_transactions_array(json)?;
+    let next_token = json["next-token"].as_str().map(String::from);
+    Ok(Page::new(transactions, next_token))
+}
@@ -0,0 +1,88 @@
+//! Watchlist polling methods for AlgoClient.
+
+use color_eyre::Result;
+
+use tokio::task::JoinSet;
+
+use super::AlgoClient;
+use crate::domain::{WatchStatus, WatchTarget};
+
+impl AlgoClient {
+    /// Fetch the current state of every watchlist entry concurrently.
+    ///
+    /// Balances and activity bypass the response cache so each poll sees
+    /// the latest round. Failed lookups are reported in
+    /// [`WatchStatus::error`] instead of failing the whole batch.
+    pub async fn get_watch_statuses(&self, targets: &[WatchTarget]) -> Vec<WatchStatus> {
+        let mut tasks = JoinSet::new();
+        for (index, target) in targets.iter().cloned().enumerate() {
+            let client = self.clone();
+            tasks.spawn(async move { (index, client.get_watch_status(target).await) });
+        }
+
+        let mut statuses: Vec<(usize, WatchStatus)> = Vec::with_capacity(targets.len());
+        while let Some(joined) = tasks.join_next().await {
+            if let Ok(status) = joined {
+                statuses.push(status);
+            }
+        }
+        statuses.sort_by_key(|(index, _)| *index);
+        statuses.into_iter().map(|(_, status)| status).collect()
+    }
+
+    /// Fetch the state of a single watchlist entry.
+    async fn get_watch_status(&self, target: WatchTarget) -> WatchStatus {
+        let mut status = WatchStatus::new(target.clone());
+        let result = match &target {
+            WatchTarget::Account(address) => self.fill_account_status(&mut status, address).await,
+            WatchTarget::App(app_id) => {
+                let address = Self::compute_application_address(*app_id);
+                self.fill_account_status(&mut status, &address).await
+            }
+            WatchTarget::Asset(asset_id) => match self.search_asset(&asset_id.to_string()).await {
+                Ok(Some(info)) => {
+                    status.asset = Some(info);
+                    Ok(())
+                }
+                Ok(None) => Err(color_eyre::eyre::eyre!("Asset not found")),
+                Err(e) => Err(e),
+            },
+        };
+        if let Err(e) = result {
+            status.error = Some(e.to_string());
+        }
+        status
+    }
+
+    /// Fill in the balance and last activity round of an account.
+    async fn fill_account_status(&self, status: &mut WatchStatus, address: &str) -> Result<()> {
+        let account = self.fetch_account_json(address).await?;
+        status.balance = account["amount"].as_u64();
+        // Activity comes from the indexer; an unreachable indexer only
+        // leaves the round blank.
+        status.last_round = self.last_activity_round(address).await.ok().flatten();
+        Ok(())
+    }
+
+    /// Round of the most recent transaction involving an address.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the indexer request fails.
+    async fn last_activity_round(&self, address: &str) -> Result<Option<u64>> {
+        self.require_indexer("Account activity")?;
+        let url = format!(
+            "{}/v2/accounts/{}/transactions?limit=1",
+            self.indexer_url, address
+        );
+        let response = self.build_indexer_request(&url).send().await?;
+        if !response.status().is_success() {
+            return Err(color_eyre::eyre::eyre!(
+                "Indexer request failed with status {}",
+                response.status()
+            ));
+        }
+        let json: serde_json::Value = response.json().await?;
+        Ok(json["transactions"][0]["confirmed-round"].as_u64())
+    }
+}
@@ -0,0 +1,421 @@
+//! Disk-backed cache for API responses.
+//!
+//! Confirmed transactions, historical blocks and deleted entities never
+//! change, so their responses are kept on disk and reused across sessions.
+//! Mutable entities (accounts, app state, live assets) are stored with a
+//! short time-to-live instead.
+//!
+//! # Layout
+//!
+//! Entries live under the user's cache directory (`~/.cache/lazylora` on
+//! Linux), in one subdirectory per network genesis hash so that a LocalNet
+//! reset or a custom network never serves another chain's data:
+//!
+//! ```text
+//! lazylora/
+//! ├── endpoints/<algod url hash>   genesis hash last seen at that algod
+//! └── <genesis hash>/<key hash>.json
+//! ```
+//!
+//! The total size is capped; when a write pushes it over the cap, the least
+//! recently used entries are removed.
+
+use std::fs::{self, File};
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+
+use crate::constants::CACHE_MAX_BYTES;
+use crate::domain::abi;
+
+/// Application name used for the cache directory.
+const APP_NAME: &str = "lazylora";
+
+/// Directory remembering the genesis hash of each algod endpoint.
+const ENDPOINTS_DIR: &str = "endpoints";
+
+/// Fraction of the size cap kept after pruning, so that pruning does not
+/// run again on the very next write.
+const PRUNE_TARGET_PERCENT: u64 = 75;
+
+static CACHE: OnceLock<ResponseCache> = OnceLock::new();
+
+/// Installs the process-wide cache used by [`AlgoClient`](super::AlgoClient).
+///
+/// Only the first call has an effect; without it, responses are not cached.
+pub fn install(cache: ResponseCache) {
+    let _ = CACHE.set(cache);
+}
+
+/// Returns the installed cache, if any.
+#[must_use]
+pub fn global() -> Option<&'static ResponseCache> {
+    CACHE.get()
+}
+
+// ============================================================================
+// Expiry
+// ============================================================================
+
+/// How long a cached response stays valid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Expiry {
+    /// Immutable data: confirmed transactions, blocks, deleted entities.
+    Never,
+    /// Mutable data, valid for the given duration after it was stored.
+    After(Duration),
+}
+
+/// A cached response as stored on disk.
+#[derive(Debug, Serialize, Deserialize)]
+struct CacheEntry {
+    /// Unix time (seconds) after which the entry is stale, if it expires.
+    expires_at: Option<u64>,
+    /// The cached JSON value.
+    value: Value,
+}
+
+// ============================================================================
+// ResponseCache
+// ============================================================================
+
+/// A size-capped directory of cached JSON responses.
+#[derive(Debug)]
+pub struct ResponseCache {
+    /// Root cache directory.
+    root: PathBuf,
+    /// Maximum total size of cached entries, in bytes.
+    max_bytes: u64,
+    /// Approximate current size, updated on writes and recomputed on prune.
+    size: AtomicU64,
+}
+
+impl ResponseCache {
+    /// Opens a cache rooted at `root`, creating the directory if needed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the directory cannot be created.
+    pub fn open(root: PathBuf, max_bytes: u64) -> io::Result<Self> {
+        fs::create_dir_all(&root)?;
+        let size = entry_files(&root).iter().map(|entry| entry.size).sum();
+        Ok(Self {
+            root,
+            max_bytes,
+            size: AtomicU64::new(size),
+        })
+    }
+
+    /// Opens the cache in the user's cache directory with the default size cap.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the cache directory cannot be determined or created.
+    pub fn open_default() -> io::Result<Self> {
+        Self::open(Self::default_dir()?, CACHE_MAX_BYTES)
+    }
+
+    /// Returns the default cache directory (`<user cache dir>/lazylora`).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the user's cache directory cannot be determined.
+    pub fn default_dir() -> io::Result<PathBuf> {
+        let mut path = dirs::cache_dir().ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::NotFound,
+                "Could not determine cache directory. Expected XDG_CACHE_HOME or ~/.cache on Linux, ~/Library/Caches on macOS, %LOCALAPPDATA% on Windows",
+            )
+        })?;
+        path.push(APP_NAME);
+        Ok(path)
+    }
+
+    /// Returns the cache's root directory.
+    #[must_use]
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// Returns the cached value for `key` on the network `genesis_hash`,
+    /// unless it is missing or expired.
+    #[must_use]
+    pub fn get(&self, genesis_hash: &str, key: &str) -> Option<Value> {
+        let path = self.entry_path(genesis_hash, key);
+        let entry: CacheEntry = serde_json::from_slice(&fs::read(&path).ok()?).ok()?;
+        if entry
+            .expires_at
+            .is_some_and(|expires_at| expires_at <= unix_now())
+        {
+            return None;
+        }
+        // Touch the file so pruning keeps recently used entries
+        let _ = File::options()
+            .append(true)
+            .open(&path)
+            .and_then(|file| file.set_modified(SystemTime::now()));
+        Some(entry.value)
+    }
+
+    /// Stores `value` for `key` on the network `genesis_hash`.
+    ///
+    /// Write failures are logged and otherwise ignored; the cache is only an
+    /// optimization.
+    pub fn put(&self, genesis_hash: &str, key: &str, value: &Value, expiry: Expiry) {
+        let entry = CacheEntry {
+            expires_at: match expiry {
+                Expiry::Never => None,
+                Expiry::After(ttl) => Some(unix_now() + ttl.as_secs()),
+            },
+            value: value.clone(),
+        };
+        let path = self.entry_path(genesis_hash, key);
+        let replaced = fs::metadata(&path).map_or(0, |metadata| metadata.len());
+        let result = serde_json::to_vec(&entry)
+            .map_err(io::Error::other)
+            .and_then(|bytes| write_file(&path, &bytes).map(|()| bytes.len() as u64));
+        match result {
+            Ok(written) => {
+                // An overwritten entry no longer counts towards the size
+                let resize = |size: u64| (size + written).saturating_sub(replaced);
+                let size = resize(
+                    self.size
+                        .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |size| {
+                            Some(resize(size))
+                        })
+                        .unwrap_or_else(|size| size),
+                );
+                if size > self.max_bytes {
+                    self.prune();
+                }
+            }
+            Err(e) => tracing::debug!("Cache write for {key} failed: {e}"),
+        }
+    }
+
+    /// Returns the genesis hash last seen at `algod_url`, if any.
+    #[must_use]
+    pub fn remembered_genesis(&self, algod_url: &str) -> Option<String> {
+        let genesis = fs::read_to_string(self.endpoint_path(algod_url)).ok()?;
+        let genesis = genesis.trim();
+        (!genesis.is_empty()).then(|| genesis.to_string())
+    }
+
+    /// Records the genesis hash served by `algod_url`, so cached data stays
+    /// reachable when the node cannot be asked.
+    pub fn remember_genesis(&self, algod_url: &str, genesis_hash: &str) {
+        if self.remembered_genesis(algod_url).as_deref() == Some(genesis_hash) {
+            return;
+        }
+        if let Err(e) = write_file(&self.endpoint_path(algod_url), genesis_hash.as_bytes()) {
+            tracing::debug!("Failed to record genesis for {algod_url}: {e}");
+        }
+    }
+
+    /// Removes every cached entry.
+    ///
+    /// # Returns
+    ///
+    /// The number of bytes freed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the cache directory cannot be removed.
+    pub fn clear(&self) -> io::Result<u64> {
+        let freed = entry_files(&self.root).iter().map(|entry| entry.size).sum();
+        match fs::remove_dir_all(&self.root) {
+            Ok(()) => {}
+            Err(e) if e.kind() == io::ErrorKind::NotFound => {}
+            Err(e) => return Err(e),
+        }
+        self.size.store(0, Ordering::Relaxed);
+        Ok(freed)
+    }
+
+    /// Removes least recently used entries until the cache is well under its cap.
+    fn prune(&self) {
+        let mut entries = entry_files(&self.root);
+        entries.sort_by_key(|entry| entry.modified);
+
+        let target = self.max_bytes / 100 * PRUNE_TARGET_PERCENT;
+        let mut size: u64 = entries.iter().map(|entry| entry.size).sum();
+        for entry in entries {
+            if size <= target {
+                break;
+            }
+            if fs::remove_file(&entry.path).is_ok() {
+                size -= entry.size;
+            }
+        }
+        self.size.store(size, Ordering::Relaxed);
+    }
+
+    fn entry_path(&self, genesis_hash: &str, key: &str) -> PathBuf {
+        self.root
+            .join(short_hash(genesis_hash))
+            .join(format!("{}.json", short_hash(key)))
+    }
+
+    fn endpoint_path(&self, algod_url: &str) -> PathBuf {
+        self.root
+            .join(ENDPOINTS_DIR)
+            .join(short_hash(algod_url.trim_end_matches('/')))
+    }
+}
+
+// ============================================================================
+// Helpers
+// ============================================================================
+
+/// A cached entry file found on disk.
+struct EntryFile {
+    path: PathBuf,
+    size: u64,
+    modified: SystemTime,
+}
+
+/// Lists the entry files in every network directory under `root`.
+fn entry_files(root: &Path) -> Vec<EntryFile> {
+    let Ok(networks) = fs::read_dir(root) else {
+        return Vec::new();
+    };
+    networks
+        .flatten()
+        .filter(|dir| dir.file_name() != ENDPOINTS_DIR)
+        .filter_map(|dir| fs::read_dir(dir.path()).ok())
+        .flatten()
+        .flatten()
+        .filter_map(|file| {
+            let metadata = file.metadata().ok()?;
+            metadata.is_file().then(|| EntryFile {
+                path: file.path(),
+                size: metadata.len(),
+                modified: metadata.modified().unwrap_or(UNIX_EPOCH),
+            })
+        })
+        .collect()
+}
+
+/// Writes `bytes` to `path` through a temporary file, creating parent directories.
+fn write_file(path: &Path, bytes: &[u8]) -> io::Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let tmp = path.with_extension("tmp");
+    fs::write(&tmp, bytes)?;
+    fs::rename(&tmp, path)
+}
+
+/// Hex prefix of the SHA-256 of `input`, safe to use as a file name.
+fn short_hash(input: &str) -> String {
+    abi::hex(&Sha256::digest(input.as_bytes())[..16])
+}
+
+fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_secs())
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    const GENESIS: &str = "wGHE2Pwdvd7S12BL5FaOP20EGYesN73ktiC1qzkkit8=";
+
+    fn temp_cache(name: &str, max_bytes: u64) -> ResponseCache {
+        let root =
+            std::env::temp_dir().join(format!("lazylora-cache-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        ResponseCache::open(root, max_bytes).unwrap()
+    }
+
+    #[test]
+    fn test_put_get_and_expiry() {
+        let cache = temp_cache("expiry", CACHE_MAX_BYTES);
+        let value = json!({"id": "TXID"});
+
+        cache.put(GENESIS, "txn:TXID", &value, Expiry::Never);
+        cache.put(
+            GENESIS,
+            "account:ADDR",
+            &value,
+            Expiry::After(Duration::ZERO),
+        );
+
+        assert_eq!(cache.get(GENESIS, "txn:TXID"), Some(value));
+        // Zero TTL is stale immediately
+        assert_eq!(cache.get(GENESIS, "account:ADDR"), None);
+        // Entries are scoped to their network
+        assert_eq!(cache.get("other-genesis", "txn:TXID"), None);
+
+        cache.clear().unwrap();
+    }
+
+    #[test]
+    fn test_overwrite_does_not_grow_size() {
+        let cache = temp_cache("overwrite", CACHE_MAX_BYTES);
+
+        cache.put(
+            GENESIS,
+            "account:ADDR",
+            &json!({"amount": 1}),
+            Expiry::Never,
+        );
+        cache.put(
+            GENESIS,
+            "account:ADDR",
+            &json!({"amount": 2}),
+            Expiry::Never,
+        );
+
+        let size: u64 = entry_files(cache.root()).iter().map(|e| e.size).sum();
+        assert_eq!(cache.size.load(Ordering::Relaxed), size);
+
+        cache.clear().unwrap();
+    }
+
+    #[test]
+    fn test_genesis_is_remembered_per_endpoint() {
+        let cache = temp_cache("endpoints", CACHE_MAX_BYTES);
+
+        assert_eq!(cache.remembered_genesis("http://localhost:4001"), None);
+        cache.remember_genesis("http://localhost:4001/", GENESIS);
+        assert_eq!(
+            cache.remembered_genesis("http://localhost:4001").as_deref(),
+            Some(GENESIS)
+        );
+
+        cache.clear().unwrap();
+    }
+
+    #[test]
+    fn test_prune_keeps_size_under_cap() {
+        let cache = temp_cache("prune", 1_000);
+        let value = json!({"payload": "x".repeat(200)});
+
+        for i in 0..10 {
+            cache.put(GENESIS, &format!("block:{i}"), &value, Expiry::Never);
+        }
+
+        let size: u64 = entry_files(cache.root()).iter().map(|e| e.size).sum();
+        assert!(size <= 1_000, "cache grew to {size} bytes");
+        // The newest entry survives pruning
+        assert!(cache.get(GENESIS, "block:9").is_some());
+
+        let freed = cache.clear().unwrap();
+        assert_eq!(freed, size);
+        assert!(!cache.root().exists());
+    }
+}
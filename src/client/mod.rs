@@ -1,5 +1,6 @@
 //! HTTP client for Algorand network APIs.
 
 pub mod algo;
+pub mod cache;
 
 pub use algo::AlgoClient;
@@ -4,13 +4,15 @@
 //! making it easy to:
 //! - Test key mappings in isolation
 //! - Add new keybindings
-//! - Support future keybinding customization
+//! - Customize keybindings from the configuration file
+//!
+//! Key events are translated to commands by the active [`Keymap`](crate::keymap::Keymap).
 //!
 //! # Example
 //!
 //! ```ignore
 //! let context = app.get_input_context();
-//! let command = map_key(key_event, &context);
+//! let command = app.keymap.map_key(key_event, &context);
 //!
 //! match command {
 //!     AppCommand::ConfirmQuit => app.exit = true,
@@ -19,8 +21,6 @@
 //! }
 //! ```
 
-use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
-
 // ============================================================================
 // Input Context
 // ============================================================================
@@ -29,7 +29,7 @@ use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 ///
 /// The input context determines which keybindings are active and how
 /// key events should be interpreted.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum InputContext {
     /// Normal browsing mode - viewing blocks and transactions lists.
     Main,
@@ -41,8 +41,18 @@ pub enum InputContext {
     BlockDetailView,
     /// Viewing account details overlay with tabs (Info / Assets / Apps).
     AccountDetailView,
-    /// Viewing application details overlay with tabs (Info / State / Programs).
+    /// Viewing application details overlay with tabs (Info / State / Boxes / Programs).
     AppDetailView,
+    /// Viewing asset details overlay with tabs (Info / Holders).
+    AssetDetailView,
+    /// Viewing atomic group details overlay with tabs (Members / Graph).
+    GroupDetailView,
+    /// Viewing the advanced transaction search results.
+    TxnSearchView,
+    /// Viewing the pending transaction pool.
+    PendingPoolView,
+    /// Viewing the outcome of a transaction group simulation with tabs (Result / Graph).
+    SimulationView,
     /// Network selection popup is open.
     NetworkSelect,
     /// Search popup with text input is open.
@@ -59,6 +69,102 @@ pub enum InputContext {
     ConfirmQuit,
     /// Adding or editing a custom network.
     NetworkForm,
+    /// Entering an account address to look up its local state in an application.
+    LocalStateLookup,
+    /// Editing the live transaction feed filter.
+    TxnFilterInput,
+    /// Filling in the advanced transaction search form.
+    AdvancedSearchForm,
+    /// Entering the output path of a list export.
+    ExportPrompt,
+    /// Entering the transaction file or base64 group to simulate.
+    SimulateInput,
+}
+
+impl InputContext {
+    /// All input contexts.
+    pub const ALL: &[Self] = &[
+        Self::Main,
+        Self::DetailView,
+        Self::TxnDetailViewTable,
+        Self::BlockDetailView,
+        Self::AccountDetailView,
+        Self::AppDetailView,
+        Self::AssetDetailView,
+        Self::GroupDetailView,
+        Self::TxnSearchView,
+        Self::PendingPoolView,
+        Self::SimulationView,
+        Self::NetworkSelect,
+        Self::SearchInput,
+        Self::InlineSearch,
+        Self::SearchResults,
+        Self::MessagePopup,
+        Self::HelpPopup,
+        Self::ConfirmQuit,
+        Self::NetworkForm,
+        Self::LocalStateLookup,
+        Self::TxnFilterInput,
+        Self::AdvancedSearchForm,
+        Self::ExportPrompt,
+        Self::SimulateInput,
+    ];
+
+    /// Returns the name used for this context in the `keybindings` config.
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Main => "main",
+            Self::DetailView => "txn_graph",
+            Self::TxnDetailViewTable => "txn_table",
+            Self::BlockDetailView => "block_details",
+            Self::AccountDetailView => "account_details",
+            Self::AppDetailView => "app_details",
+            Self::AssetDetailView => "asset_details",
+            Self::GroupDetailView => "group_details",
+            Self::TxnSearchView => "txn_search",
+            Self::PendingPoolView => "pending_pool",
+            Self::SimulationView => "simulation",
+            Self::NetworkSelect => "network_select",
+            Self::SearchInput => "search_input",
+            Self::InlineSearch => "inline_search",
+            Self::SearchResults => "search_results",
+            Self::MessagePopup => "message",
+            Self::HelpPopup => "help",
+            Self::ConfirmQuit => "confirm_quit",
+            Self::NetworkForm => "network_form",
+            Self::LocalStateLookup => "local_state_lookup",
+            Self::TxnFilterInput => "txn_filter",
+            Self::AdvancedSearchForm => "advanced_search",
+            Self::ExportPrompt => "export",
+            Self::SimulateInput => "simulate",
+        }
+    }
+
+    /// Looks up a context by its config name.
+    #[must_use]
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL
+            .iter()
+            .copied()
+            .find(|context| context.name() == name)
+    }
+
+    /// Returns true if unbound character keys are typed as text.
+    #[must_use]
+    pub const fn accepts_text(self) -> bool {
+        matches!(
+            self,
+            Self::SearchInput
+                | Self::InlineSearch
+                | Self::NetworkForm
+                | Self::LocalStateLookup
+                | Self::TxnFilterInput
+                | Self::AdvancedSearchForm
+                | Self::ExportPrompt
+                | Self::SimulateInput
+        )
+    }
 }
 
 // ============================================================================
@@ -131,6 +237,8 @@ pub enum AppCommand {
     GraphScrollDown,
     /// Export transaction graph as SVG file.
     ExportSvg,
+    /// Open the atomic group the viewed transaction belongs to.
+    ViewGroup,
     /// Toggle fullscreen mode for detail popups.
     ToggleFullscreen,
 
@@ -189,22 +297,113 @@ pub enum AppCommand {
     SelectBlockTxn,
 
     // === Account Detail View Actions ===
-    /// Cycle between account detail tabs (Info / Assets / Apps).
+    /// Cycle between account detail tabs (Info / Assets / Created Assets / Apps / Created Apps / History).
     CycleAccountDetailTab,
+    /// Cycle the transaction type filter on the account History tab.
+    CycleAccountHistoryFilter,
+    /// Cycle the sort column of the current account asset or app list.
+    CycleAccountListSort,
+    /// Toggle hiding zero-balance holdings on the account Assets tab.
+    ToggleHideZeroBalances,
     /// Move up in account item list (assets or apps).
     MoveAccountItemUp,
     /// Move down in account item list (assets or apps).
     MoveAccountItemDown,
-    /// Select asset or app from account details to view full details.
+    /// Select asset, app or transaction from account details to view full details.
     SelectAccountItem,
 
     // === Application Detail View Actions ===
-    /// Cycle between app detail tabs (Info / State / Programs).
+    /// Cycle between app detail tabs (Info / State / Boxes / Programs).
     CycleAppDetailTab,
     /// Move up in app state list.
     MoveAppStateUp,
     /// Move down in app state list.
     MoveAppStateDown,
+    /// Open the prompt for looking up an account's local state in the app.
+    LookupLocalState,
+    /// Submit the local state lookup prompt.
+    SubmitLocalStateLookup,
+
+    // === Asset Detail View Actions ===
+    /// Cycle between asset detail tabs (Info / Holders).
+    CycleAssetDetailTab,
+    /// Move up in the asset holder list.
+    MoveAssetHolderUp,
+    /// Move down in the asset holder list.
+    MoveAssetHolderDown,
+    /// Open the selected holder's account details.
+    SelectAssetHolder,
+    /// Cycle the minimum balance filter on the Holders tab.
+    CycleAssetHolderFilter,
+
+    // === Group Detail View Actions ===
+    /// Cycle between group detail tabs (Members / Graph).
+    CycleGroupDetailTab,
+    /// Move up in the group member list.
+    MoveGroupTxnUp,
+    /// Move down in the group member list.
+    MoveGroupTxnDown,
+    /// Open the selected member transaction.
+    SelectGroupTxn,
+
+    // === Transaction Feed Actions ===
+    /// Open the prompt for filtering the live transaction feed.
+    OpenTxnFilter,
+    /// Apply the transaction feed filter prompt (empty input clears it).
+    SubmitTxnFilter,
+
+    // === Advanced Search Actions ===
+    /// Open the advanced transaction search form.
+    OpenAdvancedSearch,
+    /// Run the search described by the form.
+    SubmitAdvancedSearch,
+    /// Move to the next field in the search form.
+    AdvancedSearchNextField,
+    /// Move to the previous field in the search form.
+    AdvancedSearchPrevField,
+    /// Move up in the search results.
+    MoveSearchTxnUp,
+    /// Move down in the search results.
+    MoveSearchTxnDown,
+    /// Open the selected search result.
+    SelectSearchTxn,
+
+    // === Pending Pool Actions ===
+    /// Open the pending transaction pool (the viewed account's, in account details).
+    OpenPendingPool,
+    /// Move up in the pending pool.
+    MovePendingTxnUp,
+    /// Move down in the pending pool.
+    MovePendingTxnDown,
+    /// Open the selected pending or confirmed transaction.
+    SelectPendingTxn,
+
+    // === Simulation Actions ===
+    /// Open the prompt for simulating a transaction group.
+    OpenSimulate,
+    /// Simulate the file or base64 group in the prompt.
+    SubmitSimulate,
+    /// Cycle between simulation tabs (Result / Graph).
+    CycleSimulationTab,
+    /// Move up in the simulated transactions.
+    MoveSimulatedTxnUp,
+    /// Move down in the simulated transactions.
+    MoveSimulatedTxnDown,
+    /// Open the selected simulated transaction.
+    SelectSimulatedTxn,
+
+    // === Export Actions ===
+    /// Open the prompt for exporting the current list to a file.
+    OpenExport,
+    /// Start the export described by the prompt.
+    SubmitExport,
+    /// Switch the export path between CSV and JSON Lines.
+    ToggleExportFormat,
+
+    // === Watchlist Actions ===
+    /// Add the viewed account, asset or app to the watchlist, or remove it
+    /// (the selected entry when the watchlist panel has focus).
+    ToggleWatch,
 
     // === Network Form Actions ===
     /// Submit the custom network form.
@@ -219,275 +418,213 @@ pub enum AppCommand {
     Noop,
 }
 
-// ============================================================================
-// Key Mapping Functions
-// ============================================================================
-
-/// Maps a key event to an application command based on the current context.
-///
-/// This is a pure function with no side effects - it simply translates
-/// input events to semantic commands, providing a centralized place for
-/// all keybinding logic.
-///
-/// # Arguments
-///
-/// * `key` - The key event to map
-/// * `context` - The current input context
-///
-/// # Returns
-///
-/// The appropriate `AppCommand` for the given key and context.
-#[must_use]
-pub fn map_key(key: KeyEvent, context: &InputContext) -> AppCommand {
-    match context {
-        InputContext::Main => map_main_keys(key),
-        InputContext::DetailView => map_detail_view_keys(key),
-        InputContext::TxnDetailViewTable => map_txn_detail_view_table_keys(key),
-        InputContext::BlockDetailView => map_block_detail_view_keys(key),
-        InputContext::AccountDetailView => map_account_detail_view_keys(key),
-        InputContext::AppDetailView => map_app_detail_view_keys(key),
-        InputContext::NetworkSelect => map_network_select_keys(key),
-        InputContext::SearchInput => map_search_input_keys(key),
-        InputContext::InlineSearch => map_inline_search_keys(key),
-        InputContext::SearchResults => map_search_results_keys(key),
-        InputContext::MessagePopup => map_message_popup_keys(key),
-        InputContext::HelpPopup => map_help_popup_keys(key),
-        InputContext::ConfirmQuit => map_confirm_quit_keys(key),
-        InputContext::NetworkForm => map_network_form_keys(key),
-    }
-}
-
-/// Maps keys in the main browsing context.
-fn map_main_keys(key: KeyEvent) -> AppCommand {
-    match key.code {
-        KeyCode::Char('q') => AppCommand::RequestQuit,
-        KeyCode::Char('r') => AppCommand::Refresh,
-        KeyCode::Char(' ') => AppCommand::ToggleLive,
-        KeyCode::Char('?') => AppCommand::ToggleHelp,
-        KeyCode::Char('f') => AppCommand::FocusInlineSearch,
-        KeyCode::Char('n') => AppCommand::OpenNetworkSelect,
-        KeyCode::Tab => AppCommand::CycleFocus,
-        KeyCode::Char('k') | KeyCode::Up => AppCommand::MoveUp,
-        KeyCode::Char('j') | KeyCode::Down => AppCommand::MoveDown,
-        KeyCode::Char('g') => AppCommand::GoToTop,
-        KeyCode::Char('G') => AppCommand::GoToBottom,
-        KeyCode::Enter => AppCommand::Select,
-        KeyCode::Esc => AppCommand::Dismiss,
-        _ => AppCommand::Noop,
-    }
-}
-
-/// Maps keys in the detail view context.
-fn map_detail_view_keys(key: KeyEvent) -> AppCommand {
-    match key.code {
-        KeyCode::Esc => AppCommand::Dismiss,
-        KeyCode::Char('c') => AppCommand::CopyToClipboard,
-        KeyCode::Char('y') => AppCommand::CopyJson,
-        KeyCode::Char('o') => AppCommand::OpenInBrowser,
-        KeyCode::Char('s') => AppCommand::ExportSvg,
-        KeyCode::Char('q') => AppCommand::RequestQuit,
-        KeyCode::Char('f') => AppCommand::ToggleFullscreen,
-        KeyCode::Tab => AppCommand::ToggleDetailViewMode,
-        // Arrow keys for graph scrolling (Visual mode) and section navigation (Table mode)
-        KeyCode::Up => AppCommand::GraphScrollUp,
-        KeyCode::Down => AppCommand::GraphScrollDown,
-        KeyCode::Left => AppCommand::GraphScrollLeft,
-        KeyCode::Right => AppCommand::GraphScrollRight,
-        // j/k for section navigation in Table mode
-        KeyCode::Char('j') => AppCommand::DetailSectionDown,
-        KeyCode::Char('k') => AppCommand::DetailSectionUp,
-        KeyCode::Enter | KeyCode::Char(' ') => AppCommand::ToggleDetailSection,
-        _ => AppCommand::Noop,
-    }
-}
-
-/// Maps keys in the block detail view context.
-fn map_block_detail_view_keys(key: KeyEvent) -> AppCommand {
-    match key.code {
-        KeyCode::Esc => AppCommand::Dismiss,
-        KeyCode::Tab => AppCommand::CycleBlockDetailTab,
-        KeyCode::Char('k') | KeyCode::Up => AppCommand::MoveBlockTxnUp,
-        KeyCode::Char('j') | KeyCode::Down => AppCommand::MoveBlockTxnDown,
-        KeyCode::Char('g') => AppCommand::GoToTop,
-        KeyCode::Char('G') => AppCommand::GoToBottom,
-        KeyCode::Enter => AppCommand::SelectBlockTxn,
-        KeyCode::Char('c') => AppCommand::CopyToClipboard,
-        KeyCode::Char('y') => AppCommand::CopyJson,
-        KeyCode::Char('o') => AppCommand::OpenInBrowser,
-        KeyCode::Char('f') => AppCommand::ToggleFullscreen,
-        KeyCode::Char('q') => AppCommand::RequestQuit,
-        _ => AppCommand::Noop,
-    }
-}
-
-/// Maps keys in the add custom network form.
-fn map_network_form_keys(key: KeyEvent) -> AppCommand {
-    match key.code {
-        KeyCode::Esc => AppCommand::Dismiss,
-        KeyCode::Enter => AppCommand::SubmitNetworkForm,
-        KeyCode::Tab | KeyCode::Down => AppCommand::NetworkFormNextField,
-        KeyCode::Up => AppCommand::NetworkFormPrevField,
-        KeyCode::Backspace => AppCommand::Backspace,
-        KeyCode::Char(c) => AppCommand::TypeChar(c),
-        _ => AppCommand::Noop,
-    }
-}
-/// Maps keys in the account detail view context.
-fn map_account_detail_view_keys(key: KeyEvent) -> AppCommand {
-    match key.code {
-        KeyCode::Esc => AppCommand::Dismiss,
-        KeyCode::Tab => AppCommand::CycleAccountDetailTab,
-        KeyCode::Char('k') | KeyCode::Up => AppCommand::MoveAccountItemUp,
-        KeyCode::Char('j') | KeyCode::Down => AppCommand::MoveAccountItemDown,
-        KeyCode::Char('g') => AppCommand::GoToTop,
-        KeyCode::Char('G') => AppCommand::GoToBottom,
-        KeyCode::Enter => AppCommand::SelectAccountItem,
-        KeyCode::Char('c') => AppCommand::CopyToClipboard,
-        KeyCode::Char('y') => AppCommand::CopyJson,
-        KeyCode::Char('o') => AppCommand::OpenInBrowser,
-        KeyCode::Char('f') => AppCommand::ToggleFullscreen,
-        KeyCode::Char('q') => AppCommand::RequestQuit,
-        _ => AppCommand::Noop,
-    }
-}
-
-/// Maps keys in the application detail view context.
-fn map_app_detail_view_keys(key: KeyEvent) -> AppCommand {
-    match key.code {
-        KeyCode::Esc => AppCommand::Dismiss,
-        KeyCode::Tab => AppCommand::CycleAppDetailTab,
-        KeyCode::Char('k') | KeyCode::Up => AppCommand::MoveAppStateUp,
-        KeyCode::Char('j') | KeyCode::Down => AppCommand::MoveAppStateDown,
-        KeyCode::Char('g') => AppCommand::GoToTop,
-        KeyCode::Char('G') => AppCommand::GoToBottom,
-        KeyCode::Char('c') => AppCommand::CopyToClipboard,
-        KeyCode::Char('y') => AppCommand::CopyJson,
-        KeyCode::Char('o') => AppCommand::OpenInBrowser,
-        KeyCode::Char('f') => AppCommand::ToggleFullscreen,
-        KeyCode::Char('q') => AppCommand::RequestQuit,
-        _ => AppCommand::Noop,
-    }
-}
+impl AppCommand {
+    /// Commands that can be bound to keys, i.e. all but `TypeChar` and `Noop`.
+    pub const BINDABLE: &[Self] = &[
+        Self::RequestQuit,
+        Self::ConfirmQuit,
+        Self::Refresh,
+        Self::ToggleLive,
+        Self::ToggleHelp,
+        Self::FocusInlineSearch,
+        Self::OpenNetworkSelect,
+        Self::Dismiss,
+        Self::CycleFocus,
+        Self::MoveUp,
+        Self::MoveDown,
+        Self::GoToTop,
+        Self::GoToBottom,
+        Self::Select,
+        Self::CopyToClipboard,
+        Self::CopyJson,
+        Self::OpenInBrowser,
+        Self::ToggleDetailViewMode,
+        Self::DetailSectionUp,
+        Self::DetailSectionDown,
+        Self::ToggleDetailSection,
+        Self::GraphScrollLeft,
+        Self::GraphScrollRight,
+        Self::GraphScrollUp,
+        Self::GraphScrollDown,
+        Self::ExportSvg,
+        Self::ViewGroup,
+        Self::ToggleFullscreen,
+        Self::Backspace,
+        Self::CycleSearchType,
+        Self::SubmitSearch,
+        Self::SearchHistoryPrev,
+        Self::SearchHistoryNext,
+        Self::SearchCursorLeft,
+        Self::SearchCursorRight,
+        Self::NetworkUp,
+        Self::NetworkDown,
+        Self::SelectNetwork,
+        Self::AddNetwork,
+        Self::DeleteNetwork,
+        Self::PreviousResult,
+        Self::NextResult,
+        Self::SelectResult,
+        Self::ScrollHelpUp,
+        Self::ScrollHelpDown,
+        Self::CycleBlockDetailTab,
+        Self::MoveBlockTxnUp,
+        Self::MoveBlockTxnDown,
+        Self::SelectBlockTxn,
+        Self::CycleAccountDetailTab,
+        Self::CycleAccountHistoryFilter,
+        Self::CycleAccountListSort,
+        Self::ToggleHideZeroBalances,
+        Self::MoveAccountItemUp,
+        Self::MoveAccountItemDown,
+        Self::SelectAccountItem,
+        Self::CycleAppDetailTab,
+        Self::MoveAppStateUp,
+        Self::MoveAppStateDown,
+        Self::LookupLocalState,
+        Self::SubmitLocalStateLookup,
+        Self::CycleAssetDetailTab,
+        Self::MoveAssetHolderUp,
+        Self::MoveAssetHolderDown,
+        Self::SelectAssetHolder,
+        Self::CycleAssetHolderFilter,
+        Self::CycleGroupDetailTab,
+        Self::MoveGroupTxnUp,
+        Self::MoveGroupTxnDown,
+        Self::SelectGroupTxn,
+        Self::OpenTxnFilter,
+        Self::SubmitTxnFilter,
+        Self::OpenAdvancedSearch,
+        Self::SubmitAdvancedSearch,
+        Self::AdvancedSearchNextField,
+        Self::AdvancedSearchPrevField,
+        Self::MoveSearchTxnUp,
+        Self::MoveSearchTxnDown,
+        Self::SelectSearchTxn,
+        Self::OpenPendingPool,
+        Self::MovePendingTxnUp,
+        Self::MovePendingTxnDown,
+        Self::SelectPendingTxn,
+        Self::OpenSimulate,
+        Self::SubmitSimulate,
+        Self::CycleSimulationTab,
+        Self::MoveSimulatedTxnUp,
+        Self::MoveSimulatedTxnDown,
+        Self::SelectSimulatedTxn,
+        Self::OpenExport,
+        Self::SubmitExport,
+        Self::ToggleExportFormat,
+        Self::ToggleWatch,
+        Self::SubmitNetworkForm,
+        Self::NetworkFormNextField,
+        Self::NetworkFormPrevField,
+    ];
 
-/// Maps keys in the network selection popup.
-fn map_network_select_keys(key: KeyEvent) -> AppCommand {
-    match key.code {
-        KeyCode::Esc => AppCommand::Dismiss,
-        KeyCode::Char('k') | KeyCode::Up => AppCommand::NetworkUp,
-        KeyCode::Char('j') | KeyCode::Down => AppCommand::NetworkDown,
-        KeyCode::Char('g') => AppCommand::GoToTop,
-        KeyCode::Char('G') => AppCommand::GoToBottom,
-        KeyCode::Enter => AppCommand::SelectNetwork,
-        KeyCode::Char('a') => AppCommand::AddNetwork,
-        KeyCode::Char('d') => AppCommand::DeleteNetwork,
-        KeyCode::Char('q') => AppCommand::RequestQuit,
-        _ => AppCommand::Noop,
+    /// Looks up a bindable command by its variant name, e.g. `Refresh`.
+    #[must_use]
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::BINDABLE
+            .iter()
+            .find(|command| format!("{command:?}") == name)
+            .cloned()
     }
-}
 
-/// Maps keys in the search input popup.
-fn map_search_input_keys(key: KeyEvent) -> AppCommand {
-    match key.code {
-        KeyCode::Esc => AppCommand::Dismiss,
-        KeyCode::Enter => AppCommand::SubmitSearch,
-        KeyCode::Tab => AppCommand::CycleSearchType,
-        KeyCode::Backspace => AppCommand::Backspace,
-        KeyCode::Char(c) => {
-            // Handle Ctrl+C as quit in search mode too
-            if c == 'c' && key.modifiers.contains(KeyModifiers::CONTROL) {
-                AppCommand::Dismiss
-            } else {
-                AppCommand::TypeChar(c)
-            }
+    /// Returns the short description shown in the help popup.
+    #[must_use]
+    pub const fn description(&self) -> &'static str {
+        match self {
+            Self::RequestQuit => "Quit application",
+            Self::ConfirmQuit => "Confirm quit",
+            Self::Refresh => "Refresh data",
+            Self::ToggleLive => "Toggle live updates",
+            Self::ToggleHelp => "Show this help",
+            Self::FocusInlineSearch => "Focus search bar",
+            Self::OpenNetworkSelect => "Select network",
+            Self::Dismiss => "Close / Cancel",
+            Self::CycleFocus => "Cycle panel focus",
+            Self::MoveUp | Self::NetworkUp | Self::PreviousResult => "Move up",
+            Self::MoveDown | Self::NetworkDown | Self::NextResult => "Move down",
+            Self::GoToTop => "Go to top",
+            Self::GoToBottom => "Go to bottom",
+            Self::Select => "View details",
+            Self::CopyToClipboard => "Copy ID",
+            Self::CopyJson => "Copy JSON",
+            Self::OpenInBrowser => "Open in browser",
+            Self::ToggleDetailViewMode => "Switch Graph / Table view",
+            Self::DetailSectionUp => "Previous section",
+            Self::DetailSectionDown => "Next section",
+            Self::ToggleDetailSection => "Expand / collapse section",
+            Self::GraphScrollLeft => "Scroll graph left",
+            Self::GraphScrollRight => "Scroll graph right",
+            Self::GraphScrollUp => "Scroll graph up",
+            Self::GraphScrollDown => "Scroll graph down",
+            Self::ExportSvg => "Export SVG",
+            Self::ViewGroup => "View atomic group",
+            Self::ToggleFullscreen => "Toggle fullscreen",
+            Self::TypeChar(_) => "Type character",
+            Self::Backspace => "Delete character",
+            Self::CycleSearchType => "Cycle search type",
+            Self::SubmitSearch => "Search",
+            Self::SearchHistoryPrev => "Previous search",
+            Self::SearchHistoryNext => "Next search",
+            Self::SearchCursorLeft => "Move cursor left",
+            Self::SearchCursorRight => "Move cursor right",
+            Self::SelectNetwork => "Switch to network",
+            Self::AddNetwork => "Add custom network",
+            Self::DeleteNetwork => "Delete custom network",
+            Self::SelectResult => "Open result",
+            Self::ScrollHelpUp => "Scroll up",
+            Self::ScrollHelpDown => "Scroll down",
+            Self::CycleBlockDetailTab
+            | Self::CycleAccountDetailTab
+            | Self::CycleAppDetailTab
+            | Self::CycleAssetDetailTab
+            | Self::CycleGroupDetailTab
+            | Self::CycleSimulationTab => "Switch tab",
+            Self::MoveBlockTxnUp
+            | Self::MoveAccountItemUp
+            | Self::MoveAppStateUp
+            | Self::MoveAssetHolderUp
+            | Self::MoveGroupTxnUp
+            | Self::MoveSearchTxnUp
+            | Self::MovePendingTxnUp
+            | Self::MoveSimulatedTxnUp => "Move up",
+            Self::MoveBlockTxnDown
+            | Self::MoveAccountItemDown
+            | Self::MoveAppStateDown
+            | Self::MoveAssetHolderDown
+            | Self::MoveGroupTxnDown
+            | Self::MoveSearchTxnDown
+            | Self::MovePendingTxnDown
+            | Self::MoveSimulatedTxnDown => "Move down",
+            Self::SelectBlockTxn
+            | Self::SelectAccountItem
+            | Self::SelectGroupTxn
+            | Self::SelectSearchTxn
+            | Self::SelectPendingTxn
+            | Self::SelectSimulatedTxn => "Open selected item",
+            Self::SelectAssetHolder => "Open holder account",
+            Self::CycleAssetHolderFilter => "Filter holders by balance",
+            Self::CycleAccountHistoryFilter => "Filter history by type",
+            Self::CycleAccountListSort => "Sort list",
+            Self::ToggleHideZeroBalances => "Hide zero balances",
+            Self::LookupLocalState => "Look up account local state",
+            Self::OpenTxnFilter => "Filter transactions",
+            Self::SubmitTxnFilter => "Apply filter",
+            Self::OpenAdvancedSearch => "Advanced search",
+            Self::SubmitAdvancedSearch => "Search",
+            Self::OpenPendingPool => "Pending transaction pool",
+            Self::OpenSimulate => "Simulate transaction group",
+            Self::SubmitSimulate => "Simulate",
+            Self::OpenExport => "Export list (CSV / JSONL)",
+            Self::SubmitExport => "Export",
+            Self::ToggleExportFormat => "Switch CSV / JSONL",
+            Self::ToggleWatch => "Watch / unwatch",
+            Self::SubmitLocalStateLookup => "Look up local state",
+            Self::SubmitNetworkForm => "Save network",
+            Self::NetworkFormNextField | Self::AdvancedSearchNextField => "Next field",
+            Self::NetworkFormPrevField | Self::AdvancedSearchPrevField => "Previous field",
+            Self::Noop => "",
         }
-        _ => AppCommand::Noop,
-    }
-}
-
-/// Maps keys in the inline search bar context.
-fn map_inline_search_keys(key: KeyEvent) -> AppCommand {
-    match key.code {
-        KeyCode::Esc => AppCommand::Dismiss,
-        KeyCode::Enter => AppCommand::SubmitSearch,
-        KeyCode::Backspace => AppCommand::Backspace,
-        KeyCode::Tab => AppCommand::CycleSearchType,
-        KeyCode::Up => AppCommand::SearchHistoryPrev,
-        KeyCode::Down => AppCommand::SearchHistoryNext,
-        KeyCode::Left => AppCommand::SearchCursorLeft,
-        KeyCode::Right => AppCommand::SearchCursorRight,
-        KeyCode::Char(c) => {
-            // Handle Ctrl+C as dismiss
-            if c == 'c' && key.modifiers.contains(KeyModifiers::CONTROL) {
-                AppCommand::Dismiss
-            } else {
-                AppCommand::TypeChar(c)
-            }
-        }
-        _ => AppCommand::Noop,
-    }
-}
-
-/// Maps keys in the search results popup.
-fn map_search_results_keys(key: KeyEvent) -> AppCommand {
-    match key.code {
-        KeyCode::Esc => AppCommand::Dismiss,
-        KeyCode::Char('k') | KeyCode::Up => AppCommand::PreviousResult,
-        KeyCode::Char('j') | KeyCode::Down => AppCommand::NextResult,
-        KeyCode::Char('g') => AppCommand::GoToTop,
-        KeyCode::Char('G') => AppCommand::GoToBottom,
-        KeyCode::Enter => AppCommand::SelectResult,
-        KeyCode::Char('q') => AppCommand::RequestQuit,
-        _ => AppCommand::Noop,
-    }
-}
-
-/// Maps keys in the message popup.
-fn map_message_popup_keys(key: KeyEvent) -> AppCommand {
-    match key.code {
-        KeyCode::Esc | KeyCode::Enter | KeyCode::Char(' ') => AppCommand::Dismiss,
-        KeyCode::Char('q') => AppCommand::RequestQuit,
-        _ => AppCommand::Noop,
-    }
-}
-
-/// Maps keys in the help popup.
-fn map_help_popup_keys(key: KeyEvent) -> AppCommand {
-    match key.code {
-        KeyCode::Esc | KeyCode::Char('?') => AppCommand::Dismiss,
-        KeyCode::Char('k') | KeyCode::Up => AppCommand::ScrollHelpUp,
-        KeyCode::Char('j') | KeyCode::Down => AppCommand::ScrollHelpDown,
-        KeyCode::Char('g') => AppCommand::GoToTop,
-        KeyCode::Char('G') => AppCommand::GoToBottom,
-        KeyCode::Char('q') => AppCommand::RequestQuit,
-        _ => AppCommand::Noop,
-    }
-}
-
-/// Maps keys in the quit confirmation popup.
-fn map_confirm_quit_keys(key: KeyEvent) -> AppCommand {
-    match key.code {
-        KeyCode::Char('y') | KeyCode::Char('Y') => AppCommand::ConfirmQuit,
-        KeyCode::Char('n') | KeyCode::Char('N') | KeyCode::Esc => AppCommand::Dismiss,
-        _ => AppCommand::Noop,
-    }
-}
-
-/// Maps keys in the transaction detail view (Table mode) context.
-fn map_txn_detail_view_table_keys(key: KeyEvent) -> AppCommand {
-    match key.code {
-        KeyCode::Esc => AppCommand::Dismiss,
-        KeyCode::Tab => AppCommand::ToggleDetailViewMode,
-        KeyCode::Char('k') | KeyCode::Up => AppCommand::DetailSectionUp,
-        KeyCode::Char('j') | KeyCode::Down => AppCommand::DetailSectionDown,
-        KeyCode::Char('g') => AppCommand::GoToTop,
-        KeyCode::Char('G') => AppCommand::GoToBottom,
-        KeyCode::Char('c') => AppCommand::CopyToClipboard,
-        KeyCode::Char('y') => AppCommand::CopyJson,
-        KeyCode::Char('o') => AppCommand::OpenInBrowser,
-        KeyCode::Char('f') => AppCommand::ToggleFullscreen,
-        KeyCode::Char('s') => AppCommand::ExportSvg,
-        KeyCode::Char('q') => AppCommand::RequestQuit,
-        _ => AppCommand::Noop,
     }
 }
 
@@ -498,9 +635,15 @@ fn map_txn_detail_view_table_keys(key: KeyEvent) -> AppCommand {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::keymap::Keymap;
     use crossterm::event::{KeyCode, KeyEvent, KeyEventKind, KeyEventState, KeyModifiers};
     use rstest::rstest;
 
+    /// Maps a key event with the default keymap.
+    fn map_key(key: KeyEvent, context: &InputContext) -> AppCommand {
+        Keymap::default().map_key(key, context)
+    }
+
     /// Helper to create a key event for testing.
     fn key_event(code: KeyCode) -> KeyEvent {
         KeyEvent {
@@ -542,6 +685,11 @@ mod tests {
     #[case::go_bottom(KeyCode::Char('G'), AppCommand::GoToBottom)]
     #[case::select(KeyCode::Enter, AppCommand::Select)]
     #[case::dismiss(KeyCode::Esc, AppCommand::Dismiss)]
+    #[case::unwatch(KeyCode::Char('w'), AppCommand::ToggleWatch)]
+    #[case::txn_filter(KeyCode::Char('/'), AppCommand::OpenTxnFilter)]
+    #[case::advanced_search(KeyCode::Char('S'), AppCommand::OpenAdvancedSearch)]
+    #[case::pending_pool(KeyCode::Char('p'), AppCommand::OpenPendingPool)]
+    #[case::simulate(KeyCode::Char('x'), AppCommand::OpenSimulate)]
     #[case::noop(KeyCode::F(1), AppCommand::Noop)]
     fn test_main_context(#[case] key_code: KeyCode, #[case] expected: AppCommand) {
         assert_eq!(map_key(key_event(key_code), &InputContext::Main), expected);
@@ -554,6 +702,7 @@ mod tests {
     #[case::copy_json(KeyCode::Char('y'), AppCommand::CopyJson)]
     #[case::open_browser(KeyCode::Char('o'), AppCommand::OpenInBrowser)]
     #[case::export_svg(KeyCode::Char('s'), AppCommand::ExportSvg)]
+    #[case::view_group(KeyCode::Char('a'), AppCommand::ViewGroup)]
     #[case::fullscreen(KeyCode::Char('f'), AppCommand::ToggleFullscreen)]
     #[case::quit(KeyCode::Char('q'), AppCommand::RequestQuit)]
     #[case::toggle_mode(KeyCode::Tab, AppCommand::ToggleDetailViewMode)]
@@ -609,6 +758,11 @@ mod tests {
     #[case::go_top(KeyCode::Char('g'), AppCommand::GoToTop)]
     #[case::go_bottom(KeyCode::Char('G'), AppCommand::GoToBottom)]
     #[case::select(KeyCode::Enter, AppCommand::SelectAccountItem)]
+    #[case::cycle_history_filter(KeyCode::Char('t'), AppCommand::CycleAccountHistoryFilter)]
+    #[case::cycle_sort(KeyCode::Char('s'), AppCommand::CycleAccountListSort)]
+    #[case::hide_zero(KeyCode::Char('z'), AppCommand::ToggleHideZeroBalances)]
+    #[case::watch(KeyCode::Char('w'), AppCommand::ToggleWatch)]
+    #[case::pending_pool(KeyCode::Char('p'), AppCommand::OpenPendingPool)]
     #[case::copy_clipboard(KeyCode::Char('c'), AppCommand::CopyToClipboard)]
     #[case::copy_json(KeyCode::Char('y'), AppCommand::CopyJson)]
     #[case::open_browser(KeyCode::Char('o'), AppCommand::OpenInBrowser)]
@@ -631,6 +785,8 @@ mod tests {
     #[case::move_up_vim(KeyCode::Char('k'), AppCommand::MoveAppStateUp)]
     #[case::move_down_arrow(KeyCode::Down, AppCommand::MoveAppStateDown)]
     #[case::move_down_vim(KeyCode::Char('j'), AppCommand::MoveAppStateDown)]
+    #[case::lookup_local_state(KeyCode::Char('l'), AppCommand::LookupLocalState)]
+    #[case::watch(KeyCode::Char('w'), AppCommand::ToggleWatch)]
     #[case::go_top(KeyCode::Char('g'), AppCommand::GoToTop)]
     #[case::go_bottom(KeyCode::Char('G'), AppCommand::GoToBottom)]
     #[case::copy_clipboard(KeyCode::Char('c'), AppCommand::CopyToClipboard)]
@@ -647,6 +803,55 @@ mod tests {
         );
     }
 
+    /// Tests all key mappings for AssetDetailView context.
+    #[rstest]
+    #[case::dismiss(KeyCode::Esc, AppCommand::Dismiss)]
+    #[case::cycle_tab(KeyCode::Tab, AppCommand::CycleAssetDetailTab)]
+    #[case::move_up_arrow(KeyCode::Up, AppCommand::MoveAssetHolderUp)]
+    #[case::move_up_vim(KeyCode::Char('k'), AppCommand::MoveAssetHolderUp)]
+    #[case::move_down_arrow(KeyCode::Down, AppCommand::MoveAssetHolderDown)]
+    #[case::move_down_vim(KeyCode::Char('j'), AppCommand::MoveAssetHolderDown)]
+    #[case::go_top(KeyCode::Char('g'), AppCommand::GoToTop)]
+    #[case::go_bottom(KeyCode::Char('G'), AppCommand::GoToBottom)]
+    #[case::select(KeyCode::Enter, AppCommand::SelectAssetHolder)]
+    #[case::cycle_filter(KeyCode::Char('m'), AppCommand::CycleAssetHolderFilter)]
+    #[case::watch(KeyCode::Char('w'), AppCommand::ToggleWatch)]
+    #[case::copy_clipboard(KeyCode::Char('c'), AppCommand::CopyToClipboard)]
+    #[case::copy_json(KeyCode::Char('y'), AppCommand::CopyJson)]
+    #[case::open_browser(KeyCode::Char('o'), AppCommand::OpenInBrowser)]
+    #[case::fullscreen(KeyCode::Char('f'), AppCommand::ToggleFullscreen)]
+    #[case::quit(KeyCode::Char('q'), AppCommand::RequestQuit)]
+    #[case::noop_x(KeyCode::Char('x'), AppCommand::Noop)]
+    fn test_asset_detail_view_context(#[case] key_code: KeyCode, #[case] expected: AppCommand) {
+        assert_eq!(
+            map_key(key_event(key_code), &InputContext::AssetDetailView),
+            expected
+        );
+    }
+
+    /// Tests all key mappings for GroupDetailView context.
+    #[rstest]
+    #[case::dismiss(KeyCode::Esc, AppCommand::Dismiss)]
+    #[case::cycle_tab(KeyCode::Tab, AppCommand::CycleGroupDetailTab)]
+    #[case::move_up_arrow(KeyCode::Up, AppCommand::MoveGroupTxnUp)]
+    #[case::move_up_vim(KeyCode::Char('k'), AppCommand::MoveGroupTxnUp)]
+    #[case::move_down_arrow(KeyCode::Down, AppCommand::MoveGroupTxnDown)]
+    #[case::move_down_vim(KeyCode::Char('j'), AppCommand::MoveGroupTxnDown)]
+    #[case::scroll_left(KeyCode::Left, AppCommand::GraphScrollLeft)]
+    #[case::scroll_right(KeyCode::Right, AppCommand::GraphScrollRight)]
+    #[case::go_top(KeyCode::Char('g'), AppCommand::GoToTop)]
+    #[case::go_bottom(KeyCode::Char('G'), AppCommand::GoToBottom)]
+    #[case::select(KeyCode::Enter, AppCommand::SelectGroupTxn)]
+    #[case::fullscreen(KeyCode::Char('f'), AppCommand::ToggleFullscreen)]
+    #[case::quit(KeyCode::Char('q'), AppCommand::RequestQuit)]
+    #[case::noop_x(KeyCode::Char('x'), AppCommand::Noop)]
+    fn test_group_detail_view_context(#[case] key_code: KeyCode, #[case] expected: AppCommand) {
+        assert_eq!(
+            map_key(key_event(key_code), &InputContext::GroupDetailView),
+            expected
+        );
+    }
+
     /// Tests all key mappings for NetworkSelect context.
     #[rstest]
     #[case::dismiss(KeyCode::Esc, AppCommand::Dismiss)]
@@ -684,6 +889,128 @@ mod tests {
         );
     }
 
+    /// Tests key mappings for LocalStateLookup context.
+    #[rstest]
+    #[case::dismiss(KeyCode::Esc, AppCommand::Dismiss)]
+    #[case::submit(KeyCode::Enter, AppCommand::SubmitLocalStateLookup)]
+    #[case::backspace(KeyCode::Backspace, AppCommand::Backspace)]
+    #[case::type_char(KeyCode::Char('l'), AppCommand::TypeChar('l'))]
+    fn test_local_state_lookup_context(#[case] key_code: KeyCode, #[case] expected: AppCommand) {
+        assert_eq!(
+            map_key(key_event(key_code), &InputContext::LocalStateLookup),
+            expected
+        );
+    }
+
+    /// Tests key mappings for TxnFilterInput context.
+    #[rstest]
+    #[case::dismiss(KeyCode::Esc, AppCommand::Dismiss)]
+    #[case::submit(KeyCode::Enter, AppCommand::SubmitTxnFilter)]
+    #[case::backspace(KeyCode::Backspace, AppCommand::Backspace)]
+    #[case::type_char(KeyCode::Char('>'), AppCommand::TypeChar('>'))]
+    fn test_txn_filter_context(#[case] key_code: KeyCode, #[case] expected: AppCommand) {
+        assert_eq!(
+            map_key(key_event(key_code), &InputContext::TxnFilterInput),
+            expected
+        );
+    }
+
+    /// Tests key mappings for AdvancedSearchForm context.
+    #[rstest]
+    #[case::dismiss(KeyCode::Esc, AppCommand::Dismiss)]
+    #[case::submit(KeyCode::Enter, AppCommand::SubmitAdvancedSearch)]
+    #[case::next_tab(KeyCode::Tab, AppCommand::AdvancedSearchNextField)]
+    #[case::next_down(KeyCode::Down, AppCommand::AdvancedSearchNextField)]
+    #[case::prev_backtab(KeyCode::BackTab, AppCommand::AdvancedSearchPrevField)]
+    #[case::prev_up(KeyCode::Up, AppCommand::AdvancedSearchPrevField)]
+    #[case::backspace(KeyCode::Backspace, AppCommand::Backspace)]
+    #[case::type_char(KeyCode::Char('q'), AppCommand::TypeChar('q'))]
+    fn test_advanced_search_form_context(#[case] key_code: KeyCode, #[case] expected: AppCommand) {
+        assert_eq!(
+            map_key(key_event(key_code), &InputContext::AdvancedSearchForm),
+            expected
+        );
+    }
+
+    /// Tests key mappings for ExportPrompt context.
+    #[rstest]
+    #[case::dismiss(KeyCode::Esc, AppCommand::Dismiss)]
+    #[case::submit(KeyCode::Enter, AppCommand::SubmitExport)]
+    #[case::toggle(KeyCode::Tab, AppCommand::ToggleExportFormat)]
+    #[case::backspace(KeyCode::Backspace, AppCommand::Backspace)]
+    #[case::type_char(KeyCode::Char('x'), AppCommand::TypeChar('x'))]
+    fn test_export_prompt_context(#[case] key_code: KeyCode, #[case] expected: AppCommand) {
+        assert_eq!(
+            map_key(key_event(key_code), &InputContext::ExportPrompt),
+            expected
+        );
+    }
+
+    /// Tests key mappings for TxnSearchView context.
+    #[rstest]
+    #[case::dismiss(KeyCode::Esc, AppCommand::Dismiss)]
+    #[case::move_up(KeyCode::Char('k'), AppCommand::MoveSearchTxnUp)]
+    #[case::move_down(KeyCode::Down, AppCommand::MoveSearchTxnDown)]
+    #[case::go_bottom(KeyCode::Char('G'), AppCommand::GoToBottom)]
+    #[case::select(KeyCode::Enter, AppCommand::SelectSearchTxn)]
+    #[case::edit_search(KeyCode::Char('S'), AppCommand::OpenAdvancedSearch)]
+    #[case::export(KeyCode::Char('e'), AppCommand::OpenExport)]
+    #[case::quit(KeyCode::Char('q'), AppCommand::RequestQuit)]
+    fn test_txn_search_context(#[case] key_code: KeyCode, #[case] expected: AppCommand) {
+        assert_eq!(
+            map_key(key_event(key_code), &InputContext::TxnSearchView),
+            expected
+        );
+    }
+
+    /// Tests key mappings for PendingPoolView context.
+    #[rstest]
+    #[case::dismiss(KeyCode::Esc, AppCommand::Dismiss)]
+    #[case::move_up(KeyCode::Char('k'), AppCommand::MovePendingTxnUp)]
+    #[case::move_down(KeyCode::Down, AppCommand::MovePendingTxnDown)]
+    #[case::go_top(KeyCode::Char('g'), AppCommand::GoToTop)]
+    #[case::select(KeyCode::Enter, AppCommand::SelectPendingTxn)]
+    #[case::fullscreen(KeyCode::Char('f'), AppCommand::ToggleFullscreen)]
+    #[case::quit(KeyCode::Char('q'), AppCommand::RequestQuit)]
+    #[case::noop(KeyCode::Char('x'), AppCommand::Noop)]
+    fn test_pending_pool_context(#[case] key_code: KeyCode, #[case] expected: AppCommand) {
+        assert_eq!(
+            map_key(key_event(key_code), &InputContext::PendingPoolView),
+            expected
+        );
+    }
+
+    /// Tests key mappings for SimulationView context.
+    #[rstest]
+    #[case::dismiss(KeyCode::Esc, AppCommand::Dismiss)]
+    #[case::cycle_tab(KeyCode::Tab, AppCommand::CycleSimulationTab)]
+    #[case::move_up(KeyCode::Char('k'), AppCommand::MoveSimulatedTxnUp)]
+    #[case::move_down(KeyCode::Down, AppCommand::MoveSimulatedTxnDown)]
+    #[case::scroll_left(KeyCode::Left, AppCommand::GraphScrollLeft)]
+    #[case::select(KeyCode::Enter, AppCommand::SelectSimulatedTxn)]
+    #[case::simulate_again(KeyCode::Char('x'), AppCommand::OpenSimulate)]
+    #[case::fullscreen(KeyCode::Char('f'), AppCommand::ToggleFullscreen)]
+    #[case::quit(KeyCode::Char('q'), AppCommand::RequestQuit)]
+    fn test_simulation_context(#[case] key_code: KeyCode, #[case] expected: AppCommand) {
+        assert_eq!(
+            map_key(key_event(key_code), &InputContext::SimulationView),
+            expected
+        );
+    }
+
+    /// Tests key mappings for SimulateInput context.
+    #[rstest]
+    #[case::dismiss(KeyCode::Esc, AppCommand::Dismiss)]
+    #[case::submit(KeyCode::Enter, AppCommand::SubmitSimulate)]
+    #[case::backspace(KeyCode::Backspace, AppCommand::Backspace)]
+    #[case::type_slash(KeyCode::Char('/'), AppCommand::TypeChar('/'))]
+    fn test_simulate_input_context(#[case] key_code: KeyCode, #[case] expected: AppCommand) {
+        assert_eq!(
+            map_key(key_event(key_code), &InputContext::SimulateInput),
+            expected
+        );
+    }
+
     /// Tests all key mappings for SearchInput context.
     #[rstest]
     #[case::dismiss(KeyCode::Esc, AppCommand::Dismiss)]
@@ -810,4 +1137,22 @@ mod tests {
             expected
         );
     }
+
+    /// Tests config names round-trip for every context and bindable command.
+    #[test]
+    fn test_config_names_round_trip() {
+        for context in InputContext::ALL {
+            assert_eq!(InputContext::from_name(context.name()), Some(*context));
+        }
+        for command in AppCommand::BINDABLE {
+            let name = format!("{command:?}");
+            assert_eq!(AppCommand::from_name(&name).as_ref(), Some(command));
+            assert!(
+                !command.description().is_empty(),
+                "{name} has no description"
+            );
+        }
+        assert_eq!(AppCommand::from_name("Noop"), None);
+        assert_eq!(InputContext::from_name("Main"), None);
+    }
 }
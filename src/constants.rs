@@ -4,6 +4,8 @@
 //! and numeric values used throughout the application. Grouping these constants
 //! improves maintainability and ensures consistency across the codebase.
 
+use std::time::Duration;
+
 // ============================================================================
 // UI Dimension Constants
 // ============================================================================
@@ -25,6 +27,13 @@ pub const BLOCK_HEIGHT: u16 = 3;
 /// - Line 4: Empty spacer
 pub const TXN_HEIGHT: u16 = 4;
 
+/// Height of each watchlist item in the watchlist panel (in rows).
+///
+/// Each watchlist entry displays:
+/// - Line 1: Kind badge and address, ID or name
+/// - Line 2: Balance and last activity round
+pub const WATCH_HEIGHT: u16 = 2;
+
 /// Height of the application header area (in rows).
 ///
 /// The header contains:
@@ -58,6 +67,106 @@ pub const DEFAULT_VISIBLE_BLOCKS: u16 = 10;
 /// Used for scroll calculations when determining visible range.
 pub const DEFAULT_VISIBLE_TRANSACTIONS: u16 = 10;
 
+/// Default number of visible entries in the watchlist panel.
+///
+/// The watchlist shares the left column with the blocks list, so fewer
+/// entries fit. Used for scroll calculations.
+pub const DEFAULT_VISIBLE_WATCHES: u16 = 4;
+
+// ============================================================================
+// Pagination Constants
+// ============================================================================
+
+/// Number of items requested per indexer page.
+///
+/// The indexer caps most list endpoints at 1000 results per request.
+pub const INDEXER_PAGE_LIMIT: usize = 1000;
+
+/// Maximum number of pages fetched eagerly when following `next-token`.
+///
+/// Bounds the work done for lists that are loaded in one go rather than
+/// on demand as the user scrolls.
+pub const MAX_EAGER_PAGES: usize = 5;
+
+/// Number of rounds searched when a live feed filter is first applied.
+///
+/// Filtered feeds are pushed down to the indexer, so they can look much
+/// further back than the unfiltered 20-round window.
+pub const FILTER_BACKFILL_ROUNDS: u64 = 1000;
+
+/// Rounds in the first window searched when backfilling a filtered feed.
+///
+/// The indexer lists a round range oldest first, so the backfill walks back
+/// from the latest round, doubling the window until the feed is full.
+pub const FILTER_FIRST_WINDOW_ROUNDS: u64 = 20;
+
+/// Number of matches a filtered feed backfill stops at.
+pub const FILTER_FEED_LIMIT: usize = 100;
+
+/// Maximum number of blocks decoded per poll in algod-only mode.
+///
+/// Without an indexer every transaction comes from a block body, so feeds
+/// only look back this many rounds.
+pub const ALGOD_ONLY_SCAN_ROUNDS: u64 = 20;
+
+/// Maximum number of rounds fetched when live updates catch up on a gap.
+///
+/// After a long disconnect only the most recent rounds are filled in, the
+/// panels wouldn't show older ones anyway.
+pub const LIVE_CATCHUP_ROUNDS: u64 = 20;
+
+/// How long a single `wait-for-block-after` request may block.
+///
+/// algod answers after a new round or its own one minute timeout, so this
+/// only has to outlast that.
+pub const WAIT_FOR_BLOCK_TIMEOUT: Duration = Duration::from_secs(70);
+
+/// Delay before the live updates loop retries after a failed request.
+pub const LIVE_RETRY_DELAY: Duration = Duration::from_secs(5);
+
+/// Maximum number of transactions requested from algod's pool per poll.
+pub const PENDING_POOL_LIMIT: u64 = 100;
+
+/// How often the pending pool view polls algod while it is open.
+///
+/// The pool changes between rounds as transactions arrive, so it can't be
+/// driven by `wait-for-block-after` like the blocks panel.
+pub const PENDING_POOL_POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+/// Number of transactions kept in the pending pool's recently confirmed list.
+pub const PENDING_POOL_CONFIRMED: usize = 10;
+
+/// Number of rows from the end of a paged list at which the next page is requested.
+pub const LOAD_MORE_THRESHOLD: usize = 10;
+
+/// Number of items requested per page for account asset and app lists.
+///
+/// Smaller than [`INDEXER_PAGE_LIMIT`] because each asset holding needs a
+/// follow-up lookup to resolve its name, unit and decimals.
+pub const ACCOUNT_LIST_PAGE_LIMIT: usize = 100;
+
+/// Maximum number of asset parameter lookups in flight at once.
+pub const ASSET_LOOKUP_CONCURRENCY: usize = 10;
+
+// ============================================================================
+// Response Cache Constants
+// ============================================================================
+
+/// Maximum total size of the on-disk response cache, in bytes.
+pub const CACHE_MAX_BYTES: u64 = 64 * 1024 * 1024;
+
+/// How long cached accounts and application state stay valid.
+///
+/// Short enough that balances look current, long enough to make moving
+/// back and forth between related popups instant.
+pub const CACHE_MUTABLE_TTL: Duration = Duration::from_secs(30);
+
+/// How long cached parameters of live (not deleted) assets stay valid.
+///
+/// Asset names, units and decimals can only change through a rare
+/// reconfiguration, so they are kept much longer than account data.
+pub const CACHE_ASSET_TTL: Duration = Duration::from_secs(60 * 60);
+
 // ============================================================================
 // Display Symbols
 // ============================================================================
@@ -0,0 +1,501 @@
+//! ARC-4 ABI types and value decoding.
+//!
+//! This module parses ARC-4 type strings (e.g. `uint64`, `(address,byte[])`,
+//! `string[3]`) and decodes ABI-encoded byte strings into displayable values.
+//! It is used to decode ARC-28 event payloads and ABI return values found in
+//! application call logs.
+
+use std::fmt;
+
+use sha2::{Digest, Sha512_256};
+
+use super::account::encode_address;
+use super::error::AlgoError;
+
+// ============================================================================
+// ABI Type
+// ============================================================================
+
+/// An ARC-4 ABI type.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AbiType {
+    /// Unsigned integer of the given bit width (`uint8` .. `uint512`).
+    Uint(u16),
+    /// Unsigned fixed-point decimal (`ufixed<bits>x<precision>`).
+    UFixed(u16, u8),
+    /// A single byte.
+    Byte,
+    /// A boolean (packed into bits when adjacent in a tuple).
+    Bool,
+    /// A 32-byte Algorand address.
+    Address,
+    /// A length-prefixed UTF-8 string.
+    String,
+    /// A fixed-length array (`T[N]`).
+    StaticArray(Box<AbiType>, usize),
+    /// A length-prefixed array (`T[]`).
+    DynamicArray(Box<AbiType>),
+    /// A tuple of types (`(T1,T2,...)`).
+    Tuple(Vec<AbiType>),
+    /// A reference type (`account`, `asset`, `application`), encoded as `uint8`.
+    Reference(String),
+    /// A transaction argument (`txn`, `pay`, `axfer`, ...), not encoded in args.
+    Transaction(String),
+}
+
+impl AbiType {
+    /// Parse an ARC-4 type string.
+    ///
+    /// # Errors
+    ///
+    /// Returns `AlgoError::Parse` if the type string is not a valid ARC-4 type.
+    pub fn parse(s: &str) -> Result<Self, AlgoError> {
+        let s = s.trim();
+        let invalid = || AlgoError::parse(format!("invalid ABI type: {s}"));
+
+        if let Some(inner) = s.strip_suffix("[]") {
+            return Ok(Self::DynamicArray(Box::new(Self::parse(inner)?)));
+        }
+        if s.ends_with(']')
+            && let Some(open) = s.rfind('[')
+        {
+            let len = s[open + 1..s.len() - 1]
+                .parse::<usize>()
+                .map_err(|_| invalid())?;
+            return Ok(Self::StaticArray(Box::new(Self::parse(&s[..open])?), len));
+        }
+        if let Some(inner) = s.strip_prefix('(').and_then(|r| r.strip_suffix(')')) {
+            return split_tuple(inner)?
+                .into_iter()
+                .map(Self::parse)
+                .collect::<Result<Vec<_>, _>>()
+                .map(Self::Tuple);
+        }
+
+        match s {
+            "byte" => Ok(Self::Byte),
+            "bool" => Ok(Self::Bool),
+            "address" => Ok(Self::Address),
+            "string" => Ok(Self::String),
+            "account" | "asset" | "application" => Ok(Self::Reference(s.to_string())),
+            "txn" | "pay" | "keyreg" | "acfg" | "axfer" | "afrz" | "appl" => {
+                Ok(Self::Transaction(s.to_string()))
+            }
+            _ => {
+                if let Some(bits) = s.strip_prefix("uint") {
+                    let bits = bits.parse::<u16>().map_err(|_| invalid())?;
+                    if bits == 0 || bits > 512 || bits % 8 != 0 {
+                        return Err(invalid());
+                    }
+                    Ok(Self::Uint(bits))
+                } else if let Some(rest) = s.strip_prefix("ufixed") {
+                    let (bits, precision) = rest.split_once('x').ok_or_else(invalid)?;
+                    let bits = bits.parse::<u16>().map_err(|_| invalid())?;
+                    let precision = precision.parse::<u8>().map_err(|_| invalid())?;
+                    if bits == 0 || bits > 512 || bits % 8 != 0 || precision == 0 {
+                        return Err(invalid());
+                    }
+                    Ok(Self::UFixed(bits, precision))
+                } else {
+                    Err(invalid())
+                }
+            }
+        }
+    }
+
+    /// Returns true if the encoded size of this type depends on its value.
+    #[must_use]
+    pub fn is_dynamic(&self) -> bool {
+        match self {
+            Self::String | Self::DynamicArray(_) => true,
+            Self::StaticArray(elem, _) => elem.is_dynamic(),
+            Self::Tuple(types) => types.iter().any(Self::is_dynamic),
+            _ => false,
+        }
+    }
+}
+
+impl fmt::Display for AbiType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Uint(bits) => write!(f, "uint{bits}"),
+            Self::UFixed(bits, precision) => write!(f, "ufixed{bits}x{precision}"),
+            Self::Byte => write!(f, "byte"),
+            Self::Bool => write!(f, "bool"),
+            Self::Address => write!(f, "address"),
+            Self::String => write!(f, "string"),
+            Self::StaticArray(elem, len) => write!(f, "{elem}[{len}]"),
+            Self::DynamicArray(elem) => write!(f, "{elem}[]"),
+            Self::Tuple(types) => {
+                let inner: Vec<String> = types.iter().map(ToString::to_string).collect();
+                write!(f, "({})", inner.join(","))
+            }
+            Self::Reference(name) | Self::Transaction(name) => write!(f, "{name}"),
+        }
+    }
+}
+
+/// Split the inside of a tuple type string on top-level commas.
+fn split_tuple(inner: &str) -> Result<Vec<&str>, AlgoError> {
+    if inner.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let mut parts = Vec::new();
+    let mut depth = 0usize;
+    let mut start = 0;
+    for (i, c) in inner.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => {
+                depth = depth
+                    .checked_sub(1)
+                    .ok_or_else(|| AlgoError::parse(format!("unbalanced tuple: ({inner})")))?;
+            }
+            ',' if depth == 0 => {
+                parts.push(&inner[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    if depth != 0 {
+        return Err(AlgoError::parse(format!("unbalanced tuple: ({inner})")));
+    }
+    parts.push(&inner[start..]);
+    Ok(parts)
+}
+
+// ============================================================================
+// ABI Value
+// ============================================================================
+
+/// A decoded ABI value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AbiValue {
+    /// An unsigned integer that fits in 128 bits.
+    Uint(u128),
+    /// An unsigned integer too large for 128 bits (big-endian bytes).
+    BigUint(Vec<u8>),
+    /// A fixed-point decimal with its precision.
+    UFixed(u128, u8),
+    /// A single byte.
+    Byte(u8),
+    /// A boolean.
+    Bool(bool),
+    /// An Algorand address.
+    Address(String),
+    /// A UTF-8 string.
+    String(String),
+    /// A byte array (`byte[]` or `byte[N]`).
+    Bytes(Vec<u8>),
+    /// An array of values.
+    Array(Vec<AbiValue>),
+    /// A tuple of values.
+    Tuple(Vec<AbiValue>),
+}
+
+impl fmt::Display for AbiValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Uint(value) => write!(f, "{value}"),
+            Self::BigUint(bytes) | Self::Bytes(bytes) => write!(f, "0x{}", hex(bytes)),
+            Self::UFixed(value, precision) => {
+                let digits = format!("{value:0>width$}", width = usize::from(*precision) + 1);
+                let (int, frac) = digits.split_at(digits.len() - usize::from(*precision));
+                write!(f, "{int}.{frac}")
+            }
+            Self::Byte(byte) => write!(f, "{byte}"),
+            Self::Bool(value) => write!(f, "{value}"),
+            Self::Address(address) => write!(f, "{address}"),
+            Self::String(s) => write!(f, "{s:?}"),
+            Self::Array(values) => write!(f, "[{}]", join(values)),
+            Self::Tuple(values) => write!(f, "({})", join(values)),
+        }
+    }
+}
+
+/// Join values with ", " for display.
+fn join(values: &[AbiValue]) -> String {
+    values
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Lowercase hex encoding of bytes.
+#[must_use]
+pub fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{b:02x}")).collect()
+}
+
+// ============================================================================
+// Decoding
+// ============================================================================
+
+/// Decode an ABI-encoded value of the given type.
+///
+/// The whole input must be consumed by the value; trailing bytes are an error
+/// so that mismatched signatures are not reported as successful decodes.
+///
+/// # Errors
+///
+/// Returns `AlgoError::Parse` if the bytes are not a valid encoding of `ty`.
+pub fn decode(ty: &AbiType, bytes: &[u8]) -> Result<AbiValue, AlgoError> {
+    let (value, used) = decode_at(ty, bytes, 0)?;
+    if used != bytes.len() {
+        return Err(AlgoError::parse(format!(
+            "{} trailing bytes after {ty} value",
+            bytes.len() - used
+        )));
+    }
+    Ok(value)
+}
+
+/// Decode a value starting at `offset`, returning the value and the end offset
+/// of its encoding.
+fn decode_at(ty: &AbiType, bytes: &[u8], offset: usize) -> Result<(AbiValue, usize), AlgoError> {
+    match ty {
+        AbiType::Uint(bits) | AbiType::UFixed(bits, _) => {
+            let size = usize::from(*bits) / 8;
+            let raw = take(bytes, offset, size)?;
+            let value = match uint_from_bytes(raw) {
+                Some(value) => match ty {
+                    AbiType::UFixed(_, precision) => AbiValue::UFixed(value, *precision),
+                    _ => AbiValue::Uint(value),
+                },
+                None => AbiValue::BigUint(raw.to_vec()),
+            };
+            Ok((value, offset + size))
+        }
+        AbiType::Byte => Ok((AbiValue::Byte(take(bytes, offset, 1)?[0]), offset + 1)),
+        AbiType::Reference(_) => Ok((
+            AbiValue::Uint(u128::from(take(bytes, offset, 1)?[0])),
+            offset + 1,
+        )),
+        AbiType::Bool => Ok((
+            AbiValue::Bool(take(bytes, offset, 1)?[0] & 0x80 != 0),
+            offset + 1,
+        )),
+        AbiType::Address => {
+            let raw = take(bytes, offset, 32)?;
+            Ok((AbiValue::Address(encode_address(raw)), offset + 32))
+        }
+        AbiType::String => {
+            let len = read_u16(bytes, offset)?;
+            let raw = take(bytes, offset + 2, len)?;
+            let value = String::from_utf8(raw.to_vec())
+                .map_err(|_| AlgoError::parse("ABI string is not valid UTF-8"))?;
+            Ok((AbiValue::String(value), offset + 2 + len))
+        }
+        AbiType::StaticArray(elem, len) => {
+            let (values, end) = decode_sequence(&vec![(**elem).clone(); *len], bytes, offset)?;
+            Ok((array_value(elem, values), end))
+        }
+        AbiType::DynamicArray(elem) => {
+            let len = read_u16(bytes, offset)?;
+            let (values, end) = decode_sequence(&vec![(**elem).clone(); len], bytes, offset + 2)?;
+            Ok((array_value(elem, values), end))
+        }
+        AbiType::Tuple(types) => {
+            let (values, end) = decode_sequence(types, bytes, offset)?;
+            Ok((AbiValue::Tuple(values), end))
+        }
+        AbiType::Transaction(name) => Err(AlgoError::parse(format!(
+            "transaction type {name} has no byte encoding"
+        ))),
+    }
+}
+
+/// Decode a tuple-encoded sequence of types starting at `start`.
+///
+/// Static members are read inline from the head, dynamic members through
+/// 2-byte offsets relative to `start`. Adjacent bools share a byte.
+fn decode_sequence(
+    types: &[AbiType],
+    bytes: &[u8],
+    start: usize,
+) -> Result<(Vec<AbiValue>, usize), AlgoError> {
+    let mut values = Vec::with_capacity(types.len());
+    let mut head = start;
+    let mut end = start;
+    let mut i = 0;
+
+    while i < types.len() {
+        let ty = &types[i];
+        if *ty == AbiType::Bool {
+            let run = types[i..]
+                .iter()
+                .take_while(|t| **t == AbiType::Bool)
+                .count();
+            let packed = take(bytes, head, run.div_ceil(8))?;
+            for bit in 0..run {
+                values.push(AbiValue::Bool(packed[bit / 8] & (0x80 >> (bit % 8)) != 0));
+            }
+            head += packed.len();
+            end = end.max(head);
+            i += run;
+        } else if ty.is_dynamic() {
+            let tail = start + read_u16(bytes, head)?;
+            let (value, value_end) = decode_at(ty, bytes, tail)?;
+            values.push(value);
+            head += 2;
+            end = end.max(head).max(value_end);
+            i += 1;
+        } else {
+            let (value, value_end) = decode_at(ty, bytes, head)?;
+            values.push(value);
+            head = value_end;
+            end = end.max(head);
+            i += 1;
+        }
+    }
+
+    Ok((values, end))
+}
+
+/// Collapse byte arrays into [`AbiValue::Bytes`] for compact display.
+fn array_value(elem: &AbiType, values: Vec<AbiValue>) -> AbiValue {
+    if *elem == AbiType::Byte {
+        AbiValue::Bytes(
+            values
+                .into_iter()
+                .filter_map(|v| match v {
+                    AbiValue::Byte(b) => Some(b),
+                    _ => None,
+                })
+                .collect(),
+        )
+    } else {
+        AbiValue::Array(values)
+    }
+}
+
+/// Borrow `len` bytes at `offset`, erroring if the input is too short.
+fn take(bytes: &[u8], offset: usize, len: usize) -> Result<&[u8], AlgoError> {
+    bytes
+        .get(offset..offset + len)
+        .ok_or_else(|| AlgoError::parse(format!("ABI value truncated at byte {offset}")))
+}
+
+/// Read a big-endian u16 length or offset.
+fn read_u16(bytes: &[u8], offset: usize) -> Result<usize, AlgoError> {
+    let raw = take(bytes, offset, 2)?;
+    Ok(usize::from(u16::from_be_bytes([raw[0], raw[1]])))
+}
+
+/// Interpret big-endian bytes as a u128, if the value fits.
+fn uint_from_bytes(raw: &[u8]) -> Option<u128> {
+    let significant = raw
+        .iter()
+        .position(|b| *b != 0)
+        .map_or(&[][..], |i| &raw[i..]);
+    if significant.len() > 16 {
+        return None;
+    }
+    Some(
+        significant
+            .iter()
+            .fold(0u128, |acc, b| (acc << 8) | u128::from(*b)),
+    )
+}
+
+// ============================================================================
+// Selectors
+// ============================================================================
+
+/// Compute the 4-byte ARC-4 selector of a method or event signature.
+///
+/// The selector is the first four bytes of the SHA-512/256 hash of the
+/// signature, e.g. `add(uint64,uint64)uint128` or `Transfer(address,uint64)`.
+#[must_use]
+pub fn selector(signature: &str) -> [u8; 4] {
+    let hash = Sha512_256::digest(signature.as_bytes());
+    [hash[0], hash[1], hash[2], hash[3]]
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case::uint("uint64", AbiType::Uint(64))]
+    #[case::ufixed("ufixed64x2", AbiType::UFixed(64, 2))]
+    #[case::dynamic_array("byte[]", AbiType::DynamicArray(Box::new(AbiType::Byte)))]
+    #[case::static_array("bool[3]", AbiType::StaticArray(Box::new(AbiType::Bool), 3))]
+    #[case::nested_tuple(
+        "(address,(uint8,string))",
+        AbiType::Tuple(vec![
+            AbiType::Address,
+            AbiType::Tuple(vec![AbiType::Uint(8), AbiType::String]),
+        ])
+    )]
+    #[case::reference("asset", AbiType::Reference("asset".to_string()))]
+    fn test_parse_abi_type(#[case] input: &str, #[case] expected: AbiType) {
+        let parsed = AbiType::parse(input).unwrap();
+        assert_eq!(parsed, expected);
+        assert_eq!(parsed.to_string(), input);
+    }
+
+    #[rstest]
+    #[case::bad_width("uint7")]
+    #[case::unknown("float")]
+    #[case::unbalanced("(uint64")]
+    fn test_parse_abi_type_rejects_invalid(#[case] input: &str) {
+        assert!(AbiType::parse(input).is_err());
+    }
+
+    #[test]
+    fn test_decode_tuple_with_dynamic_and_packed_bools() {
+        // (uint16,string,bool,bool): head = 2 + 2 (offset) + 1 (packed bools)
+        let ty = AbiType::parse("(uint16,string,bool,bool)").unwrap();
+        let bytes = [0x00, 0x2a, 0x00, 0x05, 0b0100_0000, 0x00, 0x02, b'h', b'i'];
+
+        let value = decode(&ty, &bytes).unwrap();
+
+        assert_eq!(value.to_string(), r#"(42, "hi", false, true)"#);
+    }
+
+    #[test]
+    fn test_decode_values_display() {
+        let big = decode(&AbiType::Uint(256), &[0xff; 32]).unwrap();
+        assert!(matches!(big, AbiValue::BigUint(_)));
+
+        let fixed = decode(&AbiType::UFixed(64, 2), &1234u64.to_be_bytes()).unwrap();
+        assert_eq!(fixed.to_string(), "12.34");
+
+        let bytes = decode(
+            &AbiType::parse("byte[]").unwrap(),
+            &[0x00, 0x02, 0xca, 0xfe],
+        )
+        .unwrap();
+        assert_eq!(bytes.to_string(), "0xcafe");
+
+        let address = decode(&AbiType::Address, &[0u8; 32]).unwrap();
+        assert_eq!(
+            address.to_string(),
+            "AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAY5HFKQ"
+        );
+    }
+
+    #[test]
+    fn test_decode_rejects_truncated_and_trailing_bytes() {
+        assert!(decode(&AbiType::Uint(64), &[0x01, 0x02]).is_err());
+        assert!(decode(&AbiType::Uint(8), &[0x01, 0x02]).is_err());
+    }
+
+    #[test]
+    fn test_selector_matches_arc4_example() {
+        // Example from ARC-4: add(uint64,uint64)uint128 -> 0x8aa3b61f
+        assert_eq!(
+            selector("add(uint64,uint64)uint128"),
+            [0x8a, 0xa3, 0xb6, 0x1f]
+        );
+    }
+}
@@ -3,6 +3,8 @@
 //! This module defines account-related types including basic account info
 //! for search results and detailed account information for popups.
 
+use serde::Serialize;
+
 use super::nfd::NfdInfo;
 
 // ============================================================================
@@ -12,7 +14,7 @@ use super::nfd::NfdInfo;
 /// Basic account info for search results display.
 ///
 /// Contains essential account metadata for display in search results.
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct AccountInfo {
     /// The Algorand address (58 characters).
     pub address: String,
@@ -85,7 +87,7 @@ impl AccountInfo {
 // ============================================================================
 
 /// Detailed account information for popup display.
-#[derive(Debug, Clone, PartialEq, Default)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize)]
 pub struct AccountDetails {
     /// The Algorand address (58 characters).
     pub address: String,
@@ -115,14 +117,6 @@ pub struct AccountDetails {
     pub auth_addr: Option<String>,
     /// Participation info for online accounts.
     pub participation: Option<ParticipationInfo>,
-    /// Asset holdings (limited to first entries).
-    pub assets: Vec<AccountAssetHolding>,
-    /// Created assets (limited to first entries).
-    pub created_assets: Vec<CreatedAssetInfo>,
-    /// App local states (limited to first entries).
-    pub apps_local_state: Vec<AppLocalState>,
-    /// Created apps (limited to first entries).
-    pub created_apps: Vec<CreatedAppInfo>,
     /// NFD name if available (MainNet/TestNet only).
     pub nfd: Option<NfdInfo>,
 }
@@ -180,7 +174,7 @@ impl AccountDetails {
 /// Participation key info for online accounts.
 ///
 /// Contains the participation keys and validity range for consensus participation.
-#[derive(Debug, Clone, PartialEq, Default)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize)]
 pub struct ParticipationInfo {
     /// First round the participation key is valid for.
     pub vote_first: u64,
@@ -234,15 +228,23 @@ impl ParticipationInfo {
 
 /// Asset holding info for an account.
 ///
-/// Represents an asset that the account has opted into and holds.
-#[derive(Debug, Clone, PartialEq, Default)]
+/// Represents an asset that the account has opted into and holds. The name,
+/// unit and decimals are resolved from the asset's parameters and stay empty
+/// if the lookup fails.
+#[derive(Debug, Clone, PartialEq, Default, Serialize)]
 pub struct AccountAssetHolding {
     /// The asset ID.
     pub asset_id: u64,
-    /// Amount of the asset held.
+    /// Amount of the asset held, in base units.
     pub amount: u64,
     /// Whether the holding is frozen.
     pub is_frozen: bool,
+    /// Asset name.
+    pub name: String,
+    /// Asset unit name.
+    pub unit_name: String,
+    /// Number of decimals in the asset's display amount.
+    pub decimals: u64,
 }
 
 impl AccountAssetHolding {
@@ -264,6 +266,7 @@ impl AccountAssetHolding {
             asset_id,
             amount,
             is_frozen,
+            ..Self::default()
         }
     }
 }
@@ -275,7 +278,7 @@ impl AccountAssetHolding {
 /// Created asset summary.
 ///
 /// Brief information about an asset created by an account.
-#[derive(Debug, Clone, PartialEq, Default)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize)]
 pub struct CreatedAssetInfo {
     /// The asset ID.
     pub asset_id: u64,
@@ -283,6 +286,10 @@ pub struct CreatedAssetInfo {
     pub name: String,
     /// Asset unit name.
     pub unit_name: String,
+    /// Number of decimals in the asset's display amount.
+    pub decimals: u64,
+    /// Total supply in base units.
+    pub total: u64,
 }
 
 impl CreatedAssetInfo {
@@ -304,6 +311,7 @@ impl CreatedAssetInfo {
             asset_id,
             name,
             unit_name,
+            ..Self::default()
         }
     }
 }
@@ -315,7 +323,7 @@ impl CreatedAssetInfo {
 /// App local state summary.
 ///
 /// Brief information about an application's local state for an account.
-#[derive(Debug, Clone, PartialEq, Default)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize)]
 pub struct AppLocalState {
     /// The application ID.
     pub app_id: u64,
@@ -366,7 +374,7 @@ impl AppLocalState {
 /// Created app summary.
 ///
 /// Brief information about an application created by an account.
-#[derive(Debug, Clone, PartialEq, Default)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize)]
 pub struct CreatedAppInfo {
     /// The application ID.
     pub app_id: u64,
@@ -389,6 +397,37 @@ impl CreatedAppInfo {
     }
 }
 
+// ============================================================================
+// Address Encoding
+// ============================================================================
+
+/// Encode a 32-byte public key as an Algorand address (base32 with checksum).
+#[must_use]
+pub fn encode_address(public_key: &[u8]) -> String {
+    use sha2::{Digest, Sha512_256};
+
+    // Checksum is the last 4 bytes of the hash of the public key
+    let hash = Sha512_256::digest(public_key);
+    let checksum = &hash[28..32];
+
+    let mut addr_bytes = Vec::with_capacity(public_key.len() + checksum.len());
+    addr_bytes.extend_from_slice(public_key);
+    addr_bytes.extend_from_slice(checksum);
+
+    data_encoding::BASE32_NOPAD.encode(&addr_bytes)
+}
+
+/// Returns true if `address` is a well-formed Algorand address with a valid checksum.
+#[must_use]
+pub fn is_valid_address(address: &str) -> bool {
+    if address.len() != 58 {
+        return false;
+    }
+    data_encoding::BASE32_NOPAD
+        .decode(address.as_bytes())
+        .is_ok_and(|bytes| bytes.len() == 36 && encode_address(&bytes[..32]) == address)
+}
+
 // ============================================================================
 // Tests
 // ============================================================================
@@ -500,4 +539,15 @@ mod tests {
         let app = CreatedAppInfo::new(12345);
         assert_eq!(app.app_id, 12345);
     }
+
+    #[test]
+    fn test_is_valid_address() {
+        let zero = encode_address(&[0u8; 32]);
+        assert!(is_valid_address(&zero));
+        assert!(!is_valid_address(
+            "Y76M3MSY6DKBRHBL7C3NNDXGS5IIMQVQVUAB6MP4XEMMGVF2QWNPL226CB"
+        ));
+        assert!(!is_valid_address("TESTADDRESS"));
+        assert!(!is_valid_address(&zero.to_lowercase()));
+    }
 }
@@ -0,0 +1,754 @@
+//! ARC-4 / ARC-56 application specs and ABI method call decoding.
+//!
+//! Contract specs are JSON files placed in the `specs` folder next to the
+//! configuration file. A spec is matched to an application by:
+//!
+//! - a file name of the form `<app id>.json`
+//! - the `networks` app IDs of an ARC-56 spec
+//! - a file name of the form `<program hash>.json`, or the `byteCode.approval`
+//!   of an ARC-56 spec, where the hash is the hex SHA-512/256 of the approval
+//!   program bytes
+//!
+//! Plain ARC-4 contract descriptions, ARC-32 app specs (with a nested
+//! `contract`) and ARC-56 app specs are accepted. Once matched, the first
+//! application argument selects the method and the remaining arguments and
+//! the return log are decoded into typed values.
+
+use std::collections::HashMap;
+use std::collections::hash_map::Entry;
+use std::fmt;
+use std::path::Path;
+use std::sync::{Arc, OnceLock, RwLock};
+
+use base64::{Engine as _, engine::general_purpose::STANDARD as BASE64};
+use serde_json::Value;
+use sha2::{Digest, Sha512_256};
+
+use super::abi::{self, AbiType, AbiValue};
+use super::error::AlgoError;
+use super::logs::{ABI_RETURN_PREFIX, EventRegistry};
+use super::transaction::AppCallDetails;
+
+/// Number of application arguments available to ABI method arguments.
+///
+/// ARC-4 packs arguments beyond the 15th into a tuple in the last slot.
+const MAX_ENCODED_ARGS: usize = 15;
+
+/// Specs loaded at startup, shared by all views.
+static SPECS: OnceLock<AppSpecRegistry> = OnceLock::new();
+
+/// Install the registry used by [`specs`]. Only the first call has an effect.
+pub fn install(registry: AppSpecRegistry) {
+    let _ = SPECS.set(registry);
+}
+
+/// Returns the installed spec registry, or an empty one if none was installed.
+#[must_use]
+pub fn specs() -> &'static AppSpecRegistry {
+    SPECS.get_or_init(AppSpecRegistry::default)
+}
+
+/// Hex SHA-512/256 of a program, used to match specs by approval program.
+#[must_use]
+pub fn program_hash(program: &[u8]) -> String {
+    abi::hex(&Sha512_256::digest(program))
+}
+
+// ============================================================================
+// ABI Method
+// ============================================================================
+
+/// A named ABI method argument.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AbiArg {
+    /// Argument name (`arg<N>` when the spec leaves it out).
+    pub name: String,
+    /// Argument type.
+    pub ty: AbiType,
+}
+
+/// An ARC-4 ABI method.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AbiMethod {
+    /// Method name.
+    pub name: String,
+    /// Arguments, in call order.
+    pub args: Vec<AbiArg>,
+    /// Return type, `None` for `void`.
+    pub returns: Option<AbiType>,
+}
+
+impl AbiMethod {
+    /// Parse a method object from an ARC-4 or ARC-56 spec.
+    ///
+    /// # Errors
+    ///
+    /// Returns `AlgoError::Parse` if the name is missing or a type is invalid.
+    pub fn from_json(method_json: &Value) -> Result<Self, AlgoError> {
+        let name = method_json["name"]
+            .as_str()
+            .ok_or_else(|| AlgoError::parse("ABI method without a name"))?
+            .to_string();
+
+        let args = method_json["args"]
+            .as_array()
+            .map(|arr| {
+                arr.iter()
+                    .enumerate()
+                    .map(|(idx, arg)| {
+                        Ok(AbiArg {
+                            name: arg["name"]
+                                .as_str()
+                                .map_or_else(|| format!("arg{idx}"), String::from),
+                            ty: AbiType::parse(arg["type"].as_str().unwrap_or_default())?,
+                        })
+                    })
+                    .collect::<Result<Vec<_>, AlgoError>>()
+            })
+            .transpose()?
+            .unwrap_or_default();
+
+        let returns = match method_json["returns"]["type"].as_str() {
+            None | Some("void") => None,
+            Some(ty) => Some(AbiType::parse(ty)?),
+        };
+
+        Ok(Self {
+            name,
+            args,
+            returns,
+        })
+    }
+
+    /// Returns the method signature, e.g. `add(uint64,uint64)uint128`.
+    #[must_use]
+    pub fn signature(&self) -> String {
+        let args: Vec<String> = self.args.iter().map(|arg| arg.ty.to_string()).collect();
+        let returns = self
+            .returns
+            .as_ref()
+            .map_or_else(|| "void".to_string(), ToString::to_string);
+        format!("{}({}){}", self.name, args.join(","), returns)
+    }
+
+    /// Returns the 4-byte selector passed as the first application argument.
+    #[must_use]
+    pub fn selector(&self) -> [u8; 4] {
+        abi::selector(&self.signature())
+    }
+}
+
+// ============================================================================
+// App Spec
+// ============================================================================
+
+/// The parts of an application spec used to decode calls.
+#[derive(Debug, Clone, Default)]
+pub struct AppSpec {
+    /// Contract name.
+    pub name: String,
+    /// ABI methods.
+    pub methods: Vec<AbiMethod>,
+    /// Built-in events plus the events declared by the spec.
+    pub events: EventRegistry,
+    /// App IDs listed under the ARC-56 `networks` key.
+    pub app_ids: Vec<u64>,
+    /// Hash of the ARC-56 `byteCode.approval` program, if present.
+    pub approval_hash: Option<String>,
+}
+
+impl AppSpec {
+    /// Parse an ARC-4 contract, ARC-32 app spec or ARC-56 app spec.
+    ///
+    /// # Errors
+    ///
+    /// Returns `AlgoError::Parse` if there are no methods or a method or event
+    /// is invalid.
+    pub fn from_json(spec_json: &Value) -> Result<Self, AlgoError> {
+        // ARC-32 nests the ARC-4 contract description
+        let contract = if spec_json["contract"].is_object() {
+            &spec_json["contract"]
+        } else {
+            spec_json
+        };
+
+        let method_values = contract["methods"]
+            .as_array()
+            .ok_or_else(|| AlgoError::parse("app spec has no methods"))?;
+        let methods = method_values
+            .iter()
+            .map(AbiMethod::from_json)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        // ARC-56 declares events both per method and at the top level
+        let mut events = EventRegistry::known();
+        let event_values = contract["events"].as_array().into_iter().flatten().chain(
+            method_values
+                .iter()
+                .filter_map(|m| m["events"].as_array())
+                .flatten(),
+        );
+        for event in event_values {
+            events.register(&event_signature(event)?)?;
+        }
+
+        let app_ids = contract["networks"]
+            .as_object()
+            .map(|networks| {
+                networks
+                    .values()
+                    .filter_map(|network| network["appID"].as_u64())
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let approval_hash = contract["byteCode"]["approval"]
+            .as_str()
+            .and_then(|program| BASE64.decode(program).ok())
+            .map(|program| program_hash(&program));
+
+        Ok(Self {
+            name: contract["name"].as_str().unwrap_or_default().to_string(),
+            methods,
+            events,
+            app_ids,
+            approval_hash,
+        })
+    }
+
+    /// Decode an application call made with one of this spec's methods.
+    ///
+    /// Returns `None` if the first argument matches no method selector.
+    #[must_use]
+    pub fn decode_call(&self, details: &AppCallDetails, sender: &str) -> Option<DecodedCall> {
+        let selector = details
+            .app_args
+            .first()
+            .and_then(|arg| BASE64.decode(arg).ok())?;
+        let method = self
+            .methods
+            .iter()
+            .find(|method| method.selector()[..] == selector[..])?;
+
+        let encoded: Vec<Vec<u8>> = details.app_args[1..]
+            .iter()
+            .map(|arg| BASE64.decode(arg).unwrap_or_default())
+            .collect();
+        let values = decode_arg_values(method, &encoded);
+
+        let args = method
+            .args
+            .iter()
+            .zip(values)
+            .map(|(arg, value)| DecodedArg {
+                name: arg.name.clone(),
+                ty: arg.ty.clone(),
+                value: match value {
+                    ArgValue::Transaction => "(group transaction)".to_string(),
+                    ArgValue::Decoded(value) => resolve_reference(&arg.ty, &value, details, sender)
+                        .unwrap_or_else(|| value.to_string()),
+                    ArgValue::Invalid(error) => format!("<{error}>"),
+                },
+            })
+            .collect();
+
+        let returns = method.returns.as_ref().and_then(|ty| {
+            let payload = details.logs.iter().rev().find_map(|log| {
+                let bytes = BASE64.decode(log).ok()?;
+                bytes
+                    .strip_prefix(&ABI_RETURN_PREFIX[..])
+                    .map(<[u8]>::to_vec)
+            })?;
+            Some(match abi::decode(ty, &payload) {
+                Ok(value) => value.to_string(),
+                Err(e) => format!("<{e}>"),
+            })
+        });
+
+        Some(DecodedCall {
+            contract: self.name.clone(),
+            method: method.name.clone(),
+            signature: method.signature(),
+            args,
+            returns_type: method.returns.clone(),
+            returns,
+        })
+    }
+}
+
+/// Build an event signature from an ARC-28/ARC-56 event object.
+fn event_signature(event: &Value) -> Result<String, AlgoError> {
+    let name = event["name"]
+        .as_str()
+        .ok_or_else(|| AlgoError::parse("event without a name"))?;
+    let args: Vec<&str> = event["args"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .map(|arg| arg["type"].as_str().unwrap_or_default())
+        .collect();
+    Ok(format!("{name}({})", args.join(",")))
+}
+
+/// An ABI argument value before display.
+enum ArgValue {
+    /// A transaction argument, passed as a preceding group transaction.
+    Transaction,
+    /// A value decoded from the application arguments.
+    Decoded(AbiValue),
+    /// The argument was missing or malformed.
+    Invalid(String),
+}
+
+/// Decode the encoded (non-transaction) arguments of a method call.
+fn decode_arg_values(method: &AbiMethod, encoded: &[Vec<u8>]) -> Vec<ArgValue> {
+    let encoded_types: Vec<&AbiType> = method
+        .args
+        .iter()
+        .map(|arg| &arg.ty)
+        .filter(|ty| !matches!(ty, AbiType::Transaction(_)))
+        .collect();
+
+    // Arguments past the 14th share the last slot as a tuple
+    let packed = encoded_types.len() > MAX_ENCODED_ARGS - 1;
+    let split = if packed {
+        MAX_ENCODED_ARGS - 1
+    } else {
+        encoded_types.len()
+    };
+
+    let mut values: Vec<ArgValue> = encoded_types[..split]
+        .iter()
+        .enumerate()
+        .map(|(idx, ty)| match encoded.get(idx) {
+            Some(bytes) => match abi::decode(ty, bytes) {
+                Ok(value) => ArgValue::Decoded(value),
+                Err(e) => ArgValue::Invalid(e.to_string()),
+            },
+            None => ArgValue::Invalid("missing argument".to_string()),
+        })
+        .collect();
+
+    if packed {
+        let rest: Vec<AbiType> = encoded_types[split..]
+            .iter()
+            .map(|ty| (*ty).clone())
+            .collect();
+        let count = rest.len();
+        match encoded
+            .get(split)
+            .map(|bytes| abi::decode(&AbiType::Tuple(rest), bytes))
+        {
+            Some(Ok(AbiValue::Tuple(tuple))) => {
+                values.extend(tuple.into_iter().map(ArgValue::Decoded));
+            }
+            Some(Err(e)) => {
+                values.extend((0..count).map(|_| ArgValue::Invalid(e.to_string())));
+            }
+            _ => {
+                values.extend((0..count).map(|_| ArgValue::Invalid("missing argument".into())));
+            }
+        }
+    }
+
+    // Re-insert transaction arguments at their positions
+    let mut values = values.into_iter();
+    method
+        .args
+        .iter()
+        .map(|arg| match arg.ty {
+            AbiType::Transaction(_) => ArgValue::Transaction,
+            _ => values
+                .next()
+                .unwrap_or_else(|| ArgValue::Invalid("missing argument".to_string())),
+        })
+        .collect()
+}
+
+/// Resolve a reference-type argument (a uint8 index) into the referenced item.
+fn resolve_reference(
+    ty: &AbiType,
+    value: &AbiValue,
+    details: &AppCallDetails,
+    sender: &str,
+) -> Option<String> {
+    let (AbiType::Reference(kind), AbiValue::Uint(index)) = (ty, value) else {
+        return None;
+    };
+    let index = usize::try_from(*index).ok()?;
+    match kind.as_str() {
+        // Index 0 is the sender; the rest index the accounts array
+        "account" if index == 0 => Some(sender.to_string()),
+        "account" => details.accounts.get(index - 1).cloned(),
+        "asset" => details.foreign_assets.get(index).map(u64::to_string),
+        // Index 0 is the called application; the rest index the apps array
+        "application" if index == 0 => Some(details.app_id.to_string()),
+        "application" => details.foreign_apps.get(index - 1).map(u64::to_string),
+        _ => None,
+    }
+}
+
+// ============================================================================
+// Decoded Call
+// ============================================================================
+
+/// A decoded ABI method argument.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DecodedArg {
+    /// Argument name.
+    pub name: String,
+    /// Argument type.
+    pub ty: AbiType,
+    /// Display value.
+    pub value: String,
+}
+
+/// An application call decoded against an app spec.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DecodedCall {
+    /// Contract name from the spec.
+    pub contract: String,
+    /// Method name.
+    pub method: String,
+    /// Full method signature.
+    pub signature: String,
+    /// Arguments, in call order.
+    pub args: Vec<DecodedArg>,
+    /// Declared return type, `None` for `void`.
+    pub returns_type: Option<AbiType>,
+    /// Decoded return value, if the call logged one.
+    pub returns: Option<String>,
+}
+
+impl fmt::Display for DecodedCall {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let args: Vec<String> = self
+            .args
+            .iter()
+            .map(|arg| format!("{}={}", arg.name, arg.value))
+            .collect();
+        write!(f, "{}({})", self.method, args.join(", "))
+    }
+}
+
+// ============================================================================
+// Registry
+// ============================================================================
+
+/// Specs keyed by app ID and by approval program hash.
+#[derive(Debug, Default)]
+pub struct AppSpecRegistry {
+    by_app_id: HashMap<u64, Arc<AppSpec>>,
+    by_program_hash: HashMap<String, Arc<AppSpec>>,
+    /// Approval program hashes learned at runtime; `None` while unknown.
+    app_programs: RwLock<HashMap<u64, Option<String>>>,
+}
+
+impl AppSpecRegistry {
+    /// Load every `*.json` spec in `dir`.
+    ///
+    /// A missing directory yields an empty registry. Files that cannot be read
+    /// or parsed are skipped and reported in the returned warnings.
+    #[must_use]
+    pub fn load_dir(dir: &Path) -> (Self, Vec<String>) {
+        let mut registry = Self::default();
+        let mut warnings = Vec::new();
+
+        let Ok(entries) = std::fs::read_dir(dir) else {
+            return (registry, warnings);
+        };
+        let mut paths: Vec<_> = entries
+            .filter_map(Result::ok)
+            .map(|entry| entry.path())
+            .filter(|path| path.extension().is_some_and(|ext| ext == "json"))
+            .collect();
+        paths.sort();
+
+        for path in paths {
+            let stem = path
+                .file_stem()
+                .and_then(|s| s.to_str())
+                .unwrap_or_default()
+                .to_string();
+            let result = std::fs::read_to_string(&path)
+                .map_err(|e| e.to_string())
+                .and_then(|content| {
+                    serde_json::from_str::<Value>(&content).map_err(|e| e.to_string())
+                })
+                .and_then(|json| AppSpec::from_json(&json).map_err(|e| e.to_string()));
+            match result {
+                Ok(spec) => registry.insert(&stem, spec),
+                Err(e) => warnings.push(format!("{}: {e}", path.display())),
+            }
+        }
+
+        (registry, warnings)
+    }
+
+    /// Add a spec, keyed by `key` (an app ID or program hash) and by the
+    /// app IDs and program hash it declares itself.
+    pub fn insert(&mut self, key: &str, spec: AppSpec) {
+        let spec = Arc::new(spec);
+        if let Ok(app_id) = key.parse::<u64>() {
+            self.by_app_id.insert(app_id, Arc::clone(&spec));
+        } else if key.len() == 64 && key.chars().all(|c| c.is_ascii_hexdigit()) {
+            self.by_program_hash
+                .insert(key.to_ascii_lowercase(), Arc::clone(&spec));
+        }
+        for app_id in &spec.app_ids {
+            self.by_app_id.insert(*app_id, Arc::clone(&spec));
+        }
+        if let Some(hash) = &spec.approval_hash {
+            self.by_program_hash.insert(hash.clone(), Arc::clone(&spec));
+        }
+    }
+
+    /// Find the spec for an application call.
+    ///
+    /// Matches the app ID first, then the hash of the approval program carried
+    /// by the call (on create and update) or learned via [`Self::learn_program`].
+    #[must_use]
+    pub fn spec_for(&self, details: &AppCallDetails) -> Option<Arc<AppSpec>> {
+        let app_id = details.created_app_id.unwrap_or(details.app_id);
+        if let Some(spec) = self.by_app_id.get(&app_id) {
+            return Some(Arc::clone(spec));
+        }
+        if self.by_program_hash.is_empty() {
+            return None;
+        }
+
+        let hash = details
+            .approval_program
+            .as_deref()
+            .and_then(|program| BASE64.decode(program).ok())
+            .map(|program| program_hash(&program))
+            .or_else(|| {
+                self.app_programs
+                    .read()
+                    .ok()
+                    .and_then(|programs| programs.get(&app_id).cloned().flatten())
+            })?;
+        self.by_program_hash.get(&hash).cloned()
+    }
+
+    /// Decode an application call, if a spec for the app knows its method.
+    #[must_use]
+    pub fn decode_call(&self, details: &AppCallDetails, sender: &str) -> Option<DecodedCall> {
+        self.spec_for(details)?.decode_call(details, sender)
+    }
+
+    /// Returns true, once per app, when the app's approval program must be
+    /// fetched before a hash-keyed spec can be matched.
+    ///
+    /// The caller is expected to fetch the program and pass it to
+    /// [`Self::learn_program`].
+    #[must_use]
+    pub fn should_fetch_program(&self, app_id: u64) -> bool {
+        if app_id == 0 || self.by_program_hash.is_empty() || self.by_app_id.contains_key(&app_id) {
+            return false;
+        }
+        self.app_programs
+            .write()
+            .is_ok_and(|mut programs| match programs.entry(app_id) {
+                Entry::Occupied(_) => false,
+                Entry::Vacant(entry) => {
+                    entry.insert(None);
+                    true
+                }
+            })
+    }
+
+    /// Record the approval program (Base64 encoded) of an application.
+    pub fn learn_program(&self, app_id: u64, approval_program: &str) {
+        let Ok(program) = BASE64.decode(approval_program) else {
+            return;
+        };
+        if let Ok(mut programs) = self.app_programs.write() {
+            programs.insert(app_id, Some(program_hash(&program)));
+        }
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn arc4_spec() -> Value {
+        json!({
+            "name": "Token",
+            "methods": [
+                {
+                    "name": "transfer",
+                    "args": [
+                        {"type": "address", "name": "receiver"},
+                        {"type": "uint64", "name": "amount"}
+                    ],
+                    "returns": {"type": "uint64"}
+                },
+                {
+                    "name": "optin",
+                    "args": [{"type": "pay", "name": "payment"}, {"type": "asset", "name": "asa"}],
+                    "returns": {"type": "void"}
+                }
+            ]
+        })
+    }
+
+    fn call_details(app_args: Vec<Vec<u8>>) -> AppCallDetails {
+        AppCallDetails {
+            app_id: 1234,
+            app_args: app_args.iter().map(|arg| BASE64.encode(arg)).collect(),
+            foreign_assets: vec![31566704],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_method_signature_and_selector() {
+        let method = AbiMethod::from_json(&json!({
+            "name": "add",
+            "args": [{"type": "uint64"}, {"type": "uint64"}],
+            "returns": {"type": "uint128"}
+        }))
+        .unwrap();
+
+        assert_eq!(method.signature(), "add(uint64,uint64)uint128");
+        assert_eq!(method.selector(), [0x8a, 0xa3, 0xb6, 0x1f]);
+        assert_eq!(method.args[1].name, "arg1");
+    }
+
+    #[test]
+    fn test_decode_call_with_return() {
+        let spec = AppSpec::from_json(&arc4_spec()).unwrap();
+        let selector = spec.methods[0].selector().to_vec();
+        let mut details = call_details(vec![selector, vec![0; 32], 1000u64.to_be_bytes().into()]);
+        let mut ret = ABI_RETURN_PREFIX.to_vec();
+        ret.extend_from_slice(&7u64.to_be_bytes());
+        details.logs = vec![BASE64.encode(ret)];
+
+        let call = spec.decode_call(&details, "SENDER").unwrap();
+
+        assert_eq!(call.method, "transfer");
+        assert_eq!(call.args[1].value, "1000");
+        assert_eq!(call.returns.as_deref(), Some("7"));
+        assert_eq!(
+            call.to_string(),
+            "transfer(receiver=AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAY5HFKQ, amount=1000)"
+        );
+    }
+
+    #[test]
+    fn test_decode_call_resolves_references_and_group_txns() {
+        let spec = AppSpec::from_json(&arc4_spec()).unwrap();
+        let selector = spec.methods[1].selector().to_vec();
+        let details = call_details(vec![selector, vec![0]]);
+
+        let call = spec.decode_call(&details, "SENDER").unwrap();
+
+        assert_eq!(call.args[0].value, "(group transaction)");
+        assert_eq!(call.args[1].value, "31566704");
+        assert_eq!(call.returns_type, None);
+    }
+
+    #[test]
+    fn test_decode_call_packs_args_beyond_fifteen() {
+        let args: Vec<Value> = (0..16).map(|_| json!({"type": "uint8"})).collect();
+        let spec = AppSpec::from_json(&json!({
+            "name": "Many",
+            "methods": [{"name": "many", "args": args, "returns": {"type": "void"}}]
+        }))
+        .unwrap();
+        let mut app_args = vec![spec.methods[0].selector().to_vec()];
+        app_args.extend((0..14u8).map(|i| vec![i]));
+        app_args.push(vec![14, 15]);
+
+        let call = spec.decode_call(&call_details(app_args), "SENDER").unwrap();
+
+        assert_eq!(call.args.len(), 16);
+        assert_eq!(call.args[15].value, "15");
+    }
+
+    #[test]
+    fn test_unknown_selector_is_not_decoded() {
+        let spec = AppSpec::from_json(&arc4_spec()).unwrap();
+        assert_eq!(
+            spec.decode_call(&call_details(vec![vec![1, 2, 3, 4]]), "SENDER"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_arc56_events_and_networks() {
+        let spec = AppSpec::from_json(&json!({
+            "name": "Arc56",
+            "methods": [{
+                "name": "ping",
+                "args": [],
+                "returns": {"type": "void"},
+                "events": [{"name": "Pinged", "args": [{"type": "uint64", "name": "count"}]}]
+            }],
+            "networks": {"wGHE2Pwdvd7S12BL5FaOP20EGYesN73ktiC1qzkkit8=": {"appID": 42}},
+            "byteCode": {"approval": "CIEBQw==", "clear": "CIEBQw=="}
+        }))
+        .unwrap();
+
+        let mut event = abi::selector("Pinged(uint64)").to_vec();
+        event.extend_from_slice(&3u64.to_be_bytes());
+        assert_eq!(spec.events.decode(&event).to_string(), "Pinged(3)");
+        assert_eq!(spec.app_ids, vec![42]);
+
+        let mut registry = AppSpecRegistry::default();
+        registry.insert("Arc56", spec);
+        let details = AppCallDetails {
+            app_id: 42,
+            ..Default::default()
+        };
+        assert!(registry.spec_for(&details).is_some());
+    }
+
+    #[test]
+    fn test_registry_matches_learned_program_hash() {
+        let program = BASE64.decode("CIEBQw==").unwrap();
+        let mut registry = AppSpecRegistry::default();
+        registry.insert(
+            &program_hash(&program),
+            AppSpec::from_json(&arc4_spec()).unwrap(),
+        );
+        let details = call_details(vec![]);
+
+        assert!(registry.spec_for(&details).is_none());
+        assert!(registry.should_fetch_program(1234));
+        assert!(!registry.should_fetch_program(1234));
+
+        registry.learn_program(1234, "CIEBQw==");
+        assert!(registry.spec_for(&details).is_some());
+    }
+
+    #[test]
+    fn test_load_dir_reports_invalid_files() {
+        let dir = std::env::temp_dir().join(format!("lazylora-specs-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("1234.json"), arc4_spec().to_string()).unwrap();
+        std::fs::write(dir.join("broken.json"), "{").unwrap();
+        std::fs::write(dir.join("notes.txt"), "ignored").unwrap();
+
+        let (registry, warnings) = AppSpecRegistry::load_dir(&dir);
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert!(
+            registry
+                .spec_for(&AppCallDetails {
+                    app_id: 1234,
+                    ..Default::default()
+                })
+                .is_some()
+        );
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].contains("broken.json"));
+    }
+}
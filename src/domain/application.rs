@@ -3,6 +3,8 @@
 //! This module defines application-related types including basic app info
 //! for search results and detailed application information for popups.
 
+use serde::Serialize;
+
 // ============================================================================
 // Application Info
 // ============================================================================
@@ -10,7 +12,7 @@
 /// Basic application info for search results display.
 ///
 /// Contains essential application metadata for display in search results.
-#[derive(Debug, Clone, PartialEq, Default)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize)]
 pub struct ApplicationInfo {
     /// The application ID.
     pub app_id: u64,
@@ -44,7 +46,7 @@ impl ApplicationInfo {
 // ============================================================================
 
 /// Detailed application information for popup display.
-#[derive(Debug, Clone, PartialEq, Default)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize)]
 pub struct ApplicationDetails {
     /// The application ID.
     pub app_id: u64,
@@ -103,7 +105,7 @@ impl ApplicationDetails {
 // ============================================================================
 
 /// Application state key-value pair.
-#[derive(Debug, Clone, PartialEq, Default)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize)]
 pub struct AppStateValue {
     /// The key name (decoded from Base64).
     pub key: String,
@@ -132,6 +134,145 @@ impl AppStateValue {
     }
 }
 
+// ============================================================================
+// App Box
+// ============================================================================
+
+/// A box owned by an application.
+///
+/// Box names are listed first; the value is fetched on demand when the box
+/// is selected, so `value` stays `None` until then.
+#[derive(Debug, Clone, PartialEq, Default, Serialize)]
+pub struct AppBox {
+    /// The box name (Base64).
+    pub name: String,
+    /// The box value (Base64), once fetched.
+    pub value: Option<String>,
+}
+
+impl AppBox {
+    /// Create a box entry with no value loaded yet.
+    #[must_use]
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            value: None,
+        }
+    }
+
+    /// Returns the decoded box name for display.
+    #[must_use]
+    pub fn display_name(&self) -> String {
+        StateBytes::from_base64(&self.name).map_or_else(|| self.name.clone(), |b| b.to_string())
+    }
+}
+
+// ============================================================================
+// State Encoding Helpers
+// ============================================================================
+
+/// A box byte value decoded with the display heuristics.
+///
+/// Values are tried as printable UTF-8, then as a big-endian uint64 (8 bytes),
+/// then as an Algorand address (32 bytes), and otherwise kept as raw bytes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StateBytes {
+    /// Printable UTF-8 text.
+    Text(String),
+    /// An 8-byte big-endian unsigned integer.
+    Uint(u64),
+    /// A 32-byte public key, shown as an address.
+    Address(String),
+    /// Anything else.
+    Binary(Vec<u8>),
+}
+
+impl StateBytes {
+    /// Decode raw bytes.
+    #[must_use]
+    pub fn decode(bytes: &[u8]) -> Self {
+        if let Ok(text) = std::str::from_utf8(bytes)
+            && text.chars().all(|c| c.is_ascii_graphic() || c == ' ')
+        {
+            return Self::Text(text.to_string());
+        }
+        match bytes.len() {
+            8 => {
+                let mut buf = [0u8; 8];
+                buf.copy_from_slice(bytes);
+                Self::Uint(u64::from_be_bytes(buf))
+            }
+            32 => Self::Address(super::account::encode_address(bytes)),
+            _ => Self::Binary(bytes.to_vec()),
+        }
+    }
+
+    /// Decode a Base64 value, returning `None` if it is not valid Base64.
+    #[must_use]
+    pub fn from_base64(b64: &str) -> Option<Self> {
+        use base64::Engine;
+        base64::engine::general_purpose::STANDARD
+            .decode(b64)
+            .ok()
+            .map(|bytes| Self::decode(&bytes))
+    }
+
+    /// Short label for the decoded kind ("text", "uint64", "address" or "hex").
+    #[must_use]
+    pub const fn kind(&self) -> &'static str {
+        match self {
+            Self::Text(_) => "text",
+            Self::Uint(_) => "uint64",
+            Self::Address(_) => "address",
+            Self::Binary(_) => "hex",
+        }
+    }
+}
+
+impl std::fmt::Display for StateBytes {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Text(text) => f.write_str(text),
+            Self::Uint(value) => write!(f, "{value}"),
+            Self::Address(address) => f.write_str(address),
+            Self::Binary(bytes) => write!(f, "0x{}", super::abi::hex(bytes)),
+        }
+    }
+}
+
+/// Decode a Base64 state key to UTF-8, falling back to a binary size marker.
+#[must_use]
+pub fn decode_state_key(b64: &str) -> String {
+    use base64::Engine;
+    let engine = base64::engine::general_purpose::STANDARD;
+    match engine.decode(b64) {
+        Ok(bytes) => {
+            let len = bytes.len();
+            String::from_utf8(bytes).unwrap_or_else(|_| format!("<binary:{len} bytes>"))
+        }
+        Err(_) => b64.to_string(),
+    }
+}
+
+/// Format a Base64 state byte value for display.
+///
+/// Printable values are shown as text, anything else as truncated Base64.
+#[must_use]
+pub fn format_state_bytes(b64: &str) -> String {
+    let decoded = decode_state_key(b64);
+    if decoded.chars().all(|c| c.is_ascii_graphic() || c == ' ') {
+        decoded
+    } else {
+        // Show truncated Base64 for binary data
+        let truncated = b64.chars().take(20).collect::<String>();
+        if b64.len() > 20 {
+            format!("{}...", truncated)
+        } else {
+            truncated
+        }
+    }
+}
+
 // ============================================================================
 // Tests
 // ============================================================================
@@ -173,4 +314,58 @@ mod tests {
         assert_eq!(state.value_type, "Bytes");
         assert_eq!(state.value, "ADDR123...");
     }
+
+    #[test]
+    fn test_state_encoding_helpers() {
+        // "counter"
+        assert_eq!(decode_state_key("Y291bnRlcg=="), "counter");
+        assert_eq!(decode_state_key("/w=="), "<binary:1 bytes>");
+        assert_eq!(format_state_bytes("aGVsbG8gd29ybGQ="), "hello world");
+        assert_eq!(
+            format_state_bytes("AAECAwQFBgcICQoLDA0ODxAREhMUFRYX"),
+            "AAECAwQFBgcICQoLDA0O..."
+        );
+        // State values are not guessed as integers; only box values are
+        assert_eq!(format_state_bytes("AAAAAAAAA+g="), "<binary:8 bytes>");
+    }
+
+    #[test]
+    fn test_state_bytes_decoding() {
+        let cases: [(&[u8], StateBytes, &str); 5] = [
+            (b"hello", StateBytes::Text("hello".to_string()), "hello"),
+            (&[0, 0, 0, 0, 0, 0, 0, 42], StateBytes::Uint(42), "42"),
+            // Printable 8-byte values stay text
+            (
+                b"counter1",
+                StateBytes::Text("counter1".to_string()),
+                "counter1",
+            ),
+            (
+                &[0xde, 0xad],
+                StateBytes::Binary(vec![0xde, 0xad]),
+                "0xdead",
+            ),
+            (&[], StateBytes::Text(String::new()), ""),
+        ];
+        for (bytes, expected, display) in cases {
+            let decoded = StateBytes::decode(bytes);
+            assert_eq!(decoded, expected);
+            assert_eq!(decoded.to_string(), display);
+        }
+
+        let address = StateBytes::decode(&[0u8; 32]);
+        assert_eq!(address.kind(), "address");
+        assert_eq!(
+            address.to_string(),
+            "AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAY5HFKQ"
+        );
+        assert_eq!(StateBytes::from_base64("not base64!"), None);
+    }
+
+    #[test]
+    fn test_app_box_display_name() {
+        // "b" prefix followed by binary data falls back to hex
+        assert_eq!(AppBox::new("YgAB").display_name(), "0x620001");
+        assert_eq!(AppBox::new("bmFtZQ==").display_name(), "name");
+    }
 }
@@ -3,6 +3,8 @@
 //! This module defines asset-related types including basic asset info
 //! for search results and detailed asset information for popups.
 
+use serde::Serialize;
+
 // ============================================================================
 // Asset Info
 // ============================================================================
@@ -10,7 +12,7 @@
 /// Basic asset info for search results display.
 ///
 /// Contains essential asset metadata for display in search results.
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct AssetInfo {
     /// The asset ID.
     pub id: u64,
@@ -102,7 +104,7 @@ impl AssetInfo {
 // ============================================================================
 
 /// Detailed asset information for popup display.
-#[derive(Debug, Clone, PartialEq, Default)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize)]
 pub struct AssetDetails {
     /// The asset ID.
     pub id: u64,
@@ -233,6 +235,47 @@ impl AssetDetails {
     }
 }
 
+// ============================================================================
+// Asset Holder
+// ============================================================================
+
+/// An account's holding of an asset, from the indexer balances listing.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize)]
+pub struct AssetHolder {
+    /// The holder's address.
+    pub address: String,
+    /// Amount held in base units.
+    pub amount: u64,
+    /// Whether the holding is frozen.
+    pub is_frozen: bool,
+    /// Round when the account opted in, if known.
+    pub opted_in_at_round: Option<u64>,
+}
+
+/// Formats a base-unit amount with the asset's decimals.
+///
+/// Uses integer arithmetic so large supplies keep full precision, e.g.
+/// `format_asset_amount(1_234_500, 6)` is `"1.234500"`.
+#[must_use]
+pub fn format_asset_amount(amount: u64, decimals: u64) -> String {
+    if decimals == 0 {
+        return amount.to_string();
+    }
+    let Some(divisor) = u32::try_from(decimals)
+        .ok()
+        .and_then(|d| 10u64.checked_pow(d))
+    else {
+        // More than 19 decimals: the whole part is always zero
+        return format!("0.{amount:0>width$}", width = decimals as usize);
+    };
+    format!(
+        "{}.{:0>width$}",
+        amount / divisor,
+        amount % divisor,
+        width = decimals as usize
+    )
+}
+
 // ============================================================================
 // Asset Params (for transaction parsing)
 // ============================================================================
@@ -240,7 +283,7 @@ impl AssetDetails {
 /// Asset parameters used in asset configuration transactions.
 ///
 /// This struct is used when parsing asset creation or modification transactions.
-#[derive(Debug, Clone, PartialEq, Default)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize)]
 #[allow(dead_code)]
 pub struct AssetParams {
     /// Total supply in base units.
@@ -304,6 +347,21 @@ impl AssetParams {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_format_asset_amount() {
+        let cases = [
+            (0, 0, "0"),
+            (42, 0, "42"),
+            (1_234_500, 6, "1.234500"),
+            (5, 6, "0.000005"),
+            (u64::MAX, 19, "1.8446744073709551615"),
+            (7, 20, "0.00000000000000000007"),
+        ];
+        for (amount, decimals, expected) in cases {
+            assert_eq!(format_asset_amount(amount, decimals), expected);
+        }
+    }
+
     #[test]
     fn test_asset_info_new() {
         let info = AssetInfo::new(
@@ -5,6 +5,9 @@
 
 use std::collections::HashMap;
 
+use serde::{Deserialize, Serialize};
+
+use super::page::Page;
 use super::transaction::{Transaction, TxnType};
 
 // ============================================================================
@@ -42,7 +45,7 @@ pub fn count_transactions(block: &serde_json::Value) -> u16 {
 /// Basic block information for list display.
 ///
 /// Contains essential block metadata for display in block lists.
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct AlgoBlock {
     /// Block number (round).
     pub id: u64,
@@ -82,7 +85,7 @@ impl AlgoBlock {
 /// Detailed block information for search results and popups.
 ///
 /// Contains more detailed block metadata including proposer information.
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct BlockInfo {
     /// Block number (round).
     pub id: u64,
@@ -131,14 +134,16 @@ impl BlockInfo {
 ///
 /// Contains complete block information with all transactions
 /// and aggregated statistics.
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct BlockDetails {
     /// Basic block info.
     pub info: BlockInfo,
     /// Transactions in this block.
     pub transactions: Vec<Transaction>,
-    /// Count of transactions by type.
+    /// Count of transactions by type (over the transactions loaded so far).
     pub txn_type_counts: HashMap<TxnType, usize>,
+    /// Indexer token for the next page of transactions, if more remain.
+    pub next_token: Option<String>,
 }
 
 impl BlockDetails {
@@ -165,7 +170,44 @@ impl BlockDetails {
             info,
             transactions,
             txn_type_counts,
+            next_token: None,
+        }
+    }
+
+    /// Create a new `BlockDetails` from the first page of block transactions.
+    ///
+    /// # Arguments
+    ///
+    /// * `info` - Basic block information
+    /// * `page` - First page of transactions in the block
+    ///
+    /// # Returns
+    ///
+    /// A new `BlockDetails` instance that remembers the next-page token.
+    #[must_use]
+    pub fn from_page(info: BlockInfo, page: Page<Transaction>) -> Self {
+        let mut details = Self::new(info, page.items);
+        details.next_token = page.next_token;
+        details
+    }
+
+    /// Appends the next page of transactions and updates the type counts.
+    ///
+    /// # Arguments
+    ///
+    /// * `page` - The page fetched with the current `next_token`
+    pub fn append_page(&mut self, page: Page<Transaction>) {
+        for txn in &page.items {
+            *self.txn_type_counts.entry(txn.txn_type).or_insert(0) += 1;
         }
+        self.transactions.extend(page.items);
+        self.next_token = page.next_token;
+    }
+
+    /// Returns true if more transactions remain to be fetched.
+    #[must_use]
+    pub fn has_more(&self) -> bool {
+        self.next_token.is_some()
     }
 
     /// Returns the total number of transactions in this block.
@@ -256,6 +298,32 @@ mod tests {
         assert_eq!(count_transactions(&empty_block), 0);
     }
 
+    #[test]
+    fn test_block_details_append_page() {
+        use crate::test_utils::{BlockMother, TransactionMother};
+
+        let info = BlockMother::info(12345, 3);
+        let first = Page::new(
+            vec![TransactionMother::payment("tx1")],
+            Some("token-1".to_string()),
+        );
+        let mut details = BlockDetails::from_page(info, first);
+        assert!(details.has_more());
+        assert_eq!(details.count_by_type(TxnType::Payment), 1);
+
+        details.append_page(Page::new(
+            vec![
+                TransactionMother::payment("tx2"),
+                TransactionMother::app_call("tx3", 42),
+            ],
+            None,
+        ));
+        assert!(!details.has_more());
+        assert_eq!(details.transaction_count(), 3);
+        assert_eq!(details.count_by_type(TxnType::Payment), 2);
+        assert_eq!(details.count_by_type(TxnType::AppCall), 1);
+    }
+
     #[test]
     fn test_block_details_count_by_type() {
         let info = BlockInfo::new(
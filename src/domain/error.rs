@@ -42,6 +42,10 @@ pub enum AlgoError {
     /// HTTP client initialization failed.
     #[error("Failed to initialize HTTP client: {0}")]
     ClientInit(String),
+
+    /// The feature needs an indexer, but the network is in algod-only mode.
+    #[error("{0} requires an indexer, which is unavailable in algod-only mode")]
+    IndexerUnavailable(&'static str),
 }
 
 impl AlgoError {
@@ -99,17 +103,28 @@ impl AlgoError {
         Self::ClientInit(message.into())
     }
 
+    /// Create a new indexer unavailable error.
+    ///
+    /// # Arguments
+    ///
+    /// * `feature` - The indexer-only feature that was requested
+    #[must_use]
+    pub fn indexer_unavailable(feature: &'static str) -> Self {
+        Self::IndexerUnavailable(feature)
+    }
+
     /// Convert to a `color_eyre::Report` for API compatibility.
     ///
     /// This method allows `AlgoError` to be used with color_eyre's error
-    /// handling infrastructure while preserving the error message.
+    /// handling infrastructure while preserving the error, so that callers
+    /// can still `downcast_ref::<AlgoError>()` it.
     ///
     /// # Returns
     ///
-    /// A `color_eyre::Report` containing the error message.
+    /// A `color_eyre::Report` wrapping the error.
     #[must_use = "this converts the error into a Report for display"]
     pub fn into_report(self) -> color_eyre::Report {
-        color_eyre::eyre::eyre!("{}", self)
+        color_eyre::Report::new(self)
     }
 }
 
@@ -0,0 +1,460 @@
+//! List exports to CSV and JSON Lines files.
+//!
+//! An [`ExportSource`] names one of the paged list views. Exports refetch
+//! the whole list page by page and turn each item into a record with
+//! decimal amounts and ISO-8601 timestamps.
+
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+use chrono::NaiveDateTime;
+
+use super::{AlgoError, AssetHolder, Transaction, TxnQuery, TxnType, format_asset_amount};
+use crate::constants::MICROALGOS_PER_ALGO_U64;
+
+/// Decimals of ALGO amounts (microAlgos).
+const ALGO_DECIMALS: u64 = MICROALGOS_PER_ALGO_U64.ilog10() as u64;
+
+/// Columns of a transaction export.
+pub const TRANSACTION_COLUMNS: &[&str] = &[
+    "id",
+    "round",
+    "timestamp",
+    "type",
+    "sender",
+    "receiver",
+    "asset_id",
+    "amount",
+    "amount_base_units",
+    "fee",
+    "group",
+];
+
+/// Columns of an asset holder export.
+pub const HOLDER_COLUMNS: &[&str] = &["address", "amount", "frozen", "opted_in_round"];
+
+// ============================================================================
+// Format
+// ============================================================================
+
+/// Output file format, chosen by the file extension.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ExportFormat {
+    /// Comma-separated values with a header row.
+    #[default]
+    Csv,
+    /// One JSON object per line.
+    JsonLines,
+}
+
+impl ExportFormat {
+    /// The file extension written for this format.
+    #[must_use]
+    pub const fn extension(self) -> &'static str {
+        match self {
+            Self::Csv => "csv",
+            Self::JsonLines => "jsonl",
+        }
+    }
+
+    /// The other format.
+    #[must_use]
+    pub const fn toggled(self) -> Self {
+        match self {
+            Self::Csv => Self::JsonLines,
+            Self::JsonLines => Self::Csv,
+        }
+    }
+
+    /// Picks the format from a path's extension (`.csv`, `.jsonl` or `.ndjson`).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AlgoError::InvalidInput`] for any other extension.
+    pub fn from_path(path: &str) -> Result<Self, AlgoError> {
+        let extension = Path::new(path)
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .map(str::to_ascii_lowercase);
+        match extension.as_deref() {
+            Some("csv") => Ok(Self::Csv),
+            Some("jsonl" | "ndjson") => Ok(Self::JsonLines),
+            _ => Err(AlgoError::invalid_input(
+                "Export path must end in .csv or .jsonl",
+            )),
+        }
+    }
+
+    /// Replaces the extension of `path` with this format's one.
+    #[must_use]
+    pub fn apply_to(self, path: &str) -> String {
+        let stem = match path.rfind('.') {
+            Some(dot) if !path[dot..].contains(['/', '\\']) => &path[..dot],
+            _ => path,
+        };
+        format!("{stem}.{}", self.extension())
+    }
+
+    /// The header line written before any record (CSV only).
+    #[must_use]
+    pub fn header(self, columns: &[&str]) -> String {
+        match self {
+            Self::Csv => {
+                let fields: Vec<String> = columns.iter().map(|c| csv_field(c)).collect();
+                format!("{}\n", fields.join(","))
+            }
+            Self::JsonLines => String::new(),
+        }
+    }
+
+    /// One record as a line of output, including the trailing newline.
+    #[must_use]
+    pub fn record(self, columns: &[&str], values: &[ExportValue]) -> String {
+        match self {
+            Self::Csv => {
+                let fields: Vec<String> =
+                    values.iter().map(|v| csv_field(&v.to_string())).collect();
+                format!("{}\n", fields.join(","))
+            }
+            Self::JsonLines => {
+                // Built by hand so keys keep the column order
+                let members: Vec<String> = columns
+                    .iter()
+                    .zip(values)
+                    .map(|(column, value)| {
+                        format!("{}:{}", serde_json::Value::from(*column), value.to_json())
+                    })
+                    .collect();
+                format!("{{{}}}\n", members.join(","))
+            }
+        }
+    }
+}
+
+/// Quotes a CSV field if it contains a separator, quote or line break.
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+// ============================================================================
+// Values
+// ============================================================================
+
+/// One exported field.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ExportValue {
+    /// Free text; decimal amounts are text too so they keep full precision.
+    Text(String),
+    /// A whole number such as a round or an ID.
+    Integer(u64),
+    /// A flag.
+    Bool(bool),
+    /// Not applicable to this record.
+    Empty,
+}
+
+impl ExportValue {
+    fn to_json(&self) -> serde_json::Value {
+        match self {
+            Self::Text(text) => serde_json::Value::from(text.as_str()),
+            Self::Integer(n) => serde_json::Value::from(*n),
+            Self::Bool(b) => serde_json::Value::from(*b),
+            Self::Empty => serde_json::Value::Null,
+        }
+    }
+}
+
+impl fmt::Display for ExportValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Text(text) => f.write_str(text),
+            Self::Integer(n) => write!(f, "{n}"),
+            Self::Bool(b) => write!(f, "{b}"),
+            Self::Empty => Ok(()),
+        }
+    }
+}
+
+impl From<Option<String>> for ExportValue {
+    fn from(value: Option<String>) -> Self {
+        value.map_or(Self::Empty, Self::Text)
+    }
+}
+
+impl From<Option<u64>> for ExportValue {
+    fn from(value: Option<u64>) -> Self {
+        value.map_or(Self::Empty, Self::Integer)
+    }
+}
+
+/// Converts a timestamp as shown in the UI (UTC) to ISO-8601.
+///
+/// Returns `None` when the timestamp is not available.
+#[must_use]
+pub fn iso_timestamp(timestamp: &str) -> Option<String> {
+    NaiveDateTime::parse_from_str(timestamp, "%a, %d %b %Y %H:%M:%S")
+        .ok()
+        .map(|datetime| datetime.format("%Y-%m-%dT%H:%M:%SZ").to_string())
+}
+
+/// The export record for a transaction.
+///
+/// Payments are in ALGO and asset transfers use `asset_decimals`; the raw
+/// amount is always in `amount_base_units`, and `amount` is left empty when
+/// an asset's decimals are unknown. Other types have no amount.
+#[must_use]
+pub fn transaction_record(txn: &Transaction, asset_decimals: Option<u64>) -> Vec<ExportValue> {
+    let (amount, base_units) = match txn.txn_type {
+        TxnType::Payment => (
+            Some(format_asset_amount(txn.amount, ALGO_DECIMALS)),
+            Some(txn.amount),
+        ),
+        TxnType::AssetTransfer => (
+            asset_decimals.map(|decimals| format_asset_amount(txn.amount, decimals)),
+            Some(txn.amount),
+        ),
+        _ => (None, None),
+    };
+    vec![
+        ExportValue::Text(txn.id.clone()),
+        ExportValue::Integer(txn.block),
+        iso_timestamp(&txn.timestamp).into(),
+        txn.txn_type.api_code().map(str::to_string).into(),
+        ExportValue::Text(txn.from.clone()),
+        Some(txn.to.clone()).filter(|to| !to.is_empty()).into(),
+        txn.asset_id.into(),
+        amount.into(),
+        base_units.into(),
+        ExportValue::Text(format_asset_amount(txn.fee, ALGO_DECIMALS)),
+        txn.group.clone().into(),
+    ]
+}
+
+/// The export record for an asset holder, with the amount in whole units.
+#[must_use]
+pub fn holder_record(holder: &AssetHolder, decimals: u64) -> Vec<ExportValue> {
+    vec![
+        ExportValue::Text(holder.address.clone()),
+        ExportValue::Text(format_asset_amount(holder.amount, decimals)),
+        ExportValue::Bool(holder.is_frozen),
+        holder.opted_in_at_round.into(),
+    ]
+}
+
+// ============================================================================
+// Source
+// ============================================================================
+
+/// A paged list view that can be exported.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ExportSource {
+    /// All transactions in a block.
+    BlockTransactions {
+        /// Block round.
+        round: u64,
+    },
+    /// An account's transaction history.
+    AccountHistory {
+        /// Account address.
+        address: String,
+        /// Transaction type filter of the History tab.
+        txn_type: Option<TxnType>,
+    },
+    /// Holders of an asset.
+    AssetHolders {
+        /// Asset ID.
+        asset_id: u64,
+        /// Asset decimals, for normalizing amounts.
+        decimals: u64,
+        /// Only holders with more than this many base units.
+        min_balance: Option<u64>,
+    },
+    /// Advanced transaction search results.
+    TxnSearch(TxnQuery),
+}
+
+impl ExportSource {
+    /// Whether the list can only be read from an indexer.
+    ///
+    /// Block transactions can also be decoded from the block itself.
+    #[must_use]
+    pub const fn requires_indexer(&self) -> bool {
+        !matches!(self, Self::BlockTransactions { .. })
+    }
+
+    /// The columns of each exported record.
+    #[must_use]
+    pub const fn columns(&self) -> &'static [&'static str] {
+        match self {
+            Self::AssetHolders { .. } => HOLDER_COLUMNS,
+            _ => TRANSACTION_COLUMNS,
+        }
+    }
+
+    /// A suggested file name for the export, in the current directory.
+    #[must_use]
+    pub fn default_path(&self, format: ExportFormat) -> String {
+        let name = match self {
+            Self::BlockTransactions { round } => format!("block_{round}_txns"),
+            Self::AccountHistory { address, .. } => {
+                format!("{}_history", &address[..address.len().min(8)])
+            }
+            Self::AssetHolders { asset_id, .. } => format!("asset_{asset_id}_holders"),
+            Self::TxnSearch(_) => "search".to_string(),
+        };
+        format!("lazylora_{name}.{}", format.extension())
+    }
+}
+
+impl fmt::Display for ExportSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::BlockTransactions { round } => write!(f, "Block #{round} transactions"),
+            Self::AccountHistory { address, txn_type } => {
+                write!(f, "History of {}", &address[..address.len().min(8)])?;
+                match txn_type {
+                    Some(txn_type) => write!(f, "... ({})", txn_type.as_str()),
+                    None => write!(f, "..."),
+                }
+            }
+            Self::AssetHolders { asset_id, .. } => write!(f, "Holders of asset {asset_id}"),
+            Self::TxnSearch(query) => write!(f, "Search results ({query})"),
+        }
+    }
+}
+
+/// Expands a leading `~/` to the home directory.
+#[must_use]
+pub fn expand_path(path: &str) -> PathBuf {
+    match (path.strip_prefix("~/"), dirs::home_dir()) {
+        (Some(rest), Some(home)) => home.join(rest),
+        _ => PathBuf::from(path),
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::TransactionMother;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case::csv("out.csv", Some(ExportFormat::Csv))]
+    #[case::upper("OUT.CSV", Some(ExportFormat::Csv))]
+    #[case::jsonl("dir/out.jsonl", Some(ExportFormat::JsonLines))]
+    #[case::ndjson("out.ndjson", Some(ExportFormat::JsonLines))]
+    #[case::json("out.json", None)]
+    #[case::none("out", None)]
+    fn test_format_from_path(#[case] path: &str, #[case] expected: Option<ExportFormat>) {
+        assert_eq!(ExportFormat::from_path(path).ok(), expected);
+    }
+
+    #[rstest]
+    #[case::swap("out.csv", ExportFormat::JsonLines, "out.jsonl")]
+    #[case::add("out", ExportFormat::Csv, "out.csv")]
+    #[case::dotted_dir("./a.b/out", ExportFormat::Csv, "./a.b/out.csv")]
+    fn test_apply_extension(
+        #[case] path: &str,
+        #[case] format: ExportFormat,
+        #[case] expected: &str,
+    ) {
+        assert_eq!(format.apply_to(path), expected);
+    }
+
+    #[test]
+    fn test_iso_timestamp() {
+        let shown = crate::domain::format_timestamp(1_700_000_000);
+        assert_eq!(
+            iso_timestamp(&shown).as_deref(),
+            Some("2023-11-14T22:13:20Z")
+        );
+        assert_eq!(iso_timestamp("Timestamp not available"), None);
+    }
+
+    #[test]
+    fn test_transaction_records() {
+        let payment = TransactionMother::payment("PAYID");
+        let record = transaction_record(&payment, None);
+        assert_eq!(record.len(), TRANSACTION_COLUMNS.len());
+        assert_eq!(record[3], ExportValue::Text("pay".to_string()));
+        assert_eq!(
+            record[7],
+            ExportValue::Text(format_asset_amount(payment.amount, 6))
+        );
+        assert_eq!(record[8], ExportValue::Integer(payment.amount));
+
+        let transfer = TransactionMother::asset_transfer("AXFERID", 31566704, 2_500);
+        let record = transaction_record(&transfer, Some(2));
+        assert_eq!(record[6], ExportValue::Integer(31566704));
+        assert_eq!(record[7], ExportValue::Text("25.00".to_string()));
+        assert_eq!(record[8], ExportValue::Integer(2_500));
+
+        // Unknown decimals leave the normalized amount empty, not in base units
+        let record = transaction_record(&transfer, None);
+        assert_eq!(record[7], ExportValue::Empty);
+        assert_eq!(record[8], ExportValue::Integer(2_500));
+    }
+
+    #[test]
+    fn test_csv_output() {
+        let columns = ["name", "note", "n", "flag", "none"];
+        let values = [
+            ExportValue::Text("a,b".to_string()),
+            ExportValue::Text("say \"hi\"".to_string()),
+            ExportValue::Integer(7),
+            ExportValue::Bool(true),
+            ExportValue::Empty,
+        ];
+        assert_eq!(
+            ExportFormat::Csv.header(&columns),
+            "name,note,n,flag,none\n"
+        );
+        assert_eq!(
+            ExportFormat::Csv.record(&columns, &values),
+            "\"a,b\",\"say \"\"hi\"\"\",7,true,\n"
+        );
+    }
+
+    #[test]
+    fn test_json_lines_output() {
+        let holder = AssetHolder {
+            address: "HOLDER".to_string(),
+            amount: 1_234_500,
+            is_frozen: false,
+            opted_in_at_round: None,
+        };
+        let format = ExportFormat::JsonLines;
+        assert_eq!(format.header(HOLDER_COLUMNS), "");
+        assert_eq!(
+            format.record(HOLDER_COLUMNS, &holder_record(&holder, 6)),
+            "{\"address\":\"HOLDER\",\"amount\":\"1.234500\",\"frozen\":false,\"opted_in_round\":null}\n"
+        );
+    }
+
+    #[test]
+    fn test_default_paths() {
+        let source = ExportSource::BlockTransactions { round: 42 };
+        assert_eq!(
+            source.default_path(ExportFormat::Csv),
+            "lazylora_block_42_txns.csv"
+        );
+        let source = ExportSource::AssetHolders {
+            asset_id: 7,
+            decimals: 0,
+            min_balance: None,
+        };
+        assert_eq!(source.columns(), HOLDER_COLUMNS);
+        assert_eq!(
+            source.default_path(ExportFormat::JsonLines),
+            "lazylora_asset_7_holders.jsonl"
+        );
+    }
+}
@@ -0,0 +1,91 @@
+//! Atomic transaction group types.
+//!
+//! Transactions submitted together as an atomic group share a group ID and
+//! are confirmed in the same round, all or nothing.
+
+use std::collections::BTreeMap;
+
+use super::{Transaction, TxnType};
+
+/// The members of an atomic transaction group, in group order.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TxnGroup {
+    /// Group ID (Base64 encoded).
+    pub id: String,
+    /// Round the group was confirmed in.
+    pub round: u64,
+    /// Member transactions in the order they appear in the group.
+    pub transactions: Vec<Transaction>,
+}
+
+impl TxnGroup {
+    /// Creates a group from its members.
+    #[must_use]
+    pub const fn new(id: String, round: u64, transactions: Vec<Transaction>) -> Self {
+        Self {
+            id,
+            round,
+            transactions,
+        }
+    }
+
+    /// Total fees paid by the members, in microAlgos.
+    #[must_use]
+    pub fn total_fees(&self) -> u64 {
+        self.transactions.iter().map(|txn| txn.fee).sum()
+    }
+
+    /// Total microAlgos moved by the members' payments.
+    ///
+    /// Inner transactions are not counted; only the top-level members.
+    #[must_use]
+    pub fn total_algos(&self) -> u64 {
+        self.transactions
+            .iter()
+            .filter(|txn| txn.txn_type == TxnType::Payment)
+            .map(|txn| txn.amount)
+            .sum()
+    }
+
+    /// Total base units moved by the members' asset transfers, per asset ID.
+    #[must_use]
+    pub fn asset_totals(&self) -> BTreeMap<u64, u64> {
+        let mut totals = BTreeMap::new();
+        for txn in &self.transactions {
+            if txn.txn_type == TxnType::AssetTransfer
+                && let Some(asset_id) = txn.asset_id
+            {
+                *totals.entry(asset_id).or_insert(0) += txn.amount;
+            }
+        }
+        totals
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::TransactionMother;
+
+    #[test]
+    fn test_group_totals() {
+        let group = TxnGroup::new(
+            "GROUP".to_string(),
+            100,
+            vec![
+                TransactionMother::payment("A"),
+                TransactionMother::asset_transfer("B", 31566704, 250),
+                TransactionMother::asset_transfer("C", 31566704, 750),
+                TransactionMother::asset_transfer("D", 7, 1),
+                TransactionMother::app_call("E", 123),
+            ],
+        );
+
+        assert_eq!(group.total_fees(), 5_000);
+        assert_eq!(group.total_algos(), 1_000_000);
+        assert_eq!(
+            group.asset_totals().into_iter().collect::<Vec<_>>(),
+            vec![(7, 1), (31566704, 1_000)]
+        );
+    }
+}
@@ -0,0 +1,320 @@
+//! Application call log decoding.
+//!
+//! Application calls may emit logs via the `log` opcode. Besides arbitrary
+//! bytes, two conventions are recognised:
+//!
+//! - ABI return values, prefixed with `0x151f7c75` (ARC-4)
+//! - ARC-28 events, prefixed with the 4-byte selector of the event signature
+//!
+//! Events are only decoded when their signature is known to an [`EventRegistry`].
+
+use std::sync::LazyLock;
+
+use base64::{Engine as _, engine::general_purpose::STANDARD as BASE64};
+
+use super::abi::{self, AbiType, AbiValue};
+use super::error::AlgoError;
+
+/// Prefix of a log carrying an ABI method return value.
+pub const ABI_RETURN_PREFIX: [u8; 4] = [0x15, 0x1f, 0x7c, 0x75];
+
+/// Event signatures from widely deployed ARCs, decoded without any app spec.
+const KNOWN_EVENT_SIGNATURES: &[&str] = &[
+    // ARC-200 fungible tokens
+    "arc200_Transfer(address,address,uint256)",
+    "arc200_Approval(address,address,uint256)",
+    // ARC-72 non-fungible tokens
+    "arc72_Transfer(address,address,uint256)",
+    "arc72_Approval(address,address,uint256)",
+    "arc72_ApprovalForAll(address,address,bool)",
+];
+
+/// Registry of the built-in event signatures.
+static KNOWN_EVENTS: LazyLock<EventRegistry> = LazyLock::new(|| {
+    let mut registry = EventRegistry::default();
+    for signature in KNOWN_EVENT_SIGNATURES {
+        // Built-in signatures are valid by construction
+        let _ = registry.register(signature);
+    }
+    registry
+});
+
+// ============================================================================
+// Log Value
+// ============================================================================
+
+/// Raw log bytes rendered in the most readable form available.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LogValue {
+    /// Printable UTF-8 text.
+    Utf8(String),
+    /// Exactly eight bytes, shown as a big-endian uint64.
+    Uint64(u64),
+    /// Anything else, shown as hex.
+    Hex(Vec<u8>),
+}
+
+impl LogValue {
+    /// Classify raw log bytes.
+    ///
+    /// Text wins over uint64 so that 8-character strings stay readable.
+    #[must_use]
+    pub fn from_bytes(bytes: &[u8]) -> Self {
+        if let Ok(text) = std::str::from_utf8(bytes)
+            && !text.is_empty()
+            && text
+                .chars()
+                .all(|c| !c.is_control() || c == '\n' || c == '\t')
+        {
+            return Self::Utf8(text.to_string());
+        }
+        if let Ok(raw) = <[u8; 8]>::try_from(bytes) {
+            return Self::Uint64(u64::from_be_bytes(raw));
+        }
+        Self::Hex(bytes.to_vec())
+    }
+}
+
+impl std::fmt::Display for LogValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Utf8(text) => write!(f, "{text:?}"),
+            Self::Uint64(value) => write!(f, "{value}"),
+            Self::Hex(bytes) if bytes.is_empty() => write!(f, "(empty)"),
+            Self::Hex(bytes) => write!(f, "0x{}", abi::hex(bytes)),
+        }
+    }
+}
+
+// ============================================================================
+// Decoded Log
+// ============================================================================
+
+/// A single application log after decoding.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DecodedLog {
+    /// An ABI method return value (log prefixed with [`ABI_RETURN_PREFIX`]).
+    Return(LogValue),
+    /// An ARC-28 event with a known signature.
+    Event {
+        /// Event name.
+        name: String,
+        /// Decoded arguments, in signature order.
+        args: Vec<AbiValue>,
+    },
+    /// A log that matched no known convention.
+    Raw(LogValue),
+}
+
+impl DecodedLog {
+    /// Decode a base64 log entry as returned by the indexer.
+    ///
+    /// Logs that are not valid base64 are kept as raw text.
+    #[must_use]
+    #[allow(dead_code)] // Public API for decoding without an app spec
+    pub fn from_base64(log: &str) -> Self {
+        Self::from_base64_with(log, &KNOWN_EVENTS)
+    }
+
+    /// Decode a base64 log entry, matching events against `registry`.
+    #[must_use]
+    pub fn from_base64_with(log: &str, registry: &EventRegistry) -> Self {
+        match BASE64.decode(log) {
+            Ok(bytes) => registry.decode(&bytes),
+            Err(_) => Self::Raw(LogValue::Utf8(log.to_string())),
+        }
+    }
+
+    /// Short label for the kind of log, used in detail views.
+    #[must_use]
+    pub const fn kind(&self) -> &'static str {
+        match self {
+            Self::Return(_) => "return",
+            Self::Event { .. } => "event",
+            Self::Raw(_) => "log",
+        }
+    }
+}
+
+impl std::fmt::Display for DecodedLog {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Return(value) | Self::Raw(value) => write!(f, "{value}"),
+            Self::Event { name, args } => {
+                let args: Vec<String> = args.iter().map(ToString::to_string).collect();
+                write!(f, "{name}({})", args.join(", "))
+            }
+        }
+    }
+}
+
+// ============================================================================
+// Event Registry
+// ============================================================================
+
+/// An ARC-28 event definition.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EventSignature {
+    /// Event name.
+    pub name: String,
+    /// Argument types, in order.
+    pub args: Vec<AbiType>,
+    /// 4-byte selector that prefixes the event's log.
+    pub selector: [u8; 4],
+}
+
+impl EventSignature {
+    /// Parse an event signature such as `Transfer(address,address,uint64)`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `AlgoError::Parse` if the signature or its argument types are invalid.
+    pub fn parse(signature: &str) -> Result<Self, AlgoError> {
+        let signature = signature.trim();
+        let open = signature
+            .find('(')
+            .filter(|_| signature.ends_with(')'))
+            .ok_or_else(|| AlgoError::parse(format!("invalid event signature: {signature}")))?;
+        let name = signature[..open].to_string();
+        let AbiType::Tuple(args) = AbiType::parse(&signature[open..])? else {
+            return Err(AlgoError::parse(format!(
+                "invalid event signature: {signature}"
+            )));
+        };
+
+        Ok(Self {
+            name,
+            args,
+            selector: abi::selector(signature),
+        })
+    }
+}
+
+/// A set of known ARC-28 event signatures, matched by selector.
+#[derive(Debug, Clone, Default)]
+pub struct EventRegistry {
+    events: Vec<EventSignature>,
+}
+
+impl EventRegistry {
+    /// Returns a registry holding the built-in ARC-200 and ARC-72 events.
+    #[must_use]
+    pub fn known() -> Self {
+        KNOWN_EVENTS.clone()
+    }
+
+    /// Add an event signature to the registry.
+    ///
+    /// # Errors
+    ///
+    /// Returns `AlgoError::Parse` if the signature is invalid.
+    pub fn register(&mut self, signature: &str) -> Result<(), AlgoError> {
+        let event = EventSignature::parse(signature)?;
+        if !self.events.iter().any(|e| e.selector == event.selector) {
+            self.events.push(event);
+        }
+        Ok(())
+    }
+
+    /// Decode raw log bytes against the ABI return prefix and known events.
+    #[must_use]
+    pub fn decode(&self, bytes: &[u8]) -> DecodedLog {
+        let Some((prefix, payload)) = bytes.split_first_chunk::<4>() else {
+            return DecodedLog::Raw(LogValue::from_bytes(bytes));
+        };
+
+        if *prefix == ABI_RETURN_PREFIX {
+            return DecodedLog::Return(LogValue::from_bytes(payload));
+        }
+
+        // Only report an event when the payload decodes cleanly, so that a
+        // selector collision with arbitrary bytes falls back to the raw log.
+        self.events
+            .iter()
+            .filter(|event| event.selector == *prefix)
+            .find_map(
+                |event| match abi::decode(&AbiType::Tuple(event.args.clone()), payload) {
+                    Ok(AbiValue::Tuple(args)) => Some(DecodedLog::Event {
+                        name: event.name.clone(),
+                        args,
+                    }),
+                    _ => None,
+                },
+            )
+            .unwrap_or_else(|| DecodedLog::Raw(LogValue::from_bytes(bytes)))
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case::text(b"hello world".to_vec(), LogValue::Utf8("hello world".to_string()))]
+    #[case::uint64(vec![0, 0, 0, 0, 0, 0, 0x03, 0xe8], LogValue::Uint64(1000))]
+    #[case::eight_char_text(b"abcdefgh".to_vec(), LogValue::Utf8("abcdefgh".to_string()))]
+    #[case::binary(vec![0xde, 0xad, 0xbe], LogValue::Hex(vec![0xde, 0xad, 0xbe]))]
+    fn test_log_value_from_bytes(#[case] bytes: Vec<u8>, #[case] expected: LogValue) {
+        assert_eq!(LogValue::from_bytes(&bytes), expected);
+    }
+
+    #[test]
+    fn test_decode_abi_return() {
+        let mut bytes = ABI_RETURN_PREFIX.to_vec();
+        bytes.extend_from_slice(&42u64.to_be_bytes());
+
+        let decoded = DecodedLog::from_base64(&BASE64.encode(bytes));
+
+        assert_eq!(decoded, DecodedLog::Return(LogValue::Uint64(42)));
+        assert_eq!(decoded.to_string(), "42");
+    }
+
+    #[test]
+    fn test_decode_known_event() {
+        let signature = "arc200_Transfer(address,address,uint256)";
+        let mut bytes = abi::selector(signature).to_vec();
+        bytes.extend_from_slice(&[0u8; 32]);
+        bytes.extend_from_slice(&[0u8; 32]);
+        let mut amount = [0u8; 32];
+        amount[31] = 5;
+        bytes.extend_from_slice(&amount);
+
+        let decoded = DecodedLog::from_base64(&BASE64.encode(bytes));
+
+        let DecodedLog::Event { name, args } = &decoded else {
+            panic!("expected event, got {decoded:?}");
+        };
+        assert_eq!(name, "arc200_Transfer");
+        assert_eq!(args[2], AbiValue::Uint(5));
+        assert_eq!(decoded.kind(), "event");
+    }
+
+    #[test]
+    fn test_registry_falls_back_to_raw_on_bad_payload() {
+        let mut registry = EventRegistry::default();
+        registry.register("Ping(uint64)").unwrap();
+        let mut bytes = abi::selector("Ping(uint64)").to_vec();
+        bytes.push(0x01); // too short for a uint64
+
+        assert!(matches!(registry.decode(&bytes), DecodedLog::Raw(_)));
+
+        bytes.extend_from_slice(&[0; 7]);
+        assert_eq!(
+            registry.decode(&bytes).to_string(),
+            "Ping(72057594037927936)"
+        );
+    }
+
+    #[test]
+    fn test_invalid_base64_is_kept_as_text() {
+        assert_eq!(
+            DecodedLog::from_base64("not base64!"),
+            DecodedLog::Raw(LogValue::Utf8("not base64!".to_string()))
+        );
+    }
+}
@@ -5,26 +5,52 @@
 //!
 //! # Module Organization
 //!
+//! - [`abi`] - ARC-4 ABI types and value decoding
+//! - [`app_spec`] - ARC-4/ARC-56 app specs and method call decoding
 //! - [`error`] - Custom error types for Algorand operations
+//! - [`export`] - CSV and JSON Lines list exports
 //! - [`network`] - Network configuration (MainNet, TestNet, LocalNet)
 //! - [`transaction`] - Transaction types and details
 //! - [`block`] - Block types and information
+//! - [`group`] - Atomic transaction groups
 //! - [`account`] - Account types and details
 //! - [`asset`] - Asset types and details
 //! - [`nfd`] - NFD (Non-Fungible Domain) types
+//! - [`page`] - Paginated indexer results
+//! - [`teal`] - TEAL bytecode disassembler
+//! - [`logs`] - Application log and ARC-28 event decoding
+//! - [`msgpack`] - Algorand canonical msgpack decoding
+//! - [`signature`] - Transaction signature types (single, multisig, logicsig)
+//! - [`simulate`] - Transaction group simulation
+//! - [`txn_filter`] - Live transaction feed filters
+//! - [`txn_query`] - Advanced transaction search queries
+//! - [`watchlist`] - Watched accounts, assets and applications
 
 // ============================================================================
 // Module Declarations
 // ============================================================================
 
+pub mod abi;
 pub mod account;
+pub mod app_spec;
 pub mod application;
 pub mod asset;
 pub mod block;
 pub mod error;
+pub mod export;
+pub mod group;
+pub mod logs;
+pub mod msgpack;
 pub mod network;
 pub mod nfd;
+pub mod page;
+pub mod signature;
+pub mod simulate;
+pub mod teal;
 pub mod transaction;
+pub mod txn_filter;
+pub mod txn_query;
+pub mod watchlist;
 
 // ============================================================================
 // Re-exports
@@ -34,16 +60,19 @@ pub mod transaction;
 pub use error::AlgoError;
 
 // Network types
-pub use network::{CustomNetwork, Network, NetworkConfig};
+pub use network::{CustomNetwork, DataSource, Network, NetworkConfig};
 
 // Transaction types
 #[allow(unused_imports)] // OnComplete used by tests in client/algo.rs
 pub use transaction::{
     AppCallDetails, AssetConfigDetails, AssetFreezeDetails, AssetTransferDetails, HeartbeatDetails,
-    KeyRegDetails, OnComplete, PaymentDetails, StateProofDetails, Transaction, TransactionDetails,
-    TxnType, format_timestamp,
+    KeyRegDetails, LocalStateDelta, OnComplete, PaymentDetails, StateDelta, StateDeltaAction,
+    StateProofDetails, Transaction, TransactionDetails, TxnType, format_timestamp,
 };
 
+// Atomic group types
+pub use group::TxnGroup;
+
 // Block types
 pub use block::{AlgoBlock, BlockDetails, BlockInfo, count_transactions};
 
@@ -54,14 +83,47 @@ pub use account::{
 };
 
 // Application types
-pub use application::{AppStateValue, ApplicationDetails, ApplicationInfo};
+pub use application::{AppBox, AppStateValue, ApplicationDetails, ApplicationInfo, StateBytes};
 
 // Asset types
-pub use asset::{AssetDetails, AssetInfo};
+pub use asset::{AssetDetails, AssetHolder, AssetInfo, format_asset_amount};
 
 // NFD types
 pub use nfd::NfdInfo;
 
+// Pagination types
+pub use page::Page;
+
+// TEAL disassembly
+pub use teal::disassemble_base64;
+
+// Application logs
+pub use logs::DecodedLog;
+
+// Canonical msgpack types
+pub use msgpack::{MsgpBlock, PendingTransaction, PendingTransactions, SignedTxn};
+
+// App specs
+pub use app_spec::{AppSpecRegistry, DecodedCall};
+
+// Signature types
+pub use signature::TransactionSignature;
+
+// Transaction group simulation
+pub use simulate::{SimulatedTxn, Simulation};
+
+// Transaction feed filters
+pub use txn_filter::TxnFilter;
+
+// List exports
+pub use export::{ExportFormat, ExportSource};
+
+// Advanced transaction search
+pub use txn_query::{AddressRole, TxnQuery};
+
+// Watchlist types
+pub use watchlist::{WatchStatus, WatchTarget};
+
 // ============================================================================
 // Search Result Types
 // ============================================================================
@@ -0,0 +1,1222 @@
+//! Algorand canonical msgpack decoding.
+//!
+//! algod can answer block, pending transaction and raw transaction requests
+//! with `?format=msgpack`, which is far cheaper to decode than JSON for busy
+//! blocks. The node encodes everything canonically (sorted keys, minimal
+//! integer widths, zero values omitted), so re-encoding a decoded value
+//! reproduces the node's bytes exactly and transaction IDs can be computed
+//! from them.
+//!
+//! Decoded transactions are converted into the indexer JSON shape and parsed
+//! with [`Transaction::from_json`], so detail views work the same whichever
+//! source a transaction came from.
+
+use base64::{Engine as _, engine::general_purpose::STANDARD as BASE64};
+use rmpv::Value;
+use serde_json::{Map, Value as JsonValue, json};
+use sha2::{Digest, Sha512_256};
+
+use super::account::encode_address;
+use super::block::{AlgoBlock, BlockInfo};
+use super::error::AlgoError;
+use super::simulate::{SimulatedTxn, Simulation};
+use super::transaction::{Transaction, format_timestamp};
+
+/// Domain separation prefix hashed in front of a transaction to form its ID.
+const TXID_PREFIX: &[u8] = b"TX";
+
+// ============================================================================
+// Encoding
+// ============================================================================
+
+/// Decode a single msgpack value, rejecting trailing bytes.
+///
+/// # Errors
+///
+/// Returns `AlgoError::Parse` if the bytes are not exactly one msgpack value.
+pub fn decode(bytes: &[u8]) -> Result<Value, AlgoError> {
+    let mut reader = bytes;
+    let value = rmpv::decode::read_value(&mut reader)
+        .map_err(|e| AlgoError::parse(format!("Invalid msgpack: {e}")))?;
+    if !reader.is_empty() {
+        return Err(AlgoError::parse(format!(
+            "Invalid msgpack: {} trailing bytes",
+            reader.len()
+        )));
+    }
+    Ok(value)
+}
+
+/// Decode a sequence of concatenated msgpack values, as written by `goal`
+/// for transaction group files.
+///
+/// # Errors
+///
+/// Returns `AlgoError::Parse` if any value is malformed.
+pub fn decode_all(bytes: &[u8]) -> Result<Vec<Value>, AlgoError> {
+    let mut reader = bytes;
+    let mut values = Vec::new();
+    while !reader.is_empty() {
+        let value = rmpv::decode::read_value(&mut reader)
+            .map_err(|e| AlgoError::parse(format!("Invalid msgpack: {e}")))?;
+        values.push(value);
+    }
+    Ok(values)
+}
+
+/// Encode a value with the minimal encodings used by the node.
+#[must_use]
+pub fn encode(value: &Value) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    // Writing to a Vec cannot fail
+    let _ = rmpv::encode::write_value(&mut bytes, value);
+    bytes
+}
+
+/// Look up a string key in a msgpack map, returning `Nil` when absent.
+fn get<'a>(value: &'a Value, key: &str) -> &'a Value {
+    const NIL: &Value = &Value::Nil;
+    value
+        .as_map()
+        .and_then(|map| {
+            map.iter()
+                .find(|(k, _)| k.as_str() == Some(key))
+                .map(|(_, v)| v)
+        })
+        .unwrap_or(NIL)
+}
+
+/// Insert or replace a key, keeping the map sorted as canonical encoding requires.
+fn set(value: &mut Value, key: &str, field: Value) {
+    let Value::Map(map) = value else {
+        return;
+    };
+    map.retain(|(k, _)| k.as_str() != Some(key));
+    map.push((Value::from(key), field));
+    map.sort_by(|(a, _), (b, _)| a.as_str().cmp(&b.as_str()));
+}
+
+fn bytes_of(value: &Value) -> Option<&[u8]> {
+    match value {
+        Value::Binary(bytes) => Some(bytes),
+        Value::String(s) => Some(s.as_bytes()),
+        _ => None,
+    }
+}
+
+fn base64_of(value: &Value) -> Option<String> {
+    bytes_of(value).map(|bytes| BASE64.encode(bytes))
+}
+
+fn address_of(value: &Value) -> Option<String> {
+    bytes_of(value)
+        .filter(|bytes| bytes.len() == 32)
+        .map(encode_address)
+}
+
+// ============================================================================
+// Signed Transactions
+// ============================================================================
+
+/// A signed transaction decoded from canonical msgpack.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SignedTxn {
+    /// The full signed transaction map (`txn`, `sig`/`msig`/`lsig`, `sgnr`).
+    value: Value,
+}
+
+impl SignedTxn {
+    /// Decode a signed transaction from its msgpack bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns `AlgoError::Parse` if the bytes are not a signed transaction.
+    #[allow(dead_code)] // Part of the msgpack API
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, AlgoError> {
+        Self::from_value(decode(bytes)?)
+    }
+
+    /// Wrap a decoded signed transaction map.
+    ///
+    /// # Errors
+    ///
+    /// Returns `AlgoError::Parse` if the value has no `txn` map.
+    pub fn from_value(value: Value) -> Result<Self, AlgoError> {
+        if get(&value, "txn").as_map().is_none() {
+            return Err(AlgoError::parse("Signed transaction missing 'txn'"));
+        }
+        Ok(Self { value })
+    }
+
+    /// Wrap an entry from a transaction file: a signed transaction, or a
+    /// bare unsigned transaction as the SDKs write them.
+    ///
+    /// # Errors
+    ///
+    /// Returns `AlgoError::Parse` if the value is neither.
+    pub fn from_file_value(value: Value) -> Result<Self, AlgoError> {
+        if get(&value, "txn").as_map().is_some() {
+            return Ok(Self { value });
+        }
+        if get(&value, "type").as_str().is_some() {
+            return Ok(Self {
+                value: Value::Map(vec![(Value::from("txn"), value)]),
+            });
+        }
+        Err(AlgoError::parse("File entry is not a transaction"))
+    }
+
+    /// Returns the transaction fields.
+    #[must_use]
+    pub fn txn(&self) -> &Value {
+        get(&self.value, "txn")
+    }
+
+    /// Returns the canonical bytes of the signed transaction.
+    #[allow(dead_code)] // Part of the msgpack API
+    #[must_use]
+    pub fn to_bytes(&self) -> Vec<u8> {
+        encode(&self.value)
+    }
+
+    /// Computes the transaction ID: base32 of `SHA-512/256("TX" || txn)`.
+    #[must_use]
+    pub fn id(&self) -> String {
+        let mut hasher = Sha512_256::new();
+        hasher.update(TXID_PREFIX);
+        hasher.update(encode(self.txn()));
+        data_encoding::BASE32_NOPAD.encode(&hasher.finalize())
+    }
+
+    /// Returns the sender address.
+    #[allow(dead_code)] // Part of the msgpack API
+    #[must_use]
+    pub fn sender(&self) -> Option<String> {
+        address_of(get(self.txn(), "snd"))
+    }
+
+    /// Returns the rekeyed signer, if the sender's authorization was rekeyed.
+    #[must_use]
+    pub fn auth_addr(&self) -> Option<String> {
+        address_of(get(&self.value, "sgnr"))
+    }
+
+    /// Convert to the indexer JSON transaction shape.
+    #[must_use]
+    pub fn to_json(&self) -> JsonValue {
+        let mut json = txn_json(self.txn());
+        json["id"] = json!(self.id());
+        if let Some(signature) = signature_json(&self.value) {
+            json["signature"] = signature;
+        }
+        if let Some(auth) = self.auth_addr() {
+            json["auth-addr"] = json!(auth);
+        }
+        json
+    }
+
+    /// Convert to a domain transaction.
+    ///
+    /// # Errors
+    ///
+    /// Returns `AlgoError::Parse` if the converted transaction cannot be parsed.
+    #[allow(dead_code)] // Part of the msgpack API
+    pub fn to_transaction(&self) -> Result<Transaction, AlgoError> {
+        Transaction::from_json(&self.to_json())
+    }
+}
+
+/// Converts msgpack transaction fields into the indexer JSON shape.
+fn txn_json(txn: &Value) -> JsonValue {
+    let mut json = Map::new();
+    let mut put = |key: &str, value: Option<JsonValue>| {
+        if let Some(value) = value {
+            json.insert(key.to_string(), value);
+        }
+    };
+
+    let txn_type = get(txn, "type").as_str().unwrap_or_default().to_string();
+    put("tx-type", Some(json!(txn_type)));
+    put("sender", address_of(get(txn, "snd")).map(JsonValue::from));
+    put("fee", Some(json!(get(txn, "fee").as_u64().unwrap_or(0))));
+    put("first-valid", get(txn, "fv").as_u64().map(JsonValue::from));
+    put("last-valid", get(txn, "lv").as_u64().map(JsonValue::from));
+    put("note", base64_of(get(txn, "note")).map(JsonValue::from));
+    put("genesis-id", get(txn, "gen").as_str().map(JsonValue::from));
+    put(
+        "genesis-hash",
+        base64_of(get(txn, "gh")).map(JsonValue::from),
+    );
+    put("group", base64_of(get(txn, "grp")).map(JsonValue::from));
+    put("lease", base64_of(get(txn, "lx")).map(JsonValue::from));
+    put(
+        "rekey-to",
+        address_of(get(txn, "rekey")).map(JsonValue::from),
+    );
+
+    let (key, fields) = match txn_type.as_str() {
+        "pay" => ("payment-transaction", payment_json(txn)),
+        "axfer" => ("asset-transfer-transaction", asset_transfer_json(txn)),
+        "acfg" => ("asset-config-transaction", asset_config_json(txn)),
+        "afrz" => ("asset-freeze-transaction", asset_freeze_json(txn)),
+        "appl" => ("application-transaction", app_call_json(txn)),
+        "keyreg" => ("keyreg-transaction", keyreg_json(txn)),
+        "stpf" => (
+            "state-proof-transaction",
+            json!({ "state-proof-type": get(txn, "sptype").as_u64().unwrap_or(0) }),
+        ),
+        "hb" => ("heartbeat-transaction", heartbeat_json(get(txn, "hb"))),
+        _ => return JsonValue::Object(json),
+    };
+    json.insert(key.to_string(), fields);
+    JsonValue::Object(json)
+}
+
+/// Builds a JSON object from `(key, value)` pairs, skipping absent values.
+fn object(fields: impl IntoIterator<Item = (&'static str, Option<JsonValue>)>) -> JsonValue {
+    JsonValue::Object(
+        fields
+            .into_iter()
+            .filter_map(|(key, value)| value.map(|value| (key.to_string(), value)))
+            .collect(),
+    )
+}
+
+fn uint(value: &Value) -> Option<JsonValue> {
+    value.as_u64().map(JsonValue::from)
+}
+
+fn addr(value: &Value) -> Option<JsonValue> {
+    address_of(value).map(JsonValue::from)
+}
+
+fn b64(value: &Value) -> Option<JsonValue> {
+    base64_of(value).map(JsonValue::from)
+}
+
+fn payment_json(txn: &Value) -> JsonValue {
+    object([
+        ("receiver", addr(get(txn, "rcv"))),
+        ("amount", Some(json!(get(txn, "amt").as_u64().unwrap_or(0)))),
+        ("close-remainder-to", addr(get(txn, "close"))),
+    ])
+}
+
+fn asset_transfer_json(txn: &Value) -> JsonValue {
+    object([
+        (
+            "asset-id",
+            Some(json!(get(txn, "xaid").as_u64().unwrap_or(0))),
+        ),
+        (
+            "amount",
+            Some(json!(get(txn, "aamt").as_u64().unwrap_or(0))),
+        ),
+        ("receiver", addr(get(txn, "arcv"))),
+        ("sender", addr(get(txn, "asnd"))),
+        ("close-to", addr(get(txn, "aclose"))),
+    ])
+}
+
+fn asset_config_json(txn: &Value) -> JsonValue {
+    let apar = get(txn, "apar");
+    let params = apar.as_map().map(|_| {
+        object([
+            ("total", uint(get(apar, "t"))),
+            ("decimals", uint(get(apar, "dc"))),
+            (
+                "default-frozen",
+                get(apar, "df").as_bool().map(JsonValue::from),
+            ),
+            ("unit-name", get(apar, "un").as_str().map(JsonValue::from)),
+            ("name", get(apar, "an").as_str().map(JsonValue::from)),
+            ("url", get(apar, "au").as_str().map(JsonValue::from)),
+            ("metadata-hash", b64(get(apar, "am"))),
+            ("manager", addr(get(apar, "m"))),
+            ("reserve", addr(get(apar, "r"))),
+            ("freeze", addr(get(apar, "f"))),
+            ("clawback", addr(get(apar, "c"))),
+        ])
+    });
+    object([("asset-id", uint(get(txn, "caid"))), ("params", params)])
+}
+
+fn asset_freeze_json(txn: &Value) -> JsonValue {
+    object([
+        ("asset-id", uint(get(txn, "faid"))),
+        ("address", addr(get(txn, "fadd"))),
+        (
+            "new-freeze-status",
+            Some(json!(get(txn, "afrz").as_bool().unwrap_or(false))),
+        ),
+    ])
+}
+
+fn app_call_json(txn: &Value) -> JsonValue {
+    let app_id = get(txn, "apid").as_u64().unwrap_or(0);
+    let on_completion = match get(txn, "apan").as_u64().unwrap_or(0) {
+        1 => "optin",
+        2 => "closeout",
+        3 => "clearstate",
+        4 => "update",
+        5 => "delete",
+        _ => "noop",
+    };
+    let array = |key: &str, convert: fn(&Value) -> Option<JsonValue>| {
+        get(txn, key)
+            .as_array()
+            .map(|items| JsonValue::Array(items.iter().filter_map(convert).collect()))
+    };
+    let schema = |key: &str| {
+        let schema = get(txn, key);
+        schema.as_map().map(|_| {
+            json!({
+                "num-uint": get(schema, "nui").as_u64().unwrap_or(0),
+                "num-byte-slice": get(schema, "nbs").as_u64().unwrap_or(0),
+            })
+        })
+    };
+    // Box references index into the foreign apps, with 0 meaning the called app
+    let foreign_apps: Vec<u64> = get(txn, "apfa")
+        .as_array()
+        .map(|apps| apps.iter().filter_map(Value::as_u64).collect())
+        .unwrap_or_default();
+    let boxes = get(txn, "apbx").as_array().map(|refs| {
+        refs.iter()
+            .map(|b| {
+                let index = get(b, "i").as_u64().unwrap_or(0) as usize;
+                let box_app = match index {
+                    0 => app_id,
+                    i => foreign_apps.get(i - 1).copied().unwrap_or(0),
+                };
+                json!({ "i": box_app, "n": base64_of(get(b, "n")).unwrap_or_default() })
+            })
+            .collect::<Vec<_>>()
+    });
+
+    object([
+        ("application-id", Some(json!(app_id))),
+        ("on-completion", Some(json!(on_completion))),
+        ("application-args", array("apaa", b64)),
+        ("accounts", array("apat", addr)),
+        ("foreign-apps", array("apfa", uint)),
+        ("foreign-assets", array("apas", uint)),
+        ("boxes", boxes.map(JsonValue::from)),
+        ("approval-program", b64(get(txn, "apap"))),
+        ("clear-state-program", b64(get(txn, "apsu"))),
+        ("global-state-schema", schema("apgs")),
+        ("local-state-schema", schema("apls")),
+        ("extra-program-pages", uint(get(txn, "apep"))),
+    ])
+}
+
+fn keyreg_json(txn: &Value) -> JsonValue {
+    object([
+        ("vote-participation-key", b64(get(txn, "votekey"))),
+        ("selection-participation-key", b64(get(txn, "selkey"))),
+        ("state-proof-key", b64(get(txn, "sprfkey"))),
+        ("vote-first-valid", uint(get(txn, "votefst"))),
+        ("vote-last-valid", uint(get(txn, "votelst"))),
+        ("vote-key-dilution", uint(get(txn, "votekd"))),
+        (
+            "non-participation",
+            Some(json!(get(txn, "nonpart").as_bool().unwrap_or(false))),
+        ),
+    ])
+}
+
+fn heartbeat_json(hb: &Value) -> JsonValue {
+    object([
+        ("hb-address", addr(get(hb, "a"))),
+        ("hb-key-dilution", uint(get(hb, "kd"))),
+        ("hb-seed", b64(get(hb, "sd"))),
+        ("hb-vote-id", b64(get(hb, "vid"))),
+    ])
+}
+
+/// Converts the `sig`/`msig`/`lsig` fields into the indexer `signature` object.
+fn signature_json(stxn: &Value) -> Option<JsonValue> {
+    let multisig = |msig: &Value| {
+        let subsignature = get(msig, "subsig")
+            .as_array()
+            .map(|subs| {
+                subs.iter()
+                    .map(|sub| {
+                        object([
+                            ("public-key", b64(get(sub, "pk"))),
+                            ("signature", b64(get(sub, "s"))),
+                        ])
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+        json!({
+            "version": get(msig, "v").as_u64().unwrap_or(1),
+            "threshold": get(msig, "thr").as_u64().unwrap_or(0),
+            "subsignature": subsignature,
+        })
+    };
+
+    let lsig = get(stxn, "lsig");
+    if lsig.as_map().is_some() {
+        let args = get(lsig, "arg")
+            .as_array()
+            .map(|args| args.iter().filter_map(base64_of).collect::<Vec<_>>())
+            .unwrap_or_default();
+        let delegated_msig = [get(lsig, "lmsig"), get(lsig, "msig")]
+            .into_iter()
+            .find(|msig| msig.as_map().is_some())
+            .map(multisig);
+        let logicsig = object([
+            ("logic", b64(get(lsig, "l"))),
+            ("args", Some(json!(args))),
+            ("signature", b64(get(lsig, "sig"))),
+            ("multisig-signature", delegated_msig),
+        ]);
+        return Some(json!({ "logicsig": logicsig }));
+    }
+    let msig = get(stxn, "msig");
+    if msig.as_map().is_some() {
+        return Some(json!({ "multisig": multisig(msig) }));
+    }
+    b64(get(stxn, "sig")).map(|sig| json!({ "sig": sig }))
+}
+
+// ============================================================================
+// Blocks
+// ============================================================================
+
+/// A block decoded from algod's msgpack `GET /v2/blocks/{round}` response.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MsgpBlock {
+    /// Block header and payset.
+    block: Value,
+}
+
+impl MsgpBlock {
+    /// Decode a block response (`{block, cert}`) or a bare block.
+    ///
+    /// # Errors
+    ///
+    /// Returns `AlgoError::Parse` if the bytes are not a block.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, AlgoError> {
+        let value = decode(bytes)?;
+        let block = match get(&value, "block") {
+            Value::Nil => value,
+            block => block.clone(),
+        };
+        if block.as_map().is_none() {
+            return Err(AlgoError::parse("Block response is not a map"));
+        }
+        Ok(Self { block })
+    }
+
+    /// Returns the block round.
+    #[must_use]
+    pub fn round(&self) -> u64 {
+        get(&self.block, "rnd").as_u64().unwrap_or(0)
+    }
+
+    /// Returns the block timestamp in seconds since the epoch.
+    #[must_use]
+    pub fn timestamp(&self) -> u64 {
+        get(&self.block, "ts").as_u64().unwrap_or(0)
+    }
+
+    /// Returns the number of transactions in the payset.
+    #[must_use]
+    pub fn txn_count(&self) -> usize {
+        get(&self.block, "txns").as_array().map_or(0, Vec::len)
+    }
+
+    /// Returns the block summary for list display.
+    #[must_use]
+    pub fn to_algo_block(&self) -> AlgoBlock {
+        AlgoBlock {
+            id: self.round(),
+            txn_count: self.txn_count() as u16,
+            timestamp: format_timestamp(self.timestamp()),
+        }
+    }
+
+    /// Returns the block header information.
+    #[allow(dead_code)] // Part of the msgpack API
+    #[must_use]
+    pub fn to_block_info(&self) -> BlockInfo {
+        BlockInfo {
+            id: self.round(),
+            timestamp: format_timestamp(self.timestamp()),
+            txn_count: self.txn_count() as u16,
+            proposer: address_of(get(&self.block, "prp")).unwrap_or_else(|| "unknown".to_string()),
+            seed: base64_of(get(&self.block, "seed")).unwrap_or_else(|| "unknown".to_string()),
+        }
+    }
+
+    /// Returns the signed transactions with the genesis fields the block
+    /// strips from them restored, so their IDs match the ones on chain.
+    #[must_use]
+    pub fn signed_txns(&self) -> Vec<SignedTxn> {
+        let Some(payset) = get(&self.block, "txns").as_array() else {
+            return Vec::new();
+        };
+        payset
+            .iter()
+            .filter_map(|stib| {
+                let mut stxn = stib.clone();
+                let mut txn = get(stib, "txn").clone();
+                if get(stib, "hgi").as_bool() == Some(true) {
+                    set(&mut txn, "gen", get(&self.block, "gen").clone());
+                }
+                // Current consensus always requires the genesis hash
+                set(&mut txn, "gh", get(&self.block, "gh").clone());
+                set(&mut stxn, "txn", txn);
+                SignedTxn::from_value(stxn).ok()
+            })
+            .collect()
+    }
+
+    /// Returns the confirmed transactions, including apply data such as
+    /// created asset and application IDs, logs and inner transactions.
+    #[must_use]
+    pub fn transactions(&self) -> Vec<Transaction> {
+        let round_time = self.timestamp();
+        let payset = get(&self.block, "txns").as_array();
+        self.signed_txns()
+            .iter()
+            .zip(payset.into_iter().flatten())
+            .filter_map(|(stxn, stib)| {
+                let mut json = stxn.to_json();
+                apply_data_json(&mut json, stib, stxn.txn());
+                json["confirmed-round"] = json!(self.round());
+                json["round-time"] = json!(round_time);
+                Transaction::from_json(&json).ok()
+            })
+            .collect()
+    }
+}
+
+/// Merges block apply data (`ca`, `aca`, `caid`, `apid`, `dt`) into indexer JSON.
+fn apply_data_json(json: &mut JsonValue, ad: &Value, txn: &Value) {
+    if let Some(amount) = get(ad, "ca").as_u64()
+        && json["payment-transaction"].is_object()
+    {
+        json["payment-transaction"]["close-amount"] = json!(amount);
+    }
+    if let Some(amount) = get(ad, "aca").as_u64()
+        && json["asset-transfer-transaction"].is_object()
+    {
+        json["asset-transfer-transaction"]["close-amount"] = json!(amount);
+    }
+    if let Some(asset_id) = get(ad, "caid").as_u64() {
+        json["created-asset-index"] = json!(asset_id);
+    }
+    if let Some(app_id) = get(ad, "apid").as_u64() {
+        json["created-application-index"] = json!(app_id);
+    }
+
+    let dt = get(ad, "dt");
+    if let Some(logs) = get(dt, "lg").as_array() {
+        json["logs"] = json!(logs.iter().filter_map(base64_of).collect::<Vec<_>>());
+    }
+    if get(dt, "gd").as_map().is_some() {
+        json["global-state-delta"] = state_delta_json(get(dt, "gd"));
+    }
+    if let Some(local) = get(dt, "ld").as_map() {
+        // Local deltas are keyed by account index: 0 is the sender, then
+        // `apat`, then accounts shared from other transactions in the group
+        let accounts: Vec<&Value> = [get(txn, "apat"), get(dt, "sa")]
+            .into_iter()
+            .filter_map(Value::as_array)
+            .flatten()
+            .collect();
+        let deltas: Vec<JsonValue> = local
+            .iter()
+            .map(|(index, delta)| {
+                let address = match index.as_u64().unwrap_or(0) {
+                    0 => address_of(get(txn, "snd")),
+                    i => accounts
+                        .get(i as usize - 1)
+                        .and_then(|account| address_of(account)),
+                };
+                json!({
+                    "address": address.unwrap_or_else(|| "unknown".to_string()),
+                    "delta": state_delta_json(delta),
+                })
+            })
+            .collect();
+        json["local-state-delta"] = json!(deltas);
+    }
+    if let Some(inner) = get(dt, "itx").as_array() {
+        let inner_txns: Vec<JsonValue> = inner
+            .iter()
+            .map(|itxn| {
+                let mut inner_json = txn_json(get(itxn, "txn"));
+                apply_data_json(&mut inner_json, itxn, get(itxn, "txn"));
+                inner_json
+            })
+            .collect();
+        json["inner-txns"] = json!(inner_txns);
+    }
+}
+
+/// Converts an eval state delta map (`key -> {at, bs, ui}`) into the indexer list.
+fn state_delta_json(delta: &Value) -> JsonValue {
+    let entries = delta
+        .as_map()
+        .map(|map| {
+            map.iter()
+                .map(|(key, value)| {
+                    json!({
+                        "key": base64_of(key).unwrap_or_default(),
+                        "value": object([
+                            ("action", uint(get(value, "at"))),
+                            ("bytes", b64(get(value, "bs"))),
+                            ("uint", uint(get(value, "ui"))),
+                        ]),
+                    })
+                })
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+    JsonValue::Array(entries)
+}
+
+// ============================================================================
+// Pending Transactions
+// ============================================================================
+
+/// A transaction pool listing from `GET /v2/transactions/pending`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PendingTransactions {
+    /// The highest-priority pending transactions.
+    pub top: Vec<SignedTxn>,
+    /// Total number of transactions in the pool.
+    pub total: u64,
+}
+
+impl PendingTransactions {
+    /// Decode a pending transactions response.
+    ///
+    /// # Errors
+    ///
+    /// Returns `AlgoError::Parse` if the bytes are not a pending transactions response.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, AlgoError> {
+        let value = decode(bytes)?;
+        let top = get(&value, "top-transactions")
+            .as_array()
+            .map(|txns| {
+                txns.iter()
+                    .cloned()
+                    .map(SignedTxn::from_value)
+                    .collect::<Result<Vec<_>, _>>()
+            })
+            .transpose()?
+            .unwrap_or_default();
+        Ok(Self {
+            top,
+            total: get(&value, "total-transactions").as_u64().unwrap_or(0),
+        })
+    }
+}
+
+/// A single pool entry from `GET /v2/transactions/pending/{txid}`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PendingTransaction {
+    /// The signed transaction.
+    pub txn: SignedTxn,
+    /// The round it was confirmed in, once it leaves the pool.
+    pub confirmed_round: Option<u64>,
+    /// Why the pool rejected it, if it did.
+    pub pool_error: Option<String>,
+}
+
+impl PendingTransaction {
+    /// Decode a pending transaction response.
+    ///
+    /// # Errors
+    ///
+    /// Returns `AlgoError::Parse` if the bytes are not a pending transaction response.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, AlgoError> {
+        let value = decode(bytes)?;
+        Ok(Self {
+            txn: SignedTxn::from_value(get(&value, "txn").clone())?,
+            confirmed_round: get(&value, "confirmed-round")
+                .as_u64()
+                .filter(|&round| round > 0),
+            pool_error: get(&value, "pool-error")
+                .as_str()
+                .filter(|e| !e.is_empty())
+                .map(String::from),
+        })
+    }
+
+    /// Convert to a domain transaction, with its round once confirmed.
+    ///
+    /// # Errors
+    ///
+    /// Returns `AlgoError::Parse` if the converted transaction cannot be parsed.
+    pub fn to_transaction(&self) -> Result<Transaction, AlgoError> {
+        let mut json = self.txn.to_json();
+        if let Some(round) = self.confirmed_round {
+            json["confirmed-round"] = json!(round);
+        }
+        Transaction::from_json(&json)
+    }
+}
+
+// ============================================================================
+// Simulation
+// ============================================================================
+
+/// Encode a simulate request for one transaction group, allowing
+/// transactions without signatures.
+#[must_use]
+pub fn simulate_request(txns: &[SignedTxn]) -> Vec<u8> {
+    let group = Value::Map(vec![(
+        Value::from("txns"),
+        Value::Array(txns.iter().map(|stxn| stxn.value.clone()).collect()),
+    )]);
+    encode(&Value::Map(vec![
+        (Value::from("allow-empty-signatures"), Value::Boolean(true)),
+        (Value::from("txn-groups"), Value::Array(vec![group])),
+    ]))
+}
+
+impl Simulation {
+    /// Decode a simulate response for a single group.
+    ///
+    /// # Errors
+    ///
+    /// Returns `AlgoError::Parse` if the bytes are not a simulate response or
+    /// a transaction cannot be parsed.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, AlgoError> {
+        let value = decode(bytes)?;
+        let group = get(&value, "txn-groups")
+            .as_array()
+            .and_then(|groups| groups.first())
+            .ok_or_else(|| AlgoError::parse("Simulate response has no transaction group"))?;
+
+        let transactions = get(group, "txn-results")
+            .as_array()
+            .into_iter()
+            .flatten()
+            .map(|result| {
+                let info = get(result, "txn-result");
+                let stxn = SignedTxn::from_value(get(info, "txn").clone())?;
+                let mut json = stxn.to_json();
+                txn_info_json(&mut json, info);
+                Ok(SimulatedTxn {
+                    transaction: Transaction::from_json(&json)?,
+                    app_budget_consumed: get(result, "app-budget-consumed").as_u64(),
+                    logic_sig_budget_consumed: get(result, "logic-sig-budget-consumed").as_u64(),
+                })
+            })
+            .collect::<Result<Vec<_>, AlgoError>>()?;
+
+        Ok(Self {
+            round: get(&value, "last-round").as_u64().unwrap_or(0),
+            transactions,
+            failure_message: get(group, "failure-message")
+                .as_str()
+                .filter(|message| !message.is_empty())
+                .map(String::from),
+            failed_at: get(group, "failed-at")
+                .as_array()
+                .map(|path| path.iter().filter_map(Value::as_u64).collect())
+                .unwrap_or_default(),
+            app_budget_added: get(group, "app-budget-added").as_u64().unwrap_or(0),
+            app_budget_consumed: get(group, "app-budget-consumed").as_u64().unwrap_or(0),
+        })
+    }
+}
+
+/// Merges the effects algod reports for an evaluated transaction (created
+/// IDs, close amounts, logs, state deltas, inner transactions) into indexer
+/// JSON.
+///
+/// Unlike block apply data, algod already reports state deltas in the
+/// indexer's shape, with Base64 keys and addresses.
+fn txn_info_json(json: &mut JsonValue, info: &Value) {
+    if let Some(amount) = get(info, "closing-amount").as_u64()
+        && json["payment-transaction"].is_object()
+    {
+        json["payment-transaction"]["close-amount"] = json!(amount);
+    }
+    if let Some(amount) = get(info, "asset-closing-amount").as_u64()
+        && json["asset-transfer-transaction"].is_object()
+    {
+        json["asset-transfer-transaction"]["close-amount"] = json!(amount);
+    }
+    if let Some(asset_id) = get(info, "asset-index").as_u64() {
+        json["created-asset-index"] = json!(asset_id);
+    }
+    if let Some(app_id) = get(info, "application-index").as_u64() {
+        json["created-application-index"] = json!(app_id);
+    }
+    if let Some(logs) = get(info, "logs").as_array() {
+        json["logs"] = json!(logs.iter().filter_map(base64_of).collect::<Vec<_>>());
+    }
+    if get(info, "global-state-delta").as_array().is_some() {
+        json["global-state-delta"] = plain_json(get(info, "global-state-delta"));
+    }
+    if get(info, "local-state-delta").as_array().is_some() {
+        json["local-state-delta"] = plain_json(get(info, "local-state-delta"));
+    }
+    if let Some(inner) = get(info, "inner-txns").as_array() {
+        let inner_txns: Vec<JsonValue> = inner
+            .iter()
+            .map(|inner_info| {
+                let mut inner_json = txn_json(get(get(inner_info, "txn"), "txn"));
+                txn_info_json(&mut inner_json, inner_info);
+                inner_json
+            })
+            .collect();
+        json["inner-txns"] = json!(inner_txns);
+    }
+}
+
+/// Converts a msgpack value to JSON as is, with binary values as Base64.
+fn plain_json(value: &Value) -> JsonValue {
+    match value {
+        Value::Boolean(b) => json!(b),
+        Value::Integer(i) => i
+            .as_u64()
+            .map(JsonValue::from)
+            .or_else(|| i.as_i64().map(JsonValue::from))
+            .unwrap_or(JsonValue::Null),
+        Value::F32(f) => json!(f),
+        Value::F64(f) => json!(f),
+        Value::String(_) | Value::Binary(_) => value
+            .as_str()
+            .map(JsonValue::from)
+            .or_else(|| base64_of(value).map(JsonValue::from))
+            .unwrap_or(JsonValue::Null),
+        Value::Array(items) => JsonValue::Array(items.iter().map(plain_json).collect()),
+        Value::Map(entries) => JsonValue::Object(
+            entries
+                .iter()
+                .filter_map(|(key, value)| Some((key.as_str()?.to_string(), plain_json(value))))
+                .collect(),
+        ),
+        Value::Nil | Value::Ext(..) => JsonValue::Null,
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{OnComplete, TransactionDetails, TransactionSignature, TxnType};
+    use rstest::*;
+
+    fn map(entries: Vec<(&str, Value)>) -> Value {
+        Value::Map(
+            entries
+                .into_iter()
+                .map(|(k, v)| (Value::from(k), v))
+                .collect(),
+        )
+    }
+
+    fn key(byte: u8) -> Value {
+        Value::Binary(vec![byte; 32])
+    }
+
+    /// A canonically ordered payment transaction.
+    fn payment_txn() -> Value {
+        map(vec![
+            ("amt", Value::from(5_000_000u64)),
+            ("fee", Value::from(1000u64)),
+            ("fv", Value::from(100u64)),
+            ("gen", Value::from("testnet-v1.0")),
+            ("gh", Value::Binary(vec![9; 32])),
+            ("lv", Value::from(1100u64)),
+            ("note", Value::Binary(b"hello".to_vec())),
+            ("rcv", key(2)),
+            ("snd", key(1)),
+            ("type", Value::from("pay")),
+        ])
+    }
+
+    fn signed(txn: Value) -> Value {
+        map(vec![("sig", Value::Binary(vec![7; 64])), ("txn", txn)])
+    }
+
+    #[test]
+    fn test_signed_txn_round_trips_bytes() {
+        let bytes = encode(&signed(payment_txn()));
+        let stxn = SignedTxn::from_bytes(&bytes).unwrap();
+        assert_eq!(stxn.to_bytes(), bytes);
+    }
+
+    #[test]
+    fn test_decode_rejects_trailing_bytes() {
+        let mut bytes = encode(&signed(payment_txn()));
+        bytes.push(0);
+        assert!(decode(&bytes).is_err());
+        assert_eq!(decode_all(&bytes).unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_signed_txn_id() {
+        let stxn = SignedTxn::from_value(signed(payment_txn())).unwrap();
+        let mut preimage = b"TX".to_vec();
+        preimage.extend(encode(&payment_txn()));
+        let expected = data_encoding::BASE32_NOPAD.encode(&Sha512_256::digest(&preimage));
+        assert_eq!(stxn.id(), expected);
+        assert_eq!(stxn.id().len(), 52);
+    }
+
+    /// A MainNet app call confirmed in round 56183757, encoded as algod
+    /// serves it; the indexer fixture `test_data/app_call_txn.json` holds the
+    /// same transaction.
+    const MAINNET_APP_CALL: &str = "gqNzaWfEQBlV2dDz3lcGvg+RvmbLogop3dnL75AnwuSYGiKFy9ONAEoQ7LVzlOgI075o8WZHyPyCFVyr/pj1u1+hOxb3GAajdHhu3gAQpGFwYWGXxAgAAAAAAECN7sQIAAAAAAAAAADEAv//xAcAAAIAAQABxAcAAAEAAQACxAcAAAAAAQAExAcA//8AAQADpGFwYXOTzhd06IfOnsYPhM6++J5BpGFwYXSUxCA0QgGZL7TN1cTEQJu3JTt52ix3HR12Pqy8FqybLXkohcQgQyNyeqOve/xGUTCim5SJDyiQWaBbvhJbNXMgjb4TcZ3EIKXcm/mpGZadqAue04MY1yvulCmWTmd5LDpQK4679knexCDAB8xMvAodHG2kvUSWP9pv+c7gtbP9b/XFxEwr4mccaaRhcGZhkc47wZMdpGFwaWTOR55X4qNmZWXNA+iiZnbOA1lLy6NnZW6sbWFpbm5ldC12MS4womdoxCDAYcTY/B293tLXYEvkVo4/bQQZh6w3veS2ILWrOSSK36NncnDEIM5SHaJkBy8W36wa7mrv+zhhq1td4IJH2/E0PJpb+iMuomx2zgNZS8+ibHjEIINwk0HvR3wJ1MNaQV0CceYqAopqPxKxuCuVe+6/uVQtpG5vdGXEMADkYGl1QFfkf0feyImVo6Rw7sZf+g5DM/7SyniNCFmWWeWgHV3ieDBd7no3QWN8DKVyZWtlecQgMQFvVrDGoPwoiPHAcB9sbBrL4dVWK1RsP7/0J9v8PGujc25kxCAABCGmZXwj2kE53v7Sx74Qucn//iWCaqTdbjUCzhYNpqR0eXBlpGFwcGw=";
+
+    #[test]
+    fn test_signed_txn_id_matches_mainnet() {
+        let bytes = BASE64.decode(MAINNET_APP_CALL).unwrap();
+        let stxn = SignedTxn::from_bytes(&bytes).unwrap();
+        assert_eq!(
+            stxn.id(),
+            "RSTLLBOXL3LIVU6JDP2MYP7DR6624F4M7NDXERCKSETCLRNADWHQ"
+        );
+        assert_eq!(stxn.to_bytes(), bytes);
+        assert_eq!(
+            stxn.sender().as_deref(),
+            Some("AACCDJTFPQR5UQJZ337NFR56CC44T776EWBGVJG5NY2QFTQWBWTALTEN4A")
+        );
+    }
+
+    #[test]
+    fn test_signed_txn_to_transaction() {
+        let stxn = SignedTxn::from_value(signed(payment_txn())).unwrap();
+        let txn = stxn.to_transaction().unwrap();
+        assert_eq!(txn.id, stxn.id());
+        assert_eq!(txn.txn_type, TxnType::Payment);
+        assert_eq!(txn.from, encode_address(&[1; 32]));
+        assert_eq!(txn.to, encode_address(&[2; 32]));
+        assert_eq!(txn.amount, 5_000_000);
+        assert_eq!(txn.fee, 1000);
+        assert_eq!(txn.note, BASE64.encode(b"hello"));
+        assert_eq!(txn.signature, Some(TransactionSignature::Single));
+    }
+
+    #[rstest]
+    #[case::optin(1, OnComplete::OptIn)]
+    #[case::delete(5, OnComplete::DeleteApplication)]
+    fn test_app_call_fields(#[case] apan: u64, #[case] expected: OnComplete) {
+        let txn = map(vec![
+            ("apaa", Value::Array(vec![Value::Binary(b"go".to_vec())])),
+            ("apan", Value::from(apan)),
+            (
+                "apbx",
+                Value::Array(vec![map(vec![("n", Value::Binary(b"k".to_vec()))])]),
+            ),
+            ("apid", Value::from(42u64)),
+            ("snd", key(1)),
+            ("type", Value::from("appl")),
+        ]);
+        let txn = SignedTxn::from_value(map(vec![("txn", txn)]))
+            .unwrap()
+            .to_transaction()
+            .unwrap();
+        let TransactionDetails::AppCall(details) = txn.details else {
+            panic!("expected app call details");
+        };
+        assert_eq!(details.app_id, 42);
+        assert_eq!(details.on_complete, expected);
+        assert_eq!(details.app_args, vec![BASE64.encode(b"go")]);
+        assert_eq!(details.boxes[0].app_id, 42);
+        assert_eq!(txn.signature, None);
+    }
+
+    #[test]
+    fn test_block_restores_genesis_fields_for_ids() {
+        let standalone = SignedTxn::from_value(signed(payment_txn())).unwrap();
+
+        // Blocks strip `gen` and `gh`, flagging them with `hgi`
+        let mut stripped = payment_txn();
+        if let Value::Map(fields) = &mut stripped {
+            fields.retain(|(k, _)| k.as_str() != Some("gen") && k.as_str() != Some("gh"));
+        }
+        let stib = map(vec![
+            ("hgi", Value::from(true)),
+            ("sig", Value::Binary(vec![7; 64])),
+            ("txn", stripped),
+        ]);
+        let block = map(vec![
+            ("gen", Value::from("testnet-v1.0")),
+            ("gh", Value::Binary(vec![9; 32])),
+            ("prp", key(3)),
+            ("rnd", Value::from(77u64)),
+            ("ts", Value::from(1_700_000_000u64)),
+            ("txns", Value::Array(vec![stib])),
+        ]);
+        let bytes = encode(&map(vec![("block", block)]));
+        let block = MsgpBlock::from_bytes(&bytes).unwrap();
+
+        assert_eq!(block.round(), 77);
+        assert_eq!(block.txn_count(), 1);
+        assert_eq!(block.signed_txns()[0].id(), standalone.id());
+        assert_eq!(block.to_block_info().proposer, encode_address(&[3; 32]));
+
+        let txns = block.transactions();
+        assert_eq!(txns[0].id, standalone.id());
+        assert_eq!(txns[0].block, 77);
+        assert_eq!(txns[0].timestamp, format_timestamp(1_700_000_000));
+    }
+
+    #[test]
+    fn test_local_delta_accounts_resolve_through_shared_accounts() {
+        let txn = map(vec![
+            ("apat", Value::Array(vec![key(2)])),
+            ("snd", key(1)),
+            ("type", Value::from("appl")),
+        ]);
+        let ld = Value::Map(
+            (0..4u64)
+                .map(|index| (Value::from(index), Value::Map(Vec::new())))
+                .collect(),
+        );
+        let ad = map(vec![(
+            "dt",
+            map(vec![("ld", ld), ("sa", Value::Array(vec![key(4)]))]),
+        )]);
+
+        let mut json = json!({});
+        apply_data_json(&mut json, &ad, &txn);
+        let addresses: Vec<&str> = json["local-state-delta"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|entry| entry["address"].as_str().unwrap())
+            .collect();
+        assert_eq!(
+            addresses,
+            vec![
+                encode_address(&[1; 32]).as_str(),
+                encode_address(&[2; 32]).as_str(),
+                encode_address(&[4; 32]).as_str(),
+                "unknown",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_pending_transactions() {
+        let pool = map(vec![
+            (
+                "top-transactions",
+                Value::Array(vec![signed(payment_txn())]),
+            ),
+            ("total-transactions", Value::from(12u64)),
+        ]);
+        let pending = PendingTransactions::from_bytes(&encode(&pool)).unwrap();
+        assert_eq!(pending.total, 12);
+        assert_eq!(pending.top.len(), 1);
+
+        let entry = map(vec![
+            ("confirmed-round", Value::from(88u64)),
+            ("pool-error", Value::from("")),
+            ("txn", signed(payment_txn())),
+        ]);
+        let entry = PendingTransaction::from_bytes(&encode(&entry)).unwrap();
+        assert_eq!(entry.confirmed_round, Some(88));
+        assert_eq!(entry.pool_error, None);
+        assert_eq!(entry.to_transaction().unwrap().block, 88);
+    }
+
+    #[test]
+    fn test_simulate_request() {
+        let stxn = SignedTxn::from_file_value(payment_txn()).unwrap();
+        let request = decode(&simulate_request(&[stxn])).unwrap();
+
+        assert_eq!(
+            get(&request, "allow-empty-signatures").as_bool(),
+            Some(true)
+        );
+        let groups = get(&request, "txn-groups").as_array().unwrap();
+        let txns = get(&groups[0], "txns").as_array().unwrap();
+        assert_eq!(txns, &vec![map(vec![("txn", payment_txn())])]);
+    }
+
+    #[test]
+    fn test_simulation_from_bytes() {
+        let app_call = map(vec![
+            ("apid", Value::from(42u64)),
+            ("snd", key(1)),
+            ("type", Value::from("appl")),
+        ]);
+        let inner = map(vec![
+            ("logs", Value::Array(vec![Value::Binary(b"inner".to_vec())])),
+            ("txn", map(vec![("txn", payment_txn())])),
+        ]);
+        let result = map(vec![
+            ("app-budget-consumed", Value::from(700u64)),
+            (
+                "txn-result",
+                map(vec![
+                    (
+                        "global-state-delta",
+                        Value::Array(vec![map(vec![
+                            ("key", Value::from(BASE64.encode(b"count"))),
+                            (
+                                "value",
+                                map(vec![
+                                    ("action", Value::from(2u64)),
+                                    ("uint", Value::from(5u64)),
+                                ]),
+                            ),
+                        ])]),
+                    ),
+                    ("inner-txns", Value::Array(vec![inner])),
+                    ("logs", Value::Array(vec![Value::Binary(b"hello".to_vec())])),
+                    ("pool-error", Value::from("")),
+                    ("txn", map(vec![("txn", app_call)])),
+                ]),
+            ),
+        ]);
+        let group = map(vec![
+            ("app-budget-added", Value::from(700u64)),
+            ("app-budget-consumed", Value::from(700u64)),
+            (
+                "failed-at",
+                Value::Array(vec![Value::from(0u64), Value::from(0u64)]),
+            ),
+            (
+                "failure-message",
+                Value::from("logic eval error: err opcode"),
+            ),
+            ("txn-results", Value::Array(vec![result])),
+        ]);
+        let response = map(vec![
+            ("last-round", Value::from(1234u64)),
+            ("txn-groups", Value::Array(vec![group])),
+            ("version", Value::from(2u64)),
+        ]);
+
+        let simulation = Simulation::from_bytes(&encode(&response)).unwrap();
+
+        assert_eq!(simulation.round, 1234);
+        assert_eq!(
+            simulation.failure_message.as_deref(),
+            Some("logic eval error: err opcode")
+        );
+        assert_eq!(simulation.failed_at, vec![0, 0]);
+        assert_eq!(simulation.app_budget_added, 700);
+        assert_eq!(simulation.transactions[0].app_budget_consumed, Some(700));
+        assert_eq!(simulation.transactions[0].logic_sig_budget_consumed, None);
+
+        let txn = &simulation.transactions[0].transaction;
+        let TransactionDetails::AppCall(details) = &txn.details else {
+            panic!("expected app call details");
+        };
+        assert_eq!(details.logs, vec![BASE64.encode(b"hello")]);
+        assert_eq!(details.global_state_delta[0].key, "count");
+        assert_eq!(txn.inner_transactions[0].amount, 5_000_000);
+        assert_eq!(txn.logs_by_path().len(), 1);
+    }
+}
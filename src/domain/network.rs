@@ -43,6 +43,9 @@ pub struct CustomNetwork {
     /// Optional NFD API URL.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub nfd_api_url: Option<String>,
+    /// Read everything from algod, without an indexer.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub algod_only: bool,
 }
 
 impl CustomNetwork {
@@ -61,6 +64,7 @@ impl CustomNetwork {
             indexer_token: None,
             algod_token: None,
             nfd_api_url: None,
+            algod_only: false,
         }
     }
 
@@ -72,6 +76,14 @@ impl CustomNetwork {
         self
     }
 
+    /// Marks the network as having no indexer.
+    #[must_use]
+    #[allow(dead_code)] // Part of public API
+    pub fn algod_only(mut self) -> Self {
+        self.algod_only = true;
+        self
+    }
+
     #[must_use]
     pub fn as_str(&self) -> &str {
         &self.name
@@ -84,6 +96,17 @@ impl CustomNetwork {
     }
 }
 
+/// Where transactions and other chain history are read from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DataSource {
+    /// The indexer, with algod for current state.
+    #[default]
+    Indexer,
+    /// Algod alone: transactions are derived from block bodies and
+    /// indexer-only features are unavailable.
+    AlgodOnly,
+}
+
 /// Network configuration that can be either built-in or custom.
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(untagged)]
@@ -153,6 +176,15 @@ impl NetworkConfig {
         }
     }
 
+    /// Whether the network is configured without an indexer.
+    #[must_use]
+    pub fn algod_only(&self) -> bool {
+        match self {
+            Self::BuiltIn(_) => false,
+            Self::Custom(custom) => custom.algod_only,
+        }
+    }
+
     #[must_use]
     #[allow(dead_code)] // Part of public API
     pub fn supports_nfd(&self) -> bool {
@@ -450,6 +482,20 @@ mod tests {
         assert!(network.supports_nfd());
     }
 
+    #[test]
+    fn test_custom_network_algod_only_serialization() {
+        let network = CustomNetwork::new("Test", "", "http://algod");
+        let json = serde_json::to_string(&network).unwrap();
+        assert!(!json.contains("algod_only"));
+
+        let network = network.algod_only();
+        let json = serde_json::to_string(&network).unwrap();
+        let deserialized: CustomNetwork = serde_json::from_str(&json).unwrap();
+        assert!(deserialized.algod_only);
+        assert!(NetworkConfig::Custom(deserialized).algod_only());
+        assert!(!NetworkConfig::BuiltIn(Network::LocalNet).algod_only());
+    }
+
     #[test]
     fn test_custom_network_display() {
         let network = CustomNetwork::new("MyCustomNet", "http://idx", "http://algod");
@@ -4,6 +4,7 @@
 //! a naming service on Algorand that provides human-readable names
 //! for Algorand addresses (e.g., "alice.algo").
 
+use serde::Serialize;
 use serde_json::Value;
 
 // ============================================================================
@@ -14,7 +15,7 @@ use serde_json::Value;
 ///
 /// This is a simplified view of the NFD data for display purposes.
 /// NFD is only available on MainNet and TestNet.
-#[derive(Debug, Clone, PartialEq, Default)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize)]
 pub struct NfdInfo {
     /// The NFD name (e.g., "alice.algo").
     pub name: String,
@@ -0,0 +1,66 @@
+//! Pagination types for indexer list endpoints.
+//!
+//! Indexer list endpoints return at most `limit` items per response together
+//! with an opaque `next-token`. This module defines the page container used
+//! to carry that token back to callers so lists can be continued.
+
+// ============================================================================
+// Page
+// ============================================================================
+
+/// One page of results from a paginated indexer endpoint.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Page<T> {
+    /// Items returned in this page.
+    pub items: Vec<T>,
+    /// Token for fetching the next page, if the indexer reported more results.
+    pub next_token: Option<String>,
+}
+
+impl<T> Page<T> {
+    /// Create a new `Page`.
+    ///
+    /// Empty tokens are normalized to `None`, since the indexer sometimes
+    /// returns an empty `next-token` on the last page.
+    ///
+    /// # Arguments
+    ///
+    /// * `items` - Items in this page
+    /// * `next_token` - Token for the next page, if any
+    ///
+    /// # Returns
+    ///
+    /// A new `Page` instance.
+    #[must_use]
+    pub fn new(items: Vec<T>, next_token: Option<String>) -> Self {
+        Self {
+            items,
+            next_token: next_token.filter(|token| !token.is_empty()),
+        }
+    }
+
+    /// Returns true if another page can be requested.
+    #[must_use]
+    pub fn has_more(&self) -> bool {
+        self.next_token.is_some()
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_page_normalizes_empty_token() {
+        let page: Page<u64> = Page::new(vec![1, 2], Some(String::new()));
+        assert!(!page.has_more());
+
+        let page: Page<u64> = Page::new(vec![1, 2], Some("abc".to_string()));
+        assert!(page.has_more());
+        assert_eq!(page.next_token.as_deref(), Some("abc"));
+    }
+}
@@ -0,0 +1,247 @@
+//! Transaction signature types.
+//!
+//! Algorand transactions are authorized by exactly one of a single Ed25519
+//! signature, a multisignature, or a logic signature (a TEAL program, either
+//! as a contract account or delegated by a key). This module models the
+//! indexer's `signature` object so the detail views can tell them apart.
+
+use base64::{Engine as _, engine::general_purpose::STANDARD as BASE64};
+use serde::Serialize;
+use serde_json::Value;
+
+use super::account::encode_address;
+
+// ============================================================================
+// Transaction Signature
+// ============================================================================
+
+/// How a transaction was authorized.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub enum TransactionSignature {
+    /// A single Ed25519 signature.
+    Single,
+    /// A multisignature.
+    Multisig(MultisigInfo),
+    /// A logic signature.
+    LogicSig(LogicSigInfo),
+}
+
+impl TransactionSignature {
+    /// Parse the indexer `signature` object.
+    ///
+    /// Returns `None` if the object is missing or empty, as it is for inner
+    /// transactions, which are authorized by their calling application.
+    #[must_use]
+    pub fn from_json(sig_json: &Value) -> Option<Self> {
+        if sig_json["logicsig"].is_object() {
+            let lsig = &sig_json["logicsig"];
+            let delegation = if lsig["multisig-signature"].is_object() {
+                LogicSigDelegation::Multisig(MultisigInfo::from_json(&lsig["multisig-signature"]))
+            } else if lsig["signature"].is_string() {
+                LogicSigDelegation::Single
+            } else {
+                LogicSigDelegation::ContractAccount
+            };
+            return Some(Self::LogicSig(LogicSigInfo {
+                logic: lsig["logic"].as_str().unwrap_or_default().to_string(),
+                args: lsig["args"]
+                    .as_array()
+                    .map(|arr| {
+                        arr.iter()
+                            .filter_map(|v| v.as_str().map(String::from))
+                            .collect()
+                    })
+                    .unwrap_or_default(),
+                delegation,
+            }));
+        }
+        if sig_json["multisig"].is_object() {
+            return Some(Self::Multisig(MultisigInfo::from_json(
+                &sig_json["multisig"],
+            )));
+        }
+        sig_json["sig"].is_string().then_some(Self::Single)
+    }
+
+    /// Returns a short description, e.g. `Single`, `Multisig 2 of 3` or
+    /// `LogicSig (contract account)`.
+    #[must_use]
+    pub fn describe(&self) -> String {
+        match self {
+            Self::Single => "Single".to_string(),
+            Self::Multisig(msig) => format!("Multisig {}", msig.describe()),
+            Self::LogicSig(lsig) => match &lsig.delegation {
+                LogicSigDelegation::ContractAccount => "LogicSig (contract account)".to_string(),
+                LogicSigDelegation::Single => "LogicSig (delegated)".to_string(),
+                LogicSigDelegation::Multisig(msig) => {
+                    format!("LogicSig (delegated, multisig {})", msig.describe())
+                }
+            },
+        }
+    }
+
+    /// Returns the multisig details, including those of a multisig-delegated logicsig.
+    #[must_use]
+    pub fn multisig(&self) -> Option<&MultisigInfo> {
+        match self {
+            Self::Multisig(msig)
+            | Self::LogicSig(LogicSigInfo {
+                delegation: LogicSigDelegation::Multisig(msig),
+                ..
+            }) => Some(msig),
+            _ => None,
+        }
+    }
+}
+
+// ============================================================================
+// Multisig
+// ============================================================================
+
+/// Multisignature parameters and participants.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize)]
+pub struct MultisigInfo {
+    /// Multisig version.
+    pub version: u64,
+    /// Number of signatures required.
+    pub threshold: u64,
+    /// Participants, in the order that defines the multisig address.
+    pub subsignatures: Vec<MultisigSubsig>,
+}
+
+impl MultisigInfo {
+    /// Parse an indexer multisig object.
+    #[must_use]
+    pub fn from_json(msig_json: &Value) -> Self {
+        let subsignatures = msig_json["subsignature"]
+            .as_array()
+            .map(|arr| {
+                arr.iter()
+                    .map(|sub| MultisigSubsig {
+                        address: sub["public-key"]
+                            .as_str()
+                            .and_then(|key| BASE64.decode(key).ok())
+                            .map(|key| encode_address(&key))
+                            .unwrap_or_else(|| "unknown".to_string()),
+                        signed: sub["signature"].is_string(),
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        Self {
+            version: msig_json["version"].as_u64().unwrap_or(1),
+            threshold: msig_json["threshold"].as_u64().unwrap_or(0),
+            subsignatures,
+        }
+    }
+
+    /// Returns the number of participants that signed.
+    #[must_use]
+    pub fn signed_count(&self) -> usize {
+        self.subsignatures.iter().filter(|sub| sub.signed).count()
+    }
+
+    /// Returns e.g. `2 of 3 (v1)`.
+    #[must_use]
+    pub fn describe(&self) -> String {
+        format!(
+            "{} of {} (v{})",
+            self.threshold,
+            self.subsignatures.len(),
+            self.version
+        )
+    }
+}
+
+/// A multisig participant.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize)]
+pub struct MultisigSubsig {
+    /// Participant address (derived from its public key).
+    pub address: String,
+    /// Whether this participant signed the transaction.
+    pub signed: bool,
+}
+
+// ============================================================================
+// Logic Signature
+// ============================================================================
+
+/// A logic signature: the program, its arguments and who delegated it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct LogicSigInfo {
+    /// The TEAL program (Base64 encoded).
+    pub logic: String,
+    /// Program arguments (Base64 encoded).
+    pub args: Vec<String>,
+    /// Whether the program is a contract account or a delegated signature.
+    pub delegation: LogicSigDelegation,
+}
+
+/// How a logic signature relates to the sender account.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub enum LogicSigDelegation {
+    /// The sender is the program's own (escrow) address.
+    ContractAccount,
+    /// The sender's key signed the program.
+    Single,
+    /// The sender's multisig signed the program.
+    Multisig(MultisigInfo),
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_signature_kinds() {
+        assert_eq!(TransactionSignature::from_json(&json!({})), None);
+        assert_eq!(TransactionSignature::from_json(&Value::Null), None);
+        assert_eq!(
+            TransactionSignature::from_json(&json!({"sig": "c2ln"})),
+            Some(TransactionSignature::Single)
+        );
+
+        let contract = TransactionSignature::from_json(&json!({
+            "logicsig": {"logic": "CIEB", "args": ["AQ=="]}
+        }))
+        .unwrap();
+        assert_eq!(contract.describe(), "LogicSig (contract account)");
+
+        let delegated = TransactionSignature::from_json(&json!({
+            "logicsig": {"logic": "CIEB", "signature": "c2ln"}
+        }))
+        .unwrap();
+        assert_eq!(delegated.describe(), "LogicSig (delegated)");
+    }
+
+    #[test]
+    fn test_multisig_participants() {
+        let zero_key = BASE64.encode([0u8; 32]);
+        let sig = TransactionSignature::from_json(&json!({
+            "multisig": {
+                "version": 1,
+                "threshold": 2,
+                "subsignature": [
+                    {"public-key": zero_key, "signature": "c2ln"},
+                    {"public-key": zero_key}
+                ]
+            }
+        }))
+        .unwrap();
+
+        let msig = sig.multisig().unwrap();
+        assert_eq!(sig.describe(), "Multisig 2 of 2 (v1)");
+        assert_eq!(msig.signed_count(), 1);
+        assert_eq!(
+            msig.subsignatures[0].address,
+            "AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAY5HFKQ"
+        );
+        assert!(!msig.subsignatures[1].signed);
+    }
+}
@@ -0,0 +1,303 @@
+//! Transaction group simulation.
+//!
+//! algod can evaluate a transaction group against the latest round without
+//! committing it (`POST /v2/transactions/simulate`). Groups are read from
+//! transaction files written by `goal` or the SDKs, signed or not, and the
+//! simulated transactions carry their logs, state changes and inner
+//! transactions like confirmed ones do.
+
+use base64::{Engine as _, engine::general_purpose::STANDARD as BASE64};
+use serde::Serialize;
+
+use super::error::AlgoError;
+use super::export::expand_path;
+use super::msgpack::{self, SignedTxn};
+use super::transaction::Transaction;
+
+/// Maximum number of transactions in an atomic group.
+pub const MAX_GROUP_SIZE: usize = 16;
+
+// ============================================================================
+// Input
+// ============================================================================
+
+/// Loads a transaction group from a file, or parses `input` itself as a
+/// base64 group when it is not a path to a file.
+///
+/// # Errors
+///
+/// Returns `AlgoError::InvalidInput` if the file is missing, cannot be read
+/// or holds no transactions, and `AlgoError::Parse` if it holds something
+/// else.
+pub fn load_txn_group(input: &str) -> Result<Vec<SignedTxn>, AlgoError> {
+    let input = input.trim();
+    let path = expand_path(input);
+    if path.is_file() {
+        let bytes = std::fs::read(&path)
+            .map_err(|e| AlgoError::invalid_input(format!("Cannot read {input}: {e}")))?;
+        return parse_txn_group(&bytes);
+    }
+    if looks_like_path(input) {
+        return Err(AlgoError::invalid_input(format!("No such file: {input}")));
+    }
+    parse_txn_group(input.as_bytes())
+}
+
+/// Returns `true` if `input` names a file rather than holding base64.
+///
+/// Dots (as in `.txn`, `.stxn` and `.tx` files) never appear in base64, and
+/// a slash only counts when the input doesn't decode as base64 anyway.
+fn looks_like_path(input: &str) -> bool {
+    input.contains('.') || (input.contains('/') && decode_base64(input.as_bytes()).is_err())
+}
+
+/// Parses a transaction group from raw file contents.
+///
+/// Accepts concatenated msgpack transactions, as in `.txn`, `.stxn` and
+/// `.tx` files, or the same bytes as base64. Base64 may also hold one
+/// transaction per line or comma-separated entry.
+///
+/// # Errors
+///
+/// Returns `AlgoError::InvalidInput` for empty input, bad base64 or too many
+/// transactions, and `AlgoError::Parse` if an entry is not a transaction.
+pub fn parse_txn_group(input: &[u8]) -> Result<Vec<SignedTxn>, AlgoError> {
+    if input.trim_ascii().is_empty() {
+        return Err(AlgoError::invalid_input("No transactions to simulate"));
+    }
+    let bytes = if is_base64_text(input) {
+        decode_base64(input)?
+    } else {
+        input.to_vec()
+    };
+
+    let txns = msgpack::decode_all(&bytes)?
+        .into_iter()
+        .map(SignedTxn::from_file_value)
+        .collect::<Result<Vec<_>, _>>()?;
+    if txns.is_empty() {
+        return Err(AlgoError::invalid_input("No transactions to simulate"));
+    }
+    if txns.len() > MAX_GROUP_SIZE {
+        return Err(AlgoError::invalid_input(format!(
+            "A group holds at most {MAX_GROUP_SIZE} transactions, found {}",
+            txns.len()
+        )));
+    }
+    Ok(txns)
+}
+
+/// Decodes base64 holding one or more transactions, split by line breaks or
+/// commas.
+fn decode_base64(input: &[u8]) -> Result<Vec<u8>, AlgoError> {
+    let mut bytes = Vec::new();
+    for chunk in input
+        .split(|b| b.is_ascii_whitespace() || *b == b',')
+        .filter(|chunk| !chunk.is_empty())
+    {
+        let decoded = BASE64
+            .decode(chunk)
+            .map_err(|e| AlgoError::invalid_input(format!("Invalid base64: {e}")))?;
+        bytes.extend(decoded);
+    }
+    Ok(bytes)
+}
+
+/// Returns `true` if the input is printable base64 text rather than msgpack.
+///
+/// A msgpack transaction starts with a map header, which is never a base64
+/// character, so the two cannot be confused.
+fn is_base64_text(input: &[u8]) -> bool {
+    input
+        .iter()
+        .all(|b| b.is_ascii_alphanumeric() || b"+/=,".contains(b) || b.is_ascii_whitespace())
+}
+
+// ============================================================================
+// Results
+// ============================================================================
+
+/// The outcome of simulating a transaction group.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Simulation {
+    /// The round the group was evaluated against.
+    pub round: u64,
+    /// The group's transactions in order, with their effects.
+    pub transactions: Vec<SimulatedTxn>,
+    /// Why the group would be rejected, if it would.
+    pub failure_message: Option<String>,
+    /// Path to the failing transaction: its index in the group, then its
+    /// inner transaction indexes.
+    pub failed_at: Vec<u64>,
+    /// App call budget pooled by the group's app calls.
+    pub app_budget_added: u64,
+    /// App call budget used by the group.
+    pub app_budget_consumed: u64,
+}
+
+/// A simulated transaction and the budget it used.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SimulatedTxn {
+    /// The transaction with its logs, state changes and inner transactions.
+    pub transaction: Transaction,
+    /// App call budget used, for app calls.
+    pub app_budget_consumed: Option<u64>,
+    /// Logic signature budget used, for logic signature transactions.
+    pub logic_sig_budget_consumed: Option<u64>,
+}
+
+impl Simulation {
+    /// Returns `true` if the group would be accepted.
+    #[must_use]
+    pub const fn would_succeed(&self) -> bool {
+        self.failure_message.is_none()
+    }
+
+    /// Index of the failing transaction in the group.
+    #[must_use]
+    pub fn failed_index(&self) -> Option<usize> {
+        self.failed_at.first().map(|&idx| idx as usize)
+    }
+
+    /// Describes where the group failed, e.g. `Transaction 2, inner 1.3`.
+    ///
+    /// Positions are 1-based, with inner transactions numbered like log
+    /// sections.
+    #[must_use]
+    pub fn failed_at_label(&self) -> Option<String> {
+        let (first, inner) = self.failed_at.split_first()?;
+        let mut label = format!("Transaction {}", first + 1);
+        if !inner.is_empty() {
+            let path: Vec<String> = inner.iter().map(|idx| (idx + 1).to_string()).collect();
+            label.push_str(&format!(", inner {}", path.join(".")));
+        }
+        Some(label)
+    }
+
+    /// The simulated transactions, for graphing.
+    #[must_use]
+    pub fn transactions(&self) -> Vec<Transaction> {
+        self.transactions
+            .iter()
+            .map(|simulated| simulated.transaction.clone())
+            .collect()
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::TransactionMother;
+    use rmpv::Value;
+    use rstest::rstest;
+
+    fn payment(amount: u64) -> Value {
+        Value::Map(vec![
+            (Value::from("amt"), Value::from(amount)),
+            (Value::from("rcv"), Value::Binary(vec![2; 32])),
+            (Value::from("snd"), Value::Binary(vec![1; 32])),
+            (Value::from("type"), Value::from("pay")),
+        ])
+    }
+
+    fn signed(txn: Value) -> Value {
+        Value::Map(vec![(Value::from("txn"), txn)])
+    }
+
+    /// A group file: one transaction as `goal` writes it, one bare unsigned.
+    fn group_file() -> Vec<u8> {
+        let mut bytes = msgpack::encode(&signed(payment(1)));
+        bytes.extend(msgpack::encode(&payment(2)));
+        bytes
+    }
+
+    #[rstest]
+    #[case::msgpack(group_file())]
+    #[case::base64(BASE64.encode(group_file()).into_bytes())]
+    #[case::base64_lines(format!(
+        "{}\n{}\n",
+        BASE64.encode(msgpack::encode(&signed(payment(1)))),
+        BASE64.encode(msgpack::encode(&payment(2)))
+    ).into_bytes())]
+    fn test_parse_txn_group(#[case] input: Vec<u8>) {
+        let txns = parse_txn_group(&input).unwrap();
+        let amounts: Vec<u64> = txns
+            .iter()
+            .map(|stxn| stxn.to_transaction().unwrap().amount)
+            .collect();
+        assert_eq!(amounts, vec![1, 2]);
+    }
+
+    #[rstest]
+    #[case::empty(b"  \n".to_vec(), "No transactions to simulate")]
+    #[case::bad_base64(b"A".to_vec(), "Invalid base64")]
+    #[case::not_a_txn(msgpack::encode(&Value::from(7)), "not a transaction")]
+    #[case::too_many(
+        (0..17).flat_map(|i| msgpack::encode(&payment(i))).collect(),
+        "at most 16 transactions, found 17"
+    )]
+    fn test_parse_txn_group_errors(#[case] input: Vec<u8>, #[case] expected: &str) {
+        let err = parse_txn_group(&input).unwrap_err().to_string();
+        assert!(err.contains(expected), "{err}");
+    }
+
+    #[test]
+    fn test_load_txn_group_from_file() {
+        let path = std::env::temp_dir().join("lazylora_simulate_test.stxn");
+        std::fs::write(&path, group_file()).unwrap();
+        let txns = load_txn_group(&path.display().to_string()).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(txns.len(), 2);
+
+        // Anything that is not a file is read as base64
+        let encoded = BASE64.encode(group_file());
+        assert_eq!(load_txn_group(&encoded).unwrap(), txns);
+    }
+
+    #[rstest]
+    #[case::extension("missing.stxn")]
+    #[case::relative("./group")]
+    #[case::directory("txns/group")]
+    #[case::home("~/group.tx")]
+    fn test_load_txn_group_missing_file(#[case] input: &str) {
+        let err = load_txn_group(input).unwrap_err().to_string();
+        assert!(err.contains(&format!("No such file: {input}")), "{err}");
+    }
+
+    #[test]
+    fn test_base64_with_slashes_is_not_a_path() {
+        assert!(!looks_like_path(&BASE64.encode([0xff, 0xfe, 0xfd])));
+        assert!(!looks_like_path(&BASE64.encode(group_file())));
+    }
+
+    #[test]
+    fn test_failed_at_label() {
+        let mut simulation = Simulation {
+            round: 100,
+            transactions: vec![SimulatedTxn {
+                transaction: TransactionMother::payment("A"),
+                app_budget_consumed: None,
+                logic_sig_budget_consumed: None,
+            }],
+            failure_message: None,
+            failed_at: Vec::new(),
+            app_budget_added: 0,
+            app_budget_consumed: 0,
+        };
+        assert!(simulation.would_succeed());
+        assert_eq!(simulation.failed_at_label(), None);
+
+        simulation.failure_message = Some("logic eval error".to_string());
+        simulation.failed_at = vec![1, 0, 2];
+        assert!(!simulation.would_succeed());
+        assert_eq!(simulation.failed_index(), Some(1));
+        assert_eq!(
+            simulation.failed_at_label().as_deref(),
+            Some("Transaction 2, inner 1.3")
+        );
+    }
+}
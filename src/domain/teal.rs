@@ -0,0 +1,888 @@
+//! TEAL bytecode disassembler.
+//!
+//! This module turns compiled AVM programs (approval, clear state and logic
+//! signature programs) back into readable TEAL listings, similar to
+//! `goal clerk compile -D`. It decodes the version header, opcodes, their
+//! immediates, `intcblock`/`bytecblock` constants and branch targets, which
+//! are rendered as numbered labels.
+
+use std::collections::BTreeMap;
+
+use base64::{Engine as _, engine::general_purpose::STANDARD as BASE64};
+
+use super::error::AlgoError;
+
+// ============================================================================
+// Listing
+// ============================================================================
+
+/// A disassembled TEAL program.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct TealListing {
+    /// The program version from the `#pragma version` header.
+    pub version: u64,
+    /// The listing lines, starting with the `#pragma version` line.
+    ///
+    /// Branch targets appear as `labelN:` lines. If decoding stops early
+    /// (unknown opcode or truncated immediate), the last line is a
+    /// `// error: ...` comment describing where and why.
+    pub lines: Vec<String>,
+}
+
+/// Disassembles a base64-encoded program as returned by algod and indexer.
+///
+/// # Arguments
+///
+/// * `program` - The base64-encoded program bytes
+///
+/// # Returns
+///
+/// The TEAL listing, or a parse error if the input is not valid base64.
+///
+/// # Errors
+///
+/// Returns `AlgoError::Parse` if `program` cannot be base64-decoded.
+pub fn disassemble_base64(program: &str) -> Result<TealListing, AlgoError> {
+    let bytes = BASE64
+        .decode(program.trim())
+        .map_err(|e| AlgoError::parse(format!("Invalid program encoding: {e}")))?;
+    Ok(disassemble(&bytes))
+}
+
+/// Disassembles raw program bytes into a TEAL listing.
+///
+/// Decoding never fails outright: an unknown opcode or a truncated immediate
+/// ends the listing with an error comment so the readable prefix is kept.
+///
+/// # Arguments
+///
+/// * `bytecode` - The compiled program bytes
+///
+/// # Returns
+///
+/// The TEAL listing for the program.
+#[must_use]
+pub fn disassemble(bytecode: &[u8]) -> TealListing {
+    let mut reader = Reader::new(bytecode);
+    let Some(version) = reader.varuint() else {
+        return TealListing {
+            version: 0,
+            lines: vec!["// error: empty or truncated version header".to_string()],
+        };
+    };
+
+    let mut instructions = Vec::new();
+    let mut error = None;
+    while !reader.at_end() {
+        match decode_instruction(&mut reader) {
+            Ok(instruction) => instructions.push(instruction),
+            Err(message) => {
+                error = Some(message);
+                break;
+            }
+        }
+    }
+
+    // Number branch targets in address order, like goal does
+    let mut labels: BTreeMap<usize, String> = BTreeMap::new();
+    for instruction in &instructions {
+        for target in instruction.branch_targets() {
+            labels.entry(target).or_default();
+        }
+    }
+    for (idx, name) in labels.values_mut().enumerate() {
+        *name = format!("label{}", idx + 1);
+    }
+
+    let mut lines = vec![format!("#pragma version {version}")];
+    let mut int_constants: Vec<u64> = Vec::new();
+    let mut byte_constants: Vec<Vec<u8>> = Vec::new();
+
+    for instruction in &instructions {
+        if let Some(label) = labels.get(&instruction.pc) {
+            lines.push(format!("{label}:"));
+        }
+        match (instruction.name, instruction.operands.first()) {
+            ("intcblock", Some(Operand::Uints(values))) => int_constants.clone_from(values),
+            ("bytecblock", Some(Operand::ByteStrings(values))) => {
+                byte_constants.clone_from(values);
+            }
+            _ => {}
+        }
+        lines.push(instruction.format(&labels, &int_constants, &byte_constants));
+    }
+
+    // A branch may target the very end of the program
+    if let Some(label) = labels.get(&reader.pos) {
+        lines.push(format!("{label}:"));
+    }
+
+    if let Some(message) = error {
+        lines.push(format!("// error: {message}"));
+    }
+
+    TealListing { version, lines }
+}
+
+// ============================================================================
+// Instruction Decoding
+// ============================================================================
+
+/// Kind of immediate argument following an opcode.
+#[derive(Debug, Clone, Copy)]
+enum Imm {
+    /// Single unsigned byte.
+    Uint8,
+    /// Single signed byte (frame offsets).
+    Int8,
+    /// Signed 16-bit big-endian branch offset.
+    Label,
+    /// Varuint-encoded integer.
+    Varuint,
+    /// Varuint length followed by that many bytes.
+    Bytes,
+    /// Varuint count followed by that many varuints.
+    Varuints,
+    /// Varuint count followed by that many length-prefixed byte strings.
+    ByteStrings,
+    /// Byte count followed by that many branch offsets.
+    Labels,
+    /// Single byte naming a field from the given table.
+    Field(&'static [&'static str]),
+}
+
+/// Decoded immediate argument.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Operand {
+    Uint(u64),
+    Int(i64),
+    Target(Option<usize>),
+    Field(String),
+    Bytes(Vec<u8>),
+    Uints(Vec<u64>),
+    ByteStrings(Vec<Vec<u8>>),
+    Targets(Vec<Option<usize>>),
+}
+
+/// A single decoded instruction.
+#[derive(Debug, Clone)]
+struct Instruction {
+    pc: usize,
+    name: &'static str,
+    operands: Vec<Operand>,
+}
+
+impl Instruction {
+    fn branch_targets(&self) -> impl Iterator<Item = usize> + '_ {
+        self.operands.iter().flat_map(|operand| match operand {
+            Operand::Target(target) => target.iter().copied().collect::<Vec<_>>(),
+            Operand::Targets(targets) => targets.iter().flatten().copied().collect(),
+            _ => Vec::new(),
+        })
+    }
+
+    fn format(
+        &self,
+        labels: &BTreeMap<usize, String>,
+        int_constants: &[u64],
+        byte_constants: &[Vec<u8>],
+    ) -> String {
+        let label_name = |target: &Option<usize>| {
+            target
+                .and_then(|pc| labels.get(&pc).cloned())
+                .unwrap_or_else(|| "<invalid>".to_string())
+        };
+
+        let mut parts = vec![self.name.to_string()];
+        for operand in &self.operands {
+            match operand {
+                Operand::Uint(value) => parts.push(value.to_string()),
+                Operand::Int(value) => parts.push(value.to_string()),
+                Operand::Field(name) => parts.push(name.clone()),
+                Operand::Target(target) => parts.push(label_name(target)),
+                Operand::Targets(targets) => parts.extend(targets.iter().map(label_name)),
+                Operand::Uints(values) => parts.extend(values.iter().map(u64::to_string)),
+                Operand::Bytes(bytes) => parts.push(format_bytes(bytes)),
+                Operand::ByteStrings(values) => {
+                    parts.extend(values.iter().map(|v| format_bytes(v)))
+                }
+            }
+        }
+        let mut line = parts.join(" ");
+
+        // Annotate constant loads and printable literals with their value
+        let comment = match (self.name, self.operands.as_slice()) {
+            ("intc", [Operand::Uint(idx)]) => int_constants.get(*idx as usize).map(u64::to_string),
+            ("bytec", [Operand::Uint(idx)]) => byte_constants
+                .get(*idx as usize)
+                .map(|bytes| format_bytes_comment(bytes)),
+            ("pushbytes", [Operand::Bytes(bytes)]) => {
+                printable_ascii(bytes).map(|text| format!("{text:?}"))
+            }
+            (name, []) => constant_slot(name, "intc_")
+                .and_then(|idx| int_constants.get(idx))
+                .map(u64::to_string)
+                .or_else(|| {
+                    constant_slot(name, "bytec_")
+                        .and_then(|idx| byte_constants.get(idx))
+                        .map(|bytes| format_bytes_comment(bytes))
+                }),
+            _ => None,
+        };
+        if let Some(comment) = comment {
+            line.push_str(" // ");
+            line.push_str(&comment);
+        }
+        line
+    }
+}
+
+/// Parses the slot number out of `intc_N` / `bytec_N` shortcut opcodes.
+fn constant_slot(name: &str, prefix: &str) -> Option<usize> {
+    name.strip_prefix(prefix)?.parse().ok()
+}
+
+/// Formats a byte string as a `0x` hex literal.
+fn format_bytes(bytes: &[u8]) -> String {
+    let hex: String = bytes.iter().map(|b| format!("{b:02x}")).collect();
+    format!("0x{hex}")
+}
+
+/// Formats a byte string for a trailing comment, preferring readable text.
+fn format_bytes_comment(bytes: &[u8]) -> String {
+    printable_ascii(bytes).map_or_else(|| format_bytes(bytes), |text| format!("{text:?}"))
+}
+
+/// Returns the bytes as text if they are non-empty printable ASCII.
+fn printable_ascii(bytes: &[u8]) -> Option<&str> {
+    if !bytes.is_empty() && bytes.iter().all(|b| (0x20..0x7f).contains(b)) {
+        std::str::from_utf8(bytes).ok()
+    } else {
+        None
+    }
+}
+
+fn decode_instruction(reader: &mut Reader<'_>) -> Result<Instruction, String> {
+    let pc = reader.pos;
+    let opcode = reader
+        .byte()
+        .ok_or_else(|| format!("unexpected end of program at pc {pc}"))?;
+    let (name, imms) =
+        op_spec(opcode).ok_or_else(|| format!("unknown opcode 0x{opcode:02x} at pc {pc}"))?;
+
+    let truncated = || format!("truncated immediate for {name} at pc {pc}");
+    let mut branch_offsets: Vec<(usize, i16)> = Vec::new();
+    let mut operands = Vec::with_capacity(imms.len());
+    for imm in imms {
+        let operand = match imm {
+            Imm::Uint8 => Operand::Uint(u64::from(reader.byte().ok_or_else(truncated)?)),
+            Imm::Int8 => Operand::Int(i64::from(reader.byte().ok_or_else(truncated)? as i8)),
+            Imm::Label => {
+                branch_offsets.push((operands.len(), reader.int16().ok_or_else(truncated)?));
+                Operand::Target(None)
+            }
+            Imm::Varuint => Operand::Uint(reader.varuint().ok_or_else(truncated)?),
+            Imm::Bytes => Operand::Bytes(reader.length_prefixed().ok_or_else(truncated)?),
+            Imm::Varuints => {
+                let count = reader.varuint().ok_or_else(truncated)?;
+                let mut values = Vec::new();
+                for _ in 0..count {
+                    values.push(reader.varuint().ok_or_else(truncated)?);
+                }
+                Operand::Uints(values)
+            }
+            Imm::ByteStrings => {
+                let count = reader.varuint().ok_or_else(truncated)?;
+                let mut values = Vec::new();
+                for _ in 0..count {
+                    values.push(reader.length_prefixed().ok_or_else(truncated)?);
+                }
+                Operand::ByteStrings(values)
+            }
+            Imm::Labels => {
+                let count = reader.byte().ok_or_else(truncated)?;
+                let mut offsets = Vec::with_capacity(usize::from(count));
+                for _ in 0..count {
+                    offsets.push(reader.int16().ok_or_else(truncated)?);
+                }
+                // Offsets are relative to the end of the whole instruction
+                let end = reader.pos;
+                Operand::Targets(offsets.into_iter().map(|o| resolve(end, o)).collect())
+            }
+            Imm::Field(names) => {
+                let idx = reader.byte().ok_or_else(truncated)?;
+                Operand::Field(
+                    names
+                        .get(usize::from(idx))
+                        .map_or_else(|| idx.to_string(), |name| (*name).to_string()),
+                )
+            }
+        };
+        operands.push(operand);
+    }
+
+    // Single-label branches are relative to the end of the instruction
+    for (idx, offset) in branch_offsets {
+        operands[idx] = Operand::Target(resolve(reader.pos, offset));
+    }
+
+    Ok(Instruction { pc, name, operands })
+}
+
+/// Resolves a relative branch offset to an absolute program counter.
+fn resolve(base: usize, offset: i16) -> Option<usize> {
+    base.checked_add_signed(isize::from(offset))
+}
+
+// ============================================================================
+// Byte Reader
+// ============================================================================
+
+/// Cursor over program bytes with the AVM's immediate encodings.
+struct Reader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    const fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes, pos: 0 }
+    }
+
+    const fn at_end(&self) -> bool {
+        self.pos >= self.bytes.len()
+    }
+
+    fn byte(&mut self) -> Option<u8> {
+        let b = *self.bytes.get(self.pos)?;
+        self.pos += 1;
+        Some(b)
+    }
+
+    fn int16(&mut self) -> Option<i16> {
+        let hi = self.byte()?;
+        let lo = self.byte()?;
+        Some(i16::from_be_bytes([hi, lo]))
+    }
+
+    fn varuint(&mut self) -> Option<u64> {
+        let mut value: u64 = 0;
+        for shift in (0..64).step_by(7) {
+            let b = self.byte()?;
+            value |= u64::from(b & 0x7f) << shift;
+            if b & 0x80 == 0 {
+                return Some(value);
+            }
+        }
+        None
+    }
+
+    fn length_prefixed(&mut self) -> Option<Vec<u8>> {
+        let len = usize::try_from(self.varuint()?).ok()?;
+        let end = self.pos.checked_add(len)?;
+        let slice = self.bytes.get(self.pos..end)?;
+        self.pos = end;
+        Some(slice.to_vec())
+    }
+}
+
+// ============================================================================
+// Opcode Table
+// ============================================================================
+
+const TXN_FIELDS: &[&str] = &[
+    "Sender",
+    "Fee",
+    "FirstValid",
+    "FirstValidTime",
+    "LastValid",
+    "Note",
+    "Lease",
+    "Receiver",
+    "Amount",
+    "CloseRemainderTo",
+    "VotePK",
+    "SelectionPK",
+    "VoteFirst",
+    "VoteLast",
+    "VoteKeyDilution",
+    "Type",
+    "TypeEnum",
+    "XferAsset",
+    "AssetAmount",
+    "AssetSender",
+    "AssetReceiver",
+    "AssetCloseTo",
+    "GroupIndex",
+    "TxID",
+    "ApplicationID",
+    "OnCompletion",
+    "ApplicationArgs",
+    "NumAppArgs",
+    "Accounts",
+    "NumAccounts",
+    "ApprovalProgram",
+    "ClearStateProgram",
+    "RekeyTo",
+    "ConfigAsset",
+    "ConfigAssetTotal",
+    "ConfigAssetDecimals",
+    "ConfigAssetDefaultFrozen",
+    "ConfigAssetUnitName",
+    "ConfigAssetName",
+    "ConfigAssetURL",
+    "ConfigAssetMetadataHash",
+    "ConfigAssetManager",
+    "ConfigAssetReserve",
+    "ConfigAssetFreeze",
+    "ConfigAssetClawback",
+    "FreezeAsset",
+    "FreezeAssetAccount",
+    "FreezeAssetFrozen",
+    "Assets",
+    "NumAssets",
+    "Applications",
+    "NumApplications",
+    "GlobalNumUint",
+    "GlobalNumByteSlice",
+    "LocalNumUint",
+    "LocalNumByteSlice",
+    "ExtraProgramPages",
+    "Nonparticipation",
+    "Logs",
+    "NumLogs",
+    "CreatedAssetID",
+    "CreatedApplicationID",
+    "LastLog",
+    "StateProofPK",
+    "ApprovalProgramPages",
+    "NumApprovalProgramPages",
+    "ClearStateProgramPages",
+    "NumClearStateProgramPages",
+    "RejectVersion",
+];
+
+const GLOBAL_FIELDS: &[&str] = &[
+    "MinTxnFee",
+    "MinBalance",
+    "MaxTxnLife",
+    "ZeroAddress",
+    "GroupSize",
+    "LogicSigVersion",
+    "Round",
+    "LatestTimestamp",
+    "CurrentApplicationID",
+    "CreatorAddress",
+    "CurrentApplicationAddress",
+    "GroupID",
+    "OpcodeBudget",
+    "CallerApplicationID",
+    "CallerApplicationAddress",
+    "AssetCreateMinBalance",
+    "AssetOptInMinBalance",
+    "GenesisHash",
+    "PayoutsEnabled",
+    "PayoutsGoOnlineFee",
+    "PayoutsPercent",
+    "PayoutsMinBalance",
+    "PayoutsMaxBalance",
+];
+
+const ASSET_HOLDING_FIELDS: &[&str] = &["AssetBalance", "AssetFrozen"];
+
+const ASSET_PARAMS_FIELDS: &[&str] = &[
+    "AssetTotal",
+    "AssetDecimals",
+    "AssetDefaultFrozen",
+    "AssetUnitName",
+    "AssetName",
+    "AssetURL",
+    "AssetMetadataHash",
+    "AssetManager",
+    "AssetReserve",
+    "AssetFreeze",
+    "AssetClawback",
+    "AssetCreator",
+];
+
+const APP_PARAMS_FIELDS: &[&str] = &[
+    "AppApprovalProgram",
+    "AppClearStateProgram",
+    "AppGlobalNumUint",
+    "AppGlobalNumByteSlice",
+    "AppLocalNumUint",
+    "AppLocalNumByteSlice",
+    "AppExtraProgramPages",
+    "AppCreator",
+    "AppAddress",
+    "AppVersion",
+];
+
+const ACCT_PARAMS_FIELDS: &[&str] = &[
+    "AcctBalance",
+    "AcctMinBalance",
+    "AcctAuthAddr",
+    "AcctTotalNumUint",
+    "AcctTotalNumByteSlice",
+    "AcctTotalExtraAppPages",
+    "AcctTotalAppsCreated",
+    "AcctTotalAppsOptedIn",
+    "AcctTotalAssetsCreated",
+    "AcctTotalAssets",
+    "AcctTotalBoxes",
+    "AcctTotalBoxBytes",
+    "AcctIncentiveEligible",
+    "AcctLastProposed",
+    "AcctLastHeartbeat",
+];
+
+const VOTER_PARAMS_FIELDS: &[&str] = &["VoterBalance", "VoterIncentiveEligible"];
+
+const BLOCK_FIELDS: &[&str] = &[
+    "BlkSeed",
+    "BlkTimestamp",
+    "BlkProposer",
+    "BlkFeesCollected",
+    "BlkBonus",
+    "BlkBranch",
+    "BlkFeeSink",
+    "BlkProtocol",
+    "BlkTxnCounter",
+    "BlkProposerPayout",
+];
+
+const ECDSA_CURVES: &[&str] = &["Secp256k1", "Secp256r1"];
+const EC_GROUPS: &[&str] = &["BN254g1", "BN254g2", "BLS12_381g1", "BLS12_381g2"];
+const BASE64_ENCODINGS: &[&str] = &["URLEncoding", "StdEncoding"];
+const JSON_REF_TYPES: &[&str] = &["JSONString", "JSONUint64", "JSONObject"];
+const VRF_STANDARDS: &[&str] = &["VrfAlgorand"];
+const MIMC_CONFIGS: &[&str] = &["BN254Mp110", "BLS12_381Mp111"];
+
+const NONE: &[Imm] = &[];
+const U8: &[Imm] = &[Imm::Uint8];
+const U8_U8: &[Imm] = &[Imm::Uint8, Imm::Uint8];
+const LABEL: &[Imm] = &[Imm::Label];
+const TXN: &[Imm] = &[Imm::Field(TXN_FIELDS)];
+const TXN_U8: &[Imm] = &[Imm::Field(TXN_FIELDS), Imm::Uint8];
+const U8_TXN: &[Imm] = &[Imm::Uint8, Imm::Field(TXN_FIELDS)];
+const U8_TXN_U8: &[Imm] = &[Imm::Uint8, Imm::Field(TXN_FIELDS), Imm::Uint8];
+const ECDSA: &[Imm] = &[Imm::Field(ECDSA_CURVES)];
+const EC: &[Imm] = &[Imm::Field(EC_GROUPS)];
+
+/// Looks up the mnemonic and immediate layout for an opcode byte.
+#[allow(clippy::too_many_lines)]
+const fn op_spec(opcode: u8) -> Option<(&'static str, &'static [Imm])> {
+    let spec: (&'static str, &'static [Imm]) = match opcode {
+        0x00 => ("err", NONE),
+        0x01 => ("sha256", NONE),
+        0x02 => ("keccak256", NONE),
+        0x03 => ("sha512_256", NONE),
+        0x04 => ("ed25519verify", NONE),
+        0x05 => ("ecdsa_verify", ECDSA),
+        0x06 => ("ecdsa_pk_decompress", ECDSA),
+        0x07 => ("ecdsa_pk_recover", ECDSA),
+        0x08 => ("+", NONE),
+        0x09 => ("-", NONE),
+        0x0a => ("/", NONE),
+        0x0b => ("*", NONE),
+        0x0c => ("<", NONE),
+        0x0d => (">", NONE),
+        0x0e => ("<=", NONE),
+        0x0f => (">=", NONE),
+        0x10 => ("&&", NONE),
+        0x11 => ("||", NONE),
+        0x12 => ("==", NONE),
+        0x13 => ("!=", NONE),
+        0x14 => ("!", NONE),
+        0x15 => ("len", NONE),
+        0x16 => ("itob", NONE),
+        0x17 => ("btoi", NONE),
+        0x18 => ("%", NONE),
+        0x19 => ("|", NONE),
+        0x1a => ("&", NONE),
+        0x1b => ("^", NONE),
+        0x1c => ("~", NONE),
+        0x1d => ("mulw", NONE),
+        0x1e => ("addw", NONE),
+        0x1f => ("divmodw", NONE),
+        0x20 => ("intcblock", &[Imm::Varuints]),
+        0x21 => ("intc", U8),
+        0x22 => ("intc_0", NONE),
+        0x23 => ("intc_1", NONE),
+        0x24 => ("intc_2", NONE),
+        0x25 => ("intc_3", NONE),
+        0x26 => ("bytecblock", &[Imm::ByteStrings]),
+        0x27 => ("bytec", U8),
+        0x28 => ("bytec_0", NONE),
+        0x29 => ("bytec_1", NONE),
+        0x2a => ("bytec_2", NONE),
+        0x2b => ("bytec_3", NONE),
+        0x2c => ("arg", U8),
+        0x2d => ("arg_0", NONE),
+        0x2e => ("arg_1", NONE),
+        0x2f => ("arg_2", NONE),
+        0x30 => ("arg_3", NONE),
+        0x31 => ("txn", TXN),
+        0x32 => ("global", &[Imm::Field(GLOBAL_FIELDS)]),
+        0x33 => ("gtxn", U8_TXN),
+        0x34 => ("load", U8),
+        0x35 => ("store", U8),
+        0x36 => ("txna", TXN_U8),
+        0x37 => ("gtxna", U8_TXN_U8),
+        0x38 => ("gtxns", TXN),
+        0x39 => ("gtxnsa", TXN_U8),
+        0x3a => ("gload", U8_U8),
+        0x3b => ("gloads", U8),
+        0x3c => ("gaid", U8),
+        0x3d => ("gaids", NONE),
+        0x3e => ("loads", NONE),
+        0x3f => ("stores", NONE),
+        0x40 => ("bnz", LABEL),
+        0x41 => ("bz", LABEL),
+        0x42 => ("b", LABEL),
+        0x43 => ("return", NONE),
+        0x44 => ("assert", NONE),
+        0x45 => ("bury", U8),
+        0x46 => ("popn", U8),
+        0x47 => ("dupn", U8),
+        0x48 => ("pop", NONE),
+        0x49 => ("dup", NONE),
+        0x4a => ("dup2", NONE),
+        0x4b => ("dig", U8),
+        0x4c => ("swap", NONE),
+        0x4d => ("select", NONE),
+        0x4e => ("cover", U8),
+        0x4f => ("uncover", U8),
+        0x50 => ("concat", NONE),
+        0x51 => ("substring", U8_U8),
+        0x52 => ("substring3", NONE),
+        0x53 => ("getbit", NONE),
+        0x54 => ("setbit", NONE),
+        0x55 => ("getbyte", NONE),
+        0x56 => ("setbyte", NONE),
+        0x57 => ("extract", U8_U8),
+        0x58 => ("extract3", NONE),
+        0x59 => ("extract_uint16", NONE),
+        0x5a => ("extract_uint32", NONE),
+        0x5b => ("extract_uint64", NONE),
+        0x5c => ("replace2", U8),
+        0x5d => ("replace3", NONE),
+        0x5e => ("base64_decode", &[Imm::Field(BASE64_ENCODINGS)]),
+        0x5f => ("json_ref", &[Imm::Field(JSON_REF_TYPES)]),
+        0x60 => ("balance", NONE),
+        0x61 => ("app_opted_in", NONE),
+        0x62 => ("app_local_get", NONE),
+        0x63 => ("app_local_get_ex", NONE),
+        0x64 => ("app_global_get", NONE),
+        0x65 => ("app_global_get_ex", NONE),
+        0x66 => ("app_local_put", NONE),
+        0x67 => ("app_global_put", NONE),
+        0x68 => ("app_local_del", NONE),
+        0x69 => ("app_global_del", NONE),
+        0x70 => ("asset_holding_get", &[Imm::Field(ASSET_HOLDING_FIELDS)]),
+        0x71 => ("asset_params_get", &[Imm::Field(ASSET_PARAMS_FIELDS)]),
+        0x72 => ("app_params_get", &[Imm::Field(APP_PARAMS_FIELDS)]),
+        0x73 => ("acct_params_get", &[Imm::Field(ACCT_PARAMS_FIELDS)]),
+        0x74 => ("voter_params_get", &[Imm::Field(VOTER_PARAMS_FIELDS)]),
+        0x75 => ("online_stake", NONE),
+        0x78 => ("min_balance", NONE),
+        0x80 => ("pushbytes", &[Imm::Bytes]),
+        0x81 => ("pushint", &[Imm::Varuint]),
+        0x82 => ("pushbytess", &[Imm::ByteStrings]),
+        0x83 => ("pushints", &[Imm::Varuints]),
+        0x84 => ("ed25519verify_bare", NONE),
+        0x85 => ("falcon_verify", NONE),
+        0x86 => ("sumhash512", NONE),
+        0x88 => ("callsub", LABEL),
+        0x89 => ("retsub", NONE),
+        0x8a => ("proto", U8_U8),
+        0x8b => ("frame_dig", &[Imm::Int8]),
+        0x8c => ("frame_bury", &[Imm::Int8]),
+        0x8d => ("switch", &[Imm::Labels]),
+        0x8e => ("match", &[Imm::Labels]),
+        0x90 => ("shl", NONE),
+        0x91 => ("shr", NONE),
+        0x92 => ("sqrt", NONE),
+        0x93 => ("bitlen", NONE),
+        0x94 => ("exp", NONE),
+        0x95 => ("expw", NONE),
+        0x96 => ("bsqrt", NONE),
+        0x97 => ("divw", NONE),
+        0x98 => ("sha3_256", NONE),
+        0xa0 => ("b+", NONE),
+        0xa1 => ("b-", NONE),
+        0xa2 => ("b/", NONE),
+        0xa3 => ("b*", NONE),
+        0xa4 => ("b<", NONE),
+        0xa5 => ("b>", NONE),
+        0xa6 => ("b<=", NONE),
+        0xa7 => ("b>=", NONE),
+        0xa8 => ("b==", NONE),
+        0xa9 => ("b!=", NONE),
+        0xaa => ("b%", NONE),
+        0xab => ("b|", NONE),
+        0xac => ("b&", NONE),
+        0xad => ("b^", NONE),
+        0xae => ("b~", NONE),
+        0xaf => ("bzero", NONE),
+        0xb0 => ("log", NONE),
+        0xb1 => ("itxn_begin", NONE),
+        0xb2 => ("itxn_field", TXN),
+        0xb3 => ("itxn_submit", NONE),
+        0xb4 => ("itxn", TXN),
+        0xb5 => ("itxna", TXN_U8),
+        0xb6 => ("itxn_next", NONE),
+        0xb7 => ("gitxn", U8_TXN),
+        0xb8 => ("gitxna", U8_TXN_U8),
+        0xb9 => ("box_create", NONE),
+        0xba => ("box_extract", NONE),
+        0xbb => ("box_replace", NONE),
+        0xbc => ("box_del", NONE),
+        0xbd => ("box_len", NONE),
+        0xbe => ("box_get", NONE),
+        0xbf => ("box_put", NONE),
+        0xc0 => ("txnas", TXN),
+        0xc1 => ("gtxnas", U8_TXN),
+        0xc2 => ("gtxnsas", TXN),
+        0xc3 => ("args", NONE),
+        0xc4 => ("gloadss", NONE),
+        0xc5 => ("itxnas", TXN),
+        0xc6 => ("gitxnas", U8_TXN),
+        0xd0 => ("vrf_verify", &[Imm::Field(VRF_STANDARDS)]),
+        0xd1 => ("block", &[Imm::Field(BLOCK_FIELDS)]),
+        0xd2 => ("box_splice", NONE),
+        0xd3 => ("box_resize", NONE),
+        0xe0 => ("ec_add", EC),
+        0xe1 => ("ec_scalar_mul", EC),
+        0xe2 => ("ec_pairing_check", EC),
+        0xe3 => ("ec_multi_scalar_mul", EC),
+        0xe4 => ("ec_subgroup_check", EC),
+        0xe5 => ("ec_map_to", EC),
+        0xe6 => ("mimc", &[Imm::Field(MIMC_CONFIGS)]),
+        _ => return None,
+    };
+    Some(spec)
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[test]
+    fn test_disassemble_constant_blocks_and_branches() {
+        // #pragma version 4; intcblock 1 0; bytecblock 0x;
+        // txn ApplicationID; intc_0; ==; bnz ...
+        let listing = disassemble_base64(
+            "BCACAQAmAQAxGCISQAAoMRkjEkAAGDEZIxJAAAgxGSQSQAABADEbQQAKIjUBQQAGMQA0ARJA",
+        )
+        .unwrap();
+
+        assert_eq!(listing.version, 4);
+        assert_eq!(listing.lines[0], "#pragma version 4");
+        assert_eq!(listing.lines[1], "intcblock 1 0");
+        assert_eq!(listing.lines[2], "bytecblock 0x");
+        assert_eq!(listing.lines[3], "txn ApplicationID");
+        assert_eq!(listing.lines[4], "intc_0 // 1");
+        assert_eq!(listing.lines[5], "==");
+        assert!(listing.lines[6].starts_with("bnz label"));
+        assert!(listing.lines.iter().any(|line| line == "txn OnCompletion"));
+        assert!(listing.lines.iter().any(|line| line.ends_with(':')));
+    }
+
+    #[test]
+    fn test_disassemble_clear_program() {
+        let listing = disassemble_base64("BIEB").unwrap();
+        assert_eq!(listing.lines, vec!["#pragma version 4", "pushint 1"]);
+    }
+
+    #[test]
+    fn test_branch_labels_are_numbered_by_address() {
+        let program = [
+            0x08, // version 8
+            0x88, 0x00, 0x01, // callsub -> pc 5
+            0x00, // err
+            0x42, 0xff, 0xfc, // b -> pc 4
+            0x89, // retsub
+        ];
+        let listing = disassemble(&program);
+        assert_eq!(
+            listing.lines,
+            vec![
+                "#pragma version 8",
+                "callsub label2",
+                "label1:",
+                "err",
+                "label2:",
+                "b label1",
+                "retsub",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_switch_targets_and_frame_immediates() {
+        let program = [
+            0x08, // version 8
+            0x8d, 0x02, 0x00, 0x00, 0x00, 0x02, // switch -> pc 7, pc 9
+            0x8b, 0xff, // frame_dig -1
+            0x43, // return
+        ];
+        let listing = disassemble(&program);
+        assert_eq!(
+            listing.lines,
+            vec![
+                "#pragma version 8",
+                "switch label1 label2",
+                "label1:",
+                "frame_dig -1",
+                "label2:",
+                "return",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_push_literals_and_bytec_comments() {
+        let program = [
+            0x06, // version 6
+            0x26, 0x01, 0x05, b'o', b'w', b'n', b'e', b'r', // bytecblock "owner"
+            0x28, // bytec_0
+            0x80, 0x02, 0xca, 0xfe, // pushbytes 0xcafe
+            0x80, 0x02, b'h', b'i', // pushbytes "hi"
+            0x81, 0xe8, 0x07, // pushint 1000
+            0x64, // app_global_get
+        ];
+        let listing = disassemble(&program);
+        assert_eq!(
+            listing.lines,
+            vec![
+                "#pragma version 6",
+                "bytecblock 0x6f776e6572",
+                "bytec_0 // \"owner\"",
+                "pushbytes 0xcafe",
+                "pushbytes 0x6869 // \"hi\"",
+                "pushint 1000",
+                "app_global_get",
+            ]
+        );
+    }
+
+    #[rstest]
+    #[case::unknown_opcode(&[0x08, 0x49, 0xff], "// error: unknown opcode 0xff at pc 2")]
+    #[case::truncated_immediate(&[0x08, 0x81, 0x80], "// error: truncated immediate for pushint at pc 1")]
+    fn test_partial_listing_keeps_prefix(#[case] program: &[u8], #[case] last: &str) {
+        let listing = disassemble(program);
+        assert_eq!(listing.lines.last().map(String::as_str), Some(last));
+    }
+
+    #[test]
+    fn test_invalid_base64_is_parse_error() {
+        let result = disassemble_base64("not base64!");
+        assert!(matches!(result, Err(AlgoError::Parse { .. })));
+    }
+}
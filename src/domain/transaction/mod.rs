@@ -9,16 +9,19 @@
 //! - [`types`] - Type-specific detail structs (PaymentDetails, AppCallDetails, etc.)
 //! - [`parsing`] - JSON parsing logic for transactions
 
-use ratatui::style::Color;
+use serde::Serialize;
 use serde_json::Value;
 
+use super::signature::TransactionSignature;
+
 pub mod parsing;
 pub mod types;
 
 // Re-export all public types for convenient access
 pub use types::{
     AppCallDetails, AssetConfigDetails, AssetFreezeDetails, AssetTransferDetails, HeartbeatDetails,
-    KeyRegDetails, OnComplete, PaymentDetails, StateProofDetails, TransactionDetails,
+    KeyRegDetails, LocalStateDelta, OnComplete, PaymentDetails, StateDelta, StateDeltaAction,
+    StateProofDetails, TransactionDetails,
 };
 
 // ============================================================================
@@ -54,7 +57,7 @@ pub fn format_timestamp(timestamp_secs: u64) -> String {
 ///
 /// Each variant represents a different category of transaction
 /// that can be performed on the Algorand blockchain.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize)]
 pub enum TxnType {
     /// Payment transaction - transfers Algos between accounts.
     Payment,
@@ -78,6 +81,51 @@ pub enum TxnType {
 }
 
 impl TxnType {
+    /// Transaction types that can be used as indexer `tx-type` filters,
+    /// in the order the UI cycles through them.
+    pub const FILTERABLE: [Self; 8] = [
+        Self::Payment,
+        Self::AssetTransfer,
+        Self::AppCall,
+        Self::AssetConfig,
+        Self::AssetFreeze,
+        Self::KeyReg,
+        Self::StateProof,
+        Self::Heartbeat,
+    ];
+
+    /// Returns the protocol type code (`pay`, `appl`, ...) used by the indexer
+    /// `tx-type` parameter, or `None` for unknown types.
+    #[must_use]
+    pub const fn api_code(&self) -> Option<&'static str> {
+        match self {
+            Self::Payment => Some("pay"),
+            Self::AppCall => Some("appl"),
+            Self::AssetTransfer => Some("axfer"),
+            Self::AssetConfig => Some("acfg"),
+            Self::AssetFreeze => Some("afrz"),
+            Self::KeyReg => Some("keyreg"),
+            Self::StateProof => Some("stpf"),
+            Self::Heartbeat => Some("hb"),
+            Self::Unknown => None,
+        }
+    }
+
+    /// Returns the next type filter in [`Self::FILTERABLE`] order.
+    ///
+    /// `None` means "all types"; cycling past the last type wraps back to `None`.
+    #[must_use]
+    pub fn next_filter(filter: Option<Self>) -> Option<Self> {
+        match filter {
+            None => Some(Self::FILTERABLE[0]),
+            Some(current) => Self::FILTERABLE
+                .iter()
+                .position(|t| *t == current)
+                .and_then(|idx| Self::FILTERABLE.get(idx + 1))
+                .copied(),
+        }
+    }
+
     /// Returns the human-readable name of the transaction type.
     #[must_use]
     pub const fn as_str(&self) -> &str {
@@ -94,24 +142,6 @@ impl TxnType {
         }
     }
 
-    /// Returns the display color for this transaction type.
-    ///
-    /// Used for visual differentiation in the TUI.
-    #[must_use]
-    pub const fn color(&self) -> Color {
-        match self {
-            Self::Payment => Color::Green,
-            Self::AppCall => Color::Blue,
-            Self::AssetTransfer => Color::Yellow,
-            Self::AssetConfig => Color::Cyan,
-            Self::AssetFreeze => Color::Magenta,
-            Self::KeyReg => Color::Red,
-            Self::StateProof => Color::Gray,
-            Self::Heartbeat => Color::White,
-            Self::Unknown => Color::DarkGray,
-        }
-    }
-
     /// Determine transaction type from JSON data.
     #[must_use]
     pub fn from_json(txn_json: &Value) -> Self {
@@ -151,7 +181,7 @@ impl std::fmt::Display for TxnType {
 ///
 /// This is the main transaction type that contains all common fields
 /// plus type-specific details for different transaction categories.
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct Transaction {
     /// Transaction ID (52-character base32 string).
     pub id: String,
@@ -181,10 +211,43 @@ pub struct Transaction {
     pub details: TransactionDetails,
     /// Inner transactions (for app calls).
     pub inner_transactions: Vec<Transaction>,
+    /// How the transaction was signed (`None` for inner transactions).
+    pub signature: Option<TransactionSignature>,
 }
 
 // Note: Transaction::from_json is implemented in parsing.rs
 
+impl Transaction {
+    /// Returns the app calls that logged, in this transaction and all inner transactions.
+    ///
+    /// Each call is paired with its position in the inner transaction tree:
+    /// an empty path for this transaction, `"1"` for its first inner
+    /// transaction, `"1.2"` for that one's second inner transaction, and so on.
+    /// Transactions without logs are skipped.
+    #[must_use]
+    pub fn logs_by_path(&self) -> Vec<(String, &AppCallDetails)> {
+        let mut result = Vec::new();
+        self.collect_logs(String::new(), &mut result);
+        result
+    }
+
+    fn collect_logs<'a>(&'a self, path: String, out: &mut Vec<(String, &'a AppCallDetails)>) {
+        if let TransactionDetails::AppCall(details) = &self.details
+            && !details.logs.is_empty()
+        {
+            out.push((path.clone(), details));
+        }
+        for (idx, inner) in self.inner_transactions.iter().enumerate() {
+            let inner_path = if path.is_empty() {
+                (idx + 1).to_string()
+            } else {
+                format!("{path}.{}", idx + 1)
+            };
+            inner.collect_logs(inner_path, out);
+        }
+    }
+}
+
 // ============================================================================
 // Tests
 // ============================================================================
@@ -193,37 +256,54 @@ pub struct Transaction {
 mod tests {
     use super::*;
 
-    /// Tests TxnType string representation and color mapping.
+    /// Tests TxnType string representation.
+    ///
+    /// Display colors live in the theme, see `theme::Theme::txn_color`.
     #[test]
     fn test_txn_type_properties() {
         let test_cases = [
-            (TxnType::Payment, "Payment", Color::Green),
-            (TxnType::AppCall, "App Call", Color::Blue),
-            (TxnType::AssetTransfer, "Asset Transfer", Color::Yellow),
-            (TxnType::AssetConfig, "Asset Config", Color::Cyan),
-            (TxnType::AssetFreeze, "Asset Freeze", Color::Magenta),
-            (TxnType::KeyReg, "Key Registration", Color::Red),
-            (TxnType::StateProof, "State Proof", Color::Gray),
-            (TxnType::Heartbeat, "Heartbeat", Color::White),
-            (TxnType::Unknown, "Unknown", Color::DarkGray),
+            (TxnType::Payment, "Payment"),
+            (TxnType::AppCall, "App Call"),
+            (TxnType::AssetTransfer, "Asset Transfer"),
+            (TxnType::AssetConfig, "Asset Config"),
+            (TxnType::AssetFreeze, "Asset Freeze"),
+            (TxnType::KeyReg, "Key Registration"),
+            (TxnType::StateProof, "State Proof"),
+            (TxnType::Heartbeat, "Heartbeat"),
+            (TxnType::Unknown, "Unknown"),
         ];
 
-        for (txn_type, expected_str, expected_color) in test_cases {
+        for (txn_type, expected_str) in test_cases {
             assert_eq!(
                 txn_type.as_str(),
                 expected_str,
                 "{:?}.as_str() mismatch",
                 txn_type
             );
-            assert_eq!(
-                txn_type.color(),
-                expected_color,
-                "{:?}.color() mismatch",
-                txn_type
-            );
         }
     }
 
+    /// Tests indexer type codes and filter cycling.
+    #[test]
+    fn test_txn_type_filter_cycle() {
+        assert_eq!(TxnType::Payment.api_code(), Some("pay"));
+        assert_eq!(TxnType::AppCall.api_code(), Some("appl"));
+        assert_eq!(TxnType::Unknown.api_code(), None);
+
+        // Cycling from "all" visits every filterable type once, then wraps
+        let mut filter = None;
+        let mut visited = Vec::new();
+        loop {
+            filter = TxnType::next_filter(filter);
+            match filter {
+                Some(t) => visited.push(t),
+                None => break,
+            }
+        }
+        assert_eq!(visited, TxnType::FILTERABLE.to_vec());
+        assert!(visited.iter().all(|t| t.api_code().is_some()));
+    }
+
     /// Tests timestamp formatting for edge cases.
     #[test]
     fn test_format_timestamp() {
@@ -5,12 +5,14 @@
 
 use serde_json::Value;
 
+use crate::domain::application::{decode_state_key, format_state_bytes};
 use crate::domain::error::AlgoError;
+use crate::domain::signature::TransactionSignature;
 
 use super::types::{
     AppCallDetails, AssetConfigDetails, AssetFreezeDetails, AssetTransferDetails, BoxRef,
-    HeartbeatDetails, KeyRegDetails, OnComplete, PaymentDetails, StateProofDetails, StateSchema,
-    TransactionDetails,
+    HeartbeatDetails, KeyRegDetails, LocalStateDelta, OnComplete, PaymentDetails, StateDelta,
+    StateDeltaAction, StateProofDetails, StateSchema, TransactionDetails,
 };
 use super::{Transaction, TxnType, format_timestamp};
 
@@ -57,6 +59,7 @@ impl Transaction {
         let rekey_to = txn_json["rekey-to"].as_str().map(String::from);
         let group = txn_json["group"].as_str().map(String::from);
         let details = extract_details(txn_json, &txn_type);
+        let signature = TransactionSignature::from_json(&txn_json["signature"]);
 
         // Parse inner transactions recursively
         let inner_transactions = parse_inner_transactions(txn_json)?;
@@ -76,6 +79,7 @@ impl Transaction {
             group,
             details,
             inner_transactions,
+            signature,
         })
     }
 }
@@ -285,6 +289,30 @@ fn extract_app_call_details(txn_json: &Value) -> TransactionDetails {
         .map(|arr| arr.iter().filter_map(|v| v.as_u64()).collect())
         .unwrap_or_default();
 
+    // Logs live on the transaction itself rather than the application fields
+    let logs = txn_json["logs"]
+        .as_array()
+        .map(|arr| {
+            arr.iter()
+                .filter_map(|v| v.as_str().map(String::from))
+                .collect()
+        })
+        .unwrap_or_default();
+
+    // State deltas are also reported on the transaction itself
+    let global_state_delta = parse_state_delta(&txn_json["global-state-delta"]);
+    let local_state_delta = txn_json["local-state-delta"]
+        .as_array()
+        .map(|arr| {
+            arr.iter()
+                .map(|entry| LocalStateDelta {
+                    address: entry["address"].as_str().unwrap_or("unknown").to_string(),
+                    deltas: parse_state_delta(&entry["delta"]),
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
     let boxes = appl["boxes"]
         .as_array()
         .map(|arr| {
@@ -335,9 +363,38 @@ fn extract_app_call_details(txn_json: &Value) -> TransactionDetails {
         global_state_schema,
         local_state_schema,
         extra_program_pages: appl["extra-program-pages"].as_u64(),
+        logs,
+        global_state_delta,
+        local_state_delta,
     })
 }
 
+/// Parse an indexer state delta list (`[{key, value: {action, bytes, uint}}]`).
+///
+/// Actions follow the indexer encoding: 1 = set bytes, 2 = set uint, 3 = delete.
+#[must_use]
+fn parse_state_delta(delta_json: &Value) -> Vec<StateDelta> {
+    let Some(entries) = delta_json.as_array() else {
+        return Vec::new();
+    };
+
+    entries
+        .iter()
+        .filter_map(|entry| {
+            let key = decode_state_key(entry["key"].as_str()?);
+            let value = &entry["value"];
+            let action = match value["action"].as_u64()? {
+                1 => StateDeltaAction::SetBytes(format_state_bytes(
+                    value["bytes"].as_str().unwrap_or(""),
+                )),
+                2 => StateDeltaAction::SetUint(value["uint"].as_u64().unwrap_or(0)),
+                _ => StateDeltaAction::Delete,
+            };
+            Some(StateDelta { key, action })
+        })
+        .collect()
+}
+
 /// Extract key registration transaction details.
 #[must_use]
 fn extract_keyreg_details(txn_json: &Value) -> TransactionDetails {
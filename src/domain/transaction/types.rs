@@ -4,6 +4,8 @@
 //! including payment, asset transfer, asset config, asset freeze, application call,
 //! key registration, state proof, and heartbeat transactions.
 
+use serde::Serialize;
+
 // ============================================================================
 // Transaction Details - Type-specific metadata
 // ============================================================================
@@ -12,7 +14,7 @@
 ///
 /// Contains additional information specific to each transaction type,
 /// providing access to fields that are only relevant for certain operations.
-#[derive(Debug, Clone, PartialEq, Default)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize)]
 pub enum TransactionDetails {
     /// No additional details available.
     #[default]
@@ -67,7 +69,7 @@ impl TransactionDetails {
 ///
 /// Contains additional information specific to payment transactions,
 /// particularly for close-out operations.
-#[derive(Debug, Clone, PartialEq, Default)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize)]
 pub struct PaymentDetails {
     /// Address to receive remaining funds when closing out.
     pub close_remainder_to: Option<String>,
@@ -83,7 +85,7 @@ pub struct PaymentDetails {
 ///
 /// Contains additional information for asset transfer operations,
 /// including clawback and close-out information.
-#[derive(Debug, Clone, PartialEq, Default)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize)]
 pub struct AssetTransferDetails {
     /// For clawback transactions, the address being clawed back from.
     pub asset_sender: Option<String>,
@@ -100,7 +102,7 @@ pub struct AssetTransferDetails {
 /// Asset configuration transaction details.
 ///
 /// Contains all parameters for asset creation, modification, or destruction.
-#[derive(Debug, Clone, PartialEq, Default)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize)]
 pub struct AssetConfigDetails {
     /// Asset ID for modify/destroy (None for create).
     pub asset_id: Option<u64>,
@@ -137,7 +139,7 @@ pub struct AssetConfigDetails {
 /// Asset freeze transaction details.
 ///
 /// Contains information about freeze/unfreeze operations on asset holdings.
-#[derive(Debug, Clone, PartialEq, Default)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize)]
 pub struct AssetFreezeDetails {
     /// Whether the target is being frozen or unfrozen.
     pub frozen: bool,
@@ -153,7 +155,7 @@ pub struct AssetFreezeDetails {
 ///
 /// Contains all parameters for smart contract interactions including
 /// creation, calls, updates, and deletions.
-#[derive(Debug, Clone, PartialEq, Default)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize)]
 pub struct AppCallDetails {
     /// Application ID (0 for creation).
     pub app_id: u64,
@@ -181,6 +183,66 @@ pub struct AppCallDetails {
     pub local_state_schema: Option<StateSchema>,
     /// Extra program pages for large programs.
     pub extra_program_pages: Option<u64>,
+    /// Logs emitted by the call (Base64 encoded).
+    pub logs: Vec<String>,
+    /// Global state changes made by the call.
+    pub global_state_delta: Vec<StateDelta>,
+    /// Local state changes made by the call, per account.
+    pub local_state_delta: Vec<LocalStateDelta>,
+}
+
+impl AppCallDetails {
+    /// Returns true if the call changed any global or local state.
+    #[must_use]
+    pub fn has_state_changes(&self) -> bool {
+        !self.global_state_delta.is_empty() || !self.local_state_delta.is_empty()
+    }
+}
+
+// ============================================================================
+// State Deltas
+// ============================================================================
+
+/// A single key change in application global or local state.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize)]
+pub struct StateDelta {
+    /// The key name (decoded from Base64).
+    pub key: String,
+    /// What happened to the key.
+    pub action: StateDeltaAction,
+}
+
+/// The change applied to a state key.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize)]
+pub enum StateDeltaAction {
+    /// Key set to a byte value (decoded/formatted).
+    SetBytes(String),
+    /// Key set to a uint value.
+    SetUint(u64),
+    /// Key deleted.
+    #[default]
+    Delete,
+}
+
+impl StateDeltaAction {
+    /// Returns a short description of the change, e.g. `set uint 5` or `delete`.
+    #[must_use]
+    pub fn describe(&self) -> String {
+        match self {
+            Self::SetBytes(value) => format!("set bytes {}", value),
+            Self::SetUint(value) => format!("set uint {}", value),
+            Self::Delete => "delete".to_string(),
+        }
+    }
+}
+
+/// State changes made to one account's local state.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize)]
+pub struct LocalStateDelta {
+    /// The account whose local state changed.
+    pub address: String,
+    /// Key changes for that account.
+    pub deltas: Vec<StateDelta>,
 }
 
 // ============================================================================
@@ -190,7 +252,7 @@ pub struct AppCallDetails {
 /// Application call on-completion type.
 ///
 /// Specifies what action to take after the application call completes.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize)]
 pub enum OnComplete {
     /// No additional action (default).
     #[default]
@@ -250,7 +312,7 @@ impl std::fmt::Display for OnComplete {
 /// Box reference for application calls.
 ///
 /// References a box in an application's box storage.
-#[derive(Debug, Clone, PartialEq, Default)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize)]
 pub struct BoxRef {
     /// Application ID (0 means current app).
     pub app_id: u64,
@@ -265,7 +327,7 @@ pub struct BoxRef {
 /// State schema for application storage.
 ///
 /// Defines the storage requirements for application state.
-#[derive(Debug, Clone, PartialEq, Default)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize)]
 pub struct StateSchema {
     /// Number of uint64 values.
     pub num_uint: u64,
@@ -280,7 +342,7 @@ pub struct StateSchema {
 /// Key registration transaction details.
 ///
 /// Contains participation key information for consensus participation.
-#[derive(Debug, Clone, PartialEq, Default)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize)]
 pub struct KeyRegDetails {
     /// Voting public key (Base64 encoded).
     pub vote_key: Option<String>,
@@ -305,7 +367,7 @@ pub struct KeyRegDetails {
 /// State proof transaction details.
 ///
 /// Contains cryptographic proof information for blockchain state verification.
-#[derive(Debug, Clone, PartialEq, Default)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize)]
 pub struct StateProofDetails {
     /// Type of state proof.
     pub state_proof_type: Option<u64>,
@@ -320,7 +382,7 @@ pub struct StateProofDetails {
 /// Heartbeat transaction details.
 ///
 /// Contains node liveness indicator information.
-#[derive(Debug, Clone, PartialEq, Default)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize)]
 pub struct HeartbeatDetails {
     /// Heartbeat address.
     pub hb_address: Option<String>,
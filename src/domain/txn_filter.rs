@@ -0,0 +1,347 @@
+//! Live transaction feed filters.
+//!
+//! A filter is written as space-separated terms, all of which must match:
+//!
+//! ```text
+//! type:axfer asset:31566704 from:ADDR amount>1000000 app:123
+//! ```
+//!
+//! Supported terms are `type:`, `asset:`, `app:`, `from:`, `to:`, `addr:`
+//! and `amount` compared with `>`, `>=`, `<`, `<=` or `=`. Filters are
+//! applied client-side and, where the indexer supports it, pushed down
+//! into query parameters.
+
+use std::fmt;
+
+use super::account::is_valid_address;
+use super::{AlgoError, Transaction, TransactionDetails, TxnType};
+
+/// A parsed transaction feed filter.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TxnFilter {
+    /// Transaction type.
+    pub txn_type: Option<TxnType>,
+    /// Asset ID.
+    pub asset_id: Option<u64>,
+    /// Application ID.
+    pub app_id: Option<u64>,
+    /// Sender address.
+    pub from: Option<String>,
+    /// Receiver address.
+    pub to: Option<String>,
+    /// Address appearing as sender or receiver.
+    pub address: Option<String>,
+    /// Inclusive lower bound on the amount.
+    pub min_amount: Option<u64>,
+    /// Inclusive upper bound on the amount.
+    pub max_amount: Option<u64>,
+    /// The query as typed, normalized to single spaces.
+    query: String,
+}
+
+impl TxnFilter {
+    /// Parses a filter query.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AlgoError::InvalidInput`] for an empty query, an unknown
+    /// term or a malformed value.
+    pub fn parse(input: &str) -> Result<Self, AlgoError> {
+        let terms: Vec<&str> = input.split_whitespace().collect();
+        if terms.is_empty() {
+            return Err(AlgoError::invalid_input("Filter is empty"));
+        }
+
+        let mut filter = Self {
+            query: terms.join(" "),
+            ..Self::default()
+        };
+        for term in terms {
+            filter.apply_term(term)?;
+        }
+
+        if let (Some(min), Some(max)) = (filter.min_amount, filter.max_amount)
+            && min > max
+        {
+            return Err(AlgoError::invalid_input("Amount range is empty"));
+        }
+        Ok(filter)
+    }
+
+    fn apply_term(&mut self, term: &str) -> Result<(), AlgoError> {
+        if let Some(rest) = term.strip_prefix("amount") {
+            return self.apply_amount(rest);
+        }
+
+        let Some((key, value)) = term.split_once(':') else {
+            return Err(AlgoError::invalid_input(format!(
+                "Unknown filter term '{term}'"
+            )));
+        };
+        match key {
+            "type" => {
+                let txn_type = TxnType::FILTERABLE
+                    .into_iter()
+                    .find(|t| t.api_code() == Some(value))
+                    .ok_or_else(|| {
+                        AlgoError::invalid_input(format!("Unknown transaction type '{value}'"))
+                    })?;
+                self.txn_type = Some(txn_type);
+            }
+            "asset" => self.asset_id = Some(parse_number(key, value)?),
+            "app" => self.app_id = Some(parse_number(key, value)?),
+            "from" => self.from = Some(parse_address(key, value)?),
+            "to" => self.to = Some(parse_address(key, value)?),
+            "addr" => self.address = Some(parse_address(key, value)?),
+            _ => {
+                return Err(AlgoError::invalid_input(format!(
+                    "Unknown filter term '{key}'"
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    fn apply_amount(&mut self, rest: &str) -> Result<(), AlgoError> {
+        let (op, value) = [">=", "<=", ">", "<", "="]
+            .into_iter()
+            .find_map(|op| rest.strip_prefix(op).map(|value| (op, value)))
+            .ok_or_else(|| {
+                AlgoError::invalid_input("Amount needs a comparison such as amount>1000")
+            })?;
+        let amount = parse_number("amount", value)?;
+        let empty = || AlgoError::invalid_input("Amount range is empty");
+
+        match op {
+            ">=" => self.min_amount = Some(amount),
+            ">" => self.min_amount = Some(amount.checked_add(1).ok_or_else(empty)?),
+            "<=" => self.max_amount = Some(amount),
+            "<" => self.max_amount = Some(amount.checked_sub(1).ok_or_else(empty)?),
+            _ => {
+                self.min_amount = Some(amount);
+                self.max_amount = Some(amount);
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns true if a transaction satisfies every term of the filter.
+    #[must_use]
+    pub fn matches(&self, txn: &Transaction) -> bool {
+        if self.txn_type.is_some_and(|t| t != txn.txn_type) {
+            return false;
+        }
+        if self.asset_id.is_some() && self.asset_id != txn.asset_id {
+            return false;
+        }
+        if let Some(app_id) = self.app_id {
+            let matches_app = match &txn.details {
+                TransactionDetails::AppCall(details) => {
+                    details.app_id == app_id || details.created_app_id == Some(app_id)
+                }
+                _ => false,
+            };
+            if !matches_app {
+                return false;
+            }
+        }
+        if self.from.as_ref().is_some_and(|from| *from != txn.from) {
+            return false;
+        }
+        if self.to.as_ref().is_some_and(|to| *to != txn.to) {
+            return false;
+        }
+        if self
+            .address
+            .as_ref()
+            .is_some_and(|addr| *addr != txn.from && *addr != txn.to)
+        {
+            return false;
+        }
+        self.min_amount.is_none_or(|min| txn.amount >= min)
+            && self.max_amount.is_none_or(|max| txn.amount <= max)
+    }
+
+    /// Indexer `/v2/transactions` query parameters equivalent to the filter.
+    ///
+    /// The indexer accepts a single address, so only the first of `from:`,
+    /// `to:` and `addr:` is pushed down; the rest are left to
+    /// [`Self::matches`]. Amount bounds are only pushed down for payments
+    /// or a single asset, since the indexer compares them against microAlgos
+    /// otherwise.
+    #[must_use]
+    pub fn query_params(&self) -> Vec<(&'static str, String)> {
+        let mut params = Vec::new();
+        if let Some(code) = self.txn_type.and_then(|t| t.api_code()) {
+            params.push(("tx-type", code.to_string()));
+        }
+        if let Some(asset_id) = self.asset_id {
+            params.push(("asset-id", asset_id.to_string()));
+        }
+        if let Some(app_id) = self.app_id {
+            params.push(("application-id", app_id.to_string()));
+        }
+        if let Some(from) = &self.from {
+            params.push(("address", from.clone()));
+            params.push(("address-role", "sender".to_string()));
+        } else if let Some(to) = &self.to {
+            params.push(("address", to.clone()));
+            params.push(("address-role", "receiver".to_string()));
+        } else if let Some(address) = &self.address {
+            params.push(("address", address.clone()));
+        }
+        let amounts_in_one_unit =
+            self.asset_id.is_some() || self.txn_type == Some(TxnType::Payment);
+        if !amounts_in_one_unit {
+            return params;
+        }
+        // The indexer's currency bounds are exclusive.
+        if let Some(min) = self.min_amount.filter(|min| *min > 0) {
+            params.push(("currency-greater-than", (min - 1).to_string()));
+        }
+        if let Some(max) = self.max_amount.and_then(|max| max.checked_add(1)) {
+            params.push(("currency-less-than", max.to_string()));
+        }
+        params
+    }
+}
+
+impl fmt::Display for TxnFilter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.query)
+    }
+}
+
+fn parse_number(key: &str, value: &str) -> Result<u64, AlgoError> {
+    value
+        .parse()
+        .map_err(|_| AlgoError::invalid_input(format!("'{key}' needs a number, got '{value}'")))
+}
+
+fn parse_address(key: &str, value: &str) -> Result<String, AlgoError> {
+    if is_valid_address(value) {
+        Ok(value.to_string())
+    } else {
+        Err(AlgoError::invalid_input(format!(
+            "'{key}' needs an Algorand address"
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::AppCallDetails;
+    use crate::test_utils::TransactionMother;
+    use rstest::rstest;
+
+    const ADDR: &str = "AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAY5HFKQ";
+
+    fn txn(txn_type: TxnType, amount: u64, asset_id: Option<u64>) -> Transaction {
+        Transaction {
+            txn_type,
+            amount,
+            asset_id,
+            ..TransactionMother::payment_with_addresses("TX", ADDR, "receiver")
+        }
+    }
+
+    #[test]
+    fn test_parse_full_query() {
+        let filter = TxnFilter::parse(&format!(
+            "type:axfer  asset:31566704 from:{ADDR} amount>1000000"
+        ))
+        .unwrap();
+        assert_eq!(filter.txn_type, Some(TxnType::AssetTransfer));
+        assert_eq!(filter.asset_id, Some(31566704));
+        assert_eq!(filter.from.as_deref(), Some(ADDR));
+        assert_eq!(filter.min_amount, Some(1_000_001));
+        assert_eq!(filter.max_amount, None);
+        assert_eq!(
+            filter.to_string(),
+            format!("type:axfer asset:31566704 from:{ADDR} amount>1000000")
+        );
+        assert_eq!(
+            filter.query_params(),
+            vec![
+                ("tx-type", "axfer".to_string()),
+                ("asset-id", "31566704".to_string()),
+                ("address", ADDR.to_string()),
+                ("address-role", "sender".to_string()),
+                ("currency-greater-than", "1000000".to_string()),
+            ]
+        );
+    }
+
+    #[rstest]
+    #[case::empty("  ")]
+    #[case::unknown_key("foo:1")]
+    #[case::bare_word("pay")]
+    #[case::unknown_type("type:xyz")]
+    #[case::bad_number("asset:abc")]
+    #[case::bad_address("from:NOTANADDRESS")]
+    #[case::missing_operator("amount1000")]
+    #[case::below_zero("amount<0")]
+    #[case::empty_range("amount>10 amount<5")]
+    fn test_parse_errors(#[case] input: &str) {
+        assert!(TxnFilter::parse(input).is_err());
+    }
+
+    #[rstest]
+    #[case::type_match("type:pay", txn(TxnType::Payment, 5, None), true)]
+    #[case::type_mismatch("type:axfer", txn(TxnType::Payment, 5, None), false)]
+    #[case::asset_match("asset:7", txn(TxnType::AssetTransfer, 5, Some(7)), true)]
+    #[case::asset_mismatch("asset:7", txn(TxnType::AssetTransfer, 5, Some(8)), false)]
+    #[case::amount_gt("amount>5", txn(TxnType::Payment, 5, None), false)]
+    #[case::amount_ge("amount>=5", txn(TxnType::Payment, 5, None), true)]
+    #[case::amount_lt("amount<6", txn(TxnType::Payment, 5, None), true)]
+    #[case::amount_eq("amount=4", txn(TxnType::Payment, 5, None), false)]
+    #[case::addr_sender(&format!("addr:{ADDR}"), txn(TxnType::Payment, 5, None), true)]
+    #[case::to_mismatch(&format!("to:{ADDR}"), txn(TxnType::Payment, 5, None), false)]
+    #[case::app_not_app_call("app:123", txn(TxnType::Payment, 5, None), false)]
+    fn test_matches(#[case] query: &str, #[case] txn: Transaction, #[case] expected: bool) {
+        assert_eq!(TxnFilter::parse(query).unwrap().matches(&txn), expected);
+    }
+
+    #[test]
+    fn test_matches_app_call() {
+        let filter = TxnFilter::parse("app:123").unwrap();
+        let mut call = TransactionMother::app_call("TX", 123);
+        call.details = TransactionDetails::AppCall(AppCallDetails {
+            app_id: 123,
+            ..AppCallDetails::default()
+        });
+        assert!(filter.matches(&call));
+        assert_eq!(
+            filter.query_params(),
+            vec![("application-id", "123".to_string())]
+        );
+    }
+
+    #[test]
+    fn test_amount_range_push_down() {
+        let filter = TxnFilter::parse("type:pay amount>=10 amount<=20").unwrap();
+        assert_eq!(
+            filter.query_params(),
+            vec![
+                ("tx-type", "pay".to_string()),
+                ("currency-greater-than", "9".to_string()),
+                ("currency-less-than", "21".to_string()),
+            ]
+        );
+        // Without a type or asset the bounds would mix ALGO and asset amounts
+        let filter = TxnFilter::parse("amount>=10").unwrap();
+        assert_eq!(filter.query_params(), vec![]);
+    }
+
+    #[test]
+    fn test_asset_amounts_without_asset_stay_client_side() {
+        let filter = TxnFilter::parse("type:axfer amount>5").unwrap();
+        assert_eq!(
+            filter.query_params(),
+            vec![("tx-type", "axfer".to_string())]
+        );
+        assert!(filter.matches(&txn(TxnType::AssetTransfer, 2_500, Some(7))));
+        assert!(!filter.matches(&txn(TxnType::AssetTransfer, 5, Some(7))));
+    }
+}
@@ -0,0 +1,316 @@
+//! Advanced transaction search queries.
+//!
+//! A [`TxnQuery`] maps one-to-one onto the indexer's `/v2/transactions`
+//! search parameters, so every criterion is evaluated server-side and the
+//! results can be paged with `next-token`.
+
+use std::fmt;
+
+use base64::{Engine as _, engine::general_purpose::STANDARD as BASE64};
+use chrono::{DateTime, NaiveDate};
+
+use super::{AlgoError, TxnType};
+
+/// How the searched address must appear in a transaction.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum AddressRole {
+    /// Any role (sender, receiver, close-to, freeze target, ...).
+    #[default]
+    Any,
+    /// Transaction sender.
+    Sender,
+    /// Payment or asset receiver.
+    Receiver,
+    /// Target account of an asset freeze.
+    FreezeTarget,
+}
+
+impl AddressRole {
+    /// All roles, in the order they are listed in the search form.
+    pub const ALL: [Self; 4] = [Self::Any, Self::Sender, Self::Receiver, Self::FreezeTarget];
+
+    /// The indexer `address-role` value, or `None` for any role.
+    #[must_use]
+    pub const fn api_code(self) -> Option<&'static str> {
+        match self {
+            Self::Any => None,
+            Self::Sender => Some("sender"),
+            Self::Receiver => Some("receiver"),
+            Self::FreezeTarget => Some("freeze-target"),
+        }
+    }
+
+    /// Parses a role as typed in the search form.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AlgoError::InvalidInput`] for an unknown role.
+    pub fn parse(input: &str) -> Result<Self, AlgoError> {
+        match input.trim() {
+            "" | "any" => Ok(Self::Any),
+            value => Self::ALL
+                .into_iter()
+                .find(|role| role.api_code() == Some(value))
+                .ok_or_else(|| AlgoError::invalid_input(format!("Unknown address role '{value}'"))),
+        }
+    }
+}
+
+/// Indexer transaction search criteria. Unset fields are not constrained.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TxnQuery {
+    /// Address involved in the transaction.
+    pub address: Option<String>,
+    /// Role the address must play; only meaningful with an address.
+    pub address_role: AddressRole,
+    /// Transaction type.
+    pub txn_type: Option<TxnType>,
+    /// Asset ID.
+    pub asset_id: Option<u64>,
+    /// Application ID.
+    pub app_id: Option<u64>,
+    /// Lowest round to include.
+    pub min_round: Option<u64>,
+    /// Highest round to include.
+    pub max_round: Option<u64>,
+    /// Only transactions confirmed after this RFC 3339 time.
+    pub after_time: Option<String>,
+    /// Only transactions confirmed before this RFC 3339 time.
+    pub before_time: Option<String>,
+    /// Exclusive lower bound on the amount (microAlgos, or asset base units
+    /// with an asset ID).
+    pub currency_greater_than: Option<u64>,
+    /// Exclusive upper bound on the amount.
+    pub currency_less_than: Option<u64>,
+    /// Note prefix, as raw text.
+    pub note_prefix: Option<String>,
+    /// Only transactions that rekey their sender.
+    pub rekey_to: bool,
+}
+
+impl TxnQuery {
+    /// Returns true if no criterion is set.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Checks the rules that span several criteria.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AlgoError::InvalidInput`] for an empty query, a role
+    /// without an address, or an empty round or amount range.
+    pub fn validate(&self) -> Result<(), AlgoError> {
+        if self.is_empty() {
+            return Err(AlgoError::invalid_input("Enter at least one criterion"));
+        }
+        if self.address.is_none() && self.address_role != AddressRole::Any {
+            return Err(AlgoError::invalid_input("Address role needs an address"));
+        }
+        if let (Some(min), Some(max)) = (self.min_round, self.max_round)
+            && min > max
+        {
+            return Err(AlgoError::invalid_input("Round range is empty"));
+        }
+        if let (Some(above), Some(below)) = (self.currency_greater_than, self.currency_less_than)
+            && above.saturating_add(1) >= below
+        {
+            return Err(AlgoError::invalid_input("Amount range is empty"));
+        }
+        Ok(())
+    }
+
+    /// Indexer `/v2/transactions` query parameters for the search.
+    #[must_use]
+    pub fn query_params(&self) -> Vec<(&'static str, String)> {
+        let mut params = Vec::new();
+        if let Some(address) = &self.address {
+            params.push(("address", address.clone()));
+            if let Some(role) = self.address_role.api_code() {
+                params.push(("address-role", role.to_string()));
+            }
+        }
+        if let Some(code) = self.txn_type.and_then(|t| t.api_code()) {
+            params.push(("tx-type", code.to_string()));
+        }
+        if let Some(asset_id) = self.asset_id {
+            params.push(("asset-id", asset_id.to_string()));
+        }
+        if let Some(app_id) = self.app_id {
+            params.push(("application-id", app_id.to_string()));
+        }
+        if let Some(round) = self.min_round {
+            params.push(("min-round", round.to_string()));
+        }
+        if let Some(round) = self.max_round {
+            params.push(("max-round", round.to_string()));
+        }
+        if let Some(time) = &self.after_time {
+            params.push(("after-time", time.clone()));
+        }
+        if let Some(time) = &self.before_time {
+            params.push(("before-time", time.clone()));
+        }
+        if let Some(amount) = self.currency_greater_than {
+            params.push(("currency-greater-than", amount.to_string()));
+        }
+        if let Some(amount) = self.currency_less_than {
+            params.push(("currency-less-than", amount.to_string()));
+        }
+        if let Some(prefix) = &self.note_prefix {
+            params.push(("note-prefix", BASE64.encode(prefix)));
+        }
+        if self.rekey_to {
+            params.push(("rekey-to", "true".to_string()));
+        }
+        params
+    }
+}
+
+impl fmt::Display for TxnQuery {
+    /// Short summary of the criteria, e.g. `sender:ABCDEFGH… type:pay round:10-20`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut parts = Vec::new();
+        if let Some(address) = &self.address {
+            let short: String = address.chars().take(8).collect();
+            let role = self.address_role.api_code().unwrap_or("addr");
+            parts.push(format!("{role}:{short}…"));
+        }
+        if let Some(code) = self.txn_type.and_then(|t| t.api_code()) {
+            parts.push(format!("type:{code}"));
+        }
+        if let Some(asset_id) = self.asset_id {
+            parts.push(format!("asset:{asset_id}"));
+        }
+        if let Some(app_id) = self.app_id {
+            parts.push(format!("app:{app_id}"));
+        }
+        match (self.min_round, self.max_round) {
+            (Some(min), Some(max)) => parts.push(format!("round:{min}-{max}")),
+            (Some(min), None) => parts.push(format!("round>={min}")),
+            (None, Some(max)) => parts.push(format!("round<={max}")),
+            (None, None) => {}
+        }
+        if let Some(time) = &self.after_time {
+            parts.push(format!("after:{time}"));
+        }
+        if let Some(time) = &self.before_time {
+            parts.push(format!("before:{time}"));
+        }
+        if let Some(amount) = self.currency_greater_than {
+            parts.push(format!("amount>{amount}"));
+        }
+        if let Some(amount) = self.currency_less_than {
+            parts.push(format!("amount<{amount}"));
+        }
+        if let Some(prefix) = &self.note_prefix {
+            parts.push(format!("note:{prefix}"));
+        }
+        if self.rekey_to {
+            parts.push("rekey".to_string());
+        }
+        f.write_str(&parts.join(" "))
+    }
+}
+
+/// Parses a search time as typed: RFC 3339, or a plain `YYYY-MM-DD` date
+/// meaning midnight UTC.
+///
+/// # Errors
+///
+/// Returns [`AlgoError::InvalidInput`] if the input is neither.
+pub fn parse_query_time(input: &str) -> Result<String, AlgoError> {
+    let input = input.trim();
+    if let Ok(time) = DateTime::parse_from_rfc3339(input) {
+        return Ok(time.to_rfc3339());
+    }
+    NaiveDate::parse_from_str(input, "%Y-%m-%d")
+        .map(|date| format!("{date}T00:00:00Z"))
+        .map_err(|_| {
+            AlgoError::invalid_input(format!("'{input}' is not a date (YYYY-MM-DD or RFC 3339)"))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    const ADDR: &str = "AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAY5HFKQ";
+
+    #[test]
+    fn test_query_params() {
+        let query = TxnQuery {
+            address: Some(ADDR.to_string()),
+            address_role: AddressRole::FreezeTarget,
+            txn_type: Some(TxnType::AssetFreeze),
+            asset_id: Some(31566704),
+            min_round: Some(10),
+            max_round: Some(20),
+            after_time: Some("2024-01-01T00:00:00Z".to_string()),
+            currency_greater_than: Some(5),
+            note_prefix: Some("hi".to_string()),
+            rekey_to: true,
+            ..TxnQuery::default()
+        };
+        assert!(query.validate().is_ok());
+        assert_eq!(
+            query.query_params(),
+            vec![
+                ("address", ADDR.to_string()),
+                ("address-role", "freeze-target".to_string()),
+                ("tx-type", "afrz".to_string()),
+                ("asset-id", "31566704".to_string()),
+                ("min-round", "10".to_string()),
+                ("max-round", "20".to_string()),
+                ("after-time", "2024-01-01T00:00:00Z".to_string()),
+                ("currency-greater-than", "5".to_string()),
+                ("note-prefix", "aGk=".to_string()),
+                ("rekey-to", "true".to_string()),
+            ]
+        );
+        assert_eq!(
+            query.to_string(),
+            "freeze-target:AAAAAAAA… type:afrz asset:31566704 round:10-20 \
+             after:2024-01-01T00:00:00Z amount>5 note:hi rekey"
+        );
+    }
+
+    #[rstest]
+    #[case::empty(TxnQuery::default())]
+    #[case::role_without_address(TxnQuery {
+        address_role: AddressRole::Sender,
+        asset_id: Some(1),
+        ..TxnQuery::default()
+    })]
+    #[case::round_range(TxnQuery {
+        min_round: Some(20),
+        max_round: Some(10),
+        ..TxnQuery::default()
+    })]
+    #[case::amount_range(TxnQuery {
+        currency_greater_than: Some(10),
+        currency_less_than: Some(11),
+        ..TxnQuery::default()
+    })]
+    fn test_validate_errors(#[case] query: TxnQuery) {
+        assert!(query.validate().is_err());
+    }
+
+    #[rstest]
+    #[case::date("2024-01-31", Some("2024-01-31T00:00:00Z"))]
+    #[case::rfc3339("2024-01-31T12:30:00+00:00", Some("2024-01-31T12:30:00+00:00"))]
+    #[case::garbage("yesterday", None)]
+    fn test_parse_query_time(#[case] input: &str, #[case] expected: Option<&str>) {
+        assert_eq!(parse_query_time(input).ok().as_deref(), expected);
+    }
+
+    #[rstest]
+    #[case::blank("", AddressRole::Any)]
+    #[case::sender("sender", AddressRole::Sender)]
+    #[case::freeze(" freeze-target ", AddressRole::FreezeTarget)]
+    fn test_parse_address_role(#[case] input: &str, #[case] expected: AddressRole) {
+        assert_eq!(AddressRole::parse(input).unwrap(), expected);
+    }
+}
@@ -0,0 +1,96 @@
+//! Watchlist domain types.
+//!
+//! A watchlist is a per-network list of accounts, assets and applications
+//! whose balances and latest activity are polled in the background.
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+use super::AssetInfo;
+
+/// An entity pinned to the watchlist.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(tag = "type", content = "id", rename_all = "lowercase")]
+pub enum WatchTarget {
+    /// An account address.
+    Account(String),
+    /// An asset ID.
+    Asset(u64),
+    /// An application ID.
+    App(u64),
+}
+
+impl WatchTarget {
+    /// Short tag shown in front of the entry.
+    #[must_use]
+    pub const fn kind(&self) -> &'static str {
+        match self {
+            Self::Account(_) => "ACCT",
+            Self::Asset(_) => "ASA",
+            Self::App(_) => "APP",
+        }
+    }
+
+    /// The address or ID as displayed.
+    #[must_use]
+    pub fn id(&self) -> String {
+        match self {
+            Self::Account(address) => address.clone(),
+            Self::Asset(id) | Self::App(id) => id.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for WatchTarget {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Account(address) => write!(f, "account {address}"),
+            Self::Asset(id) => write!(f, "asset {id}"),
+            Self::App(id) => write!(f, "app {id}"),
+        }
+    }
+}
+
+/// Latest polled state of a watchlist entry.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WatchStatus {
+    /// The watched entity.
+    pub target: WatchTarget,
+    /// Balance in microAlgos (for apps, the app account's balance).
+    pub balance: Option<u64>,
+    /// Asset parameters, for asset entries.
+    pub asset: Option<AssetInfo>,
+    /// Round of the most recent transaction involving the entity.
+    pub last_round: Option<u64>,
+    /// Lookup error, if the entity could not be fetched.
+    pub error: Option<String>,
+}
+
+impl WatchStatus {
+    /// Creates an empty status for a target.
+    #[must_use]
+    pub const fn new(target: WatchTarget) -> Self {
+        Self {
+            target,
+            balance: None,
+            asset: None,
+            last_round: None,
+            error: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case::account(WatchTarget::Account("ADDR".into()), r#"{"type":"account","id":"ADDR"}"#)]
+    #[case::asset(WatchTarget::Asset(31566704), r#"{"type":"asset","id":31566704}"#)]
+    #[case::app(WatchTarget::App(1284326447), r#"{"type":"app","id":1284326447}"#)]
+    fn test_watch_target_serialization(#[case] target: WatchTarget, #[case] json: &str) {
+        assert_eq!(serde_json::to_string(&target).unwrap(), json);
+        assert_eq!(serde_json::from_str::<WatchTarget>(json).unwrap(), target);
+    }
+}
@@ -0,0 +1,903 @@
+//! Key chords and the active keymap.
+//!
+//! The [`Keymap`] maps key chords to [`AppCommand`]s per [`InputContext`]. It
+//! starts from the built-in [`DEFAULT_BINDINGS`] and applies the user's
+//! `keybindings` overrides from the configuration file, e.g.:
+//!
+//! ```json
+//! "keybindings": {
+//!   "main": { "ctrl+r": "Refresh", "x": "none" },
+//!   "account_details": { "h": "CycleAccountHistoryFilter" }
+//! }
+//! ```
+//!
+//! An override replaces whatever the chord was bound to in that context;
+//! `"none"` removes the binding. The help popup and footer are generated from
+//! the active keymap, so they always show the real bindings.
+
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use std::sync::LazyLock;
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use thiserror::Error;
+
+use crate::commands::{AppCommand, InputContext};
+
+/// Command name that removes a binding in the configuration file.
+const UNBIND: &str = "none";
+
+// ============================================================================
+// Key Chord
+// ============================================================================
+
+/// A key with its Ctrl/Alt modifiers.
+///
+/// Shift is folded into the character (`shift+g` is `G`) and ignored for
+/// other keys, so chords match what terminals actually report.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct KeyChord {
+    /// The key.
+    pub code: KeyCode,
+    /// Ctrl and Alt modifiers.
+    pub modifiers: KeyModifiers,
+}
+
+impl KeyChord {
+    /// Create a chord, normalizing the modifiers.
+    #[must_use]
+    pub fn new(code: KeyCode, modifiers: KeyModifiers) -> Self {
+        Self {
+            code,
+            modifiers: modifiers & (KeyModifiers::CONTROL | KeyModifiers::ALT),
+        }
+    }
+
+    /// Parse a chord such as `q`, `G`, `ctrl+r`, `shift+tab`, `enter` or `f5`.
+    ///
+    /// Modifier and key names are case-insensitive; single characters are not.
+    ///
+    /// # Errors
+    ///
+    /// Returns a description of the problem if the chord is not recognised.
+    pub fn parse(s: &str) -> Result<Self, String> {
+        let mut parts: Vec<&str> = s.split('+').collect();
+        // A trailing "+" is the plus key itself, e.g. "ctrl++"
+        if s.ends_with("++") || s == "+" {
+            parts.truncate(parts.len().saturating_sub(2));
+            parts.push("+");
+        }
+        let (key, modifier_names) = parts
+            .split_last()
+            .filter(|(key, _)| !key.is_empty())
+            .ok_or_else(|| format!("invalid key '{s}'"))?;
+
+        let mut modifiers = KeyModifiers::NONE;
+        for name in modifier_names {
+            modifiers |= match name.to_ascii_lowercase().as_str() {
+                "ctrl" | "control" => KeyModifiers::CONTROL,
+                "alt" | "meta" => KeyModifiers::ALT,
+                "shift" => KeyModifiers::SHIFT,
+                _ => return Err(format!("unknown modifier '{name}' in '{s}'")),
+            };
+        }
+
+        let mut chars = key.chars();
+        let code = match (chars.next(), chars.next()) {
+            (Some(c), None) => KeyCode::Char(c),
+            _ => match key.to_ascii_lowercase().as_str() {
+                "space" => KeyCode::Char(' '),
+                "enter" | "return" => KeyCode::Enter,
+                "esc" | "escape" => KeyCode::Esc,
+                "tab" => KeyCode::Tab,
+                "backtab" => KeyCode::BackTab,
+                "backspace" => KeyCode::Backspace,
+                "delete" | "del" => KeyCode::Delete,
+                "insert" => KeyCode::Insert,
+                "up" => KeyCode::Up,
+                "down" => KeyCode::Down,
+                "left" => KeyCode::Left,
+                "right" => KeyCode::Right,
+                "home" => KeyCode::Home,
+                "end" => KeyCode::End,
+                "pageup" => KeyCode::PageUp,
+                "pagedown" => KeyCode::PageDown,
+                name => name
+                    .strip_prefix('f')
+                    .and_then(|n| n.parse::<u8>().ok())
+                    .filter(|n| (1..=12).contains(n))
+                    .map(KeyCode::F)
+                    .ok_or_else(|| format!("unknown key '{key}' in '{s}'"))?,
+            },
+        };
+
+        // Fold shift into characters and shift+tab into backtab
+        let code = match code {
+            KeyCode::Char(c) if modifiers.contains(KeyModifiers::SHIFT) => {
+                KeyCode::Char(c.to_ascii_uppercase())
+            }
+            KeyCode::Tab if modifiers.contains(KeyModifiers::SHIFT) => KeyCode::BackTab,
+            code => code,
+        };
+        Ok(Self::new(code, modifiers))
+    }
+}
+
+impl From<KeyEvent> for KeyChord {
+    fn from(key: KeyEvent) -> Self {
+        Self::new(key.code, key.modifiers)
+    }
+}
+
+impl fmt::Display for KeyChord {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.modifiers.contains(KeyModifiers::CONTROL) {
+            write!(f, "Ctrl+")?;
+        }
+        if self.modifiers.contains(KeyModifiers::ALT) {
+            write!(f, "Alt+")?;
+        }
+        match self.code {
+            KeyCode::Char(' ') => write!(f, "Space"),
+            KeyCode::Char(c) => write!(f, "{c}"),
+            KeyCode::Up => write!(f, "↑"),
+            KeyCode::Down => write!(f, "↓"),
+            KeyCode::Left => write!(f, "←"),
+            KeyCode::Right => write!(f, "→"),
+            KeyCode::BackTab => write!(f, "Shift+Tab"),
+            KeyCode::PageUp => write!(f, "PgUp"),
+            KeyCode::PageDown => write!(f, "PgDn"),
+            KeyCode::F(n) => write!(f, "F{n}"),
+            KeyCode::Enter => write!(f, "Enter"),
+            KeyCode::Esc => write!(f, "Esc"),
+            KeyCode::Tab => write!(f, "Tab"),
+            KeyCode::Backspace => write!(f, "Backspace"),
+            KeyCode::Delete => write!(f, "Del"),
+            KeyCode::Insert => write!(f, "Ins"),
+            code => write!(f, "{code:?}"),
+        }
+    }
+}
+
+// ============================================================================
+// Default Bindings
+// ============================================================================
+
+/// Built-in bindings per context, in the order the help popup lists them.
+pub const DEFAULT_BINDINGS: &[(InputContext, &[(&str, AppCommand)])] = &[
+    (
+        InputContext::Main,
+        &[
+            ("q", AppCommand::RequestQuit),
+            ("r", AppCommand::Refresh),
+            ("?", AppCommand::ToggleHelp),
+            ("n", AppCommand::OpenNetworkSelect),
+            ("space", AppCommand::ToggleLive),
+            ("f", AppCommand::FocusInlineSearch),
+            ("tab", AppCommand::CycleFocus),
+            ("up", AppCommand::MoveUp),
+            ("k", AppCommand::MoveUp),
+            ("down", AppCommand::MoveDown),
+            ("j", AppCommand::MoveDown),
+            ("g", AppCommand::GoToTop),
+            ("G", AppCommand::GoToBottom),
+            ("enter", AppCommand::Select),
+            ("w", AppCommand::ToggleWatch),
+            ("/", AppCommand::OpenTxnFilter),
+            ("S", AppCommand::OpenAdvancedSearch),
+            ("p", AppCommand::OpenPendingPool),
+            ("x", AppCommand::OpenSimulate),
+            ("esc", AppCommand::Dismiss),
+        ],
+    ),
+    (
+        InputContext::DetailView,
+        &[
+            ("esc", AppCommand::Dismiss),
+            ("tab", AppCommand::ToggleDetailViewMode),
+            ("c", AppCommand::CopyToClipboard),
+            ("y", AppCommand::CopyJson),
+            ("o", AppCommand::OpenInBrowser),
+            ("s", AppCommand::ExportSvg),
+            ("a", AppCommand::ViewGroup),
+            ("f", AppCommand::ToggleFullscreen),
+            ("up", AppCommand::GraphScrollUp),
+            ("down", AppCommand::GraphScrollDown),
+            ("left", AppCommand::GraphScrollLeft),
+            ("right", AppCommand::GraphScrollRight),
+            ("k", AppCommand::DetailSectionUp),
+            ("j", AppCommand::DetailSectionDown),
+            ("enter", AppCommand::ToggleDetailSection),
+            ("space", AppCommand::ToggleDetailSection),
+            ("q", AppCommand::RequestQuit),
+        ],
+    ),
+    (
+        InputContext::TxnDetailViewTable,
+        &[
+            ("esc", AppCommand::Dismiss),
+            ("tab", AppCommand::ToggleDetailViewMode),
+            ("up", AppCommand::DetailSectionUp),
+            ("k", AppCommand::DetailSectionUp),
+            ("down", AppCommand::DetailSectionDown),
+            ("j", AppCommand::DetailSectionDown),
+            ("g", AppCommand::GoToTop),
+            ("G", AppCommand::GoToBottom),
+            ("c", AppCommand::CopyToClipboard),
+            ("y", AppCommand::CopyJson),
+            ("o", AppCommand::OpenInBrowser),
+            ("f", AppCommand::ToggleFullscreen),
+            ("s", AppCommand::ExportSvg),
+            ("a", AppCommand::ViewGroup),
+            ("q", AppCommand::RequestQuit),
+        ],
+    ),
+    (
+        InputContext::BlockDetailView,
+        &[
+            ("esc", AppCommand::Dismiss),
+            ("tab", AppCommand::CycleBlockDetailTab),
+            ("up", AppCommand::MoveBlockTxnUp),
+            ("k", AppCommand::MoveBlockTxnUp),
+            ("down", AppCommand::MoveBlockTxnDown),
+            ("j", AppCommand::MoveBlockTxnDown),
+            ("g", AppCommand::GoToTop),
+            ("G", AppCommand::GoToBottom),
+            ("enter", AppCommand::SelectBlockTxn),
+            ("c", AppCommand::CopyToClipboard),
+            ("y", AppCommand::CopyJson),
+            ("o", AppCommand::OpenInBrowser),
+            ("e", AppCommand::OpenExport),
+            ("f", AppCommand::ToggleFullscreen),
+            ("q", AppCommand::RequestQuit),
+        ],
+    ),
+    (
+        InputContext::AccountDetailView,
+        &[
+            ("esc", AppCommand::Dismiss),
+            ("tab", AppCommand::CycleAccountDetailTab),
+            ("up", AppCommand::MoveAccountItemUp),
+            ("k", AppCommand::MoveAccountItemUp),
+            ("down", AppCommand::MoveAccountItemDown),
+            ("j", AppCommand::MoveAccountItemDown),
+            ("g", AppCommand::GoToTop),
+            ("G", AppCommand::GoToBottom),
+            ("enter", AppCommand::SelectAccountItem),
+            ("t", AppCommand::CycleAccountHistoryFilter),
+            ("s", AppCommand::CycleAccountListSort),
+            ("z", AppCommand::ToggleHideZeroBalances),
+            ("w", AppCommand::ToggleWatch),
+            ("p", AppCommand::OpenPendingPool),
+            ("c", AppCommand::CopyToClipboard),
+            ("y", AppCommand::CopyJson),
+            ("o", AppCommand::OpenInBrowser),
+            ("e", AppCommand::OpenExport),
+            ("f", AppCommand::ToggleFullscreen),
+            ("q", AppCommand::RequestQuit),
+        ],
+    ),
+    (
+        InputContext::AppDetailView,
+        &[
+            ("esc", AppCommand::Dismiss),
+            ("tab", AppCommand::CycleAppDetailTab),
+            ("up", AppCommand::MoveAppStateUp),
+            ("k", AppCommand::MoveAppStateUp),
+            ("down", AppCommand::MoveAppStateDown),
+            ("j", AppCommand::MoveAppStateDown),
+            ("g", AppCommand::GoToTop),
+            ("G", AppCommand::GoToBottom),
+            ("l", AppCommand::LookupLocalState),
+            ("w", AppCommand::ToggleWatch),
+            ("c", AppCommand::CopyToClipboard),
+            ("y", AppCommand::CopyJson),
+            ("o", AppCommand::OpenInBrowser),
+            ("f", AppCommand::ToggleFullscreen),
+            ("q", AppCommand::RequestQuit),
+        ],
+    ),
+    (
+        InputContext::AssetDetailView,
+        &[
+            ("esc", AppCommand::Dismiss),
+            ("tab", AppCommand::CycleAssetDetailTab),
+            ("up", AppCommand::MoveAssetHolderUp),
+            ("k", AppCommand::MoveAssetHolderUp),
+            ("down", AppCommand::MoveAssetHolderDown),
+            ("j", AppCommand::MoveAssetHolderDown),
+            ("g", AppCommand::GoToTop),
+            ("G", AppCommand::GoToBottom),
+            ("enter", AppCommand::SelectAssetHolder),
+            ("m", AppCommand::CycleAssetHolderFilter),
+            ("w", AppCommand::ToggleWatch),
+            ("c", AppCommand::CopyToClipboard),
+            ("y", AppCommand::CopyJson),
+            ("o", AppCommand::OpenInBrowser),
+            ("e", AppCommand::OpenExport),
+            ("f", AppCommand::ToggleFullscreen),
+            ("q", AppCommand::RequestQuit),
+        ],
+    ),
+    (
+        InputContext::GroupDetailView,
+        &[
+            ("esc", AppCommand::Dismiss),
+            ("tab", AppCommand::CycleGroupDetailTab),
+            ("up", AppCommand::MoveGroupTxnUp),
+            ("k", AppCommand::MoveGroupTxnUp),
+            ("down", AppCommand::MoveGroupTxnDown),
+            ("j", AppCommand::MoveGroupTxnDown),
+            ("left", AppCommand::GraphScrollLeft),
+            ("right", AppCommand::GraphScrollRight),
+            ("g", AppCommand::GoToTop),
+            ("G", AppCommand::GoToBottom),
+            ("enter", AppCommand::SelectGroupTxn),
+            ("f", AppCommand::ToggleFullscreen),
+            ("q", AppCommand::RequestQuit),
+        ],
+    ),
+    (
+        InputContext::TxnSearchView,
+        &[
+            ("esc", AppCommand::Dismiss),
+            ("up", AppCommand::MoveSearchTxnUp),
+            ("k", AppCommand::MoveSearchTxnUp),
+            ("down", AppCommand::MoveSearchTxnDown),
+            ("j", AppCommand::MoveSearchTxnDown),
+            ("g", AppCommand::GoToTop),
+            ("G", AppCommand::GoToBottom),
+            ("enter", AppCommand::SelectSearchTxn),
+            ("S", AppCommand::OpenAdvancedSearch),
+            ("e", AppCommand::OpenExport),
+            ("f", AppCommand::ToggleFullscreen),
+            ("q", AppCommand::RequestQuit),
+        ],
+    ),
+    (
+        InputContext::PendingPoolView,
+        &[
+            ("esc", AppCommand::Dismiss),
+            ("up", AppCommand::MovePendingTxnUp),
+            ("k", AppCommand::MovePendingTxnUp),
+            ("down", AppCommand::MovePendingTxnDown),
+            ("j", AppCommand::MovePendingTxnDown),
+            ("g", AppCommand::GoToTop),
+            ("G", AppCommand::GoToBottom),
+            ("enter", AppCommand::SelectPendingTxn),
+            ("f", AppCommand::ToggleFullscreen),
+            ("q", AppCommand::RequestQuit),
+        ],
+    ),
+    (
+        InputContext::SimulationView,
+        &[
+            ("esc", AppCommand::Dismiss),
+            ("tab", AppCommand::CycleSimulationTab),
+            ("up", AppCommand::MoveSimulatedTxnUp),
+            ("k", AppCommand::MoveSimulatedTxnUp),
+            ("down", AppCommand::MoveSimulatedTxnDown),
+            ("j", AppCommand::MoveSimulatedTxnDown),
+            ("left", AppCommand::GraphScrollLeft),
+            ("right", AppCommand::GraphScrollRight),
+            ("g", AppCommand::GoToTop),
+            ("G", AppCommand::GoToBottom),
+            ("enter", AppCommand::SelectSimulatedTxn),
+            ("x", AppCommand::OpenSimulate),
+            ("f", AppCommand::ToggleFullscreen),
+            ("q", AppCommand::RequestQuit),
+        ],
+    ),
+    (
+        InputContext::NetworkSelect,
+        &[
+            ("esc", AppCommand::Dismiss),
+            ("up", AppCommand::NetworkUp),
+            ("k", AppCommand::NetworkUp),
+            ("down", AppCommand::NetworkDown),
+            ("j", AppCommand::NetworkDown),
+            ("g", AppCommand::GoToTop),
+            ("G", AppCommand::GoToBottom),
+            ("enter", AppCommand::SelectNetwork),
+            ("a", AppCommand::AddNetwork),
+            ("d", AppCommand::DeleteNetwork),
+            ("q", AppCommand::RequestQuit),
+        ],
+    ),
+    (
+        InputContext::SearchInput,
+        &[
+            ("esc", AppCommand::Dismiss),
+            ("ctrl+c", AppCommand::Dismiss),
+            ("enter", AppCommand::SubmitSearch),
+            ("tab", AppCommand::CycleSearchType),
+            ("backspace", AppCommand::Backspace),
+        ],
+    ),
+    (
+        InputContext::InlineSearch,
+        &[
+            ("esc", AppCommand::Dismiss),
+            ("ctrl+c", AppCommand::Dismiss),
+            ("enter", AppCommand::SubmitSearch),
+            ("tab", AppCommand::CycleSearchType),
+            ("up", AppCommand::SearchHistoryPrev),
+            ("down", AppCommand::SearchHistoryNext),
+            ("left", AppCommand::SearchCursorLeft),
+            ("right", AppCommand::SearchCursorRight),
+            ("backspace", AppCommand::Backspace),
+        ],
+    ),
+    (
+        InputContext::SearchResults,
+        &[
+            ("esc", AppCommand::Dismiss),
+            ("up", AppCommand::PreviousResult),
+            ("k", AppCommand::PreviousResult),
+            ("down", AppCommand::NextResult),
+            ("j", AppCommand::NextResult),
+            ("g", AppCommand::GoToTop),
+            ("G", AppCommand::GoToBottom),
+            ("enter", AppCommand::SelectResult),
+            ("q", AppCommand::RequestQuit),
+        ],
+    ),
+    (
+        InputContext::MessagePopup,
+        &[
+            ("esc", AppCommand::Dismiss),
+            ("enter", AppCommand::Dismiss),
+            ("space", AppCommand::Dismiss),
+            ("q", AppCommand::RequestQuit),
+        ],
+    ),
+    (
+        InputContext::HelpPopup,
+        &[
+            ("esc", AppCommand::Dismiss),
+            ("?", AppCommand::Dismiss),
+            ("up", AppCommand::ScrollHelpUp),
+            ("k", AppCommand::ScrollHelpUp),
+            ("down", AppCommand::ScrollHelpDown),
+            ("j", AppCommand::ScrollHelpDown),
+            ("g", AppCommand::GoToTop),
+            ("G", AppCommand::GoToBottom),
+            ("q", AppCommand::RequestQuit),
+        ],
+    ),
+    (
+        InputContext::ConfirmQuit,
+        &[
+            ("y", AppCommand::ConfirmQuit),
+            ("Y", AppCommand::ConfirmQuit),
+            ("n", AppCommand::Dismiss),
+            ("N", AppCommand::Dismiss),
+            ("esc", AppCommand::Dismiss),
+        ],
+    ),
+    (
+        InputContext::NetworkForm,
+        &[
+            ("esc", AppCommand::Dismiss),
+            ("enter", AppCommand::SubmitNetworkForm),
+            ("tab", AppCommand::NetworkFormNextField),
+            ("down", AppCommand::NetworkFormNextField),
+            ("up", AppCommand::NetworkFormPrevField),
+            ("backspace", AppCommand::Backspace),
+        ],
+    ),
+    (
+        InputContext::LocalStateLookup,
+        &[
+            ("esc", AppCommand::Dismiss),
+            ("ctrl+c", AppCommand::Dismiss),
+            ("enter", AppCommand::SubmitLocalStateLookup),
+            ("backspace", AppCommand::Backspace),
+        ],
+    ),
+    (
+        InputContext::TxnFilterInput,
+        &[
+            ("esc", AppCommand::Dismiss),
+            ("ctrl+c", AppCommand::Dismiss),
+            ("enter", AppCommand::SubmitTxnFilter),
+            ("backspace", AppCommand::Backspace),
+        ],
+    ),
+    (
+        InputContext::AdvancedSearchForm,
+        &[
+            ("esc", AppCommand::Dismiss),
+            ("ctrl+c", AppCommand::Dismiss),
+            ("enter", AppCommand::SubmitAdvancedSearch),
+            ("tab", AppCommand::AdvancedSearchNextField),
+            ("down", AppCommand::AdvancedSearchNextField),
+            ("backtab", AppCommand::AdvancedSearchPrevField),
+            ("up", AppCommand::AdvancedSearchPrevField),
+            ("backspace", AppCommand::Backspace),
+        ],
+    ),
+    (
+        InputContext::ExportPrompt,
+        &[
+            ("esc", AppCommand::Dismiss),
+            ("ctrl+c", AppCommand::Dismiss),
+            ("enter", AppCommand::SubmitExport),
+            ("tab", AppCommand::ToggleExportFormat),
+            ("backspace", AppCommand::Backspace),
+        ],
+    ),
+    (
+        InputContext::SimulateInput,
+        &[
+            ("esc", AppCommand::Dismiss),
+            ("ctrl+c", AppCommand::Dismiss),
+            ("enter", AppCommand::SubmitSimulate),
+            ("backspace", AppCommand::Backspace),
+        ],
+    ),
+];
+
+/// The built-in keymap, shared by callers without a configured one.
+static DEFAULT_KEYMAP: LazyLock<Keymap> = LazyLock::new(|| {
+    let bindings = DEFAULT_BINDINGS
+        .iter()
+        .map(|(context, bindings)| {
+            let bindings = bindings
+                .iter()
+                .map(|(chord, command)| {
+                    // Default chords are valid by construction (checked in tests)
+                    let chord = KeyChord::parse(chord).unwrap_or_else(|e| panic!("{e}"));
+                    (chord, command.clone())
+                })
+                .collect();
+            (*context, bindings)
+        })
+        .collect();
+    Keymap { bindings }
+});
+
+// ============================================================================
+// Keymap
+// ============================================================================
+
+/// Invalid `keybindings` configuration.
+#[derive(Debug, Error)]
+#[error("Invalid keybindings in config:\n{}", .problems.join("\n"))]
+pub struct KeymapError {
+    /// One line per problem found.
+    pub problems: Vec<String>,
+}
+
+/// Key chords bound to commands, per input context.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Keymap {
+    bindings: HashMap<InputContext, Vec<(KeyChord, AppCommand)>>,
+}
+
+impl Default for Keymap {
+    fn default() -> Self {
+        DEFAULT_KEYMAP.clone()
+    }
+}
+
+impl Keymap {
+    /// Build the keymap from the defaults and the configured overrides.
+    ///
+    /// Overrides map context names to `{ chord: command }` objects.
+    ///
+    /// # Errors
+    ///
+    /// Returns every unknown context, key or command, and every pair of
+    /// entries in a context that bind the same chord to different commands.
+    pub fn from_config(
+        overrides: &BTreeMap<String, BTreeMap<String, String>>,
+    ) -> Result<Self, KeymapError> {
+        let mut keymap = Self::default();
+        let mut problems = Vec::new();
+
+        for (context_name, entries) in overrides {
+            let Some(context) = InputContext::from_name(context_name) else {
+                let names: Vec<&str> = InputContext::ALL.iter().map(|c| c.name()).collect();
+                problems.push(format!(
+                    "unknown context '{context_name}' (expected one of: {})",
+                    names.join(", ")
+                ));
+                continue;
+            };
+
+            let mut seen: HashMap<KeyChord, (&str, &str)> = HashMap::new();
+            for (chord_name, command_name) in entries {
+                let chord = match KeyChord::parse(chord_name) {
+                    Ok(chord) => chord,
+                    Err(e) => {
+                        problems.push(format!("{context_name}: {e}"));
+                        continue;
+                    }
+                };
+                let command = if command_name == UNBIND {
+                    AppCommand::Noop
+                } else if let Some(command) = AppCommand::from_name(command_name) {
+                    command
+                } else {
+                    problems.push(format!(
+                        "{context_name}: unknown command '{command_name}' for '{chord_name}'"
+                    ));
+                    continue;
+                };
+
+                if let Some((other_chord, other_command)) = seen.get(&chord) {
+                    if *other_command != command_name.as_str() {
+                        problems.push(format!(
+                            "{context_name}: '{other_chord}' and '{chord_name}' are both {chord} \
+                             but bind {other_command} and {command_name}"
+                        ));
+                    }
+                    continue;
+                }
+                seen.insert(chord, (chord_name, command_name));
+                keymap.bind(context, chord, command);
+            }
+        }
+
+        if problems.is_empty() {
+            Ok(keymap)
+        } else {
+            Err(KeymapError { problems })
+        }
+    }
+
+    /// Bind `chord` to `command` in `context`, replacing any existing binding.
+    ///
+    /// Binding [`AppCommand::Noop`] removes the chord.
+    pub fn bind(&mut self, context: InputContext, chord: KeyChord, command: AppCommand) {
+        let bindings = self.bindings.entry(context).or_default();
+        let existing = bindings.iter().position(|(c, _)| *c == chord);
+        match (existing, command) {
+            (Some(idx), AppCommand::Noop) => {
+                bindings.remove(idx);
+            }
+            (Some(idx), command) => bindings[idx].1 = command,
+            (None, AppCommand::Noop) => {}
+            (None, command) => bindings.push((chord, command)),
+        }
+    }
+
+    /// Maps a key event to a command in the given context.
+    ///
+    /// Falls back to the key without modifiers, and in text input contexts to
+    /// typing the character.
+    #[must_use]
+    pub fn map_key(&self, key: KeyEvent, context: &InputContext) -> AppCommand {
+        let chord = KeyChord::from(key);
+        let lookup = |chord: KeyChord| {
+            self.bindings(*context)
+                .iter()
+                .find(|(c, _)| *c == chord)
+                .map(|(_, command)| command.clone())
+        };
+
+        if let Some(command) = lookup(chord) {
+            return command;
+        }
+        if context.accepts_text()
+            && let KeyCode::Char(c) = key.code
+        {
+            return AppCommand::TypeChar(c);
+        }
+        if !chord.modifiers.is_empty()
+            && let Some(command) = lookup(KeyChord::new(chord.code, KeyModifiers::NONE))
+        {
+            return command;
+        }
+        AppCommand::Noop
+    }
+
+    /// Returns the bindings of a context, in display order.
+    #[must_use]
+    pub fn bindings(&self, context: InputContext) -> &[(KeyChord, AppCommand)] {
+        self.bindings.get(&context).map_or(&[], Vec::as_slice)
+    }
+
+    /// Returns the chords bound to `command` in `context`.
+    #[must_use]
+    pub fn chords_for(&self, context: InputContext, command: &AppCommand) -> Vec<KeyChord> {
+        self.bindings(context)
+            .iter()
+            .filter(|(_, c)| c == command)
+            .map(|(chord, _)| *chord)
+            .collect()
+    }
+
+    /// Returns the help entries of a context: the chords of each command
+    /// joined with ` / `, paired with the command description.
+    #[must_use]
+    pub fn help_entries(&self, context: InputContext) -> Vec<(String, &'static str)> {
+        let mut commands: Vec<&AppCommand> = Vec::new();
+        for (_, command) in self.bindings(context) {
+            if !commands.contains(&command) {
+                commands.push(command);
+            }
+        }
+
+        commands
+            .into_iter()
+            .map(|command| {
+                let chords: Vec<String> = self
+                    .chords_for(context, command)
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect();
+                (chords.join(" / "), command.description())
+            })
+            .collect()
+    }
+
+    /// Returns a compact hint for a group of commands, e.g. `↑↓` for
+    /// previous/next, using each command's first chord.
+    ///
+    /// Returns `None` if any of the commands is unbound.
+    #[must_use]
+    pub fn hint(&self, context: InputContext, commands: &[AppCommand]) -> Option<String> {
+        commands
+            .iter()
+            .map(|command| {
+                self.chords_for(context, command)
+                    .first()
+                    .map(ToString::to_string)
+            })
+            .collect()
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::{KeyEventKind, KeyEventState};
+    use rstest::rstest;
+
+    fn key(code: KeyCode, modifiers: KeyModifiers) -> KeyEvent {
+        KeyEvent {
+            code,
+            modifiers,
+            kind: KeyEventKind::Press,
+            state: KeyEventState::empty(),
+        }
+    }
+
+    fn overrides(
+        context: &str,
+        entries: &[(&str, &str)],
+    ) -> BTreeMap<String, BTreeMap<String, String>> {
+        BTreeMap::from([(
+            context.to_string(),
+            entries
+                .iter()
+                .map(|(chord, command)| (chord.to_string(), command.to_string()))
+                .collect(),
+        )])
+    }
+
+    #[rstest]
+    #[case::char("q", KeyCode::Char('q'), KeyModifiers::NONE)]
+    #[case::upper("G", KeyCode::Char('G'), KeyModifiers::NONE)]
+    #[case::shift_char("shift+g", KeyCode::Char('G'), KeyModifiers::NONE)]
+    #[case::ctrl("Ctrl+r", KeyCode::Char('r'), KeyModifiers::CONTROL)]
+    #[case::named("PageDown", KeyCode::PageDown, KeyModifiers::NONE)]
+    #[case::backtab("shift+tab", KeyCode::BackTab, KeyModifiers::NONE)]
+    #[case::function("alt+f5", KeyCode::F(5), KeyModifiers::ALT)]
+    #[case::plus("ctrl++", KeyCode::Char('+'), KeyModifiers::CONTROL)]
+    fn test_parse_chord(
+        #[case] input: &str,
+        #[case] code: KeyCode,
+        #[case] modifiers: KeyModifiers,
+    ) {
+        assert_eq!(KeyChord::parse(input), Ok(KeyChord::new(code, modifiers)));
+    }
+
+    #[rstest]
+    #[case::empty("")]
+    #[case::unknown_key("hyper")]
+    #[case::unknown_modifier("super+q")]
+    #[case::bad_function("f13")]
+    fn test_parse_chord_rejects_invalid(#[case] input: &str) {
+        assert!(KeyChord::parse(input).is_err());
+    }
+
+    #[test]
+    fn test_default_bindings_are_valid_and_unique() {
+        for (context, bindings) in DEFAULT_BINDINGS {
+            let mut chords = Vec::new();
+            for (chord, _) in *bindings {
+                let chord = KeyChord::parse(chord).unwrap();
+                assert!(!chords.contains(&chord), "{context:?} binds {chord} twice");
+                chords.push(chord);
+            }
+        }
+        assert_eq!(DEFAULT_BINDINGS.len(), InputContext::ALL.len());
+    }
+
+    #[test]
+    fn test_shifted_key_event_matches_uppercase_binding() {
+        let keymap = Keymap::default();
+        assert_eq!(
+            keymap.map_key(
+                key(KeyCode::Char('G'), KeyModifiers::SHIFT),
+                &InputContext::Main
+            ),
+            AppCommand::GoToBottom
+        );
+    }
+
+    #[test]
+    fn test_config_overrides_and_unbinds() {
+        let keymap = Keymap::from_config(&overrides(
+            "main",
+            &[("ctrl+r", "Refresh"), ("r", "none"), ("x", "ToggleHelp")],
+        ))
+        .unwrap();
+
+        let main = InputContext::Main;
+        assert_eq!(
+            keymap.map_key(key(KeyCode::Char('r'), KeyModifiers::CONTROL), &main),
+            AppCommand::Refresh
+        );
+        assert_eq!(
+            keymap.map_key(key(KeyCode::Char('r'), KeyModifiers::NONE), &main),
+            AppCommand::Noop
+        );
+        assert_eq!(
+            keymap.map_key(key(KeyCode::Char('x'), KeyModifiers::NONE), &main),
+            AppCommand::ToggleHelp
+        );
+        assert!(
+            keymap
+                .help_entries(main)
+                .contains(&("Ctrl+r".to_string(), "Refresh data"))
+        );
+    }
+
+    #[test]
+    fn test_config_reports_conflicts_and_unknown_names() {
+        let mut config = overrides("main", &[("G", "Refresh"), ("shift+g", "GoToTop")]);
+        config.extend(overrides("sidebar", &[("x", "Refresh")]));
+        config.extend(overrides(
+            "help",
+            &[("x", "Explode"), ("hyper+x", "Dismiss")],
+        ));
+
+        let err = Keymap::from_config(&config).unwrap_err();
+
+        assert_eq!(err.problems.len(), 4, "{err}");
+        assert!(err.to_string().contains("'G' and 'shift+g' are both G"));
+        assert!(err.to_string().contains("unknown context 'sidebar'"));
+        assert!(err.to_string().contains("unknown command 'Explode'"));
+        assert!(err.to_string().contains("unknown modifier 'hyper'"));
+    }
+
+    #[test]
+    fn test_hint_and_help_entries() {
+        let keymap = Keymap::default();
+
+        assert_eq!(
+            keymap.hint(
+                InputContext::InlineSearch,
+                &[AppCommand::SearchHistoryPrev, AppCommand::SearchHistoryNext]
+            ),
+            Some("↑↓".to_string())
+        );
+        assert_eq!(
+            keymap.hint(InputContext::Main, &[AppCommand::AddNetwork]),
+            None
+        );
+        assert!(
+            keymap
+                .help_entries(InputContext::Main)
+                .contains(&("↑ / k".to_string(), "Move up"))
+        );
+    }
+}
@@ -7,6 +7,8 @@ mod client;
 mod commands;
 mod constants;
 mod domain;
+mod keymap;
+mod show;
 mod state;
 #[cfg(test)]
 mod test_utils;
@@ -18,7 +20,9 @@ mod widgets;
 
 use boot_screen::BootScreen;
 use client::AlgoClient;
-use domain::Network;
+use client::cache::{self, ResponseCache};
+use domain::{Network, NetworkConfig};
+use show::{OutputFormat, ShowTarget};
 use state::{App, StartupOptions, StartupSearch, prefetch_initial_data};
 use tokio::sync::mpsc;
 
@@ -74,12 +78,16 @@ struct Cli {
     asset: Option<u64>,
 
     /// Network to connect to
-    #[arg(short = 'n', long = "network", value_enum)]
+    #[arg(short = 'n', long = "network", value_enum, global = true)]
     network: Option<NetworkArg>,
 
     /// Open transaction in graph view
     #[arg(short = 'g', long = "graph")]
     graph: bool,
+
+    /// Color theme: dark, light, high-contrast or the name of a theme file
+    #[arg(long = "theme")]
+    theme: Option<String>,
 }
 
 impl Cli {
@@ -110,13 +118,56 @@ enum Commands {
     },
 
     Version,
+
+    /// Print a transaction, account, block, asset or app without starting the TUI
+    Show {
+        #[command(subcommand)]
+        target: ShowTarget,
+
+        /// Output format
+        #[arg(short, long, value_enum, default_value_t = OutputFormat::Json, global = true)]
+        format: OutputFormat,
+    },
+
+    /// Simulate a transaction group from a .txn/.stxn/.tx file or base64
+    ///
+    /// Opens the result in the TUI, or prints it when --format is given.
+    Simulate {
+        /// Transaction file, or the group as base64
+        input: String,
+
+        /// Print the result in this format instead of opening the TUI
+        #[arg(short, long, value_enum)]
+        format: Option<OutputFormat>,
+    },
+
+    /// Manage the on-disk cache of transactions, blocks and other responses
+    Cache {
+        #[command(subcommand)]
+        action: CacheAction,
+    },
+}
+
+#[derive(Subcommand)]
+enum CacheAction {
+    /// Remove every cached response
+    Clear,
+}
+
+/// Installs the on-disk response cache, or continues without one.
+fn install_response_cache() {
+    match ResponseCache::open_default() {
+        Ok(response_cache) => cache::install(response_cache),
+        Err(e) => eprintln!("Response cache disabled: {e}"),
+    }
 }
 
 #[tokio::main]
 async fn main() -> Result<()> {
-    let cli = Cli::parse();
+    let mut cli = Cli::parse();
+    let mut startup_simulation = None;
 
-    if let Some(command) = cli.command {
+    if let Some(command) = cli.command.take() {
         match command {
             Commands::Update { install } => {
                 let source = updater::detect_install_source();
@@ -178,17 +229,81 @@ async fn main() -> Result<()> {
                 println!("A terminal UI for exploring the Algorand blockchain");
                 exit(0);
             }
+            Commands::Show { target, format } => {
+                let network_config = match cli.network {
+                    Some(network) => NetworkConfig::BuiltIn(network.into()),
+                    None => state::AppConfig::load().network,
+                };
+                install_response_cache();
+                let client = AlgoClient::from_config(&network_config)?;
+                exit(show::run(&client, &target, format).await);
+            }
+            Commands::Simulate {
+                input,
+                format: Some(format),
+            } => {
+                let network_config = match cli.network {
+                    Some(network) => NetworkConfig::BuiltIn(network.into()),
+                    None => state::AppConfig::load().network,
+                };
+                let client = AlgoClient::from_config(&network_config)?;
+                exit(show::simulate(&client, &input, format).await);
+            }
+            Commands::Simulate {
+                input,
+                format: None,
+            } => {
+                startup_simulation = Some(input);
+            }
+            Commands::Cache {
+                action: CacheAction::Clear,
+            } => {
+                let response_cache = ResponseCache::open_default()?;
+                let freed = response_cache.clear()?;
+                println!(
+                    "Cleared {:.1} MB from {}",
+                    freed as f64 / (1024.0 * 1024.0),
+                    response_cache.root().display()
+                );
+                exit(0);
+            }
         }
     }
 
     color_eyre::install()?;
 
-    let startup_options = cli.into_startup_options();
+    let theme_arg = cli.theme.take();
+    let mut startup_options = cli.into_startup_options();
+    if let Some(input) = startup_simulation {
+        startup_options.search = Some(StartupSearch::Simulation(input));
+    }
 
     // Load config and create client before boot screen
     let config = state::AppConfig::load();
+
+    // Install the color theme before anything is drawn. A bad --theme is an
+    // error; a bad theme in the config falls back to the default.
+    let themes_dir = state::AppConfig::themes_dir().unwrap_or_default();
+    if let Some(name) = &theme_arg {
+        theme::install(theme::Theme::load(name, &themes_dir)?);
+    } else if let Some(name) = &config.theme {
+        match theme::Theme::load(name, &themes_dir) {
+            Ok(theme) => theme::install(theme),
+            Err(e) => eprintln!("{e}\nUsing the default theme."),
+        }
+    }
+
+    // Load app specs used to decode ABI method calls
+    if let Ok(specs_dir) = state::AppConfig::specs_dir() {
+        let (specs, warnings) = domain::AppSpecRegistry::load_dir(&specs_dir);
+        for warning in warnings {
+            eprintln!("Skipping app spec {warning}");
+        }
+        domain::app_spec::install(specs);
+    }
+    install_response_cache();
     let network_config = if let Some(network) = startup_options.network {
-        domain::NetworkConfig::BuiltIn(network)
+        NetworkConfig::BuiltIn(network)
     } else {
         config.network.clone()
     };
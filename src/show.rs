@@ -0,0 +1,380 @@
+//! Non-interactive `show` and `simulate` subcommands.
+//!
+//! Looks up a single entity, or simulates a transaction group, with
+//! [`AlgoClient`] and prints the parsed domain value to stdout as JSON, YAML
+//! or a two-column table, so that lazylora's normalized view can be used in
+//! scripts and CI.
+
+use clap::{Subcommand, ValueEnum};
+use color_eyre::Result;
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::client::AlgoClient;
+use crate::domain::simulate::load_txn_group;
+use crate::domain::{
+    AccountAssetHolding, AccountDetails, AlgoError, AppLocalState, CreatedAppInfo,
+    CreatedAssetInfo, Page, Simulation,
+};
+
+/// Exit code for lookup and output errors.
+pub const EXIT_ERROR: i32 = 1;
+
+/// Exit code when the requested entity does not exist.
+pub const EXIT_NOT_FOUND: i32 = 2;
+
+/// Exit code when a simulated group would be rejected.
+pub const EXIT_REJECTED: i32 = 3;
+
+// ============================================================================
+// Arguments
+// ============================================================================
+
+/// Entity to look up.
+#[derive(Debug, Clone, Subcommand)]
+pub enum ShowTarget {
+    /// Transaction by ID
+    #[command(alias = "txn")]
+    Tx { id: String },
+    /// Account by address, with every asset and app it holds
+    Account { address: String },
+    /// Block by round, including all of its transactions
+    Block { round: u64 },
+    /// Asset by ID
+    Asset { id: u64 },
+    /// Application by ID
+    #[command(alias = "application")]
+    App { id: u64 },
+}
+
+/// Output format for `show` and `simulate`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum OutputFormat {
+    /// Pretty-printed JSON
+    #[default]
+    Json,
+    /// Flattened `key  value` rows
+    Table,
+    /// YAML
+    Yaml,
+}
+
+// ============================================================================
+// Command
+// ============================================================================
+
+/// Looks up `target`, prints it in `format` and returns the process exit code.
+///
+/// Errors are printed to stderr. Returns [`EXIT_NOT_FOUND`] if the entity does
+/// not exist and [`EXIT_ERROR`] for any other failure.
+pub async fn run(client: &AlgoClient, target: &ShowTarget, format: OutputFormat) -> i32 {
+    let output = fetch(client, target)
+        .await
+        .and_then(|value| render(&value, format));
+
+    match output {
+        Ok(output) => {
+            println!("{}", output.trim_end());
+            0
+        }
+        Err(e) => {
+            eprintln!("Error: {e}");
+            match e.downcast_ref::<AlgoError>() {
+                Some(AlgoError::NotFound { .. }) => EXIT_NOT_FOUND,
+                _ => EXIT_ERROR,
+            }
+        }
+    }
+}
+
+/// Fetches the entity and serializes its domain type.
+async fn fetch(client: &AlgoClient, target: &ShowTarget) -> Result<Value> {
+    let value = match target {
+        ShowTarget::Tx { id } => {
+            let txn = client
+                .get_transaction_by_id(id)
+                .await?
+                .ok_or_else(|| AlgoError::not_found("transaction", id.as_str()).into_report())?;
+            serde_json::to_value(txn)?
+        }
+        ShowTarget::Account { address } => {
+            let details = client.get_account_details(address).await?;
+            // Holdings are listed by the indexer only
+            let holdings = if client.is_algod_only() {
+                None
+            } else {
+                Some(fetch_account_holdings(client, address).await?)
+            };
+            serde_json::to_value(AccountWithHoldings { details, holdings })?
+        }
+        ShowTarget::Block { round } => {
+            let mut details = client
+                .get_block_details(*round)
+                .await?
+                .ok_or_else(|| AlgoError::not_found("block", round.to_string()).into_report())?;
+            while let Some(token) = details.next_token.clone() {
+                let page = client
+                    .get_block_transactions_page(*round, Some(&token))
+                    .await?;
+                details.append_page(page);
+            }
+            serde_json::to_value(details)?
+        }
+        ShowTarget::Asset { id } => serde_json::to_value(client.get_asset_details(*id).await?)?,
+        ShowTarget::App { id } => serde_json::to_value(client.get_application_details(*id).await?)?,
+    };
+    Ok(value)
+}
+
+/// Simulates the group in `input`, prints the outcome in `format` and
+/// returns the process exit code.
+///
+/// `input` is a transaction file or a base64 group. Errors are printed to
+/// stderr. Returns [`EXIT_REJECTED`] if the group would fail and
+/// [`EXIT_ERROR`] if it could not be simulated.
+pub async fn simulate(client: &AlgoClient, input: &str, format: OutputFormat) -> i32 {
+    let output = run_simulation(client, input).await.and_then(|simulation| {
+        let output = render(&serde_json::to_value(&simulation)?, format)?;
+        Ok((output, simulation.would_succeed()))
+    });
+
+    match output {
+        Ok((output, would_succeed)) => {
+            println!("{}", output.trim_end());
+            if would_succeed { 0 } else { EXIT_REJECTED }
+        }
+        Err(e) => {
+            eprintln!("Error: {e}");
+            EXIT_ERROR
+        }
+    }
+}
+
+/// Loads the group and sends it to algod.
+async fn run_simulation(client: &AlgoClient, input: &str) -> Result<Simulation> {
+    let txns = load_txn_group(input).map_err(AlgoError::into_report)?;
+    client.simulate_transactions(&txns).await
+}
+
+// ============================================================================
+// Accounts
+// ============================================================================
+
+/// An account as printed by `show account`, with every holding and app.
+#[derive(Debug, Serialize)]
+struct AccountWithHoldings {
+    #[serde(flatten)]
+    details: AccountDetails,
+    #[serde(flatten)]
+    holdings: Option<AccountHoldings>,
+}
+
+/// Every asset and app list of an account.
+#[derive(Debug, Default, Serialize)]
+struct AccountHoldings {
+    assets: Vec<AccountAssetHolding>,
+    created_assets: Vec<CreatedAssetInfo>,
+    apps_local_state: Vec<AppLocalState>,
+    created_apps: Vec<CreatedAppInfo>,
+}
+
+/// Pages through each of the account's lists to completion.
+async fn fetch_account_holdings(client: &AlgoClient, address: &str) -> Result<AccountHoldings> {
+    Ok(AccountHoldings {
+        assets: collect_pages(|token| async move {
+            client
+                .get_account_assets_page(address, token.as_deref())
+                .await
+        })
+        .await?,
+        created_assets: collect_pages(|token| async move {
+            client
+                .get_account_created_assets_page(address, token.as_deref())
+                .await
+        })
+        .await?,
+        apps_local_state: collect_pages(|token| async move {
+            client
+                .get_account_apps_page(address, token.as_deref())
+                .await
+        })
+        .await?,
+        created_apps: collect_pages(|token| async move {
+            client
+                .get_account_created_apps_page(address, token.as_deref())
+                .await
+        })
+        .await?,
+    })
+}
+
+/// Fetches every page of a list, following next tokens.
+async fn collect_pages<T, F, Fut>(fetch_page: F) -> Result<Vec<T>>
+where
+    F: Fn(Option<String>) -> Fut,
+    Fut: Future<Output = Result<Page<T>>>,
+{
+    let mut items = Vec::new();
+    let mut next_token = None;
+    loop {
+        let page = fetch_page(next_token).await?;
+        items.extend(page.items);
+        match page.next_token {
+            Some(token) => next_token = Some(token),
+            None => return Ok(items),
+        }
+    }
+}
+
+// ============================================================================
+// Rendering
+// ============================================================================
+
+/// Renders a serialized entity in the requested format.
+///
+/// # Errors
+///
+/// Returns an error if the value cannot be serialized to the format.
+pub fn render(value: &Value, format: OutputFormat) -> Result<String> {
+    Ok(match format {
+        OutputFormat::Json => serde_json::to_string_pretty(value)?,
+        OutputFormat::Yaml => serde_norway::to_string(value)?,
+        OutputFormat::Table => render_table(value),
+    })
+}
+
+/// Renders a value as aligned `key  value` rows, one per leaf.
+///
+/// Nested keys are joined with `.` and array elements are indexed, e.g.
+/// `details.app_args[0]`.
+fn render_table(value: &Value) -> String {
+    let mut rows = Vec::new();
+    flatten(String::new(), value, &mut rows);
+
+    let width = rows.iter().map(|(key, _)| key.len()).max().unwrap_or(0);
+    rows.iter()
+        .map(|(key, value)| format!("{key:<width$}  {value}"))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Collects the leaves of `value` as (path, display value) pairs.
+fn flatten(path: String, value: &Value, rows: &mut Vec<(String, String)>) {
+    match value {
+        Value::Object(map) if !map.is_empty() => {
+            for (key, child) in map {
+                let child_path = if path.is_empty() {
+                    key.clone()
+                } else {
+                    format!("{path}.{key}")
+                };
+                flatten(child_path, child, rows);
+            }
+        }
+        Value::Array(items) if !items.is_empty() => {
+            for (idx, child) in items.iter().enumerate() {
+                flatten(format!("{path}[{idx}]"), child, rows);
+            }
+        }
+        Value::Object(_) => rows.push((path, "{}".to_string())),
+        Value::Array(_) => rows.push((path, "[]".to_string())),
+        Value::Null => rows.push((path, "-".to_string())),
+        Value::String(s) => rows.push((path, s.clone())),
+        other => rows.push((path, other.to_string())),
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::TransactionMother;
+    use serde_json::json;
+
+    #[test]
+    fn test_render_table_flattens_nested_values() {
+        let value = json!({
+            "id": "TX1",
+            "details": {"AppCall": {"app_args": ["AQ==", "Ag=="], "accounts": []}},
+            "group": null
+        });
+
+        let table = render(&value, OutputFormat::Table).unwrap();
+
+        assert_eq!(
+            table,
+            "details.AppCall.accounts     []\n\
+             details.AppCall.app_args[0]  AQ==\n\
+             details.AppCall.app_args[1]  Ag==\n\
+             group                        -\n\
+             id                           TX1"
+        );
+    }
+
+    #[test]
+    fn test_render_account_with_holdings() {
+        let account = AccountWithHoldings {
+            details: AccountDetails {
+                address: "ADDR".to_string(),
+                balance: 5_000_000,
+                ..AccountDetails::default()
+            },
+            holdings: Some(AccountHoldings {
+                assets: vec![AccountAssetHolding::new(31566704, 2_500, false)],
+                created_apps: vec![CreatedAppInfo::new(1234)],
+                ..AccountHoldings::default()
+            }),
+        };
+        let value = serde_json::to_value(&account).unwrap();
+
+        assert_eq!(value["address"], "ADDR");
+        assert_eq!(value["assets"][0]["asset_id"], 31566704);
+        assert_eq!(value["assets"][0]["amount"], 2_500);
+        let table = render(&value, OutputFormat::Table).unwrap();
+        assert!(table.contains("assets[0].asset_id"), "{table}");
+        assert!(table.contains("created_apps[0].app_id"), "{table}");
+        assert!(
+            table
+                .lines()
+                .any(|line| line.starts_with("apps_local_state "))
+        );
+
+        // Without an indexer the lists are left out rather than shown empty
+        let value = serde_json::to_value(AccountWithHoldings {
+            details: AccountDetails::default(),
+            holdings: None,
+        })
+        .unwrap();
+        assert!(value.get("assets").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_collect_pages_follows_next_tokens() {
+        let items = collect_pages(|token| async move {
+            Ok(match token.as_deref() {
+                None => Page::new(vec![1, 2], Some("p2".to_string())),
+                Some("p2") => Page::new(vec![3], None),
+                Some(other) => panic!("unexpected token {other}"),
+            })
+        })
+        .await
+        .unwrap();
+        assert_eq!(items, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn test_render_transaction_formats() {
+        let txn = TransactionMother::mainnet_payment();
+        let value = serde_json::to_value(&txn).unwrap();
+
+        let json = render(&value, OutputFormat::Json).unwrap();
+        let yaml = render(&value, OutputFormat::Yaml).unwrap();
+
+        assert_eq!(serde_json::from_str::<Value>(&json).unwrap(), value);
+        assert!(yaml.contains(&format!("id: {}", txn.id)));
+        assert!(yaml.contains("txn_type: Payment"));
+    }
+}
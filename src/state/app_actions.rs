@@ -1,17 +1,31 @@
 //! Application actions for LazyLora.
 //!
 //! This module handles high-level actions like searching, network switching,
-//! clipboard operations, browser integration, and SVG export.
+//! watchlist edits, transaction simulation, clipboard operations, browser
+//! integration, and SVG and list exports.
 
 use arboard::Clipboard;
 use color_eyre::Result;
-use std::io::Write;
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
 use std::process::{Command, Stdio};
 use std::time::Duration;
+use tokio::sync::mpsc;
 
-use super::{App, AppConfig, AppMessage, DetailViewMode, PopupState, SearchType};
+use super::{
+    AccountDetailTab, App, AppConfig, AppMessage, AssetDetailTab, AssetHolders, BlockDetailTab,
+    DetailViewMode, Focus, PopupState, SearchType, SimulationTab,
+};
 use crate::client::AlgoClient;
-use crate::domain::{Network, NetworkConfig, SearchResultItem, Transaction, TransactionDetails};
+use crate::commands::InputContext;
+use crate::domain::export::expand_path;
+use crate::domain::simulate::load_txn_group;
+use crate::domain::{
+    AlgoError, DataSource, ExportFormat, ExportSource, Network, NetworkConfig, SearchResultItem,
+    Simulation, Transaction, TransactionDetails, TxnFilter, WatchTarget, app_spec,
+};
 use crate::ui;
 use crate::widgets::TxnGraph;
 
@@ -57,14 +71,17 @@ impl App {
         let indexer_token = form.indexer_token.trim();
         let algod_token = form.algod_token.trim();
 
-        if name.is_empty() || indexer_url.is_empty() || algod_url.is_empty() {
-            self.ui
-                .show_toast("Name, Indexer URL, and Algod URL are required", 30);
+        if name.is_empty() || algod_url.is_empty() {
+            self.ui.show_toast("Name and Algod URL are required", 30);
             return Ok(());
         }
 
+        // Without an indexer URL the network is read from algod alone
+        let algod_only = indexer_url.is_empty();
+
         let indexer_port: u16 = match indexer_port.parse() {
             Ok(port) if port > 0 => port,
+            _ if algod_only => 0,
             _ => {
                 self.ui.show_toast("Indexer port must be a number", 30);
                 return Ok(());
@@ -79,12 +96,16 @@ impl App {
             }
         };
 
-        if !indexer_url.starts_with("http") || !algod_url.starts_with("http") {
+        if !(algod_only || indexer_url.starts_with("http")) || !algod_url.starts_with("http") {
             self.ui.show_toast("URLs should start with http/https", 30);
             return Ok(());
         }
 
-        let indexer_full = Self::append_port_if_missing(&indexer_url, indexer_port);
+        let indexer_full = if algod_only {
+            String::new()
+        } else {
+            Self::append_port_if_missing(&indexer_url, indexer_port)
+        };
         let algod_full = Self::append_port_if_missing(&algod_url, algod_port);
 
         let custom = crate::domain::CustomNetwork {
@@ -94,6 +115,7 @@ impl App {
             indexer_token: (!indexer_token.is_empty()).then(|| indexer_token.to_string()),
             algod_token: (!algod_token.is_empty()).then(|| algod_token.to_string()),
             nfd_api_url: None,
+            algod_only,
         };
 
         let mut config = AppConfig::load();
@@ -153,9 +175,12 @@ impl App {
         self.network_config = config;
 
         self.save_config();
+        self.close_pending_pool();
         self.data.clear();
         self.nav.reset();
         self.ui.viewing_search_result = false;
+        let watchlist = AppConfig::load().watchlist(&self.network_config).to_vec();
+        self.set_watchlist(&watchlist);
 
         self.initial_data_fetch().await;
 
@@ -169,6 +194,39 @@ impl App {
         });
     }
 
+    /// Applies the data source reported by the latest network check.
+    ///
+    /// The change is announced when the indexer disappears or comes back.
+    pub(crate) fn set_data_source(&mut self, source: DataSource) {
+        self.client.set_data_source(source);
+        let source = self.client.data_source();
+        if self.data.data_source == source {
+            return;
+        }
+        self.data.data_source = source;
+        match source {
+            DataSource::AlgodOnly => self.ui.show_toast(
+                "No indexer - algod-only mode, history features unavailable",
+                50,
+            ),
+            DataSource::Indexer => self.ui.show_toast("Indexer available", 30),
+        }
+    }
+
+    /// Shows why `feature` can't be used if the network is in algod-only mode.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the feature is unavailable.
+    pub(crate) fn indexer_unavailable(&mut self, feature: &'static str) -> bool {
+        if self.data.data_source == DataSource::Indexer {
+            return false;
+        }
+        self.ui
+            .show_toast(AlgoError::indexer_unavailable(feature).to_string(), 40);
+        true
+    }
+
     pub(crate) fn toggle_live_updates(&mut self) {
         self.show_live = !self.show_live;
         // Watch channel send: background task reacts to toggle
@@ -185,6 +243,200 @@ impl App {
         }
     }
 
+    // ========================================================================
+    // Watchlist
+    // ========================================================================
+
+    /// Watches or unwatches the account, asset or app shown in the active
+    /// detail popup; in the main view, unwatches the selected watchlist entry.
+    pub(crate) fn handle_toggle_watch(&mut self) {
+        let target = match self.get_input_context() {
+            InputContext::AccountDetailView => self
+                .data
+                .viewed_account
+                .as_ref()
+                .map(|account| WatchTarget::Account(account.address.clone())),
+            InputContext::AppDetailView => self
+                .data
+                .viewed_application
+                .as_ref()
+                .map(|app| WatchTarget::App(app.app_id)),
+            InputContext::AssetDetailView => self
+                .data
+                .viewed_asset
+                .as_ref()
+                .map(|asset| WatchTarget::Asset(asset.id)),
+            InputContext::Main if self.ui.focus == Focus::Watchlist => self
+                .nav
+                .selected_watch_index
+                .and_then(|index| self.data.watchlist.get(index))
+                .map(|status| status.target.clone()),
+            _ => None,
+        };
+        if let Some(target) = target {
+            self.toggle_watch(target);
+        }
+    }
+
+    /// Adds a target to the current network's watchlist, or removes it, and
+    /// saves the configuration.
+    pub(crate) fn toggle_watch(&mut self, target: WatchTarget) {
+        let mut config = AppConfig::load();
+        let watched = config.toggle_watch(&self.network_config, target.clone());
+        if let Err(e) = config.save() {
+            self.ui
+                .show_toast(format!("Failed to save watchlist: {e}"), 30);
+            return;
+        }
+
+        let watchlist = config.watchlist(&self.network_config).to_vec();
+        self.set_watchlist(&watchlist);
+        let message = if watched {
+            format!("Watching {target}")
+        } else {
+            format!("Stopped watching {target}")
+        };
+        self.ui.show_toast(message, 20);
+    }
+
+    /// Replaces the watched targets and restarts their background polling.
+    pub(crate) fn set_watchlist(&mut self, targets: &[WatchTarget]) {
+        self.data.set_watchlist(targets);
+        if targets.is_empty() {
+            self.nav.selected_watch_index = None;
+            self.nav.watch_scroll = 0;
+            if self.ui.focus == Focus::Watchlist {
+                self.ui.focus = Focus::Blocks;
+            }
+        } else if let Some(index) = self.nav.selected_watch_index {
+            self.nav.selected_watch_index = Some(index.min(targets.len() - 1));
+        }
+        // Watch channel send: background task polls the new list right away
+        let _ = self.watchlist_tx.send(targets.to_vec());
+    }
+
+    // ========================================================================
+    // Transaction Feed Filter
+    // ========================================================================
+
+    /// Applies or clears the live transaction feed filter.
+    ///
+    /// Non-matching transactions are dropped right away; the background task
+    /// then backfills matches from the indexer.
+    pub(crate) fn set_txn_filter(&mut self, filter: Option<TxnFilter>) {
+        let message = match &filter {
+            Some(filter) => format!("Filtering transactions: {filter}"),
+            None => "Transaction filter cleared".to_string(),
+        };
+        self.data.set_txn_filter(filter.clone());
+        self.nav.selected_transaction_index = None;
+        self.nav.selected_transaction_id = None;
+        self.nav.transaction_scroll = 0;
+        // Watch channel: background task refetches the feed right away
+        self.txn_filter_tx.send_replace(filter);
+        self.ui.show_toast(message, 20);
+    }
+
+    // ========================================================================
+    // Simulation
+    // ========================================================================
+
+    /// Opens the simulation prompt, prefilled with the last simulated input.
+    ///
+    /// Only available from the main view or over the last simulation.
+    pub(crate) fn open_simulate(&mut self) {
+        if self.nav.is_showing_details() && !self.nav.show_simulation {
+            return;
+        }
+        if self.data.simulating {
+            self.ui.show_toast("A simulation is already running", 20);
+            return;
+        }
+        self.ui.open_simulate(self.data.simulate_input.clone());
+    }
+
+    /// Submits the simulation prompt and sends the group to algod.
+    ///
+    /// Input that does not hold a transaction group keeps the prompt open.
+    pub(crate) fn handle_submit_simulate(&mut self) {
+        let PopupState::Simulate(input) = &self.ui.popup_state else {
+            return;
+        };
+        let input = input.trim().to_string();
+        let txns = match load_txn_group(&input) {
+            Ok(txns) => txns,
+            Err(e) => {
+                self.ui.show_toast(e.to_string(), 30);
+                return;
+            }
+        };
+        self.ui.dismiss_popup();
+        self.ui
+            .show_toast(format!("Simulating {} transactions...", txns.len()), 100);
+        self.data.simulate_input = input;
+        self.data.simulating = true;
+
+        let client = self.client.clone();
+        let message_tx = self.message_tx.clone();
+        tokio::spawn(async move {
+            let message = match client.simulate_transactions(&txns).await {
+                Ok(simulation) => AppMessage::SimulationLoaded(Box::new(simulation)),
+                Err(e) => AppMessage::SimulationFailed(e.to_string()),
+            };
+            // Receiver may be dropped during shutdown - safe to ignore
+            let _ = message_tx.send(message);
+        });
+    }
+
+    /// Shows a finished simulation in place of any open view, with the
+    /// failing transaction selected.
+    pub(crate) fn show_simulation(&mut self, simulation: Simulation) {
+        self.data.simulating = false;
+        let message = match simulation.failed_at_label() {
+            Some(label) => format!("[x] Group would fail at {label}"),
+            None => "[+] Group would succeed".to_string(),
+        };
+        self.ui.show_toast(message, 40);
+
+        if self.nav.is_showing_details() {
+            self.close_pending_pool();
+            self.nav.close_details();
+            self.nav.popup_stack.clear();
+            self.data.viewed_transaction = None;
+        }
+        self.nav.reset_simulation();
+        self.nav.graph_scroll_x = 0;
+        self.nav.graph_scroll_y = 0;
+        self.nav.simulation_txn_index = simulation.failed_index().or(Some(0));
+        self.data.simulation = Some(simulation);
+        self.nav.show_simulation = true;
+    }
+
+    /// Opens the selected simulated transaction.
+    ///
+    /// The simulation state is saved to the stack so Esc returns to it.
+    pub(crate) fn handle_select_simulated_txn(&mut self) {
+        if self.nav.simulation_tab != SimulationTab::Result {
+            return;
+        }
+        let Some(txn) = self
+            .nav
+            .simulation_txn_index
+            .and_then(|idx| self.data.simulation.as_ref()?.transactions.get(idx))
+            .map(|simulated| simulated.transaction.clone())
+        else {
+            return;
+        };
+
+        self.nav.push_simulation_state();
+        self.nav.show_simulation = false;
+        self.nav.detail_row_index = None;
+        self.nav.detail_row_scroll = 0;
+        self.data.viewed_transaction = Some(txn);
+        self.nav.show_transaction_details = true;
+        self.update_detail_table_rows();
+    }
+
     // ========================================================================
     // Clipboard
     // ========================================================================
@@ -608,6 +860,98 @@ impl App {
         }
     }
 
+    /// The list shown in the current detail view, if it can be exported.
+    ///
+    /// Covers block transactions, account history, asset holders and
+    /// advanced search results.
+    pub(crate) fn current_export_source(&self) -> Option<ExportSource> {
+        if self.nav.show_block_details {
+            if self.nav.block_detail_tab != BlockDetailTab::Transactions {
+                return None;
+            }
+            let round = self.data.block_details.as_ref()?.info.id;
+            Some(ExportSource::BlockTransactions { round })
+        } else if self.nav.show_account_details {
+            if self.nav.account_detail_tab != AccountDetailTab::History {
+                return None;
+            }
+            let history = self.data.account_history.as_ref()?;
+            Some(ExportSource::AccountHistory {
+                address: history.address.clone(),
+                txn_type: history.filter,
+            })
+        } else if self.nav.show_asset_details {
+            if self.nav.asset_detail_tab != AssetDetailTab::Holders {
+                return None;
+            }
+            let asset = self.data.viewed_asset.as_ref()?;
+            let min_units = self.data.asset_holders.as_ref().and_then(|h| h.min_units);
+            Some(ExportSource::AssetHolders {
+                asset_id: asset.id,
+                decimals: asset.decimals,
+                min_balance: min_units.map(|units| AssetHolders::base_units(units, asset.decimals)),
+            })
+        } else if self.nav.show_txn_search {
+            let search = self.data.txn_search.as_ref()?;
+            Some(ExportSource::TxnSearch(search.query.clone()))
+        } else {
+            None
+        }
+    }
+
+    /// Opens the export path prompt for the list in the current view.
+    pub(crate) fn open_export(&mut self) {
+        if self.data.exporting {
+            self.ui.show_toast("An export is already running", 20);
+            return;
+        }
+        match self.current_export_source() {
+            Some(source) if source.requires_indexer() => {
+                if !self.indexer_unavailable("Exporting this list") {
+                    self.ui.open_export(source);
+                }
+            }
+            Some(source) => self.ui.open_export(source),
+            None => self.ui.show_toast("Nothing to export in this view", 20),
+        }
+    }
+
+    /// Submits the export prompt and starts writing the file.
+    ///
+    /// An unsupported extension keeps the prompt open.
+    pub(crate) fn handle_submit_export(&mut self) {
+        let PopupState::Export(prompt) = &self.ui.popup_state else {
+            return;
+        };
+        let path = prompt.path.trim().to_string();
+        let format = match ExportFormat::from_path(&path) {
+            Ok(format) => format,
+            Err(e) => {
+                self.ui.show_toast(e.to_string(), 30);
+                return;
+            }
+        };
+        let source = prompt.source.clone();
+        self.ui.dismiss_popup();
+        self.ui.show_toast(format!("Exporting {source}..."), 100);
+        self.data.exporting = true;
+
+        let client = self.client.clone();
+        let message_tx = self.message_tx.clone();
+        tokio::spawn(async move {
+            let path = expand_path(&path);
+            let message = match write_export(&client, &source, format, &path, &message_tx).await {
+                Ok(rows) => AppMessage::ExportFinished {
+                    path: path.display().to_string(),
+                    rows,
+                },
+                Err(e) => AppMessage::ExportFailed(e.to_string()),
+            };
+            // Receiver may be dropped during shutdown - safe to ignore
+            let _ = message_tx.send(message);
+        });
+    }
+
     // ========================================================================
     // Expandable Sections
     // ========================================================================
@@ -770,12 +1114,40 @@ impl App {
             // Initialize row selection at first row
             self.nav
                 .init_detail_row_if_needed(self.ui.detail_table_rows.len());
+            self.fetch_programs_for_app_specs(&txn);
         } else {
             self.ui.detail_table_rows.clear();
             self.nav.reset_detail_row();
         }
     }
 
+    /// Fetches the approval programs of called apps that may match an app spec
+    /// keyed by program hash, so that their ABI calls can be decoded.
+    ///
+    /// Results land in the spec registry and show on the next redraw.
+    fn fetch_programs_for_app_specs(&self, txn: &Transaction) {
+        let mut pending = vec![txn];
+        while let Some(txn) = pending.pop() {
+            pending.extend(&txn.inner_transactions);
+            let TransactionDetails::AppCall(details) = &txn.details else {
+                continue;
+            };
+            if !app_spec::specs().should_fetch_program(details.app_id) {
+                continue;
+            }
+
+            let client = self.client.clone();
+            let app_id = details.app_id;
+            tokio::spawn(async move {
+                if let Ok(app) = client.get_application_details(app_id).await
+                    && let Some(program) = &app.approval_program
+                {
+                    app_spec::specs().learn_program(app_id, program);
+                }
+            });
+        }
+    }
+
     /// Gets the transaction to display in details (from various sources).
     pub(crate) fn get_transaction_for_details(&self) -> Option<Transaction> {
         // First check for directly viewed transaction (from block details)
@@ -808,3 +1180,90 @@ impl App {
             .and_then(|index| self.data.transactions.get(index).cloned())
     }
 }
+
+/// Writes every page of `source` to `path`, reporting progress after each page.
+///
+/// Records go to a `.part` file next to `path` that is renamed once the last
+/// page is written, so a failed export leaves no truncated file behind.
+///
+/// Returns the number of records written.
+async fn write_export(
+    client: &AlgoClient,
+    source: &ExportSource,
+    format: ExportFormat,
+    path: &Path,
+    message_tx: &mpsc::UnboundedSender<AppMessage>,
+) -> Result<usize> {
+    let mut part_name = path.file_name().unwrap_or_default().to_os_string();
+    part_name.push(".part");
+    let part_path = path.with_file_name(part_name);
+
+    let result = write_export_pages(client, source, format, part_path.clone(), message_tx).await;
+    match result {
+        Ok(rows) => {
+            let path = path.to_path_buf();
+            blocking_io(move || std::fs::rename(part_path, path)).await?;
+            Ok(rows)
+        }
+        Err(e) => {
+            // Nothing may have been created if the directory is missing
+            let _ = blocking_io(move || std::fs::remove_file(part_path)).await;
+            Err(e)
+        }
+    }
+}
+
+/// Writes every page of `source` to `path`.
+async fn write_export_pages(
+    client: &AlgoClient,
+    source: &ExportSource,
+    format: ExportFormat,
+    path: PathBuf,
+    message_tx: &mpsc::UnboundedSender<AppMessage>,
+) -> Result<usize> {
+    let columns = source.columns();
+    let header = format.header(columns);
+    let mut file = blocking_io(move || {
+        let mut file = BufWriter::new(File::create(path)?);
+        file.write_all(header.as_bytes())?;
+        Ok(file)
+    })
+    .await?;
+
+    let mut asset_decimals = HashMap::new();
+    let mut next_token = None;
+    let mut rows = 0;
+    loop {
+        let page = client
+            .get_export_page(source, next_token.as_deref(), &mut asset_decimals)
+            .await?;
+        let chunk: String = page
+            .items
+            .iter()
+            .map(|record| format.record(columns, record))
+            .collect();
+        file = blocking_io(move || {
+            file.write_all(chunk.as_bytes())?;
+            Ok(file)
+        })
+        .await?;
+        rows += page.items.len();
+        // The indexer may hand out a token with an empty final page
+        if page.items.is_empty() || !page.has_more() {
+            break;
+        }
+        let _ = message_tx.send(AppMessage::ExportProgress { rows });
+        next_token = page.next_token;
+    }
+    blocking_io(move || file.flush()).await?;
+    Ok(rows)
+}
+
+/// Runs file I/O off the async worker threads.
+async fn blocking_io<T, F>(io: F) -> Result<T>
+where
+    T: Send + 'static,
+    F: FnOnce() -> std::io::Result<T> + Send + 'static,
+{
+    Ok(tokio::task::spawn_blocking(io).await??)
+}
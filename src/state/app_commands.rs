@@ -7,20 +7,23 @@ use color_eyre::Result;
 use crossterm::event::{KeyEvent, MouseButton, MouseEvent, MouseEventKind};
 
 use super::{
-    AccountDetailTab, App, AppConfig, AppDetailTab, AppMessage, BlockDetailTab, DetailViewMode,
-    Focus, PopupState, SearchType, navigation::DetailPopupType,
+    AccountDetailTab, AccountHistory, AccountList, AccountListPage, App, AppBoxes, AppConfig,
+    AppDetailTab, AppMessage, AssetDetailTab, AssetHolders, BlockDetailTab, DetailViewMode, Focus,
+    GroupDetailTab, LocalStateView, PendingPool, PoolScope, PopupState, SearchType, SimulationTab,
+    TxnSearch, navigation::DetailPopupType, ui_state::AdvancedSearchForm,
 };
-use crate::commands::{AppCommand, InputContext, map_key};
+use crate::commands::{AppCommand, InputContext};
 use crate::constants::{
-    BLOCK_HEIGHT, DEFAULT_TERMINAL_WIDTH, HEADER_HEIGHT, SEARCH_BAR_HEIGHT, TXN_HEIGHT,
+    BLOCK_HEIGHT, DEFAULT_TERMINAL_WIDTH, HEADER_HEIGHT, LOAD_MORE_THRESHOLD, SEARCH_BAR_HEIGHT,
+    TXN_HEIGHT,
 };
-use crate::domain::{NetworkConfig, SearchResultItem};
+use crate::domain::{NetworkConfig, SearchResultItem, TxnFilter, TxnQuery, TxnType};
 use crate::ui;
 
 impl App {
     pub(crate) async fn handle_key_event(&mut self, key_event: KeyEvent) -> Result<()> {
         let context = self.get_input_context();
-        let command = map_key(key_event, &context);
+        let command = self.keymap.map_key(key_event, &context);
         self.execute_command(command).await
     }
 
@@ -40,6 +43,11 @@ impl App {
             PopupState::ConfirmQuit => InputContext::ConfirmQuit,
             PopupState::NetworkSelect(_) => InputContext::NetworkSelect,
             PopupState::NetworkForm(_) => InputContext::NetworkForm,
+            PopupState::LocalStateLookup(_) => InputContext::LocalStateLookup,
+            PopupState::TxnFilter(_) => InputContext::TxnFilterInput,
+            PopupState::AdvancedSearch(_) => InputContext::AdvancedSearchForm,
+            PopupState::Export(_) => InputContext::ExportPrompt,
+            PopupState::Simulate(_) => InputContext::SimulateInput,
             PopupState::SearchWithType(_, _) => InputContext::SearchInput,
             PopupState::SearchResults(_) => InputContext::SearchResults,
             PopupState::Message(_) => InputContext::MessagePopup,
@@ -63,7 +71,15 @@ impl App {
                         InputContext::DetailView
                     }
                 } else if self.nav.show_asset_details {
-                    InputContext::DetailView
+                    InputContext::AssetDetailView
+                } else if self.nav.show_group_details {
+                    InputContext::GroupDetailView
+                } else if self.nav.show_txn_search {
+                    InputContext::TxnSearchView
+                } else if self.nav.show_pending_pool {
+                    InputContext::PendingPoolView
+                } else if self.nav.show_simulation {
+                    InputContext::SimulationView
                 } else {
                     InputContext::Main
                 }
@@ -108,7 +124,7 @@ impl App {
 
             // === Navigation ===
             AppCommand::CycleFocus => {
-                self.ui.cycle_focus();
+                self.ui.cycle_focus(!self.data.watchlist.is_empty());
             }
             AppCommand::MoveUp => {
                 self.move_selection_up();
@@ -181,12 +197,18 @@ impl App {
                 }
             }
             AppCommand::GraphScrollLeft => {
-                if self.nav.show_transaction_details {
+                if self.nav.show_transaction_details
+                    || self.nav.show_group_details
+                    || self.nav.show_simulation
+                {
                     self.nav.graph_scroll_x = self.nav.graph_scroll_x.saturating_sub(4);
                 }
             }
             AppCommand::GraphScrollRight => {
-                if self.nav.show_transaction_details {
+                if self.nav.show_transaction_details
+                    || self.nav.show_group_details
+                    || self.nav.show_simulation
+                {
                     self.nav.graph_scroll_x = self.nav.graph_scroll_x.saturating_add(4);
                 }
             }
@@ -220,6 +242,11 @@ impl App {
                     self.export_transaction_svg();
                 }
             }
+            AppCommand::ViewGroup => {
+                if self.nav.show_transaction_details {
+                    self.view_transaction_group();
+                }
+            }
             AppCommand::ToggleFullscreen => {
                 if self.nav.is_showing_details() {
                     self.ui.toggle_fullscreen();
@@ -248,6 +275,7 @@ impl App {
                     // Use a reasonable default visible height (popup content area ~20 lines)
                     self.nav.move_block_txn_down(max, 20);
                 }
+                self.load_more_block_transactions_if_needed();
             }
             AppCommand::SelectBlockTxn => {
                 // If we have a selected transaction in block details, fetch and show its full details
@@ -268,47 +296,300 @@ impl App {
             // === Account Detail View Actions ===
             AppCommand::CycleAccountDetailTab => {
                 self.nav.cycle_account_detail_tab();
+                self.ensure_account_tab_loaded();
+            }
+            AppCommand::CycleAccountHistoryFilter => {
+                if self.nav.account_detail_tab == AccountDetailTab::History {
+                    self.cycle_account_history_filter();
+                }
+            }
+            AppCommand::CycleAccountListSort => {
+                self.cycle_account_list_sort();
+            }
+            AppCommand::ToggleHideZeroBalances => {
+                if self.nav.account_detail_tab == AccountDetailTab::Assets
+                    && let Some(list) = &mut self.data.account_assets
+                {
+                    list.hide_zero = !list.hide_zero;
+                    self.nav.account_item_index = None;
+                    self.nav.account_item_scroll = 0;
+                    self.load_more_account_list_if_needed();
+                }
             }
             AppCommand::MoveAccountItemUp => {
                 self.nav.move_account_item_up();
+                if self.nav.account_detail_tab == AccountDetailTab::Apps {
+                    self.load_selected_app_local_state();
+                }
             }
             AppCommand::MoveAccountItemDown => {
-                if let Some(account) = &self.data.viewed_account {
-                    let max = match self.nav.account_detail_tab {
-                        AccountDetailTab::Assets => account.assets.len().saturating_sub(1),
-                        AccountDetailTab::Apps => account.apps_local_state.len().saturating_sub(1),
-                        AccountDetailTab::Info => 0,
+                if self.data.viewed_account.is_some() {
+                    let tab = self.nav.account_detail_tab;
+                    let max = self.data.account_list_len(tab).saturating_sub(1);
+                    let visible_height = if tab == AccountDetailTab::History {
+                        // History rows are TXN_HEIGHT lines tall (~6 fit in the popup)
+                        6
+                    } else {
+                        // Reasonable default visible height (~8 items in the list area)
+                        8
                     };
-                    // Reasonable default visible height (~8 items in the list area)
-                    self.nav.move_account_item_down(max, 8);
+                    self.nav.move_account_item_down(max, visible_height);
+                }
+                match self.nav.account_detail_tab {
+                    AccountDetailTab::History => self.load_more_account_history_if_needed(),
+                    AccountDetailTab::Apps => {
+                        self.load_selected_app_local_state();
+                        self.load_more_account_list_if_needed();
+                    }
+                    AccountDetailTab::Assets
+                    | AccountDetailTab::CreatedAssets
+                    | AccountDetailTab::CreatedApps => self.load_more_account_list_if_needed(),
+                    AccountDetailTab::Info => {}
                 }
             }
             AppCommand::SelectAccountItem => {
-                self.handle_select_account_item();
+                self.handle_select_account_item().await;
             }
 
             // === Application Detail View Actions ===
             AppCommand::CycleAppDetailTab => {
                 self.nav.cycle_app_detail_tab();
+                if self.nav.app_detail_tab == AppDetailTab::Boxes {
+                    self.ensure_app_boxes_loaded();
+                }
             }
             AppCommand::MoveAppStateUp => {
-                self.nav.move_app_state_up();
+                if self.nav.app_detail_tab == AppDetailTab::Programs {
+                    self.nav.scroll_app_program_up();
+                } else {
+                    self.nav.move_app_state_up();
+                }
+                if self.nav.app_detail_tab == AppDetailTab::Boxes {
+                    self.load_selected_box_value();
+                }
             }
             AppCommand::MoveAppStateDown => {
                 if let Some(app) = &self.data.viewed_application {
-                    let max = match self.nav.app_detail_tab {
-                        AppDetailTab::State => app.global_state.len().saturating_sub(1),
-                        AppDetailTab::Info | AppDetailTab::Programs => 0,
-                    };
-                    // Reasonable default visible height (~8 items in the list area)
-                    self.nav.move_app_state_down(max, 8);
+                    match self.nav.app_detail_tab {
+                        AppDetailTab::Programs => {
+                            let line_count =
+                                ui::panels::details::application::program_listing_len(app);
+                            let max_scroll =
+                                u16::try_from(line_count.saturating_sub(1)).unwrap_or(u16::MAX);
+                            self.nav.scroll_app_program_down(max_scroll);
+                        }
+                        AppDetailTab::State => {
+                            let max = app.global_state.len().saturating_sub(1);
+                            // Reasonable default visible height (~8 items in the list area)
+                            self.nav.move_app_state_down(max, 8);
+                        }
+                        AppDetailTab::Boxes => {
+                            let max = self
+                                .data
+                                .app_boxes
+                                .as_ref()
+                                .map_or(0, |b| b.boxes.len().saturating_sub(1));
+                            self.nav.move_app_state_down(max, 8);
+                        }
+                        AppDetailTab::Info => {}
+                    }
+                }
+                if self.nav.app_detail_tab == AppDetailTab::Boxes {
+                    self.load_selected_box_value();
+                    self.load_more_app_boxes_if_needed();
+                }
+            }
+            AppCommand::LookupLocalState => {
+                if self.data.viewed_application.is_some() {
+                    self.ui.open_local_state_lookup();
+                }
+            }
+            AppCommand::SubmitLocalStateLookup => {
+                self.handle_submit_local_state_lookup();
+            }
+
+            // === Asset Detail View Actions ===
+            AppCommand::CycleAssetDetailTab => {
+                self.nav.cycle_asset_detail_tab();
+                if self.nav.asset_detail_tab == AssetDetailTab::Holders {
+                    self.ensure_asset_holders_loaded();
+                }
+            }
+            AppCommand::MoveAssetHolderUp => {
+                self.nav.move_asset_holder_up();
+            }
+            AppCommand::MoveAssetHolderDown => {
+                if self.nav.asset_detail_tab == AssetDetailTab::Holders
+                    && let Some(holders) = &self.data.asset_holders
+                    && !holders.holders.is_empty()
+                {
+                    let max = holders.holders.len() - 1;
+                    // Reasonable default visible height (~10 rows in the list area)
+                    self.nav.move_asset_holder_down(max, 10);
+                    self.load_more_asset_holders_if_needed();
+                }
+            }
+            AppCommand::SelectAssetHolder => {
+                self.handle_select_asset_holder();
+            }
+            AppCommand::CycleAssetHolderFilter => {
+                if self.nav.asset_detail_tab == AssetDetailTab::Holders {
+                    self.cycle_asset_holder_filter();
+                }
+            }
+
+            // === Group Detail View Actions ===
+            AppCommand::CycleGroupDetailTab => {
+                self.nav.cycle_group_detail_tab();
+            }
+            AppCommand::MoveGroupTxnUp => {
+                if self.nav.group_detail_tab == GroupDetailTab::Graph {
+                    self.nav.graph_scroll_y = self.nav.graph_scroll_y.saturating_sub(1);
+                } else {
+                    self.nav.move_group_txn_up();
+                }
+            }
+            AppCommand::MoveGroupTxnDown => {
+                if self.nav.group_detail_tab == GroupDetailTab::Graph {
+                    self.nav.graph_scroll_y = self.nav.graph_scroll_y.saturating_add(1);
+                } else if let Some(group) = &self.data.viewed_group {
+                    let max = group.transactions.len().saturating_sub(1);
+                    // Reasonable default visible height (~10 rows in the list area)
+                    self.nav.move_group_txn_down(max, 10);
+                }
+            }
+            AppCommand::SelectGroupTxn => {
+                self.handle_select_group_txn();
+            }
+
+            // === Transaction Feed Actions ===
+            AppCommand::OpenTxnFilter => {
+                let query = self
+                    .data
+                    .txn_filter
+                    .as_ref()
+                    .map(ToString::to_string)
+                    .unwrap_or_default();
+                self.ui.open_txn_filter(query);
+            }
+            AppCommand::SubmitTxnFilter => {
+                self.handle_submit_txn_filter();
+            }
+
+            // === Advanced Search Actions ===
+            AppCommand::OpenAdvancedSearch => {
+                if !self.indexer_unavailable("Advanced search") {
+                    let form = self
+                        .data
+                        .txn_search
+                        .as_ref()
+                        .map(|search| AdvancedSearchForm::from_query(&search.query))
+                        .unwrap_or_default();
+                    self.ui.open_advanced_search(form);
+                }
+            }
+            AppCommand::SubmitAdvancedSearch => {
+                self.handle_submit_advanced_search();
+            }
+            AppCommand::AdvancedSearchNextField => {
+                self.ui.advanced_search_next_field();
+            }
+            AppCommand::AdvancedSearchPrevField => {
+                self.ui.advanced_search_prev_field();
+            }
+            AppCommand::MoveSearchTxnUp => {
+                self.nav.move_txn_search_up();
+            }
+            AppCommand::MoveSearchTxnDown => {
+                if let Some(search) = &self.data.txn_search {
+                    let max = search.transactions.len().saturating_sub(1);
+                    // Result rows are TXN_HEIGHT lines tall (~7 fit in the popup)
+                    self.nav.move_txn_search_down(max, 7);
+                }
+                self.load_more_txn_search_if_needed();
+            }
+            AppCommand::SelectSearchTxn => {
+                self.handle_select_search_txn();
+            }
+
+            // === Pending Pool Actions ===
+            AppCommand::OpenPendingPool => {
+                self.handle_open_pending_pool();
+            }
+            AppCommand::MovePendingTxnUp => {
+                self.nav.move_pending_pool_up();
+            }
+            AppCommand::MovePendingTxnDown => {
+                if let Some(pool) = &self.data.pending_pool {
+                    let max = pool.len().saturating_sub(1);
+                    // Pool rows are 3 lines tall (~10 fit in the popup)
+                    self.nav.move_pending_pool_down(max, 10);
+                }
+            }
+            AppCommand::SelectPendingTxn => {
+                self.handle_select_pending_txn().await;
+            }
+
+            // === Simulation Actions ===
+            AppCommand::OpenSimulate => {
+                self.open_simulate();
+            }
+            AppCommand::SubmitSimulate => {
+                self.handle_submit_simulate();
+            }
+            AppCommand::CycleSimulationTab => {
+                self.nav.cycle_simulation_tab();
+            }
+            AppCommand::MoveSimulatedTxnUp => {
+                if self.nav.simulation_tab == SimulationTab::Graph {
+                    self.nav.graph_scroll_y = self.nav.graph_scroll_y.saturating_sub(1);
+                } else {
+                    self.nav.move_simulation_txn_up();
                 }
             }
+            AppCommand::MoveSimulatedTxnDown => {
+                if self.nav.simulation_tab == SimulationTab::Graph {
+                    self.nav.graph_scroll_y = self.nav.graph_scroll_y.saturating_add(1);
+                } else if let Some(simulation) = &self.data.simulation {
+                    let max = simulation.transactions.len().saturating_sub(1);
+                    // Transaction rows are 2 lines tall (~8 fit below the summary)
+                    self.nav.move_simulation_txn_down(max, 8);
+                }
+            }
+            AppCommand::SelectSimulatedTxn => {
+                self.handle_select_simulated_txn();
+            }
+
+            // === Export Actions ===
+            AppCommand::OpenExport => {
+                self.open_export();
+            }
+            AppCommand::SubmitExport => {
+                self.handle_submit_export();
+            }
+            AppCommand::ToggleExportFormat => {
+                self.ui.export_toggle_format();
+            }
+
+            // === Watchlist Actions ===
+            AppCommand::ToggleWatch => {
+                self.handle_toggle_watch();
+            }
 
             // === Search Input Actions ===
             AppCommand::TypeChar(c) => {
                 if matches!(self.ui.popup_state, PopupState::NetworkForm(_)) {
                     self.ui.network_form_type_char(c);
+                } else if matches!(self.ui.popup_state, PopupState::LocalStateLookup(_)) {
+                    self.ui.local_state_lookup_type_char(c);
+                } else if matches!(self.ui.popup_state, PopupState::TxnFilter(_)) {
+                    self.ui.txn_filter_type_char(c);
+                } else if matches!(self.ui.popup_state, PopupState::AdvancedSearch(_)) {
+                    self.ui.advanced_search_type_char(c);
+                } else if matches!(self.ui.popup_state, PopupState::Export(_)) {
+                    self.ui.export_type_char(c);
+                } else if matches!(self.ui.popup_state, PopupState::Simulate(_)) {
+                    self.ui.simulate_type_char(c);
                 } else if self.ui.is_search_focused() {
                     self.ui.search_type_char(c);
                 } else if let PopupState::SearchWithType(query, search_type) = &self.ui.popup_state
@@ -322,6 +603,16 @@ impl App {
             AppCommand::Backspace => {
                 if matches!(self.ui.popup_state, PopupState::NetworkForm(_)) {
                     self.ui.network_form_backspace();
+                } else if matches!(self.ui.popup_state, PopupState::LocalStateLookup(_)) {
+                    self.ui.local_state_lookup_backspace();
+                } else if matches!(self.ui.popup_state, PopupState::TxnFilter(_)) {
+                    self.ui.txn_filter_backspace();
+                } else if matches!(self.ui.popup_state, PopupState::AdvancedSearch(_)) {
+                    self.ui.advanced_search_backspace();
+                } else if matches!(self.ui.popup_state, PopupState::Export(_)) {
+                    self.ui.export_backspace();
+                } else if matches!(self.ui.popup_state, PopupState::Simulate(_)) {
+                    self.ui.simulate_backspace();
                 } else if self.ui.is_search_focused() {
                     self.ui.search_backspace();
                 } else if let PopupState::SearchWithType(query, search_type) = &self.ui.popup_state
@@ -517,22 +808,94 @@ impl App {
             return;
         }
 
+        // Prompts opened from a detail view close without closing the view
+        if matches!(
+            self.ui.popup_state,
+            PopupState::LocalStateLookup(_)
+                | PopupState::AdvancedSearch(_)
+                | PopupState::Export(_)
+                | PopupState::Simulate(_)
+        ) {
+            self.ui.dismiss_popup();
+            return;
+        }
+
         if self.nav.is_showing_details() {
             // Check if we have a saved popup state in the stack (nested navigation)
             if self.nav.has_popup_stack() {
+                if self.nav.show_pending_pool {
+                    self.close_pending_pool();
+                }
                 // Close the current detail popup
                 self.nav.close_details();
+                self.data.viewed_transaction = None;
                 self.data.viewed_asset = None;
                 self.data.viewed_application = None;
+                self.data.app_boxes = None;
 
                 // Pop and restore the parent popup state
-                if let Some(saved) = self.nav.pop_popup_state()
-                    && saved.popup_type == DetailPopupType::Account
-                {
-                    // Reload account details (we still have the address)
-                    self.load_account_details(&saved.entity_id);
-                    // Restore navigation state
-                    self.nav.restore_account_state(&saved);
+                match self.nav.pop_popup_state() {
+                    Some(saved) if saved.popup_type == DetailPopupType::Account => {
+                        self.data.asset_holders = None;
+                        self.nav.reset_asset_detail();
+                        // Reload account details (we still have the address)
+                        self.load_account_details(&saved.entity_id);
+                        // Restore navigation state
+                        self.nav.restore_account_state(&saved);
+                    }
+                    Some(saved) if saved.popup_type == DetailPopupType::Asset => {
+                        // The child was a holder's account
+                        self.data.viewed_account = None;
+                        self.data.clear_account_lists();
+                        self.data.local_state = None;
+                        self.nav.reset_account_detail();
+                        if let Ok(asset_id) = saved.entity_id.parse() {
+                            self.load_asset_details(asset_id);
+                        }
+                        self.nav.restore_asset_state(&saved);
+                    }
+                    Some(saved) if saved.popup_type == DetailPopupType::TxnSearch => {
+                        // The child was a search result; the results are still loaded
+                        self.nav.detail_row_index = None;
+                        self.nav.detail_row_scroll = 0;
+                        self.nav.restore_txn_search_state(&saved);
+                    }
+                    Some(saved) if saved.popup_type == DetailPopupType::PendingPool => {
+                        // The child was a pool transaction; the pool is still polled
+                        self.nav.detail_row_index = None;
+                        self.nav.detail_row_scroll = 0;
+                        self.nav.restore_pending_pool_state(&saved);
+                    }
+                    Some(saved) if saved.popup_type == DetailPopupType::Simulation => {
+                        // The child was a simulated transaction; the result is still loaded
+                        self.nav.detail_row_index = None;
+                        self.nav.detail_row_scroll = 0;
+                        self.nav.restore_simulation_state(&saved);
+                    }
+                    Some(saved) if saved.popup_type == DetailPopupType::Group => {
+                        // The child was a member transaction; the group is still loaded
+                        self.nav.restore_group_state(&saved);
+                    }
+                    Some(saved) if saved.popup_type == DetailPopupType::Transaction => {
+                        // The child was the transaction's group, which holds the transaction
+                        let txn = self.data.viewed_group.take().and_then(|group| {
+                            group
+                                .transactions
+                                .into_iter()
+                                .find(|txn| txn.id == saved.entity_id)
+                        });
+                        self.nav.reset_group_detail();
+                        self.nav.graph_scroll_x = 0;
+                        self.nav.graph_scroll_y = 0;
+                        self.nav.detail_row_index = saved.item_index;
+                        self.nav.detail_row_scroll = saved.item_scroll;
+                        if txn.is_some() {
+                            self.data.viewed_transaction = txn;
+                        }
+                        self.nav.show_transaction_details = true;
+                        self.update_detail_table_rows();
+                    }
+                    _ => {}
                 }
                 return;
             }
@@ -543,8 +906,16 @@ impl App {
             self.ui.reset_expanded_sections();
             self.data.viewed_transaction = None;
             self.data.viewed_account = None;
+            self.data.clear_account_lists();
             self.data.viewed_asset = None;
+            self.data.asset_holders = None;
             self.data.viewed_application = None;
+            self.data.app_boxes = None;
+            self.data.local_state = None;
+            self.data.viewed_group = None;
+            self.data.txn_search = None;
+            self.data.simulation = None;
+            self.close_pending_pool();
             // Reset graph scroll position
             self.nav.graph_scroll_x = 0;
             self.nav.graph_scroll_y = 0;
@@ -552,6 +923,14 @@ impl App {
             self.nav.reset_account_detail();
             // Reset app detail view state
             self.nav.reset_app_detail();
+            // Reset asset detail view state
+            self.nav.reset_asset_detail();
+            // Reset group detail view state
+            self.nav.reset_group_detail();
+            // Reset search results view state
+            self.nav.reset_txn_search();
+            self.nav.reset_pending_pool();
+            self.nav.reset_simulation();
         } else {
             match &self.ui.popup_state {
                 PopupState::SearchWithType(_, _) | PopupState::SearchResults(_) => {
@@ -564,7 +943,14 @@ impl App {
                     let return_index = form.return_to_index.min(max_index);
                     self.ui.popup_state = PopupState::NetworkSelect(return_index);
                 }
-                PopupState::NetworkSelect(_) | PopupState::Message(_) | PopupState::ConfirmQuit => {
+                PopupState::NetworkSelect(_)
+                | PopupState::Message(_)
+                | PopupState::ConfirmQuit
+                | PopupState::LocalStateLookup(_)
+                | PopupState::TxnFilter(_)
+                | PopupState::AdvancedSearch(_)
+                | PopupState::Export(_)
+                | PopupState::Simulate(_) => {
                     self.ui.dismiss_popup();
                 }
                 PopupState::None => {}
@@ -631,7 +1017,7 @@ impl App {
     /// When pressing Enter in the Assets or Apps tab of account details,
     /// this opens the selected asset or application details popup while
     /// saving the account popup state for stack-based navigation.
-    pub(crate) fn handle_select_account_item(&mut self) {
+    pub(crate) async fn handle_select_account_item(&mut self) {
         let Some(account) = &self.data.viewed_account else {
             return;
         };
@@ -641,11 +1027,22 @@ impl App {
         };
 
         match self.nav.account_detail_tab {
-            AccountDetailTab::Assets => {
+            AccountDetailTab::Assets | AccountDetailTab::CreatedAssets => {
                 // Get the selected asset
-                if let Some(asset_holding) = account.assets.get(item_index) {
-                    let asset_id = asset_holding.asset_id;
-
+                let asset_id = if self.nav.account_detail_tab == AccountDetailTab::Assets {
+                    self.data
+                        .account_assets
+                        .as_ref()
+                        .and_then(|l| l.visible_item(item_index))
+                        .map(|holding| holding.asset_id)
+                } else {
+                    self.data
+                        .account_created_assets
+                        .as_ref()
+                        .and_then(|l| l.visible_item(item_index))
+                        .map(|asset| asset.asset_id)
+                };
+                if let Some(asset_id) = asset_id {
                     // Save current account popup state to stack
                     self.nav.push_account_state(&account.address);
 
@@ -654,11 +1051,22 @@ impl App {
                     self.load_asset_details(asset_id);
                 }
             }
-            AccountDetailTab::Apps => {
+            AccountDetailTab::Apps | AccountDetailTab::CreatedApps => {
                 // Get the selected app
-                if let Some(app_state) = account.apps_local_state.get(item_index) {
-                    let app_id = app_state.app_id;
-
+                let app_id = if self.nav.account_detail_tab == AccountDetailTab::Apps {
+                    self.data
+                        .account_apps
+                        .as_ref()
+                        .and_then(|l| l.visible_item(item_index))
+                        .map(|app_state| app_state.app_id)
+                } else {
+                    self.data
+                        .account_created_apps
+                        .as_ref()
+                        .and_then(|l| l.visible_item(item_index))
+                        .map(|app_info| app_info.app_id)
+                };
+                if let Some(app_id) = app_id {
                     // Save current account popup state to stack
                     self.nav.push_account_state(&account.address);
 
@@ -667,12 +1075,760 @@ impl App {
                     self.load_application_details(app_id);
                 }
             }
+            AccountDetailTab::History => {
+                let txn_id = self
+                    .data
+                    .account_history
+                    .as_ref()
+                    .and_then(|h| h.transactions.get(item_index))
+                    .map(|txn| txn.id.clone());
+                if let Some(txn_id) = txn_id {
+                    // Save current account popup state to stack
+                    self.nav.push_account_state(&account.address);
+
+                    // Close account details and open transaction details
+                    self.nav.show_account_details = false;
+                    self.load_transaction_details(&txn_id).await;
+                }
+            }
             AccountDetailTab::Info => {
                 // Nothing to select in Info tab
             }
         }
     }
 
+    /// Starts loading the list shown in the current account tab unless it is
+    /// already loaded for the viewed account.
+    pub(crate) fn ensure_account_tab_loaded(&mut self) {
+        let Some(address) = self.data.viewed_account.as_ref().map(|a| a.address.clone()) else {
+            return;
+        };
+        let tab = self.nav.account_detail_tab;
+        let needs_load = match tab {
+            AccountDetailTab::History => {
+                self.ensure_account_history_loaded();
+                false
+            }
+            AccountDetailTab::Assets => {
+                AccountList::reset_if_stale(&mut self.data.account_assets, &address)
+            }
+            AccountDetailTab::CreatedAssets => {
+                AccountList::reset_if_stale(&mut self.data.account_created_assets, &address)
+            }
+            AccountDetailTab::Apps => {
+                AccountList::reset_if_stale(&mut self.data.account_apps, &address)
+            }
+            AccountDetailTab::CreatedApps => {
+                AccountList::reset_if_stale(&mut self.data.account_created_apps, &address)
+            }
+            AccountDetailTab::Info => false,
+        };
+        if needs_load {
+            self.fetch_account_list_page(tab, address, None);
+        }
+    }
+
+    /// Fetches the next page of the current account list when the selection
+    /// nears the end.
+    pub(crate) fn load_more_account_list_if_needed(&mut self) {
+        let selected = self.nav.account_item_index.unwrap_or(0);
+        let tab = self.nav.account_detail_tab;
+        let request = match tab {
+            AccountDetailTab::Assets => {
+                AccountList::next_page_request(&mut self.data.account_assets, selected)
+            }
+            AccountDetailTab::CreatedAssets => {
+                AccountList::next_page_request(&mut self.data.account_created_assets, selected)
+            }
+            AccountDetailTab::Apps => {
+                AccountList::next_page_request(&mut self.data.account_apps, selected)
+            }
+            AccountDetailTab::CreatedApps => {
+                AccountList::next_page_request(&mut self.data.account_created_apps, selected)
+            }
+            AccountDetailTab::Info | AccountDetailTab::History => None,
+        };
+        if let Some((address, next_token)) = request {
+            self.fetch_account_list_page(tab, address, Some(next_token));
+        }
+    }
+
+    /// Switches the current account list to its next sort column.
+    pub(crate) fn cycle_account_list_sort(&mut self) {
+        match self.nav.account_detail_tab {
+            AccountDetailTab::Assets => {
+                self.data
+                    .account_assets
+                    .as_mut()
+                    .map(AccountList::cycle_sort);
+            }
+            AccountDetailTab::CreatedAssets => {
+                self.data
+                    .account_created_assets
+                    .as_mut()
+                    .map(AccountList::cycle_sort);
+            }
+            AccountDetailTab::Apps => {
+                self.data.account_apps.as_mut().map(AccountList::cycle_sort);
+            }
+            AccountDetailTab::CreatedApps => {
+                self.data
+                    .account_created_apps
+                    .as_mut()
+                    .map(AccountList::cycle_sort);
+            }
+            AccountDetailTab::Info | AccountDetailTab::History => return,
+        }
+        self.nav.account_item_index = None;
+        self.nav.account_item_scroll = 0;
+    }
+
+    /// Spawns a fetch for one page of an account asset or app list.
+    fn fetch_account_list_page(
+        &self,
+        tab: AccountDetailTab,
+        address: String,
+        next_token: Option<String>,
+    ) {
+        let message_tx = self.message_tx.clone();
+        let client = self.client.clone();
+
+        tokio::spawn(async move {
+            let token = next_token.as_deref();
+            let result = match tab {
+                AccountDetailTab::Assets => client
+                    .get_account_assets_page(&address, token)
+                    .await
+                    .map(AccountListPage::Assets),
+                AccountDetailTab::CreatedAssets => client
+                    .get_account_created_assets_page(&address, token)
+                    .await
+                    .map(AccountListPage::CreatedAssets),
+                AccountDetailTab::Apps => client
+                    .get_account_apps_page(&address, token)
+                    .await
+                    .map(AccountListPage::Apps),
+                AccountDetailTab::CreatedApps => client
+                    .get_account_created_apps_page(&address, token)
+                    .await
+                    .map(AccountListPage::CreatedApps),
+                AccountDetailTab::Info | AccountDetailTab::History => return,
+            };
+            // Channel sends below: receiver may be dropped during shutdown - safe to ignore
+            match result {
+                Ok(page) => {
+                    let _ = message_tx.send(AppMessage::AccountListLoaded { address, page });
+                }
+                Err(e) => {
+                    let _ = message_tx.send(AppMessage::AccountListFailed {
+                        tab,
+                        error: e.to_string(),
+                    });
+                }
+            }
+        });
+    }
+
+    /// Starts loading the viewed account's history unless it is already loaded.
+    pub(crate) fn ensure_account_history_loaded(&mut self) {
+        let Some(account) = &self.data.viewed_account else {
+            return;
+        };
+        let filter = self.data.account_history.as_ref().and_then(|h| h.filter);
+        let is_loaded = self
+            .data
+            .account_history
+            .as_ref()
+            .is_some_and(|h| h.address == account.address);
+        if !is_loaded {
+            let address = account.address.clone();
+            self.reload_account_history(&address, filter);
+        }
+    }
+
+    /// Switches the history tab to the next transaction type filter.
+    pub(crate) fn cycle_account_history_filter(&mut self) {
+        let Some(account) = &self.data.viewed_account else {
+            return;
+        };
+        let address = account.address.clone();
+        let filter =
+            TxnType::next_filter(self.data.account_history.as_ref().and_then(|h| h.filter));
+        self.nav.account_item_index = None;
+        self.nav.account_item_scroll = 0;
+        self.reload_account_history(&address, filter);
+    }
+
+    /// Fetches the next history page when the selection nears the end.
+    pub(crate) fn load_more_account_history_if_needed(&mut self) {
+        let Some(history) = &mut self.data.account_history else {
+            return;
+        };
+        let selected = self.nav.account_item_index.unwrap_or(0);
+        if history.loading || selected + LOAD_MORE_THRESHOLD < history.transactions.len() {
+            return;
+        }
+        let Some(next_token) = history.next_token.clone() else {
+            return;
+        };
+        history.loading = true;
+        let address = history.address.clone();
+        let filter = history.filter;
+        self.fetch_account_history_page(address, filter, Some(next_token));
+    }
+
+    /// Replaces the account history with a fresh list and requests its first page.
+    fn reload_account_history(&mut self, address: &str, filter: Option<TxnType>) {
+        self.data.account_history = Some(AccountHistory::new(address, filter));
+        self.fetch_account_history_page(address.to_string(), filter, None);
+    }
+
+    /// Spawns a fetch for one page of account history.
+    fn fetch_account_history_page(
+        &self,
+        address: String,
+        filter: Option<TxnType>,
+        next_token: Option<String>,
+    ) {
+        let message_tx = self.message_tx.clone();
+        let client = self.client.clone();
+
+        tokio::spawn(async move {
+            // Channel sends below: receiver may be dropped during shutdown - safe to ignore
+            match client
+                .get_account_transactions_page(&address, filter, next_token.as_deref())
+                .await
+            {
+                Ok(page) => {
+                    let _ = message_tx.send(AppMessage::AccountHistoryLoaded {
+                        address,
+                        filter,
+                        page,
+                    });
+                }
+                Err(e) => {
+                    let _ = message_tx.send(AppMessage::AccountHistoryFailed(e.to_string()));
+                }
+            }
+        });
+    }
+
+    /// Loads the viewed account's local state for the selected opted-in app.
+    pub(crate) fn load_selected_app_local_state(&mut self) {
+        let Some(account) = &self.data.viewed_account else {
+            return;
+        };
+        let Some(app_id) = self
+            .nav
+            .account_item_index
+            .and_then(|idx| self.data.account_apps.as_ref()?.visible_item(idx))
+            .map(|app_state| app_state.app_id)
+        else {
+            return;
+        };
+        let address = account.address.clone();
+        self.load_local_state(&address, app_id);
+    }
+
+    /// Submits the local state lookup prompt for the viewed application.
+    pub(crate) fn handle_submit_local_state_lookup(&mut self) {
+        let PopupState::LocalStateLookup(address) = &self.ui.popup_state else {
+            return;
+        };
+        let address = address.trim().to_string();
+        let Some(app_id) = self.data.viewed_application.as_ref().map(|a| a.app_id) else {
+            self.ui.dismiss_popup();
+            return;
+        };
+        if !crate::domain::account::is_valid_address(&address) {
+            self.ui
+                .show_toast("Enter a valid 58-character Algorand address", 30);
+            return;
+        }
+        self.ui.dismiss_popup();
+        self.nav.app_detail_tab = AppDetailTab::State;
+        self.load_local_state(&address, app_id);
+    }
+
+    /// Submits the transaction feed filter prompt.
+    ///
+    /// Empty input clears the filter; invalid queries keep the prompt open.
+    pub(crate) fn handle_submit_txn_filter(&mut self) {
+        let PopupState::TxnFilter(query) = &self.ui.popup_state else {
+            return;
+        };
+        let filter = if query.trim().is_empty() {
+            None
+        } else {
+            match TxnFilter::parse(query) {
+                Ok(filter) => Some(filter),
+                Err(e) => {
+                    self.ui.show_toast(e.to_string(), 30);
+                    return;
+                }
+            }
+        };
+        self.ui.dismiss_popup();
+        self.set_txn_filter(filter);
+    }
+
+    /// Submits the advanced search form and opens the result list.
+    ///
+    /// Invalid input keeps the form open.
+    pub(crate) fn handle_submit_advanced_search(&mut self) {
+        let PopupState::AdvancedSearch(form) = &self.ui.popup_state else {
+            return;
+        };
+        let query = match form.to_query() {
+            Ok(query) => query,
+            Err(e) => {
+                self.ui.show_toast(e.to_string(), 30);
+                return;
+            }
+        };
+        self.ui.dismiss_popup();
+        self.nav.reset_txn_search();
+        self.nav.show_txn_search = true;
+        self.data.txn_search = Some(TxnSearch::new(query.clone()));
+        self.fetch_txn_search_page(query, None);
+    }
+
+    /// Fetches the next search results page when the selection nears the end.
+    pub(crate) fn load_more_txn_search_if_needed(&mut self) {
+        let Some(search) = &mut self.data.txn_search else {
+            return;
+        };
+        let selected = self.nav.txn_search_index.unwrap_or(0);
+        if search.loading || selected + LOAD_MORE_THRESHOLD < search.transactions.len() {
+            return;
+        }
+        let Some(next_token) = search.next_token.clone() else {
+            return;
+        };
+        search.loading = true;
+        let query = search.query.clone();
+        self.fetch_txn_search_page(query, Some(next_token));
+    }
+
+    /// Spawns a fetch for one page of advanced search results.
+    fn fetch_txn_search_page(&self, query: TxnQuery, next_token: Option<String>) {
+        let message_tx = self.message_tx.clone();
+        let client = self.client.clone();
+
+        tokio::spawn(async move {
+            // Channel sends below: receiver may be dropped during shutdown - safe to ignore
+            match client
+                .search_transactions_page(&query, next_token.as_deref())
+                .await
+            {
+                Ok(page) => {
+                    let _ = message_tx.send(AppMessage::TxnSearchLoaded { query, page });
+                }
+                Err(e) => {
+                    let _ = message_tx.send(AppMessage::TxnSearchFailed(e.to_string()));
+                }
+            }
+        });
+    }
+
+    /// Opens the selected search result.
+    ///
+    /// The result list state is saved to the stack so Esc returns to the
+    /// same result.
+    pub(crate) fn handle_select_search_txn(&mut self) {
+        let Some(txn) = self
+            .nav
+            .txn_search_index
+            .and_then(|idx| self.data.txn_search.as_ref()?.transactions.get(idx))
+            .cloned()
+        else {
+            return;
+        };
+
+        self.nav.push_txn_search_state();
+        self.nav.show_txn_search = false;
+        self.nav.detail_row_index = None;
+        self.nav.detail_row_scroll = 0;
+        self.data.viewed_transaction = Some(txn);
+        self.nav.show_transaction_details = true;
+        self.update_detail_table_rows();
+    }
+
+    /// Opens the pending transaction pool.
+    ///
+    /// From account details only the account's own pool transactions are
+    /// shown, and Esc returns to the account.
+    pub(crate) fn handle_open_pending_pool(&mut self) {
+        let scope = if self.nav.show_account_details {
+            let Some(address) = self.data.viewed_account.as_ref().map(|a| a.address.clone()) else {
+                return;
+            };
+            self.nav.push_account_state(&address);
+            self.nav.show_account_details = false;
+            PoolScope::Account(address)
+        } else if self.nav.is_showing_details() {
+            return;
+        } else {
+            PoolScope::All
+        };
+
+        self.nav.reset_pending_pool();
+        self.nav.show_pending_pool = true;
+        self.data.pending_pool = Some(PendingPool::new(scope.clone()));
+        // Watch channel: background task polls the pool while it is open
+        self.pending_pool_tx.send_replace(Some(scope));
+    }
+
+    /// Stops polling and drops the pending pool.
+    pub(crate) fn close_pending_pool(&mut self) {
+        if self.data.pending_pool.take().is_some() {
+            self.pending_pool_tx.send_replace(None);
+        }
+    }
+
+    /// Opens the selected pool transaction.
+    ///
+    /// Pending transactions open straight away from their decoded algod copy.
+    /// Confirmed ones are fetched from the indexer, and the pool stays open
+    /// until it has them.
+    pub(crate) async fn handle_select_pending_txn(&mut self) {
+        let Some((txn, confirmed)) = self
+            .nav
+            .pending_pool_index
+            .and_then(|idx| self.data.pending_pool.as_ref()?.get(idx))
+            .map(|(txn, confirmed)| (txn.clone(), confirmed))
+        else {
+            return;
+        };
+
+        if confirmed {
+            self.load_transaction_details(&txn.id).await;
+            return;
+        }
+
+        self.nav.push_pending_pool_state();
+        self.nav.show_pending_pool = false;
+        self.nav.detail_row_index = None;
+        self.nav.detail_row_scroll = 0;
+        self.data.viewed_transaction = Some(txn);
+        self.nav.show_transaction_details = true;
+        self.update_detail_table_rows();
+    }
+
+    /// Fetches an account's local state for an application unless already loaded.
+    fn load_local_state(&mut self, address: &str, app_id: u64) {
+        if self
+            .data
+            .local_state
+            .as_ref()
+            .is_some_and(|view| view.matches(address, app_id) && view.error.is_none())
+        {
+            return;
+        }
+        self.data.local_state = Some(LocalStateView::new(address, app_id));
+
+        let message_tx = self.message_tx.clone();
+        let client = self.client.clone();
+        let address = address.to_string();
+
+        tokio::spawn(async move {
+            // Channel sends below: receiver may be dropped during shutdown - safe to ignore
+            match client.get_account_app_local_state(&address, app_id).await {
+                Ok(entries) => {
+                    let _ = message_tx.send(AppMessage::LocalStateLoaded {
+                        address,
+                        app_id,
+                        entries,
+                    });
+                }
+                Err(e) => {
+                    let _ = message_tx.send(AppMessage::LocalStateFailed {
+                        address,
+                        app_id,
+                        error: e.to_string(),
+                    });
+                }
+            }
+        });
+    }
+
+    /// Starts loading the viewed application's boxes unless they are already loaded.
+    pub(crate) fn ensure_app_boxes_loaded(&mut self) {
+        let Some(application) = &self.data.viewed_application else {
+            return;
+        };
+        let app_id = application.app_id;
+        let is_loaded = self
+            .data
+            .app_boxes
+            .as_ref()
+            .is_some_and(|b| b.app_id == app_id);
+        if !is_loaded {
+            self.data.app_boxes = Some(AppBoxes::new(app_id));
+            self.fetch_app_boxes_page(app_id, None);
+        }
+    }
+
+    /// Fetches the next box page when the selection nears the end.
+    pub(crate) fn load_more_app_boxes_if_needed(&mut self) {
+        let Some(app_boxes) = &mut self.data.app_boxes else {
+            return;
+        };
+        let selected = self.nav.app_state_index.unwrap_or(0);
+        if app_boxes.loading || selected + LOAD_MORE_THRESHOLD < app_boxes.boxes.len() {
+            return;
+        }
+        let Some(next_token) = app_boxes.next_token.clone() else {
+            return;
+        };
+        app_boxes.loading = true;
+        let app_id = app_boxes.app_id;
+        self.fetch_app_boxes_page(app_id, Some(next_token));
+    }
+
+    /// Fetches the value of the selected box unless it is loaded or in flight.
+    pub(crate) fn load_selected_box_value(&mut self) {
+        let Some(app_boxes) = &mut self.data.app_boxes else {
+            return;
+        };
+        let Some(app_box) = self
+            .nav
+            .app_state_index
+            .and_then(|idx| app_boxes.boxes.get(idx))
+        else {
+            return;
+        };
+        if app_box.value.is_some() || app_boxes.value_loading.as_ref() == Some(&app_box.name) {
+            return;
+        }
+        let name = app_box.name.clone();
+        let app_id = app_boxes.app_id;
+        app_boxes.value_loading = Some(name.clone());
+
+        let message_tx = self.message_tx.clone();
+        let client = self.client.clone();
+
+        tokio::spawn(async move {
+            // Channel sends below: receiver may be dropped during shutdown - safe to ignore
+            match client.get_application_box_value(app_id, &name).await {
+                Ok(value) => {
+                    let _ = message_tx.send(AppMessage::AppBoxValueLoaded {
+                        app_id,
+                        name,
+                        value,
+                    });
+                }
+                Err(e) => {
+                    let _ = message_tx.send(AppMessage::AppBoxValueFailed(e.to_string()));
+                }
+            }
+        });
+    }
+
+    /// Spawns a fetch for one page of application box names.
+    fn fetch_app_boxes_page(&self, app_id: u64, next_token: Option<String>) {
+        let message_tx = self.message_tx.clone();
+        let client = self.client.clone();
+
+        tokio::spawn(async move {
+            // Channel sends below: receiver may be dropped during shutdown - safe to ignore
+            match client
+                .get_application_boxes_page(app_id, next_token.as_deref())
+                .await
+            {
+                Ok(page) => {
+                    let _ = message_tx.send(AppMessage::AppBoxesLoaded { app_id, page });
+                }
+                Err(e) => {
+                    let _ = message_tx.send(AppMessage::AppBoxesFailed(e.to_string()));
+                }
+            }
+        });
+    }
+
+    /// Opens the selected holder's account from the asset Holders tab.
+    ///
+    /// The asset popup state is saved to the stack so Esc returns to the
+    /// same holder.
+    pub(crate) fn handle_select_asset_holder(&mut self) {
+        if self.nav.asset_detail_tab != AssetDetailTab::Holders {
+            return;
+        }
+        let Some(asset_id) = self.data.viewed_asset.as_ref().map(|a| a.id) else {
+            return;
+        };
+        let Some(address) = self
+            .nav
+            .asset_holder_index
+            .and_then(|idx| self.data.asset_holders.as_ref()?.holders.get(idx))
+            .map(|holder| holder.address.clone())
+        else {
+            return;
+        };
+
+        // Save current asset popup state to stack
+        self.nav.push_asset_state(asset_id);
+
+        // Close asset details and open the holder's account
+        self.nav.show_asset_details = false;
+        self.nav.reset_account_detail();
+        self.load_account_details(&address);
+    }
+
+    /// Fetches every member of the viewed transaction's atomic group.
+    ///
+    /// The group view opens when the members arrive, provided the
+    /// transaction is still on screen.
+    pub(crate) fn view_transaction_group(&mut self) {
+        let Some(txn) = self.get_transaction_for_details() else {
+            return;
+        };
+        let Some(group_id) = txn.group else {
+            self.ui
+                .show_toast("Transaction is not part of a group".to_string(), 20);
+            return;
+        };
+        self.ui
+            .show_toast("Loading atomic group...".to_string(), 10);
+
+        let message_tx = self.message_tx.clone();
+        let client = self.client.clone();
+        let round = txn.block;
+        tokio::spawn(async move {
+            // Channel sends below: receiver may be dropped during shutdown - safe to ignore
+            match client.get_group_transactions(&group_id, round).await {
+                Ok(group) => {
+                    let _ = message_tx.send(AppMessage::GroupLoaded(group));
+                }
+                Err(e) => {
+                    let _ = message_tx.send(AppMessage::GroupFailed(e.to_string()));
+                }
+            }
+        });
+    }
+
+    /// Opens the selected member transaction from the group Members tab.
+    ///
+    /// The group popup state is saved to the stack so Esc returns to the
+    /// same member.
+    pub(crate) fn handle_select_group_txn(&mut self) {
+        if self.nav.group_detail_tab != GroupDetailTab::Members {
+            return;
+        }
+        let Some(group) = &self.data.viewed_group else {
+            return;
+        };
+        let Some(txn) = self
+            .nav
+            .group_txn_index
+            .and_then(|idx| group.transactions.get(idx))
+            .cloned()
+        else {
+            return;
+        };
+
+        // Save current group popup state to stack
+        self.nav.push_group_state(&group.id);
+
+        // Close group details and open the member transaction
+        self.nav.show_group_details = false;
+        self.nav.graph_scroll_x = 0;
+        self.nav.graph_scroll_y = 0;
+        self.nav.detail_row_index = None;
+        self.nav.detail_row_scroll = 0;
+        self.data.viewed_transaction = Some(txn);
+        self.nav.show_transaction_details = true;
+        self.update_detail_table_rows();
+    }
+
+    /// Starts loading the viewed asset's holders unless they are already loaded.
+    pub(crate) fn ensure_asset_holders_loaded(&mut self) {
+        let Some(asset) = &self.data.viewed_asset else {
+            return;
+        };
+        let is_loaded = self
+            .data
+            .asset_holders
+            .as_ref()
+            .is_some_and(|h| h.asset_id == asset.id);
+        if !is_loaded {
+            let (asset_id, decimals) = (asset.id, asset.decimals);
+            let min_units = self.data.asset_holders.as_ref().and_then(|h| h.min_units);
+            self.reload_asset_holders(asset_id, decimals, min_units);
+        }
+    }
+
+    /// Switches the Holders tab to the next minimum balance filter.
+    pub(crate) fn cycle_asset_holder_filter(&mut self) {
+        let Some(asset) = &self.data.viewed_asset else {
+            return;
+        };
+        let (asset_id, decimals) = (asset.id, asset.decimals);
+        let min_units =
+            AssetHolders::next_filter(self.data.asset_holders.as_ref().and_then(|h| h.min_units));
+        self.nav.asset_holder_index = None;
+        self.nav.asset_holder_scroll = 0;
+        self.reload_asset_holders(asset_id, decimals, min_units);
+    }
+
+    /// Fetches the next holder page when the selection nears the end.
+    pub(crate) fn load_more_asset_holders_if_needed(&mut self) {
+        let Some(decimals) = self.data.viewed_asset.as_ref().map(|a| a.decimals) else {
+            return;
+        };
+        let Some(holders) = &mut self.data.asset_holders else {
+            return;
+        };
+        let selected = self.nav.asset_holder_index.unwrap_or(0);
+        if holders.loading || selected + LOAD_MORE_THRESHOLD < holders.holders.len() {
+            return;
+        }
+        let Some(next_token) = holders.next_token.clone() else {
+            return;
+        };
+        holders.loading = true;
+        let (asset_id, min_units) = (holders.asset_id, holders.min_units);
+        self.fetch_asset_holders_page(asset_id, decimals, min_units, Some(next_token));
+    }
+
+    /// Replaces the holder listing with a fresh list and requests its first page.
+    fn reload_asset_holders(&mut self, asset_id: u64, decimals: u64, min_units: Option<u64>) {
+        self.data.asset_holders = Some(AssetHolders::new(asset_id, min_units));
+        self.fetch_asset_holders_page(asset_id, decimals, min_units, None);
+    }
+
+    /// Spawns a fetch for one page of asset holders.
+    fn fetch_asset_holders_page(
+        &self,
+        asset_id: u64,
+        decimals: u64,
+        min_units: Option<u64>,
+        next_token: Option<String>,
+    ) {
+        let message_tx = self.message_tx.clone();
+        let client = self.client.clone();
+        let min_balance = min_units.map(|units| AssetHolders::base_units(units, decimals));
+
+        tokio::spawn(async move {
+            // Channel sends below: receiver may be dropped during shutdown - safe to ignore
+            match client
+                .get_asset_holders_page(asset_id, min_balance, next_token.as_deref())
+                .await
+            {
+                Ok(page) => {
+                    let _ = message_tx.send(AppMessage::AssetHoldersLoaded {
+                        asset_id,
+                        min_units,
+                        page,
+                    });
+                }
+                Err(e) => {
+                    let _ = message_tx.send(AppMessage::AssetHoldersFailed(e.to_string()));
+                }
+            }
+        });
+    }
+
     /// Loads account details asynchronously
     pub(crate) fn load_account_details(&self, address: &str) {
         let message_tx = self.message_tx.clone();
@@ -769,6 +1925,9 @@ impl App {
             Focus::Transactions => {
                 self.move_transaction_selection_up();
             }
+            Focus::Watchlist => {
+                self.move_watch_selection_up();
+            }
         }
         Ok(())
     }
@@ -787,6 +1946,9 @@ impl App {
             Focus::Transactions => {
                 self.move_transaction_selection_down();
             }
+            Focus::Watchlist => {
+                self.move_watch_selection_down();
+            }
         }
         Ok(())
     }
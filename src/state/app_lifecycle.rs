@@ -8,21 +8,60 @@
 
 use color_eyre::Result;
 use crossterm::event::{self, Event, KeyEventKind};
+use std::collections::HashSet;
+use std::future::Future;
+use std::pin::Pin;
 use std::time::{Duration, Instant};
 use tokio::sync::mpsc;
 use tokio::time::interval;
 
 use crate::client::AlgoClient;
-use crate::domain::NetworkConfig;
+use crate::constants::{LIVE_CATCHUP_ROUNDS, LIVE_RETRY_DELAY, PENDING_POOL_POLL_INTERVAL};
+use crate::domain::{NetworkConfig, Transaction, TxnFilter, WatchTarget};
+use crate::keymap::Keymap;
 use crate::tui::Tui;
 use crate::ui;
 
-use super::{App, AppConfig, AppMessage, NavigationState, StartupOptions, StartupSearch};
+use super::{
+    App, AppConfig, AppMessage, NavigationState, PoolScope, StartupOptions, StartupSearch, UiState,
+};
 
 // ============================================================================
 // Lifecycle Methods
 // ============================================================================
 
+/// Builds the configured keymap.
+///
+/// Invalid keybindings are reported in a message popup and the defaults are
+/// used instead, so a typo in the config never locks the user out.
+fn load_keymap(config: &AppConfig, ui: &mut UiState) -> Keymap {
+    config.keymap().unwrap_or_else(|err| {
+        ui.show_message(err.to_string());
+        Keymap::default()
+    })
+}
+
+/// A pending wait for the next round, resolving to the latest round.
+type RoundWait = Pin<Box<dyn Future<Output = Result<u64>> + Send>>;
+
+/// Returns the range of rounds live updates should fetch once algod reports
+/// `latest`, or `None` if there is nothing new.
+///
+/// The first update fetches nothing: the initial fetch has already shown the
+/// latest round, which only becomes the starting point. The first update
+/// after the chain was reset fetches the latest round. Gaps are filled up to
+/// [`LIVE_CATCHUP_ROUNDS`] back, so every round is sent exactly once.
+pub(super) fn rounds_to_fetch(last_round: Option<u64>, latest: u64) -> Option<(u64, u64)> {
+    let last_round = last_round?;
+    let oldest = latest.saturating_sub(LIVE_CATCHUP_ROUNDS - 1).max(1);
+    let first = if last_round <= latest {
+        (last_round + 1).max(oldest)
+    } else {
+        latest
+    };
+    (first >= 1 && first <= latest).then_some((first, latest))
+}
+
 /// Prefetch initial data during boot screen.
 /// Sends results to the provided channel for later consumption.
 pub async fn prefetch_initial_data(
@@ -35,8 +74,8 @@ pub async fn prefetch_initial_data(
             let _ = message_tx.send(AppMessage::NetworkError(error_msg));
             return;
         }
-        Ok(()) => {
-            let _ = message_tx.send(AppMessage::NetworkConnected);
+        Ok(source) => {
+            let _ = message_tx.send(AppMessage::NetworkConnected(source));
         }
     }
 
@@ -84,6 +123,10 @@ impl App {
 
         let show_live = config.show_live;
         let client = AlgoClient::from_config(&network_config)?;
+        let watchlist = config.watchlist(&network_config).to_vec();
+        let (watchlist_tx, _watchlist_rx) = tokio::sync::watch::channel(watchlist.clone());
+        let (txn_filter_tx, _txn_filter_rx) = tokio::sync::watch::channel(None);
+        let (pending_pool_tx, _pending_pool_rx) = tokio::sync::watch::channel(None);
 
         // Cache available networks
         let available_networks = config.get_all_networks();
@@ -93,20 +136,30 @@ impl App {
         let _ = live_updates_tx.send(show_live);
         let _ = network_tx.send(network_config.clone());
 
+        let mut ui = UiState::new();
+        let keymap = load_keymap(&config, &mut ui);
+
+        let mut data = super::DataState::new();
+        data.set_watchlist(&watchlist);
+
         Ok(Self {
             nav: NavigationState::new(),
-            data: super::DataState::new(),
-            ui: super::UiState::new(),
+            data,
+            ui,
             network,
             network_config,
             available_networks,
             show_live,
             exit: false,
             animation_tick: 0,
+            keymap,
             message_tx,
             message_rx,
             live_updates_tx,
             network_tx,
+            watchlist_tx,
+            txn_filter_tx,
+            pending_pool_tx,
             client,
             startup_options: Some(startup_options),
         })
@@ -145,6 +198,10 @@ impl App {
 
         let show_live = config.show_live;
         let client = AlgoClient::from_config(&network_config)?;
+        let watchlist = config.watchlist(&network_config).to_vec();
+        let (watchlist_tx, _watchlist_rx) = tokio::sync::watch::channel(watchlist.clone());
+        let (txn_filter_tx, _txn_filter_rx) = tokio::sync::watch::channel(None);
+        let (pending_pool_tx, _pending_pool_rx) = tokio::sync::watch::channel(None);
 
         // Cache available networks
         let available_networks = config.get_all_networks();
@@ -154,8 +211,12 @@ impl App {
         let _ = live_updates_tx.send(show_live);
         let _ = network_tx.send(network_config.clone());
 
+        let mut ui = UiState::new();
+        let keymap = load_keymap(&config, &mut ui);
+
         // Create initial data state and drain prefetched messages
         let mut data = super::DataState::new();
+        data.set_watchlist(&watchlist);
         while let Ok(message) = message_rx.try_recv() {
             match message {
                 AppMessage::BlocksUpdated(blocks) => {
@@ -164,7 +225,11 @@ impl App {
                 AppMessage::TransactionsUpdated(transactions) => {
                     data.transactions = transactions;
                 }
-                AppMessage::NetworkError(_) | AppMessage::NetworkConnected => {
+                AppMessage::NetworkConnected(source) => {
+                    client.set_data_source(source);
+                    data.data_source = source;
+                }
+                AppMessage::NetworkError(_) => {
                     // Network status handled by background tasks
                 }
                 _ => {
@@ -176,17 +241,21 @@ impl App {
         Ok(Self {
             nav: NavigationState::new(),
             data,
-            ui: super::UiState::new(),
+            ui,
             network,
             network_config,
             available_networks,
             show_live,
             exit: false,
             animation_tick: 0,
+            keymap,
             message_tx,
             message_rx,
             live_updates_tx,
             network_tx,
+            watchlist_tx,
+            txn_filter_tx,
+            pending_pool_tx,
             client,
             startup_options: Some(startup_options),
         })
@@ -254,10 +323,22 @@ impl App {
         let message_tx = self.message_tx.clone();
         let live_updates_rx = self.live_updates_tx.subscribe();
         let network_rx = self.network_tx.subscribe();
+        let watchlist_rx = self.watchlist_tx.subscribe();
+        let txn_filter_rx = self.txn_filter_tx.subscribe();
+        let pending_pool_rx = self.pending_pool_tx.subscribe();
         let client = self.client.clone();
 
         tokio::spawn(async move {
-            Self::data_fetching_task(message_tx, live_updates_rx, network_rx, client).await;
+            Self::data_fetching_task(
+                message_tx,
+                live_updates_rx,
+                network_rx,
+                watchlist_rx,
+                txn_filter_rx,
+                pending_pool_rx,
+                client,
+            )
+            .await;
         });
     }
 
@@ -265,26 +346,59 @@ impl App {
         message_tx: mpsc::UnboundedSender<AppMessage>,
         mut live_updates_rx: tokio::sync::watch::Receiver<bool>,
         mut network_rx: tokio::sync::watch::Receiver<NetworkConfig>,
+        mut watchlist_rx: tokio::sync::watch::Receiver<Vec<WatchTarget>>,
+        mut txn_filter_rx: tokio::sync::watch::Receiver<Option<TxnFilter>>,
+        mut pending_pool_rx: tokio::sync::watch::Receiver<Option<PoolScope>>,
         mut client: AlgoClient,
     ) {
-        let mut block_interval = interval(Duration::from_secs(5));
-        let mut transaction_interval = interval(Duration::from_secs(5));
+        let mut watchlist_interval = interval(Duration::from_secs(5));
         let mut network_check_interval = interval(Duration::from_secs(10));
+        let mut pending_pool_interval = interval(PENDING_POOL_POLL_INTERVAL);
 
         let mut is_network_available = true;
         let mut network_error_shown = false;
+        let mut data_source = client.data_source();
+        // Last round shown in the panels; `None` until the first wait returns
+        // the round the initial fetch showed
+        let mut last_round: Option<u64> = None;
+        // Transactions in the pool at the last poll, to spot confirmations
+        let mut pool_ids: HashSet<String> = HashSet::new();
+        let mut round_wait = Self::wait_for_round(
+            &client,
+            last_round,
+            *live_updates_rx.borrow(),
+            Duration::ZERO,
+        );
 
         loop {
             tokio::select! {
-                _ = live_updates_rx.changed() => {}
+                _ = live_updates_rx.changed() => {
+                    let live = *live_updates_rx.borrow_and_update();
+                    round_wait = Self::wait_for_round(&client, last_round, live, Duration::ZERO);
+                }
 
                 _ = network_rx.changed() => {
                     let new_config = network_rx.borrow_and_update().clone();
                     match AlgoClient::from_config(&new_config) {
                         Ok(new_client) => {
                             client = new_client;
+                            data_source = client.data_source();
                             is_network_available = true;
                             network_error_shown = false;
+                            last_round = None;
+                            round_wait = Self::wait_for_round(
+                                &client,
+                                last_round,
+                                *live_updates_rx.borrow(),
+                                Duration::ZERO,
+                            );
+                            // Re-poll the new network's watchlist with the new client
+                            watchlist_rx.mark_changed();
+                            // Backfill the filtered feed on the new network
+                            if txn_filter_rx.borrow().is_some() {
+                                txn_filter_rx.mark_changed();
+                            }
+                            pool_ids.clear();
                         }
                         Err(e) => {
                             let _ = message_tx.send(AppMessage::NetworkError(e.to_string()));
@@ -294,14 +408,58 @@ impl App {
                     }
                 }
 
+                _ = watchlist_rx.changed() => {
+                    let targets = watchlist_rx.borrow_and_update().clone();
+                    if !targets.is_empty() {
+                        let statuses = client.get_watch_statuses(&targets).await;
+                        // Receiver may be dropped during shutdown - safe to ignore
+                        let _ = message_tx.send(AppMessage::WatchlistUpdated(statuses));
+                    }
+                }
+
+                _ = txn_filter_rx.changed() => {
+                    let filter = txn_filter_rx.borrow_and_update().clone();
+                    if is_network_available {
+                        match Self::fetch_feed(&client, filter.as_ref()).await {
+                            Ok(transactions) => {
+                                // Receiver may be dropped during shutdown - safe to ignore
+                                let _ = message_tx.send(AppMessage::TransactionsUpdated(transactions));
+                            }
+                            Err(err) => {
+                                // Receiver may be dropped during shutdown - safe to ignore
+                                let _ = message_tx.send(AppMessage::NetworkError(err.to_string()));
+                            }
+                        }
+                    }
+                }
+
+                _ = pending_pool_rx.changed() => {
+                    let scope = pending_pool_rx.borrow_and_update().clone();
+                    pool_ids.clear();
+                    if let Some(scope) = scope {
+                        Self::poll_pending_pool(&client, &message_tx, scope, &mut pool_ids).await;
+                    }
+                }
+
+                _ = pending_pool_interval.tick() => {
+                    let scope = pending_pool_rx.borrow().clone();
+                    if let Some(scope) = scope
+                        && *live_updates_rx.borrow()
+                        && is_network_available
+                    {
+                        Self::poll_pending_pool(&client, &message_tx, scope, &mut pool_ids).await;
+                    }
+                }
+
                 _ = network_check_interval.tick() => {
                     if *live_updates_rx.borrow() {
                         match client.get_network_status().await {
-                            Ok(()) => {
-                                if !is_network_available {
+                            Ok(source) => {
+                                if !is_network_available || source != data_source {
                                     // Receiver may be dropped during shutdown - safe to ignore
-                                    let _ = message_tx.send(AppMessage::NetworkConnected);
+                                    let _ = message_tx.send(AppMessage::NetworkConnected(source));
                                 }
+                                data_source = source;
                                 is_network_available = true;
                                 network_error_shown = false;
                             }
@@ -317,42 +475,53 @@ impl App {
                     }
                 }
 
-                _ = block_interval.tick() => {
-                    if *live_updates_rx.borrow() && is_network_available {
-                        match client.get_latest_blocks(5).await {
-                            Ok(blocks) => {
-                                network_error_shown = false;
+                result = &mut round_wait => {
+                    let result = match result {
+                        Ok(latest) => match rounds_to_fetch(last_round, latest) {
+                            Some((first, last)) => client
+                                .get_rounds(first, last)
+                                .await
+                                .map(|update| (latest, Some(update))),
+                            None => Ok((latest, None)),
+                        },
+                        Err(err) => Err(err),
+                    };
+                    let retry_delay = match result {
+                        Ok((latest, update)) => {
+                            last_round = Some(latest);
+                            if let Some((blocks, transactions)) = update {
                                 // Receiver may be dropped during shutdown - safe to ignore
                                 let _ = message_tx.send(AppMessage::BlocksUpdated(blocks));
+                                let _ = message_tx.send(AppMessage::TransactionsUpdated(transactions));
                             }
-                            Err(err) => {
-                                if !network_error_shown {
-                                    // Receiver may be dropped during shutdown - safe to ignore
-                                    let _ = message_tx.send(AppMessage::NetworkError(err.to_string()));
-                                    network_error_shown = true;
-                                }
-                                is_network_available = false;
+                            network_error_shown = false;
+                            Duration::ZERO
+                        }
+                        Err(err) => {
+                            if !network_error_shown {
+                                // Receiver may be dropped during shutdown - safe to ignore
+                                let _ = message_tx.send(AppMessage::NetworkError(err.to_string()));
+                                network_error_shown = true;
                             }
+                            is_network_available = false;
+                            LIVE_RETRY_DELAY
                         }
-                    }
+                    };
+                    round_wait = Self::wait_for_round(
+                        &client,
+                        last_round,
+                        *live_updates_rx.borrow(),
+                        retry_delay,
+                    );
                 }
 
-                _ = transaction_interval.tick() => {
+                _ = watchlist_interval.tick() => {
                     if *live_updates_rx.borrow() && is_network_available {
-                        match client.get_latest_transactions(5).await {
-                            Ok(transactions) => {
-                                network_error_shown = false;
-                                // Receiver may be dropped during shutdown - safe to ignore
-                                let _ = message_tx.send(AppMessage::TransactionsUpdated(transactions));
-                            }
-                            Err(err) => {
-                                if !network_error_shown {
-                                    // Receiver may be dropped during shutdown - safe to ignore
-                                    let _ = message_tx.send(AppMessage::NetworkError(err.to_string()));
-                                    network_error_shown = true;
-                                }
-                                is_network_available = false;
-                            }
+                        let targets = watchlist_rx.borrow().clone();
+                        if !targets.is_empty() {
+                            let statuses = client.get_watch_statuses(&targets).await;
+                            // Receiver may be dropped during shutdown - safe to ignore
+                            let _ = message_tx.send(AppMessage::WatchlistUpdated(statuses));
                         }
                     }
                 }
@@ -360,6 +529,73 @@ impl App {
         }
     }
 
+    /// Starts waiting for the round after `last_round`, after `delay`.
+    ///
+    /// Without a `last_round` this resolves straight away with the current
+    /// round. While live updates are paused the wait never resolves.
+    fn wait_for_round(
+        client: &AlgoClient,
+        last_round: Option<u64>,
+        live: bool,
+        delay: Duration,
+    ) -> RoundWait {
+        if !live {
+            return Box::pin(std::future::pending());
+        }
+        let client = client.clone();
+        Box::pin(async move {
+            tokio::time::sleep(delay).await;
+            client.wait_for_block_after(last_round.unwrap_or(0)).await
+        })
+    }
+
+    /// Polls the pending pool and looks up where transactions that left it
+    /// since the last poll ended up.
+    async fn poll_pending_pool(
+        client: &AlgoClient,
+        message_tx: &mpsc::UnboundedSender<AppMessage>,
+        scope: PoolScope,
+        pool_ids: &mut HashSet<String>,
+    ) {
+        // Channel sends below: receiver may be dropped during shutdown - safe to ignore
+        match client.get_pool_transactions(scope.address()).await {
+            Ok((pending, total)) => {
+                let ids: HashSet<String> = pending.iter().map(|txn| txn.id.clone()).collect();
+                let left: Vec<String> = pool_ids.difference(&ids).cloned().collect();
+                *pool_ids = ids;
+                let confirmed = if left.is_empty() {
+                    Vec::new()
+                } else {
+                    client.get_confirmed_pool_transactions(left).await
+                };
+                let _ = message_tx.send(AppMessage::PendingPoolUpdated {
+                    scope,
+                    pending,
+                    total,
+                    confirmed,
+                });
+            }
+            Err(err) => {
+                let _ = message_tx.send(AppMessage::PendingPoolFailed(err.to_string()));
+            }
+        }
+    }
+
+    /// Fetches the live transaction feed after the filter changes.
+    ///
+    /// Without a filter this is the latest 20-round window. With one, the
+    /// filter is pushed down to the indexer to backfill older matches. New
+    /// rounds arrive with their blocks, so this is never polled.
+    async fn fetch_feed(
+        client: &AlgoClient,
+        filter: Option<&TxnFilter>,
+    ) -> Result<Vec<Transaction>> {
+        let Some(filter) = filter else {
+            return client.get_latest_transactions(5).await;
+        };
+        client.get_filtered_transactions(filter).await
+    }
+
     pub(super) async fn initial_data_fetch(&self) {
         let message_tx = self.message_tx.clone();
         let client = self.client.clone();
@@ -371,8 +607,8 @@ impl App {
                     let _ = message_tx.send(AppMessage::NetworkError(error_msg));
                     return;
                 }
-                Ok(()) => {
-                    let _ = message_tx.send(AppMessage::NetworkConnected);
+                Ok(source) => {
+                    let _ = message_tx.send(AppMessage::NetworkConnected(source));
                 }
             }
 
@@ -402,7 +638,8 @@ impl App {
         });
     }
 
-    /// Process startup search options (transaction, account, block, or asset lookup).
+    /// Process startup search options (transaction, account, block, or asset
+    /// lookup, or a simulation).
     async fn process_startup_search(&mut self) {
         let startup_options = match self.startup_options.take() {
             Some(opts) => opts,
@@ -433,6 +670,11 @@ impl App {
             StartupSearch::Asset(asset_id) => {
                 self.load_asset_details_by_query(asset_id);
             }
+            StartupSearch::Simulation(input) => {
+                // Bad input leaves the prompt open with the error shown
+                self.ui.open_simulate(input);
+                self.handle_submit_simulate();
+            }
         }
     }
 
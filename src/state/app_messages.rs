@@ -5,8 +5,8 @@
 
 use std::collections::HashSet;
 
-use super::{App, AppMessage};
-use crate::domain::{AlgoBlock, SearchResultItem, Transaction};
+use super::{App, AppMessage, AssetDetailTab};
+use crate::domain::{AlgoBlock, SearchResultItem, Transaction, app_spec};
 
 impl App {
     pub(crate) async fn process_messages(&mut self) {
@@ -32,7 +32,7 @@ impl App {
                     }
                     self.show_live = false;
                 }
-                AppMessage::NetworkConnected => {}
+                AppMessage::NetworkConnected(source) => self.set_data_source(source),
                 AppMessage::NetworkSwitchComplete => {
                     self.ui.show_toast("Network switched", 20);
                 }
@@ -43,8 +43,30 @@ impl App {
                         self.nav.block_txn_scroll = 0;
                     }
                     self.data.block_details = Some(details);
+                    self.data.block_txns_loading_more = false;
+                }
+                AppMessage::BlockTransactionsPageLoaded { round, page } => {
+                    self.data.block_txns_loading_more = false;
+                    // Ignore pages for a block that is no longer shown
+                    if let Some(details) = &mut self.data.block_details
+                        && details.info.id == round
+                    {
+                        details.append_page(page);
+                    }
+                }
+                AppMessage::BlockTransactionsPageFailed(error) => {
+                    self.data.block_txns_loading_more = false;
+                    self.ui
+                        .show_toast(format!("Failed to load more transactions: {error}"), 30);
                 }
                 AppMessage::TransactionDetailsLoaded(txn) => {
+                    if self.nav.show_pending_pool {
+                        // A confirmed pool transaction the indexer now has
+                        self.nav.push_pending_pool_state();
+                        self.nav.show_pending_pool = false;
+                        self.nav.detail_row_index = None;
+                        self.nav.detail_row_scroll = 0;
+                    }
                     // Store the transaction for viewing
                     self.data.viewed_transaction = Some(*txn);
                     self.nav.show_transaction_details = true;
@@ -58,22 +80,76 @@ impl App {
                 AppMessage::AccountDetailsLoaded(details) => {
                     self.data.viewed_account = Some(*details);
                     self.nav.show_account_details = true;
+                    // A restored parent popup may be on a tab whose list was replaced
+                    self.ensure_account_tab_loaded();
                 }
                 AppMessage::AccountDetailsFailed(error) => {
                     self.nav.show_account_details = false;
                     self.ui
                         .show_message(format!("Failed to load account: {}", error));
                 }
+                AppMessage::AccountHistoryLoaded {
+                    address,
+                    filter,
+                    page,
+                } => {
+                    // Ignore pages for a history that has since been replaced
+                    if let Some(history) = &mut self.data.account_history
+                        && history.matches(&address, filter)
+                    {
+                        history.append_page(page);
+                    }
+                }
+                AppMessage::AccountHistoryFailed(error) => {
+                    if let Some(history) = &mut self.data.account_history {
+                        history.loading = false;
+                    }
+                    self.ui
+                        .show_toast(format!("Failed to load history: {error}"), 30);
+                }
+                AppMessage::AccountListLoaded { address, page } => {
+                    self.data.append_account_list_page(&address, page);
+                }
+                AppMessage::AccountListFailed { tab, error } => {
+                    self.data.stop_account_list_loading(tab);
+                    self.ui
+                        .show_toast(format!("Failed to load account list: {error}"), 30);
+                }
                 AppMessage::AssetDetailsLoaded(details) => {
                     self.data.viewed_asset = Some(*details);
                     self.nav.show_asset_details = true;
+                    if self.nav.asset_detail_tab == AssetDetailTab::Holders {
+                        self.ensure_asset_holders_loaded();
+                    }
                 }
                 AppMessage::AssetDetailsFailed(error) => {
                     self.nav.show_asset_details = false;
                     self.ui
                         .show_message(format!("Failed to load asset: {}", error));
                 }
+                AppMessage::AssetHoldersLoaded {
+                    asset_id,
+                    min_units,
+                    page,
+                } => {
+                    // Ignore pages for a listing that has since been replaced
+                    if let Some(holders) = &mut self.data.asset_holders
+                        && holders.matches(asset_id, min_units)
+                    {
+                        holders.append_page(page);
+                    }
+                }
+                AppMessage::AssetHoldersFailed(error) => {
+                    if let Some(holders) = &mut self.data.asset_holders {
+                        holders.loading = false;
+                    }
+                    self.ui
+                        .show_toast(format!("Failed to load holders: {error}"), 30);
+                }
                 AppMessage::ApplicationDetailsLoaded(details) => {
+                    if let Some(program) = &details.approval_program {
+                        app_spec::specs().learn_program(details.app_id, program);
+                    }
                     self.data.viewed_application = Some(*details);
                     self.nav.show_application_details = true;
                 }
@@ -82,6 +158,168 @@ impl App {
                     self.ui
                         .show_message(format!("Failed to load application: {}", error));
                 }
+                AppMessage::AppBoxesLoaded { app_id, page } => {
+                    // Ignore pages for a listing that has since been replaced
+                    if let Some(app_boxes) = &mut self.data.app_boxes
+                        && app_boxes.app_id == app_id
+                    {
+                        app_boxes.append_page(page);
+                    }
+                }
+                AppMessage::AppBoxesFailed(error) => {
+                    if let Some(app_boxes) = &mut self.data.app_boxes {
+                        app_boxes.loading = false;
+                    }
+                    self.ui
+                        .show_toast(format!("Failed to load boxes: {error}"), 30);
+                }
+                AppMessage::AppBoxValueLoaded {
+                    app_id,
+                    name,
+                    value,
+                } => {
+                    if let Some(app_boxes) = &mut self.data.app_boxes
+                        && app_boxes.app_id == app_id
+                    {
+                        app_boxes.set_value(&name, value);
+                    }
+                }
+                AppMessage::LocalStateLoaded {
+                    address,
+                    app_id,
+                    entries,
+                } => {
+                    if let Some(view) = &mut self.data.local_state
+                        && view.matches(&address, app_id)
+                    {
+                        view.entries = entries;
+                        view.loading = false;
+                    }
+                }
+                AppMessage::LocalStateFailed {
+                    address,
+                    app_id,
+                    error,
+                } => {
+                    if let Some(view) = &mut self.data.local_state
+                        && view.matches(&address, app_id)
+                    {
+                        view.error = Some(error);
+                        view.loading = false;
+                    }
+                }
+                AppMessage::GroupLoaded(group) => {
+                    // Ignore a group whose transaction is no longer on screen
+                    let parent = self
+                        .nav
+                        .show_transaction_details
+                        .then(|| self.get_transaction_for_details())
+                        .flatten()
+                        .filter(|txn| txn.group.as_deref() == Some(group.id.as_str()));
+                    if let Some(parent) = parent {
+                        self.nav.push_transaction_state(&parent.id);
+                        self.nav.show_transaction_details = false;
+                        self.data.viewed_transaction = None;
+                        self.nav.reset_group_detail();
+                        self.nav.group_txn_index = Some(0);
+                        self.nav.graph_scroll_x = 0;
+                        self.nav.graph_scroll_y = 0;
+                        self.data.viewed_group = Some(group);
+                        self.nav.show_group_details = true;
+                    }
+                }
+                AppMessage::GroupFailed(error) => {
+                    self.ui
+                        .show_toast(format!("Failed to load group: {error}"), 30);
+                }
+                AppMessage::TxnSearchLoaded { query, page } => {
+                    // Ignore pages for a search that has since been replaced
+                    if let Some(search) = &mut self.data.txn_search
+                        && search.matches(&query)
+                    {
+                        search.append_page(page);
+                        if self.nav.txn_search_index.is_none() && !search.transactions.is_empty() {
+                            self.nav.txn_search_index = Some(0);
+                        }
+                    }
+                }
+                AppMessage::TxnSearchFailed(error) => {
+                    if let Some(search) = &mut self.data.txn_search {
+                        search.loading = false;
+                    }
+                    self.ui.show_toast(format!("Search failed: {error}"), 30);
+                }
+                AppMessage::ExportProgress { rows } => {
+                    // Long enough to bridge slow pages; the next update replaces it
+                    self.ui.show_toast(format!("Exporting... {rows} rows"), 100);
+                }
+                AppMessage::ExportFinished { path, rows } => {
+                    self.data.exporting = false;
+                    self.ui
+                        .show_toast(format!("[+] Exported {rows} rows to {path}"), 40);
+                }
+                AppMessage::ExportFailed(error) => {
+                    self.data.exporting = false;
+                    self.ui
+                        .show_toast(format!("[x] Export failed, no file written: {error}"), 40);
+                }
+                AppMessage::WatchlistUpdated(statuses) => {
+                    self.data.update_watch_statuses(statuses);
+                }
+                AppMessage::PendingPoolUpdated {
+                    scope,
+                    pending,
+                    total,
+                    confirmed,
+                } => {
+                    // Ignore polls for a view that has since been closed or re-scoped
+                    if let Some(pool) = &mut self.data.pending_pool
+                        && pool.matches(&scope)
+                    {
+                        let selected_id = self
+                            .nav
+                            .pending_pool_index
+                            .and_then(|idx| pool.get(idx))
+                            .map(|(txn, _)| txn.id.clone());
+                        pool.update(pending, total, confirmed);
+                        // Keep the selection on the same transaction as the list moves
+                        let index = selected_id.and_then(|id| pool.position(&id)).or_else(|| {
+                            pool.len()
+                                .checked_sub(1)
+                                .map(|max| self.nav.pending_pool_index.unwrap_or(0).min(max))
+                        });
+                        self.nav.pending_pool_index = index;
+                        if let Some(index) = index {
+                            self.nav.pending_pool_scroll =
+                                self.nav.pending_pool_scroll.min(index as u16);
+                        }
+                    }
+                }
+                AppMessage::PendingPoolFailed(error) => {
+                    // Later failures are reported by the network status check
+                    if let Some(pool) = &mut self.data.pending_pool
+                        && pool.loading
+                    {
+                        pool.loading = false;
+                        self.ui
+                            .show_toast(format!("Failed to load pending pool: {error}"), 30);
+                    }
+                }
+                AppMessage::SimulationLoaded(simulation) => {
+                    self.show_simulation(*simulation);
+                }
+                AppMessage::SimulationFailed(error) => {
+                    self.data.simulating = false;
+                    self.ui
+                        .show_toast(format!("[x] Could not simulate: {error}"), 40);
+                }
+                AppMessage::AppBoxValueFailed(error) => {
+                    if let Some(app_boxes) = &mut self.data.app_boxes {
+                        app_boxes.value_loading = None;
+                    }
+                    self.ui
+                        .show_toast(format!("Failed to load box value: {error}"), 30);
+                }
             }
         }
     }
@@ -123,6 +361,12 @@ impl App {
     }
 
     pub(crate) fn merge_transactions(&mut self, new_transactions: Vec<Transaction>) {
+        // An unfiltered fetch may land just after a filter was applied
+        let new_transactions: Vec<Transaction> = new_transactions
+            .into_iter()
+            .filter(|txn| self.data.txn_filter_matches(txn))
+            .take(100)
+            .collect();
         if new_transactions.is_empty() {
             return;
         }
@@ -3,11 +3,16 @@
 //! This module handles cursor movement, selection synchronization,
 //! and loading detail views for blocks, transactions, accounts, etc.
 
-use super::{AccountDetailTab, AppDetailTab, BlockDetailTab};
+use super::{
+    AccountDetailTab, AppDetailTab, AssetDetailTab, BlockDetailTab, GroupDetailTab, SimulationTab,
+};
 use super::{App, AppMessage, Focus, PopupState};
 use crate::constants::{
-    BLOCK_HEIGHT, DEFAULT_VISIBLE_BLOCKS, DEFAULT_VISIBLE_TRANSACTIONS, TXN_HEIGHT,
+    BLOCK_HEIGHT, DEFAULT_VISIBLE_BLOCKS, DEFAULT_VISIBLE_TRANSACTIONS, DEFAULT_VISIBLE_WATCHES,
+    LOAD_MORE_THRESHOLD, TXN_HEIGHT, WATCH_HEIGHT,
 };
+use crate::domain::WatchTarget;
+use crate::ui;
 
 impl App {
     pub(crate) fn sync_selections(&mut self) {
@@ -39,6 +44,7 @@ impl App {
         match self.ui.focus {
             Focus::Blocks => self.move_block_selection_up(),
             Focus::Transactions => self.move_transaction_selection_up(),
+            Focus::Watchlist => self.move_watch_selection_up(),
         }
     }
 
@@ -77,11 +83,27 @@ impl App {
         }
     }
 
+    pub(crate) fn move_watch_selection_up(&mut self) {
+        if let Some(index) = self.nav.selected_watch_index {
+            let new_index = index.saturating_sub(1);
+            self.nav.selected_watch_index = Some(new_index);
+
+            let watch_scroll = new_index as u16 * WATCH_HEIGHT;
+            if watch_scroll < self.nav.watch_scroll {
+                self.nav.watch_scroll = watch_scroll;
+            }
+        } else if !self.data.watchlist.is_empty() {
+            self.nav.selected_watch_index = Some(0);
+            self.nav.watch_scroll = 0;
+        }
+    }
+
     /// Moves the selection down in the currently focused list.
     pub fn move_selection_down(&mut self) {
         match self.ui.focus {
             Focus::Blocks => self.move_block_selection_down(),
             Focus::Transactions => self.move_transaction_selection_down(),
+            Focus::Watchlist => self.move_watch_selection_down(),
         }
     }
 
@@ -128,6 +150,26 @@ impl App {
         }
     }
 
+    pub(crate) fn move_watch_selection_down(&mut self) {
+        let max_index = self.data.watchlist.len().saturating_sub(1);
+
+        if let Some(index) = self.nav.selected_watch_index {
+            if index < max_index {
+                let new_index = index + 1;
+                self.nav.selected_watch_index = Some(new_index);
+
+                let visible_end = self.nav.watch_scroll + (DEFAULT_VISIBLE_WATCHES * WATCH_HEIGHT);
+                let item_position = (new_index as u16) * WATCH_HEIGHT;
+
+                if item_position >= visible_end {
+                    self.nav.watch_scroll = self.nav.watch_scroll.saturating_add(WATCH_HEIGHT);
+                }
+            }
+        } else if !self.data.watchlist.is_empty() {
+            self.nav.selected_watch_index = Some(0);
+        }
+    }
+
     /// Jumps to the top of the currently focused list.
     pub fn go_to_top(&mut self) {
         // Check if help popup is open
@@ -149,27 +191,106 @@ impl App {
         }
 
         if self.nav.show_account_details {
-            if let Some(account) = &self.data.viewed_account {
-                let has_items = match self.nav.account_detail_tab {
-                    AccountDetailTab::Assets => !account.assets.is_empty(),
-                    AccountDetailTab::Apps => !account.apps_local_state.is_empty(),
-                    AccountDetailTab::Info => false,
-                };
-                if has_items {
-                    self.nav.account_item_index = Some(0);
-                    self.nav.account_item_scroll = 0;
-                }
+            let tab = self.nav.account_detail_tab;
+            if self.data.viewed_account.is_some() && self.data.account_list_len(tab) > 0 {
+                self.nav.account_item_index = Some(0);
+                self.nav.account_item_scroll = 0;
+            }
+            if self.nav.account_detail_tab == AccountDetailTab::Apps {
+                self.load_selected_app_local_state();
             }
             return;
         }
 
         if self.nav.show_application_details {
-            if let Some(app) = &self.data.viewed_application
+            if self.nav.app_detail_tab == AppDetailTab::Programs {
+                self.nav.app_state_scroll = 0;
+            } else if let Some(app) = &self.data.viewed_application
                 && self.nav.app_detail_tab == AppDetailTab::State
                 && !app.global_state.is_empty()
             {
                 self.nav.app_state_index = Some(0);
                 self.nav.app_state_scroll = 0;
+            } else if self.nav.app_detail_tab == AppDetailTab::Boxes
+                && self
+                    .data
+                    .app_boxes
+                    .as_ref()
+                    .is_some_and(|b| !b.boxes.is_empty())
+            {
+                self.nav.app_state_index = Some(0);
+                self.nav.app_state_scroll = 0;
+                self.load_selected_box_value();
+            }
+            return;
+        }
+
+        if self.nav.show_asset_details {
+            if self.nav.asset_detail_tab == AssetDetailTab::Holders
+                && self
+                    .data
+                    .asset_holders
+                    .as_ref()
+                    .is_some_and(|h| !h.holders.is_empty())
+            {
+                self.nav.asset_holder_index = Some(0);
+                self.nav.asset_holder_scroll = 0;
+            }
+            return;
+        }
+
+        if self.nav.show_group_details {
+            if self.nav.group_detail_tab == GroupDetailTab::Graph {
+                self.nav.graph_scroll_y = 0;
+            } else if self
+                .data
+                .viewed_group
+                .as_ref()
+                .is_some_and(|g| !g.transactions.is_empty())
+            {
+                self.nav.group_txn_index = Some(0);
+                self.nav.group_txn_scroll = 0;
+            }
+            return;
+        }
+
+        if self.nav.show_txn_search {
+            if self
+                .data
+                .txn_search
+                .as_ref()
+                .is_some_and(|search| !search.transactions.is_empty())
+            {
+                self.nav.txn_search_index = Some(0);
+                self.nav.txn_search_scroll = 0;
+            }
+            return;
+        }
+
+        if self.nav.show_pending_pool {
+            if self
+                .data
+                .pending_pool
+                .as_ref()
+                .is_some_and(|pool| !pool.is_empty())
+            {
+                self.nav.pending_pool_index = Some(0);
+                self.nav.pending_pool_scroll = 0;
+            }
+            return;
+        }
+
+        if self.nav.show_simulation {
+            if self.nav.simulation_tab == SimulationTab::Graph {
+                self.nav.graph_scroll_y = 0;
+            } else if self
+                .data
+                .simulation
+                .as_ref()
+                .is_some_and(|s| !s.transactions.is_empty())
+            {
+                self.nav.simulation_txn_index = Some(0);
+                self.nav.simulation_txn_scroll = 0;
             }
             return;
         }
@@ -201,6 +322,12 @@ impl App {
                     self.nav.transaction_scroll = 0;
                 }
             }
+            Focus::Watchlist => {
+                if !self.data.watchlist.is_empty() {
+                    self.nav.selected_watch_index = Some(0);
+                    self.nav.watch_scroll = 0;
+                }
+            }
         }
     }
 
@@ -227,17 +354,15 @@ impl App {
                     let visible_height: u16 = 20; // Approximate visible height
                     self.nav.block_txn_scroll = total_height.saturating_sub(visible_height);
                 }
+                self.load_more_block_transactions_if_needed();
             }
             return;
         }
 
         if self.nav.show_account_details {
-            if let Some(account) = &self.data.viewed_account {
-                let max = match self.nav.account_detail_tab {
-                    AccountDetailTab::Assets => account.assets.len().saturating_sub(1),
-                    AccountDetailTab::Apps => account.apps_local_state.len().saturating_sub(1),
-                    AccountDetailTab::Info => return,
-                };
+            let tab = self.nav.account_detail_tab;
+            if self.data.viewed_account.is_some() && tab != AccountDetailTab::Info {
+                let max = self.data.account_list_len(tab).saturating_sub(1);
                 if max > 0 {
                     self.nav.account_item_index = Some(max);
                     // Scroll to show the last item
@@ -245,11 +370,28 @@ impl App {
                     self.nav.account_item_scroll = (max as u16).saturating_sub(visible_height) + 1;
                 }
             }
+            match self.nav.account_detail_tab {
+                AccountDetailTab::History => self.load_more_account_history_if_needed(),
+                AccountDetailTab::Apps => {
+                    self.load_selected_app_local_state();
+                    self.load_more_account_list_if_needed();
+                }
+                AccountDetailTab::Assets
+                | AccountDetailTab::CreatedAssets
+                | AccountDetailTab::CreatedApps => self.load_more_account_list_if_needed(),
+                AccountDetailTab::Info => {}
+            }
             return;
         }
 
         if self.nav.show_application_details {
             if let Some(app) = &self.data.viewed_application
+                && self.nav.app_detail_tab == AppDetailTab::Programs
+            {
+                let line_count = ui::panels::details::application::program_listing_len(app);
+                self.nav.app_state_scroll =
+                    u16::try_from(line_count.saturating_sub(1)).unwrap_or(u16::MAX);
+            } else if let Some(app) = &self.data.viewed_application
                 && self.nav.app_detail_tab == AppDetailTab::State
                 && !app.global_state.is_empty()
             {
@@ -258,6 +400,87 @@ impl App {
                 // Scroll to show the last item
                 let visible_height: u16 = 8;
                 self.nav.app_state_scroll = (max as u16).saturating_sub(visible_height) + 1;
+            } else if self.nav.app_detail_tab == AppDetailTab::Boxes
+                && let Some(app_boxes) = &self.data.app_boxes
+                && !app_boxes.boxes.is_empty()
+            {
+                let max = app_boxes.boxes.len().saturating_sub(1);
+                self.nav.app_state_index = Some(max);
+                // Scroll to show the last item
+                let visible_height: u16 = 8;
+                self.nav.app_state_scroll = (max as u16).saturating_sub(visible_height) + 1;
+                self.load_selected_box_value();
+                self.load_more_app_boxes_if_needed();
+            }
+            return;
+        }
+
+        if self.nav.show_asset_details {
+            if self.nav.asset_detail_tab == AssetDetailTab::Holders
+                && let Some(holders) = &self.data.asset_holders
+                && !holders.holders.is_empty()
+            {
+                let max = holders.holders.len() - 1;
+                self.nav.asset_holder_index = Some(max);
+                // Scroll to show the last item
+                let visible_height: u16 = 10;
+                self.nav.asset_holder_scroll = (max as u16 + 1).saturating_sub(visible_height);
+                self.load_more_asset_holders_if_needed();
+            }
+            return;
+        }
+
+        if self.nav.show_group_details {
+            if self.nav.group_detail_tab == GroupDetailTab::Members
+                && let Some(group) = &self.data.viewed_group
+                && !group.transactions.is_empty()
+            {
+                let max = group.transactions.len() - 1;
+                self.nav.group_txn_index = Some(max);
+                // Scroll to show the last item
+                let visible_height: u16 = 10;
+                self.nav.group_txn_scroll = (max as u16 + 1).saturating_sub(visible_height);
+            }
+            return;
+        }
+
+        if self.nav.show_txn_search {
+            if let Some(search) = &self.data.txn_search
+                && !search.transactions.is_empty()
+            {
+                let max = search.transactions.len() - 1;
+                self.nav.txn_search_index = Some(max);
+                // Scroll to show the last item (~7 result rows fit)
+                let visible_items: u16 = 7;
+                self.nav.txn_search_scroll = (max as u16 + 1).saturating_sub(visible_items);
+                self.load_more_txn_search_if_needed();
+            }
+            return;
+        }
+
+        if self.nav.show_pending_pool {
+            if let Some(pool) = &self.data.pending_pool
+                && !pool.is_empty()
+            {
+                let max = pool.len() - 1;
+                self.nav.pending_pool_index = Some(max);
+                // Scroll to show the last item (~10 pool rows fit)
+                let visible_items: u16 = 10;
+                self.nav.pending_pool_scroll = (max as u16 + 1).saturating_sub(visible_items);
+            }
+            return;
+        }
+
+        if self.nav.show_simulation {
+            if self.nav.simulation_tab == SimulationTab::Result
+                && let Some(simulation) = &self.data.simulation
+                && !simulation.transactions.is_empty()
+            {
+                let max = simulation.transactions.len() - 1;
+                self.nav.simulation_txn_index = Some(max);
+                // Scroll to show the last item (~8 transaction rows fit)
+                let visible_height: u16 = 8;
+                self.nav.simulation_txn_scroll = (max as u16 + 1).saturating_sub(visible_height);
             }
             return;
         }
@@ -298,6 +521,15 @@ impl App {
                     self.nav.transaction_scroll = total_height.saturating_sub(visible_height);
                 }
             }
+            Focus::Watchlist => {
+                if !self.data.watchlist.is_empty() {
+                    self.nav.selected_watch_index = Some(self.data.watchlist.len() - 1);
+                    // Scroll to show the last item
+                    let total_height = self.data.watchlist.len() as u16 * WATCH_HEIGHT;
+                    let visible_height = DEFAULT_VISIBLE_WATCHES * WATCH_HEIGHT;
+                    self.nav.watch_scroll = total_height.saturating_sub(visible_height);
+                }
+            }
         }
     }
 
@@ -326,6 +558,29 @@ impl App {
                 // Build detail table rows for copy functionality
                 self.update_detail_table_rows();
             }
+            Focus::Watchlist => {
+                let Some(status) = self
+                    .nav
+                    .selected_watch_index
+                    .and_then(|index| self.data.watchlist.get(index))
+                else {
+                    return;
+                };
+                match status.target.clone() {
+                    WatchTarget::Account(address) => {
+                        self.load_account_details(&address);
+                        self.nav.show_account_details = true;
+                    }
+                    WatchTarget::Asset(asset_id) => {
+                        self.load_asset_details(asset_id);
+                        self.nav.show_asset_details = true;
+                    }
+                    WatchTarget::App(app_id) => {
+                        self.load_application_details(app_id);
+                        self.nav.show_application_details = true;
+                    }
+                }
+            }
             _ => {}
         }
     }
@@ -354,6 +609,46 @@ impl App {
         });
     }
 
+    /// Fetches the next page of block transactions when the selection nears the end.
+    ///
+    /// Does nothing if all transactions are loaded or a page is already in flight.
+    pub(crate) fn load_more_block_transactions_if_needed(&mut self) {
+        if self.data.block_txns_loading_more {
+            return;
+        }
+        let Some(details) = &self.data.block_details else {
+            return;
+        };
+        let Some(next_token) = details.next_token.clone() else {
+            return;
+        };
+        let selected = self.nav.block_txn_index.unwrap_or(0);
+        if selected + LOAD_MORE_THRESHOLD < details.transactions.len() {
+            return;
+        }
+
+        self.data.block_txns_loading_more = true;
+        let round = details.info.id;
+        let client = self.client.clone();
+        let message_tx = self.message_tx.clone();
+
+        tokio::spawn(async move {
+            // Channel sends below: receiver may be dropped during shutdown - safe to ignore
+            match client
+                .get_block_transactions_page(round, Some(&next_token))
+                .await
+            {
+                Ok(page) => {
+                    let _ =
+                        message_tx.send(AppMessage::BlockTransactionsPageLoaded { round, page });
+                }
+                Err(e) => {
+                    let _ = message_tx.send(AppMessage::BlockTransactionsPageFailed(e.to_string()));
+                }
+            }
+        });
+    }
+
     /// Loads transaction details asynchronously by transaction ID.
     pub(crate) async fn load_transaction_details(&self, txn_id: &str) {
         let client = self.client.clone();
@@ -26,10 +26,12 @@
 
 use color_eyre::Result;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::fs;
 use std::path::PathBuf;
 
-use crate::domain::{CustomNetwork, Network, NetworkConfig};
+use crate::domain::{CustomNetwork, Network, NetworkConfig, WatchTarget};
+use crate::keymap::{Keymap, KeymapError};
 
 // ============================================================================
 // Constants
@@ -41,6 +43,12 @@ const APP_NAME: &str = "lazylora";
 /// Configuration file name.
 const CONFIG_FILE: &str = "config.json";
 
+/// Directory (next to the configuration file) holding ARC-4/ARC-56 app specs.
+const SPECS_DIR: &str = "specs";
+
+/// Directory (next to the configuration file) holding theme files.
+const THEMES_DIR: &str = "themes";
+
 // ============================================================================
 // AppConfig
 // ============================================================================
@@ -55,6 +63,9 @@ const CONFIG_FILE: &str = "config.json";
 /// * `network` - The currently selected network (built-in or custom)
 /// * `custom_networks` - List of user-defined custom networks
 /// * `show_live` - Whether live updates are enabled
+/// * `keybindings` - Key chord overrides per input context
+/// * `theme` - Name of the color theme (built-in or theme file)
+/// * `watchlists` - Watched accounts, assets and apps per network
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub struct AppConfig {
     /// The currently selected network.
@@ -65,6 +76,15 @@ pub struct AppConfig {
     pub custom_networks: Vec<CustomNetwork>,
     /// Whether live updates are enabled.
     pub show_live: bool,
+    /// Key chord to command name overrides, keyed by input context name.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub keybindings: BTreeMap<String, BTreeMap<String, String>>,
+    /// Color theme name; the default dark theme if unset.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub theme: Option<String>,
+    /// Watched entities, keyed by network name.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub watchlists: BTreeMap<String, Vec<WatchTarget>>,
 }
 
 impl Default for AppConfig {
@@ -73,6 +93,9 @@ impl Default for AppConfig {
             network: NetworkConfig::BuiltIn(Network::MainNet),
             custom_networks: Vec::new(),
             show_live: true,
+            keybindings: BTreeMap::new(),
+            theme: None,
+            watchlists: BTreeMap::new(),
         }
     }
 }
@@ -100,6 +123,32 @@ impl AppConfig {
         Ok(path)
     }
 
+    /// Returns the directory holding app spec JSON files.
+    ///
+    /// The directory is not created; a missing directory means no specs.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the configuration directory cannot be determined
+    /// or created.
+    pub fn specs_dir() -> Result<PathBuf> {
+        let mut path = Self::config_path()?;
+        path.set_file_name(SPECS_DIR);
+        Ok(path)
+    }
+
+    /// Returns the directory holding theme files (`<name>.json`).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the configuration directory cannot be determined
+    /// or created.
+    pub fn themes_dir() -> Result<PathBuf> {
+        let mut path = Self::config_path()?;
+        path.set_file_name(THEMES_DIR);
+        Ok(path)
+    }
+
     /// Loads the configuration from disk.
     ///
     /// If the configuration file doesn't exist or cannot be parsed,
@@ -157,6 +206,15 @@ impl AppConfig {
         Ok(())
     }
 
+    /// Builds the keymap from the defaults and the `keybindings` overrides.
+    ///
+    /// # Errors
+    ///
+    /// Returns every invalid or conflicting binding.
+    pub fn keymap(&self) -> Result<Keymap, KeymapError> {
+        Keymap::from_config(&self.keybindings)
+    }
+
     /// Adds a custom network and saves the configuration.
     ///
     /// # Arguments
@@ -206,6 +264,37 @@ impl AppConfig {
         self.save()
     }
 
+    /// Returns the watchlist for a network.
+    #[must_use]
+    pub fn watchlist(&self, network: &NetworkConfig) -> &[WatchTarget] {
+        self.watchlists
+            .get(network.as_str())
+            .map_or(&[], Vec::as_slice)
+    }
+
+    /// Adds a target to a network's watchlist, or removes it if present.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the target is now watched.
+    pub fn toggle_watch(&mut self, network: &NetworkConfig, target: WatchTarget) -> bool {
+        let list = self
+            .watchlists
+            .entry(network.as_str().to_string())
+            .or_default();
+        let watched = if let Some(pos) = list.iter().position(|t| *t == target) {
+            list.remove(pos);
+            false
+        } else {
+            list.push(target);
+            true
+        };
+        if list.is_empty() {
+            self.watchlists.remove(network.as_str());
+        }
+        watched
+    }
+
     /// Returns all available networks (built-in + custom).
     ///
     /// # Returns
@@ -246,12 +335,39 @@ mod tests {
         assert!(config.show_live);
     }
 
+    #[test]
+    fn test_toggle_watch_is_per_network() {
+        let mut config = AppConfig::default();
+        let mainnet = NetworkConfig::BuiltIn(Network::MainNet);
+        let testnet = NetworkConfig::BuiltIn(Network::TestNet);
+
+        assert!(config.toggle_watch(&mainnet, WatchTarget::Asset(31566704)));
+        assert!(config.toggle_watch(&mainnet, WatchTarget::App(1)));
+        assert_eq!(
+            config.watchlist(&mainnet),
+            [WatchTarget::Asset(31566704), WatchTarget::App(1)]
+        );
+        assert!(config.watchlist(&testnet).is_empty());
+
+        let json = serde_json::to_string(&config).unwrap();
+        assert!(json.contains(r#""watchlists":{"MainNet":[{"type":"asset","id":31566704}"#));
+        let deserialized: AppConfig = serde_json::from_str(&json).unwrap();
+        assert_eq!(config, deserialized);
+
+        assert!(!config.toggle_watch(&mainnet, WatchTarget::Asset(31566704)));
+        assert!(!config.toggle_watch(&mainnet, WatchTarget::App(1)));
+        assert!(config.watchlists.is_empty());
+    }
+
     #[test]
     fn test_serialization_builtin() {
         let config = AppConfig {
             network: NetworkConfig::BuiltIn(Network::TestNet),
             custom_networks: Vec::new(),
             show_live: false,
+            keybindings: BTreeMap::new(),
+            theme: None,
+            watchlists: BTreeMap::new(),
         };
 
         let json = serde_json::to_string(&config).unwrap();
@@ -389,6 +505,21 @@ mod tests {
         assert!(json.contains("custom_networks"));
     }
 
+    #[test]
+    fn test_keybindings_section() {
+        let json =
+            r#"{"show_live":true,"keybindings":{"main":{"ctrl+r":"Refresh","x":"Explode"}}}"#;
+        let config: AppConfig = serde_json::from_str(json).unwrap();
+
+        assert_eq!(config.keybindings["main"]["ctrl+r"], "Refresh");
+        let err = config.keymap().unwrap_err();
+        assert_eq!(err.problems, ["main: unknown command 'Explode' for 'x'"]);
+
+        let json = serde_json::to_string(&AppConfig::default()).unwrap();
+        assert!(!json.contains("keybindings"));
+        assert!(!json.contains("theme"));
+    }
+
     #[test]
     fn test_config_path_has_json_extension() {
         if let Ok(path) = AppConfig::config_path() {
@@ -406,6 +537,9 @@ mod tests {
             network: NetworkConfig::BuiltIn(network),
             custom_networks: Vec::new(),
             show_live: true,
+            keybindings: BTreeMap::new(),
+            theme: None,
+            watchlists: BTreeMap::new(),
         };
         let json = serde_json::to_string(&config).unwrap();
         let deserialized: AppConfig = serde_json::from_str(&json).unwrap();
@@ -10,11 +10,623 @@
 //! The data state is separate from navigation state, allowing the data
 //! to be updated independently of what's currently selected or visible.
 
+use std::cmp::Reverse;
+
+use super::AccountDetailTab;
+use crate::constants::{LOAD_MORE_THRESHOLD, PENDING_POOL_CONFIRMED};
 use crate::domain::{
-    AccountDetails, AlgoBlock, ApplicationDetails, AssetDetails, BlockDetails, SearchResultItem,
-    Transaction,
+    AccountAssetHolding, AccountDetails, AlgoBlock, AppBox, AppLocalState, AppStateValue,
+    ApplicationDetails, AssetDetails, AssetHolder, BlockDetails, CreatedAppInfo, CreatedAssetInfo,
+    DataSource, Page, SearchResultItem, Simulation, Transaction, TxnFilter, TxnGroup, TxnQuery,
+    TxnType, WatchStatus, WatchTarget,
 };
 
+// ============================================================================
+// Account History
+// ============================================================================
+
+/// Paged transaction history for the account shown in the account popup.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct AccountHistory {
+    /// The account the history belongs to.
+    pub address: String,
+    /// Active transaction type filter (`None` = all types).
+    pub filter: Option<TxnType>,
+    /// Transactions loaded so far, newest first.
+    pub transactions: Vec<Transaction>,
+    /// Indexer token for the next page, if more remain.
+    pub next_token: Option<String>,
+    /// Whether a page request is in flight.
+    pub loading: bool,
+}
+
+impl AccountHistory {
+    /// Creates an empty history for an address and filter, marked as loading.
+    ///
+    /// # Arguments
+    ///
+    /// * `address` - The account address
+    /// * `filter` - Transaction type filter (`None` = all types)
+    #[must_use]
+    pub fn new(address: &str, filter: Option<TxnType>) -> Self {
+        Self {
+            address: address.to_string(),
+            filter,
+            loading: true,
+            ..Self::default()
+        }
+    }
+
+    /// Returns true if this history was requested for the given address and filter.
+    #[must_use]
+    pub fn matches(&self, address: &str, filter: Option<TxnType>) -> bool {
+        self.address == address && self.filter == filter
+    }
+
+    /// Appends a fetched page and clears the loading flag.
+    pub fn append_page(&mut self, page: Page<Transaction>) {
+        self.transactions.extend(page.items);
+        self.next_token = page.next_token;
+        self.loading = false;
+    }
+
+    /// Returns true if more transactions remain to be fetched.
+    #[must_use]
+    pub fn has_more(&self) -> bool {
+        self.next_token.is_some()
+    }
+}
+
+// ============================================================================
+// Transaction Search
+// ============================================================================
+
+/// Paged results of an advanced transaction search.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TxnSearch {
+    /// The criteria the results were requested for.
+    pub query: TxnQuery,
+    /// Transactions loaded so far, newest first.
+    pub transactions: Vec<Transaction>,
+    /// Indexer token for the next page, if more remain.
+    pub next_token: Option<String>,
+    /// Whether a page request is in flight.
+    pub loading: bool,
+}
+
+impl TxnSearch {
+    /// Creates an empty result list for a query, marked as loading.
+    #[must_use]
+    pub fn new(query: TxnQuery) -> Self {
+        Self {
+            query,
+            loading: true,
+            ..Self::default()
+        }
+    }
+
+    /// Returns true if these results were requested for the given query.
+    #[must_use]
+    pub fn matches(&self, query: &TxnQuery) -> bool {
+        self.query == *query
+    }
+
+    /// Appends a fetched page and clears the loading flag.
+    pub fn append_page(&mut self, page: Page<Transaction>) {
+        self.transactions.extend(page.items);
+        self.next_token = page.next_token;
+        self.loading = false;
+    }
+
+    /// Returns true if more transactions remain to be fetched.
+    #[must_use]
+    pub fn has_more(&self) -> bool {
+        self.next_token.is_some()
+    }
+}
+
+// ============================================================================
+// Pending Pool
+// ============================================================================
+
+/// Which part of algod's transaction pool the pending pool view shows.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum PoolScope {
+    /// The whole pool.
+    #[default]
+    All,
+    /// Only transactions sent by this account.
+    Account(String),
+}
+
+impl PoolScope {
+    /// Returns the sender the pool is narrowed to, if any.
+    #[must_use]
+    pub fn address(&self) -> Option<&str> {
+        match self {
+            Self::All => None,
+            Self::Account(address) => Some(address),
+        }
+    }
+}
+
+/// Live view of algod's transaction pool.
+///
+/// Transactions that leave the pool confirmed move to a short list of
+/// recently confirmed ones, listed after the pending ones.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PendingPool {
+    /// The part of the pool shown.
+    pub scope: PoolScope,
+    /// Transactions still in the pool, in algod's priority order.
+    pub pending: Vec<Transaction>,
+    /// Transactions that left the pool confirmed, newest first.
+    pub confirmed: Vec<Transaction>,
+    /// Total number of transactions in the pool for the scope.
+    pub total: u64,
+    /// Whether the first poll is still in flight.
+    pub loading: bool,
+}
+
+impl PendingPool {
+    /// Creates an empty pool view for a scope, marked as loading.
+    #[must_use]
+    pub fn new(scope: PoolScope) -> Self {
+        Self {
+            scope,
+            loading: true,
+            ..Self::default()
+        }
+    }
+
+    /// Returns true if this view shows the given scope.
+    #[must_use]
+    pub fn matches(&self, scope: &PoolScope) -> bool {
+        self.scope == *scope
+    }
+
+    /// Returns the number of listed transactions, pending and confirmed.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.pending.len() + self.confirmed.len()
+    }
+
+    /// Returns true if no transactions are listed.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the listed transaction at `index`, and whether it was confirmed.
+    #[must_use]
+    pub fn get(&self, index: usize) -> Option<(&Transaction, bool)> {
+        match index.checked_sub(self.pending.len()) {
+            None => self.pending.get(index).map(|txn| (txn, false)),
+            Some(index) => self.confirmed.get(index).map(|txn| (txn, true)),
+        }
+    }
+
+    /// Returns the index of the listed transaction with the given ID.
+    #[must_use]
+    pub fn position(&self, txn_id: &str) -> Option<usize> {
+        self.pending
+            .iter()
+            .chain(&self.confirmed)
+            .position(|txn| txn.id == txn_id)
+    }
+
+    /// Replaces the pending transactions with a fresh poll and records the
+    /// ones that were confirmed since the last one.
+    pub fn update(&mut self, pending: Vec<Transaction>, total: u64, confirmed: Vec<Transaction>) {
+        self.confirmed
+            .retain(|old| !confirmed.iter().any(|txn| txn.id == old.id));
+        self.confirmed.splice(0..0, confirmed);
+        self.confirmed.truncate(PENDING_POOL_CONFIRMED);
+        self.pending = pending;
+        self.total = total;
+        self.loading = false;
+    }
+}
+
+// ============================================================================
+// Account Lists
+// ============================================================================
+
+/// Column an account list is sorted by.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ListSort {
+    /// The order the indexer returned.
+    #[default]
+    Default,
+    /// Ascending asset or application ID.
+    Id,
+    /// Name, case-insensitive.
+    Name,
+    /// Largest amount first.
+    Amount,
+}
+
+impl ListSort {
+    /// Returns the label shown in the list header.
+    #[must_use]
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::Default => "Default",
+            Self::Id => "ID",
+            Self::Name => "Name",
+            Self::Amount => "Amount",
+        }
+    }
+}
+
+/// An entry in one of the account popup's paged lists.
+pub trait AccountListEntry {
+    /// Columns the list can be sorted by, besides the default order.
+    const SORT_COLUMNS: &'static [ListSort];
+    /// Whether entries carry a balance that can be hidden when zero.
+    const HAS_BALANCE: bool = false;
+
+    /// The asset or application ID.
+    fn id(&self) -> u64;
+
+    /// The display name, if the entry has one.
+    fn name(&self) -> &str {
+        ""
+    }
+
+    /// The amount used for sorting and zero-balance filtering.
+    fn amount(&self) -> u64 {
+        0
+    }
+}
+
+impl AccountListEntry for AccountAssetHolding {
+    const SORT_COLUMNS: &'static [ListSort] = &[ListSort::Id, ListSort::Name, ListSort::Amount];
+    const HAS_BALANCE: bool = true;
+
+    fn id(&self) -> u64 {
+        self.asset_id
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn amount(&self) -> u64 {
+        self.amount
+    }
+}
+
+impl AccountListEntry for CreatedAssetInfo {
+    const SORT_COLUMNS: &'static [ListSort] = &[ListSort::Id, ListSort::Name, ListSort::Amount];
+
+    fn id(&self) -> u64 {
+        self.asset_id
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn amount(&self) -> u64 {
+        self.total
+    }
+}
+
+impl AccountListEntry for AppLocalState {
+    const SORT_COLUMNS: &'static [ListSort] = &[ListSort::Id];
+
+    fn id(&self) -> u64 {
+        self.app_id
+    }
+}
+
+impl AccountListEntry for CreatedAppInfo {
+    const SORT_COLUMNS: &'static [ListSort] = &[ListSort::Id];
+
+    fn id(&self) -> u64 {
+        self.app_id
+    }
+}
+
+/// A paged list shown in one of the account popup's asset or app tabs.
+///
+/// Items are kept in indexer order; sorting and the zero-balance filter only
+/// change the view returned by [`AccountList::visible`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct AccountList<T> {
+    /// The account the list belongs to.
+    pub address: String,
+    /// Items loaded so far, in indexer order.
+    pub items: Vec<T>,
+    /// Indexer token for the next page, if more remain.
+    pub next_token: Option<String>,
+    /// Whether a page request is in flight.
+    pub loading: bool,
+    /// Active sort column.
+    pub sort: ListSort,
+    /// Whether entries with a zero balance are hidden.
+    pub hide_zero: bool,
+}
+
+impl<T: AccountListEntry> AccountList<T> {
+    /// Creates an empty list for an address, marked as loading.
+    #[must_use]
+    pub fn new(address: &str) -> Self {
+        Self {
+            address: address.to_string(),
+            items: Vec::new(),
+            next_token: None,
+            loading: true,
+            sort: ListSort::Default,
+            hide_zero: false,
+        }
+    }
+
+    /// Replaces `list` with a fresh loading list unless it already belongs to `address`.
+    ///
+    /// Returns true if the caller should request the first page.
+    pub fn reset_if_stale(list: &mut Option<Self>, address: &str) -> bool {
+        if list.as_ref().is_some_and(|l| l.address == address) {
+            return false;
+        }
+        *list = Some(Self::new(address));
+        true
+    }
+
+    /// Appends a fetched page and clears the loading flag.
+    pub fn append_page(&mut self, page: Page<T>) {
+        self.items.extend(page.items);
+        self.next_token = page.next_token;
+        self.loading = false;
+    }
+
+    /// Returns true if more items remain to be fetched.
+    #[must_use]
+    pub fn has_more(&self) -> bool {
+        self.next_token.is_some()
+    }
+
+    /// Returns the items to display, filtered and sorted.
+    #[must_use]
+    pub fn visible(&self) -> Vec<&T> {
+        let mut items: Vec<&T> = self
+            .items
+            .iter()
+            .filter(|item| !(self.hide_zero && T::HAS_BALANCE && item.amount() == 0))
+            .collect();
+        match self.sort {
+            ListSort::Default => {}
+            ListSort::Id => items.sort_by_key(|item| item.id()),
+            ListSort::Name => items.sort_by_cached_key(|item| item.name().to_lowercase()),
+            ListSort::Amount => items.sort_by_key(|item| Reverse(item.amount())),
+        }
+        items
+    }
+
+    /// Returns the displayed item at `index`.
+    #[must_use]
+    pub fn visible_item(&self, index: usize) -> Option<&T> {
+        self.visible().get(index).copied()
+    }
+
+    /// Switches to the next sort column, wrapping back to the default order.
+    pub fn cycle_sort(&mut self) {
+        let position = T::SORT_COLUMNS.iter().position(|&c| c == self.sort);
+        self.sort = match position {
+            None => T::SORT_COLUMNS.first().copied().unwrap_or_default(),
+            Some(i) => T::SORT_COLUMNS.get(i + 1).copied().unwrap_or_default(),
+        };
+    }
+
+    /// Marks the list as loading and returns the address and token for its
+    /// next page, if the selection is near the end of the displayed items.
+    pub fn next_page_request(list: &mut Option<Self>, selected: usize) -> Option<(String, String)> {
+        let list = list.as_mut()?;
+        if list.loading || selected + LOAD_MORE_THRESHOLD < list.visible().len() {
+            return None;
+        }
+        let next_token = list.next_token.clone()?;
+        list.loading = true;
+        Some((list.address.clone(), next_token))
+    }
+}
+
+/// A fetched page for one of the account lists.
+#[derive(Debug, Clone, PartialEq)]
+pub enum AccountListPage {
+    /// Asset holdings.
+    Assets(Page<AccountAssetHolding>),
+    /// Assets created by the account.
+    CreatedAssets(Page<CreatedAssetInfo>),
+    /// Applications the account has opted into.
+    Apps(Page<AppLocalState>),
+    /// Applications created by the account.
+    CreatedApps(Page<CreatedAppInfo>),
+}
+
+// ============================================================================
+// Application Boxes
+// ============================================================================
+
+/// Paged box listing for the application shown in the application popup.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct AppBoxes {
+    /// The application the boxes belong to.
+    pub app_id: u64,
+    /// Boxes loaded so far, in indexer order.
+    pub boxes: Vec<AppBox>,
+    /// Indexer token for the next page, if more remain.
+    pub next_token: Option<String>,
+    /// Whether a page request is in flight.
+    pub loading: bool,
+    /// Name of the box whose value is being fetched, if any.
+    pub value_loading: Option<String>,
+}
+
+impl AppBoxes {
+    /// Creates an empty box listing for an application, marked as loading.
+    #[must_use]
+    pub fn new(app_id: u64) -> Self {
+        Self {
+            app_id,
+            loading: true,
+            ..Self::default()
+        }
+    }
+
+    /// Appends a fetched page and clears the loading flag.
+    pub fn append_page(&mut self, page: Page<AppBox>) {
+        self.boxes.extend(page.items);
+        self.next_token = page.next_token;
+        self.loading = false;
+    }
+
+    /// Returns true if more boxes remain to be fetched.
+    #[must_use]
+    pub fn has_more(&self) -> bool {
+        self.next_token.is_some()
+    }
+
+    /// Stores a fetched value on the box with the given name.
+    pub fn set_value(&mut self, name: &str, value: String) {
+        if let Some(app_box) = self.boxes.iter_mut().find(|b| b.name == name) {
+            app_box.value = Some(value);
+        }
+        if self.value_loading.as_deref() == Some(name) {
+            self.value_loading = None;
+        }
+    }
+}
+
+// ============================================================================
+// Asset Holders
+// ============================================================================
+
+/// Paged holder listing for the asset shown in the asset popup.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct AssetHolders {
+    /// The asset the holders belong to.
+    pub asset_id: u64,
+    /// Minimum balance filter in whole units (`None` = all holders, including zero).
+    pub min_units: Option<u64>,
+    /// Holders loaded so far, in indexer order.
+    pub holders: Vec<AssetHolder>,
+    /// Indexer token for the next page, if more remain.
+    pub next_token: Option<String>,
+    /// Whether a page request is in flight.
+    pub loading: bool,
+}
+
+impl AssetHolders {
+    /// Creates an empty holder listing for an asset and filter, marked as loading.
+    ///
+    /// # Arguments
+    ///
+    /// * `asset_id` - The asset ID
+    /// * `min_units` - Minimum balance filter in whole units
+    #[must_use]
+    pub fn new(asset_id: u64, min_units: Option<u64>) -> Self {
+        Self {
+            asset_id,
+            min_units,
+            loading: true,
+            ..Self::default()
+        }
+    }
+
+    /// Returns true if this listing was requested for the given asset and filter.
+    #[must_use]
+    pub fn matches(&self, asset_id: u64, min_units: Option<u64>) -> bool {
+        self.asset_id == asset_id && self.min_units == min_units
+    }
+
+    /// Returns the filter after `current` in the cycle: all holders, then
+    /// balances above 0, 1, 1,000 and 1,000,000 whole units.
+    #[must_use]
+    pub fn next_filter(current: Option<u64>) -> Option<u64> {
+        const STEPS: [u64; 4] = [0, 1, 1_000, 1_000_000];
+        match current {
+            None => Some(STEPS[0]),
+            Some(units) => STEPS.iter().copied().find(|&step| step > units),
+        }
+    }
+
+    /// Converts a whole-unit threshold to base units for the indexer query.
+    #[must_use]
+    pub fn base_units(units: u64, decimals: u64) -> u64 {
+        u32::try_from(decimals)
+            .ok()
+            .and_then(|d| 10u64.checked_pow(d))
+            .map_or(u64::MAX, |scale| units.saturating_mul(scale))
+    }
+
+    /// Appends a fetched page and clears the loading flag.
+    pub fn append_page(&mut self, page: Page<AssetHolder>) {
+        self.holders.extend(page.items);
+        self.next_token = page.next_token;
+        self.loading = false;
+    }
+
+    /// Returns true if more holders remain to be fetched.
+    #[must_use]
+    pub fn has_more(&self) -> bool {
+        self.next_token.is_some()
+    }
+
+    /// Sum of the `n` largest loaded balances, in base units.
+    ///
+    /// Only covers holders loaded so far; the indexer lists holders by
+    /// address, not by balance.
+    #[must_use]
+    pub fn top_holdings(&self, n: usize) -> u128 {
+        let mut amounts: Vec<u64> = self.holders.iter().map(|h| h.amount).collect();
+        amounts.sort_unstable_by(|a, b| b.cmp(a));
+        amounts.iter().take(n).map(|&a| u128::from(a)).sum()
+    }
+}
+
+// ============================================================================
+// Account Local State
+// ============================================================================
+
+/// One account's local state for one application.
+///
+/// Shown in the account popup's Apps tab and, after a lookup, in the
+/// application popup's State tab.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct LocalStateView {
+    /// The account address.
+    pub address: String,
+    /// The application ID.
+    pub app_id: u64,
+    /// Decoded key/value pairs, once loaded.
+    pub entries: Vec<AppStateValue>,
+    /// Whether the request is in flight.
+    pub loading: bool,
+    /// Why the state could not be loaded (e.g. the account is not opted in).
+    pub error: Option<String>,
+}
+
+impl LocalStateView {
+    /// Creates an empty view for an account and application, marked as loading.
+    #[must_use]
+    pub fn new(address: &str, app_id: u64) -> Self {
+        Self {
+            address: address.to_string(),
+            app_id,
+            loading: true,
+            ..Self::default()
+        }
+    }
+
+    /// Returns true if this view was requested for the given account and application.
+    #[must_use]
+    pub fn matches(&self, address: &str, app_id: u64) -> bool {
+        self.address == address && self.app_id == app_id
+    }
+}
+
 // ============================================================================
 // Data State
 // ============================================================================
@@ -44,6 +656,12 @@ pub struct DataState {
     pub blocks: Vec<AlgoBlock>,
     /// List of recent transactions.
     pub transactions: Vec<Transaction>,
+    /// Filter applied to the live transaction feed, kept for the session.
+    pub txn_filter: Option<TxnFilter>,
+    /// Results of the last advanced transaction search.
+    pub txn_search: Option<TxnSearch>,
+    /// The open pending pool view.
+    pub pending_pool: Option<PendingPool>,
 
     // === Search Results ===
     /// Filtered search results with their original indices.
@@ -52,14 +670,54 @@ pub struct DataState {
     // === Detail View Data ===
     /// Currently loaded block details (for block details popup).
     pub block_details: Option<BlockDetails>,
+    /// Whether the next page of block transactions is being fetched.
+    pub block_txns_loading_more: bool,
     /// Currently viewed transaction details (for transaction details popup).
     pub viewed_transaction: Option<Transaction>,
     /// Currently viewed account details (for account details popup).
     pub viewed_account: Option<AccountDetails>,
+    /// Transaction history for the viewed account (History tab).
+    pub account_history: Option<AccountHistory>,
+    /// Asset holdings of the viewed account (Assets tab).
+    pub account_assets: Option<AccountList<AccountAssetHolding>>,
+    /// Assets created by the viewed account (Created Assets tab).
+    pub account_created_assets: Option<AccountList<CreatedAssetInfo>>,
+    /// Applications the viewed account has opted into (Apps tab).
+    pub account_apps: Option<AccountList<AppLocalState>>,
+    /// Applications created by the viewed account (Created Apps tab).
+    pub account_created_apps: Option<AccountList<CreatedAppInfo>>,
     /// Currently viewed asset details (for asset details popup).
     pub viewed_asset: Option<AssetDetails>,
+    /// Holder listing for the viewed asset (Holders tab).
+    pub asset_holders: Option<AssetHolders>,
     /// Currently viewed application details (for application details popup).
     pub viewed_application: Option<ApplicationDetails>,
+    /// Box listing for the viewed application (Boxes tab).
+    pub app_boxes: Option<AppBoxes>,
+    /// Local state of one account in one application.
+    pub local_state: Option<LocalStateView>,
+    /// Currently viewed atomic group (for group details popup).
+    pub viewed_group: Option<TxnGroup>,
+
+    // === Simulation ===
+    /// Result of the last transaction group simulation.
+    pub simulation: Option<Simulation>,
+    /// The file path or base64 group last simulated, to prefill the prompt.
+    pub simulate_input: String,
+    /// Whether a simulation request is in flight.
+    pub simulating: bool,
+
+    // === Watchlist ===
+    /// Watched entities of the current network with their latest polled state.
+    pub watchlist: Vec<WatchStatus>,
+
+    // === Export ===
+    /// Whether a list export is running.
+    pub exporting: bool,
+
+    // === Network ===
+    /// Where chain history is read from, as last reported by the network check.
+    pub data_source: DataSource,
 }
 
 impl DataState {
@@ -82,10 +740,102 @@ impl DataState {
         self.transactions.clear();
         self.filtered_search_results.clear();
         self.block_details = None;
+        self.block_txns_loading_more = false;
         self.viewed_transaction = None;
         self.viewed_account = None;
+        self.account_history = None;
+        self.clear_account_lists();
         self.viewed_asset = None;
+        self.asset_holders = None;
         self.viewed_application = None;
+        self.app_boxes = None;
+        self.local_state = None;
+        self.viewed_group = None;
+        self.txn_search = None;
+        self.pending_pool = None;
+        self.simulation = None;
+        self.watchlist.clear();
+        self.data_source = DataSource::default();
+    }
+
+    // ========================================================================
+    // Account List Operations
+    // ========================================================================
+
+    /// Drops all paged account lists.
+    pub fn clear_account_lists(&mut self) {
+        self.account_assets = None;
+        self.account_created_assets = None;
+        self.account_apps = None;
+        self.account_created_apps = None;
+    }
+
+    /// Appends a fetched page to the matching list if it still belongs to `address`.
+    pub fn append_account_list_page(&mut self, address: &str, page: AccountListPage) {
+        fn append<T: AccountListEntry>(
+            list: &mut Option<AccountList<T>>,
+            address: &str,
+            page: Page<T>,
+        ) {
+            if let Some(list) = list
+                && list.address == address
+            {
+                list.append_page(page);
+            }
+        }
+        match page {
+            AccountListPage::Assets(page) => append(&mut self.account_assets, address, page),
+            AccountListPage::CreatedAssets(page) => {
+                append(&mut self.account_created_assets, address, page);
+            }
+            AccountListPage::Apps(page) => append(&mut self.account_apps, address, page),
+            AccountListPage::CreatedApps(page) => {
+                append(&mut self.account_created_apps, address, page);
+            }
+        }
+    }
+
+    /// Clears the loading flag on the list shown in `tab`.
+    pub fn stop_account_list_loading(&mut self, tab: AccountDetailTab) {
+        let loading = match tab {
+            AccountDetailTab::Assets => self.account_assets.as_mut().map(|l| &mut l.loading),
+            AccountDetailTab::CreatedAssets => {
+                self.account_created_assets.as_mut().map(|l| &mut l.loading)
+            }
+            AccountDetailTab::Apps => self.account_apps.as_mut().map(|l| &mut l.loading),
+            AccountDetailTab::CreatedApps => {
+                self.account_created_apps.as_mut().map(|l| &mut l.loading)
+            }
+            AccountDetailTab::Info | AccountDetailTab::History => None,
+        };
+        if let Some(loading) = loading {
+            *loading = false;
+        }
+    }
+
+    /// Number of displayed items in the list shown in `tab`.
+    #[must_use]
+    pub fn account_list_len(&self, tab: AccountDetailTab) -> usize {
+        match tab {
+            AccountDetailTab::Assets => self
+                .account_assets
+                .as_ref()
+                .map_or(0, |l| l.visible().len()),
+            AccountDetailTab::CreatedAssets => self
+                .account_created_assets
+                .as_ref()
+                .map_or(0, |l| l.visible().len()),
+            AccountDetailTab::Apps => self.account_apps.as_ref().map_or(0, |l| l.visible().len()),
+            AccountDetailTab::CreatedApps => self
+                .account_created_apps
+                .as_ref()
+                .map_or(0, |l| l.visible().len()),
+            AccountDetailTab::History => self
+                .account_history
+                .as_ref()
+                .map_or(0, |h| h.transactions.len()),
+            AccountDetailTab::Info => 0,
+        }
     }
 
     // ========================================================================
@@ -106,10 +856,62 @@ impl DataState {
         self.blocks.iter().position(|b| b.id == block_id)
     }
 
+    // ========================================================================
+    // Watchlist Operations
+    // ========================================================================
+
+    /// Replaces the watched targets, keeping the polled state of targets
+    /// that are still watched.
+    pub fn set_watchlist(&mut self, targets: &[WatchTarget]) {
+        let mut previous = std::mem::take(&mut self.watchlist);
+        self.watchlist = targets
+            .iter()
+            .map(|target| {
+                previous
+                    .iter()
+                    .position(|s| s.target == *target)
+                    .map_or_else(
+                        || WatchStatus::new(target.clone()),
+                        |pos| previous.swap_remove(pos),
+                    )
+            })
+            .collect();
+    }
+
+    /// Applies freshly polled statuses to the entries that are still watched.
+    pub fn update_watch_statuses(&mut self, statuses: Vec<WatchStatus>) {
+        for status in statuses {
+            if let Some(entry) = self
+                .watchlist
+                .iter_mut()
+                .find(|entry| entry.target == status.target)
+            {
+                *entry = status;
+            }
+        }
+    }
+
     // ========================================================================
     // Transaction Operations
     // ========================================================================
 
+    /// Sets or clears the live feed filter, dropping transactions that no
+    /// longer match.
+    pub fn set_txn_filter(&mut self, filter: Option<TxnFilter>) {
+        if let Some(filter) = &filter {
+            self.transactions.retain(|txn| filter.matches(txn));
+        }
+        self.txn_filter = filter;
+    }
+
+    /// Returns true if a transaction passes the live feed filter.
+    #[must_use]
+    pub fn txn_filter_matches(&self, txn: &Transaction) -> bool {
+        self.txn_filter
+            .as_ref()
+            .is_none_or(|filter| filter.matches(txn))
+    }
+
     /// Finds a transaction index by its ID.
     ///
     /// # Arguments
@@ -159,4 +961,185 @@ mod tests {
         assert_eq!(data.find_transaction_index("tx3"), Some(2));
         assert_eq!(data.find_transaction_index("tx4"), None);
     }
+
+    #[test]
+    fn test_watchlist_keeps_polled_state() {
+        let mut data = DataState::new();
+        data.set_watchlist(&[WatchTarget::Asset(1), WatchTarget::App(2)]);
+        data.update_watch_statuses(vec![
+            WatchStatus {
+                balance: Some(5),
+                ..WatchStatus::new(WatchTarget::App(2))
+            },
+            // Removed before the poll finished
+            WatchStatus::new(WatchTarget::Account("GONE".into())),
+        ]);
+        assert_eq!(data.watchlist.len(), 2);
+        assert_eq!(data.watchlist[1].balance, Some(5));
+
+        data.set_watchlist(&[WatchTarget::App(2), WatchTarget::Asset(3)]);
+        let targets: Vec<_> = data.watchlist.iter().map(|s| s.target.clone()).collect();
+        assert_eq!(targets, vec![WatchTarget::App(2), WatchTarget::Asset(3)]);
+        assert_eq!(data.watchlist[0].balance, Some(5));
+        assert_eq!(data.watchlist[1], WatchStatus::new(WatchTarget::Asset(3)));
+    }
+
+    #[test]
+    fn test_account_list_sort_and_zero_filter() {
+        let holding = |asset_id, name: &str, amount| AccountAssetHolding {
+            asset_id,
+            amount,
+            name: name.to_string(),
+            ..AccountAssetHolding::default()
+        };
+        let mut list = Some(AccountList::new("ADDR"));
+        assert!(!AccountList::<AccountAssetHolding>::reset_if_stale(
+            &mut list, "ADDR"
+        ));
+        let list_ref = list.as_mut().unwrap();
+        list_ref.append_page(Page::new(
+            vec![
+                holding(3, "beta", 0),
+                holding(1, "Gamma", 50),
+                holding(2, "alpha", 900),
+            ],
+            Some("next".to_string()),
+        ));
+
+        let ids = |list: &AccountList<AccountAssetHolding>| {
+            list.visible()
+                .iter()
+                .map(|h| h.asset_id)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(ids(list_ref), vec![3, 1, 2]);
+        list_ref.cycle_sort();
+        assert_eq!(list_ref.sort, ListSort::Id);
+        assert_eq!(ids(list_ref), vec![1, 2, 3]);
+        list_ref.cycle_sort();
+        assert_eq!(ids(list_ref), vec![2, 3, 1]);
+        list_ref.cycle_sort();
+        assert_eq!(ids(list_ref), vec![2, 1, 3]);
+        list_ref.hide_zero = true;
+        assert_eq!(ids(list_ref), vec![2, 1]);
+        list_ref.cycle_sort();
+        assert_eq!(list_ref.sort, ListSort::Default);
+
+        // Near the end of the list, the next page is requested once
+        let request = AccountList::next_page_request(&mut list, 0);
+        assert_eq!(request, Some(("ADDR".to_string(), "next".to_string())));
+        assert_eq!(AccountList::next_page_request(&mut list, 0), None);
+
+        assert!(AccountList::reset_if_stale(&mut list, "OTHER"));
+        assert!(list.as_ref().unwrap().items.is_empty());
+    }
+
+    #[test]
+    fn test_asset_holders_paging_and_top_holdings() {
+        let holder = |address: &str, amount| AssetHolder {
+            address: address.to_string(),
+            amount,
+            ..AssetHolder::default()
+        };
+
+        let mut holders = AssetHolders::new(7, Some(0));
+        assert!(holders.loading);
+        assert!(holders.matches(7, Some(0)));
+        assert!(!holders.matches(7, None));
+
+        holders.append_page(Page::new(
+            vec![holder("A", 10), holder("B", 300)],
+            Some("B".to_string()),
+        ));
+        assert!(!holders.loading);
+        assert!(holders.has_more());
+
+        holders.append_page(Page::new(
+            vec![holder("C", 50), holder("D", u64::MAX)],
+            None,
+        ));
+        assert!(!holders.has_more());
+        assert_eq!(holders.holders.len(), 4);
+        assert_eq!(holders.top_holdings(2), u128::from(u64::MAX) + 300);
+        assert_eq!(holders.top_holdings(10), u128::from(u64::MAX) + 360);
+    }
+
+    #[test]
+    fn test_asset_holder_filter_cycle() {
+        let mut filter = None;
+        let mut seen = Vec::new();
+        for _ in 0..6 {
+            filter = AssetHolders::next_filter(filter);
+            seen.push(filter);
+        }
+        assert_eq!(
+            seen,
+            vec![
+                Some(0),
+                Some(1),
+                Some(1_000),
+                Some(1_000_000),
+                None,
+                Some(0)
+            ]
+        );
+
+        assert_eq!(AssetHolders::base_units(0, 6), 0);
+        assert_eq!(AssetHolders::base_units(1_000, 6), 1_000_000_000);
+        assert_eq!(AssetHolders::base_units(1, 0), 1);
+        assert_eq!(AssetHolders::base_units(1_000_000, 19), u64::MAX);
+    }
+
+    #[test]
+    fn test_pending_pool_update() {
+        let mut pool = PendingPool::new(PoolScope::Account("ADDR".to_string()));
+        assert!(pool.loading);
+        assert_eq!(pool.scope.address(), Some("ADDR"));
+        assert!(pool.matches(&PoolScope::Account("ADDR".to_string())));
+        assert!(!pool.matches(&PoolScope::All));
+
+        pool.update(
+            vec![
+                TransactionMother::payment("p1"),
+                TransactionMother::payment("p2"),
+            ],
+            2,
+            Vec::new(),
+        );
+        assert!(!pool.loading);
+        assert_eq!(pool.len(), 2);
+
+        // A confirmed transaction moves below the pending ones
+        pool.update(
+            vec![TransactionMother::payment("p2")],
+            1,
+            vec![TransactionMother::payment("p1")],
+        );
+        assert_eq!(pool.len(), 2);
+        assert_eq!(
+            pool.get(0).map(|(txn, c)| (txn.id.as_str(), c)),
+            Some(("p2", false))
+        );
+        assert_eq!(
+            pool.get(1).map(|(txn, c)| (txn.id.as_str(), c)),
+            Some(("p1", true))
+        );
+        assert!(pool.get(2).is_none());
+        assert_eq!(pool.position("p1"), Some(1));
+
+        // Newest confirmations first, capped
+        for i in 0..PENDING_POOL_CONFIRMED {
+            pool.update(
+                Vec::new(),
+                0,
+                vec![TransactionMother::payment(&format!("c{i}"))],
+            );
+        }
+        assert_eq!(pool.confirmed.len(), PENDING_POOL_CONFIRMED);
+        assert_eq!(
+            pool.confirmed[0].id,
+            format!("c{}", PENDING_POOL_CONFIRMED - 1)
+        );
+        assert!(!pool.confirmed.iter().any(|txn| txn.id == "p1"));
+    }
 }
@@ -33,7 +33,8 @@
 use tokio::sync::{mpsc, watch};
 
 use crate::client::AlgoClient;
-use crate::domain::{Network, NetworkConfig};
+use crate::domain::{Network, NetworkConfig, TxnFilter, WatchTarget};
+use crate::keymap::Keymap;
 
 // ============================================================================
 // Module Declarations
@@ -55,11 +56,15 @@ pub use app_lifecycle::prefetch_initial_data;
 
 // Navigation types
 pub use navigation::{
-    AccountDetailTab, AppDetailTab, BlockDetailTab, DetailViewMode, NavigationState,
+    AccountDetailTab, AppDetailTab, AssetDetailTab, BlockDetailTab, DetailViewMode, GroupDetailTab,
+    NavigationState, SimulationTab,
 };
 
 // Data types
-pub use data::DataState;
+pub use data::{
+    AccountHistory, AccountList, AccountListEntry, AccountListPage, AppBoxes, AssetHolders,
+    DataState, LocalStateView, PendingPool, PoolScope, TxnSearch,
+};
 
 // UI state types
 pub use ui_state::{Focus, PopupState, SearchType, UiState};
@@ -85,12 +90,21 @@ pub enum AppMessage {
     SearchCompleted(Result<Vec<crate::domain::SearchResultItem>, String>),
     /// Network error occurred.
     NetworkError(String),
-    /// Network connection established.
-    NetworkConnected,
+    /// Network connection established, reading history from the given source.
+    NetworkConnected(crate::domain::DataSource),
     /// Network switch completed successfully.
     NetworkSwitchComplete,
     /// Block details loaded.
     BlockDetailsLoaded(crate::domain::BlockDetails),
+    /// Another page of a block's transactions loaded.
+    BlockTransactionsPageLoaded {
+        /// The round the page belongs to.
+        round: u64,
+        /// The fetched page.
+        page: crate::domain::Page<crate::domain::Transaction>,
+    },
+    /// Fetching another page of a block's transactions failed.
+    BlockTransactionsPageFailed(String),
     /// Transaction details loaded.
     TransactionDetailsLoaded(Box<crate::domain::Transaction>),
     /// Transaction details fetch failed.
@@ -99,14 +113,134 @@ pub enum AppMessage {
     AccountDetailsLoaded(Box<crate::domain::AccountDetails>),
     /// Account details fetch failed.
     AccountDetailsFailed(String),
+    /// A page of account transaction history loaded.
+    AccountHistoryLoaded {
+        /// The account the page belongs to.
+        address: String,
+        /// The type filter the page was requested with.
+        filter: Option<crate::domain::TxnType>,
+        /// The fetched page.
+        page: crate::domain::Page<crate::domain::Transaction>,
+    },
+    /// Account transaction history fetch failed.
+    AccountHistoryFailed(String),
+    /// A page of one of the account asset or app lists loaded.
+    AccountListLoaded {
+        /// The account the page belongs to.
+        address: String,
+        /// The fetched page.
+        page: AccountListPage,
+    },
+    /// Fetching an account asset or app list failed.
+    AccountListFailed {
+        /// The tab whose list failed to load.
+        tab: AccountDetailTab,
+        /// The error message.
+        error: String,
+    },
     /// Asset details loaded.
     AssetDetailsLoaded(Box<crate::domain::AssetDetails>),
     /// Asset details fetch failed.
     AssetDetailsFailed(String),
+    /// A page of asset holders loaded.
+    AssetHoldersLoaded {
+        /// The asset the page belongs to.
+        asset_id: u64,
+        /// The minimum balance filter (whole units) the page was requested with.
+        min_units: Option<u64>,
+        /// The fetched page.
+        page: crate::domain::Page<crate::domain::AssetHolder>,
+    },
+    /// Asset holder listing fetch failed.
+    AssetHoldersFailed(String),
     /// Application details loaded.
     ApplicationDetailsLoaded(Box<crate::domain::ApplicationDetails>),
     /// Application details fetch failed.
     ApplicationDetailsFailed(String),
+    /// A page of application box names loaded.
+    AppBoxesLoaded {
+        /// The application the page belongs to.
+        app_id: u64,
+        /// The fetched page.
+        page: crate::domain::Page<crate::domain::AppBox>,
+    },
+    /// Application box listing fetch failed.
+    AppBoxesFailed(String),
+    /// A box value loaded.
+    AppBoxValueLoaded {
+        /// The application the box belongs to.
+        app_id: u64,
+        /// The box name (Base64).
+        name: String,
+        /// The box value (Base64).
+        value: String,
+    },
+    /// Box value fetch failed.
+    AppBoxValueFailed(String),
+    /// An account's local state for an application loaded.
+    LocalStateLoaded {
+        /// The account address.
+        address: String,
+        /// The application ID.
+        app_id: u64,
+        /// The decoded key/value pairs.
+        entries: Vec<crate::domain::AppStateValue>,
+    },
+    /// Local state fetch failed (or the account is not opted in).
+    LocalStateFailed {
+        /// The account address.
+        address: String,
+        /// The application ID.
+        app_id: u64,
+        /// The error message.
+        error: String,
+    },
+    /// All members of an atomic group loaded.
+    GroupLoaded(crate::domain::TxnGroup),
+    /// Atomic group fetch failed.
+    GroupFailed(String),
+    /// A page of advanced transaction search results loaded.
+    TxnSearchLoaded {
+        /// The query the page was requested for.
+        query: crate::domain::TxnQuery,
+        /// The fetched page.
+        page: crate::domain::Page<crate::domain::Transaction>,
+    },
+    /// Advanced transaction search failed.
+    TxnSearchFailed(String),
+    /// A list export wrote another page of records.
+    ExportProgress {
+        /// Records written so far.
+        rows: usize,
+    },
+    /// A list export finished.
+    ExportFinished {
+        /// The file written.
+        path: String,
+        /// Records written.
+        rows: usize,
+    },
+    /// A list export failed.
+    ExportFailed(String),
+    /// Latest polled state of the watchlist entries.
+    WatchlistUpdated(Vec<crate::domain::WatchStatus>),
+    /// Latest poll of algod's transaction pool.
+    PendingPoolUpdated {
+        /// The part of the pool that was polled.
+        scope: PoolScope,
+        /// Transactions still in the pool, in priority order.
+        pending: Vec<crate::domain::Transaction>,
+        /// Total number of transactions in the pool for the scope.
+        total: u64,
+        /// Transactions confirmed since the previous poll.
+        confirmed: Vec<crate::domain::Transaction>,
+    },
+    /// Polling algod's transaction pool failed.
+    PendingPoolFailed(String),
+    /// A transaction group simulation finished.
+    SimulationLoaded(Box<crate::domain::Simulation>),
+    /// algod could not simulate the group.
+    SimulationFailed(String),
 }
 
 // ============================================================================
@@ -124,6 +258,8 @@ pub enum StartupSearch {
     Block(u64),
     /// Search for an asset by ID.
     Asset(u64),
+    /// Simulate a transaction group from a file or base64.
+    Simulation(String),
 }
 
 /// Options that can be passed when starting the application.
@@ -202,6 +338,9 @@ pub struct App {
     /// Animation tick counter for UI animations.
     pub animation_tick: u64,
 
+    /// Active keybindings (defaults plus config overrides).
+    pub keymap: Keymap,
+
     // ========================================================================
     // Async Communication Channels
     // ========================================================================
@@ -220,6 +359,15 @@ pub struct App {
     /// Watch channel for network changes.
     pub(crate) network_tx: watch::Sender<NetworkConfig>,
 
+    /// Watch channel for watchlist changes.
+    pub(crate) watchlist_tx: watch::Sender<Vec<WatchTarget>>,
+
+    /// Watch channel for live transaction feed filter changes.
+    pub(crate) txn_filter_tx: watch::Sender<Option<TxnFilter>>,
+
+    /// Watch channel for the pending pool view; `None` while it is closed.
+    pub(crate) pending_pool_tx: watch::Sender<Option<PoolScope>>,
+
     // ========================================================================
     // Network Client
     // ========================================================================
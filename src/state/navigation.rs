@@ -81,28 +81,68 @@ impl BlockDetailTab {
 
 /// The tab in the account details popup.
 ///
-/// Account details can show general info, asset holdings, or application opt-ins.
+/// Account details can show general info, asset holdings, created assets,
+/// application opt-ins, created applications, or the account's transaction
+/// history.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum AccountDetailTab {
     /// General account information (balance, status, etc.).
     #[default]
     Info,
-    /// Asset holdings and created assets.
+    /// Asset holdings.
     Assets,
-    /// Application opt-ins and created apps.
+    /// Assets created by the account.
+    CreatedAssets,
+    /// Application opt-ins with the selected app's local state.
     Apps,
+    /// Applications created by the account.
+    CreatedApps,
+    /// Transaction history from the indexer.
+    History,
 }
 
 impl AccountDetailTab {
+    /// All tabs in display order.
+    pub const ALL: [Self; 6] = [
+        Self::Info,
+        Self::Assets,
+        Self::CreatedAssets,
+        Self::Apps,
+        Self::CreatedApps,
+        Self::History,
+    ];
+
     /// Cycles to the next tab.
     #[must_use]
     pub const fn next(self) -> Self {
         match self {
             Self::Info => Self::Assets,
-            Self::Assets => Self::Apps,
-            Self::Apps => Self::Info,
+            Self::Assets => Self::CreatedAssets,
+            Self::CreatedAssets => Self::Apps,
+            Self::Apps => Self::CreatedApps,
+            Self::CreatedApps => Self::History,
+            Self::History => Self::Info,
+        }
+    }
+
+    /// Returns the label shown in the tab bar.
+    #[must_use]
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::Info => "Info",
+            Self::Assets => "Assets",
+            Self::CreatedAssets => "Created Assets",
+            Self::Apps => "Apps",
+            Self::CreatedApps => "Created Apps",
+            Self::History => "History",
         }
     }
+
+    /// Returns the tab's position in [`Self::ALL`].
+    #[must_use]
+    pub fn index(self) -> usize {
+        Self::ALL.iter().position(|&tab| tab == self).unwrap_or(0)
+    }
 }
 
 // ============================================================================
@@ -111,7 +151,7 @@ impl AccountDetailTab {
 
 /// The tab in the application details popup.
 ///
-/// Application details can show general info, state, or programs.
+/// Application details can show general info, state, boxes, or programs.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum AppDetailTab {
     /// General application information (ID, creator, schemas).
@@ -119,6 +159,8 @@ pub enum AppDetailTab {
     Info,
     /// Global state key-value pairs.
     State,
+    /// Box storage names and values.
+    Boxes,
     /// Program information (approval/clear programs).
     Programs,
 }
@@ -129,12 +171,88 @@ impl AppDetailTab {
     pub const fn next(self) -> Self {
         match self {
             Self::Info => Self::State,
-            Self::State => Self::Programs,
+            Self::State => Self::Boxes,
+            Self::Boxes => Self::Programs,
             Self::Programs => Self::Info,
         }
     }
 }
 
+// ============================================================================
+// Asset Detail Tab
+// ============================================================================
+
+/// The tab in the asset details popup.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AssetDetailTab {
+    /// Asset parameters (name, supply, roles).
+    #[default]
+    Info,
+    /// Accounts holding the asset.
+    Holders,
+}
+
+impl AssetDetailTab {
+    /// Cycles to the next tab.
+    #[must_use]
+    pub const fn next(self) -> Self {
+        match self {
+            Self::Info => Self::Holders,
+            Self::Holders => Self::Info,
+        }
+    }
+}
+
+// ============================================================================
+// Group Detail Tab
+// ============================================================================
+
+/// The tab in the atomic group details popup.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum GroupDetailTab {
+    /// Group totals and the list of member transactions.
+    #[default]
+    Members,
+    /// Flow graph of the whole group.
+    Graph,
+}
+
+impl GroupDetailTab {
+    /// Cycles to the next tab.
+    #[must_use]
+    pub const fn next(self) -> Self {
+        match self {
+            Self::Members => Self::Graph,
+            Self::Graph => Self::Members,
+        }
+    }
+}
+
+// ============================================================================
+// Simulation Tab
+// ============================================================================
+
+/// The tab in the simulation popup.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SimulationTab {
+    /// Outcome, budget and the list of simulated transactions.
+    #[default]
+    Result,
+    /// Flow graph of the simulated group.
+    Graph,
+}
+
+impl SimulationTab {
+    /// Cycles to the next tab.
+    #[must_use]
+    pub const fn next(self) -> Self {
+        match self {
+            Self::Result => Self::Graph,
+            Self::Graph => Self::Result,
+        }
+    }
+}
+
 // ============================================================================
 // Detail Popup Stack
 // ============================================================================
@@ -171,6 +289,14 @@ pub enum DetailPopupType {
     Asset,
     /// Application details popup.
     Application,
+    /// Atomic group details popup.
+    Group,
+    /// Advanced transaction search results.
+    TxnSearch,
+    /// Pending transaction pool.
+    PendingPool,
+    /// Transaction group simulation.
+    Simulation,
 }
 
 // ============================================================================
@@ -203,6 +329,8 @@ pub struct NavigationState {
     pub block_scroll: u16,
     /// Scroll position for transactions list (in rows).
     pub transaction_scroll: u16,
+    /// Scroll position for the watchlist (in rows).
+    pub watch_scroll: u16,
 
     // === Selection State ===
     /// Currently selected block index in the blocks list.
@@ -213,6 +341,8 @@ pub struct NavigationState {
     pub selected_block_id: Option<u64>,
     /// The transaction ID of the currently selected transaction (for stable selection).
     pub selected_transaction_id: Option<String>,
+    /// Currently selected entry in the watchlist.
+    pub selected_watch_index: Option<usize>,
 
     // === Detail View Flags ===
     /// Whether the block details popup is shown.
@@ -225,6 +355,14 @@ pub struct NavigationState {
     pub show_asset_details: bool,
     /// Whether the application details popup is shown.
     pub show_application_details: bool,
+    /// Whether the atomic group details popup is shown.
+    pub show_group_details: bool,
+    /// Whether the advanced search results popup is shown.
+    pub show_txn_search: bool,
+    /// Whether the pending transaction pool popup is shown.
+    pub show_pending_pool: bool,
+    /// Whether the simulation popup is shown.
+    pub show_simulation: bool,
 
     // === Block Detail View State ===
     /// Current tab in block details popup.
@@ -247,9 +385,45 @@ pub struct NavigationState {
     pub app_detail_tab: AppDetailTab,
     /// Selected item index within application state list.
     pub app_state_index: Option<usize>,
-    /// Scroll position for application state list.
+    /// Scroll position for application state list or program listing.
     pub app_state_scroll: u16,
 
+    // === Asset Detail View State ===
+    /// Current tab in asset details popup.
+    pub asset_detail_tab: AssetDetailTab,
+    /// Selected holder index within the Holders tab.
+    pub asset_holder_index: Option<usize>,
+    /// Scroll position for the holder list.
+    pub asset_holder_scroll: u16,
+
+    // === Group Detail View State ===
+    /// Current tab in group details popup.
+    pub group_detail_tab: GroupDetailTab,
+    /// Selected member index within group details.
+    pub group_txn_index: Option<usize>,
+    /// Scroll position for the group member list.
+    pub group_txn_scroll: u16,
+
+    // === Search Results View State ===
+    /// Selected transaction index within the advanced search results.
+    pub txn_search_index: Option<usize>,
+    /// Scroll position for the search results (in items).
+    pub txn_search_scroll: u16,
+
+    // === Pending Pool View State ===
+    /// Selected transaction index within the pending pool.
+    pub pending_pool_index: Option<usize>,
+    /// Scroll position for the pending pool (in items).
+    pub pending_pool_scroll: u16,
+
+    // === Simulation View State ===
+    /// Current tab in the simulation popup.
+    pub simulation_tab: SimulationTab,
+    /// Selected transaction index within the simulated group.
+    pub simulation_txn_index: Option<usize>,
+    /// Scroll position for the simulated transaction list.
+    pub simulation_txn_scroll: u16,
+
     // === Graph View State ===
     /// Horizontal scroll offset for transaction graph view.
     pub graph_scroll_x: u16,
@@ -290,15 +464,21 @@ impl NavigationState {
     pub fn reset(&mut self) {
         self.block_scroll = 0;
         self.transaction_scroll = 0;
+        self.watch_scroll = 0;
         self.selected_block_index = None;
         self.selected_transaction_index = None;
         self.selected_block_id = None;
         self.selected_transaction_id = None;
+        self.selected_watch_index = None;
         self.show_block_details = false;
         self.show_transaction_details = false;
         self.show_account_details = false;
         self.show_asset_details = false;
         self.show_application_details = false;
+        self.show_group_details = false;
+        self.show_txn_search = false;
+        self.show_pending_pool = false;
+        self.show_simulation = false;
         self.block_detail_tab = BlockDetailTab::default();
         self.block_txn_index = None;
         self.block_txn_scroll = 0;
@@ -308,6 +488,19 @@ impl NavigationState {
         self.app_detail_tab = AppDetailTab::default();
         self.app_state_index = None;
         self.app_state_scroll = 0;
+        self.asset_detail_tab = AssetDetailTab::default();
+        self.asset_holder_index = None;
+        self.asset_holder_scroll = 0;
+        self.group_detail_tab = GroupDetailTab::default();
+        self.group_txn_index = None;
+        self.group_txn_scroll = 0;
+        self.txn_search_index = None;
+        self.txn_search_scroll = 0;
+        self.pending_pool_index = None;
+        self.pending_pool_scroll = 0;
+        self.simulation_tab = SimulationTab::default();
+        self.simulation_txn_index = None;
+        self.simulation_txn_scroll = 0;
         self.graph_scroll_x = 0;
         self.graph_scroll_y = 0;
         self.graph_max_scroll_x = 0;
@@ -333,6 +526,10 @@ impl NavigationState {
             || self.show_account_details
             || self.show_asset_details
             || self.show_application_details
+            || self.show_group_details
+            || self.show_txn_search
+            || self.show_pending_pool
+            || self.show_simulation
     }
 
     /// Closes all detail views.
@@ -342,6 +539,10 @@ impl NavigationState {
         self.show_account_details = false;
         self.show_asset_details = false;
         self.show_application_details = false;
+        self.show_group_details = false;
+        self.show_txn_search = false;
+        self.show_pending_pool = false;
+        self.show_simulation = false;
     }
 
     /// Returns `true` if there are saved popups in the stack.
@@ -354,15 +555,10 @@ impl NavigationState {
     ///
     /// Call this before opening a nested popup from account details.
     pub fn push_account_state(&mut self, address: &str) {
-        let tab_index = match self.account_detail_tab {
-            AccountDetailTab::Info => 0,
-            AccountDetailTab::Assets => 1,
-            AccountDetailTab::Apps => 2,
-        };
         self.popup_stack.push(SavedPopupState {
             popup_type: DetailPopupType::Account,
             entity_id: address.to_string(),
-            tab_index,
+            tab_index: self.account_detail_tab.index(),
             item_index: self.account_item_index,
             item_scroll: self.account_item_scroll,
         });
@@ -377,16 +573,152 @@ impl NavigationState {
 
     /// Restores account detail state from a saved popup state.
     pub fn restore_account_state(&mut self, saved: &SavedPopupState) {
-        self.account_detail_tab = match saved.tab_index {
-            0 => AccountDetailTab::Info,
-            1 => AccountDetailTab::Assets,
-            _ => AccountDetailTab::Apps,
-        };
+        self.account_detail_tab = AccountDetailTab::ALL
+            .get(saved.tab_index)
+            .copied()
+            .unwrap_or_default();
         self.account_item_index = saved.item_index;
         self.account_item_scroll = saved.item_scroll;
         self.show_account_details = true;
     }
 
+    /// Pushes the current asset popup state to the stack.
+    ///
+    /// Call this before opening a holder's account from asset details.
+    pub fn push_asset_state(&mut self, asset_id: u64) {
+        let tab_index = match self.asset_detail_tab {
+            AssetDetailTab::Info => 0,
+            AssetDetailTab::Holders => 1,
+        };
+        self.popup_stack.push(SavedPopupState {
+            popup_type: DetailPopupType::Asset,
+            entity_id: asset_id.to_string(),
+            tab_index,
+            item_index: self.asset_holder_index,
+            item_scroll: self.asset_holder_scroll,
+        });
+    }
+
+    /// Restores asset detail state from a saved popup state.
+    pub fn restore_asset_state(&mut self, saved: &SavedPopupState) {
+        self.asset_detail_tab = match saved.tab_index {
+            0 => AssetDetailTab::Info,
+            _ => AssetDetailTab::Holders,
+        };
+        self.asset_holder_index = saved.item_index;
+        self.asset_holder_scroll = saved.item_scroll;
+        self.show_asset_details = true;
+    }
+
+    /// Pushes the current transaction popup state to the stack.
+    ///
+    /// Call this before opening the atomic group of the viewed transaction.
+    pub fn push_transaction_state(&mut self, txn_id: &str) {
+        self.popup_stack.push(SavedPopupState {
+            popup_type: DetailPopupType::Transaction,
+            entity_id: txn_id.to_string(),
+            tab_index: 0,
+            item_index: self.detail_row_index,
+            item_scroll: self.detail_row_scroll,
+        });
+    }
+
+    /// Pushes the current group popup state to the stack.
+    ///
+    /// Call this before opening a member transaction from group details.
+    pub fn push_group_state(&mut self, group_id: &str) {
+        let tab_index = match self.group_detail_tab {
+            GroupDetailTab::Members => 0,
+            GroupDetailTab::Graph => 1,
+        };
+        self.popup_stack.push(SavedPopupState {
+            popup_type: DetailPopupType::Group,
+            entity_id: group_id.to_string(),
+            tab_index,
+            item_index: self.group_txn_index,
+            item_scroll: self.group_txn_scroll,
+        });
+    }
+
+    /// Pushes the current search results state to the stack.
+    ///
+    /// Call this before opening a transaction from the search results.
+    pub fn push_txn_search_state(&mut self) {
+        self.popup_stack.push(SavedPopupState {
+            popup_type: DetailPopupType::TxnSearch,
+            entity_id: String::new(),
+            tab_index: 0,
+            item_index: self.txn_search_index,
+            item_scroll: self.txn_search_scroll,
+        });
+    }
+
+    /// Restores search results state from a saved popup state.
+    pub fn restore_txn_search_state(&mut self, saved: &SavedPopupState) {
+        self.txn_search_index = saved.item_index;
+        self.txn_search_scroll = saved.item_scroll;
+        self.show_txn_search = true;
+    }
+
+    /// Pushes the current pending pool state to the stack.
+    ///
+    /// Call this before opening a transaction from the pending pool.
+    pub fn push_pending_pool_state(&mut self) {
+        self.popup_stack.push(SavedPopupState {
+            popup_type: DetailPopupType::PendingPool,
+            entity_id: String::new(),
+            tab_index: 0,
+            item_index: self.pending_pool_index,
+            item_scroll: self.pending_pool_scroll,
+        });
+    }
+
+    /// Restores pending pool state from a saved popup state.
+    pub fn restore_pending_pool_state(&mut self, saved: &SavedPopupState) {
+        self.pending_pool_index = saved.item_index;
+        self.pending_pool_scroll = saved.item_scroll;
+        self.show_pending_pool = true;
+    }
+
+    /// Pushes the current simulation popup state to the stack.
+    ///
+    /// Call this before opening a simulated transaction.
+    pub fn push_simulation_state(&mut self) {
+        let tab_index = match self.simulation_tab {
+            SimulationTab::Result => 0,
+            SimulationTab::Graph => 1,
+        };
+        self.popup_stack.push(SavedPopupState {
+            popup_type: DetailPopupType::Simulation,
+            entity_id: String::new(),
+            tab_index,
+            item_index: self.simulation_txn_index,
+            item_scroll: self.simulation_txn_scroll,
+        });
+    }
+
+    /// Restores simulation popup state from a saved popup state.
+    pub fn restore_simulation_state(&mut self, saved: &SavedPopupState) {
+        self.simulation_tab = match saved.tab_index {
+            0 => SimulationTab::Result,
+            _ => SimulationTab::Graph,
+        };
+        self.simulation_txn_index = saved.item_index;
+        self.simulation_txn_scroll = saved.item_scroll;
+        self.show_simulation = true;
+    }
+
+    /// Restores group detail state from a saved popup state.
+    pub fn restore_group_state(&mut self, saved: &SavedPopupState) {
+        self.group_detail_tab = match saved.tab_index {
+            0 => GroupDetailTab::Members,
+            _ => GroupDetailTab::Graph,
+        };
+        self.group_txn_index = saved.item_index;
+        self.group_txn_scroll = saved.item_scroll;
+        self.show_group_details = true;
+    }
+
     // ========================================================================
     // Block Selection
     // ========================================================================
@@ -582,6 +914,22 @@ impl NavigationState {
         }
     }
 
+    /// Scrolls the program listing up by one line.
+    pub fn scroll_app_program_up(&mut self) {
+        self.app_state_scroll = self.app_state_scroll.saturating_sub(1);
+    }
+
+    /// Scrolls the program listing down by one line.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_scroll` - Maximum scroll offset (line count - 1)
+    pub fn scroll_app_program_down(&mut self, max_scroll: u16) {
+        if self.app_state_scroll < max_scroll {
+            self.app_state_scroll += 1;
+        }
+    }
+
     /// Resets app detail view state.
     pub fn reset_app_detail(&mut self) {
         self.app_detail_tab = AppDetailTab::default();
@@ -589,6 +937,256 @@ impl NavigationState {
         self.app_state_scroll = 0;
     }
 
+    // ========================================================================
+    // Asset Detail Navigation
+    // ========================================================================
+
+    /// Cycles the asset detail tab to the next tab.
+    pub fn cycle_asset_detail_tab(&mut self) {
+        self.asset_detail_tab = self.asset_detail_tab.next();
+        // Reset item selection when switching tabs
+        self.asset_holder_index = None;
+        self.asset_holder_scroll = 0;
+    }
+
+    /// Moves the asset holder selection up.
+    pub fn move_asset_holder_up(&mut self) {
+        if let Some(idx) = self.asset_holder_index
+            && idx > 0
+        {
+            self.asset_holder_index = Some(idx - 1);
+            let new_pos = (idx - 1) as u16;
+            if new_pos < self.asset_holder_scroll {
+                self.asset_holder_scroll = new_pos;
+            }
+        }
+    }
+
+    /// Moves the asset holder selection down.
+    ///
+    /// # Arguments
+    ///
+    /// * `max` - Maximum valid index (length - 1)
+    /// * `visible_height` - Number of visible rows in the list area
+    pub fn move_asset_holder_down(&mut self, max: usize, visible_height: u16) {
+        if let Some(idx) = self.asset_holder_index {
+            if idx < max {
+                self.asset_holder_index = Some(idx + 1);
+                let new_pos = (idx + 1) as u16;
+                let visible_end = self.asset_holder_scroll + visible_height;
+                if new_pos >= visible_end {
+                    self.asset_holder_scroll = new_pos.saturating_sub(visible_height) + 1;
+                }
+            }
+        } else {
+            self.asset_holder_index = Some(0);
+            self.asset_holder_scroll = 0;
+        }
+    }
+
+    /// Resets asset detail view state.
+    pub fn reset_asset_detail(&mut self) {
+        self.asset_detail_tab = AssetDetailTab::default();
+        self.asset_holder_index = None;
+        self.asset_holder_scroll = 0;
+    }
+
+    // ========================================================================
+    // Group Detail Navigation
+    // ========================================================================
+
+    /// Cycles the group detail tab between Members and Graph.
+    pub fn cycle_group_detail_tab(&mut self) {
+        self.group_detail_tab = self.group_detail_tab.next();
+        self.graph_scroll_x = 0;
+        self.graph_scroll_y = 0;
+    }
+
+    /// Moves the group member selection up.
+    pub fn move_group_txn_up(&mut self) {
+        if let Some(idx) = self.group_txn_index
+            && idx > 0
+        {
+            self.group_txn_index = Some(idx - 1);
+            let new_pos = (idx - 1) as u16;
+            if new_pos < self.group_txn_scroll {
+                self.group_txn_scroll = new_pos;
+            }
+        }
+    }
+
+    /// Moves the group member selection down.
+    ///
+    /// # Arguments
+    ///
+    /// * `max` - Maximum valid index (length - 1)
+    /// * `visible_height` - Number of visible rows in the list area
+    pub fn move_group_txn_down(&mut self, max: usize, visible_height: u16) {
+        if let Some(idx) = self.group_txn_index {
+            if idx < max {
+                self.group_txn_index = Some(idx + 1);
+                let new_pos = (idx + 1) as u16;
+                let visible_end = self.group_txn_scroll + visible_height;
+                if new_pos >= visible_end {
+                    self.group_txn_scroll = new_pos.saturating_sub(visible_height) + 1;
+                }
+            }
+        } else {
+            self.group_txn_index = Some(0);
+            self.group_txn_scroll = 0;
+        }
+    }
+
+    /// Resets group detail view state.
+    pub fn reset_group_detail(&mut self) {
+        self.group_detail_tab = GroupDetailTab::default();
+        self.group_txn_index = None;
+        self.group_txn_scroll = 0;
+    }
+
+    // ========================================================================
+    // Search Results Navigation
+    // ========================================================================
+
+    /// Moves the search result selection up.
+    pub fn move_txn_search_up(&mut self) {
+        if let Some(idx) = self.txn_search_index
+            && idx > 0
+        {
+            self.txn_search_index = Some(idx - 1);
+            let new_pos = (idx - 1) as u16;
+            if new_pos < self.txn_search_scroll {
+                self.txn_search_scroll = new_pos;
+            }
+        }
+    }
+
+    /// Moves the search result selection down.
+    ///
+    /// # Arguments
+    ///
+    /// * `max` - Maximum valid index (length - 1)
+    /// * `visible_items` - Number of results that fit in the list area
+    pub fn move_txn_search_down(&mut self, max: usize, visible_items: u16) {
+        if let Some(idx) = self.txn_search_index {
+            if idx < max {
+                self.txn_search_index = Some(idx + 1);
+                let new_pos = (idx + 1) as u16;
+                let visible_end = self.txn_search_scroll + visible_items;
+                if new_pos >= visible_end {
+                    self.txn_search_scroll = new_pos.saturating_sub(visible_items) + 1;
+                }
+            }
+        } else {
+            self.txn_search_index = Some(0);
+            self.txn_search_scroll = 0;
+        }
+    }
+
+    /// Resets search results view state.
+    pub fn reset_txn_search(&mut self) {
+        self.txn_search_index = None;
+        self.txn_search_scroll = 0;
+    }
+
+    // ========================================================================
+    // Pending Pool Navigation
+    // ========================================================================
+
+    /// Moves the pending pool selection up.
+    pub fn move_pending_pool_up(&mut self) {
+        if let Some(idx) = self.pending_pool_index
+            && idx > 0
+        {
+            self.pending_pool_index = Some(idx - 1);
+            let new_pos = (idx - 1) as u16;
+            if new_pos < self.pending_pool_scroll {
+                self.pending_pool_scroll = new_pos;
+            }
+        }
+    }
+
+    /// Moves the pending pool selection down.
+    ///
+    /// # Arguments
+    ///
+    /// * `max` - Maximum valid index (length - 1)
+    /// * `visible_items` - Number of transactions that fit in the list area
+    pub fn move_pending_pool_down(&mut self, max: usize, visible_items: u16) {
+        if let Some(idx) = self.pending_pool_index {
+            if idx < max {
+                self.pending_pool_index = Some(idx + 1);
+                let new_pos = (idx + 1) as u16;
+                let visible_end = self.pending_pool_scroll + visible_items;
+                if new_pos >= visible_end {
+                    self.pending_pool_scroll = new_pos.saturating_sub(visible_items) + 1;
+                }
+            }
+        } else {
+            self.pending_pool_index = Some(0);
+            self.pending_pool_scroll = 0;
+        }
+    }
+
+    /// Resets pending pool view state.
+    pub fn reset_pending_pool(&mut self) {
+        self.pending_pool_index = None;
+        self.pending_pool_scroll = 0;
+    }
+
+    // ========================================================================
+    // Simulation Navigation
+    // ========================================================================
+
+    /// Cycles the simulation tab between Result and Graph.
+    pub fn cycle_simulation_tab(&mut self) {
+        self.simulation_tab = self.simulation_tab.next();
+        self.graph_scroll_x = 0;
+        self.graph_scroll_y = 0;
+    }
+
+    /// Moves the simulated transaction selection up.
+    pub fn move_simulation_txn_up(&mut self) {
+        if let Some(idx) = self.simulation_txn_index
+            && idx > 0
+        {
+            self.simulation_txn_index = Some(idx - 1);
+            let new_pos = (idx - 1) as u16;
+            if new_pos < self.simulation_txn_scroll {
+                self.simulation_txn_scroll = new_pos;
+            }
+        }
+    }
+
+    /// Moves the simulated transaction selection down.
+    ///
+    /// # Arguments
+    ///
+    /// * `max` - Maximum valid index (length - 1)
+    /// * `visible_height` - Number of visible rows in the list area
+    pub fn move_simulation_txn_down(&mut self, max: usize, visible_height: u16) {
+        if let Some(idx) = self.simulation_txn_index {
+            if idx < max {
+                self.simulation_txn_index = Some(idx + 1);
+                let new_pos = (idx + 1) as u16;
+                let visible_end = self.simulation_txn_scroll + visible_height;
+                if new_pos >= visible_end {
+                    self.simulation_txn_scroll = new_pos.saturating_sub(visible_height) + 1;
+                }
+            }
+        } else {
+            self.simulation_txn_index = Some(0);
+            self.simulation_txn_scroll = 0;
+        }
+    }
+
+    /// Resets simulation view state.
+    pub fn reset_simulation(&mut self) {
+        self.simulation_tab = SimulationTab::default();
+        self.simulation_txn_index = None;
+        self.simulation_txn_scroll = 0;
+    }
+
     // ========================================================================
     // Detail Table Row Navigation
     // ========================================================================
@@ -702,6 +1300,31 @@ mod tests {
         assert_eq!(nav.graph_scroll_x, 0);
     }
 
+    #[test]
+    fn test_asset_state_push_and_restore() {
+        let mut nav = NavigationState::new();
+        nav.cycle_asset_detail_tab();
+        nav.move_asset_holder_down(10, 3);
+        nav.move_asset_holder_down(10, 3);
+        nav.move_asset_holder_down(10, 3);
+        nav.move_asset_holder_down(10, 3);
+        assert_eq!(nav.asset_holder_index, Some(3));
+        assert_eq!(nav.asset_holder_scroll, 1);
+
+        nav.push_asset_state(31566704);
+        nav.reset_asset_detail();
+        assert_eq!(nav.asset_detail_tab, AssetDetailTab::Info);
+
+        let saved = nav.pop_popup_state().unwrap();
+        assert_eq!(saved.popup_type, DetailPopupType::Asset);
+        assert_eq!(saved.entity_id, "31566704");
+        nav.restore_asset_state(&saved);
+        assert_eq!(nav.asset_detail_tab, AssetDetailTab::Holders);
+        assert_eq!(nav.asset_holder_index, Some(3));
+        assert_eq!(nav.asset_holder_scroll, 1);
+        assert!(nav.show_asset_details);
+    }
+
     #[test]
     fn test_detail_view_open_close_cycle() {
         let mut nav = NavigationState::new();
@@ -6,11 +6,12 @@ use super::{
     App, DataState, DetailViewMode, Focus, NavigationState, PopupState, SearchType, UiState,
 };
 use crate::client::AlgoClient;
-use crate::commands::{AppCommand, InputContext, map_key};
+use crate::commands::{AppCommand, InputContext};
 use crate::domain::{
     AlgoBlock, BlockDetails, BlockInfo, Network, NetworkConfig, SearchResultItem, Transaction,
-    TxnType,
+    TxnType, WatchTarget,
 };
+use crate::keymap::Keymap;
 
 // ========================================================================
 // Test Helper Functions
@@ -21,6 +22,9 @@ fn create_test_app() -> App {
     let (message_tx, message_rx) = mpsc::unbounded_channel();
     let (live_updates_tx, _live_updates_rx) = watch::channel(true);
     let (network_tx, _network_rx) = watch::channel(NetworkConfig::BuiltIn(Network::TestNet));
+    let (watchlist_tx, _watchlist_rx) = watch::channel(Vec::new());
+    let (txn_filter_tx, _txn_filter_rx) = watch::channel(None);
+    let (pending_pool_tx, _pending_pool_rx) = watch::channel(None);
     let network_config = NetworkConfig::BuiltIn(Network::TestNet);
 
     App {
@@ -33,10 +37,14 @@ fn create_test_app() -> App {
         show_live: true,
         exit: false,
         animation_tick: 0,
+        keymap: Keymap::default(),
         message_tx,
         message_rx,
         live_updates_tx,
         network_tx,
+        watchlist_tx,
+        txn_filter_tx,
+        pending_pool_tx,
         client: AlgoClient::from_config(&network_config).expect("test client should build"),
         startup_options: None,
     }
@@ -64,6 +72,7 @@ fn create_test_transaction(id: &str, txn_type: TxnType, block: u64) -> Transacti
         group: None,
         details: crate::domain::TransactionDetails::None,
         inner_transactions: Vec::new(),
+        signature: None,
     }
 }
 
@@ -276,6 +285,46 @@ fn test_merge_transactions_all_scenarios() {
         !ids.contains(&"OLD_99"),
         "oldest transactions dropped when at capacity"
     );
+
+    // Test a round with more transactions than fit keeps the newest
+    let mut app = create_test_app();
+    let new_txns: Vec<Transaction> = (0..150)
+        .map(|i| create_test_transaction(&format!("BIG_{i}"), TxnType::Payment, 300))
+        .collect();
+    app.merge_transactions(new_txns);
+
+    assert_eq!(app.data.transactions.len(), 100, "caps a large round");
+    assert_eq!(app.data.transactions[0].id, "BIG_0", "keeps newest first");
+    assert_eq!(app.data.transactions[99].id, "BIG_99", "drops the rest");
+}
+
+// ========================================================================
+// rounds_to_fetch() Tests
+// ========================================================================
+
+#[test]
+fn test_rounds_to_fetch_all_scenarios() {
+    use super::app_lifecycle::rounds_to_fetch;
+
+    let cases = [
+        (
+            "first update only records the latest round",
+            None,
+            500,
+            None,
+        ),
+        ("next round", Some(500), 501, Some((501, 501))),
+        ("fills a short gap", Some(500), 504, Some((501, 504))),
+        ("caps a long gap", Some(500), 1000, Some((981, 1000))),
+        ("algod timed out", Some(500), 500, None),
+        ("chain was reset", Some(500), 3, Some((3, 3))),
+        ("genesis round", None, 0, None),
+        ("young chain", Some(0), 5, Some((1, 5))),
+    ];
+
+    for (name, last_round, latest, expected) in cases {
+        assert_eq!(rounds_to_fetch(last_round, latest), expected, "{name}");
+    }
 }
 
 // ========================================================================
@@ -757,7 +806,7 @@ fn test_get_input_context_all_states() {
             setup: |app| {
                 app.nav.show_asset_details = true;
             },
-            expected: InputContext::DetailView,
+            expected: InputContext::AssetDetailView,
         },
         TestCase {
             name: "popup precedence over detail view",
@@ -841,7 +890,7 @@ async fn test_network_form_key_to_state_flow() {
     };
 
     // Map the key to a command
-    let command = map_key(key_event, &context);
+    let command = app.keymap.map_key(key_event, &context);
     assert!(matches!(command, AppCommand::TypeChar('T')));
 
     // Execute the command
@@ -862,7 +911,7 @@ async fn test_network_form_key_to_state_flow() {
             kind: KeyEventKind::Press,
             state: KeyEventState::empty(),
         };
-        let command = map_key(key_event, &app.get_input_context());
+        let command = app.keymap.map_key(key_event, &app.get_input_context());
         app.execute_command(command).await.unwrap();
     }
 
@@ -879,7 +928,9 @@ async fn test_network_form_key_to_state_flow() {
         kind: KeyEventKind::Press,
         state: KeyEventState::empty(),
     };
-    let command = map_key(backspace_event, &app.get_input_context());
+    let command = app
+        .keymap
+        .map_key(backspace_event, &app.get_input_context());
     assert!(matches!(command, AppCommand::Backspace));
     app.execute_command(command).await.unwrap();
 
@@ -896,7 +947,7 @@ async fn test_network_form_key_to_state_flow() {
         kind: KeyEventKind::Press,
         state: KeyEventState::empty(),
     };
-    let command = map_key(tab_event, &app.get_input_context());
+    let command = app.keymap.map_key(tab_event, &app.get_input_context());
     assert!(matches!(command, AppCommand::NetworkFormNextField));
     app.execute_command(command).await.unwrap();
 
@@ -908,7 +959,7 @@ async fn test_network_form_key_to_state_flow() {
             kind: KeyEventKind::Press,
             state: KeyEventState::empty(),
         };
-        let command = map_key(key_event, &app.get_input_context());
+        let command = app.keymap.map_key(key_event, &app.get_input_context());
         app.execute_command(command).await.unwrap();
     }
 
@@ -919,3 +970,686 @@ async fn test_network_form_key_to_state_flow() {
         panic!("Expected NetworkForm popup");
     }
 }
+
+/// Tests the local state lookup prompt opened from application details.
+#[tokio::test]
+async fn test_local_state_lookup_flow() {
+    use crate::domain::ApplicationDetails;
+
+    let mut app = create_test_app();
+    app.data.viewed_application = Some(ApplicationDetails {
+        app_id: 42,
+        ..Default::default()
+    });
+    app.nav.show_application_details = true;
+
+    app.execute_command(AppCommand::LookupLocalState)
+        .await
+        .unwrap();
+    assert_eq!(app.get_input_context(), InputContext::LocalStateLookup);
+
+    // An invalid address keeps the prompt open
+    for c in "NOTANADDRESS".chars() {
+        app.execute_command(AppCommand::TypeChar(c)).await.unwrap();
+    }
+    app.execute_command(AppCommand::SubmitLocalStateLookup)
+        .await
+        .unwrap();
+    assert_eq!(
+        app.ui.popup_state,
+        PopupState::LocalStateLookup("NOTANADDRESS".to_string())
+    );
+    assert!(app.data.local_state.is_none());
+
+    let address = crate::domain::account::encode_address(&[0u8; 32]);
+    app.ui.popup_state = PopupState::LocalStateLookup(address.clone());
+    app.execute_command(AppCommand::SubmitLocalStateLookup)
+        .await
+        .unwrap();
+    assert_eq!(app.ui.popup_state, PopupState::None);
+    assert_eq!(app.nav.app_detail_tab, super::AppDetailTab::State);
+    let view = app.data.local_state.as_ref().unwrap();
+    assert!(view.matches(&address, 42));
+    assert!(view.loading);
+}
+
+#[tokio::test]
+async fn test_txn_filter_flow() {
+    let mut app = create_test_app();
+    app.data.transactions = vec![
+        create_test_transaction("PAY", TxnType::Payment, 10),
+        create_test_transaction("AXFER", TxnType::AssetTransfer, 10),
+    ];
+    app.nav.selected_transaction_index = Some(1);
+
+    app.execute_command(AppCommand::OpenTxnFilter)
+        .await
+        .unwrap();
+    assert_eq!(app.get_input_context(), InputContext::TxnFilterInput);
+
+    // An invalid query keeps the prompt open
+    for c in "type:nope".chars() {
+        app.execute_command(AppCommand::TypeChar(c)).await.unwrap();
+    }
+    app.execute_command(AppCommand::SubmitTxnFilter)
+        .await
+        .unwrap();
+    assert_eq!(
+        app.ui.popup_state,
+        PopupState::TxnFilter("type:nope".to_string())
+    );
+    assert!(app.data.txn_filter.is_none());
+
+    app.ui.popup_state = PopupState::TxnFilter("type:pay".to_string());
+    app.execute_command(AppCommand::SubmitTxnFilter)
+        .await
+        .unwrap();
+    assert_eq!(app.ui.popup_state, PopupState::None);
+    assert_eq!(app.nav.selected_transaction_index, None);
+    assert_eq!(app.data.transactions.len(), 1);
+    assert_eq!(app.data.transactions[0].id, "PAY");
+    assert_eq!(
+        app.txn_filter_tx.borrow().as_ref().map(ToString::to_string),
+        Some("type:pay".to_string())
+    );
+
+    // Non-matching transactions from the feed are dropped
+    app.merge_transactions(vec![create_test_transaction(
+        "AXFER2",
+        TxnType::AssetTransfer,
+        11,
+    )]);
+    assert_eq!(app.data.transactions.len(), 1);
+
+    // The prompt reopens with the active query; clearing it removes the filter
+    app.execute_command(AppCommand::OpenTxnFilter)
+        .await
+        .unwrap();
+    assert_eq!(
+        app.ui.popup_state,
+        PopupState::TxnFilter("type:pay".to_string())
+    );
+    app.ui.popup_state = PopupState::TxnFilter(String::new());
+    app.execute_command(AppCommand::SubmitTxnFilter)
+        .await
+        .unwrap();
+    assert!(app.data.txn_filter.is_none());
+    assert!(app.txn_filter_tx.borrow().is_none());
+}
+
+#[tokio::test]
+async fn test_asset_holder_navigation_round_trip() {
+    use super::{AssetDetailTab, AssetHolders};
+    use crate::domain::{AssetDetails, AssetHolder, Page};
+
+    let mut app = create_test_app();
+    app.data.viewed_asset = Some(AssetDetails {
+        id: 7,
+        decimals: 2,
+        ..Default::default()
+    });
+    app.nav.show_asset_details = true;
+
+    app.execute_command(AppCommand::CycleAssetDetailTab)
+        .await
+        .unwrap();
+    assert_eq!(app.nav.asset_detail_tab, AssetDetailTab::Holders);
+    let holders = app.data.asset_holders.as_ref().unwrap();
+    assert!(holders.matches(7, None));
+    assert!(holders.loading);
+
+    app.execute_command(AppCommand::CycleAssetHolderFilter)
+        .await
+        .unwrap();
+    assert!(app.data.asset_holders.as_ref().unwrap().matches(7, Some(0)));
+
+    let mut loaded = AssetHolders::new(7, Some(0));
+    loaded.append_page(Page::new(
+        vec![
+            AssetHolder {
+                address: "HOLDER1".to_string(),
+                amount: 100,
+                ..Default::default()
+            },
+            AssetHolder {
+                address: "HOLDER2".to_string(),
+                amount: 200,
+                ..Default::default()
+            },
+        ],
+        None,
+    ));
+    app.data.asset_holders = Some(loaded);
+    app.execute_command(AppCommand::MoveAssetHolderDown)
+        .await
+        .unwrap();
+    app.execute_command(AppCommand::MoveAssetHolderDown)
+        .await
+        .unwrap();
+    assert_eq!(app.nav.asset_holder_index, Some(1));
+
+    // Opening a holder saves the asset popup on the stack
+    app.execute_command(AppCommand::SelectAssetHolder)
+        .await
+        .unwrap();
+    assert!(!app.nav.show_asset_details);
+    assert_eq!(app.nav.popup_stack.len(), 1);
+
+    // Simulate the holder account loading, then return to the asset
+    app.nav.show_account_details = true;
+    app.execute_command(AppCommand::Dismiss).await.unwrap();
+    assert!(app.nav.show_asset_details);
+    assert!(!app.nav.show_account_details);
+    assert!(app.data.viewed_account.is_none());
+    assert_eq!(app.nav.asset_detail_tab, AssetDetailTab::Holders);
+    assert_eq!(app.nav.asset_holder_index, Some(1));
+    assert_eq!(app.data.asset_holders.as_ref().unwrap().holders.len(), 2);
+}
+
+#[tokio::test]
+async fn test_group_navigation_round_trip() {
+    use super::{AppMessage, GroupDetailTab};
+    use crate::domain::TxnGroup;
+
+    let mut parent = create_test_transaction("PARENT", TxnType::Payment, 42);
+    parent.group = Some("GROUPID".to_string());
+    let mut sibling = create_test_transaction("SIBLING", TxnType::AssetTransfer, 42);
+    sibling.group = Some("GROUPID".to_string());
+
+    let mut app = create_test_app();
+    app.data.viewed_transaction = Some(parent.clone());
+    app.nav.show_transaction_details = true;
+
+    // A group for some other transaction is ignored
+    let other = TxnGroup::new("OTHER".to_string(), 42, vec![sibling.clone()]);
+    app.message_tx.send(AppMessage::GroupLoaded(other)).unwrap();
+    app.process_messages().await;
+    assert!(app.nav.show_transaction_details);
+    assert!(app.data.viewed_group.is_none());
+
+    // The group replaces the transaction view, which is saved on the stack
+    let group = TxnGroup::new("GROUPID".to_string(), 42, vec![parent, sibling]);
+    app.message_tx.send(AppMessage::GroupLoaded(group)).unwrap();
+    app.process_messages().await;
+    assert!(!app.nav.show_transaction_details);
+    assert!(app.nav.show_group_details);
+    assert_eq!(app.get_input_context(), InputContext::GroupDetailView);
+    assert_eq!(app.nav.group_txn_index, Some(0));
+    assert_eq!(app.nav.popup_stack.len(), 1);
+
+    // Open the second member, then come back to it
+    app.execute_command(AppCommand::MoveGroupTxnDown)
+        .await
+        .unwrap();
+    app.execute_command(AppCommand::SelectGroupTxn)
+        .await
+        .unwrap();
+    assert!(app.nav.show_transaction_details);
+    assert!(!app.nav.show_group_details);
+    assert_eq!(app.get_current_transaction().unwrap().id, "SIBLING");
+    assert_eq!(app.nav.popup_stack.len(), 2);
+
+    app.execute_command(AppCommand::Dismiss).await.unwrap();
+    assert!(app.nav.show_group_details);
+    assert_eq!(app.nav.group_txn_index, Some(1));
+    assert_eq!(app.nav.group_detail_tab, GroupDetailTab::Members);
+
+    // Leaving the group returns to the transaction it was opened from
+    app.execute_command(AppCommand::Dismiss).await.unwrap();
+    assert!(!app.nav.show_group_details);
+    assert!(app.nav.show_transaction_details);
+    assert!(app.data.viewed_group.is_none());
+    assert_eq!(app.get_current_transaction().unwrap().id, "PARENT");
+
+    app.execute_command(AppCommand::Dismiss).await.unwrap();
+    assert!(!app.nav.is_showing_details());
+}
+
+#[tokio::test]
+async fn test_advanced_search_round_trip() {
+    use super::AppMessage;
+    use crate::domain::{Page, TxnQuery};
+
+    let mut app = create_test_app();
+    app.execute_command(AppCommand::OpenAdvancedSearch)
+        .await
+        .unwrap();
+    assert_eq!(app.get_input_context(), InputContext::AdvancedSearchForm);
+
+    // Invalid input keeps the form open
+    app.execute_command(AppCommand::TypeChar('x'))
+        .await
+        .unwrap();
+    app.execute_command(AppCommand::SubmitAdvancedSearch)
+        .await
+        .unwrap();
+    assert!(matches!(app.ui.popup_state, PopupState::AdvancedSearch(_)));
+
+    app.execute_command(AppCommand::Backspace).await.unwrap();
+    app.execute_command(AppCommand::AdvancedSearchNextField)
+        .await
+        .unwrap();
+    app.execute_command(AppCommand::AdvancedSearchNextField)
+        .await
+        .unwrap();
+    for c in "pay".chars() {
+        app.execute_command(AppCommand::TypeChar(c)).await.unwrap();
+    }
+    app.execute_command(AppCommand::SubmitAdvancedSearch)
+        .await
+        .unwrap();
+    assert!(!app.ui.popup_state.is_active());
+    assert!(app.nav.show_txn_search);
+    assert_eq!(app.get_input_context(), InputContext::TxnSearchView);
+
+    // Pages for another query are ignored
+    let query = TxnQuery {
+        txn_type: Some(TxnType::Payment),
+        ..TxnQuery::default()
+    };
+    let page = Page::new(
+        vec![
+            create_test_transaction("FIRST", TxnType::Payment, 42),
+            create_test_transaction("SECOND", TxnType::Payment, 41),
+        ],
+        None,
+    );
+    app.message_tx
+        .send(AppMessage::TxnSearchLoaded {
+            query: TxnQuery::default(),
+            page: page.clone(),
+        })
+        .unwrap();
+    app.message_tx
+        .send(AppMessage::TxnSearchLoaded { query, page })
+        .unwrap();
+    app.process_messages().await;
+    let search = app.data.txn_search.as_ref().unwrap();
+    assert_eq!(search.transactions.len(), 2);
+    assert!(!search.loading);
+    assert_eq!(app.nav.txn_search_index, Some(0));
+
+    // Open the second result, then come back to it
+    app.execute_command(AppCommand::MoveSearchTxnDown)
+        .await
+        .unwrap();
+    app.execute_command(AppCommand::SelectSearchTxn)
+        .await
+        .unwrap();
+    assert!(app.nav.show_transaction_details);
+    assert!(!app.nav.show_txn_search);
+    assert_eq!(app.get_current_transaction().unwrap().id, "SECOND");
+
+    app.execute_command(AppCommand::Dismiss).await.unwrap();
+    assert!(app.nav.show_txn_search);
+    assert!(!app.nav.show_transaction_details);
+    assert_eq!(app.nav.txn_search_index, Some(1));
+
+    // Reopening the form keeps the last query
+    app.execute_command(AppCommand::OpenAdvancedSearch)
+        .await
+        .unwrap();
+    let PopupState::AdvancedSearch(form) = &app.ui.popup_state else {
+        panic!("advanced search form should be open");
+    };
+    assert_eq!(
+        form.value(crate::state::ui_state::AdvancedSearchField::TxnType),
+        "pay"
+    );
+    app.execute_command(AppCommand::Dismiss).await.unwrap();
+
+    app.execute_command(AppCommand::Dismiss).await.unwrap();
+    assert!(!app.nav.is_showing_details());
+    assert!(app.data.txn_search.is_none());
+}
+
+#[tokio::test]
+async fn test_watchlist_focus_and_navigation() {
+    let mut app = create_test_app();
+
+    // Without a watchlist, focus only alternates between blocks and transactions
+    app.execute_command(AppCommand::CycleFocus).await.unwrap();
+    app.execute_command(AppCommand::CycleFocus).await.unwrap();
+    assert_eq!(app.ui.focus, Focus::Blocks);
+
+    app.set_watchlist(&[
+        WatchTarget::Account("ADDR".to_string()),
+        WatchTarget::Asset(31566704),
+        WatchTarget::App(1284326447),
+    ]);
+    app.execute_command(AppCommand::CycleFocus).await.unwrap();
+    app.execute_command(AppCommand::CycleFocus).await.unwrap();
+    assert_eq!(app.ui.focus, Focus::Watchlist);
+
+    app.execute_command(AppCommand::MoveDown).await.unwrap();
+    app.execute_command(AppCommand::MoveDown).await.unwrap();
+    assert_eq!(app.nav.selected_watch_index, Some(1));
+    app.execute_command(AppCommand::GoToBottom).await.unwrap();
+    assert_eq!(app.nav.selected_watch_index, Some(2));
+
+    // Enter opens the selected entry's details
+    app.execute_command(AppCommand::Select).await.unwrap();
+    assert!(app.nav.show_application_details);
+    app.nav.show_application_details = false;
+
+    // Shrinking the list clamps the selection; emptying it moves focus away
+    app.set_watchlist(&[WatchTarget::Asset(31566704)]);
+    assert_eq!(app.nav.selected_watch_index, Some(0));
+    app.set_watchlist(&[]);
+    assert_eq!(app.ui.focus, Focus::Blocks);
+    assert_eq!(app.nav.selected_watch_index, None);
+}
+
+#[tokio::test]
+async fn test_list_export_prompt() {
+    use super::{AppMessage, AssetDetailTab, TxnSearch};
+    use crate::domain::{ExportSource, TxnQuery};
+
+    let mut app = create_test_app();
+
+    // Views without a list have nothing to export
+    app.execute_command(AppCommand::OpenExport).await.unwrap();
+    assert!(!app.ui.popup_state.is_active());
+    app.nav.show_asset_details = true;
+    app.data.viewed_asset = Some(crate::test_utils::AssetMother::usdc());
+    assert_eq!(app.current_export_source(), None);
+    app.nav.asset_detail_tab = AssetDetailTab::Holders;
+    assert!(matches!(
+        app.current_export_source(),
+        Some(ExportSource::AssetHolders {
+            decimals: 6,
+            min_balance: None,
+            ..
+        })
+    ));
+    app.nav.show_asset_details = false;
+
+    let query = TxnQuery {
+        txn_type: Some(TxnType::Payment),
+        ..TxnQuery::default()
+    };
+    app.data.txn_search = Some(TxnSearch::new(query.clone()));
+    app.nav.show_txn_search = true;
+    app.execute_command(AppCommand::OpenExport).await.unwrap();
+    assert_eq!(app.get_input_context(), InputContext::ExportPrompt);
+    let PopupState::Export(prompt) = &app.ui.popup_state else {
+        panic!("export prompt should be open");
+    };
+    assert_eq!(prompt.source, ExportSource::TxnSearch(query));
+    assert_eq!(prompt.path, "lazylora_search.csv");
+
+    app.execute_command(AppCommand::ToggleExportFormat)
+        .await
+        .unwrap();
+    app.execute_command(AppCommand::TypeChar('x'))
+        .await
+        .unwrap();
+    let PopupState::Export(prompt) = &app.ui.popup_state else {
+        panic!("export prompt should be open");
+    };
+    assert_eq!(prompt.path, "lazylora_search.jsonlx");
+
+    // An unsupported extension keeps the prompt open
+    app.execute_command(AppCommand::SubmitExport).await.unwrap();
+    assert!(matches!(app.ui.popup_state, PopupState::Export(_)));
+    assert!(!app.data.exporting);
+
+    // Closing the prompt leaves the results open
+    app.execute_command(AppCommand::Dismiss).await.unwrap();
+    assert!(!app.ui.popup_state.is_active());
+    assert!(app.nav.show_txn_search);
+
+    // Progress and completion are reported as toasts
+    app.data.exporting = true;
+    app.execute_command(AppCommand::OpenExport).await.unwrap();
+    assert!(!app.ui.popup_state.is_active());
+    app.message_tx
+        .send(AppMessage::ExportProgress { rows: 1000 })
+        .unwrap();
+    app.process_messages().await;
+    assert_eq!(
+        app.ui.toast.as_ref().map(|(message, _)| message.as_str()),
+        Some("Exporting... 1000 rows")
+    );
+    app.message_tx
+        .send(AppMessage::ExportFinished {
+            path: "out.csv".to_string(),
+            rows: 1234,
+        })
+        .unwrap();
+    app.process_messages().await;
+    assert!(!app.data.exporting);
+    assert_eq!(
+        app.ui.toast.as_ref().map(|(message, _)| message.as_str()),
+        Some("[+] Exported 1234 rows to out.csv")
+    );
+
+    // A failed export leaves nothing behind, and the toast says so
+    app.data.exporting = true;
+    app.message_tx
+        .send(AppMessage::ExportFailed("indexer timed out".to_string()))
+        .unwrap();
+    app.process_messages().await;
+    assert!(!app.data.exporting);
+    assert_eq!(
+        app.ui.toast.as_ref().map(|(message, _)| message.as_str()),
+        Some("[x] Export failed, no file written: indexer timed out")
+    );
+}
+
+#[tokio::test]
+async fn test_algod_only_mode_blocks_indexer_features() {
+    use super::AppMessage;
+    use crate::domain::DataSource;
+
+    let mut app = create_test_app();
+    app.message_tx
+        .send(AppMessage::NetworkConnected(DataSource::AlgodOnly))
+        .unwrap();
+    app.process_messages().await;
+    assert_eq!(app.data.data_source, DataSource::AlgodOnly);
+    assert_eq!(app.client.data_source(), DataSource::AlgodOnly);
+    assert_eq!(
+        app.ui.toast.as_ref().map(|(message, _)| message.as_str()),
+        Some("No indexer - algod-only mode, history features unavailable")
+    );
+
+    // Indexer-only features are refused with a toast
+    app.execute_command(AppCommand::OpenAdvancedSearch)
+        .await
+        .unwrap();
+    assert!(!app.ui.popup_state.is_active());
+    assert_eq!(
+        app.ui.toast.as_ref().map(|(message, _)| message.as_str()),
+        Some("Advanced search requires an indexer, which is unavailable in algod-only mode")
+    );
+
+    // Reconnecting to the same source does not repeat the toast
+    app.ui.toast = None;
+    app.message_tx
+        .send(AppMessage::NetworkConnected(DataSource::AlgodOnly))
+        .unwrap();
+    app.process_messages().await;
+    assert!(app.ui.toast.is_none());
+
+    app.message_tx
+        .send(AppMessage::NetworkConnected(DataSource::Indexer))
+        .unwrap();
+    app.process_messages().await;
+    assert_eq!(app.data.data_source, DataSource::Indexer);
+    app.execute_command(AppCommand::OpenAdvancedSearch)
+        .await
+        .unwrap();
+    assert_eq!(app.get_input_context(), InputContext::AdvancedSearchForm);
+}
+
+#[tokio::test]
+async fn test_pending_pool_round_trip() {
+    use super::{AppMessage, PoolScope};
+    use crate::domain::AccountDetails;
+
+    let mut app = create_test_app();
+    app.execute_command(AppCommand::OpenPendingPool)
+        .await
+        .unwrap();
+    assert!(app.nav.show_pending_pool);
+    assert_eq!(app.get_input_context(), InputContext::PendingPoolView);
+    assert_eq!(*app.pending_pool_tx.borrow(), Some(PoolScope::All));
+
+    // Updates for another scope are ignored
+    app.message_tx
+        .send(AppMessage::PendingPoolUpdated {
+            scope: PoolScope::Account("OTHER".to_string()),
+            pending: vec![create_test_transaction("STRAY", TxnType::Payment, 0)],
+            total: 1,
+            confirmed: Vec::new(),
+        })
+        .unwrap();
+    app.message_tx
+        .send(AppMessage::PendingPoolUpdated {
+            scope: PoolScope::All,
+            pending: vec![
+                create_test_transaction("FIRST", TxnType::Payment, 0),
+                create_test_transaction("SECOND", TxnType::AssetTransfer, 0),
+            ],
+            total: 2,
+            confirmed: Vec::new(),
+        })
+        .unwrap();
+    app.process_messages().await;
+    let pool = app.data.pending_pool.as_ref().unwrap();
+    assert_eq!(pool.len(), 2);
+    assert!(!pool.loading);
+    assert_eq!(app.nav.pending_pool_index, Some(0));
+
+    app.execute_command(AppCommand::MovePendingTxnDown)
+        .await
+        .unwrap();
+    assert_eq!(app.nav.pending_pool_index, Some(1));
+
+    // Once FIRST confirms, the selection follows SECOND
+    app.message_tx
+        .send(AppMessage::PendingPoolUpdated {
+            scope: PoolScope::All,
+            pending: vec![create_test_transaction("SECOND", TxnType::AssetTransfer, 0)],
+            total: 1,
+            confirmed: vec![create_test_transaction("FIRST", TxnType::Payment, 42)],
+        })
+        .unwrap();
+    app.process_messages().await;
+    let pool = app.data.pending_pool.as_ref().unwrap();
+    assert_eq!(pool.get(0).map(|(txn, _)| txn.id.as_str()), Some("SECOND"));
+    assert_eq!(
+        pool.get(1)
+            .map(|(txn, confirmed)| (txn.id.as_str(), confirmed)),
+        Some(("FIRST", true))
+    );
+    assert_eq!(app.nav.pending_pool_index, Some(0));
+
+    // A pending entry opens straight away, and the pool comes back on dismiss
+    app.execute_command(AppCommand::SelectPendingTxn)
+        .await
+        .unwrap();
+    assert!(app.nav.show_transaction_details);
+    assert!(!app.nav.show_pending_pool);
+    assert_eq!(app.get_current_transaction().unwrap().id, "SECOND");
+    app.execute_command(AppCommand::Dismiss).await.unwrap();
+    assert!(app.nav.show_pending_pool);
+    assert_eq!(app.nav.pending_pool_index, Some(0));
+
+    // The confirmed copy from the indexer replaces the pool the same way
+    app.message_tx
+        .send(AppMessage::TransactionDetailsLoaded(Box::new(
+            create_test_transaction("FIRST", TxnType::Payment, 42),
+        )))
+        .unwrap();
+    app.process_messages().await;
+    assert!(app.nav.show_transaction_details);
+    assert!(!app.nav.show_pending_pool);
+    app.execute_command(AppCommand::Dismiss).await.unwrap();
+    assert!(app.nav.show_pending_pool);
+
+    // Closing the pool stops polling
+    app.execute_command(AppCommand::Dismiss).await.unwrap();
+    assert!(!app.nav.is_showing_details());
+    assert!(app.data.pending_pool.is_none());
+    assert!(app.pending_pool_tx.borrow().is_none());
+
+    // From account details the pool is scoped to that sender
+    app.data.viewed_account = Some(AccountDetails {
+        address: "ACCOUNT".to_string(),
+        ..Default::default()
+    });
+    app.nav.show_account_details = true;
+    app.execute_command(AppCommand::OpenPendingPool)
+        .await
+        .unwrap();
+    assert!(app.nav.show_pending_pool);
+    assert!(!app.nav.show_account_details);
+    assert_eq!(
+        *app.pending_pool_tx.borrow(),
+        Some(PoolScope::Account("ACCOUNT".to_string()))
+    );
+    app.execute_command(AppCommand::Dismiss).await.unwrap();
+    assert!(app.nav.show_account_details);
+    assert!(app.data.pending_pool.is_none());
+}
+
+#[tokio::test]
+async fn test_simulation_round_trip() {
+    use super::AppMessage;
+    use crate::domain::{SimulatedTxn, Simulation};
+
+    let mut app = create_test_app();
+    app.execute_command(AppCommand::OpenSimulate).await.unwrap();
+    assert_eq!(app.get_input_context(), InputContext::SimulateInput);
+
+    // Input that is neither a file nor a group keeps the prompt open
+    app.execute_command(AppCommand::TypeChar('A'))
+        .await
+        .unwrap();
+    app.execute_command(AppCommand::SubmitSimulate)
+        .await
+        .unwrap();
+    assert!(matches!(app.ui.popup_state, PopupState::Simulate(_)));
+    assert!(app.ui.toast.is_some());
+    assert!(!app.data.simulating);
+    app.execute_command(AppCommand::Dismiss).await.unwrap();
+    assert_eq!(app.ui.popup_state, PopupState::None);
+
+    // The result opens with the failing transaction selected
+    let simulated = |id: &str| SimulatedTxn {
+        transaction: create_test_transaction(id, TxnType::Payment, 0),
+        app_budget_consumed: None,
+        logic_sig_budget_consumed: None,
+    };
+    app.message_tx
+        .send(AppMessage::SimulationLoaded(Box::new(Simulation {
+            round: 100,
+            transactions: vec![simulated("FIRST"), simulated("SECOND")],
+            failure_message: Some("overspend".to_string()),
+            failed_at: vec![1],
+            app_budget_added: 0,
+            app_budget_consumed: 0,
+        })))
+        .unwrap();
+    app.process_messages().await;
+    assert!(app.nav.show_simulation);
+    assert_eq!(app.get_input_context(), InputContext::SimulationView);
+    assert_eq!(app.nav.simulation_txn_index, Some(1));
+
+    // A transaction opens in the detail view, and the result comes back on dismiss
+    app.execute_command(AppCommand::SelectSimulatedTxn)
+        .await
+        .unwrap();
+    assert!(app.nav.show_transaction_details);
+    assert!(!app.nav.show_simulation);
+    assert_eq!(app.get_current_transaction().unwrap().id, "SECOND");
+    app.execute_command(AppCommand::Dismiss).await.unwrap();
+    assert!(app.nav.show_simulation);
+    assert_eq!(app.nav.simulation_txn_index, Some(1));
+
+    app.execute_command(AppCommand::Dismiss).await.unwrap();
+    assert!(!app.nav.show_simulation);
+    assert!(app.data.simulation.is_none());
+}
@@ -16,7 +16,10 @@ mod search;
 
 use std::collections::HashSet;
 
-pub use popups::{NetworkFormField, NetworkFormState, PopupState};
+pub use popups::{
+    AdvancedSearchField, AdvancedSearchForm, ExportPrompt, NetworkFormField, NetworkFormState,
+    PopupState,
+};
 pub use search::{SearchType, detect_search_type};
 
 // ============================================================================
@@ -34,19 +37,26 @@ pub enum Focus {
     Blocks,
     /// The transactions panel has focus.
     Transactions,
+    /// The watchlist panel has focus.
+    Watchlist,
 }
 
 impl Focus {
     /// Cycles to the next focus target.
     ///
+    /// # Arguments
+    ///
+    /// * `with_watchlist` - Whether the watchlist panel is shown
+    ///
     /// # Returns
     ///
     /// The next focus target in the cycle.
     #[must_use]
-    pub const fn next(self) -> Self {
+    pub const fn next(self, with_watchlist: bool) -> Self {
         match self {
             Self::Blocks => Self::Transactions,
-            Self::Transactions => Self::Blocks,
+            Self::Transactions if with_watchlist => Self::Watchlist,
+            Self::Transactions | Self::Watchlist => Self::Blocks,
         }
     }
 }
@@ -71,7 +81,7 @@ const MAX_SEARCH_HISTORY: usize = 10;
 /// let mut ui = UiState::new();
 ///
 /// // Cycle focus between panels
-/// ui.cycle_focus();
+/// ui.cycle_focus(false);
 ///
 /// // Show a toast notification
 /// ui.show_toast("Operation completed!", 20);
@@ -151,9 +161,9 @@ impl UiState {
     // Focus Management
     // ========================================================================
 
-    /// Cycles focus between Blocks and Transactions panels.
-    pub fn cycle_focus(&mut self) {
-        self.focus = self.focus.next();
+    /// Cycles focus between the Blocks, Transactions and (if shown) Watchlist panels.
+    pub fn cycle_focus(&mut self, with_watchlist: bool) {
+        self.focus = self.focus.next(with_watchlist);
     }
 
     // ========================================================================
@@ -194,6 +204,31 @@ impl UiState {
         self.popup_state = PopupState::NetworkForm(NetworkFormState::new(return_to_index));
     }
 
+    /// Opens the account address prompt for a local state lookup.
+    pub fn open_local_state_lookup(&mut self) {
+        self.popup_state = PopupState::LocalStateLookup(String::new());
+    }
+
+    /// Opens the transaction feed filter prompt, prefilled with the active query.
+    pub fn open_txn_filter(&mut self, query: String) {
+        self.popup_state = PopupState::TxnFilter(query);
+    }
+
+    /// Opens the advanced transaction search form.
+    pub fn open_advanced_search(&mut self, form: AdvancedSearchForm) {
+        self.popup_state = PopupState::AdvancedSearch(form);
+    }
+
+    /// Opens the export path prompt for a list.
+    pub fn open_export(&mut self, source: crate::domain::ExportSource) {
+        self.popup_state = PopupState::Export(ExportPrompt::new(source));
+    }
+
+    /// Opens the simulation prompt, prefilled with the last simulated input.
+    pub fn open_simulate(&mut self, input: String) {
+        self.popup_state = PopupState::Simulate(input);
+    }
+
     /// Opens the quit confirmation popup.
     pub fn open_confirm_quit(&mut self) {
         self.popup_state = PopupState::ConfirmQuit;
@@ -256,6 +291,97 @@ impl UiState {
         }
     }
 
+    /// Types a character into the local state lookup prompt.
+    pub fn local_state_lookup_type_char(&mut self, c: char) {
+        if let PopupState::LocalStateLookup(address) = &mut self.popup_state {
+            address.push(c);
+        }
+    }
+
+    /// Deletes a character from the local state lookup prompt.
+    pub fn local_state_lookup_backspace(&mut self) {
+        if let PopupState::LocalStateLookup(address) = &mut self.popup_state {
+            address.pop();
+        }
+    }
+
+    /// Types a character into the transaction feed filter prompt.
+    pub fn txn_filter_type_char(&mut self, c: char) {
+        if let PopupState::TxnFilter(query) = &mut self.popup_state {
+            query.push(c);
+        }
+    }
+
+    /// Deletes a character from the transaction feed filter prompt.
+    pub fn txn_filter_backspace(&mut self) {
+        if let PopupState::TxnFilter(query) = &mut self.popup_state {
+            query.pop();
+        }
+    }
+
+    /// Moves to the next field in the advanced search form.
+    pub fn advanced_search_next_field(&mut self) {
+        if let PopupState::AdvancedSearch(form) = &mut self.popup_state {
+            form.next_field();
+        }
+    }
+
+    /// Moves to the previous field in the advanced search form.
+    pub fn advanced_search_prev_field(&mut self) {
+        if let PopupState::AdvancedSearch(form) = &mut self.popup_state {
+            form.prev_field();
+        }
+    }
+
+    /// Types a character into the active advanced search field.
+    pub fn advanced_search_type_char(&mut self, c: char) {
+        if let PopupState::AdvancedSearch(form) = &mut self.popup_state {
+            form.push_char(c);
+        }
+    }
+
+    /// Deletes a character from the active advanced search field.
+    pub fn advanced_search_backspace(&mut self) {
+        if let PopupState::AdvancedSearch(form) = &mut self.popup_state {
+            form.backspace();
+        }
+    }
+
+    /// Types a character into the export path prompt.
+    pub fn export_type_char(&mut self, c: char) {
+        if let PopupState::Export(prompt) = &mut self.popup_state {
+            prompt.path.push(c);
+        }
+    }
+
+    /// Deletes a character from the export path prompt.
+    pub fn export_backspace(&mut self) {
+        if let PopupState::Export(prompt) = &mut self.popup_state {
+            prompt.path.pop();
+        }
+    }
+
+    /// Types a character into the simulation prompt.
+    pub fn simulate_type_char(&mut self, c: char) {
+        if let PopupState::Simulate(input) = &mut self.popup_state {
+            input.push(c);
+        }
+    }
+
+    /// Deletes a character from the simulation prompt.
+    pub fn simulate_backspace(&mut self) {
+        if let PopupState::Simulate(input) = &mut self.popup_state {
+            input.pop();
+        }
+    }
+
+    /// Switches the export path between CSV and JSON Lines.
+    pub fn export_toggle_format(&mut self) {
+        if let PopupState::Export(prompt) = &mut self.popup_state {
+            prompt.toggle_format();
+        }
+    }
+
     /// Sets search results popup.
     ///
     /// # Arguments
@@ -595,10 +721,12 @@ mod tests {
 
     #[test]
     fn test_focus_cycle_behavior() {
-        // Default is Blocks, cycles through both values
+        // Default is Blocks; the watchlist joins the cycle only when shown
         assert_eq!(Focus::default(), Focus::Blocks);
-        assert_eq!(Focus::Blocks.next(), Focus::Transactions);
-        assert_eq!(Focus::Transactions.next(), Focus::Blocks);
+        assert_eq!(Focus::Blocks.next(false), Focus::Transactions);
+        assert_eq!(Focus::Transactions.next(false), Focus::Blocks);
+        assert_eq!(Focus::Transactions.next(true), Focus::Watchlist);
+        assert_eq!(Focus::Watchlist.next(true), Focus::Blocks);
     }
 
     #[test]
@@ -626,10 +754,10 @@ mod tests {
         let mut ui = UiState::new();
         assert_eq!(ui.focus, Focus::Blocks);
 
-        ui.cycle_focus();
+        ui.cycle_focus(false);
         assert_eq!(ui.focus, Focus::Transactions);
 
-        ui.cycle_focus();
+        ui.cycle_focus(false);
         assert_eq!(ui.focus, Focus::Blocks);
     }
 
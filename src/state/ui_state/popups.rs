@@ -4,8 +4,13 @@
 //! - Network selection popup state
 //! - Search popup state
 //! - Network form state for adding custom networks
+//! - Advanced transaction search form state
+//! - List export prompt state
 
 use super::SearchType;
+use crate::domain::account::is_valid_address;
+use crate::domain::txn_query::parse_query_time;
+use crate::domain::{AddressRole, AlgoError, ExportFormat, ExportSource, TxnQuery, TxnType};
 
 // ============================================================================
 // Network Form
@@ -57,7 +62,7 @@ impl NetworkFormField {
             Self::AlgodUrl => "Algod URL",
             Self::AlgodPort => "Algod Port",
             Self::AlgodToken => "Algod API Token (optional)",
-            Self::IndexerUrl => "Indexer URL",
+            Self::IndexerUrl => "Indexer URL (blank for algod-only)",
             Self::IndexerPort => "Indexer Port",
             Self::IndexerToken => "Indexer API Token (optional)",
         }
@@ -123,6 +128,299 @@ impl NetworkFormState {
     }
 }
 
+// ============================================================================
+// Advanced Search Form
+// ============================================================================
+
+/// Fields of the advanced transaction search form, in display order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AdvancedSearchField {
+    Address,
+    AddressRole,
+    TxnType,
+    AssetId,
+    AppId,
+    MinRound,
+    MaxRound,
+    AfterTime,
+    BeforeTime,
+    AmountAbove,
+    AmountBelow,
+    NotePrefix,
+    RekeyTo,
+}
+
+impl AdvancedSearchField {
+    /// All fields in display order.
+    pub const ALL: [Self; 13] = [
+        Self::Address,
+        Self::AddressRole,
+        Self::TxnType,
+        Self::AssetId,
+        Self::AppId,
+        Self::MinRound,
+        Self::MaxRound,
+        Self::AfterTime,
+        Self::BeforeTime,
+        Self::AmountAbove,
+        Self::AmountBelow,
+        Self::NotePrefix,
+        Self::RekeyTo,
+    ];
+
+    const fn index(self) -> usize {
+        self as usize
+    }
+
+    #[must_use]
+    const fn next(self) -> Self {
+        Self::ALL[(self.index() + 1) % Self::ALL.len()]
+    }
+
+    #[must_use]
+    const fn prev(self) -> Self {
+        Self::ALL[(self.index() + Self::ALL.len() - 1) % Self::ALL.len()]
+    }
+
+    #[must_use]
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::Address => "Address",
+            Self::AddressRole => "Address Role",
+            Self::TxnType => "Type",
+            Self::AssetId => "Asset ID",
+            Self::AppId => "App ID",
+            Self::MinRound => "Min Round",
+            Self::MaxRound => "Max Round",
+            Self::AfterTime => "After",
+            Self::BeforeTime => "Before",
+            Self::AmountAbove => "Amount >",
+            Self::AmountBelow => "Amount <",
+            Self::NotePrefix => "Note Prefix",
+            Self::RekeyTo => "Rekeys Only",
+        }
+    }
+
+    /// Hint shown while the field is empty.
+    #[must_use]
+    pub const fn placeholder(self) -> &'static str {
+        match self {
+            Self::Address => "<58-character address>",
+            Self::AddressRole => "any | sender | receiver | freeze-target",
+            Self::TxnType => "pay | axfer | acfg | afrz | appl | keyreg | stpf | hb",
+            Self::AssetId | Self::AppId => "<id>",
+            Self::MinRound | Self::MaxRound => "<round>",
+            Self::AfterTime | Self::BeforeTime => "YYYY-MM-DD or RFC 3339",
+            Self::AmountAbove | Self::AmountBelow => "microAlgos, or asset base units",
+            Self::NotePrefix => "<text>",
+            Self::RekeyTo => "yes | no",
+        }
+    }
+}
+
+/// State for the advanced transaction search form.
+///
+/// Every field is edited as text and parsed on submit by [`Self::to_query`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AdvancedSearchForm {
+    values: [String; AdvancedSearchField::ALL.len()],
+    pub active_field: AdvancedSearchField,
+}
+
+impl Default for AdvancedSearchForm {
+    fn default() -> Self {
+        Self {
+            values: Default::default(),
+            active_field: AdvancedSearchField::Address,
+        }
+    }
+}
+
+impl AdvancedSearchForm {
+    /// Creates a form prefilled with an earlier query.
+    #[must_use]
+    pub fn from_query(query: &TxnQuery) -> Self {
+        let mut form = Self::default();
+        let mut set = |field: AdvancedSearchField, value: Option<String>| {
+            form.values[field.index()] = value.unwrap_or_default();
+        };
+        set(AdvancedSearchField::Address, query.address.clone());
+        set(
+            AdvancedSearchField::AddressRole,
+            query.address_role.api_code().map(str::to_string),
+        );
+        set(
+            AdvancedSearchField::TxnType,
+            query
+                .txn_type
+                .and_then(|t| t.api_code())
+                .map(str::to_string),
+        );
+        set(
+            AdvancedSearchField::AssetId,
+            query.asset_id.map(|id| id.to_string()),
+        );
+        set(
+            AdvancedSearchField::AppId,
+            query.app_id.map(|id| id.to_string()),
+        );
+        set(
+            AdvancedSearchField::MinRound,
+            query.min_round.map(|r| r.to_string()),
+        );
+        set(
+            AdvancedSearchField::MaxRound,
+            query.max_round.map(|r| r.to_string()),
+        );
+        set(AdvancedSearchField::AfterTime, query.after_time.clone());
+        set(AdvancedSearchField::BeforeTime, query.before_time.clone());
+        set(
+            AdvancedSearchField::AmountAbove,
+            query.currency_greater_than.map(|a| a.to_string()),
+        );
+        set(
+            AdvancedSearchField::AmountBelow,
+            query.currency_less_than.map(|a| a.to_string()),
+        );
+        set(AdvancedSearchField::NotePrefix, query.note_prefix.clone());
+        set(
+            AdvancedSearchField::RekeyTo,
+            query.rekey_to.then(|| "yes".to_string()),
+        );
+        form
+    }
+
+    /// The text typed into a field.
+    #[must_use]
+    pub fn value(&self, field: AdvancedSearchField) -> &str {
+        &self.values[field.index()]
+    }
+
+    /// Returns true if every field is blank.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.values.iter().all(|value| value.trim().is_empty())
+    }
+
+    pub fn next_field(&mut self) {
+        self.active_field = self.active_field.next();
+    }
+
+    pub fn prev_field(&mut self) {
+        self.active_field = self.active_field.prev();
+    }
+
+    pub fn push_char(&mut self, c: char) {
+        self.values[self.active_field.index()].push(c);
+    }
+
+    pub fn backspace(&mut self) {
+        self.values[self.active_field.index()].pop();
+    }
+
+    /// Parses the form into a validated query.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AlgoError::InvalidInput`] naming the first malformed field,
+    /// or the error from [`TxnQuery::validate`].
+    pub fn to_query(&self) -> Result<TxnQuery, AlgoError> {
+        let text = |field: AdvancedSearchField| {
+            let value = self.value(field).trim();
+            (!value.is_empty()).then_some(value)
+        };
+        let number = |field: AdvancedSearchField| {
+            text(field)
+                .map(|value| {
+                    value.parse::<u64>().map_err(|_| {
+                        AlgoError::invalid_input(format!("{} needs a number", field.label()))
+                    })
+                })
+                .transpose()
+        };
+        let time = |field: AdvancedSearchField| text(field).map(parse_query_time).transpose();
+
+        let address = text(AdvancedSearchField::Address)
+            .map(|value| {
+                if is_valid_address(value) {
+                    Ok(value.to_string())
+                } else {
+                    Err(AlgoError::invalid_input(
+                        "Address needs a 58-character Algorand address",
+                    ))
+                }
+            })
+            .transpose()?;
+        let txn_type = text(AdvancedSearchField::TxnType)
+            .map(|value| {
+                TxnType::FILTERABLE
+                    .into_iter()
+                    .find(|t| t.api_code() == Some(value))
+                    .ok_or_else(|| {
+                        AlgoError::invalid_input(format!("Unknown transaction type '{value}'"))
+                    })
+            })
+            .transpose()?;
+        let rekey_to = match text(AdvancedSearchField::RekeyTo) {
+            None | Some("no" | "n") => false,
+            Some("yes" | "y") => true,
+            Some(value) => {
+                return Err(AlgoError::invalid_input(format!(
+                    "Rekeys Only needs yes or no, got '{value}'"
+                )));
+            }
+        };
+
+        let query = TxnQuery {
+            address,
+            address_role: AddressRole::parse(self.value(AdvancedSearchField::AddressRole))?,
+            txn_type,
+            asset_id: number(AdvancedSearchField::AssetId)?,
+            app_id: number(AdvancedSearchField::AppId)?,
+            min_round: number(AdvancedSearchField::MinRound)?,
+            max_round: number(AdvancedSearchField::MaxRound)?,
+            after_time: time(AdvancedSearchField::AfterTime)?,
+            before_time: time(AdvancedSearchField::BeforeTime)?,
+            currency_greater_than: number(AdvancedSearchField::AmountAbove)?,
+            currency_less_than: number(AdvancedSearchField::AmountBelow)?,
+            note_prefix: text(AdvancedSearchField::NotePrefix).map(str::to_string),
+            rekey_to,
+        };
+        query.validate()?;
+        Ok(query)
+    }
+}
+
+// ============================================================================
+// Export Prompt
+// ============================================================================
+
+/// State for the list export prompt.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExportPrompt {
+    /// The list to export.
+    pub source: ExportSource,
+    /// Output path; its extension picks the format.
+    pub path: String,
+}
+
+impl ExportPrompt {
+    /// Creates a prompt prefilled with the source's default CSV path.
+    #[must_use]
+    pub fn new(source: ExportSource) -> Self {
+        let path = source.default_path(ExportFormat::Csv);
+        Self { source, path }
+    }
+
+    /// Switches the path between the CSV and JSON Lines extensions.
+    pub fn toggle_format(&mut self) {
+        let format = ExportFormat::from_path(&self.path)
+            .map(ExportFormat::toggled)
+            .unwrap_or_default();
+        self.path = format.apply_to(&self.path);
+    }
+}
+
 // ============================================================================
 // Popup State
 // ============================================================================
@@ -148,6 +446,16 @@ pub enum PopupState {
     ConfirmQuit,
     /// Custom network form popup.
     NetworkForm(NetworkFormState),
+    /// Account address prompt for an application local state lookup.
+    LocalStateLookup(String),
+    /// Query prompt for the live transaction feed filter.
+    TxnFilter(String),
+    /// Advanced transaction search form.
+    AdvancedSearch(AdvancedSearchForm),
+    /// Output path prompt for a list export.
+    Export(ExportPrompt),
+    /// Transaction file path or base64 group prompt for a simulation.
+    Simulate(String),
 }
 
 impl PopupState {
@@ -253,4 +561,63 @@ mod tests {
         assert_eq!(form.algod_port, "4001");
         assert_eq!(form.indexer_port, "8980");
     }
+
+    #[test]
+    fn test_advanced_search_form_to_query() {
+        let mut form = AdvancedSearchForm::default();
+        for c in "AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAY5HFKQ".chars() {
+            form.push_char(c);
+        }
+        form.next_field();
+        "sender".chars().for_each(|c| form.push_char(c));
+        form.next_field();
+        "axfer".chars().for_each(|c| form.push_char(c));
+        form.prev_field();
+        form.prev_field();
+        form.prev_field();
+        assert_eq!(form.active_field, AdvancedSearchField::RekeyTo); // Wraps around
+        form.push_char('y');
+
+        let query = form.to_query().unwrap();
+        assert_eq!(query.address_role, AddressRole::Sender);
+        assert_eq!(query.txn_type, Some(TxnType::AssetTransfer));
+        assert!(query.rekey_to);
+        assert_eq!(
+            AdvancedSearchForm::from_query(&query).to_query().ok(),
+            Some(query)
+        );
+
+        form.backspace();
+        form.push_char('x');
+        assert!(form.to_query().is_err());
+    }
+
+    #[test]
+    fn test_advanced_search_form_errors() {
+        let mut form = AdvancedSearchForm::default();
+        assert!(form.to_query().is_err()); // Empty
+
+        form.active_field = AdvancedSearchField::MinRound;
+        "12a".chars().for_each(|c| form.push_char(c));
+        assert_eq!(
+            form.to_query().unwrap_err().to_string(),
+            AlgoError::invalid_input("Min Round needs a number").to_string()
+        );
+    }
+
+    #[test]
+    fn test_export_prompt_toggle_format() {
+        let mut prompt = ExportPrompt::new(ExportSource::BlockTransactions { round: 42 });
+        assert_eq!(prompt.path, "lazylora_block_42_txns.csv");
+
+        prompt.toggle_format();
+        assert_eq!(prompt.path, "lazylora_block_42_txns.jsonl");
+        prompt.toggle_format();
+        assert_eq!(prompt.path, "lazylora_block_42_txns.csv");
+
+        // Unknown extensions are replaced with CSV
+        prompt.path = "out.txt".to_string();
+        prompt.toggle_format();
+        assert_eq!(prompt.path, "out.csv");
+    }
 }
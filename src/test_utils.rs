@@ -59,6 +59,7 @@ impl TransactionMother {
             group: None,
             details: TransactionDetails::None,
             inner_transactions: Vec::new(),
+            signature: None,
         }
     }
 
@@ -88,6 +89,7 @@ impl TransactionMother {
             group: None,
             details: TransactionDetails::None,
             inner_transactions: Vec::new(),
+            signature: None,
         }
     }
 
@@ -108,6 +110,7 @@ impl TransactionMother {
             group: None,
             details: TransactionDetails::None,
             inner_transactions: Vec::new(),
+            signature: None,
         }
     }
 
@@ -139,6 +142,7 @@ impl TransactionMother {
             group: None,
             details: TransactionDetails::None,
             inner_transactions: Vec::new(),
+            signature: None,
         }
     }
 
@@ -444,6 +448,9 @@ impl TransactionMother {
                 global_state_schema: None,
                 local_state_schema: None,
                 extra_program_pages: None,
+                logs: Vec::new(),
+                global_state_delta: Vec::new(),
+                local_state_delta: Vec::new(),
             }),
             ..Self::base_txn(
                 "INNER_APP_1",
@@ -608,6 +615,9 @@ impl TransactionMother {
                 global_state_schema: None,
                 local_state_schema: None,
                 extra_program_pages: None,
+                logs: Vec::new(),
+                global_state_delta: Vec::new(),
+                local_state_delta: Vec::new(),
             }),
             inner_transactions: vec![
                 inner_pay,
@@ -711,10 +721,6 @@ impl AccountMother {
             total_boxes: 0,
             auth_addr: None,
             participation: None,
-            assets: Vec::new(),
-            created_assets: Vec::new(),
-            apps_local_state: Vec::new(),
-            created_apps: Vec::new(),
             nfd: None,
         }
     }
@@ -749,7 +755,6 @@ impl AccountMother {
     /// stable values for snapshot testing.
     #[must_use]
     pub fn with_nfd() -> AccountDetails {
-        use crate::domain::account::AccountAssetHolding;
         use crate::domain::nfd::NfdInfo;
 
         AccountDetails {
@@ -767,14 +772,6 @@ impl AccountMother {
             total_boxes: 0,
             auth_addr: None,
             participation: None,
-            assets: vec![AccountAssetHolding {
-                asset_id: 31_566_704, // USDC
-                amount: 1_000_000,
-                is_frozen: false,
-            }],
-            created_assets: Vec::new(),
-            apps_local_state: Vec::new(),
-            created_apps: Vec::new(),
             nfd: Some(NfdInfo {
                 name: "silvio.algo".to_string(),
                 deposit_account: Some(
@@ -1124,6 +1121,7 @@ impl BlockMother {
             info,
             transactions,
             txn_type_counts,
+            next_token: None,
         }
     }
 
@@ -1144,6 +1142,7 @@ impl BlockMother {
             group: None,
             details: TransactionDetails::None,
             inner_transactions: Vec::new(),
+            signature: None,
         }
     }
 }
@@ -1165,6 +1164,38 @@ impl JsonMother {
         })
     }
 
+    #[must_use]
+    pub fn multisig_payment() -> serde_json::Value {
+        let mut json = Self::payment();
+        json["id"] = serde_json::json!("multisig-payment-id");
+        // Public keys of all-zero and all-one bytes; only the first signed
+        json["signature"] = serde_json::json!({
+            "multisig": {
+                "version": 1_u64,
+                "threshold": 1_u64,
+                "subsignature": [
+                    {
+                        "public-key": "AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA=",
+                        "signature": "c2ln"
+                    },
+                    {"public-key": "AQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQE="}
+                ]
+            }
+        });
+        json
+    }
+
+    #[must_use]
+    pub fn logicsig_payment() -> serde_json::Value {
+        let mut json = Self::payment();
+        json["id"] = serde_json::json!("logicsig-payment-id");
+        // "#pragma version 8; pushint 1" run as a contract account
+        json["signature"] = serde_json::json!({
+            "logicsig": {"logic": "CIEB", "args": ["AQ=="]}
+        });
+        json
+    }
+
     #[must_use]
     pub fn payment() -> serde_json::Value {
         let mut json = Self::base_json("test-payment-id");
@@ -1310,6 +1341,52 @@ impl JsonMother {
         json
     }
 
+    #[must_use]
+    pub fn app_call_with_logs() -> serde_json::Value {
+        let mut json = Self::base_json("app-call-logs-id");
+        json["sender"] = serde_json::json!("CALLER_ADDRESS");
+        json["confirmed-round"] = serde_json::json!(12352_u64);
+        json["application-transaction"] = serde_json::json!({
+            "application-id": 123_456_u64,
+            "on-completion": "noop"
+        });
+        // "hello" followed by an ABI return of uint64 42
+        json["logs"] = serde_json::json!(["aGVsbG8=", "FR98dQAAAAAAAAAq"]);
+
+        let mut inner = Self::base_json("app-call-logs-inner-id");
+        inner["application-transaction"] = serde_json::json!({
+            "application-id": 654_321_u64,
+            "on-completion": "noop"
+        });
+        inner["logs"] = serde_json::json!(["AAAAAAAAA+g="]);
+        json["inner-txns"] = serde_json::json!([inner]);
+        json
+    }
+
+    #[must_use]
+    pub fn app_call_with_state_delta() -> serde_json::Value {
+        let mut json = Self::base_json("app-call-delta-id");
+        json["sender"] = serde_json::json!("CALLER_ADDRESS");
+        json["confirmed-round"] = serde_json::json!(12352_u64);
+        json["application-transaction"] = serde_json::json!({
+            "application-id": 123_456_u64,
+            "on-completion": "noop"
+        });
+        // Keys: "counter", "name", "stale", "points"
+        json["global-state-delta"] = serde_json::json!([
+            {"key": "Y291bnRlcg==", "value": {"action": 2, "uint": 7_u64}},
+            {"key": "bmFtZQ==", "value": {"action": 1, "bytes": "bGF6eWxvcmE="}},
+            {"key": "c3RhbGU=", "value": {"action": 3}}
+        ]);
+        json["local-state-delta"] = serde_json::json!([
+            {
+                "address": "CALLER_ADDRESS",
+                "delta": [{"key": "cG9pbnRz", "value": {"action": 2, "uint": 100_u64}}]
+            }
+        ]);
+        json
+    }
+
     #[must_use]
     pub fn app_call_on_complete(on_complete: &str) -> serde_json::Value {
         let mut json = Self::base_json(&format!("app-{}-id", on_complete));
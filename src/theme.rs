@@ -1,54 +1,576 @@
-//! Theme and styling constants for the LazyLora TUI.
+//! Color themes for the LazyLora TUI.
 //!
-//! Tokyo Night-inspired colors and consistent styling.
+//! All colors are read from the active [`Theme`], installed once at startup
+//! with [`install`] and accessed with [`theme`]. Three themes are built in
+//! (`dark`, the Tokyo Night-inspired default, `light` and `high-contrast`);
+//! more can be added as JSON files in the `themes` directory next to the
+//! configuration file:
+//!
+//! ```json
+//! {
+//!   "base": "light",
+//!   "colors": { "primary": "#005f87", "payment": "green" }
+//! }
+//! ```
+//!
+//! A theme file starts from its `base` theme (default `dark`) and overrides
+//! any of the named colors. Colors are names (`red`, `dark gray`), ANSI
+//! indexes (`214`) or `#rrggbb` hex values.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
 
 use ratatui::style::{Color, Modifier, Style};
+use serde::Deserialize;
+use thiserror::Error;
+
+use crate::domain::TxnType;
+
+/// Name of the default theme.
+pub const DEFAULT_THEME: &str = "dark";
+
+/// Names of the built-in themes.
+pub const BUILT_IN_THEMES: &[&str] = &["dark", "light", "high-contrast"];
+
+/// The theme used for rendering, installed at startup.
+static THEME: OnceLock<Theme> = OnceLock::new();
+
+/// Installs the active theme.
+///
+/// Only the first call has an effect; until then [`theme`] returns the
+/// default dark theme.
+pub fn install(theme: Theme) {
+    let _ = THEME.set(theme);
+}
+
+/// Returns the active theme.
+#[must_use]
+pub fn theme() -> &'static Theme {
+    THEME.get_or_init(Theme::dark)
+}
 
 // ============================================================================
-// Color Constants
+// Theme
 // ============================================================================
 
-/// Primary accent color - focused elements and highlights.
-pub const PRIMARY_COLOR: Color = Color::Cyan;
+/// A named set of colors for every part of the UI.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Theme {
+    /// Theme name.
+    pub name: String,
+
+    // === Base Palette ===
+    /// Primary accent - focused elements and highlights.
+    pub primary: Color,
+    /// Secondary accent.
+    pub secondary: Color,
+    /// Success indicator.
+    pub success: Color,
+    /// Warning indicator and amounts.
+    pub warning: Color,
+    /// Error indicator.
+    pub error: Color,
+    /// Labels and secondary text.
+    pub muted: Color,
+    /// Special highlights (section titles, keys).
+    pub accent: Color,
+    /// Background of filled panes.
+    pub background: Color,
+    /// Slightly raised surfaces (graph grid lines).
+    pub surface: Color,
+    /// Regular values.
+    pub text: Color,
+    /// Unfocused borders, separators and graph connectors.
+    pub border: Color,
+    /// Background of selected rows.
+    pub selection: Color,
+    /// Text drawn on a `primary` background.
+    pub on_primary: Color,
+
+    // === Entities ===
+    /// Accounts (graph columns, flow diagrams).
+    pub account: Color,
+    /// Applications.
+    pub application: Color,
+    /// Assets.
+    pub asset: Color,
+
+    // === Transaction Types ===
+    /// Payment transactions.
+    pub payment: Color,
+    /// Application calls.
+    pub app_call: Color,
+    /// Asset transfers.
+    pub asset_transfer: Color,
+    /// Asset configuration.
+    pub asset_config: Color,
+    /// Asset freeze.
+    pub asset_freeze: Color,
+    /// Key registration.
+    pub key_reg: Color,
+    /// State proofs.
+    pub state_proof: Color,
+    /// Heartbeats.
+    pub heartbeat: Color,
+    /// Unknown transaction types.
+    pub unknown: Color,
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self::dark()
+    }
+}
+
+impl Theme {
+    /// The default Tokyo Night-inspired theme for dark terminals.
+    #[must_use]
+    pub fn dark() -> Self {
+        Self {
+            name: "dark".to_string(),
+            primary: Color::Cyan,
+            secondary: Color::Blue,
+            success: Color::Green,
+            warning: Color::Yellow,
+            error: Color::Red,
+            muted: Color::Gray,
+            accent: Color::Magenta,
+            background: Color::Rgb(26, 27, 38),
+            surface: Color::Rgb(36, 40, 59),
+            text: Color::White,
+            border: Color::DarkGray,
+            selection: Color::DarkGray,
+            on_primary: Color::Black,
+            account: Color::Yellow,
+            application: Color::Cyan,
+            asset: Color::Magenta,
+            payment: Color::Green,
+            app_call: Color::Blue,
+            asset_transfer: Color::Yellow,
+            asset_config: Color::Cyan,
+            asset_freeze: Color::Magenta,
+            key_reg: Color::Red,
+            state_proof: Color::Gray,
+            heartbeat: Color::White,
+            unknown: Color::DarkGray,
+        }
+    }
+
+    /// A Tokyo Night Day-inspired theme for light terminals.
+    #[must_use]
+    pub fn light() -> Self {
+        let blue = Color::Rgb(46, 125, 233);
+        let cyan = Color::Rgb(0, 113, 151);
+        let green = Color::Rgb(88, 117, 57);
+        let yellow = Color::Rgb(140, 108, 62);
+        let red = Color::Rgb(245, 42, 101);
+        let magenta = Color::Rgb(152, 84, 241);
+        let muted = Color::Rgb(97, 114, 176);
+        let border = Color::Rgb(168, 174, 203);
+
+        Self {
+            name: "light".to_string(),
+            primary: cyan,
+            secondary: blue,
+            success: green,
+            warning: yellow,
+            error: red,
+            muted,
+            accent: magenta,
+            background: Color::Rgb(225, 226, 231),
+            surface: Color::Rgb(208, 213, 227),
+            text: Color::Rgb(55, 96, 191),
+            border,
+            selection: Color::Rgb(183, 193, 227),
+            on_primary: Color::Rgb(225, 226, 231),
+            account: yellow,
+            application: cyan,
+            asset: magenta,
+            payment: green,
+            app_call: blue,
+            asset_transfer: yellow,
+            asset_config: cyan,
+            asset_freeze: magenta,
+            key_reg: red,
+            state_proof: muted,
+            heartbeat: Color::Rgb(55, 96, 191),
+            unknown: border,
+        }
+    }
+
+    /// A high-contrast theme using the colorblind-safe Okabe-Ito palette.
+    #[must_use]
+    pub fn high_contrast() -> Self {
+        let orange = Color::Rgb(230, 159, 0);
+        let sky_blue = Color::Rgb(86, 180, 233);
+        let green = Color::Rgb(0, 158, 115);
+        let yellow = Color::Rgb(240, 228, 66);
+        let blue = Color::Rgb(0, 114, 178);
+        let vermillion = Color::Rgb(213, 94, 0);
+        let purple = Color::Rgb(204, 121, 167);
+        let light_gray = Color::Rgb(208, 208, 208);
+
+        Self {
+            name: "high-contrast".to_string(),
+            primary: sky_blue,
+            secondary: blue,
+            success: green,
+            warning: orange,
+            error: vermillion,
+            muted: light_gray,
+            accent: purple,
+            background: Color::Black,
+            surface: Color::Rgb(38, 38, 38),
+            text: Color::Rgb(255, 255, 255),
+            border: Color::Rgb(191, 191, 191),
+            selection: Color::Rgb(64, 64, 64),
+            on_primary: Color::Black,
+            account: orange,
+            application: sky_blue,
+            asset: purple,
+            payment: green,
+            app_call: sky_blue,
+            asset_transfer: orange,
+            asset_config: yellow,
+            asset_freeze: purple,
+            key_reg: vermillion,
+            state_proof: light_gray,
+            heartbeat: Color::Rgb(255, 255, 255),
+            unknown: Color::Rgb(191, 191, 191),
+        }
+    }
+
+    /// Returns a built-in theme by name.
+    #[must_use]
+    pub fn built_in(name: &str) -> Option<Self> {
+        match name {
+            "dark" => Some(Self::dark()),
+            "light" => Some(Self::light()),
+            "high-contrast" => Some(Self::high_contrast()),
+            _ => None,
+        }
+    }
+
+    /// Loads a theme by name: a built-in theme, or `<name>.json` in `themes_dir`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no such theme exists or its file is invalid.
+    pub fn load(name: &str, themes_dir: &Path) -> Result<Self, ThemeError> {
+        if let Some(theme) = Self::built_in(name) {
+            return Ok(theme);
+        }
 
-/// Secondary accent color.
-pub const SECONDARY_COLOR: Color = Color::Blue;
+        let path = themes_dir.join(format!("{name}.json"));
+        let content = fs::read_to_string(&path).map_err(|_| ThemeError::NotFound {
+            name: name.to_string(),
+            path: path.clone(),
+        })?;
+        Self::from_json(name, &content).map_err(|problems| ThemeError::Invalid { path, problems })
+    }
 
-/// Success indicator color.
-pub const SUCCESS_COLOR: Color = Color::Green;
+    /// Parses a theme file.
+    ///
+    /// # Errors
+    ///
+    /// Returns one line per problem: unknown base, color name or color value.
+    pub fn from_json(name: &str, json: &str) -> Result<Self, Vec<String>> {
+        let file: ThemeFile = serde_json::from_str(json).map_err(|e| vec![e.to_string()])?;
 
-/// Warning indicator color.
-pub const WARNING_COLOR: Color = Color::Yellow;
+        let base = file.base.as_deref().unwrap_or(DEFAULT_THEME);
+        let mut theme = Self::built_in(base).ok_or_else(|| {
+            vec![format!(
+                "unknown base theme '{base}' (expected one of: {})",
+                BUILT_IN_THEMES.join(", ")
+            )]
+        })?;
+        theme.name = name.to_string();
 
-/// Error indicator color.
-pub const ERROR_COLOR: Color = Color::Red;
+        let mut problems = Vec::new();
+        for (key, value) in &file.colors {
+            let Ok(color) = value.parse::<Color>() else {
+                problems.push(format!("{key}: invalid color '{value}'"));
+                continue;
+            };
+            match theme.color_mut(key) {
+                Some(slot) => *slot = color,
+                None => problems.push(format!("unknown color name '{key}'")),
+            }
+        }
 
-/// Muted text color.
-pub const MUTED_COLOR: Color = Color::Gray;
+        if problems.is_empty() {
+            Ok(theme)
+        } else {
+            Err(problems)
+        }
+    }
 
-/// Accent color for special highlights.
-pub const ACCENT_COLOR: Color = Color::Magenta;
+    /// Returns the color with the given theme file name.
+    fn color_mut(&mut self, name: &str) -> Option<&mut Color> {
+        Some(match name {
+            "primary" => &mut self.primary,
+            "secondary" => &mut self.secondary,
+            "success" => &mut self.success,
+            "warning" => &mut self.warning,
+            "error" => &mut self.error,
+            "muted" => &mut self.muted,
+            "accent" => &mut self.accent,
+            "background" => &mut self.background,
+            "surface" => &mut self.surface,
+            "text" => &mut self.text,
+            "border" => &mut self.border,
+            "selection" => &mut self.selection,
+            "on_primary" => &mut self.on_primary,
+            "account" => &mut self.account,
+            "application" => &mut self.application,
+            "asset" => &mut self.asset,
+            "payment" => &mut self.payment,
+            "app_call" => &mut self.app_call,
+            "asset_transfer" => &mut self.asset_transfer,
+            "asset_config" => &mut self.asset_config,
+            "asset_freeze" => &mut self.asset_freeze,
+            "key_reg" => &mut self.key_reg,
+            "state_proof" => &mut self.state_proof,
+            "heartbeat" => &mut self.heartbeat,
+            "unknown" => &mut self.unknown,
+            _ => return None,
+        })
+    }
 
-/// Tokyo Night background color.
-pub const BG_COLOR: Color = Color::Rgb(26, 27, 38);
+    /// Returns the display color for a transaction type.
+    #[must_use]
+    pub const fn txn_color(&self, txn_type: TxnType) -> Color {
+        match txn_type {
+            TxnType::Payment => self.payment,
+            TxnType::AppCall => self.app_call,
+            TxnType::AssetTransfer => self.asset_transfer,
+            TxnType::AssetConfig => self.asset_config,
+            TxnType::AssetFreeze => self.asset_freeze,
+            TxnType::KeyReg => self.key_reg,
+            TxnType::StateProof => self.state_proof,
+            TxnType::Heartbeat => self.heartbeat,
+            TxnType::Unknown => self.unknown,
+        }
+    }
+
+    // ========================================================================
+    // Styles
+    // ========================================================================
+
+    /// Border style for unfocused elements.
+    #[must_use]
+    pub fn border_style(&self) -> Style {
+        Style::new().fg(self.border)
+    }
+
+    /// Border style for focused/active elements.
+    #[must_use]
+    pub fn focused_border_style(&self) -> Style {
+        Style::new().fg(self.primary)
+    }
+
+    /// Title style for focused/active elements.
+    #[must_use]
+    pub fn focused_title_style(&self) -> Style {
+        Style::new().fg(self.primary).add_modifier(Modifier::BOLD)
+    }
+
+    /// Style for selected items in lists.
+    #[must_use]
+    pub fn selected_style(&self) -> Style {
+        Style::new().bg(self.selection)
+    }
+
+    /// Style for highlighted items with emphasis.
+    #[must_use]
+    pub fn highlight_style(&self) -> Style {
+        Style::new().bg(self.selection).add_modifier(Modifier::BOLD)
+    }
+}
+
+/// Returns a color as `#rrggbb` for SVG export.
+///
+/// Named terminal colors have no fixed value; they are exported with their
+/// Tokyo Night equivalents. Indexed colors use the standard xterm palette.
+#[must_use]
+pub fn hex(color: Color) -> String {
+    let (r, g, b) = match color {
+        Color::Rgb(r, g, b) => (r, g, b),
+        Color::Black | Color::Reset => (26, 27, 38),
+        Color::Red | Color::LightRed => (247, 118, 142),
+        Color::Green | Color::LightGreen => (158, 206, 106),
+        Color::Yellow | Color::LightYellow => (224, 175, 104),
+        Color::Blue | Color::LightBlue => (122, 162, 247),
+        Color::Magenta | Color::LightMagenta => (187, 154, 247),
+        Color::Cyan | Color::LightCyan => (125, 207, 255),
+        Color::Gray => (169, 177, 214),
+        Color::DarkGray => (86, 95, 137),
+        Color::White => (192, 202, 245),
+        Color::Indexed(i) => indexed_rgb(i),
+    };
+    format!("#{r:02x}{g:02x}{b:02x}")
+}
+
+/// The xterm RGB value of a 256-color palette index.
+fn indexed_rgb(index: u8) -> (u8, u8, u8) {
+    const ANSI: [(u8, u8, u8); 16] = [
+        (0, 0, 0),
+        (128, 0, 0),
+        (0, 128, 0),
+        (128, 128, 0),
+        (0, 0, 128),
+        (128, 0, 128),
+        (0, 128, 128),
+        (192, 192, 192),
+        (128, 128, 128),
+        (255, 0, 0),
+        (0, 255, 0),
+        (255, 255, 0),
+        (0, 0, 255),
+        (255, 0, 255),
+        (0, 255, 255),
+        (255, 255, 255),
+    ];
+    const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+    match index {
+        0..=15 => ANSI[usize::from(index)],
+        16..=231 => {
+            let cube = usize::from(index - 16);
+            (
+                CUBE_LEVELS[cube / 36],
+                CUBE_LEVELS[cube / 6 % 6],
+                CUBE_LEVELS[cube % 6],
+            )
+        }
+        232..=255 => {
+            let level = 8 + 10 * (index - 232);
+            (level, level, level)
+        }
+    }
+}
 
 // ============================================================================
-// Style Constants
+// Theme Files
 // ============================================================================
 
-/// Default border style for unfocused elements.
-pub const BORDER_STYLE: Style = Style::new().fg(Color::DarkGray);
+/// On-disk theme format.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ThemeFile {
+    /// Built-in theme to start from.
+    #[serde(default)]
+    base: Option<String>,
+    /// Color overrides by name.
+    #[serde(default)]
+    colors: BTreeMap<String, String>,
+}
+
+/// Error loading a theme.
+#[derive(Debug, Error)]
+pub enum ThemeError {
+    /// No built-in theme or theme file with this name.
+    #[error(
+        "Theme '{name}' not found: not a built-in theme ({}) and {} does not exist",
+        BUILT_IN_THEMES.join(", "),
+        path.display()
+    )]
+    NotFound { name: String, path: PathBuf },
+
+    /// The theme file is invalid.
+    #[error("Invalid theme file {}:\n{}", path.display(), problems.join("\n"))]
+    Invalid {
+        path: PathBuf,
+        problems: Vec<String>,
+    },
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case::payment(TxnType::Payment, Color::Green)]
+    #[case::app_call(TxnType::AppCall, Color::Blue)]
+    #[case::asset_transfer(TxnType::AssetTransfer, Color::Yellow)]
+    #[case::asset_config(TxnType::AssetConfig, Color::Cyan)]
+    #[case::asset_freeze(TxnType::AssetFreeze, Color::Magenta)]
+    #[case::key_reg(TxnType::KeyReg, Color::Red)]
+    #[case::state_proof(TxnType::StateProof, Color::Gray)]
+    #[case::heartbeat(TxnType::Heartbeat, Color::White)]
+    #[case::unknown(TxnType::Unknown, Color::DarkGray)]
+    fn test_dark_txn_colors(#[case] txn_type: TxnType, #[case] expected: Color) {
+        assert_eq!(Theme::dark().txn_color(txn_type), expected);
+    }
+
+    #[test]
+    fn test_built_in_themes() {
+        for name in BUILT_IN_THEMES {
+            let theme = Theme::built_in(name).unwrap();
+            assert_eq!(theme.name, *name);
+        }
+        assert_eq!(Theme::built_in("solarized"), None);
+    }
+
+    #[test]
+    fn test_theme_file_overrides_base() {
+        let theme = Theme::from_json(
+            "mine",
+            r##"{"base": "light", "colors": {"primary": "#005f87", "payment": "dark gray"}}"##,
+        )
+        .unwrap();
+
+        assert_eq!(theme.name, "mine");
+        assert_eq!(theme.primary, Color::Rgb(0, 95, 135));
+        assert_eq!(theme.payment, Color::DarkGray);
+        assert_eq!(theme.error, Theme::light().error);
+    }
 
-/// Border style for focused/active elements.
-pub const FOCUSED_BORDER_STYLE: Style = Style::new().fg(PRIMARY_COLOR);
+    #[test]
+    fn test_theme_file_reports_problems() {
+        let problems = Theme::from_json(
+            "broken",
+            r#"{"colors": {"primray": "red", "error": "reddish"}}"#,
+        )
+        .unwrap_err();
+        assert_eq!(
+            problems,
+            [
+                "error: invalid color 'reddish'",
+                "unknown color name 'primray'"
+            ]
+        );
 
-/// Title style for focused/active elements.
-pub const FOCUSED_TITLE_STYLE: Style = Style::new().fg(PRIMARY_COLOR).add_modifier(Modifier::BOLD);
+        let problems = Theme::from_json("broken", r#"{"base": "sepia"}"#).unwrap_err();
+        assert!(problems[0].contains("unknown base theme 'sepia'"));
+    }
 
-/// Style for selected items in lists.
-pub const SELECTED_STYLE: Style = Style::new().bg(Color::DarkGray);
+    #[test]
+    fn test_load_missing_theme() {
+        let err = Theme::load("nope", Path::new("/nonexistent")).unwrap_err();
+        assert!(matches!(err, ThemeError::NotFound { .. }));
+        assert_eq!(
+            Theme::load("light", Path::new("/nonexistent")).unwrap(),
+            Theme::light()
+        );
+    }
 
-/// Style for highlighted items with emphasis.
-pub const HIGHLIGHT_STYLE: Style = Style::new()
-    .bg(Color::DarkGray)
-    .add_modifier(Modifier::BOLD);
+    #[rstest]
+    #[case::rgb(Color::Rgb(26, 27, 38), "#1a1b26")]
+    #[case::named(Color::Green, "#9ece6a")]
+    #[case::dark_gray(Color::DarkGray, "#565f89")]
+    #[case::indexed_ansi(Color::Indexed(4), "#000080")]
+    #[case::indexed_cube(Color::Indexed(196), "#ff0000")]
+    #[case::indexed_cube_mixed(Color::Indexed(110), "#87afd7")]
+    #[case::indexed_grey(Color::Indexed(244), "#808080")]
+    fn test_hex(#[case] color: Color, #[case] expected: &str) {
+        assert_eq!(hex(color), expected);
+    }
+}
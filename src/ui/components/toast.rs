@@ -12,7 +12,7 @@ use ratatui::{
     widgets::{Block, Borders, Clear, Paragraph},
 };
 
-use crate::theme::{ERROR_COLOR, SUCCESS_COLOR};
+use crate::theme::theme;
 
 // ============================================================================
 // Constants
@@ -123,8 +123,8 @@ fn create_toast_block() -> Block<'static> {
     Block::default()
         .borders(Borders::ALL)
         .border_set(border::ROUNDED)
-        .border_style(Style::default().fg(Color::DarkGray))
-        .style(Style::default().bg(Color::Black))
+        .border_style(Style::default().fg(theme().border))
+        .style(Style::default().bg(theme().background))
 }
 
 /// Determines the text color based on message content.
@@ -139,11 +139,11 @@ fn create_toast_block() -> Block<'static> {
 #[must_use]
 fn determine_text_color(message: &str) -> Color {
     if message.starts_with("[+]") {
-        SUCCESS_COLOR
+        theme().success
     } else if message.starts_with("[x]") {
-        ERROR_COLOR
+        theme().error
     } else {
-        Color::White
+        theme().text
     }
 }
 
@@ -258,19 +258,19 @@ mod tests {
         let cases = [
             TestCase {
                 message: "[+] Success",
-                expected: SUCCESS_COLOR,
+                expected: theme().success,
             },
             TestCase {
                 message: "[x] Error",
-                expected: ERROR_COLOR,
+                expected: theme().error,
             },
             TestCase {
                 message: "Info message",
-                expected: Color::White,
+                expected: theme().text,
             },
             TestCase {
                 message: "",
-                expected: Color::White,
+                expected: theme().text,
             },
         ];
 
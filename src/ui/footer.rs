@@ -10,8 +10,57 @@ use ratatui::{
     widgets::Paragraph,
 };
 
+use crate::commands::{AppCommand, InputContext};
+use crate::keymap::Keymap;
 use crate::state::App;
-use crate::theme::MUTED_COLOR;
+use crate::theme::theme;
+
+// ============================================================================
+// Footer Hints
+// ============================================================================
+
+/// A footer hint: the commands whose keys are shown, and its label.
+type Hint = (&'static [AppCommand], &'static str);
+
+/// Hints shown in the main browsing context.
+const MAIN_HINTS: &[Hint] = &[
+    (&[AppCommand::RequestQuit], "Quit"),
+    (&[AppCommand::ToggleHelp], "Help"),
+    (&[AppCommand::Refresh], "Refresh"),
+    (&[AppCommand::FocusInlineSearch], "Search"),
+    (&[AppCommand::OpenTxnFilter], "Filter"),
+    (&[AppCommand::OpenNetworkSelect], "Network"),
+    (&[AppCommand::ToggleLive], "Live"),
+    (&[AppCommand::CycleFocus], "Focus"),
+];
+
+/// Hints shown while the inline search bar is focused.
+const SEARCH_HINTS: &[Hint] = &[
+    (&[AppCommand::Dismiss], "Cancel"),
+    (&[AppCommand::CycleSearchType], "Type"),
+    (
+        &[AppCommand::SearchHistoryPrev, AppCommand::SearchHistoryNext],
+        "History",
+    ),
+    (
+        &[AppCommand::SearchCursorLeft, AppCommand::SearchCursorRight],
+        "Cursor",
+    ),
+    (&[AppCommand::SubmitSearch], "Search"),
+];
+
+/// Builds `key:Label` hints from the active keymap, skipping unbound commands.
+fn hint_text(keymap: &Keymap, context: InputContext, hints: &[Hint]) -> String {
+    hints
+        .iter()
+        .filter_map(|(commands, label)| {
+            keymap
+                .hint(context, commands)
+                .map(|keys| format!("{keys}:{label}"))
+        })
+        .collect::<Vec<_>>()
+        .join("  ")
+}
 
 // ============================================================================
 // Footer Rendering
@@ -22,23 +71,23 @@ pub fn render(frame: &mut Frame, area: Rect, app: &App) {
     let footer_text = get_footer_text(app);
 
     let footer = Paragraph::new(footer_text)
-        .style(Style::default().fg(MUTED_COLOR))
+        .style(Style::default().fg(theme().muted))
         .alignment(Alignment::Center);
 
     frame.render_widget(footer, area);
 }
 
 /// Returns context-appropriate footer hint text.
-fn get_footer_text(app: &App) -> &'static str {
+fn get_footer_text(app: &App) -> String {
     // Don't show footer hints when any popup/detail view is active (they have their own footers)
     if app.ui.popup_state.is_active() || app.ui.show_help || app.nav.is_showing_details() {
-        return "";
+        return String::new();
     }
 
     if app.ui.is_search_focused() {
-        "Esc:Cancel  Tab:Type  ↑↓:History  ←→:Cursor  Enter:Search"
+        hint_text(&app.keymap, InputContext::InlineSearch, SEARCH_HINTS)
     } else {
-        "q:Quit  ?:Help  r:Refresh  f:Search  n:Network  Space:Live  Tab:Focus"
+        hint_text(&app.keymap, InputContext::Main, MAIN_HINTS)
     }
 }
 
@@ -130,6 +179,30 @@ mod tests {
         });
     }
 
+    /// Tests footer hints follow the active keymap.
+    #[test]
+    fn test_footer_text_uses_keymap() {
+        let keymap = Keymap::default();
+        assert_eq!(
+            hint_text(&keymap, InputContext::Main, MAIN_HINTS),
+            "q:Quit  ?:Help  r:Refresh  f:Search  /:Filter  n:Network  Space:Live  Tab:Focus"
+        );
+        assert_eq!(
+            hint_text(&keymap, InputContext::InlineSearch, SEARCH_HINTS),
+            "Esc:Cancel  Tab:Type  ↑↓:History  ←→:Cursor  Enter:Search"
+        );
+
+        let mut keymap = Keymap::default();
+        let quit = crate::keymap::KeyChord::parse("q").unwrap();
+        keymap.bind(InputContext::Main, quit, AppCommand::Noop);
+        keymap.bind(
+            InputContext::Main,
+            crate::keymap::KeyChord::parse("ctrl+q").unwrap(),
+            AppCommand::RequestQuit,
+        );
+        assert!(hint_text(&keymap, InputContext::Main, MAIN_HINTS).starts_with("Ctrl+q:Quit"));
+    }
+
     /// Tests footer is empty when transaction detail view is active (popup has its own footer).
     #[test]
     fn test_footer_empty_when_transaction_detail_active() {
@@ -13,10 +13,10 @@ use ratatui::{
     widgets::{Block, Borders, Paragraph},
 };
 
-use crate::domain::NetworkConfig;
+use crate::domain::{DataSource, NetworkConfig};
 use crate::state::App;
 use crate::state::ui_state::SearchType;
-use crate::theme::{BORDER_STYLE, FOCUSED_BORDER_STYLE, MUTED_COLOR, PRIMARY_COLOR, SUCCESS_COLOR};
+use crate::theme::theme;
 
 use super::helpers::create_border_block;
 
@@ -71,12 +71,12 @@ pub fn render_search_bar(frame: &mut Frame, area: Rect, app: &App) {
         Block::default()
             .borders(Borders::ALL)
             .border_set(border::ROUNDED)
-            .border_style(FOCUSED_BORDER_STYLE)
+            .border_style(theme().focused_border_style())
     } else {
         Block::default()
             .borders(Borders::ALL)
             .border_set(border::ROUNDED)
-            .border_style(BORDER_STYLE)
+            .border_style(theme().border_style())
     };
     frame.render_widget(search_block, area);
 
@@ -97,8 +97,8 @@ fn build_search_line(app: &App) -> Line<'static> {
     if ui.search_loading {
         // Show loading state
         return Line::from(vec![
-            Span::styled("[/] ", Style::default().fg(MUTED_COLOR)),
-            Span::styled("Searching...", Style::default().fg(MUTED_COLOR)),
+            Span::styled("[/] ", Style::default().fg(theme().muted)),
+            Span::styled("Searching...", Style::default().fg(theme().muted)),
         ]);
     }
 
@@ -108,8 +108,8 @@ fn build_search_line(app: &App) -> Line<'static> {
     } else if ui.search_input.is_empty() {
         // Unfocused, empty: show placeholder
         Line::from(vec![
-            Span::styled("[/] ", Style::default().fg(MUTED_COLOR)),
-            Span::styled("Search...", Style::default().fg(MUTED_COLOR)),
+            Span::styled("[/] ", Style::default().fg(theme().muted)),
+            Span::styled("Search...", Style::default().fg(theme().muted)),
         ])
     } else {
         // Unfocused, has content: show last search
@@ -123,14 +123,14 @@ fn build_search_line(app: &App) -> Line<'static> {
         };
 
         Line::from(vec![
-            Span::styled("[/] ", Style::default().fg(MUTED_COLOR)),
+            Span::styled("[/] ", Style::default().fg(theme().muted)),
             Span::styled(
                 format!("[{}] ", type_indicator),
-                Style::default().fg(MUTED_COLOR),
+                Style::default().fg(theme().muted),
             ),
             Span::styled(
                 truncate_search_display(&ui.search_input, 40),
-                Style::default().fg(MUTED_COLOR),
+                Style::default().fg(theme().muted),
             ),
         ])
     }
@@ -153,16 +153,16 @@ fn build_focused_search_line(app: &App) -> Line<'static> {
     };
 
     let type_color = match ui.get_effective_search_type() {
-        Some(SearchType::Transaction) => Color::Cyan,
-        Some(SearchType::Block) => Color::Yellow,
-        Some(SearchType::Account) => Color::Magenta,
-        Some(SearchType::Asset) => Color::Green,
-        Some(SearchType::Application) => Color::Blue,
-        None => Color::Gray,
+        Some(SearchType::Transaction) => theme().primary,
+        Some(SearchType::Block) => theme().warning,
+        Some(SearchType::Account) => theme().accent,
+        Some(SearchType::Asset) => theme().success,
+        Some(SearchType::Application) => theme().secondary,
+        None => theme().muted,
     };
 
     let mut spans = vec![
-        Span::styled("[/] ", Style::default().fg(PRIMARY_COLOR)),
+        Span::styled("[/] ", Style::default().fg(theme().primary)),
         Span::styled(
             format!("[{type_char}]"),
             Style::default().fg(type_color).add_modifier(Modifier::BOLD),
@@ -173,10 +173,10 @@ fn build_focused_search_line(app: &App) -> Line<'static> {
     // Query text with cursor at correct position
     if query.is_empty() {
         // Cursor then placeholder
-        spans.push(Span::styled("▌", Style::default().fg(PRIMARY_COLOR)));
+        spans.push(Span::styled("▌", Style::default().fg(theme().primary)));
         spans.push(Span::styled(
             "Type to search...",
-            Style::default().fg(MUTED_COLOR),
+            Style::default().fg(theme().muted),
         ));
     } else {
         // Split text at cursor position (handle char boundaries)
@@ -189,7 +189,7 @@ fn build_focused_search_line(app: &App) -> Line<'static> {
             ));
         }
 
-        spans.push(Span::styled("▌", Style::default().fg(PRIMARY_COLOR)));
+        spans.push(Span::styled("▌", Style::default().fg(theme().primary)));
 
         if !after.is_empty() {
             spans.push(Span::styled(
@@ -233,18 +233,18 @@ fn truncate_search_display(s: &str, max_len: usize) -> String {
 fn create_live_indicator(is_live: bool) -> Line<'static> {
     if is_live {
         Line::from(vec![
-            Span::styled("● ", Style::default().fg(SUCCESS_COLOR)),
+            Span::styled("● ", Style::default().fg(theme().success)),
             Span::styled(
                 "LIVE",
                 Style::default()
-                    .fg(SUCCESS_COLOR)
+                    .fg(theme().success)
                     .add_modifier(Modifier::BOLD),
             ),
         ])
     } else {
         Line::from(vec![
-            Span::styled("○ ", Style::default().fg(MUTED_COLOR)),
-            Span::styled("PAUSED", Style::default().fg(MUTED_COLOR)),
+            Span::styled("○ ", Style::default().fg(theme().muted)),
+            Span::styled("PAUSED", Style::default().fg(theme().muted)),
         ])
     }
 }
@@ -305,19 +305,27 @@ fn render_network_indicator(frame: &mut Frame, area: Rect, row_y: u16, app: &App
     };
     let network_style = if is_custom {
         Style::default()
-            .fg(PRIMARY_COLOR)
+            .fg(theme().primary)
             .add_modifier(Modifier::BOLD)
     } else {
         Style::default()
-            .fg(SUCCESS_COLOR)
+            .fg(theme().success)
             .add_modifier(Modifier::BOLD)
     };
 
-    let network_label = Paragraph::new(label)
-        .style(network_style)
-        .alignment(Alignment::Right);
+    let mut spans = vec![Span::styled(label, network_style)];
+    if app.data.data_source == DataSource::AlgodOnly {
+        spans.push(Span::styled(
+            " (algod-only)",
+            Style::default().fg(theme().warning),
+        ));
+    }
+    let line = Line::from(spans);
+    let content_width = u16::try_from(line.width()).unwrap_or(u16::MAX);
+
+    let network_label = Paragraph::new(line).alignment(Alignment::Right);
 
-    let width = 20.min(area.width);
+    let width = content_width.max(20).min(area.width);
     let x = area.right().saturating_sub(width + 2);
     let network_area = Rect::new(x, row_y, width, 1);
     frame.render_widget(network_label, network_area);
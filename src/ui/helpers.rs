@@ -5,12 +5,12 @@
 
 use ratatui::{
     layout::Alignment,
-    style::{Color, Modifier, Style},
+    style::{Modifier, Style},
     symbols::border,
     widgets::{Block, Borders},
 };
 
-use crate::theme::{BORDER_STYLE, FOCUSED_BORDER_STYLE, FOCUSED_TITLE_STYLE};
+use crate::theme::theme;
 
 // ============================================================================
 // Border Block Helpers
@@ -42,9 +42,9 @@ use crate::theme::{BORDER_STYLE, FOCUSED_BORDER_STYLE, FOCUSED_TITLE_STYLE};
 pub fn create_border_block(title: &str, focused: bool) -> Block<'_> {
     let (border_style, border_set, title_style, display_title) = if focused {
         (
-            FOCUSED_BORDER_STYLE,
+            theme().focused_border_style(),
             border::DOUBLE,
-            FOCUSED_TITLE_STYLE,
+            theme().focused_title_style(),
             if title.is_empty() {
                 String::new()
             } else {
@@ -53,11 +53,9 @@ pub fn create_border_block(title: &str, focused: bool) -> Block<'_> {
         )
     } else {
         (
-            BORDER_STYLE,
+            theme().border_style(),
             border::ROUNDED,
-            Style::new()
-                .fg(Color::DarkGray)
-                .add_modifier(Modifier::BOLD),
+            Style::new().fg(theme().border).add_modifier(Modifier::BOLD),
             if title.is_empty() {
                 String::new()
             } else {
@@ -101,7 +99,7 @@ pub fn create_popup_block(title: &str) -> Block<'_> {
         .title_alignment(Alignment::Center)
         .borders(Borders::ALL)
         .border_set(border::ROUNDED)
-        .border_style(BORDER_STYLE)
+        .border_style(theme().border_style())
 }
 
 // ============================================================================
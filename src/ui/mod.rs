@@ -76,7 +76,7 @@ pub fn render(app: &App, frame: &mut Frame) {
 
     // Render help popup on top of everything else (except toast)
     if app.ui.show_help {
-        popups::render_help_popup(frame, size, app.ui.help_scroll_offset);
+        popups::render_help_popup(frame, size, app.ui.help_scroll_offset, &app.keymap);
     }
 
     // Render toast notification on top of everything (non-blocking overlay)
@@ -97,8 +97,17 @@ fn render_main_content(app: &App, frame: &mut Frame, area: ratatui::layout::Rect
         .constraints([Constraint::Ratio(1, 2), Constraint::Ratio(1, 2)])
         .split(area);
 
-    // Render block and transaction panels
-    panels::render_blocks(app, frame, content_chunks[0]);
+    // Render block and transaction panels, with the watchlist below the blocks
+    if app.data.watchlist.is_empty() {
+        panels::render_blocks(app, frame, content_chunks[0]);
+    } else {
+        let left_chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Percentage(60), Constraint::Percentage(40)])
+            .split(content_chunks[0]);
+        panels::render_blocks(app, frame, left_chunks[0]);
+        panels::render_watchlist(app, frame, left_chunks[1]);
+    }
     panels::render_transactions(app, frame, content_chunks[1]);
 }
 
@@ -129,6 +138,21 @@ fn render_popups(app: &App, frame: &mut Frame, area: ratatui::layout::Rect) {
         PopupState::ConfirmQuit => {
             popups::confirm::render(frame, area);
         }
+        PopupState::LocalStateLookup(address) => {
+            popups::local_state_lookup::render(frame, area, address);
+        }
+        PopupState::TxnFilter(query) => {
+            popups::txn_filter::render(frame, area, query);
+        }
+        PopupState::AdvancedSearch(form) => {
+            popups::advanced_search::render(frame, area, form);
+        }
+        PopupState::Export(prompt) => {
+            popups::export::render(frame, area, prompt);
+        }
+        PopupState::Simulate(input) => {
+            popups::simulate::render(frame, area, input);
+        }
         PopupState::None => {}
     }
 }
@@ -145,5 +169,13 @@ fn render_detail_views(app: &App, frame: &mut Frame, area: ratatui::layout::Rect
         panels::details::asset::render_asset_details(app, frame, area);
     } else if app.nav.show_application_details {
         panels::details::application::render_application_details(app, frame, area);
+    } else if app.nav.show_group_details {
+        panels::details::group::render_group_details(app, frame, area);
+    } else if app.nav.show_txn_search {
+        panels::details::txn_search::render_txn_search(app, frame, area);
+    } else if app.nav.show_pending_pool {
+        panels::details::pending_pool::render_pending_pool(app, frame, area);
+    } else if app.nav.show_simulation {
+        panels::details::simulation::render_simulation(app, frame, area);
     }
 }
@@ -6,22 +6,26 @@
 use ratatui::{
     Frame,
     layout::{Alignment, Constraint, Direction, Layout, Rect},
-    style::{Color, Modifier, Style},
+    style::{Modifier, Style},
     text::{Line, Span},
     widgets::{Block, Cell, Clear, List, ListItem, Paragraph, Row, Table},
 };
 
+use crate::constants::TXN_HEIGHT;
 use crate::domain::account::AccountDetails;
-use crate::state::{AccountDetailTab, App};
-use crate::theme::{
-    ACCENT_COLOR, MUTED_COLOR, PRIMARY_COLOR, SECONDARY_COLOR, SUCCESS_COLOR, WARNING_COLOR,
-};
+use crate::domain::{TxnType, format_asset_amount};
+use crate::state::data::ListSort;
+use crate::state::{AccountDetailTab, AccountList, AccountListEntry, App};
+use crate::theme::theme;
 use crate::ui::helpers::create_popup_block;
 use crate::ui::layout::{centered_popup_area, fullscreen_popup_area};
+use crate::ui::panels::details::application::render_local_state;
+use crate::ui::panels::transaction_list_item;
 
 /// Renders the account details popup with tabbed interface.
 ///
-/// Supports tabbed navigation between Info, Assets, and Apps views.
+/// Supports tabbed navigation between Info, Assets, Created Assets, Apps,
+/// Created Apps and History views.
 pub fn render_account_details(app: &App, frame: &mut Frame, area: Rect) {
     let Some(account) = &app.data.viewed_account else {
         // Still loading or no data
@@ -32,7 +36,7 @@ pub fn render_account_details(app: &App, frame: &mut Frame, area: Rect) {
 
         let inner_area = popup_block.inner(popup_area);
         let loading = Paragraph::new("Loading account details...")
-            .style(Style::default().fg(MUTED_COLOR))
+            .style(Style::default().fg(theme().muted))
             .alignment(Alignment::Center);
         frame.render_widget(loading, inner_area);
         return;
@@ -66,7 +70,7 @@ pub fn render_account_details(app: &App, frame: &mut Frame, area: Rect) {
     // Separator
     let separator = "─".repeat(inner_area.width as usize);
     frame.render_widget(
-        Paragraph::new(separator).style(Style::default().fg(Color::DarkGray)),
+        Paragraph::new(separator).style(Style::default().fg(theme().border)),
         content_layout[1],
     );
 
@@ -75,14 +79,36 @@ pub fn render_account_details(app: &App, frame: &mut Frame, area: Rect) {
     match app.nav.account_detail_tab {
         AccountDetailTab::Info => render_info_tab(account, frame, content_area),
         AccountDetailTab::Assets => render_assets_tab(app, account, frame, content_area),
+        AccountDetailTab::CreatedAssets => {
+            render_created_assets_tab(app, account, frame, content_area);
+        }
         AccountDetailTab::Apps => render_apps_tab(app, account, frame, content_area),
+        AccountDetailTab::CreatedApps => {
+            render_created_apps_tab(app, account, frame, content_area);
+        }
+        AccountDetailTab::History => render_history_tab(app, frame, content_area),
     }
 
     // Help text
-    let help_text = "[Tab] Switch  [↑↓] Navigate  [C] Copy  [Y] JSON  [O] Open  [Esc] Close";
+    let help_text = match app.nav.account_detail_tab {
+        AccountDetailTab::History => {
+            "[Tab] Switch  [↑↓] Navigate  [Enter] View  [T] Filter  [E] Export  [Esc] Close"
+        }
+        AccountDetailTab::Assets => {
+            "[Tab] Switch  [↑↓] Navigate  [Enter] View  [S] Sort  [Z] Hide Zero  [Esc] Close"
+        }
+        AccountDetailTab::CreatedAssets
+        | AccountDetailTab::Apps
+        | AccountDetailTab::CreatedApps => {
+            "[Tab] Switch  [↑↓] Navigate  [Enter] View  [S] Sort  [C] Copy  [Esc] Close"
+        }
+        AccountDetailTab::Info => {
+            "[Tab] Switch  [↑↓] Navigate  [C] Copy  [Y] JSON  [O] Open  [W] Watch  [Esc] Close"
+        }
+    };
     frame.render_widget(
         Paragraph::new(help_text)
-            .style(Style::default().fg(MUTED_COLOR))
+            .style(Style::default().fg(theme().muted))
             .alignment(Alignment::Center),
         content_layout[3],
     );
@@ -95,25 +121,25 @@ fn render_tab_bar(app: &App, frame: &mut Frame, area: Rect) {
     let tab_style = |is_active: bool| {
         if is_active {
             Style::default()
-                .bg(PRIMARY_COLOR)
-                .fg(Color::White)
+                .bg(theme().primary)
+                .fg(theme().text)
                 .add_modifier(Modifier::BOLD)
         } else {
-            Style::default().fg(MUTED_COLOR)
+            Style::default().fg(theme().muted)
         }
     };
 
-    let tab_bar = Line::from(vec![
-        Span::raw("  "),
-        Span::styled(" Info ", tab_style(current_tab == AccountDetailTab::Info)),
-        Span::raw("  "),
-        Span::styled(
-            " Assets ",
-            tab_style(current_tab == AccountDetailTab::Assets),
-        ),
-        Span::raw("  "),
-        Span::styled(" Apps ", tab_style(current_tab == AccountDetailTab::Apps)),
-    ]);
+    let mut spans = vec![Span::raw("  ")];
+    for tab in AccountDetailTab::ALL {
+        if tab != AccountDetailTab::Info {
+            spans.push(Span::raw("  "));
+        }
+        spans.push(Span::styled(
+            format!(" {} ", tab.label()),
+            tab_style(current_tab == tab),
+        ));
+    }
+    let tab_bar = Line::from(spans);
     frame.render_widget(Paragraph::new(tab_bar), area);
 }
 
@@ -140,12 +166,12 @@ fn render_info_tab(account: &AccountDetails, frame: &mut Frame, area: Rect) {
         rows.push(Row::new(vec![
             Cell::from("NFD Name:").style(
                 Style::default()
-                    .fg(ACCENT_COLOR)
+                    .fg(theme().accent)
                     .add_modifier(Modifier::BOLD),
             ),
             Cell::from(nfd.name.clone()).style(
                 Style::default()
-                    .fg(ACCENT_COLOR)
+                    .fg(theme().accent)
                     .add_modifier(Modifier::BOLD),
             ),
         ]));
@@ -153,10 +179,10 @@ fn render_info_tab(account: &AccountDetails, frame: &mut Frame, area: Rect) {
             rows.push(Row::new(vec![
                 Cell::from("NFD Status:").style(
                     Style::default()
-                        .fg(WARNING_COLOR)
+                        .fg(theme().warning)
                         .add_modifier(Modifier::BOLD),
                 ),
-                Cell::from("Verified").style(Style::default().fg(SUCCESS_COLOR)),
+                Cell::from("Verified").style(Style::default().fg(theme().success)),
             ]));
         }
         rows.push(Row::new(vec![Cell::from(""), Cell::from("")])); // Spacer
@@ -166,92 +192,92 @@ fn render_info_tab(account: &AccountDetails, frame: &mut Frame, area: Rect) {
         Row::new(vec![
             Cell::from("Address:").style(
                 Style::default()
-                    .fg(WARNING_COLOR)
+                    .fg(theme().warning)
                     .add_modifier(Modifier::BOLD),
             ),
-            Cell::from(address_display).style(Style::default().fg(WARNING_COLOR)),
+            Cell::from(address_display).style(Style::default().fg(theme().warning)),
         ]),
         Row::new(vec![
             Cell::from("Status:").style(
                 Style::default()
-                    .fg(WARNING_COLOR)
+                    .fg(theme().warning)
                     .add_modifier(Modifier::BOLD),
             ),
             Cell::from(account.status.clone()).style(if account.status == "Online" {
-                Style::default().fg(SUCCESS_COLOR)
+                Style::default().fg(theme().success)
             } else {
-                Style::default().fg(MUTED_COLOR)
+                Style::default().fg(theme().muted)
             }),
         ]),
         Row::new(vec![Cell::from(""), Cell::from("")]), // Spacer
         Row::new(vec![
             Cell::from("Balance:").style(
                 Style::default()
-                    .fg(WARNING_COLOR)
+                    .fg(theme().warning)
                     .add_modifier(Modifier::BOLD),
             ),
-            Cell::from(balance_algos).style(Style::default().fg(SUCCESS_COLOR)),
+            Cell::from(balance_algos).style(Style::default().fg(theme().success)),
         ]),
         Row::new(vec![
             Cell::from("Min Balance:").style(
                 Style::default()
-                    .fg(WARNING_COLOR)
+                    .fg(theme().warning)
                     .add_modifier(Modifier::BOLD),
             ),
-            Cell::from(min_balance_algos).style(Style::default().fg(MUTED_COLOR)),
+            Cell::from(min_balance_algos).style(Style::default().fg(theme().muted)),
         ]),
         Row::new(vec![
             Cell::from("Pending Rewards:").style(
                 Style::default()
-                    .fg(WARNING_COLOR)
+                    .fg(theme().warning)
                     .add_modifier(Modifier::BOLD),
             ),
-            Cell::from(pending_rewards_algos).style(Style::default().fg(PRIMARY_COLOR)),
+            Cell::from(pending_rewards_algos).style(Style::default().fg(theme().primary)),
         ]),
         Row::new(vec![
             Cell::from("Total Rewards:").style(
                 Style::default()
-                    .fg(WARNING_COLOR)
+                    .fg(theme().warning)
                     .add_modifier(Modifier::BOLD),
             ),
-            Cell::from(rewards_algos).style(Style::default().fg(PRIMARY_COLOR)),
+            Cell::from(rewards_algos).style(Style::default().fg(theme().primary)),
         ]),
         Row::new(vec![Cell::from(""), Cell::from("")]), // Spacer
         Row::new(vec![
             Cell::from("Assets Opted In:").style(
                 Style::default()
-                    .fg(WARNING_COLOR)
+                    .fg(theme().warning)
                     .add_modifier(Modifier::BOLD),
             ),
             Cell::from(format!("{}", account.total_assets_opted_in))
-                .style(Style::default().fg(ACCENT_COLOR)),
+                .style(Style::default().fg(theme().accent)),
         ]),
         Row::new(vec![
             Cell::from("Created Assets:").style(
                 Style::default()
-                    .fg(WARNING_COLOR)
+                    .fg(theme().warning)
                     .add_modifier(Modifier::BOLD),
             ),
             Cell::from(format!("{}", account.total_created_assets))
-                .style(Style::default().fg(ACCENT_COLOR)),
+                .style(Style::default().fg(theme().accent)),
         ]),
         Row::new(vec![
             Cell::from("Apps Opted In:").style(
                 Style::default()
-                    .fg(WARNING_COLOR)
+                    .fg(theme().warning)
                     .add_modifier(Modifier::BOLD),
             ),
             Cell::from(format!("{}", account.total_apps_opted_in))
-                .style(Style::default().fg(SECONDARY_COLOR)),
+                .style(Style::default().fg(theme().secondary)),
         ]),
         Row::new(vec![
             Cell::from("Created Apps:").style(
                 Style::default()
-                    .fg(WARNING_COLOR)
+                    .fg(theme().warning)
                     .add_modifier(Modifier::BOLD),
             ),
             Cell::from(format!("{}", account.total_created_apps))
-                .style(Style::default().fg(SECONDARY_COLOR)),
+                .style(Style::default().fg(theme().secondary)),
         ]),
     ]);
 
@@ -266,10 +292,10 @@ fn render_info_tab(account: &AccountDetails, frame: &mut Frame, area: Rect) {
         rows.push(Row::new(vec![
             Cell::from("Rekeyed To:").style(
                 Style::default()
-                    .fg(WARNING_COLOR)
+                    .fg(theme().warning)
                     .add_modifier(Modifier::BOLD),
             ),
-            Cell::from(auth_display).style(Style::default().fg(Color::Red)),
+            Cell::from(auth_display).style(Style::default().fg(theme().error)),
         ]));
     }
 
@@ -279,25 +305,25 @@ fn render_info_tab(account: &AccountDetails, frame: &mut Frame, area: Rect) {
         rows.push(Row::new(vec![
             Cell::from("Participation:").style(
                 Style::default()
-                    .fg(WARNING_COLOR)
+                    .fg(theme().warning)
                     .add_modifier(Modifier::BOLD),
             ),
             Cell::from("").style(Style::default()),
         ]));
         rows.push(Row::new(vec![
-            Cell::from("  Vote First:").style(Style::default().fg(MUTED_COLOR)),
+            Cell::from("  Vote First:").style(Style::default().fg(theme().muted)),
             Cell::from(format!("{}", participation.vote_first))
-                .style(Style::default().fg(Color::White)),
+                .style(Style::default().fg(theme().text)),
         ]));
         rows.push(Row::new(vec![
-            Cell::from("  Vote Last:").style(Style::default().fg(MUTED_COLOR)),
+            Cell::from("  Vote Last:").style(Style::default().fg(theme().muted)),
             Cell::from(format!("{}", participation.vote_last))
-                .style(Style::default().fg(Color::White)),
+                .style(Style::default().fg(theme().text)),
         ]));
         rows.push(Row::new(vec![
-            Cell::from("  Key Dilution:").style(Style::default().fg(MUTED_COLOR)),
+            Cell::from("  Key Dilution:").style(Style::default().fg(theme().muted)),
             Cell::from(format!("{}", participation.vote_key_dilution))
-                .style(Style::default().fg(Color::White)),
+                .style(Style::default().fg(theme().text)),
         ]));
     }
 
@@ -308,231 +334,242 @@ fn render_info_tab(account: &AccountDetails, frame: &mut Frame, area: Rect) {
     frame.render_widget(table, area);
 }
 
-/// Renders the Assets tab with asset holdings and created assets.
+/// Renders the Assets tab with the account's paged asset holdings.
 fn render_assets_tab(app: &App, account: &AccountDetails, frame: &mut Frame, area: Rect) {
-    // Split area for holdings and created assets
+    render_list_tab(
+        app,
+        app.data.account_assets.as_ref(),
+        ("Asset Holdings", account.total_assets_opted_in),
+        frame,
+        area,
+        |holding| {
+            let mut spans = asset_label_spans(holding.asset_id, &holding.name, &holding.unit_name);
+            spans.push(Span::raw("  "));
+            spans.push(Span::styled(
+                format_asset_amount(holding.amount, holding.decimals),
+                Style::default().fg(theme().success),
+            ));
+            if holding.is_frozen {
+                spans.push(Span::styled(
+                    " [frozen]",
+                    Style::default().fg(theme().error),
+                ));
+            }
+            spans
+        },
+    );
+}
+
+/// Renders the Created Assets tab with the account's paged created assets.
+fn render_created_assets_tab(app: &App, account: &AccountDetails, frame: &mut Frame, area: Rect) {
+    render_list_tab(
+        app,
+        app.data.account_created_assets.as_ref(),
+        ("Created Assets", account.total_created_assets),
+        frame,
+        area,
+        |asset| {
+            let mut spans = asset_label_spans(asset.asset_id, &asset.name, &asset.unit_name);
+            spans.push(Span::styled(
+                "  supply ",
+                Style::default().fg(theme().muted),
+            ));
+            spans.push(Span::styled(
+                format_asset_amount(asset.total, asset.decimals),
+                Style::default().fg(theme().success),
+            ));
+            spans
+        },
+    );
+}
+
+/// Renders the Apps tab with opted-in applications and the selected app's local state.
+fn render_apps_tab(app: &App, account: &AccountDetails, frame: &mut Frame, area: Rect) {
     let sections = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
-            Constraint::Length(1), // Holdings header
-            Constraint::Min(8),    // Holdings list
-            Constraint::Length(1), // Created header
-            Constraint::Min(4),    // Created list
+            Constraint::Min(6),    // Opted-in apps
+            Constraint::Length(8), // Selected app's local state
         ])
         .split(area);
 
-    // Asset Holdings section
-    let holdings_header = Paragraph::new(format!(
-        " Asset Holdings ({} total)",
-        account.total_assets_opted_in
-    ))
-    .style(
-        Style::default()
-            .fg(ACCENT_COLOR)
-            .add_modifier(Modifier::BOLD),
-    );
-    frame.render_widget(holdings_header, sections[0]);
-
-    if account.assets.is_empty() {
-        let empty_msg = Paragraph::new("  No assets held")
-            .style(Style::default().fg(MUTED_COLOR))
-            .alignment(Alignment::Left);
-        frame.render_widget(empty_msg, sections[1]);
-    } else {
-        let scroll_offset = app.nav.account_item_scroll as usize;
-        let visible_height = sections[1].height as usize;
-
-        let items: Vec<ListItem> = account
-            .assets
-            .iter()
-            .enumerate()
-            .skip(scroll_offset)
-            .take(visible_height)
-            .map(|(i, asset)| {
-                let is_selected = app.nav.account_item_index == Some(i)
-                    && app.nav.account_detail_tab == AccountDetailTab::Assets;
-                let indicator = if is_selected { "▶" } else { " " };
-                let frozen_indicator = if asset.is_frozen { " [frozen]" } else { "" };
-
-                let style = if is_selected {
-                    Style::default().fg(Color::White).bg(Color::DarkGray)
-                } else {
-                    Style::default()
-                };
-
-                ListItem::new(Line::from(vec![
-                    Span::raw(format!("{} ", indicator)),
-                    Span::styled(
-                        format!("Asset #{}", asset.asset_id),
-                        Style::default().fg(SECONDARY_COLOR),
-                    ),
-                    Span::raw(": "),
-                    Span::styled(
-                        format!("{}", asset.amount),
-                        Style::default().fg(SUCCESS_COLOR),
+    render_list_tab(
+        app,
+        app.data.account_apps.as_ref(),
+        ("Apps Opted In", account.total_apps_opted_in),
+        frame,
+        sections[0],
+        |app_state| {
+            vec![
+                Span::styled(
+                    format!("App #{}", app_state.app_id),
+                    Style::default().fg(theme().primary),
+                ),
+                Span::raw(" - "),
+                Span::styled(
+                    format!(
+                        "{} uint, {} bytes",
+                        app_state.schema_num_uint, app_state.schema_num_byte_slice
                     ),
-                    Span::styled(frozen_indicator, Style::default().fg(Color::Red)),
-                ]))
-                .style(style)
-            })
-            .collect();
+                    Style::default().fg(theme().muted),
+                ),
+            ]
+        },
+    );
 
-        let list = List::new(items).block(Block::default());
-        frame.render_widget(list, sections[1]);
+    // Local state of the selected opted-in app
+    let Some(apps) = &app.data.account_apps else {
+        return;
+    };
+    let selected_app_id = app
+        .nav
+        .account_item_index
+        .and_then(|idx| apps.visible_item(idx))
+        .map(|app_state| app_state.app_id);
+    match (selected_app_id, &app.data.local_state) {
+        (Some(app_id), Some(view)) if view.matches(&account.address, app_id) => {
+            render_local_state(view, frame, sections[1]);
+        }
+        _ if !apps.items.is_empty() => {
+            let hint = Paragraph::new("  Select an app to view its local state")
+                .style(Style::default().fg(theme().muted));
+            frame.render_widget(hint, sections[1]);
+        }
+        _ => {}
     }
+}
 
-    // Created Assets section
-    let created_header = Paragraph::new(format!(
-        " Created Assets ({} total)",
-        account.total_created_assets
-    ))
-    .style(
-        Style::default()
-            .fg(ACCENT_COLOR)
-            .add_modifier(Modifier::BOLD),
+/// Renders the Created Apps tab with the account's paged created applications.
+fn render_created_apps_tab(app: &App, account: &AccountDetails, frame: &mut Frame, area: Rect) {
+    render_list_tab(
+        app,
+        app.data.account_created_apps.as_ref(),
+        ("Created Apps", account.total_created_apps),
+        frame,
+        area,
+        |app_info| {
+            vec![Span::styled(
+                format!("App #{}", app_info.app_id),
+                Style::default().fg(theme().primary),
+            )]
+        },
     );
-    frame.render_widget(created_header, sections[2]);
-
-    if account.created_assets.is_empty() {
-        let empty_msg = Paragraph::new("  No assets created")
-            .style(Style::default().fg(MUTED_COLOR))
-            .alignment(Alignment::Left);
-        frame.render_widget(empty_msg, sections[3]);
-    } else {
-        let items: Vec<ListItem> = account
-            .created_assets
-            .iter()
-            .take(sections[3].height as usize)
-            .map(|asset| {
-                ListItem::new(Line::from(vec![
-                    Span::raw("  "),
-                    Span::styled(
-                        format!("#{}", asset.asset_id),
-                        Style::default().fg(SECONDARY_COLOR),
-                    ),
-                    Span::raw(": "),
-                    Span::styled(&asset.name, Style::default().fg(Color::White)),
-                    Span::raw(" ("),
-                    Span::styled(&asset.unit_name, Style::default().fg(MUTED_COLOR)),
-                    Span::raw(")"),
-                ]))
-            })
-            .collect();
+}
 
-        let list = List::new(items).block(Block::default());
-        frame.render_widget(list, sections[3]);
+/// Builds the id, name and unit spans shared by the asset list rows.
+fn asset_label_spans<'a>(asset_id: u64, name: &'a str, unit_name: &'a str) -> Vec<Span<'a>> {
+    let mut spans = vec![Span::styled(
+        format!("#{asset_id}"),
+        Style::default().fg(theme().secondary),
+    )];
+    if !name.is_empty() {
+        spans.push(Span::raw(" "));
+        spans.push(Span::styled(name, Style::default().fg(theme().text)));
+    }
+    if !unit_name.is_empty() {
+        spans.push(Span::raw(" ("));
+        spans.push(Span::styled(unit_name, Style::default().fg(theme().muted)));
+        spans.push(Span::raw(")"));
     }
+    spans
 }
 
-/// Renders the Apps tab with opted-in and created applications.
-fn render_apps_tab(app: &App, account: &AccountDetails, frame: &mut Frame, area: Rect) {
-    // Split area for opted apps and created apps
+/// Renders one of the paged account lists with a header, rows and paging status.
+///
+/// `heading` is the list title and the account's total count of such items.
+fn render_list_tab<'a, T: AccountListEntry>(
+    app: &App,
+    list: Option<&'a AccountList<T>>,
+    heading: (&str, usize),
+    frame: &mut Frame,
+    area: Rect,
+    row: impl Fn(&'a T) -> Vec<Span<'a>>,
+) {
     let sections = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
-            Constraint::Length(1), // Opted header
-            Constraint::Min(8),    // Opted list
-            Constraint::Length(1), // Created header
-            Constraint::Min(4),    // Created list
+            Constraint::Length(1), // Header
+            Constraint::Min(3),    // List
+            Constraint::Length(1), // Paging status
         ])
         .split(area);
 
-    // Apps Opted In section
-    let opted_header = Paragraph::new(format!(
-        " Apps Opted In ({} total)",
-        account.total_apps_opted_in
-    ))
-    .style(
-        Style::default()
-            .fg(SECONDARY_COLOR)
-            .add_modifier(Modifier::BOLD),
-    );
-    frame.render_widget(opted_header, sections[0]);
+    let Some(list) = list else {
+        let loading = Paragraph::new("  Loading...").style(Style::default().fg(theme().muted));
+        frame.render_widget(loading, sections[1]);
+        return;
+    };
+
+    let (title, total) = heading;
+    let visible = list.visible();
+    let mut header = vec![
+        Span::styled(
+            format!(" {title} ({} of {total} loaded)", list.items.len()),
+            Style::default()
+                .fg(theme().accent)
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::styled("  Sort: ", Style::default().fg(theme().muted)),
+        Span::styled(list.sort.label(), Style::default().fg(theme().accent)),
+    ];
+    if list.hide_zero {
+        header.push(Span::styled(
+            "  Zero balances hidden",
+            Style::default().fg(theme().warning),
+        ));
+    }
+    frame.render_widget(Paragraph::new(Line::from(header)), sections[0]);
 
-    if account.apps_local_state.is_empty() {
-        let empty_msg = Paragraph::new("  No apps opted into")
-            .style(Style::default().fg(MUTED_COLOR))
-            .alignment(Alignment::Left);
+    if visible.is_empty() {
+        let message = if list.loading {
+            "  Loading..."
+        } else {
+            "  None"
+        };
+        let empty_msg = Paragraph::new(message).style(Style::default().fg(theme().muted));
         frame.render_widget(empty_msg, sections[1]);
     } else {
         let scroll_offset = app.nav.account_item_scroll as usize;
         let visible_height = sections[1].height as usize;
 
-        let items: Vec<ListItem> = account
-            .apps_local_state
-            .iter()
+        let items: Vec<ListItem> = visible
+            .into_iter()
             .enumerate()
             .skip(scroll_offset)
             .take(visible_height)
-            .map(|(i, app_state)| {
-                let is_selected = app.nav.account_item_index == Some(i)
-                    && app.nav.account_detail_tab == AccountDetailTab::Apps;
+            .map(|(i, item)| {
+                let is_selected = app.nav.account_item_index == Some(i);
                 let indicator = if is_selected { "▶" } else { " " };
-
                 let style = if is_selected {
-                    Style::default().fg(Color::White).bg(Color::DarkGray)
+                    Style::default().fg(theme().text).bg(theme().selection)
                 } else {
                     Style::default()
                 };
 
-                ListItem::new(Line::from(vec![
-                    Span::raw(format!("{} ", indicator)),
-                    Span::styled(
-                        format!("App #{}", app_state.app_id),
-                        Style::default().fg(PRIMARY_COLOR),
-                    ),
-                    Span::raw(" - "),
-                    Span::styled(
-                        format!(
-                            "{} uint, {} bytes",
-                            app_state.schema_num_uint, app_state.schema_num_byte_slice
-                        ),
-                        Style::default().fg(MUTED_COLOR),
-                    ),
-                ]))
-                .style(style)
+                let mut spans = vec![Span::raw(format!("{indicator} "))];
+                spans.extend(row(item));
+                ListItem::new(Line::from(spans)).style(style)
             })
             .collect();
 
-        let list = List::new(items).block(Block::default());
-        frame.render_widget(list, sections[1]);
+        frame.render_widget(List::new(items).block(Block::default()), sections[1]);
     }
 
-    // Created Apps section
-    let created_header = Paragraph::new(format!(
-        " Created Apps ({} total)",
-        account.total_created_apps
-    ))
-    .style(
-        Style::default()
-            .fg(SECONDARY_COLOR)
-            .add_modifier(Modifier::BOLD),
-    );
-    frame.render_widget(created_header, sections[2]);
-
-    if account.created_apps.is_empty() {
-        let empty_msg = Paragraph::new("  No apps created")
-            .style(Style::default().fg(MUTED_COLOR))
-            .alignment(Alignment::Left);
-        frame.render_widget(empty_msg, sections[3]);
+    let status = if list.loading && !list.items.is_empty() {
+        Some("  Loading more...")
+    } else if list.has_more() && list.sort != ListSort::Default {
+        // Later pages are merged into the order as they arrive
+        Some("  Sorted within loaded rows - scroll down to load more")
+    } else if list.has_more() {
+        Some("  More available - scroll down to load more")
     } else {
-        let items: Vec<ListItem> = account
-            .created_apps
-            .iter()
-            .take(sections[3].height as usize)
-            .map(|app_info| {
-                ListItem::new(Line::from(vec![
-                    Span::raw("  "),
-                    Span::styled(
-                        format!("App #{}", app_info.app_id),
-                        Style::default().fg(PRIMARY_COLOR),
-                    ),
-                ]))
-            })
-            .collect();
-
-        let list = List::new(items).block(Block::default());
-        frame.render_widget(list, sections[3]);
+        None
+    };
+    if let Some(status) = status {
+        frame.render_widget(
+            Paragraph::new(status).style(Style::default().fg(theme().muted)),
+            sections[2],
+        );
     }
 }
 
@@ -540,14 +577,90 @@ fn render_apps_tab(app: &App, account: &AccountDetails, frame: &mut Frame, area:
 // Tests
 // ============================================================================
 
+/// Renders the History tab with the account's indexer transaction history.
+fn render_history_tab(app: &App, frame: &mut Frame, area: Rect) {
+    let sections = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(1), // Header
+            Constraint::Min(4),    // Transaction list
+            Constraint::Length(1), // Paging status
+        ])
+        .split(area);
+
+    let Some(history) = &app.data.account_history else {
+        let loading = Paragraph::new("  Loading transaction history...")
+            .style(Style::default().fg(theme().muted));
+        frame.render_widget(loading, sections[1]);
+        return;
+    };
+
+    let filter_label = history.filter.as_ref().map_or("All", TxnType::as_str);
+    let header = Line::from(vec![
+        Span::styled(
+            format!(" Transactions ({} loaded)", history.transactions.len()),
+            Style::default()
+                .fg(theme().secondary)
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::styled("  Type: ", Style::default().fg(theme().muted)),
+        Span::styled(filter_label, Style::default().fg(theme().accent)),
+    ]);
+    frame.render_widget(Paragraph::new(header), sections[0]);
+
+    if history.transactions.is_empty() {
+        let message = if history.loading {
+            "  Loading transaction history..."
+        } else {
+            "  No transactions found"
+        };
+        let empty_msg = Paragraph::new(message).style(Style::default().fg(theme().muted));
+        frame.render_widget(empty_msg, sections[1]);
+        return;
+    }
+
+    let scroll_offset = app.nav.account_item_scroll as usize;
+    let visible_items = (sections[1].height / TXN_HEIGHT) as usize;
+
+    let items: Vec<ListItem> = history
+        .transactions
+        .iter()
+        .enumerate()
+        .skip(scroll_offset)
+        .take(visible_items.max(1))
+        .map(|(i, txn)| transaction_list_item(txn, app.nav.account_item_index == Some(i)))
+        .collect();
+
+    let list = List::new(items).block(Block::default());
+    frame.render_widget(list, sections[1]);
+
+    let status = if history.loading {
+        Some("  Loading more transactions...")
+    } else if history.has_more() {
+        Some("  More available - scroll down to load more")
+    } else {
+        None
+    };
+    if let Some(status) = status {
+        frame.render_widget(
+            Paragraph::new(status).style(Style::default().fg(theme().muted)),
+            sections[2],
+        );
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use ratatui::{Terminal, backend::TestBackend};
     use rstest::*;
 
-    use crate::domain::account::{AccountAssetHolding, AccountDetails};
-    use crate::test_utils::{AccountMother, mock_app, test_terminal};
+    use crate::domain::AppStateValue;
+    use crate::domain::Page;
+    use crate::domain::account::{AccountAssetHolding, AccountDetails, AppLocalState};
+    use crate::state::data::ListSort;
+    use crate::state::{AccountHistory, LocalStateView};
+    use crate::test_utils::{AccountMother, TransactionMother, mock_app, test_terminal};
 
     // ============================================================================
     // Fixtures
@@ -568,18 +681,6 @@ mod tests {
             total_assets_opted_in: 42,
             total_created_apps: 3,
             total_created_assets: 10,
-            assets: vec![
-                AccountAssetHolding {
-                    asset_id: 31566704,
-                    amount: 1_000_000_000,
-                    is_frozen: false,
-                },
-                AccountAssetHolding {
-                    asset_id: 312769,
-                    amount: 500_000_000,
-                    is_frozen: true,
-                },
-            ],
             nfd: None,
             ..Default::default()
         }
@@ -637,4 +738,134 @@ mod tests {
 
         insta::assert_snapshot!("account_details_with_nfd", test_terminal.backend());
     }
+
+    /// Snapshot test for the Apps tab showing the selected app's local state.
+    #[rstest]
+    #[tokio::test]
+    async fn test_account_details_apps_tab_local_state_snapshot(
+        mut test_terminal: Terminal<TestBackend>,
+        #[future] mock_app: App,
+        mock_account: AccountDetails,
+    ) {
+        let mut app = mock_app.await;
+        let mut apps = AccountList::new(&mock_account.address);
+        apps.append_page(Page::new(
+            vec![
+                AppLocalState::new(1_002_541_853, 2, 1),
+                AppLocalState::new(552_635_992, 0, 0),
+            ],
+            None,
+        ));
+        app.data.account_apps = Some(apps);
+        let mut view = LocalStateView::new(&mock_account.address, 1_002_541_853);
+        view.loading = false;
+        view.entries = vec![
+            AppStateValue {
+                key: "balance".to_string(),
+                value_type: "Uint".to_string(),
+                value: "1000".to_string(),
+            },
+            AppStateValue {
+                key: "nickname".to_string(),
+                value_type: "Bytes".to_string(),
+                value: "alice".to_string(),
+            },
+        ];
+        app.data.local_state = Some(view);
+        app.data.viewed_account = Some(mock_account);
+        app.nav.show_account_details = true;
+        app.nav.account_detail_tab = AccountDetailTab::Apps;
+        app.nav.account_item_index = Some(0);
+
+        test_terminal
+            .draw(|frame| {
+                render_account_details(&app, frame, frame.area());
+            })
+            .unwrap();
+
+        insta::assert_snapshot!(
+            "account_details_apps_tab_local_state",
+            test_terminal.backend()
+        );
+    }
+
+    /// Snapshot test for the Assets tab sorted by amount with zero balances hidden.
+    #[rstest]
+    #[tokio::test]
+    async fn test_account_details_assets_tab_snapshot(
+        mut test_terminal: Terminal<TestBackend>,
+        #[future] mock_app: App,
+        mock_account: AccountDetails,
+    ) {
+        let mut app = mock_app.await;
+        let holding =
+            |asset_id, amount, name: &str, unit_name: &str, decimals| AccountAssetHolding {
+                asset_id,
+                amount,
+                name: name.to_string(),
+                unit_name: unit_name.to_string(),
+                decimals,
+                ..Default::default()
+            };
+        let mut assets = AccountList::new(&mock_account.address);
+        assets.append_page(Page::new(
+            vec![
+                holding(31_566_704, 1_500_000, "USDC", "USDC", 6),
+                holding(312_769, 0, "Tether USDt", "USDt", 6),
+                AccountAssetHolding {
+                    is_frozen: true,
+                    ..holding(386_192_725, 2_500_000_000, "goBTC", "goBTC", 8)
+                },
+            ],
+            Some("next-page".to_string()),
+        ));
+        assets.sort = ListSort::Amount;
+        assets.hide_zero = true;
+        app.data.account_assets = Some(assets);
+        app.data.viewed_account = Some(mock_account);
+        app.nav.show_account_details = true;
+        app.nav.account_detail_tab = AccountDetailTab::Assets;
+        app.nav.account_item_index = Some(0);
+
+        test_terminal
+            .draw(|frame| {
+                render_account_details(&app, frame, frame.area());
+            })
+            .unwrap();
+
+        insta::assert_snapshot!("account_details_assets_tab", test_terminal.backend());
+    }
+
+    /// Snapshot test for the History tab with a partially loaded, filtered history.
+    #[rstest]
+    #[tokio::test]
+    async fn test_account_details_history_tab_snapshot(
+        mut test_terminal: Terminal<TestBackend>,
+        #[future] mock_app: App,
+        mock_account: AccountDetails,
+    ) {
+        let mut app = mock_app.await;
+        let mut history = AccountHistory::new(&mock_account.address, None);
+        history.append_page(Page::new(
+            vec![
+                TransactionMother::payment("HISTORYTXN1"),
+                TransactionMother::app_call("HISTORYTXN2", 12345),
+                TransactionMother::asset_transfer("HISTORYTXN3", 31566704, 500),
+            ],
+            Some("next-page".to_string()),
+        ));
+        app.data.account_history = Some(history);
+        app.data.viewed_account = Some(mock_account);
+        app.nav.show_account_details = true;
+        app.nav.account_detail_tab = AccountDetailTab::History;
+        app.nav.account_item_index = Some(1);
+
+        test_terminal
+            .draw(|frame| {
+                render_account_details(&app, frame, frame.area());
+            })
+            .unwrap();
+
+        insta::assert_snapshot!("account_details_history_tab", test_terminal.backend());
+    }
 }
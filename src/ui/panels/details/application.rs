@@ -1,27 +1,26 @@
 //! Application detail panel rendering.
 //!
 //! This module handles the display of detailed application information including
-//! app ID, creator, state schemas, global state, and program info.
+//! app ID, creator, state schemas, global state, boxes, and disassembled programs.
 
 use ratatui::{
     Frame,
     layout::{Alignment, Constraint, Direction, Layout, Rect},
-    style::{Color, Modifier, Style},
+    style::{Modifier, Style},
     text::{Line, Span},
     widgets::{Block, Cell, Clear, List, ListItem, Paragraph, Row, Table},
 };
 
-use crate::domain::application::ApplicationDetails;
-use crate::state::{App, AppDetailTab};
-use crate::theme::{
-    ACCENT_COLOR, MUTED_COLOR, PRIMARY_COLOR, SECONDARY_COLOR, SUCCESS_COLOR, WARNING_COLOR,
-};
+use crate::domain::application::{AppStateValue, ApplicationDetails};
+use crate::domain::{StateBytes, disassemble_base64};
+use crate::state::{App, AppBoxes, AppDetailTab, LocalStateView};
+use crate::theme::theme;
 use crate::ui::helpers::create_popup_block;
 use crate::ui::layout::{centered_popup_area, fullscreen_popup_area};
 
 /// Renders the application details popup with tabbed interface.
 ///
-/// Supports tabbed navigation between Info, State, and Programs views.
+/// Supports tabbed navigation between Info, State, Boxes, and Programs views.
 ///
 /// # Arguments
 ///
@@ -38,7 +37,7 @@ pub fn render_application_details(app: &App, frame: &mut Frame, area: Rect) {
 
         let inner_area = popup_block.inner(popup_area);
         let loading = Paragraph::new("Loading application details...")
-            .style(Style::default().fg(MUTED_COLOR))
+            .style(Style::default().fg(theme().muted))
             .alignment(Alignment::Center);
         frame.render_widget(loading, inner_area);
         return;
@@ -72,7 +71,7 @@ pub fn render_application_details(app: &App, frame: &mut Frame, area: Rect) {
     // Separator
     let separator = "─".repeat(inner_area.width as usize);
     frame.render_widget(
-        Paragraph::new(separator).style(Style::default().fg(Color::DarkGray)),
+        Paragraph::new(separator).style(Style::default().fg(theme().border)),
         content_layout[1],
     );
 
@@ -81,14 +80,19 @@ pub fn render_application_details(app: &App, frame: &mut Frame, area: Rect) {
     match app.nav.app_detail_tab {
         AppDetailTab::Info => render_info_tab(application, frame, content_area),
         AppDetailTab::State => render_state_tab(app, application, frame, content_area),
-        AppDetailTab::Programs => render_programs_tab(application, frame, content_area),
+        AppDetailTab::Boxes => render_boxes_tab(app, frame, content_area),
+        AppDetailTab::Programs => render_programs_tab(app, application, frame, content_area),
     }
 
     // Help text
-    let help_text = "[Tab] Switch  [↑↓] Navigate  [C] Copy  [Y] JSON  [O] Open  [Esc] Close";
+    let help_text = if app.nav.app_detail_tab == AppDetailTab::State {
+        "[Tab] Switch  [↑↓] Navigate  [L] Local State  [C] Copy  [Y] JSON  [Esc] Close"
+    } else {
+        "[Tab] Switch  [↑↓] Navigate  [C] Copy  [Y] JSON  [O] Open  [W] Watch  [Esc] Close"
+    };
     frame.render_widget(
         Paragraph::new(help_text)
-            .style(Style::default().fg(MUTED_COLOR))
+            .style(Style::default().fg(theme().muted))
             .alignment(Alignment::Center),
         content_layout[3],
     );
@@ -101,11 +105,11 @@ fn render_tab_bar(app: &App, frame: &mut Frame, area: Rect) {
     let tab_style = |is_active: bool| {
         if is_active {
             Style::default()
-                .bg(PRIMARY_COLOR)
-                .fg(Color::White)
+                .bg(theme().primary)
+                .fg(theme().text)
                 .add_modifier(Modifier::BOLD)
         } else {
-            Style::default().fg(MUTED_COLOR)
+            Style::default().fg(theme().muted)
         }
     };
 
@@ -115,6 +119,8 @@ fn render_tab_bar(app: &App, frame: &mut Frame, area: Rect) {
         Span::raw("  "),
         Span::styled(" State ", tab_style(current_tab == AppDetailTab::State)),
         Span::raw("  "),
+        Span::styled(" Boxes ", tab_style(current_tab == AppDetailTab::Boxes)),
+        Span::raw("  "),
         Span::styled(
             " Programs ",
             tab_style(current_tab == AppDetailTab::Programs),
@@ -131,12 +137,12 @@ fn render_info_tab(application: &ApplicationDetails, frame: &mut Frame, area: Re
     rows.push(Row::new(vec![
         Cell::from("App ID:").style(
             Style::default()
-                .fg(WARNING_COLOR)
+                .fg(theme().warning)
                 .add_modifier(Modifier::BOLD),
         ),
         Cell::from(format!("{}", application.app_id)).style(
             Style::default()
-                .fg(ACCENT_COLOR)
+                .fg(theme().accent)
                 .add_modifier(Modifier::BOLD),
         ),
     ]));
@@ -148,14 +154,14 @@ fn render_info_tab(application: &ApplicationDetails, frame: &mut Frame, area: Re
         "Active"
     };
     let status_color = if application.deleted {
-        Color::Red
+        theme().error
     } else {
-        SUCCESS_COLOR
+        theme().success
     };
     rows.push(Row::new(vec![
         Cell::from("Status:").style(
             Style::default()
-                .fg(WARNING_COLOR)
+                .fg(theme().warning)
                 .add_modifier(Modifier::BOLD),
         ),
         Cell::from(status).style(Style::default().fg(status_color)),
@@ -172,10 +178,10 @@ fn render_info_tab(application: &ApplicationDetails, frame: &mut Frame, area: Re
     rows.push(Row::new(vec![
         Cell::from("Creator:").style(
             Style::default()
-                .fg(WARNING_COLOR)
+                .fg(theme().warning)
                 .add_modifier(Modifier::BOLD),
         ),
-        Cell::from(creator_display).style(Style::default().fg(SECONDARY_COLOR)),
+        Cell::from(creator_display).style(Style::default().fg(theme().secondary)),
     ]));
 
     // App address (truncated if needed)
@@ -187,10 +193,10 @@ fn render_info_tab(application: &ApplicationDetails, frame: &mut Frame, area: Re
     rows.push(Row::new(vec![
         Cell::from("App Address:").style(
             Style::default()
-                .fg(WARNING_COLOR)
+                .fg(theme().warning)
                 .add_modifier(Modifier::BOLD),
         ),
-        Cell::from(app_address_display).style(Style::default().fg(SECONDARY_COLOR)),
+        Cell::from(app_address_display).style(Style::default().fg(theme().secondary)),
     ]));
 
     rows.push(Row::new(vec![Cell::from(""), Cell::from("")])); // Spacer
@@ -199,20 +205,20 @@ fn render_info_tab(application: &ApplicationDetails, frame: &mut Frame, area: Re
     rows.push(Row::new(vec![
         Cell::from("Global State:").style(
             Style::default()
-                .fg(WARNING_COLOR)
+                .fg(theme().warning)
                 .add_modifier(Modifier::BOLD),
         ),
         Cell::from("").style(Style::default()),
     ]));
     rows.push(Row::new(vec![
-        Cell::from("  Byte Slices:").style(Style::default().fg(MUTED_COLOR)),
+        Cell::from("  Byte Slices:").style(Style::default().fg(theme().muted)),
         Cell::from(format!("{}", application.global_state_byte))
-            .style(Style::default().fg(Color::White)),
+            .style(Style::default().fg(theme().text)),
     ]));
     rows.push(Row::new(vec![
-        Cell::from("  Uint64s:").style(Style::default().fg(MUTED_COLOR)),
+        Cell::from("  Uint64s:").style(Style::default().fg(theme().muted)),
         Cell::from(format!("{}", application.global_state_uint))
-            .style(Style::default().fg(Color::White)),
+            .style(Style::default().fg(theme().text)),
     ]));
 
     rows.push(Row::new(vec![Cell::from(""), Cell::from("")])); // Spacer
@@ -221,20 +227,20 @@ fn render_info_tab(application: &ApplicationDetails, frame: &mut Frame, area: Re
     rows.push(Row::new(vec![
         Cell::from("Local State:").style(
             Style::default()
-                .fg(WARNING_COLOR)
+                .fg(theme().warning)
                 .add_modifier(Modifier::BOLD),
         ),
         Cell::from("").style(Style::default()),
     ]));
     rows.push(Row::new(vec![
-        Cell::from("  Byte Slices:").style(Style::default().fg(MUTED_COLOR)),
+        Cell::from("  Byte Slices:").style(Style::default().fg(theme().muted)),
         Cell::from(format!("{}", application.local_state_byte))
-            .style(Style::default().fg(Color::White)),
+            .style(Style::default().fg(theme().text)),
     ]));
     rows.push(Row::new(vec![
-        Cell::from("  Uint64s:").style(Style::default().fg(MUTED_COLOR)),
+        Cell::from("  Uint64s:").style(Style::default().fg(theme().muted)),
         Cell::from(format!("{}", application.local_state_uint))
-            .style(Style::default().fg(Color::White)),
+            .style(Style::default().fg(theme().text)),
     ]));
 
     // Extra program pages if any
@@ -243,10 +249,10 @@ fn render_info_tab(application: &ApplicationDetails, frame: &mut Frame, area: Re
         rows.push(Row::new(vec![
             Cell::from("Extra Pages:").style(
                 Style::default()
-                    .fg(WARNING_COLOR)
+                    .fg(theme().warning)
                     .add_modifier(Modifier::BOLD),
             ),
-            Cell::from(format!("{}", extra_pages)).style(Style::default().fg(Color::White)),
+            Cell::from(format!("{}", extra_pages)).style(Style::default().fg(theme().text)),
         ]));
     }
 
@@ -256,10 +262,10 @@ fn render_info_tab(application: &ApplicationDetails, frame: &mut Frame, area: Re
         rows.push(Row::new(vec![
             Cell::from("Created At:").style(
                 Style::default()
-                    .fg(WARNING_COLOR)
+                    .fg(theme().warning)
                     .add_modifier(Modifier::BOLD),
             ),
-            Cell::from(format!("Round {}", round)).style(Style::default().fg(MUTED_COLOR)),
+            Cell::from(format!("Round {}", round)).style(Style::default().fg(theme().muted)),
         ]));
     }
 
@@ -272,29 +278,42 @@ fn render_info_tab(application: &ApplicationDetails, frame: &mut Frame, area: Re
 
 /// Renders the State tab with global state key-value pairs.
 fn render_state_tab(app: &App, application: &ApplicationDetails, frame: &mut Frame, area: Rect) {
+    // A looked-up account's local state is shown below the global state
+    let local_state = app
+        .data
+        .local_state
+        .as_ref()
+        .filter(|view| view.app_id == application.app_id);
+    let local_height = if local_state.is_some() { 8 } else { 0 };
+
     // Section header
     let sections = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
-            Constraint::Length(1), // Header
-            Constraint::Min(4),    // State list
+            Constraint::Length(1),            // Header
+            Constraint::Min(4),               // State list
+            Constraint::Length(local_height), // Local state
         ])
         .split(area);
 
+    if let Some(view) = local_state {
+        render_local_state(view, frame, sections[2]);
+    }
+
     let header = Paragraph::new(format!(
         " Global State ({} entries)",
         application.global_state.len()
     ))
     .style(
         Style::default()
-            .fg(ACCENT_COLOR)
+            .fg(theme().accent)
             .add_modifier(Modifier::BOLD),
     );
     frame.render_widget(header, sections[0]);
 
     if application.global_state.is_empty() {
         let empty_msg = Paragraph::new("  No global state")
-            .style(Style::default().fg(MUTED_COLOR))
+            .style(Style::default().fg(theme().muted))
             .alignment(Alignment::Left);
         frame.render_widget(empty_msg, sections[1]);
     } else {
@@ -312,34 +331,14 @@ fn render_state_tab(app: &App, application: &ApplicationDetails, frame: &mut Fra
                 let indicator = if is_selected { "▶" } else { " " };
 
                 let style = if is_selected {
-                    Style::default().fg(Color::White).bg(Color::DarkGray)
+                    Style::default().fg(theme().text).bg(theme().selection)
                 } else {
                     Style::default()
                 };
 
-                // Type indicator
-                let type_indicator = match state.value_type.as_str() {
-                    "Bytes" => "[]",
-                    "Uint" => "#",
-                    _ => "?",
-                };
-
-                // Truncate value for display
-                let value_display = if state.value.len() > 40 {
-                    format!("{}...", &state.value[..37])
-                } else {
-                    state.value.clone()
-                };
-
-                ListItem::new(Line::from(vec![
-                    Span::raw(format!("{} ", indicator)),
-                    Span::styled(type_indicator, Style::default().fg(MUTED_COLOR)),
-                    Span::raw(" "),
-                    Span::styled(&state.key, Style::default().fg(SECONDARY_COLOR)),
-                    Span::raw(" = "),
-                    Span::styled(value_display, Style::default().fg(Color::White)),
-                ]))
-                .style(style)
+                let mut spans = vec![Span::raw(format!("{} ", indicator))];
+                spans.extend(state_value_spans(state));
+                ListItem::new(Line::from(spans)).style(style)
             })
             .collect();
 
@@ -348,93 +347,382 @@ fn render_state_tab(app: &App, application: &ApplicationDetails, frame: &mut Fra
     }
 }
 
-/// Renders the Programs tab with approval and clear state program info.
-fn render_programs_tab(application: &ApplicationDetails, frame: &mut Frame, area: Rect) {
-    let mut rows = vec![];
+/// Returns the type marker, key and value spans for a state entry.
+fn state_value_spans(state: &AppStateValue) -> Vec<Span<'_>> {
+    // Type indicator
+    let type_indicator = match state.value_type.as_str() {
+        "Bytes" => "[]",
+        "Uint" => "#",
+        _ => "?",
+    };
 
-    // Approval Program
-    rows.push(Row::new(vec![
-        Cell::from("Approval Program:").style(
-            Style::default()
-                .fg(WARNING_COLOR)
-                .add_modifier(Modifier::BOLD),
-        ),
-        Cell::from("").style(Style::default()),
-    ]));
+    // Truncate value for display
+    let value_display = if state.value.len() > 40 {
+        format!("{}...", &state.value[..37])
+    } else {
+        state.value.clone()
+    };
 
-    if let Some(ref program) = application.approval_program {
-        let program_len = program.len();
-        let size_display = format_program_size(program_len);
-        rows.push(Row::new(vec![
-            Cell::from("  Size:").style(Style::default().fg(MUTED_COLOR)),
-            Cell::from(size_display).style(Style::default().fg(SUCCESS_COLOR)),
-        ]));
+    vec![
+        Span::styled(type_indicator, Style::default().fg(theme().muted)),
+        Span::raw(" "),
+        Span::styled(&state.key, Style::default().fg(theme().secondary)),
+        Span::raw(" = "),
+        Span::styled(value_display, Style::default().fg(theme().text)),
+    ]
+}
 
-        // Show hash-like preview (first/last bytes)
-        let preview = format_program_preview(program);
-        rows.push(Row::new(vec![
-            Cell::from("  Preview:").style(Style::default().fg(MUTED_COLOR)),
-            Cell::from(preview).style(Style::default().fg(Color::White)),
-        ]));
+/// Renders one account's local state for an application.
+///
+/// Shared by the application State tab and the account Apps tab.
+pub(crate) fn render_local_state(view: &LocalStateView, frame: &mut Frame, area: Rect) {
+    let muted = Style::default().fg(theme().muted);
+    let address = if view.address.len() > 12 {
+        format!(
+            "{}...{}",
+            &view.address[..6],
+            &view.address[view.address.len() - 6..]
+        )
     } else {
-        rows.push(Row::new(vec![
-            Cell::from("  ").style(Style::default()),
-            Cell::from("(not available)").style(Style::default().fg(MUTED_COLOR)),
-        ]));
+        view.address.clone()
+    };
+
+    let mut lines = vec![
+        Line::from("─".repeat(area.width as usize)).style(Style::default().fg(theme().border)),
+        Line::from(Span::styled(
+            format!(
+                " Local State of {} in App #{} ({} entries)",
+                address,
+                view.app_id,
+                view.entries.len()
+            ),
+            Style::default()
+                .fg(theme().accent)
+                .add_modifier(Modifier::BOLD),
+        )),
+    ];
+
+    if view.loading {
+        lines.push(Line::from(Span::styled("  Loading local state...", muted)));
+    } else if let Some(error) = &view.error {
+        lines.push(Line::from(Span::styled(
+            format!("  {error}"),
+            Style::default().fg(theme().error),
+        )));
+    } else if view.entries.is_empty() {
+        lines.push(Line::from(Span::styled("  No local state", muted)));
+    } else {
+        lines.extend(view.entries.iter().map(|state| {
+            let mut spans = vec![Span::raw("  ")];
+            spans.extend(state_value_spans(state));
+            Line::from(spans)
+        }));
     }
 
-    rows.push(Row::new(vec![Cell::from(""), Cell::from("")])); // Spacer
+    frame.render_widget(Paragraph::new(lines), area);
+}
 
-    // Clear State Program
-    rows.push(Row::new(vec![
-        Cell::from("Clear State Program:").style(
+/// Renders the Boxes tab with box names and the selected box's value.
+fn render_boxes_tab(app: &App, frame: &mut Frame, area: Rect) {
+    let sections = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(1), // Header
+            Constraint::Min(4),    // Box list
+            Constraint::Length(5), // Selected box value
+        ])
+        .split(area);
+
+    let Some(app_boxes) = &app.data.app_boxes else {
+        let loading =
+            Paragraph::new("  Loading boxes...").style(Style::default().fg(theme().muted));
+        frame.render_widget(loading, sections[1]);
+        return;
+    };
+
+    let more = if app_boxes.loading {
+        ", loading..."
+    } else if app_boxes.has_more() {
+        ", more available"
+    } else {
+        ""
+    };
+    let header = Paragraph::new(format!(" Boxes ({} loaded{})", app_boxes.boxes.len(), more))
+        .style(
             Style::default()
-                .fg(WARNING_COLOR)
+                .fg(theme().accent)
                 .add_modifier(Modifier::BOLD),
+        );
+    frame.render_widget(header, sections[0]);
+
+    if app_boxes.boxes.is_empty() {
+        let msg = if app_boxes.loading {
+            "  Loading boxes..."
+        } else {
+            "  No boxes"
+        };
+        let empty_msg = Paragraph::new(msg)
+            .style(Style::default().fg(theme().muted))
+            .alignment(Alignment::Left);
+        frame.render_widget(empty_msg, sections[1]);
+        return;
+    }
+
+    let scroll_offset = app.nav.app_state_scroll as usize;
+    let visible_height = sections[1].height as usize;
+
+    let items: Vec<ListItem> = app_boxes
+        .boxes
+        .iter()
+        .enumerate()
+        .skip(scroll_offset)
+        .take(visible_height)
+        .map(|(i, app_box)| {
+            let is_selected = app.nav.app_state_index == Some(i);
+            let indicator = if is_selected { "▶" } else { " " };
+
+            let style = if is_selected {
+                Style::default().fg(theme().text).bg(theme().selection)
+            } else {
+                Style::default()
+            };
+
+            ListItem::new(Line::from(vec![
+                Span::raw(format!("{} ", indicator)),
+                Span::styled(
+                    app_box.display_name(),
+                    Style::default().fg(theme().secondary),
+                ),
+            ]))
+            .style(style)
+        })
+        .collect();
+
+    frame.render_widget(List::new(items).block(Block::default()), sections[1]);
+    render_box_value(app, app_boxes, frame, sections[2]);
+}
+
+/// Renders the decoded value of the selected box.
+fn render_box_value(app: &App, app_boxes: &AppBoxes, frame: &mut Frame, area: Rect) {
+    let label_style = Style::default()
+        .fg(theme().warning)
+        .add_modifier(Modifier::BOLD);
+    let muted = Style::default().fg(theme().muted);
+
+    let Some(app_box) = app
+        .nav
+        .app_state_index
+        .and_then(|idx| app_boxes.boxes.get(idx))
+    else {
+        let hint = Paragraph::new("  Select a box to view its value").style(muted);
+        frame.render_widget(hint, area);
+        return;
+    };
+
+    let mut lines = vec![
+        Line::from("─".repeat(area.width as usize)).style(Style::default().fg(theme().border)),
+    ];
+    lines.push(Line::from(vec![
+        Span::styled(" Name:  ", label_style),
+        Span::styled(
+            app_box.display_name(),
+            Style::default().fg(theme().secondary),
         ),
-        Cell::from("").style(Style::default()),
+        Span::styled(format!("  ({})", app_box.name), muted),
     ]));
 
-    if let Some(ref program) = application.clear_state_program {
-        let program_len = program.len();
-        let size_display = format_program_size(program_len);
-        rows.push(Row::new(vec![
-            Cell::from("  Size:").style(Style::default().fg(MUTED_COLOR)),
-            Cell::from(size_display).style(Style::default().fg(SUCCESS_COLOR)),
-        ]));
-
-        // Show hash-like preview (first/last bytes)
-        let preview = format_program_preview(program);
-        rows.push(Row::new(vec![
-            Cell::from("  Preview:").style(Style::default().fg(MUTED_COLOR)),
-            Cell::from(preview).style(Style::default().fg(Color::White)),
-        ]));
-    } else {
-        rows.push(Row::new(vec![
-            Cell::from("  ").style(Style::default()),
-            Cell::from("(not available)").style(Style::default().fg(MUTED_COLOR)),
-        ]));
+    match app_box
+        .value
+        .as_deref()
+        .map(|b64| (b64, StateBytes::from_base64(b64)))
+    {
+        Some((b64, Some(decoded))) => {
+            lines.push(Line::from(vec![
+                Span::styled(" Value: ", label_style),
+                Span::styled(decoded.to_string(), Style::default().fg(theme().text)),
+            ]));
+            lines.push(Line::from(vec![
+                Span::styled(" Type:  ", label_style),
+                Span::styled(decoded.kind(), muted),
+                Span::styled(format!("  ({} chars Base64)", b64.len()), muted),
+            ]));
+        }
+        Some((b64, None)) => {
+            lines.push(Line::from(vec![
+                Span::styled(" Value: ", label_style),
+                Span::styled(b64.to_string(), Style::default().fg(theme().text)),
+            ]));
+        }
+        None if app_boxes.value_loading.as_ref() == Some(&app_box.name) => {
+            lines.push(Line::from(vec![
+                Span::styled(" Value: ", label_style),
+                Span::styled("Loading...", muted),
+            ]));
+        }
+        None => {
+            lines.push(Line::from(vec![
+                Span::styled(" Value: ", label_style),
+                Span::styled("(not loaded)", muted),
+            ]));
+        }
     }
 
-    rows.push(Row::new(vec![Cell::from(""), Cell::from("")])); // Spacer
+    frame.render_widget(Paragraph::new(lines), area);
+}
+
+/// Renders the Programs tab with disassembled approval and clear state programs.
+fn render_programs_tab(app: &App, application: &ApplicationDetails, frame: &mut Frame, area: Rect) {
+    let lines = build_program_lines(application);
+    let scroll_offset = app.nav.app_state_scroll as usize;
+    let visible: Vec<Line> = lines
+        .into_iter()
+        .skip(scroll_offset)
+        .take(area.height as usize)
+        .collect();
+
+    frame.render_widget(Paragraph::new(visible), area);
+}
+
+/// Returns the number of lines in the Programs tab listing.
+///
+/// Used for scroll bounds checking.
+#[must_use]
+pub fn program_listing_len(application: &ApplicationDetails) -> usize {
+    build_program_lines(application).len()
+}
+
+/// Builds the Programs tab listing: a header per program followed by its TEAL.
+fn build_program_lines(application: &ApplicationDetails) -> Vec<Line<'static>> {
+    let mut lines = Vec::new();
+
+    push_program_section(
+        &mut lines,
+        "Approval Program",
+        application.approval_program.as_deref(),
+    );
+    lines.push(Line::from(""));
+    push_program_section(
+        &mut lines,
+        "Clear State Program",
+        application.clear_state_program.as_deref(),
+    );
 
     // Extra pages info
     if let Some(extra_pages) = application.extra_program_pages {
-        rows.push(Row::new(vec![
-            Cell::from("Extra Program Pages:").style(
+        lines.push(Line::from(""));
+        lines.push(Line::from(vec![
+            Span::styled(
+                "Extra Program Pages: ",
                 Style::default()
-                    .fg(WARNING_COLOR)
+                    .fg(theme().warning)
                     .add_modifier(Modifier::BOLD),
             ),
-            Cell::from(format!("{}", extra_pages)).style(Style::default().fg(Color::White)),
+            Span::styled(
+                format!("{}", extra_pages),
+                Style::default().fg(theme().text),
+            ),
         ]));
     }
 
-    let table = Table::new(rows, [Constraint::Length(22), Constraint::Min(50)])
-        .block(Block::default())
-        .column_spacing(2);
+    lines
+}
 
-    frame.render_widget(table, area);
+/// Appends a program header and its disassembled listing.
+fn push_program_section(lines: &mut Vec<Line<'static>>, title: &str, program: Option<&str>) {
+    let header_style = Style::default()
+        .fg(theme().warning)
+        .add_modifier(Modifier::BOLD);
+
+    let Some(program) = program else {
+        lines.push(Line::from(vec![
+            Span::styled(format!("{}: ", title), header_style),
+            Span::styled("(not available)", Style::default().fg(theme().muted)),
+        ]));
+        return;
+    };
+
+    lines.push(Line::from(vec![
+        Span::styled(format!("{}: ", title), header_style),
+        Span::styled(
+            format_program_size(program.len()),
+            Style::default().fg(theme().success),
+        ),
+    ]));
+
+    match disassemble_base64(program) {
+        Ok(listing) => {
+            lines.extend(
+                listing
+                    .lines
+                    .iter()
+                    .enumerate()
+                    .map(|(idx, line)| format_teal_line(idx + 1, line)),
+            );
+        }
+        Err(e) => {
+            lines.push(Line::from(Span::styled(
+                format!("  {}", e),
+                Style::default().fg(theme().error),
+            )));
+        }
+    }
+}
+
+/// Styles a single TEAL listing line with a line number gutter.
+pub(crate) fn format_teal_line(number: usize, line: &str) -> Line<'static> {
+    let gutter = Span::styled(
+        format!("{:>5}  ", number),
+        Style::default().fg(theme().muted),
+    );
+
+    // Labels, pragmas and whole-line comments get a single style
+    if line.ends_with(':') {
+        return Line::from(vec![
+            gutter,
+            Span::styled(
+                line.to_string(),
+                Style::default()
+                    .fg(theme().accent)
+                    .add_modifier(Modifier::BOLD),
+            ),
+        ]);
+    }
+    if line.starts_with("#pragma") || line.starts_with("//") {
+        let color = if line.starts_with("// error:") {
+            theme().error
+        } else {
+            theme().muted
+        };
+        return Line::from(vec![
+            gutter,
+            Span::styled(line.to_string(), Style::default().fg(color)),
+        ]);
+    }
+
+    let (code, comment) = match line.find(" // ") {
+        Some(pos) => (&line[..pos], Some(&line[pos..])),
+        None => (line, None),
+    };
+    let (opcode, args) = code.split_once(' ').unwrap_or((code, ""));
+
+    let mut spans = vec![
+        gutter,
+        Span::raw("  "),
+        Span::styled(opcode.to_string(), Style::default().fg(theme().secondary)),
+    ];
+    if !args.is_empty() {
+        spans.push(Span::styled(
+            format!(" {}", args),
+            Style::default().fg(theme().text),
+        ));
+    }
+    if let Some(comment) = comment {
+        spans.push(Span::styled(
+            comment.to_string(),
+            Style::default().fg(theme().muted),
+        ));
+    }
+    Line::from(spans)
 }
 
 /// Formats the program size in a human-readable way.
@@ -448,14 +736,6 @@ fn format_program_size(len: usize) -> String {
     }
 }
 
-/// Formats a program preview (first and last bytes in hex-like display).
-fn format_program_preview(program: &str) -> String {
-    if program.len() <= 20 {
-        return program.to_string();
-    }
-    format!("{}...{}", &program[..8], &program[program.len() - 8..])
-}
-
 // ============================================================================
 // Tests
 // ============================================================================
@@ -463,7 +743,8 @@ fn format_program_preview(program: &str) -> String {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::domain::application::{AppStateValue, ApplicationDetails};
+    use crate::domain::Page;
+    use crate::domain::application::{AppBox, AppStateValue, ApplicationDetails};
     use crate::test_utils::{mock_app, test_terminal};
     use ratatui::{Terminal, backend::TestBackend};
     use rstest::*;
@@ -560,6 +841,69 @@ mod tests {
         insta::assert_snapshot!("application_details_state_tab", test_terminal.backend());
     }
 
+    /// Snapshot test for the State tab with a looked-up account's local state.
+    #[rstest]
+    #[tokio::test]
+    async fn test_application_details_state_tab_local_state(
+        mut test_terminal: Terminal<TestBackend>,
+        #[future] mock_app: App,
+        mock_application: ApplicationDetails,
+    ) {
+        let mut app = mock_app.await;
+        let mut view = LocalStateView::new(&mock_application.creator, mock_application.app_id);
+        view.loading = false;
+        view.entries = vec![AppStateValue {
+            key: "staked".to_string(),
+            value_type: "Uint".to_string(),
+            value: "2500000".to_string(),
+        }];
+        app.data.local_state = Some(view);
+        app.data.viewed_application = Some(mock_application);
+        app.nav.show_application_details = true;
+        app.nav.app_detail_tab = AppDetailTab::State;
+
+        test_terminal
+            .draw(|frame| {
+                render_application_details(&app, frame, frame.area());
+            })
+            .unwrap();
+
+        insta::assert_snapshot!(
+            "application_details_state_tab_local_state",
+            test_terminal.backend()
+        );
+    }
+
+    /// Snapshot test for application details popup - Boxes tab with a selected value.
+    #[rstest]
+    #[tokio::test]
+    async fn test_application_details_boxes_tab(
+        mut test_terminal: Terminal<TestBackend>,
+        #[future] mock_app: App,
+        mock_application: ApplicationDetails,
+    ) {
+        let mut app = mock_app.await;
+        let mut app_boxes = AppBoxes::new(mock_application.app_id);
+        app_boxes.append_page(Page::new(
+            vec![AppBox::new("bmFtZQ=="), AppBox::new("YgAB")],
+            Some("b64:YgAB".to_string()),
+        ));
+        app_boxes.set_value("YgAB", "AAAAAAAAA+g=".to_string());
+        app.data.viewed_application = Some(mock_application);
+        app.data.app_boxes = Some(app_boxes);
+        app.nav.show_application_details = true;
+        app.nav.app_detail_tab = AppDetailTab::Boxes;
+        app.nav.app_state_index = Some(1);
+
+        test_terminal
+            .draw(|frame| {
+                render_application_details(&app, frame, frame.area());
+            })
+            .unwrap();
+
+        insta::assert_snapshot!("application_details_boxes_tab", test_terminal.backend());
+    }
+
     /// Snapshot test for application details popup - Programs tab.
     #[rstest]
     #[tokio::test]
@@ -581,4 +925,32 @@ mod tests {
 
         insta::assert_snapshot!("application_details_programs_tab", test_terminal.backend());
     }
+
+    /// Snapshot test for application details popup - Programs tab scrolled down.
+    #[rstest]
+    #[tokio::test]
+    async fn test_application_details_programs_tab_scrolled(
+        mut test_terminal: Terminal<TestBackend>,
+        #[future] mock_app: App,
+        mock_application: ApplicationDetails,
+    ) {
+        let mut app = mock_app.await;
+        let line_count = program_listing_len(&mock_application);
+        app.data.viewed_application = Some(mock_application);
+        app.nav.show_application_details = true;
+        app.nav.app_detail_tab = AppDetailTab::Programs;
+        app.nav.scroll_app_program_down(line_count as u16);
+        app.nav.scroll_app_program_down(line_count as u16);
+
+        test_terminal
+            .draw(|frame| {
+                render_application_details(&app, frame, frame.area());
+            })
+            .unwrap();
+
+        insta::assert_snapshot!(
+            "application_details_programs_tab_scrolled",
+            test_terminal.backend()
+        );
+    }
 }
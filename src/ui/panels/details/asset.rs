@@ -1,25 +1,30 @@
 //! Asset detail panel rendering.
 //!
 //! This module handles the display of detailed asset information including
-//! supply, decimals, management addresses, and metadata.
+//! supply, decimals, management addresses, and metadata, plus the paged list
+//! of holders with their balance distribution.
 
 use ratatui::{
     Frame,
     layout::{Alignment, Constraint, Direction, Layout, Rect},
-    style::{Color, Modifier, Style},
-    widgets::{Block, Cell, Clear, Paragraph, Row, Table},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Cell, Clear, List, ListItem, Paragraph, Row, Table},
 };
 
-use crate::state::App;
-use crate::theme::{
-    ACCENT_COLOR, MUTED_COLOR, PRIMARY_COLOR, SECONDARY_COLOR, SUCCESS_COLOR, WARNING_COLOR,
-};
+use crate::domain::{AssetDetails, format_asset_amount};
+use crate::state::{App, AssetDetailTab, AssetHolders};
+use crate::theme::theme;
 use crate::ui::helpers::create_popup_block;
 use crate::ui::layout::{centered_popup_area, fullscreen_popup_area};
 
-/// Renders the asset details popup.
+/// Number of largest holders summed for the concentration figure.
+const TOP_HOLDERS: usize = 10;
+
+/// Renders the asset details popup with tabbed interface.
 ///
-/// Displays ASA metadata, supply, and management addresses.
+/// Supports tabbed navigation between the Info view (ASA metadata, supply,
+/// and management addresses) and the Holders view.
 ///
 /// # Arguments
 ///
@@ -36,7 +41,7 @@ pub fn render_asset_details(app: &App, frame: &mut Frame, area: Rect) {
 
         let inner_area = popup_block.inner(popup_area);
         let loading = Paragraph::new("Loading asset details...")
-            .style(Style::default().fg(MUTED_COLOR))
+            .style(Style::default().fg(theme().muted))
             .alignment(Alignment::Center);
         frame.render_widget(loading, inner_area);
         return;
@@ -53,17 +58,77 @@ pub fn render_asset_details(app: &App, frame: &mut Frame, area: Rect) {
 
     let inner_area = popup_block.inner(popup_area);
 
-    // Layout: content area and help text
+    // Layout: tab bar, separator, content, help text
     let content_layout = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
+            Constraint::Length(1), // Tab bar
+            Constraint::Length(1), // Separator
             Constraint::Min(10),   // Main content
             Constraint::Length(1), // Help text
         ])
         .split(inner_area);
 
-    let content_area = content_layout[0];
+    // Render tab bar
+    render_tab_bar(app, frame, content_layout[0]);
+
+    // Separator
+    let separator = "─".repeat(inner_area.width as usize);
+    frame.render_widget(
+        Paragraph::new(separator).style(Style::default().fg(theme().border)),
+        content_layout[1],
+    );
+
+    // Content based on tab
+    let content_area = content_layout[2];
+    match app.nav.asset_detail_tab {
+        AssetDetailTab::Info => render_info_tab(asset, frame, content_area),
+        AssetDetailTab::Holders => render_holders_tab(app, asset, frame, content_area),
+    }
+
+    // Help text
+    let help_text = if app.nav.asset_detail_tab == AssetDetailTab::Holders {
+        "[Tab] Switch  [↑↓] Navigate  [Enter] View  [M] Filter  [E] Export  [Esc] Close"
+    } else {
+        "[Tab] Switch  [C] Copy  [Y] JSON  [O] Open  [W] Watch  [Esc] Close"
+    };
+    frame.render_widget(
+        Paragraph::new(help_text)
+            .style(Style::default().fg(theme().muted))
+            .alignment(Alignment::Center),
+        content_layout[3],
+    );
+}
+
+/// Renders the tab bar for asset details.
+fn render_tab_bar(app: &App, frame: &mut Frame, area: Rect) {
+    let current_tab = app.nav.asset_detail_tab;
+
+    let tab_style = |is_active: bool| {
+        if is_active {
+            Style::default()
+                .bg(theme().primary)
+                .fg(theme().text)
+                .add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(theme().muted)
+        }
+    };
 
+    let tab_bar = Line::from(vec![
+        Span::raw("  "),
+        Span::styled(" Info ", tab_style(current_tab == AssetDetailTab::Info)),
+        Span::raw("  "),
+        Span::styled(
+            " Holders ",
+            tab_style(current_tab == AssetDetailTab::Holders),
+        ),
+    ]);
+    frame.render_widget(Paragraph::new(tab_bar), area);
+}
+
+/// Renders the Info tab with the asset parameters.
+fn render_info_tab(asset: &AssetDetails, frame: &mut Frame, content_area: Rect) {
     // Format display values
     let name_display = if asset.name.is_empty() {
         "<unnamed>".to_string()
@@ -77,17 +142,11 @@ pub fn render_asset_details(app: &App, frame: &mut Frame, area: Rect) {
     };
 
     // Format total supply with decimals
-    let total_display = if asset.decimals > 0 {
-        let divisor = 10u64.pow(asset.decimals as u32);
-        format!(
-            "{:.prec$} {}",
-            asset.total as f64 / divisor as f64,
-            unit_display,
-            prec = asset.decimals as usize
-        )
-    } else {
-        format!("{} {}", asset.total, unit_display)
-    };
+    let total_display = format!(
+        "{} {}",
+        format_asset_amount(asset.total, asset.decimals),
+        unit_display
+    );
 
     let creator_display = if asset.creator.len() > 40 {
         format!("{}...", &asset.creator[..37])
@@ -99,59 +158,59 @@ pub fn render_asset_details(app: &App, frame: &mut Frame, area: Rect) {
         Row::new(vec![
             Cell::from("Asset ID:").style(
                 Style::default()
-                    .fg(WARNING_COLOR)
+                    .fg(theme().warning)
                     .add_modifier(Modifier::BOLD),
             ),
             Cell::from(format!("{}", asset.id)).style(
                 Style::default()
-                    .fg(SUCCESS_COLOR)
+                    .fg(theme().success)
                     .add_modifier(Modifier::BOLD),
             ),
         ]),
         Row::new(vec![
             Cell::from("Name:").style(
                 Style::default()
-                    .fg(WARNING_COLOR)
+                    .fg(theme().warning)
                     .add_modifier(Modifier::BOLD),
             ),
-            Cell::from(name_display).style(Style::default().fg(PRIMARY_COLOR)),
+            Cell::from(name_display).style(Style::default().fg(theme().primary)),
         ]),
         Row::new(vec![
             Cell::from("Unit Name:").style(
                 Style::default()
-                    .fg(WARNING_COLOR)
+                    .fg(theme().warning)
                     .add_modifier(Modifier::BOLD),
             ),
-            Cell::from(unit_display.clone()).style(Style::default().fg(PRIMARY_COLOR)),
+            Cell::from(unit_display.clone()).style(Style::default().fg(theme().primary)),
         ]),
         Row::new(vec![Cell::from(""), Cell::from("")]), // Spacer
         Row::new(vec![
             Cell::from("Total Supply:").style(
                 Style::default()
-                    .fg(WARNING_COLOR)
+                    .fg(theme().warning)
                     .add_modifier(Modifier::BOLD),
             ),
-            Cell::from(total_display).style(Style::default().fg(SUCCESS_COLOR)),
+            Cell::from(total_display).style(Style::default().fg(theme().success)),
         ]),
         Row::new(vec![
             Cell::from("Decimals:").style(
                 Style::default()
-                    .fg(WARNING_COLOR)
+                    .fg(theme().warning)
                     .add_modifier(Modifier::BOLD),
             ),
-            Cell::from(format!("{}", asset.decimals)).style(Style::default().fg(Color::White)),
+            Cell::from(format!("{}", asset.decimals)).style(Style::default().fg(theme().text)),
         ]),
         Row::new(vec![
             Cell::from("Default Frozen:").style(
                 Style::default()
-                    .fg(WARNING_COLOR)
+                    .fg(theme().warning)
                     .add_modifier(Modifier::BOLD),
             ),
             Cell::from(if asset.default_frozen { "Yes" } else { "No" }).style(
                 if asset.default_frozen {
-                    Style::default().fg(Color::Red)
+                    Style::default().fg(theme().error)
                 } else {
-                    Style::default().fg(SUCCESS_COLOR)
+                    Style::default().fg(theme().success)
                 },
             ),
         ]),
@@ -159,10 +218,10 @@ pub fn render_asset_details(app: &App, frame: &mut Frame, area: Rect) {
         Row::new(vec![
             Cell::from("Creator:").style(
                 Style::default()
-                    .fg(WARNING_COLOR)
+                    .fg(theme().warning)
                     .add_modifier(Modifier::BOLD),
             ),
-            Cell::from(creator_display).style(Style::default().fg(WARNING_COLOR)),
+            Cell::from(creator_display).style(Style::default().fg(theme().warning)),
         ]),
     ];
 
@@ -176,10 +235,10 @@ pub fn render_asset_details(app: &App, frame: &mut Frame, area: Rect) {
         rows.push(Row::new(vec![
             Cell::from("URL:").style(
                 Style::default()
-                    .fg(WARNING_COLOR)
+                    .fg(theme().warning)
                     .add_modifier(Modifier::BOLD),
             ),
-            Cell::from(url_display).style(Style::default().fg(SECONDARY_COLOR)),
+            Cell::from(url_display).style(Style::default().fg(theme().secondary)),
         ]));
     }
 
@@ -188,7 +247,7 @@ pub fn render_asset_details(app: &App, frame: &mut Frame, area: Rect) {
     rows.push(Row::new(vec![
         Cell::from("Management:").style(
             Style::default()
-                .fg(WARNING_COLOR)
+                .fg(theme().warning)
                 .add_modifier(Modifier::BOLD),
         ),
         Cell::from("").style(Style::default()),
@@ -204,20 +263,20 @@ pub fn render_asset_details(app: &App, frame: &mut Frame, area: Rect) {
     };
 
     rows.push(Row::new(vec![
-        Cell::from("  Manager:").style(Style::default().fg(MUTED_COLOR)),
-        Cell::from(format_addr(&asset.manager)).style(Style::default().fg(ACCENT_COLOR)),
+        Cell::from("  Manager:").style(Style::default().fg(theme().muted)),
+        Cell::from(format_addr(&asset.manager)).style(Style::default().fg(theme().accent)),
     ]));
     rows.push(Row::new(vec![
-        Cell::from("  Reserve:").style(Style::default().fg(MUTED_COLOR)),
-        Cell::from(format_addr(&asset.reserve)).style(Style::default().fg(ACCENT_COLOR)),
+        Cell::from("  Reserve:").style(Style::default().fg(theme().muted)),
+        Cell::from(format_addr(&asset.reserve)).style(Style::default().fg(theme().accent)),
     ]));
     rows.push(Row::new(vec![
-        Cell::from("  Freeze:").style(Style::default().fg(MUTED_COLOR)),
-        Cell::from(format_addr(&asset.freeze)).style(Style::default().fg(ACCENT_COLOR)),
+        Cell::from("  Freeze:").style(Style::default().fg(theme().muted)),
+        Cell::from(format_addr(&asset.freeze)).style(Style::default().fg(theme().accent)),
     ]));
     rows.push(Row::new(vec![
-        Cell::from("  Clawback:").style(Style::default().fg(MUTED_COLOR)),
-        Cell::from(format_addr(&asset.clawback)).style(Style::default().fg(ACCENT_COLOR)),
+        Cell::from("  Clawback:").style(Style::default().fg(theme().muted)),
+        Cell::from(format_addr(&asset.clawback)).style(Style::default().fg(theme().accent)),
     ]));
 
     // Add metadata hash if present
@@ -231,10 +290,10 @@ pub fn render_asset_details(app: &App, frame: &mut Frame, area: Rect) {
         rows.push(Row::new(vec![
             Cell::from("Metadata Hash:").style(
                 Style::default()
-                    .fg(WARNING_COLOR)
+                    .fg(theme().warning)
                     .add_modifier(Modifier::BOLD),
             ),
-            Cell::from(hash_display).style(Style::default().fg(MUTED_COLOR)),
+            Cell::from(hash_display).style(Style::default().fg(theme().muted)),
         ]));
     }
 
@@ -243,10 +302,10 @@ pub fn render_asset_details(app: &App, frame: &mut Frame, area: Rect) {
         rows.push(Row::new(vec![
             Cell::from("Created Round:").style(
                 Style::default()
-                    .fg(WARNING_COLOR)
+                    .fg(theme().warning)
                     .add_modifier(Modifier::BOLD),
             ),
-            Cell::from(format!("{}", round)).style(Style::default().fg(MUTED_COLOR)),
+            Cell::from(format!("{}", round)).style(Style::default().fg(theme().muted)),
         ]));
     }
 
@@ -256,11 +315,14 @@ pub fn render_asset_details(app: &App, frame: &mut Frame, area: Rect) {
         rows.push(Row::new(vec![
             Cell::from("Status:").style(
                 Style::default()
-                    .fg(WARNING_COLOR)
+                    .fg(theme().warning)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Cell::from("DELETED").style(
+                Style::default()
+                    .fg(theme().error)
                     .add_modifier(Modifier::BOLD),
             ),
-            Cell::from("DELETED")
-                .style(Style::default().fg(Color::Red).add_modifier(Modifier::BOLD)),
         ]));
     }
 
@@ -269,15 +331,145 @@ pub fn render_asset_details(app: &App, frame: &mut Frame, area: Rect) {
         .column_spacing(2);
 
     frame.render_widget(table, content_area);
+}
 
-    // Help text
-    let help_text = "[C] Copy  [Y] JSON  [O] Open  [Esc] Close";
-    frame.render_widget(
-        Paragraph::new(help_text)
-            .style(Style::default().fg(MUTED_COLOR))
-            .alignment(Alignment::Center),
-        content_layout[1],
-    );
+/// Renders the Holders tab with the paged holder list and concentration summary.
+fn render_holders_tab(app: &App, asset: &AssetDetails, frame: &mut Frame, area: Rect) {
+    let sections = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(1), // Header
+            Constraint::Length(1), // Concentration summary
+            Constraint::Min(4),    // Holder list
+            Constraint::Length(1), // Paging status
+        ])
+        .split(area);
+
+    let Some(holders) = &app.data.asset_holders else {
+        let loading =
+            Paragraph::new("  Loading holders...").style(Style::default().fg(theme().muted));
+        frame.render_widget(loading, sections[2]);
+        return;
+    };
+
+    let filter_label = match holders.min_units {
+        None => "All".to_string(),
+        Some(units) => format!("> {units}"),
+    };
+    let header = Line::from(vec![
+        Span::styled(
+            format!(" Holders ({} loaded)", holders.holders.len()),
+            Style::default()
+                .fg(theme().secondary)
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::styled("  Min Balance: ", Style::default().fg(theme().muted)),
+        Span::styled(filter_label, Style::default().fg(theme().accent)),
+    ]);
+    frame.render_widget(Paragraph::new(header), sections[0]);
+
+    if let Some(summary) = concentration_summary(holders, asset) {
+        frame.render_widget(summary, sections[1]);
+    }
+
+    if holders.holders.is_empty() {
+        let message = if holders.loading {
+            "  Loading holders..."
+        } else {
+            "  No holders found"
+        };
+        let empty_msg = Paragraph::new(message).style(Style::default().fg(theme().muted));
+        frame.render_widget(empty_msg, sections[2]);
+        return;
+    }
+
+    let scroll_offset = app.nav.asset_holder_scroll as usize;
+    let visible_height = sections[2].height as usize;
+
+    let items: Vec<ListItem> = holders
+        .holders
+        .iter()
+        .enumerate()
+        .skip(scroll_offset)
+        .take(visible_height)
+        .map(|(i, holder)| {
+            let is_selected = app.nav.asset_holder_index == Some(i);
+            let indicator = if is_selected { "▶" } else { " " };
+            let frozen_indicator = if holder.is_frozen { " [frozen]" } else { "" };
+
+            let style = if is_selected {
+                Style::default().fg(theme().text).bg(theme().selection)
+            } else {
+                Style::default()
+            };
+
+            ListItem::new(Line::from(vec![
+                Span::raw(format!("{} ", indicator)),
+                Span::styled(holder.address.clone(), Style::default().fg(theme().warning)),
+                Span::raw("  "),
+                Span::styled(
+                    format!(
+                        "{} {}",
+                        format_asset_amount(holder.amount, asset.decimals),
+                        asset.unit_name
+                    ),
+                    Style::default().fg(theme().success),
+                ),
+                Span::styled(frozen_indicator, Style::default().fg(theme().error)),
+            ]))
+            .style(style)
+        })
+        .collect();
+
+    let list = List::new(items).block(Block::default());
+    frame.render_widget(list, sections[2]);
+
+    let status = if holders.loading {
+        Some("  Loading more holders...")
+    } else if holders.has_more() {
+        Some("  More available - scroll down to load more")
+    } else {
+        None
+    };
+    if let Some(status) = status {
+        frame.render_widget(
+            Paragraph::new(status).style(Style::default().fg(theme().muted)),
+            sections[3],
+        );
+    }
+}
+
+/// Builds the "top N hold X% of supply" line for the loaded holders.
+fn concentration_summary<'a>(
+    holders: &AssetHolders,
+    asset: &AssetDetails,
+) -> Option<Paragraph<'a>> {
+    if holders.holders.is_empty() || asset.total == 0 {
+        return None;
+    }
+    let top_n = TOP_HOLDERS.min(holders.holders.len());
+    let share = holders.top_holdings(top_n) as f64 / asset.total as f64 * 100.0;
+    let scope = if holders.has_more() {
+        " (of holders loaded so far)"
+    } else {
+        ""
+    };
+    Some(Paragraph::new(Line::from(vec![
+        Span::styled(
+            format!(" Top {top_n} hold "),
+            Style::default().fg(theme().muted),
+        ),
+        Span::styled(
+            format!("{share:.2}%"),
+            Style::default()
+                .fg(theme().accent)
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::styled(
+            format!(" of total supply{scope}"),
+            Style::default().fg(theme().muted),
+        ),
+    ])))
 }
 
 // ============================================================================
@@ -290,6 +482,8 @@ mod tests {
     use ratatui::{Terminal, backend::TestBackend};
     use rstest::*;
 
+    use crate::domain::{AssetHolder, Page};
+    use crate::state::AssetHolders;
     use crate::test_utils::{AssetMother, mock_app, test_terminal};
 
     // ============================================================================
@@ -339,4 +533,49 @@ mod tests {
 
         insta::assert_snapshot!("asset_details_gousd", test_terminal.backend());
     }
+
+    /// Snapshot test for the Holders tab with a loaded page and a frozen holder.
+    #[rstest]
+    #[tokio::test]
+    async fn test_asset_details_holders_tab(
+        mut test_terminal: Terminal<TestBackend>,
+        #[future] mock_app: App,
+    ) {
+        let mut app = mock_app.await;
+        let mut usdc = AssetMother::usdc();
+        usdc.total = 10_000_000_000;
+        let mut holders = AssetHolders::new(usdc.id, Some(0));
+        holders.append_page(Page::new(
+            vec![
+                AssetHolder {
+                    address: "Y76M3MSY6DKBRHBL7C3NNDXGS5IIMQVQVUAB6MP4XEMMGVF2QWNPL226CA"
+                        .to_string(),
+                    amount: 2_500_000_000,
+                    is_frozen: false,
+                    opted_in_at_round: Some(8_000_000),
+                },
+                AssetHolder {
+                    address: "AACCDJTFPQR5UQJZ337NFR56CC44T776EWBGVJG5NY2QFTQWBWTALTEN4A"
+                        .to_string(),
+                    amount: 1_234_567,
+                    is_frozen: true,
+                    opted_in_at_round: None,
+                },
+            ],
+            Some("next".to_string()),
+        ));
+        app.data.viewed_asset = Some(usdc);
+        app.data.asset_holders = Some(holders);
+        app.nav.show_asset_details = true;
+        app.nav.asset_detail_tab = AssetDetailTab::Holders;
+        app.nav.asset_holder_index = Some(1);
+
+        test_terminal
+            .draw(|frame| {
+                render_asset_details(&app, frame, frame.area());
+            })
+            .unwrap();
+
+        insta::assert_snapshot!("asset_details_holders_tab", test_terminal.backend());
+    }
 }
@@ -7,7 +7,7 @@
 use ratatui::{
     Frame,
     layout::{Alignment, Constraint, Direction, Layout, Rect},
-    style::{Color, Modifier, Style},
+    style::{Modifier, Style},
     symbols::scrollbar,
     text::{Line, Span},
     widgets::{
@@ -17,10 +17,7 @@ use ratatui::{
 
 use crate::domain::{AlgoBlock, BlockDetails};
 use crate::state::{App, BlockDetailTab};
-use crate::theme::{
-    ACCENT_COLOR, HIGHLIGHT_STYLE, MUTED_COLOR, PRIMARY_COLOR, SECONDARY_COLOR, SELECTED_STYLE,
-    SUCCESS_COLOR, WARNING_COLOR,
-};
+use crate::theme::theme;
 use crate::ui::helpers::create_popup_block;
 use crate::ui::layout::{centered_popup_area, fullscreen_popup_area};
 
@@ -62,7 +59,7 @@ pub fn render_block_details(app: &App, frame: &mut Frame, area: Rect) {
         let inner = popup_block.inner(popup_area);
         frame.render_widget(
             Paragraph::new("Loading block details...")
-                .style(Style::default().fg(MUTED_COLOR))
+                .style(Style::default().fg(theme().muted))
                 .alignment(Alignment::Center),
             inner,
         );
@@ -95,19 +92,19 @@ pub fn render_block_details(app: &App, frame: &mut Frame, area: Rect) {
     let is_info_tab = app.nav.block_detail_tab == BlockDetailTab::Info;
     let info_style = if is_info_tab {
         Style::default()
-            .bg(PRIMARY_COLOR)
-            .fg(Color::White)
+            .bg(theme().primary)
+            .fg(theme().text)
             .add_modifier(Modifier::BOLD)
     } else {
-        Style::default().fg(MUTED_COLOR)
+        Style::default().fg(theme().muted)
     };
     let txn_style = if !is_info_tab {
         Style::default()
-            .bg(PRIMARY_COLOR)
-            .fg(Color::White)
+            .bg(theme().primary)
+            .fg(theme().text)
             .add_modifier(Modifier::BOLD)
     } else {
-        Style::default().fg(MUTED_COLOR)
+        Style::default().fg(theme().muted)
     };
 
     let tab_bar = Line::from(vec![
@@ -121,7 +118,7 @@ pub fn render_block_details(app: &App, frame: &mut Frame, area: Rect) {
     // Separator
     let separator = "─".repeat(inner_area.width as usize);
     frame.render_widget(
-        Paragraph::new(separator).style(Style::default().fg(Color::DarkGray)),
+        Paragraph::new(separator).style(Style::default().fg(theme().border)),
         content_layout[1],
     );
 
@@ -135,11 +132,14 @@ pub fn render_block_details(app: &App, frame: &mut Frame, area: Rect) {
     }
 
     // Help text
-    let help_text =
-        "[Tab] Switch  [↑↓] Navigate  [Enter] View  [C] Copy  [Y] JSON  [O] Open  [Esc] Close";
+    let help_text = if is_info_tab {
+        "[Tab] Switch  [↑↓] Navigate  [Enter] View  [C] Copy  [Y] JSON  [O] Open  [Esc] Close"
+    } else {
+        "[Tab] Switch  [↑↓] Navigate  [Enter] View  [C] Copy  [E] Export  [Esc] Close"
+    };
     frame.render_widget(
         Paragraph::new(help_text)
-            .style(Style::default().fg(MUTED_COLOR))
+            .style(Style::default().fg(theme().muted))
             .alignment(Alignment::Center),
         content_layout[3],
     );
@@ -164,27 +164,27 @@ fn render_block_info_tab(
         Row::new(vec![
             Cell::from("Block ID:").style(
                 Style::default()
-                    .fg(WARNING_COLOR)
+                    .fg(theme().warning)
                     .add_modifier(Modifier::BOLD),
             ),
-            Cell::from(format!("{}", block_data.id)).style(Style::default().fg(PRIMARY_COLOR)),
+            Cell::from(format!("{}", block_data.id)).style(Style::default().fg(theme().primary)),
         ]),
         Row::new(vec![
             Cell::from("Transactions:").style(
                 Style::default()
-                    .fg(WARNING_COLOR)
+                    .fg(theme().warning)
                     .add_modifier(Modifier::BOLD),
             ),
             Cell::from(format!("{}", block_data.txn_count))
-                .style(Style::default().fg(SUCCESS_COLOR)),
+                .style(Style::default().fg(theme().success)),
         ]),
         Row::new(vec![
             Cell::from("Timestamp:").style(
                 Style::default()
-                    .fg(WARNING_COLOR)
+                    .fg(theme().warning)
                     .add_modifier(Modifier::BOLD),
             ),
-            Cell::from(block_data.timestamp.clone()).style(Style::default().fg(MUTED_COLOR)),
+            Cell::from(block_data.timestamp.clone()).style(Style::default().fg(theme().muted)),
         ]),
     ];
 
@@ -194,22 +194,28 @@ fn render_block_info_tab(
         rows.push(Row::new(vec![
             Cell::from("Proposer:").style(
                 Style::default()
-                    .fg(WARNING_COLOR)
+                    .fg(theme().warning)
                     .add_modifier(Modifier::BOLD),
             ),
-            Cell::from(details.info.proposer.clone()).style(Style::default().fg(ACCENT_COLOR)),
+            Cell::from(details.info.proposer.clone()).style(Style::default().fg(theme().accent)),
         ]));
 
         // Type breakdown
         if !details.txn_type_counts.is_empty() {
+            // Counts only cover the pages fetched so far
+            let coverage = if details.has_more() {
+                format!("(first {} loaded)", details.transactions.len())
+            } else {
+                String::new()
+            };
             rows.push(Row::new(vec![Cell::from(""), Cell::from("")])); // Spacer
             rows.push(Row::new(vec![
                 Cell::from("Transaction Types:").style(
                     Style::default()
-                        .fg(WARNING_COLOR)
+                        .fg(theme().warning)
                         .add_modifier(Modifier::BOLD),
                 ),
-                Cell::from("").style(Style::default()),
+                Cell::from(coverage).style(Style::default().fg(theme().muted)),
             ]));
 
             // Sort by count descending, then by name for stable ordering
@@ -219,8 +225,8 @@ fn render_block_info_tab(
             for (txn_type, count) in type_counts {
                 rows.push(Row::new(vec![
                     Cell::from(format!("  {}:", txn_type.as_str()))
-                        .style(Style::default().fg(txn_type.color())),
-                    Cell::from(format!("{}", count)).style(Style::default().fg(Color::White)),
+                        .style(Style::default().fg(theme().txn_color(*txn_type))),
+                    Cell::from(format!("{}", count)).style(Style::default().fg(theme().text)),
                 ]));
             }
         }
@@ -250,7 +256,7 @@ fn render_block_transactions_tab(
     let Some(details) = block_details else {
         // Still loading
         let loading = Paragraph::new("Loading transactions...")
-            .style(Style::default().fg(MUTED_COLOR))
+            .style(Style::default().fg(theme().muted))
             .alignment(Alignment::Center);
         frame.render_widget(loading, area);
         return;
@@ -258,12 +264,40 @@ fn render_block_transactions_tab(
 
     if details.transactions.is_empty() {
         let empty = Paragraph::new("No transactions in this block")
-            .style(Style::default().fg(MUTED_COLOR))
+            .style(Style::default().fg(theme().muted))
             .alignment(Alignment::Center);
         frame.render_widget(empty, area);
         return;
     }
 
+    // Reserve a status line while more pages remain on the indexer
+    let area = if details.has_more() {
+        let [list_area, status_area] =
+            Layout::vertical([Constraint::Min(1), Constraint::Length(1)]).areas(area);
+        let status = if app.data.block_txns_loading_more {
+            format!(
+                "Loading more transactions... ({} of {})",
+                details.transactions.len(),
+                details.info.txn_count
+            )
+        } else {
+            format!(
+                "{} of {} loaded - scroll down to load more",
+                details.transactions.len(),
+                details.info.txn_count
+            )
+        };
+        frame.render_widget(
+            Paragraph::new(status)
+                .style(Style::default().fg(theme().muted))
+                .alignment(Alignment::Center),
+            status_area,
+        );
+        list_area
+    } else {
+        area
+    };
+
     let item_height: u16 = 2; // Each transaction takes 2 lines
     let items_per_page = area.height as usize / item_height as usize;
     let scroll_offset = app.nav.block_txn_scroll as usize / item_height as usize;
@@ -289,26 +323,26 @@ fn render_block_transactions_tab(
                     Span::styled(
                         txn.id.chars().take(20).collect::<String>() + "...",
                         Style::default()
-                            .fg(SECONDARY_COLOR)
+                            .fg(theme().secondary)
                             .add_modifier(Modifier::BOLD),
                     ),
                     Span::raw("  "),
                     Span::styled(
                         format!("[{}]", txn.txn_type.as_str()),
-                        Style::default().fg(txn.txn_type.color()),
+                        Style::default().fg(theme().txn_color(txn.txn_type)),
                     ),
                 ]),
                 Line::from(vec![
                     Span::raw("   "),
-                    Span::styled("From: ", Style::default().fg(MUTED_COLOR)),
+                    Span::styled("From: ", Style::default().fg(theme().muted)),
                     Span::styled(
                         txn.from.chars().take(20).collect::<String>() + "...",
-                        Style::default().fg(WARNING_COLOR),
+                        Style::default().fg(theme().warning),
                     ),
                 ]),
             ])
             .style(if is_selected {
-                SELECTED_STYLE
+                theme().selected_style()
             } else {
                 Style::default()
             })
@@ -317,7 +351,7 @@ fn render_block_transactions_tab(
 
     let txn_list = List::new(txn_items)
         .block(Block::default())
-        .highlight_style(HIGHLIGHT_STYLE);
+        .highlight_style(theme().highlight_style());
 
     frame.render_widget(txn_list, area);
 
@@ -330,8 +364,8 @@ fn render_block_transactions_tab(
             .track_symbol(None)
             .begin_symbol(None)
             .end_symbol(None)
-            .style(Style::default().fg(MUTED_COLOR))
-            .track_style(Style::default().fg(Color::DarkGray));
+            .style(Style::default().fg(theme().muted))
+            .track_style(Style::default().fg(theme().border));
 
         let content_length = total_items * item_height as usize;
         let mut scrollbar_state = ratatui::widgets::ScrollbarState::default()
@@ -416,4 +450,31 @@ mod tests {
 
         insta::assert_snapshot!(test_terminal.backend());
     }
+
+    /// Snapshot test for block details popup - Transactions tab with more pages pending.
+    #[rstest]
+    #[tokio::test]
+    async fn test_block_details_txns_tab_loading_more(
+        mut test_terminal: Terminal<TestBackend>,
+        #[future] mock_app: App,
+    ) {
+        let mut block_details = BlockMother::mainnet_block_50m();
+        block_details.info.txn_count = 1200;
+        block_details.next_token = Some("next-page".to_string());
+
+        let mut app = mock_app.await;
+        app.data.block_details = Some(block_details);
+        app.data.block_txns_loading_more = true;
+        app.nav.show_block_details = true;
+        app.nav.block_detail_tab = BlockDetailTab::Transactions;
+        app.nav.block_txn_index = Some(0);
+
+        test_terminal
+            .draw(|frame| {
+                render_block_details(&app, frame, frame.area());
+            })
+            .unwrap();
+
+        insta::assert_snapshot!(test_terminal.backend());
+    }
 }
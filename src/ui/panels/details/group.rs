@@ -0,0 +1,365 @@
+//! Atomic group detail panel rendering.
+//!
+//! This module displays the members of an atomic transaction group with
+//! the group's totals, and a flow graph of the whole group.
+
+use ratatui::{
+    Frame,
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    symbols::scrollbar,
+    text::{Line, Span},
+    widgets::{
+        Block, Cell, Clear, List, ListItem, Paragraph, Row, Scrollbar, ScrollbarOrientation, Table,
+    },
+};
+
+use crate::domain::{Transaction, TxnGroup, TxnType};
+use crate::state::{App, GroupDetailTab};
+use crate::theme::theme;
+use crate::ui::helpers::create_popup_block;
+use crate::ui::layout::{centered_popup_area, fullscreen_popup_area};
+use crate::widgets::{TxnGraph, TxnGraphWidget};
+
+/// Renders the atomic group details popup with tabbed interface.
+///
+/// # Arguments
+///
+/// * `app` - Application state containing the viewed group and navigation state
+/// * `frame` - Ratatui frame for rendering
+/// * `area` - Available screen area for rendering
+pub fn render_group_details(app: &App, frame: &mut Frame, area: Rect) {
+    let popup_area = if app.ui.detail_fullscreen {
+        fullscreen_popup_area(area)
+    } else {
+        centered_popup_area(area, 90, 32)
+    };
+    let popup_block = create_popup_block("Atomic Group");
+    frame.render_widget(Clear, popup_area);
+    frame.render_widget(popup_block.clone(), popup_area);
+
+    let inner_area = popup_block.inner(popup_area);
+
+    let Some(group) = &app.data.viewed_group else {
+        frame.render_widget(
+            Paragraph::new("Loading group...")
+                .style(Style::default().fg(theme().muted))
+                .alignment(Alignment::Center),
+            inner_area,
+        );
+        return;
+    };
+
+    // Layout: tab bar, separator, content, help text
+    let content_layout = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(1), // Tab bar
+            Constraint::Length(1), // Separator
+            Constraint::Min(10),   // Main content
+            Constraint::Length(1), // Help text
+        ])
+        .split(inner_area);
+
+    // Render tab bar
+    let active_style = Style::default()
+        .bg(theme().primary)
+        .fg(theme().text)
+        .add_modifier(Modifier::BOLD);
+    let inactive_style = Style::default().fg(theme().muted);
+    let is_members_tab = app.nav.group_detail_tab == GroupDetailTab::Members;
+    let (members_style, graph_style) = if is_members_tab {
+        (active_style, inactive_style)
+    } else {
+        (inactive_style, active_style)
+    };
+
+    let tab_bar = Line::from(vec![
+        Span::raw("  "),
+        Span::styled(" Members ", members_style),
+        Span::raw("  "),
+        Span::styled(" Graph ", graph_style),
+    ]);
+    frame.render_widget(Paragraph::new(tab_bar), content_layout[0]);
+
+    // Separator
+    let separator = "─".repeat(inner_area.width as usize);
+    frame.render_widget(
+        Paragraph::new(separator).style(Style::default().fg(theme().border)),
+        content_layout[1],
+    );
+
+    let content_area = content_layout[2];
+    let help_text = if is_members_tab {
+        render_members_tab(app, group, frame, content_area);
+        "[Tab] Switch  [↑↓] Navigate  [Enter] View member  [F] Fullscreen  [Esc] Back"
+    } else {
+        render_graph_tab(app, group, frame, content_area);
+        "[Tab] Switch  [↑↓←→] Scroll  [F] Fullscreen  [Esc] Back"
+    };
+
+    frame.render_widget(
+        Paragraph::new(help_text)
+            .style(Style::default().fg(theme().muted))
+            .alignment(Alignment::Center),
+        content_layout[3],
+    );
+}
+
+/// Renders the Members tab: group totals followed by the member list.
+fn render_members_tab(app: &App, group: &TxnGroup, frame: &mut Frame, area: Rect) {
+    let label_style = Style::default()
+        .fg(theme().warning)
+        .add_modifier(Modifier::BOLD);
+
+    let mut rows = vec![
+        Row::new(vec![
+            Cell::from("Group ID:").style(label_style),
+            Cell::from(group.id.clone()).style(Style::default().fg(theme().primary)),
+        ]),
+        Row::new(vec![
+            Cell::from("Round:").style(label_style),
+            Cell::from(group.round.to_string()).style(Style::default().fg(theme().text)),
+        ]),
+        Row::new(vec![
+            Cell::from("Members:").style(label_style),
+            Cell::from(group.transactions.len().to_string())
+                .style(Style::default().fg(theme().success)),
+        ]),
+        Row::new(vec![
+            Cell::from("Total Fees:").style(label_style),
+            Cell::from(format!(
+                "{:.6} Algos",
+                group.total_fees() as f64 / 1_000_000.0
+            ))
+            .style(Style::default().fg(theme().text)),
+        ]),
+        Row::new(vec![
+            Cell::from("Algos Moved:").style(label_style),
+            Cell::from(format!(
+                "{:.6} Algos",
+                group.total_algos() as f64 / 1_000_000.0
+            ))
+            .style(Style::default().fg(theme().text)),
+        ]),
+    ];
+    for (asset_id, amount) in group.asset_totals() {
+        rows.push(Row::new(vec![
+            Cell::from(format!("  Asset #{asset_id}:")).style(Style::default().fg(theme().muted)),
+            Cell::from(format!("{amount} units")).style(Style::default().fg(theme().text)),
+        ]));
+    }
+
+    let [summary_area, gap_area, list_area] = Layout::vertical([
+        Constraint::Length(rows.len() as u16),
+        Constraint::Length(1),
+        Constraint::Min(1),
+    ])
+    .areas(area);
+
+    frame.render_widget(
+        Table::new(rows, [Constraint::Length(20), Constraint::Min(50)]).column_spacing(2),
+        summary_area,
+    );
+    frame.render_widget(
+        Paragraph::new("─".repeat(area.width as usize)).style(Style::default().fg(theme().border)),
+        gap_area,
+    );
+
+    render_member_list(app, &group.transactions, frame, list_area);
+}
+
+/// Renders one line per member transaction, in group order.
+fn render_member_list(app: &App, members: &[Transaction], frame: &mut Frame, area: Rect) {
+    if members.is_empty() {
+        frame.render_widget(
+            Paragraph::new("No members found")
+                .style(Style::default().fg(theme().muted))
+                .alignment(Alignment::Center),
+            area,
+        );
+        return;
+    }
+
+    let visible_height = area.height as usize;
+    let scroll = app.nav.group_txn_scroll as usize;
+
+    let items: Vec<ListItem> = members
+        .iter()
+        .enumerate()
+        .skip(scroll)
+        .take(visible_height)
+        .map(|(i, txn)| {
+            let is_selected = app.nav.group_txn_index == Some(i);
+            let indicator = if is_selected { "▶" } else { " " };
+
+            ListItem::new(Line::from(vec![
+                Span::raw(format!("{indicator} {:>2}. ", i + 1)),
+                Span::styled(
+                    format!("[{}]", txn.txn_type.as_str()),
+                    Style::default().fg(theme().txn_color(txn.txn_type)),
+                ),
+                Span::raw("  "),
+                Span::styled(
+                    txn.id.chars().take(16).collect::<String>() + "...",
+                    Style::default()
+                        .fg(theme().secondary)
+                        .add_modifier(Modifier::BOLD),
+                ),
+                Span::raw("  "),
+                Span::styled(
+                    txn.from.chars().take(10).collect::<String>() + "...",
+                    Style::default().fg(theme().warning),
+                ),
+                Span::styled(" → ", Style::default().fg(theme().muted)),
+                Span::styled(
+                    txn.to.chars().take(10).collect::<String>() + "...",
+                    Style::default().fg(theme().accent),
+                ),
+                Span::raw("  "),
+                Span::styled(member_amount(txn), Style::default().fg(theme().text)),
+            ]))
+            .style(if is_selected {
+                theme().selected_style()
+            } else {
+                Style::default()
+            })
+        })
+        .collect();
+
+    frame.render_widget(List::new(items).block(Block::default()), area);
+
+    if members.len() > visible_height {
+        let scrollbar = Scrollbar::default()
+            .orientation(ScrollbarOrientation::VerticalRight)
+            .symbols(scrollbar::VERTICAL)
+            .track_symbol(None)
+            .begin_symbol(None)
+            .end_symbol(None)
+            .style(Style::default().fg(theme().muted))
+            .track_style(Style::default().fg(theme().border));
+
+        let mut scrollbar_state = ratatui::widgets::ScrollbarState::default()
+            .content_length(members.len())
+            .viewport_content_length(visible_height)
+            .position(scroll);
+
+        frame.render_stateful_widget(scrollbar, area, &mut scrollbar_state);
+    }
+}
+
+/// Short amount column for a member: Algos for payments, base units for
+/// asset transfers, blank otherwise.
+fn member_amount(txn: &Transaction) -> String {
+    match (txn.txn_type, txn.asset_id) {
+        (TxnType::Payment, _) => format!("{:.6} Algos", txn.amount as f64 / 1_000_000.0),
+        (TxnType::AssetTransfer, Some(asset_id)) => format!("{} of #{asset_id}", txn.amount),
+        _ => String::new(),
+    }
+}
+
+/// Renders the Graph tab: the flow graph of every member.
+fn render_graph_tab(app: &App, group: &TxnGroup, frame: &mut Frame, area: Rect) {
+    let graph = TxnGraph::from_transactions(&group.transactions);
+    if graph.columns.is_empty() {
+        frame.render_widget(
+            Paragraph::new("Nothing to draw")
+                .style(Style::default().fg(theme().muted))
+                .alignment(Alignment::Center),
+            area,
+        );
+        return;
+    }
+
+    let graph_widget = TxnGraphWidget::new(&graph);
+    let padded_area = Rect::new(
+        area.x + 1,
+        area.y,
+        area.width.saturating_sub(2),
+        area.height,
+    );
+
+    // Clamp scroll offsets to the graph size
+    let max_scroll_y = graph_widget
+        .required_height()
+        .saturating_sub(padded_area.height as usize);
+    let max_scroll_x = graph_widget
+        .required_width()
+        .saturating_sub(padded_area.width as usize);
+    let scroll_y = (app.nav.graph_scroll_y as usize).min(max_scroll_y) as u16;
+    let scroll_x = (app.nav.graph_scroll_x as usize).min(max_scroll_x) as u16;
+
+    frame.render_widget(
+        Paragraph::new(graph_widget.to_lines()).scroll((scroll_y, scroll_x)),
+        padded_area,
+    );
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::{Terminal, backend::TestBackend};
+    use rstest::*;
+
+    use crate::test_utils::{TransactionMother, mock_app, test_terminal};
+
+    fn sample_group() -> TxnGroup {
+        let mut payment = TransactionMother::payment_with_addresses(
+            "PAYMENTTXNID",
+            "SENDERADDRESSAAAA",
+            "RECEIVERADDRESSBB",
+        );
+        payment.block = 50_000_000;
+        let mut transfer = TransactionMother::asset_transfer("TRANSFERTXNID", 31566704, 2_500);
+        transfer.block = 50_000_000;
+        TxnGroup::new(
+            "R1JPVVBJRA==".to_string(),
+            50_000_000,
+            vec![payment, transfer],
+        )
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_group_details_members_tab(
+        mut test_terminal: Terminal<TestBackend>,
+        #[future] mock_app: App,
+    ) {
+        let mut app = mock_app.await;
+        app.data.viewed_group = Some(sample_group());
+        app.nav.show_group_details = true;
+        app.nav.group_txn_index = Some(1);
+
+        test_terminal
+            .draw(|frame| {
+                render_group_details(&app, frame, frame.area());
+            })
+            .unwrap();
+
+        insta::assert_snapshot!("group_details_members_tab", test_terminal.backend());
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_group_details_graph_tab(
+        mut test_terminal: Terminal<TestBackend>,
+        #[future] mock_app: App,
+    ) {
+        let mut app = mock_app.await;
+        app.data.viewed_group = Some(sample_group());
+        app.nav.show_group_details = true;
+        app.nav.group_detail_tab = GroupDetailTab::Graph;
+
+        test_terminal
+            .draw(|frame| {
+                render_group_details(&app, frame, frame.area());
+            })
+            .unwrap();
+
+        insta::assert_snapshot!("group_details_graph_tab", test_terminal.backend());
+    }
+}
@@ -1,11 +1,16 @@
 //! Detail panel rendering modules for LazyLora TUI.
 //!
 //! This module contains rendering functions for various detail views including
-//! blocks, transactions, accounts, and assets. Each detail type has its own
-//! dedicated module for maintainability.
+//! blocks, transactions, atomic groups, search results, the pending pool,
+//! simulations, accounts, and assets.
+//! Each detail type has its own dedicated module for maintainability.
 
 pub mod account;
 pub mod application;
 pub mod asset;
 pub mod block;
+pub mod group;
+pub mod pending_pool;
+pub mod simulation;
 pub mod transaction;
+pub mod txn_search;
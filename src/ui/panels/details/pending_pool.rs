@@ -0,0 +1,240 @@
+//! Pending transaction pool rendering.
+//!
+//! Lists the transactions waiting in algod's pool in priority order,
+//! followed by the ones recently confirmed, which open from the indexer.
+
+use ratatui::{
+    Frame,
+    layout::{Alignment, Constraint, Layout, Rect},
+    style::{Modifier, Style},
+    symbols::scrollbar,
+    text::{Line, Span},
+    widgets::{
+        Block, Clear, List, ListItem, Paragraph, Scrollbar, ScrollbarOrientation, ScrollbarState,
+    },
+};
+
+use crate::domain::Transaction;
+use crate::state::{App, PoolScope};
+use crate::theme::theme;
+use crate::ui::helpers::create_popup_block;
+use crate::ui::layout::{centered_popup_area, fullscreen_popup_area};
+use crate::widgets::helpers::format_algo_amount;
+
+/// Height of each pool row (in lines).
+const POOL_ROW_HEIGHT: u16 = 3;
+
+/// Renders the pending pool popup.
+///
+/// # Arguments
+///
+/// * `app` - Application state containing the pool and navigation state
+/// * `frame` - Ratatui frame for rendering
+/// * `area` - Available screen area for rendering
+pub fn render_pending_pool(app: &App, frame: &mut Frame, area: Rect) {
+    let popup_area = if app.ui.detail_fullscreen {
+        fullscreen_popup_area(area)
+    } else {
+        centered_popup_area(area, 90, 36)
+    };
+    let popup_block = create_popup_block("Pending Pool");
+    frame.render_widget(Clear, popup_area);
+    frame.render_widget(popup_block.clone(), popup_area);
+
+    let inner_area = popup_block.inner(popup_area);
+    let [
+        header_area,
+        separator_area,
+        list_area,
+        status_area,
+        help_area,
+    ] = Layout::vertical([
+        Constraint::Length(1), // Pool summary
+        Constraint::Length(1), // Separator
+        Constraint::Min(3),    // Transaction list
+        Constraint::Length(1), // Live status
+        Constraint::Length(1), // Help text
+    ])
+    .areas(inner_area);
+
+    frame.render_widget(
+        Paragraph::new("─".repeat(inner_area.width as usize))
+            .style(Style::default().fg(theme().border)),
+        separator_area,
+    );
+    frame.render_widget(
+        Paragraph::new("[↑↓] Navigate  [Enter] View  [Esc] Close")
+            .style(Style::default().fg(theme().muted))
+            .alignment(Alignment::Center),
+        help_area,
+    );
+
+    let Some(pool) = &app.data.pending_pool else {
+        return;
+    };
+
+    let mut header = vec![Span::styled(
+        format!(
+            " Pending ({} of {} in pool)",
+            pool.pending.len(),
+            pool.total
+        ),
+        Style::default()
+            .fg(theme().secondary)
+            .add_modifier(Modifier::BOLD),
+    )];
+    if let PoolScope::Account(address) = &pool.scope {
+        header.push(Span::styled(
+            "  Sender: ",
+            Style::default().fg(theme().muted),
+        ));
+        header.push(Span::styled(
+            address.clone(),
+            Style::default().fg(theme().accent),
+        ));
+    }
+    frame.render_widget(Paragraph::new(Line::from(header)), header_area);
+
+    if !app.show_live {
+        frame.render_widget(
+            Paragraph::new("  Live updates paused").style(Style::default().fg(theme().warning)),
+            status_area,
+        );
+    }
+
+    if pool.is_empty() {
+        let message = if pool.loading {
+            "Loading pool..."
+        } else {
+            "The pool is empty"
+        };
+        frame.render_widget(
+            Paragraph::new(message)
+                .style(Style::default().fg(theme().muted))
+                .alignment(Alignment::Center),
+            list_area,
+        );
+        return;
+    }
+
+    let scroll = app.nav.pending_pool_scroll as usize;
+    let visible_items = ((list_area.height / POOL_ROW_HEIGHT) as usize).max(1);
+
+    let items: Vec<ListItem> = (0..pool.len())
+        .filter_map(|i| pool.get(i).map(|(txn, confirmed)| (i, txn, confirmed)))
+        .skip(scroll)
+        .take(visible_items)
+        .map(|(i, txn, confirmed)| {
+            pool_list_item(txn, confirmed, app.nav.pending_pool_index == Some(i))
+        })
+        .collect();
+    frame.render_widget(List::new(items).block(Block::default()), list_area);
+
+    if pool.len() > visible_items {
+        let scrollbar = Scrollbar::default()
+            .orientation(ScrollbarOrientation::VerticalRight)
+            .symbols(scrollbar::VERTICAL)
+            .track_symbol(None)
+            .begin_symbol(None)
+            .end_symbol(None)
+            .style(Style::default().fg(theme().muted))
+            .track_style(Style::default().fg(theme().border));
+
+        let mut scrollbar_state = ScrollbarState::default()
+            .content_length(pool.len())
+            .viewport_content_length(visible_items)
+            .position(scroll);
+
+        frame.render_stateful_widget(scrollbar, list_area, &mut scrollbar_state);
+    }
+}
+
+/// Builds a pool row: ID and type, then sender and fee, or the round it was
+/// confirmed in.
+fn pool_list_item(txn: &Transaction, confirmed: bool, is_selected: bool) -> ListItem<'static> {
+    let selection_indicator = if is_selected { "▶" } else { "→" };
+    let status = if confirmed {
+        Span::styled(
+            format!("  ✓ Confirmed in round {}", txn.block),
+            Style::default().fg(theme().success),
+        )
+    } else {
+        Span::styled("  Pending", Style::default().fg(theme().warning))
+    };
+
+    ListItem::new(vec![
+        Line::from(vec![
+            Span::raw(format!("{selection_indicator} ")),
+            Span::styled(
+                txn.id.clone(),
+                Style::default()
+                    .fg(theme().secondary)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::raw("  "),
+            Span::styled(
+                format!("[{}]", txn.txn_type.as_str()),
+                Style::default().fg(theme().txn_color(txn.txn_type)),
+            ),
+            status,
+        ]),
+        Line::from(vec![
+            Span::raw("  "),
+            Span::styled("From: ", Style::default().fg(theme().muted)),
+            Span::styled(txn.from.clone(), Style::default().fg(theme().warning)),
+            Span::styled("  Fee: ", Style::default().fg(theme().muted)),
+            Span::raw(format_algo_amount(txn.fee)),
+        ]),
+        Line::from(""),
+    ])
+    .style(if is_selected {
+        theme().selected_style()
+    } else {
+        Style::default()
+    })
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::{Terminal, backend::TestBackend};
+    use rstest::*;
+
+    use crate::state::PendingPool;
+    use crate::test_utils::{TransactionMother, mock_app, test_terminal};
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_pending_pool_snapshot(
+        mut test_terminal: Terminal<TestBackend>,
+        #[future] mock_app: App,
+    ) {
+        let mut app = mock_app.await;
+        let mut pool = PendingPool::new(PoolScope::All);
+        let mut confirmed = TransactionMother::payment("CONFIRMEDTXNID");
+        confirmed.block = 51_000_123;
+        pool.update(
+            vec![
+                TransactionMother::payment("PENDINGTXNID"),
+                TransactionMother::asset_transfer("PENDINGAXFERID", 31566704, 2_500),
+            ],
+            57,
+            vec![confirmed],
+        );
+        app.data.pending_pool = Some(pool);
+        app.nav.show_pending_pool = true;
+        app.nav.pending_pool_index = Some(1);
+
+        test_terminal
+            .draw(|frame| {
+                render_pending_pool(&app, frame, frame.area());
+            })
+            .unwrap();
+
+        insta::assert_snapshot!("pending_pool", test_terminal.backend());
+    }
+}
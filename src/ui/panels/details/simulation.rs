@@ -0,0 +1,418 @@
+//! Transaction group simulation rendering.
+//!
+//! Shows whether the simulated group would be accepted, where it failed and
+//! the budget it used, followed by its transactions and a flow graph of the
+//! group. Logs and state changes open with each transaction.
+
+use ratatui::{
+    Frame,
+    layout::{Alignment, Constraint, Layout, Rect},
+    style::{Modifier, Style},
+    symbols::scrollbar,
+    text::{Line, Span},
+    widgets::{
+        Block, Clear, List, ListItem, Paragraph, Scrollbar, ScrollbarOrientation, ScrollbarState,
+        Wrap,
+    },
+};
+
+use crate::domain::{SimulatedTxn, Simulation, TransactionDetails};
+use crate::state::{App, SimulationTab};
+use crate::theme::theme;
+use crate::ui::helpers::create_popup_block;
+use crate::ui::layout::{centered_popup_area, fullscreen_popup_area};
+use crate::widgets::{TxnGraph, TxnGraphWidget};
+
+/// Height of each transaction row (in lines).
+const SIMULATED_ROW_HEIGHT: u16 = 2;
+
+/// Renders the simulation popup with tabbed interface.
+///
+/// # Arguments
+///
+/// * `app` - Application state containing the simulation and navigation state
+/// * `frame` - Ratatui frame for rendering
+/// * `area` - Available screen area for rendering
+pub fn render_simulation(app: &App, frame: &mut Frame, area: Rect) {
+    let popup_area = if app.ui.detail_fullscreen {
+        fullscreen_popup_area(area)
+    } else {
+        centered_popup_area(area, 90, 32)
+    };
+    let popup_block = create_popup_block("Simulation");
+    frame.render_widget(Clear, popup_area);
+    frame.render_widget(popup_block.clone(), popup_area);
+
+    let inner_area = popup_block.inner(popup_area);
+
+    let Some(simulation) = &app.data.simulation else {
+        return;
+    };
+
+    let [tab_area, separator_area, content_area, help_area] = Layout::vertical([
+        Constraint::Length(1), // Tab bar
+        Constraint::Length(1), // Separator
+        Constraint::Min(10),   // Main content
+        Constraint::Length(1), // Help text
+    ])
+    .areas(inner_area);
+
+    let active_style = Style::default()
+        .bg(theme().primary)
+        .fg(theme().text)
+        .add_modifier(Modifier::BOLD);
+    let inactive_style = Style::default().fg(theme().muted);
+    let is_result_tab = app.nav.simulation_tab == SimulationTab::Result;
+    let (result_style, graph_style) = if is_result_tab {
+        (active_style, inactive_style)
+    } else {
+        (inactive_style, active_style)
+    };
+
+    let tab_bar = Line::from(vec![
+        Span::raw("  "),
+        Span::styled(" Result ", result_style),
+        Span::raw("  "),
+        Span::styled(" Graph ", graph_style),
+    ]);
+    frame.render_widget(Paragraph::new(tab_bar), tab_area);
+
+    frame.render_widget(
+        Paragraph::new("─".repeat(inner_area.width as usize))
+            .style(Style::default().fg(theme().border)),
+        separator_area,
+    );
+
+    let help_text = if is_result_tab {
+        render_result_tab(app, simulation, frame, content_area);
+        "[Tab] Switch  [↑↓] Navigate  [Enter] View  [x] Simulate again  [Esc] Close"
+    } else {
+        render_graph_tab(app, simulation, frame, content_area);
+        "[Tab] Switch  [↑↓←→] Scroll  [F] Fullscreen  [Esc] Close"
+    };
+
+    frame.render_widget(
+        Paragraph::new(help_text)
+            .style(Style::default().fg(theme().muted))
+            .alignment(Alignment::Center),
+        help_area,
+    );
+}
+
+/// Renders the Result tab: the outcome followed by the transaction list.
+fn render_result_tab(app: &App, simulation: &Simulation, frame: &mut Frame, area: Rect) {
+    let summary = summary_lines(simulation);
+    // The failure message wraps onto as many lines as it needs
+    let width = usize::from(area.width.max(1));
+    let summary_height = summary
+        .iter()
+        .map(|line| line.width().max(1).div_ceil(width) as u16)
+        .sum();
+
+    let [summary_area, gap_area, list_area] = Layout::vertical([
+        Constraint::Length(summary_height),
+        Constraint::Length(1),
+        Constraint::Min(2),
+    ])
+    .areas(area);
+
+    frame.render_widget(
+        Paragraph::new(summary).wrap(Wrap { trim: false }),
+        summary_area,
+    );
+    frame.render_widget(
+        Paragraph::new("─".repeat(area.width as usize)).style(Style::default().fg(theme().border)),
+        gap_area,
+    );
+
+    render_txn_list(app, simulation, frame, list_area);
+}
+
+/// Builds the outcome summary: result, failure, round and group budget.
+fn summary_lines(simulation: &Simulation) -> Vec<Line<'static>> {
+    let label = |text: &str| {
+        Span::styled(
+            format!("{text:<14}"),
+            Style::default()
+                .fg(theme().warning)
+                .add_modifier(Modifier::BOLD),
+        )
+    };
+
+    let outcome = if simulation.would_succeed() {
+        Span::styled(
+            "✓ Would succeed",
+            Style::default()
+                .fg(theme().success)
+                .add_modifier(Modifier::BOLD),
+        )
+    } else {
+        Span::styled(
+            "✗ Would fail",
+            Style::default()
+                .fg(theme().error)
+                .add_modifier(Modifier::BOLD),
+        )
+    };
+
+    let mut lines = vec![Line::from(vec![label("Result:"), outcome])];
+    if let Some(failed_at) = simulation.failed_at_label() {
+        lines.push(Line::from(vec![
+            label("Failed At:"),
+            Span::styled(failed_at, Style::default().fg(theme().error)),
+        ]));
+    }
+    if let Some(message) = &simulation.failure_message {
+        lines.push(Line::from(vec![
+            label("Message:"),
+            Span::styled(message.clone(), Style::default().fg(theme().text)),
+        ]));
+    }
+    lines.push(Line::from(vec![
+        label("Round:"),
+        Span::styled(
+            simulation.round.to_string(),
+            Style::default().fg(theme().text),
+        ),
+    ]));
+    if simulation.app_budget_added > 0 {
+        lines.push(Line::from(vec![
+            label("App Budget:"),
+            Span::styled(
+                format!(
+                    "{} of {} used",
+                    simulation.app_budget_consumed, simulation.app_budget_added
+                ),
+                Style::default().fg(theme().text),
+            ),
+        ]));
+    }
+    lines
+}
+
+/// Renders two lines per simulated transaction, in group order.
+fn render_txn_list(app: &App, simulation: &Simulation, frame: &mut Frame, area: Rect) {
+    let total = simulation.transactions.len();
+    let visible_items = ((area.height / SIMULATED_ROW_HEIGHT) as usize).max(1);
+    let scroll = app.nav.simulation_txn_scroll as usize;
+    let failed_index = simulation.failed_index();
+
+    let items: Vec<ListItem> = simulation
+        .transactions
+        .iter()
+        .enumerate()
+        .skip(scroll)
+        .take(visible_items)
+        .map(|(i, simulated)| {
+            simulated_list_item(
+                i,
+                simulated,
+                failed_index == Some(i),
+                app.nav.simulation_txn_index == Some(i),
+            )
+        })
+        .collect();
+    frame.render_widget(List::new(items).block(Block::default()), area);
+
+    if total > visible_items {
+        let scrollbar = Scrollbar::default()
+            .orientation(ScrollbarOrientation::VerticalRight)
+            .symbols(scrollbar::VERTICAL)
+            .track_symbol(None)
+            .begin_symbol(None)
+            .end_symbol(None)
+            .style(Style::default().fg(theme().muted))
+            .track_style(Style::default().fg(theme().border));
+
+        let mut scrollbar_state = ScrollbarState::default()
+            .content_length(total)
+            .viewport_content_length(visible_items)
+            .position(scroll);
+
+        frame.render_stateful_widget(scrollbar, area, &mut scrollbar_state);
+    }
+}
+
+/// Builds a transaction row: position, type, ID and sender, then the budget
+/// it used and what it left behind.
+fn simulated_list_item(
+    index: usize,
+    simulated: &SimulatedTxn,
+    failed: bool,
+    is_selected: bool,
+) -> ListItem<'static> {
+    let txn = &simulated.transaction;
+    let indicator = if is_selected { "▶" } else { " " };
+
+    let mut header = vec![
+        Span::raw(format!("{indicator} {:>2}. ", index + 1)),
+        Span::styled(
+            format!("[{}]", txn.txn_type.as_str()),
+            Style::default().fg(theme().txn_color(txn.txn_type)),
+        ),
+        Span::raw("  "),
+        Span::styled(
+            txn.id.chars().take(16).collect::<String>() + "...",
+            Style::default()
+                .fg(theme().secondary)
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::raw("  "),
+        Span::styled(
+            txn.from.chars().take(10).collect::<String>() + "...",
+            Style::default().fg(theme().warning),
+        ),
+    ];
+    if failed {
+        header.push(Span::styled(
+            "  ✗ Failed here",
+            Style::default()
+                .fg(theme().error)
+                .add_modifier(Modifier::BOLD),
+        ));
+    }
+
+    let mut effects = Vec::new();
+    if let Some(budget) = simulated.app_budget_consumed {
+        effects.push(format!("App budget: {budget}"));
+    }
+    if let Some(budget) = simulated.logic_sig_budget_consumed {
+        effects.push(format!("LogicSig budget: {budget}"));
+    }
+    if let TransactionDetails::AppCall(details) = &txn.details {
+        if !details.logs.is_empty() {
+            effects.push(format!("Logs: {}", details.logs.len()));
+        }
+        let changes = details.global_state_delta.len() + details.local_state_delta.len();
+        if changes > 0 {
+            effects.push(format!("State changes: {changes}"));
+        }
+    }
+    if !txn.inner_transactions.is_empty() {
+        effects.push(format!("Inner: {}", txn.inner_transactions.len()));
+    }
+
+    ListItem::new(vec![
+        Line::from(header),
+        Line::from(Span::styled(
+            format!("       {}", effects.join("  ")),
+            Style::default().fg(theme().muted),
+        )),
+    ])
+    .style(if is_selected {
+        theme().selected_style()
+    } else {
+        Style::default()
+    })
+}
+
+/// Renders the Graph tab: the flow graph of the simulated group.
+fn render_graph_tab(app: &App, simulation: &Simulation, frame: &mut Frame, area: Rect) {
+    let graph = TxnGraph::from_transactions(&simulation.transactions());
+    if graph.columns.is_empty() {
+        frame.render_widget(
+            Paragraph::new("Nothing to draw")
+                .style(Style::default().fg(theme().muted))
+                .alignment(Alignment::Center),
+            area,
+        );
+        return;
+    }
+
+    let graph_widget = TxnGraphWidget::new(&graph);
+    let padded_area = Rect::new(
+        area.x + 1,
+        area.y,
+        area.width.saturating_sub(2),
+        area.height,
+    );
+
+    // Clamp scroll offsets to the graph size
+    let max_scroll_y = graph_widget
+        .required_height()
+        .saturating_sub(padded_area.height as usize);
+    let max_scroll_x = graph_widget
+        .required_width()
+        .saturating_sub(padded_area.width as usize);
+    let scroll_y = (app.nav.graph_scroll_y as usize).min(max_scroll_y) as u16;
+    let scroll_x = (app.nav.graph_scroll_x as usize).min(max_scroll_x) as u16;
+
+    frame.render_widget(
+        Paragraph::new(graph_widget.to_lines()).scroll((scroll_y, scroll_x)),
+        padded_area,
+    );
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::{Terminal, backend::TestBackend};
+    use rstest::*;
+
+    use crate::domain::AppCallDetails;
+    use crate::test_utils::{TransactionMother, mock_app, test_terminal};
+
+    fn failed_simulation() -> Simulation {
+        let payment = TransactionMother::payment_with_addresses(
+            "PAYMENTTXNID",
+            "SENDERADDRESSAAAA",
+            "RECEIVERADDRESSBB",
+        );
+        let mut app_call = TransactionMother::app_call("APPCALLTXNID", 1_234);
+        app_call.details = TransactionDetails::AppCall(AppCallDetails {
+            app_id: 1_234,
+            logs: vec!["aGVsbG8=".to_string()],
+            ..AppCallDetails::default()
+        });
+        Simulation {
+            round: 51_000_000,
+            transactions: vec![
+                SimulatedTxn {
+                    transaction: payment,
+                    app_budget_consumed: None,
+                    logic_sig_budget_consumed: None,
+                },
+                SimulatedTxn {
+                    transaction: app_call,
+                    app_budget_consumed: Some(621),
+                    logic_sig_budget_consumed: None,
+                },
+            ],
+            failure_message: Some(
+                "transaction APPCALLTXNID: logic eval error: assert failed pc=42".to_string(),
+            ),
+            failed_at: vec![1],
+            app_budget_added: 700,
+            app_budget_consumed: 621,
+        }
+    }
+
+    #[rstest]
+    #[case::result_tab(SimulationTab::Result, "simulation_result_tab")]
+    #[case::graph_tab(SimulationTab::Graph, "simulation_graph_tab")]
+    #[tokio::test]
+    async fn test_simulation_snapshots(
+        mut test_terminal: Terminal<TestBackend>,
+        #[future] mock_app: App,
+        #[case] tab: SimulationTab,
+        #[case] snapshot: &str,
+    ) {
+        let mut app = mock_app.await;
+        app.data.simulation = Some(failed_simulation());
+        app.nav.show_simulation = true;
+        app.nav.simulation_tab = tab;
+        app.nav.simulation_txn_index = Some(1);
+
+        test_terminal
+            .draw(|frame| {
+                render_simulation(&app, frame, frame.area());
+            })
+            .unwrap();
+
+        insta::assert_snapshot!(snapshot, test_terminal.backend());
+    }
+}
@@ -7,14 +7,14 @@
 use ratatui::{
     Frame,
     layout::{Alignment, Constraint, Direction, Layout, Rect},
-    style::{Color, Modifier, Style},
+    style::{Modifier, Style},
     text::{Line, Span},
     widgets::{Clear, Paragraph},
 };
 
 use crate::domain::{SearchResultItem, Transaction};
 use crate::state::{App, DetailViewMode};
-use crate::theme::{MUTED_COLOR, PRIMARY_COLOR};
+use crate::theme::theme;
 use crate::ui::helpers::create_popup_block;
 use crate::ui::layout::centered_popup_area;
 use crate::widgets::{TxnGraph, TxnGraphWidget};
@@ -68,7 +68,7 @@ pub fn render_transaction_details(app: &App, frame: &mut Frame, area: Rect) {
 
         let inner_area = popup_block.inner(popup_area);
         let error_msg = Paragraph::new("Transaction data not available.\n\nPress Esc to close.")
-            .style(Style::default().fg(Color::Red))
+            .style(Style::default().fg(theme().error))
             .alignment(Alignment::Center);
         frame.render_widget(error_msg, inner_area);
         return;
@@ -91,8 +91,12 @@ pub fn render_transaction_details(app: &App, frame: &mut Frame, area: Rect) {
     } else if is_visual && !graph.columns.is_empty() {
         // Auto-scale popup based on graph content
         // Use required_width which includes type indicator column and tree prefix
-        let graph_width = graph_widget.required_width();
-        let graph_height = graph_widget.required_height();
+        // Logs are listed below the graph, so they count towards its size
+        let log_lines = transaction_visual::build_log_lines(&txn);
+        let graph_width = graph_widget
+            .required_width()
+            .max(log_lines.iter().map(Line::width).max().unwrap_or(0));
+        let graph_height = graph_widget.required_height() + log_lines.len();
 
         // Chrome padding breakdown:
         // Horizontal: 2 (border) + 2 (inner padding) + 4 (centering margin) = 8
@@ -143,19 +147,19 @@ pub fn render_transaction_details(app: &App, frame: &mut Frame, area: Rect) {
     // Render tab bar
     let visual_style = if is_visual {
         Style::default()
-            .bg(PRIMARY_COLOR)
-            .fg(Color::White)
+            .bg(theme().primary)
+            .fg(theme().text)
             .add_modifier(Modifier::BOLD)
     } else {
-        Style::default().fg(MUTED_COLOR)
+        Style::default().fg(theme().muted)
     };
     let table_style = if !is_visual {
         Style::default()
-            .bg(PRIMARY_COLOR)
-            .fg(Color::White)
+            .bg(theme().primary)
+            .fg(theme().text)
             .add_modifier(Modifier::BOLD)
     } else {
-        Style::default().fg(MUTED_COLOR)
+        Style::default().fg(theme().muted)
     };
 
     let tab_bar = Line::from(vec![
@@ -169,7 +173,7 @@ pub fn render_transaction_details(app: &App, frame: &mut Frame, area: Rect) {
 
     // Render separator
     let separator = "─".repeat(inner_area.width as usize);
-    let separator_widget = Paragraph::new(separator).style(Style::default().fg(Color::DarkGray));
+    let separator_widget = Paragraph::new(separator).style(Style::default().fg(theme().border));
     frame.render_widget(separator_widget, content_layout[1]);
 
     // Render content based on view mode
@@ -194,32 +198,40 @@ pub fn render_transaction_details(app: &App, frame: &mut Frame, area: Rect) {
 
     // Build action bar - Export SVG only available in Visual mode
     let mut action_spans = vec![
-        Span::styled("  [C]", Style::default().fg(PRIMARY_COLOR)),
-        Span::styled(" Copy", Style::default().fg(Color::White)),
+        Span::styled("  [C]", Style::default().fg(theme().primary)),
+        Span::styled(" Copy", Style::default().fg(theme().text)),
         Span::raw("  "),
-        Span::styled("[Y]", Style::default().fg(PRIMARY_COLOR)),
-        Span::styled(" JSON", Style::default().fg(Color::White)),
+        Span::styled("[Y]", Style::default().fg(theme().primary)),
+        Span::styled(" JSON", Style::default().fg(theme().text)),
         Span::raw("  "),
-        Span::styled("[O]", Style::default().fg(PRIMARY_COLOR)),
-        Span::styled(" Open", Style::default().fg(Color::White)),
+        Span::styled("[O]", Style::default().fg(theme().primary)),
+        Span::styled(" Open", Style::default().fg(theme().text)),
         Span::raw("  "),
     ];
 
     // Only show Export SVG in Visual mode (graph view)
     if is_visual {
         action_spans.extend([
-            Span::styled("[S]", Style::default().fg(PRIMARY_COLOR)),
-            Span::styled(" SVG", Style::default().fg(Color::White)),
+            Span::styled("[S]", Style::default().fg(theme().primary)),
+            Span::styled(" SVG", Style::default().fg(theme().text)),
+            Span::raw("  "),
+        ]);
+    }
+
+    if txn.group.is_some() {
+        action_spans.extend([
+            Span::styled("[A]", Style::default().fg(theme().primary)),
+            Span::styled(" Group", Style::default().fg(theme().text)),
             Span::raw("  "),
         ]);
     }
 
     action_spans.extend([
-        Span::styled("[Tab]", Style::default().fg(PRIMARY_COLOR)),
-        Span::styled(" View", Style::default().fg(Color::White)),
+        Span::styled("[Tab]", Style::default().fg(theme().primary)),
+        Span::styled(" View", Style::default().fg(theme().text)),
         Span::raw("  "),
-        Span::styled("[Esc]", Style::default().fg(PRIMARY_COLOR)),
-        Span::styled(" Close", Style::default().fg(Color::White)),
+        Span::styled("[Esc]", Style::default().fg(theme().primary)),
+        Span::styled(" Close", Style::default().fg(theme().text)),
     ]);
 
     let action_bar = Line::from(action_spans);
@@ -240,7 +252,7 @@ pub fn render_transaction_details(app: &App, frame: &mut Frame, area: Rect) {
     };
 
     let help_msg = Paragraph::new(help_text)
-        .style(Style::default().fg(Color::DarkGray))
+        .style(Style::default().fg(theme().border))
         .alignment(Alignment::Center);
 
     frame.render_widget(help_msg, help_area);
@@ -266,6 +278,7 @@ mod tests {
 
     use crate::domain::Network;
     use crate::state::StartupOptions;
+    use crate::test_utils::{JsonMother, mock_app};
 
     /// Fixture: mainnet client for fetching real transactions
     #[fixture]
@@ -340,4 +353,54 @@ mod tests {
             insta::assert_snapshot!(case.snapshot_name, terminal.backend());
         }
     }
+
+    /// Offline snapshots of app call logs, state changes and signatures.
+    #[rstest]
+    #[case::logs_table(
+        JsonMother::app_call_with_logs(),
+        DetailViewMode::Table,
+        "transaction_popup_logs_table_mode"
+    )]
+    #[case::logs_visual(
+        JsonMother::app_call_with_logs(),
+        DetailViewMode::Visual,
+        "transaction_popup_logs_visual_mode"
+    )]
+    #[case::state_changes_table(
+        JsonMother::app_call_with_state_delta(),
+        DetailViewMode::Table,
+        "transaction_popup_state_changes_table_mode"
+    )]
+    #[case::multisig_table(
+        JsonMother::multisig_payment(),
+        DetailViewMode::Table,
+        "transaction_popup_multisig_table_mode"
+    )]
+    #[case::logicsig_table(
+        JsonMother::logicsig_payment(),
+        DetailViewMode::Table,
+        "transaction_popup_logicsig_table_mode"
+    )]
+    #[tokio::test]
+    async fn test_transaction_popup_offline_snapshots(
+        #[future] mock_app: App,
+        #[case] txn_json: serde_json::Value,
+        #[case] view_mode: DetailViewMode,
+        #[case] snapshot_name: &str,
+    ) {
+        let mut app = mock_app.await;
+        let txn = Transaction::from_json(&txn_json).expect("fixture should parse");
+        app.data.viewed_transaction = Some(txn);
+        app.ui.detail_view_mode = view_mode;
+
+        let mut terminal =
+            Terminal::new(TestBackend::new(100, 35)).expect("terminal creation should succeed");
+        terminal
+            .draw(|frame| {
+                render_transaction_details(&app, frame, frame.area());
+            })
+            .expect("draw should succeed");
+
+        insta::assert_snapshot!(snapshot_name, terminal.backend());
+    }
 }
@@ -6,15 +6,20 @@
 use ratatui::{
     Frame,
     layout::{Constraint, Rect},
-    style::{Color, Modifier, Style},
+    style::{Modifier, Style},
     widgets::{Block, Cell, Row, Table},
 };
 
 use base64::{Engine as _, engine::general_purpose::STANDARD as BASE64};
 
-use crate::domain::{Transaction, TransactionDetails};
+use crate::domain::logs::EventRegistry;
+use crate::domain::{
+    AppCallDetails, DecodedCall, DecodedLog, Transaction, TransactionDetails, TransactionSignature,
+    app_spec, disassemble_base64,
+};
 use crate::state::App;
-use crate::theme::{MUTED_COLOR, PRIMARY_COLOR, WARNING_COLOR};
+use crate::theme::theme;
+use crate::widgets::helpers::truncate_address;
 
 // ============================================================================
 // Types
@@ -86,18 +91,18 @@ pub fn render_table_mode(txn: &Transaction, app: &App, frame: &mut Frame, area:
                 DetailRow::Info { label, value } => {
                     let label_style = if is_selected {
                         Style::default()
-                            .fg(Color::Black)
-                            .bg(PRIMARY_COLOR)
+                            .fg(theme().on_primary)
+                            .bg(theme().primary)
                             .add_modifier(Modifier::BOLD)
                     } else {
                         Style::default()
-                            .fg(WARNING_COLOR)
+                            .fg(theme().warning)
                             .add_modifier(Modifier::BOLD)
                     };
                     let value_style = if is_selected {
-                        Style::default().fg(Color::Black).bg(PRIMARY_COLOR)
+                        Style::default().fg(theme().on_primary).bg(theme().primary)
                     } else {
-                        Style::default().fg(PRIMARY_COLOR)
+                        Style::default().fg(theme().primary)
                     };
 
                     Row::new(vec![
@@ -110,11 +115,11 @@ pub fn render_table_mode(txn: &Transaction, app: &App, frame: &mut Frame, area:
                     let header_text = format!("─── {} ({}) ───", title, count);
                     let style = if is_selected {
                         Style::default()
-                            .fg(Color::Black)
-                            .bg(PRIMARY_COLOR)
+                            .fg(theme().on_primary)
+                            .bg(theme().primary)
                             .add_modifier(Modifier::BOLD)
                     } else {
-                        Style::default().fg(MUTED_COLOR)
+                        Style::default().fg(theme().muted)
                     };
 
                     Row::new(vec![
@@ -154,6 +159,11 @@ pub fn build_flat_row_list(txn: &Transaction) -> Vec<DetailRow> {
 
     // Add app call specific sections if present
     if let TransactionDetails::AppCall(app_details) = &txn.details {
+        // ABI method decoded from a loaded app spec
+        if let Some(call) = app_spec::specs().decode_call(app_details, &txn.from) {
+            push_abi_call_rows(&mut rows, &call);
+        }
+
         // App Args section
         if !app_details.app_args.is_empty() {
             rows.push(DetailRow::SectionHeader {
@@ -213,11 +223,190 @@ pub fn build_flat_row_list(txn: &Transaction) -> Vec<DetailRow> {
                 });
             }
         }
+
+        // State changes made by the call
+        if app_details.has_state_changes() {
+            push_state_change_rows(&mut rows, app_details);
+        }
+
+        // Logs from this call and any inner transactions
+        push_log_rows(&mut rows, txn);
+
+        // Program sections (present on app creation and update)
+        push_program_rows(
+            &mut rows,
+            "Approval Program",
+            app_details.approval_program.as_deref(),
+        );
+        push_program_rows(
+            &mut rows,
+            "Clear State Program",
+            app_details.clear_state_program.as_deref(),
+        );
+    }
+
+    // Signature sections (multisig participants, logicsig args and program)
+    if let Some(signature) = &txn.signature {
+        push_signature_rows(&mut rows, signature);
     }
 
     rows
 }
 
+/// Appends multisig participant and logicsig sections for the transaction signature.
+fn push_signature_rows(rows: &mut Vec<DetailRow>, signature: &TransactionSignature) {
+    if let Some(msig) = signature.multisig() {
+        rows.push(DetailRow::SectionHeader {
+            title: format!("Multisig Signers, {} signed", msig.signed_count()),
+            count: msig.subsignatures.len(),
+        });
+        for (idx, sub) in msig.subsignatures.iter().enumerate() {
+            let status = if sub.signed { "signed" } else { "-" };
+            rows.push(DetailRow::Info {
+                label: format!("[{}] {}:", idx, status),
+                value: sub.address.clone(),
+            });
+        }
+    }
+
+    if let TransactionSignature::LogicSig(lsig) = signature {
+        if !lsig.args.is_empty() {
+            rows.push(DetailRow::SectionHeader {
+                title: "LogicSig Args".to_string(),
+                count: lsig.args.len(),
+            });
+            for (idx, arg) in lsig.args.iter().enumerate() {
+                rows.push(DetailRow::Info {
+                    label: format!("[{}]:", idx),
+                    value: arg.clone(),
+                });
+            }
+        }
+        push_program_rows(rows, "LogicSig Program", Some(&lsig.logic));
+    }
+}
+
+/// Appends the decoded ABI method call: one row per named argument plus the return value.
+fn push_abi_call_rows(rows: &mut Vec<DetailRow>, call: &DecodedCall) {
+    rows.push(DetailRow::SectionHeader {
+        title: format!("ABI Method {}", call.method),
+        count: call.args.len(),
+    });
+    if !call.contract.is_empty() {
+        rows.push(DetailRow::Info {
+            label: "Contract:".to_string(),
+            value: call.contract.clone(),
+        });
+    }
+    rows.push(DetailRow::Info {
+        label: "Signature:".to_string(),
+        value: call.signature.clone(),
+    });
+    for arg in &call.args {
+        rows.push(DetailRow::Info {
+            label: format!("{}:", arg.name),
+            value: format!("{} ({})", arg.value, arg.ty),
+        });
+    }
+    if let Some(returns_type) = &call.returns_type {
+        rows.push(DetailRow::Info {
+            label: "Returns:".to_string(),
+            value: match &call.returns {
+                Some(value) => format!("{} ({})", value, returns_type),
+                None => format!("- ({})", returns_type),
+            },
+        });
+    }
+}
+
+/// Appends the "State Changes" section listing global then per-account local deltas.
+fn push_state_change_rows(rows: &mut Vec<DetailRow>, app_details: &AppCallDetails) {
+    let local_count: usize = app_details
+        .local_state_delta
+        .iter()
+        .map(|local| local.deltas.len())
+        .sum();
+    rows.push(DetailRow::SectionHeader {
+        title: "State Changes".to_string(),
+        count: app_details.global_state_delta.len() + local_count,
+    });
+
+    for delta in &app_details.global_state_delta {
+        rows.push(DetailRow::Info {
+            label: "Global:".to_string(),
+            value: format!("{}: {}", delta.key, delta.action.describe()),
+        });
+    }
+    for local in &app_details.local_state_delta {
+        let label = format!("{}:", truncate_address(&local.address, 16));
+        for delta in &local.deltas {
+            rows.push(DetailRow::Info {
+                label: label.clone(),
+                value: format!("{}: {}", delta.key, delta.action.describe()),
+            });
+        }
+    }
+}
+
+/// Appends a section of decoded logs for each transaction in the tree that logged.
+fn push_log_rows(rows: &mut Vec<DetailRow>, txn: &Transaction) {
+    for (path, details) in txn.logs_by_path() {
+        let events = log_events(details);
+        rows.push(DetailRow::SectionHeader {
+            title: log_section_title(&path),
+            count: details.logs.len(),
+        });
+        for (idx, log) in details.logs.iter().enumerate() {
+            let decoded = DecodedLog::from_base64_with(log, &events);
+            rows.push(DetailRow::Info {
+                label: format!("[{}] {}:", idx, decoded.kind()),
+                value: decoded.to_string(),
+            });
+        }
+    }
+}
+
+/// Event signatures to decode a call's logs with: the app spec's, if one is loaded.
+#[must_use]
+pub(crate) fn log_events(details: &AppCallDetails) -> EventRegistry {
+    app_spec::specs()
+        .spec_for(details)
+        .map_or_else(EventRegistry::known, |spec| spec.events.clone())
+}
+
+/// Section title for the logs of the transaction at `path` in the inner tree.
+#[must_use]
+pub(crate) fn log_section_title(path: &str) -> String {
+    if path.is_empty() {
+        "Logs".to_string()
+    } else {
+        format!("Inner {} Logs", path)
+    }
+}
+
+/// Appends a disassembled program as a section of numbered listing rows.
+fn push_program_rows(rows: &mut Vec<DetailRow>, title: &str, program: Option<&str>) {
+    let Some(program) = program.filter(|p| !p.is_empty()) else {
+        return;
+    };
+
+    let lines = match disassemble_base64(program) {
+        Ok(listing) => listing.lines,
+        Err(e) => vec![format!("// error: {}", e)],
+    };
+
+    rows.push(DetailRow::SectionHeader {
+        title: title.to_string(),
+        count: lines.len(),
+    });
+    for (idx, line) in lines.into_iter().enumerate() {
+        rows.push(DetailRow::Info {
+            label: format!("{:>5}", idx + 1),
+            value: line,
+        });
+    }
+}
+
 /// Builds the transaction details as key-value pairs for the Info tab.
 ///
 /// Public for use by both rendering and copy functionality.
@@ -231,6 +420,11 @@ pub fn build_info_details(txn: &Transaction) -> Vec<(String, String)> {
         ("From:".to_string(), txn.from.clone()),
     ];
 
+    // Add signature kind if known (inner transactions have none)
+    if let Some(ref signature) = txn.signature {
+        details.push(("Signature:".to_string(), signature.describe()));
+    }
+
     // Add Group ID if present (shown early as it's important context)
     if let Some(ref group) = txn.group {
         // Truncate Base64 group ID for display
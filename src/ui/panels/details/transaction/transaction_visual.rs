@@ -5,14 +5,15 @@
 use ratatui::{
     Frame,
     layout::{Alignment, Rect},
-    style::{Color, Style},
+    style::{Modifier, Style},
     text::{Line, Span},
     widgets::Paragraph,
 };
 
-use crate::domain::Transaction;
+use super::transaction_table::{log_events, log_section_title};
+use crate::domain::{DecodedLog, Transaction};
 use crate::state::App;
-use crate::theme::BG_COLOR;
+use crate::theme::theme;
 use crate::widgets::{TxnGraph, TxnGraphWidget, TxnVisualCard};
 
 // ============================================================================
@@ -30,7 +31,8 @@ pub fn render_visual_mode(
     frame: &mut Frame,
     area: Rect,
 ) -> bool {
-    let graph_lines = graph_widget.to_lines();
+    let mut graph_lines = graph_widget.to_lines();
+    let log_lines = build_log_lines(txn);
 
     // If graph has meaningful content, show it
     if !graph.columns.is_empty() {
@@ -43,8 +45,9 @@ pub fn render_visual_mode(
         );
 
         // Calculate graph dimensions (use required_width for accurate measurement)
-        let graph_height = graph_widget.required_height();
-        let graph_width = graph_widget.required_width();
+        let graph_height = graph_widget.required_height() + log_lines.len();
+        let graph_width = graph_widget.required_width().max(lines_width(&log_lines));
+        graph_lines.extend(log_lines);
 
         // Determine if we need scrolling
         let needs_v_scroll = graph_height > padded_area.height as usize;
@@ -149,7 +152,8 @@ pub fn render_visual_mode(
     } else {
         // Fallback to TxnVisualCard for edge cases
         let visual_card = TxnVisualCard::new(txn);
-        let lines = visual_card.to_lines();
+        let mut lines = visual_card.to_lines();
+        lines.extend(log_lines);
 
         let visual_content = Paragraph::new(lines).alignment(Alignment::Left);
 
@@ -165,6 +169,52 @@ pub fn render_visual_mode(
     }
 }
 
+// ============================================================================
+// Logs
+// ============================================================================
+
+/// Builds the decoded app call logs shown below the graph.
+///
+/// Returns no lines when neither the transaction nor its inner transactions logged.
+#[must_use]
+pub fn build_log_lines(txn: &Transaction) -> Vec<Line<'static>> {
+    let mut lines = Vec::new();
+
+    for (path, details) in txn.logs_by_path() {
+        let events = log_events(details);
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled(
+            format!("{} ({})", log_section_title(&path), details.logs.len()),
+            Style::default()
+                .fg(theme().secondary)
+                .add_modifier(Modifier::BOLD),
+        )));
+        for (idx, log) in details.logs.iter().enumerate() {
+            let decoded = DecodedLog::from_base64_with(log, &events);
+            let kind_color = match decoded {
+                DecodedLog::Return(_) => theme().success,
+                DecodedLog::Event { .. } => theme().accent,
+                DecodedLog::Raw(_) => theme().muted,
+            };
+            lines.push(Line::from(vec![
+                Span::styled(format!("  [{}] ", idx), Style::default().fg(theme().muted)),
+                Span::styled(
+                    format!("{:<7}", decoded.kind()),
+                    Style::default().fg(kind_color),
+                ),
+                Span::styled(decoded.to_string(), Style::default().fg(theme().primary)),
+            ]));
+        }
+    }
+
+    lines
+}
+
+/// Display width of the widest line.
+fn lines_width(lines: &[Line]) -> usize {
+    lines.iter().map(Line::width).max().unwrap_or(0)
+}
+
 // ============================================================================
 // Scroll Indicator
 // ============================================================================
@@ -226,7 +276,7 @@ fn render_scroll_indicator(
         1,
     );
 
-    let hint_widget =
-        Paragraph::new(scroll_hint).style(Style::default().fg(Color::DarkGray).bg(BG_COLOR));
+    let hint_widget = Paragraph::new(scroll_hint)
+        .style(Style::default().fg(theme().border).bg(theme().background));
     frame.render_widget(hint_widget, hint_area);
 }
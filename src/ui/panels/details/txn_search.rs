@@ -0,0 +1,195 @@
+//! Advanced transaction search results rendering.
+//!
+//! Results are listed with the same rows as the main transactions panel and
+//! are paged in from the indexer as the selection nears the end.
+
+use ratatui::{
+    Frame,
+    layout::{Alignment, Constraint, Layout, Rect},
+    style::{Modifier, Style},
+    symbols::scrollbar,
+    text::{Line, Span},
+    widgets::{
+        Block, Clear, List, ListItem, Paragraph, Scrollbar, ScrollbarOrientation, ScrollbarState,
+    },
+};
+
+use crate::constants::TXN_HEIGHT;
+use crate::state::App;
+use crate::theme::theme;
+use crate::ui::helpers::create_popup_block;
+use crate::ui::layout::{centered_popup_area, fullscreen_popup_area};
+use crate::ui::panels::transaction_list_item;
+
+/// Renders the advanced search results popup.
+///
+/// # Arguments
+///
+/// * `app` - Application state containing the search results and navigation state
+/// * `frame` - Ratatui frame for rendering
+/// * `area` - Available screen area for rendering
+pub fn render_txn_search(app: &App, frame: &mut Frame, area: Rect) {
+    let popup_area = if app.ui.detail_fullscreen {
+        fullscreen_popup_area(area)
+    } else {
+        centered_popup_area(area, 90, 36)
+    };
+    let popup_block = create_popup_block("Search Results");
+    frame.render_widget(Clear, popup_area);
+    frame.render_widget(popup_block.clone(), popup_area);
+
+    let inner_area = popup_block.inner(popup_area);
+    let [
+        header_area,
+        separator_area,
+        list_area,
+        status_area,
+        help_area,
+    ] = Layout::vertical([
+        Constraint::Length(1), // Query summary
+        Constraint::Length(1), // Separator
+        Constraint::Min(4),    // Result list
+        Constraint::Length(1), // Paging status
+        Constraint::Length(1), // Help text
+    ])
+    .areas(inner_area);
+
+    frame.render_widget(
+        Paragraph::new("─".repeat(inner_area.width as usize))
+            .style(Style::default().fg(theme().border)),
+        separator_area,
+    );
+    frame.render_widget(
+        Paragraph::new("[↑↓] Navigate  [Enter] View  [S] Edit search  [E] Export  [Esc] Close")
+            .style(Style::default().fg(theme().muted))
+            .alignment(Alignment::Center),
+        help_area,
+    );
+
+    let Some(search) = &app.data.txn_search else {
+        return;
+    };
+
+    let header = Line::from(vec![
+        Span::styled(
+            format!(" Transactions ({} loaded)", search.transactions.len()),
+            Style::default()
+                .fg(theme().secondary)
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::styled("  Query: ", Style::default().fg(theme().muted)),
+        Span::styled(
+            search.query.to_string(),
+            Style::default().fg(theme().accent),
+        ),
+    ]);
+    frame.render_widget(Paragraph::new(header), header_area);
+
+    if search.transactions.is_empty() {
+        let message = if search.loading {
+            "Searching..."
+        } else {
+            "No matching transactions"
+        };
+        frame.render_widget(
+            Paragraph::new(message)
+                .style(Style::default().fg(theme().muted))
+                .alignment(Alignment::Center),
+            list_area,
+        );
+        return;
+    }
+
+    let scroll = app.nav.txn_search_scroll as usize;
+    let visible_items = ((list_area.height / TXN_HEIGHT) as usize).max(1);
+
+    let items: Vec<ListItem> = search
+        .transactions
+        .iter()
+        .enumerate()
+        .skip(scroll)
+        .take(visible_items)
+        .map(|(i, txn)| transaction_list_item(txn, app.nav.txn_search_index == Some(i)))
+        .collect();
+    frame.render_widget(List::new(items).block(Block::default()), list_area);
+
+    if search.transactions.len() > visible_items {
+        let scrollbar = Scrollbar::default()
+            .orientation(ScrollbarOrientation::VerticalRight)
+            .symbols(scrollbar::VERTICAL)
+            .track_symbol(None)
+            .begin_symbol(None)
+            .end_symbol(None)
+            .style(Style::default().fg(theme().muted))
+            .track_style(Style::default().fg(theme().border));
+
+        let mut scrollbar_state = ScrollbarState::default()
+            .content_length(search.transactions.len())
+            .viewport_content_length(visible_items)
+            .position(scroll);
+
+        frame.render_stateful_widget(scrollbar, list_area, &mut scrollbar_state);
+    }
+
+    let status = if search.loading {
+        Some("  Loading more transactions...")
+    } else if search.has_more() {
+        Some("  More available - scroll down to load more")
+    } else {
+        None
+    };
+    if let Some(status) = status {
+        frame.render_widget(
+            Paragraph::new(status).style(Style::default().fg(theme().muted)),
+            status_area,
+        );
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::{Terminal, backend::TestBackend};
+    use rstest::*;
+
+    use crate::domain::{Page, TxnQuery, TxnType};
+    use crate::state::TxnSearch;
+    use crate::test_utils::{TransactionMother, mock_app, test_terminal};
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_txn_search_results_snapshot(
+        mut test_terminal: Terminal<TestBackend>,
+        #[future] mock_app: App,
+    ) {
+        let mut app = mock_app.await;
+        let mut search = TxnSearch::new(TxnQuery {
+            txn_type: Some(TxnType::AssetTransfer),
+            asset_id: Some(31566704),
+            min_round: Some(50_000_000),
+            ..TxnQuery::default()
+        });
+        search.append_page(Page::new(
+            vec![
+                TransactionMother::asset_transfer("FIRSTTXNID", 31566704, 2_500),
+                TransactionMother::asset_transfer("SECONDTXNID", 31566704, 100),
+            ],
+            Some("token".to_string()),
+        ));
+        app.data.txn_search = Some(search);
+        app.nav.show_txn_search = true;
+        app.nav.txn_search_index = Some(1);
+
+        test_terminal
+            .draw(|frame| {
+                render_txn_search(&app, frame, frame.area());
+            })
+            .unwrap();
+
+        insta::assert_snapshot!("txn_search_results", test_terminal.backend());
+    }
+}
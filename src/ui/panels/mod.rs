@@ -11,6 +11,9 @@
 //! - **Blocks Panel**: Displays the latest blocks from the Algorand blockchain
 //! - **Transactions Panel**: Shows the latest transactions
 //!
+//! When the watchlist is not empty, a **Watchlist Panel** below the blocks
+//! shows the watched accounts, assets and apps.
+//!
 //! # Detail Views
 //!
 //! The `details` submodule contains rendering logic for detail popups:
@@ -31,19 +34,18 @@ pub mod details;
 use ratatui::{
     Frame,
     layout::{Alignment, Rect},
-    style::{Color, Modifier, Style},
+    style::{Modifier, Style},
     symbols::scrollbar,
     text::{Line, Span},
     widgets::{Block, List, ListItem, ListState, Paragraph, Scrollbar, ScrollbarOrientation},
 };
 
 use super::helpers::create_border_block;
-use crate::constants::{BLOCK_HEIGHT, TXN_HEIGHT};
+use crate::constants::{BLOCK_HEIGHT, TXN_HEIGHT, WATCH_HEIGHT};
+use crate::domain::{Transaction, WatchStatus, format_asset_amount};
 use crate::state::{App, Focus};
-use crate::theme::{
-    HIGHLIGHT_STYLE, MUTED_COLOR, PRIMARY_COLOR, SECONDARY_COLOR, SELECTED_STYLE, SUCCESS_COLOR,
-    WARNING_COLOR,
-};
+use crate::theme::theme;
+use crate::widgets::helpers::{format_algo_amount, truncate_address};
 
 // ============================================================================
 // Public Panel Rendering Functions
@@ -79,7 +81,7 @@ pub fn render_blocks(app: &App, frame: &mut Frame, area: Rect) {
 
     if blocks.is_empty() {
         let no_data_message = Paragraph::new("No blocks available")
-            .style(Style::default().fg(MUTED_COLOR))
+            .style(Style::default().fg(theme().muted))
             .alignment(Alignment::Center);
         frame.render_widget(no_data_message, inner_area);
         return;
@@ -98,23 +100,23 @@ pub fn render_blocks(app: &App, frame: &mut Frame, area: Rect) {
                     Span::styled(
                         block.id.to_string(),
                         Style::default()
-                            .fg(PRIMARY_COLOR)
+                            .fg(theme().primary)
                             .add_modifier(Modifier::BOLD),
                     ),
                     Span::raw("               "),
                     Span::styled(
                         format!("{} txns", block.txn_count),
-                        Style::default().fg(SUCCESS_COLOR),
+                        Style::default().fg(theme().success),
                     ),
                 ]),
                 Line::from(vec![
                     Span::raw("  "), // Indent to align with content above
-                    Span::styled(&block.timestamp, Style::default().fg(MUTED_COLOR)),
+                    Span::styled(&block.timestamp, Style::default().fg(theme().muted)),
                 ]),
                 Line::from(""),
             ])
             .style(if is_selected {
-                SELECTED_STYLE
+                theme().selected_style()
             } else {
                 Style::default()
             })
@@ -135,7 +137,7 @@ pub fn render_blocks(app: &App, frame: &mut Frame, area: Rect) {
 
     let block_list = List::new(visible_items)
         .block(Block::default())
-        .highlight_style(HIGHLIGHT_STYLE);
+        .highlight_style(theme().highlight_style());
 
     let mut modified_state = list_state.clone();
     if let Some(selected) = list_state.selected() {
@@ -159,6 +161,42 @@ pub fn render_blocks(app: &App, frame: &mut Frame, area: Rect) {
     );
 }
 
+/// Builds the [`TXN_HEIGHT`]-line list row used wherever transactions are listed.
+pub(crate) fn transaction_list_item(txn: &Transaction, is_selected: bool) -> ListItem<'static> {
+    let entity_type_style = Style::default().fg(theme().txn_color(txn.txn_type));
+    let selection_indicator = if is_selected { "▶" } else { "→" };
+
+    ListItem::new(vec![
+        Line::from(vec![
+            Span::raw(format!("{} ", selection_indicator)),
+            Span::styled(
+                txn.id.clone(),
+                Style::default()
+                    .fg(theme().secondary)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::raw("          "),
+            Span::styled(format!("[{}]", txn.txn_type.as_str()), entity_type_style),
+        ]),
+        Line::from(vec![
+            Span::raw("  "),
+            Span::styled("From: ", Style::default().fg(theme().muted)),
+            Span::styled(txn.from.clone(), Style::default().fg(theme().warning)),
+        ]),
+        Line::from(vec![
+            Span::raw("  "),
+            Span::styled("To:   ", Style::default().fg(theme().muted)),
+            Span::styled(txn.to.clone(), Style::default().fg(theme().primary)),
+        ]),
+        Line::from(""),
+    ])
+    .style(if is_selected {
+        theme().selected_style()
+    } else {
+        Style::default()
+    })
+}
+
 /// Renders the transactions panel showing the latest transactions.
 ///
 /// Displays a list of transactions with their IDs, types, sender/receiver addresses,
@@ -179,10 +217,14 @@ pub fn render_blocks(app: &App, frame: &mut Frame, area: Rect) {
 /// - Automatic scrolling to keep selected items visible
 /// - Scrollbar display when content exceeds viewport
 /// - Empty state message when no transactions are available
+/// - Active feed filter shown in the title
 pub fn render_transactions(app: &App, frame: &mut Frame, area: Rect) {
     let is_focused = app.ui.focus == Focus::Transactions;
-    let title = "Latest Transactions";
-    let txn_block = create_border_block(title, is_focused);
+    let title = match &app.data.txn_filter {
+        Some(filter) => format!("Latest Transactions [{filter}]"),
+        None => "Latest Transactions".to_string(),
+    };
+    let txn_block = create_border_block(&title, is_focused);
 
     frame.render_widget(txn_block.clone(), area);
     let inner_area = txn_block.inner(area);
@@ -190,9 +232,13 @@ pub fn render_transactions(app: &App, frame: &mut Frame, area: Rect) {
     let transactions = &app.data.transactions;
 
     if transactions.is_empty() {
-        let message = "No transactions available";
+        let message = if app.data.txn_filter.is_some() {
+            "No matching transactions"
+        } else {
+            "No transactions available"
+        };
         let no_data_message = Paragraph::new(message)
-            .style(Style::default().fg(MUTED_COLOR))
+            .style(Style::default().fg(theme().muted))
             .alignment(Alignment::Center);
         frame.render_widget(no_data_message, inner_area);
         return;
@@ -202,40 +248,7 @@ pub fn render_transactions(app: &App, frame: &mut Frame, area: Rect) {
         .iter()
         .enumerate()
         .map(|(idx, txn)| {
-            let is_selected = app.nav.selected_transaction_index == Some(idx);
-            let txn_type_str = txn.txn_type.as_str();
-            let entity_type_style = Style::default().fg(txn.txn_type.color());
-            let selection_indicator = if is_selected { "▶" } else { "→" };
-
-            ListItem::new(vec![
-                Line::from(vec![
-                    Span::raw(format!("{} ", selection_indicator)),
-                    Span::styled(
-                        txn.id.clone(),
-                        Style::default()
-                            .fg(SECONDARY_COLOR)
-                            .add_modifier(Modifier::BOLD),
-                    ),
-                    Span::raw("          "),
-                    Span::styled(format!("[{}]", txn_type_str), entity_type_style),
-                ]),
-                Line::from(vec![
-                    Span::raw("  "),
-                    Span::styled("From: ", Style::default().fg(MUTED_COLOR)),
-                    Span::styled(txn.from.clone(), Style::default().fg(WARNING_COLOR)),
-                ]),
-                Line::from(vec![
-                    Span::raw("  "),
-                    Span::styled("To:   ", Style::default().fg(MUTED_COLOR)),
-                    Span::styled(txn.to.clone(), Style::default().fg(PRIMARY_COLOR)),
-                ]),
-                Line::from(""),
-            ])
-            .style(if is_selected {
-                SELECTED_STYLE
-            } else {
-                Style::default()
-            })
+            transaction_list_item(txn, app.nav.selected_transaction_index == Some(idx))
         })
         .collect();
 
@@ -258,7 +271,7 @@ pub fn render_transactions(app: &App, frame: &mut Frame, area: Rect) {
 
     let txn_list = List::new(visible_items)
         .block(Block::default())
-        .highlight_style(HIGHLIGHT_STYLE);
+        .highlight_style(theme().highlight_style());
 
     let mut modified_state = list_state.clone();
     if let Some(selected_display_index) = list_state.selected() {
@@ -282,6 +295,129 @@ pub fn render_transactions(app: &App, frame: &mut Frame, area: Rect) {
     );
 }
 
+/// Builds the [`WATCH_HEIGHT`]-line list row for a watchlist entry.
+fn watch_list_item(status: &WatchStatus, is_selected: bool, width: usize) -> ListItem<'static> {
+    let selection_indicator = if is_selected { "▶" } else { "◆" };
+    let badge = format!("[{}] ", status.target.kind());
+    let id_width = width.saturating_sub(2 + badge.len());
+
+    let mut header = vec![
+        Span::raw(format!("{} ", selection_indicator)),
+        Span::styled(badge, Style::default().fg(theme().accent)),
+        Span::styled(
+            truncate_address(&status.target.id(), id_width),
+            Style::default()
+                .fg(theme().primary)
+                .add_modifier(Modifier::BOLD),
+        ),
+    ];
+    if let Some(asset) = &status.asset
+        && !asset.name.is_empty()
+    {
+        header.push(Span::styled(
+            format!(" {}", asset.name),
+            Style::default().fg(theme().secondary),
+        ));
+    }
+
+    let mut detail = vec![Span::raw("  ")];
+    if let Some(error) = &status.error {
+        detail.push(Span::styled(
+            error.clone(),
+            Style::default().fg(theme().error),
+        ));
+    } else if let Some(asset) = &status.asset {
+        detail.push(Span::styled("Supply ", Style::default().fg(theme().muted)));
+        detail.push(Span::styled(
+            format!(
+                "{} {}",
+                format_asset_amount(asset.total, asset.decimals),
+                asset.unit_name
+            ),
+            Style::default().fg(theme().success),
+        ));
+    } else if let Some(balance) = status.balance {
+        detail.push(Span::styled(
+            format_algo_amount(balance),
+            Style::default().fg(theme().success),
+        ));
+        if let Some(round) = status.last_round {
+            detail.push(Span::styled(
+                format!("  last #{round}"),
+                Style::default().fg(theme().muted),
+            ));
+        }
+    } else {
+        detail.push(Span::styled(
+            "Loading...",
+            Style::default().fg(theme().muted),
+        ));
+    }
+
+    ListItem::new(vec![Line::from(header), Line::from(detail)]).style(if is_selected {
+        theme().selected_style()
+    } else {
+        Style::default()
+    })
+}
+
+/// Renders the watchlist panel showing the watched accounts, assets and apps.
+///
+/// Each entry shows its kind, address or ID, and the latest polled balance
+/// (asset supply for assets) with the round of its most recent activity.
+///
+/// # Arguments
+///
+/// * `app` - The application state containing the watchlist and navigation state
+/// * `frame` - The frame to render into
+/// * `area` - The rectangular area to render the panel in
+pub fn render_watchlist(app: &App, frame: &mut Frame, area: Rect) {
+    let is_focused = app.ui.focus == Focus::Watchlist;
+    let title = format!("Watchlist ({})", app.data.watchlist.len());
+    let watch_block = create_border_block(&title, is_focused);
+
+    frame.render_widget(watch_block.clone(), area);
+    let inner_area = watch_block.inner(area);
+
+    let width = inner_area.width as usize;
+    let watch_items: Vec<ListItem> = app
+        .data
+        .watchlist
+        .iter()
+        .enumerate()
+        .map(|(idx, status)| {
+            watch_list_item(status, app.nav.selected_watch_index == Some(idx), width)
+        })
+        .collect();
+
+    let items_per_page = (inner_area.height / WATCH_HEIGHT).max(1) as usize;
+    let start_index = (app.nav.watch_scroll / WATCH_HEIGHT) as usize;
+    let start_index = start_index.min(watch_items.len().saturating_sub(1));
+    let end_index = (start_index + items_per_page).min(watch_items.len());
+
+    let mut list_state = ListState::default();
+    if let Some(selected) = app.nav.selected_watch_index
+        && (start_index..end_index).contains(&selected)
+    {
+        list_state.select(Some(selected - start_index));
+    }
+
+    let watch_list = List::new(watch_items[start_index..end_index].to_vec())
+        .block(Block::default())
+        .highlight_style(theme().highlight_style());
+    frame.render_stateful_widget(watch_list, inner_area, &mut list_state);
+
+    render_scrollbar(
+        frame,
+        inner_area,
+        is_focused,
+        app.data.watchlist.len(),
+        WATCH_HEIGHT as usize,
+        items_per_page,
+        app.nav.watch_scroll as usize,
+    );
+}
+
 // ============================================================================
 // Private Helper Functions
 // ============================================================================
@@ -317,8 +453,8 @@ fn render_scrollbar(
             .track_symbol(None)
             .begin_symbol(None)
             .end_symbol(None)
-            .style(Style::default().fg(MUTED_COLOR))
-            .track_style(Style::default().fg(Color::DarkGray));
+            .style(Style::default().fg(theme().muted))
+            .track_style(Style::default().fg(theme().border));
 
         let content_length = total_items * item_height;
 
@@ -338,7 +474,9 @@ fn render_scrollbar(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::domain::{AlgoBlock, Network, Transaction, TransactionDetails, TxnType};
+    use crate::domain::{
+        AlgoBlock, Network, Transaction, TransactionDetails, TxnFilter, TxnType, WatchTarget,
+    };
     use crate::state::StartupOptions;
     use ratatui::{Terminal, backend::TestBackend};
 
@@ -392,6 +530,7 @@ mod tests {
             group: None,
             details: TransactionDetails::None,
             inner_transactions: Vec::new(),
+            signature: None,
         }];
     }
 
@@ -488,6 +627,18 @@ mod tests {
                         .any(|(x, y)| buffer[(x, y)].symbol().contains('▶'))
                 },
             },
+            TestState {
+                name: "active filter shows in title",
+                setup: |app| {
+                    app.data.txn_filter = Some(TxnFilter::parse("type:pay").unwrap());
+                },
+                check: |buffer| {
+                    let title: String = (0..buffer.area().width)
+                        .map(|x| buffer[(x, 0)].symbol())
+                        .collect();
+                    title.contains("Latest Transactions [type:pay]")
+                },
+            },
         ];
 
         for state in &states {
@@ -537,6 +688,40 @@ mod tests {
         });
     }
 
+    #[test]
+    fn test_render_watchlist_shows_balance_and_activity() {
+        test_with_mock_app_mut(|app| {
+            app.data.set_watchlist(&[
+                WatchTarget::Account("ADDR".to_string()),
+                WatchTarget::App(7),
+            ]);
+            app.data.update_watch_statuses(vec![WatchStatus {
+                balance: Some(5_500_000),
+                last_round: Some(1234),
+                ..WatchStatus::new(WatchTarget::Account("ADDR".to_string()))
+            }]);
+            app.ui.focus = Focus::Watchlist;
+            app.nav.selected_watch_index = Some(0);
+
+            let backend = TestBackend::new(60, 8);
+            let mut terminal = Terminal::new(backend).unwrap();
+            terminal
+                .draw(|frame| render_watchlist(app, frame, frame.area()))
+                .unwrap();
+
+            let buffer = terminal.backend().buffer();
+            let text: String = (0..buffer.area().height)
+                .flat_map(|y| (0..buffer.area().width).map(move |x| (x, y)))
+                .map(|(x, y)| buffer[(x, y)].symbol())
+                .collect();
+            assert!(text.contains("Watchlist (2)"));
+            assert!(text.contains("▶ [ACCT] ADDR"));
+            assert!(text.contains("5.500000 ALGO  last #1234"));
+            assert!(text.contains("[APP] 7"));
+            assert!(text.contains("Loading..."));
+        });
+    }
+
     #[test]
     fn test_constants_are_reasonable() {
         assert!(BLOCK_HEIGHT > 0, "Block height must be positive");
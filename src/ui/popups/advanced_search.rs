@@ -0,0 +1,133 @@
+//! Advanced transaction search form popup.
+//!
+//! One line per indexer search criterion, with live validation of the
+//! form as typed. Blank fields are ignored.
+
+use ratatui::{
+    Frame,
+    layout::{Alignment, Constraint, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Clear, Paragraph},
+};
+
+use crate::state::ui_state::{AdvancedSearchField, AdvancedSearchForm};
+use crate::theme::theme;
+use crate::ui::helpers::create_popup_block;
+use crate::ui::layout::centered_popup_area;
+
+/// Width of the field label column.
+const LABEL_WIDTH: usize = 14;
+
+/// Render the advanced transaction search form.
+pub fn render(frame: &mut Frame, area: Rect, form: &AdvancedSearchForm) {
+    // Height: 13 fields + blank + hint + help + blank above fields + 2 border = 19
+    let popup_area = centered_popup_area(area, 76, 19);
+    let popup_block = create_popup_block("Advanced Transaction Search");
+
+    frame.render_widget(Clear, popup_area);
+    frame.render_widget(popup_block.clone(), popup_area);
+
+    let inner = popup_block.inner(popup_area);
+    let [_, fields_area, _, hint_area, help_area] = Layout::vertical([
+        Constraint::Length(1),
+        Constraint::Length(AdvancedSearchField::ALL.len() as u16),
+        Constraint::Length(1),
+        Constraint::Length(1),
+        Constraint::Length(1),
+    ])
+    .areas(inner);
+
+    let lines: Vec<Line> = AdvancedSearchField::ALL
+        .into_iter()
+        .map(|field| field_line(field, form.value(field), field == form.active_field))
+        .collect();
+    frame.render_widget(Paragraph::new(lines), fields_area);
+
+    let (hint, color) = if form.is_empty() {
+        (
+            "Fill in any criteria; blank fields are ignored".to_string(),
+            theme().muted,
+        )
+    } else {
+        match form.to_query() {
+            Ok(query) => (format!("Search: {query}"), theme().success),
+            Err(e) => (e.to_string(), theme().warning),
+        }
+    };
+    frame.render_widget(
+        Paragraph::new(hint)
+            .style(Style::default().fg(color))
+            .alignment(Alignment::Center),
+        hint_area,
+    );
+
+    frame.render_widget(
+        Paragraph::new("Enter: Search  Tab/Down: Next  Shift+Tab/Up: Prev  Esc: Cancel")
+            .style(Style::default().fg(theme().muted))
+            .alignment(Alignment::Center),
+        help_area,
+    );
+}
+
+/// One form row: selection marker, label, and the value or its placeholder.
+fn field_line(field: AdvancedSearchField, value: &str, is_active: bool) -> Line<'static> {
+    let (marker, label_style) = if is_active {
+        (
+            "▶ ",
+            Style::default()
+                .fg(theme().primary)
+                .add_modifier(Modifier::BOLD),
+        )
+    } else {
+        ("  ", Style::default().fg(theme().warning))
+    };
+
+    let value_span = if value.is_empty() {
+        let placeholder = if is_active {
+            format!("_ {}", field.placeholder())
+        } else {
+            field.placeholder().to_string()
+        };
+        Span::styled(placeholder, Style::default().fg(theme().muted))
+    } else if is_active {
+        Span::styled(format!("{value}_"), Style::default().fg(theme().text))
+    } else {
+        Span::styled(value.to_string(), Style::default().fg(theme().text))
+    };
+
+    Line::from(vec![
+        Span::raw(format!(" {marker}")),
+        Span::styled(format!("{:<LABEL_WIDTH$}", field.label()), label_style),
+        value_span,
+    ])
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::test_terminal;
+    use ratatui::{Terminal, backend::TestBackend};
+    use rstest::*;
+
+    #[rstest]
+    fn test_advanced_search_popup_snapshot(mut test_terminal: Terminal<TestBackend>) {
+        let mut form = AdvancedSearchForm::default();
+        form.active_field = AdvancedSearchField::TxnType;
+        "axfer".chars().for_each(|c| form.push_char(c));
+        form.next_field();
+        "31566704".chars().for_each(|c| form.push_char(c));
+
+        test_terminal
+            .draw(|frame| {
+                render(frame, frame.area(), &form);
+            })
+            .unwrap();
+
+        insta::assert_snapshot!("advanced_search_form", test_terminal.backend());
+    }
+}
@@ -6,11 +6,11 @@
 use ratatui::{
     Frame,
     layout::{Alignment, Rect},
-    style::{Color, Modifier, Style},
+    style::{Modifier, Style},
     widgets::{Clear, Paragraph},
 };
 
-use crate::theme::{MUTED_COLOR, PRIMARY_COLOR};
+use crate::theme::theme;
 use crate::ui::helpers::create_popup_block;
 use crate::ui::layout::centered_popup_area;
 
@@ -59,7 +59,7 @@ pub fn render(frame: &mut Frame, area: Rect) {
     );
 
     let separator_widget = Paragraph::new(separator)
-        .style(Style::default().fg(Color::DarkGray))
+        .style(Style::default().fg(theme().border))
         .alignment(Alignment::Center);
 
     frame.render_widget(separator_widget, separator_area);
@@ -76,24 +76,24 @@ pub fn render(frame: &mut Frame, area: Rect) {
         ratatui::text::Span::styled(
             "y",
             Style::default()
-                .fg(PRIMARY_COLOR)
+                .fg(theme().primary)
                 .add_modifier(Modifier::BOLD),
         ),
-        ratatui::text::Span::styled(":Yes  ", Style::default().fg(MUTED_COLOR)),
+        ratatui::text::Span::styled(":Yes  ", Style::default().fg(theme().muted)),
         ratatui::text::Span::styled(
             "n",
             Style::default()
-                .fg(PRIMARY_COLOR)
+                .fg(theme().primary)
                 .add_modifier(Modifier::BOLD),
         ),
-        ratatui::text::Span::styled("/", Style::default().fg(MUTED_COLOR)),
+        ratatui::text::Span::styled("/", Style::default().fg(theme().muted)),
         ratatui::text::Span::styled(
             "Esc",
             Style::default()
-                .fg(PRIMARY_COLOR)
+                .fg(theme().primary)
                 .add_modifier(Modifier::BOLD),
         ),
-        ratatui::text::Span::styled(":No", Style::default().fg(MUTED_COLOR)),
+        ratatui::text::Span::styled(":No", Style::default().fg(theme().muted)),
     ]);
 
     let help_msg = Paragraph::new(help_text).alignment(Alignment::Center);
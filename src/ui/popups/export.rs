@@ -0,0 +1,121 @@
+//! List export popup rendering.
+//!
+//! This module provides the prompt opened from a list view for choosing
+//! the file an export is written to. The extension picks the format.
+
+use ratatui::{
+    Frame,
+    layout::{Alignment, Rect},
+    style::Style,
+    symbols::border,
+    widgets::{Block, Borders, Clear, Paragraph},
+};
+
+use crate::domain::ExportFormat;
+use crate::state::ui_state::ExportPrompt;
+use crate::theme::theme;
+use crate::ui::helpers::create_popup_block;
+use crate::ui::layout::centered_popup_area;
+
+// ============================================================================
+// Public API
+// ============================================================================
+
+/// Renders the export path prompt.
+///
+/// # Arguments
+///
+/// * `frame` - The Ratatui frame to render to
+/// * `area` - The terminal area to render within
+/// * `prompt` - The list being exported and the path typed so far
+pub fn render(frame: &mut Frame, area: Rect, prompt: &ExportPrompt) {
+    let popup_area = centered_popup_area(area, 72, 10);
+
+    let popup_block = create_popup_block("Export List");
+    frame.render_widget(Clear, popup_area);
+    frame.render_widget(popup_block.clone(), popup_area);
+
+    let inner_area = popup_block.inner(popup_area);
+
+    let source_area = Rect::new(inner_area.x + 1, inner_area.y, inner_area.width - 2, 1);
+    frame.render_widget(
+        Paragraph::new(prompt.source.to_string())
+            .style(Style::default().fg(theme().secondary))
+            .alignment(Alignment::Center),
+        source_area,
+    );
+
+    let input_block = Block::default()
+        .borders(Borders::ALL)
+        .border_set(border::ROUNDED)
+        .border_style(theme().border_style())
+        .title(" File ")
+        .title_alignment(Alignment::Left);
+
+    let input_area = Rect::new(inner_area.x + 1, inner_area.y + 1, inner_area.width - 2, 3);
+    frame.render_widget(input_block.clone(), input_area);
+
+    let input = Paragraph::new(format!("{}▏", prompt.path)).alignment(Alignment::Left);
+    frame.render_widget(input, input_block.inner(input_area));
+
+    let (hint, color) = match ExportFormat::from_path(prompt.path.trim()) {
+        Ok(ExportFormat::Csv) => ("CSV with a header row".to_string(), theme().success),
+        Ok(ExportFormat::JsonLines) => ("One JSON object per line".to_string(), theme().success),
+        Err(e) => (e.to_string(), theme().warning),
+    };
+    let hint_area = Rect::new(inner_area.x + 1, input_area.y + 3, inner_area.width - 2, 1);
+    frame.render_widget(
+        Paragraph::new(hint)
+            .style(Style::default().fg(color))
+            .alignment(Alignment::Center),
+        hint_area,
+    );
+
+    let note_area = Rect::new(inner_area.x + 1, hint_area.y + 1, inner_area.width - 2, 1);
+    frame.render_widget(
+        Paragraph::new("Every page is fetched; amounts in decimal units, times in UTC")
+            .style(Style::default().fg(theme().muted))
+            .alignment(Alignment::Center),
+        note_area,
+    );
+
+    let control_area = Rect::new(
+        inner_area.x,
+        inner_area.y + inner_area.height - 1,
+        inner_area.width,
+        1,
+    );
+    frame.render_widget(
+        Paragraph::new("Enter:Export  Tab:CSV/JSONL  Esc:Close")
+            .style(Style::default().fg(theme().muted))
+            .alignment(Alignment::Center),
+        control_area,
+    );
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::ExportSource;
+    use crate::test_utils::test_terminal;
+    use ratatui::{Terminal, backend::TestBackend};
+    use rstest::*;
+
+    #[rstest]
+    fn test_export_popup_snapshot(mut test_terminal: Terminal<TestBackend>) {
+        let mut prompt = ExportPrompt::new(ExportSource::BlockTransactions { round: 42 });
+        prompt.toggle_format();
+
+        test_terminal
+            .draw(|frame| {
+                render(frame, frame.area(), &prompt);
+            })
+            .unwrap();
+
+        insta::assert_snapshot!("export_prompt", test_terminal.backend());
+    }
+}
@@ -1,74 +1,42 @@
 //! Help popup showing all keybindings organized by context.
 //!
-//! Displays a scrollable popup with keybinding sections (Global, Navigation,
-//! Detail View, Search). Activated by '?' key, closed by Esc/q/?.
+//! Displays a scrollable popup with one keybinding section per input context,
+//! generated from the active [`Keymap`]. Activated by '?' key, closed by Esc/q/?.
 
 use ratatui::{
     Frame,
     layout::{Alignment, Rect},
-    style::{Color, Modifier, Style},
+    style::{Modifier, Style},
     text::{Line, Span},
     widgets::{Block, Borders, Clear, Paragraph, Wrap},
 };
 
-use crate::theme::ACCENT_COLOR;
+use crate::commands::InputContext;
+use crate::keymap::Keymap;
+use crate::theme::theme;
 use crate::ui::layout::centered_popup_area;
 
 // ============================================================================
-// Keybinding Data (Hardcoded per Commandment 5)
+// Help Sections
 // ============================================================================
 
-/// Hardcoded keybinding sections with descriptions.
-const HELP_SECTIONS: &[(&str, &[(&str, &str)])] = &[
-    (
-        "Global Keys",
-        &[
-            ("q", "Quit application"),
-            ("r", "Refresh data"),
-            ("?", "Toggle help"),
-            ("n", "Network select"),
-            ("Space", "Toggle live updates"),
-            ("f", "Focus search"),
-        ],
-    ),
-    (
-        "Navigation",
-        &[
-            ("Tab", "Cycle panels"),
-            ("↑ / k", "Move up"),
-            ("↓ / j", "Move down"),
-            ("g", "Go to top"),
-            ("G", "Go to bottom"),
-            ("Enter", "Select/Open details"),
-            ("Esc", "Close/Cancel"),
-        ],
-    ),
-    (
-        "Detail View",
-        &[
-            ("Esc", "Close details"),
-            ("Tab", "Switch view mode"),
-            ("c", "Copy ID"),
-            ("y", "Copy JSON"),
-            ("o", "Open in browser"),
-            ("f", "Toggle fullscreen"),
-            ("j / k", "Navigate sections (table)"),
-            ("Enter", "Toggle section (table)"),
-            ("↑↓←→", "Scroll (graph view)"),
-            ("s", "Export SVG (graph view)"),
-        ],
-    ),
-    (
-        "Search",
-        &[
-            ("Esc", "Cancel search"),
-            ("Enter", "Submit query"),
-            ("Tab", "Cycle search type"),
-            ("↑ / ↓", "Search history"),
-            ("← / →", "Move cursor"),
-            ("Backspace", "Delete character"),
-        ],
-    ),
+/// Contexts listed in the help popup, with their section titles.
+///
+/// Bindings are read from the active keymap, so user overrides show up here.
+const HELP_CONTEXTS: &[(InputContext, &str)] = &[
+    (InputContext::Main, "Main View"),
+    (InputContext::InlineSearch, "Search"),
+    (InputContext::TxnDetailViewTable, "Transaction (Table)"),
+    (InputContext::DetailView, "Transaction (Graph)"),
+    (InputContext::BlockDetailView, "Block Details"),
+    (InputContext::AccountDetailView, "Account Details"),
+    (InputContext::AppDetailView, "Application Details"),
+    (InputContext::AssetDetailView, "Asset Details"),
+    (InputContext::GroupDetailView, "Atomic Group"),
+    (InputContext::TxnSearchView, "Search Results"),
+    (InputContext::PendingPoolView, "Pending Pool"),
+    (InputContext::SimulationView, "Simulation"),
+    (InputContext::NetworkSelect, "Network Select"),
 ];
 
 // ============================================================================
@@ -85,15 +53,16 @@ const HELP_SECTIONS: &[(&str, &[(&str, &str)])] = &[
 /// * `frame` - The Ratatui frame to render to
 /// * `area` - The terminal area to render within
 /// * `scroll_offset` - The current scroll position (in lines)
+/// * `keymap` - The active keymap
 ///
 /// # Example
 ///
 /// ```ignore
 /// use lazylora::ui::popups::help;
 ///
-/// help::render(&mut frame, area, 0);
+/// help::render(&mut frame, area, 0, &app.keymap);
 /// ```
-pub fn render(frame: &mut Frame, area: Rect, scroll_offset: u16) {
+pub fn render(frame: &mut Frame, area: Rect, scroll_offset: u16, keymap: &Keymap) {
     // Calculate popup size (70% width, 80% height)
     let width = (area.width * 7 / 10).max(50).min(area.width);
     let height = (area.height * 8 / 10).max(20).min(area.height);
@@ -107,7 +76,7 @@ pub fn render(frame: &mut Frame, area: Rect, scroll_offset: u16) {
         .title(" Help (? to close) ")
         .title_alignment(Alignment::Center)
         .borders(Borders::ALL)
-        .border_style(Style::default().fg(ACCENT_COLOR));
+        .border_style(Style::default().fg(theme().accent));
 
     frame.render_widget(block.clone(), popup_area);
 
@@ -117,12 +86,17 @@ pub fn render(frame: &mut Frame, area: Rect, scroll_offset: u16) {
     // Build help content with styled sections
     let mut lines = Vec::new();
 
-    for (section_title, bindings) in HELP_SECTIONS {
+    for (context, section_title) in HELP_CONTEXTS {
+        let bindings = keymap.help_entries(*context);
+        if bindings.is_empty() {
+            continue;
+        }
+
         // Section title (bold, colored)
         lines.push(Line::from(vec![Span::styled(
             *section_title,
             Style::default()
-                .fg(ACCENT_COLOR)
+                .fg(theme().accent)
                 .add_modifier(Modifier::BOLD),
         )]));
 
@@ -132,10 +106,10 @@ pub fn render(frame: &mut Frame, area: Rect, scroll_offset: u16) {
         ));
 
         // Keybindings (key in accent color, description in default)
-        for (key, description) in *bindings {
+        for (key, description) in bindings {
             lines.push(Line::from(vec![
-                Span::styled(format!("{:<12}", key), Style::default().fg(ACCENT_COLOR)),
-                Span::raw(*description),
+                Span::styled(format!("{:<16}", key), Style::default().fg(theme().accent)),
+                Span::raw(description),
             ]));
         }
 
@@ -191,7 +165,7 @@ fn render_scroll_indicator(frame: &mut Frame, popup_area: Rect, scroll: u16, max
     );
 
     let indicator_widget = Paragraph::new(indicator)
-        .style(Style::default().fg(Color::DarkGray))
+        .style(Style::default().fg(theme().border))
         .alignment(Alignment::Center);
 
     frame.render_widget(indicator_widget, indicator_area);
@@ -213,7 +187,7 @@ mod tests {
 
         terminal
             .draw(|frame| {
-                render(frame, frame.area(), 0);
+                render(frame, frame.area(), 0, &Keymap::default());
             })
             .unwrap();
 
@@ -231,7 +205,7 @@ mod tests {
         for scroll in [0, 5, 10, 100] {
             terminal
                 .draw(|frame| {
-                    render(frame, frame.area(), scroll);
+                    render(frame, frame.area(), scroll, &Keymap::default());
                 })
                 .unwrap();
         }
@@ -249,7 +223,7 @@ mod tests {
 
         terminal
             .draw(|frame| {
-                render(frame, frame.area(), 0);
+                render(frame, frame.area(), 0, &Keymap::default());
             })
             .unwrap();
 
@@ -260,13 +234,15 @@ mod tests {
     #[test]
     fn test_help_sections_not_empty() {
         // Ensure we have content to display
-        assert!(!HELP_SECTIONS.is_empty(), "Help sections must not be empty");
+        assert!(!HELP_CONTEXTS.is_empty(), "Help sections must not be empty");
 
-        for (section_name, bindings) in HELP_SECTIONS {
+        let keymap = Keymap::default();
+        for (context, section_name) in HELP_CONTEXTS {
+            let bindings = keymap.help_entries(*context);
             assert!(!section_name.is_empty(), "Section name must not be empty");
             assert!(!bindings.is_empty(), "Section must have bindings");
 
-            for (key, desc) in *bindings {
+            for (key, desc) in bindings {
                 assert!(!key.is_empty(), "Key binding must not be empty");
                 assert!(!desc.is_empty(), "Description must not be empty");
             }
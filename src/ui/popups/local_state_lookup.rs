@@ -0,0 +1,111 @@
+//! Local state lookup popup rendering.
+//!
+//! This module provides the prompt opened from the application details popup
+//! for entering an account address whose local state should be shown.
+
+use ratatui::{
+    Frame,
+    layout::{Alignment, Rect},
+    style::Style,
+    symbols::border,
+    widgets::{Block, Borders, Clear, Paragraph},
+};
+
+use crate::domain::account::is_valid_address;
+use crate::theme::theme;
+use crate::ui::helpers::create_popup_block;
+use crate::ui::layout::centered_popup_area;
+
+// ============================================================================
+// Public API
+// ============================================================================
+
+/// Renders the local state lookup prompt.
+///
+/// # Arguments
+///
+/// * `frame` - The Ratatui frame to render to
+/// * `area` - The terminal area to render within
+/// * `address` - The address typed so far
+pub fn render(frame: &mut Frame, area: Rect, address: &str) {
+    let popup_area = centered_popup_area(area, 68, 9);
+
+    let popup_block = create_popup_block("Look Up Local State");
+    frame.render_widget(Clear, popup_area);
+    frame.render_widget(popup_block.clone(), popup_area);
+
+    let inner_area = popup_block.inner(popup_area);
+
+    let input_block = Block::default()
+        .borders(Borders::ALL)
+        .border_set(border::ROUNDED)
+        .border_style(theme().border_style())
+        .title(" Account address ")
+        .title_alignment(Alignment::Left);
+
+    let input_area = Rect::new(inner_area.x + 1, inner_area.y + 1, inner_area.width - 2, 3);
+    frame.render_widget(input_block.clone(), input_area);
+
+    let input = Paragraph::new(format!("{address}▏")).alignment(Alignment::Left);
+    frame.render_widget(input, input_block.inner(input_area));
+
+    let (hint, color) = if address.trim().is_empty() {
+        ("Enter a 58-character Algorand address", theme().muted)
+    } else if is_valid_address(address.trim()) {
+        ("Valid address", theme().success)
+    } else {
+        ("Not a valid address yet", theme().warning)
+    };
+    let hint_area = Rect::new(inner_area.x + 1, input_area.y + 3, inner_area.width - 2, 1);
+    frame.render_widget(
+        Paragraph::new(hint)
+            .style(Style::default().fg(color))
+            .alignment(Alignment::Center),
+        hint_area,
+    );
+
+    let control_area = Rect::new(
+        inner_area.x,
+        inner_area.y + inner_area.height - 1,
+        inner_area.width,
+        1,
+    );
+    frame.render_widget(
+        Paragraph::new("Enter:Look up  Esc:Close")
+            .style(Style::default().fg(theme().muted))
+            .alignment(Alignment::Center),
+        control_area,
+    );
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::{Terminal, backend::TestBackend};
+
+    #[test]
+    fn test_local_state_lookup_popup_renders() {
+        let backend = TestBackend::new(80, 24);
+        let mut terminal = Terminal::new(backend).unwrap();
+
+        terminal
+            .draw(|frame| {
+                render(frame, frame.area(), "Y76M3MSY");
+            })
+            .unwrap();
+
+        let content: String = terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .map(|cell| cell.symbol())
+            .collect();
+        assert!(content.contains("Look Up Local State"));
+        assert!(content.contains("Not a valid address yet"));
+    }
+}
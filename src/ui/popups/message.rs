@@ -6,11 +6,11 @@
 use ratatui::{
     Frame,
     layout::{Alignment, Rect},
-    style::{Color, Style},
+    style::Style,
     widgets::{Clear, Paragraph, Wrap},
 };
 
-use crate::theme::MUTED_COLOR;
+use crate::theme::theme;
 use crate::ui::helpers::create_popup_block;
 use crate::ui::layout::centered_popup_area;
 
@@ -79,7 +79,7 @@ pub fn render(frame: &mut Frame, area: Rect, message: &str) {
     );
 
     let separator_widget = Paragraph::new(separator)
-        .style(Style::default().fg(Color::DarkGray))
+        .style(Style::default().fg(theme().border))
         .alignment(Alignment::Center);
 
     frame.render_widget(separator_widget, separator_area);
@@ -93,7 +93,7 @@ pub fn render(frame: &mut Frame, area: Rect, message: &str) {
     );
 
     let help_msg = Paragraph::new(help_text)
-        .style(Style::default().fg(MUTED_COLOR))
+        .style(Style::default().fg(theme().muted))
         .alignment(Alignment::Center);
 
     frame.render_widget(help_msg, help_area);
@@ -1,16 +1,22 @@
 //! Popup UI components for the LazyLora TUI.
 //!
 //! This module contains all popup rendering logic including network selection,
-//! search, search results, and message popups. Popups are modal overlays that
-//! appear on top of the main UI and require user interaction to dismiss.
+//! search, search results, advanced search, local state lookup, transaction
+//! filter, list export, simulation, and message popups. Popups are modal overlays that appear on top of
+//! the main UI and require user interaction to dismiss.
 
+pub mod advanced_search;
 pub mod confirm;
+pub mod export;
 pub mod help;
+pub mod local_state_lookup;
 pub mod message;
 pub mod network;
 pub mod network_form;
 pub mod search;
 pub mod search_results;
+pub mod simulate;
+pub mod txn_filter;
 
 // Re-export popup rendering functions for external API convenience.
 // Used by library consumers who prefer `popups::render_*` over `popups::module::render`.
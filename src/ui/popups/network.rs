@@ -11,7 +11,7 @@ use ratatui::{
 };
 
 use crate::domain::NetworkConfig;
-use crate::theme::{MUTED_COLOR, PRIMARY_COLOR, SUCCESS_COLOR};
+use crate::theme::theme;
 use crate::ui::helpers::create_popup_block;
 use crate::ui::layout::centered_popup_area;
 
@@ -78,19 +78,20 @@ pub fn render(
 
             let style = if is_selected {
                 Style::default()
-                    .fg(PRIMARY_COLOR)
+                    .fg(theme().primary)
                     .add_modifier(Modifier::BOLD)
             } else if is_current {
                 Style::default()
-                    .fg(SUCCESS_COLOR)
+                    .fg(theme().success)
                     .add_modifier(Modifier::BOLD)
             } else {
-                Style::default().fg(MUTED_COLOR)
+                Style::default().fg(theme().muted)
             };
 
             // Mark custom networks with [Custom] suffix
             let network_name = net.as_str();
             let suffix = match net {
+                NetworkConfig::Custom(_) if net.algod_only() => " [Custom, algod-only]",
                 NetworkConfig::Custom(_) => " [Custom]",
                 NetworkConfig::BuiltIn(_) => "",
             };
@@ -133,7 +134,7 @@ pub fn render(
     );
 
     let help_msg = Paragraph::new(help_text)
-        .style(Style::default().fg(MUTED_COLOR))
+        .style(Style::default().fg(theme().muted))
         .alignment(Alignment::Center);
 
     frame.render_widget(help_msg, help_area);
@@ -11,7 +11,7 @@ use ratatui::{
 };
 
 use crate::state::ui_state::{NetworkFormField, NetworkFormState};
-use crate::theme::{MUTED_COLOR, PRIMARY_COLOR};
+use crate::theme::theme;
 use crate::ui::helpers::create_popup_block;
 use crate::ui::layout::centered_popup_area;
 
@@ -91,7 +91,7 @@ pub fn render(frame: &mut Frame, area: Rect, form: &NetworkFormState) {
     );
 
     let help = Paragraph::new("Enter: Save & Switch  Tab/Down: Next  Up: Prev  Esc: Cancel")
-        .style(Style::default().fg(MUTED_COLOR))
+        .style(Style::default().fg(theme().muted))
         .alignment(Alignment::Center);
     frame.render_widget(help, rows[7]);
 }
@@ -106,10 +106,10 @@ fn render_field(
     let is_active = field == active;
     let border_style = if is_active {
         Style::default()
-            .fg(PRIMARY_COLOR)
+            .fg(theme().primary)
             .add_modifier(Modifier::BOLD)
     } else {
-        Style::default().fg(MUTED_COLOR)
+        Style::default().fg(theme().muted)
     };
 
     let display = if value.is_empty() {
@@ -134,7 +134,7 @@ fn render_field(
 
     let paragraph = Paragraph::new(content)
         .style(if value.is_empty() {
-            Style::default().fg(MUTED_COLOR)
+            Style::default().fg(theme().muted)
         } else {
             Style::default()
         })
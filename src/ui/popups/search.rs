@@ -6,14 +6,14 @@
 use ratatui::{
     Frame,
     layout::{Alignment, Rect},
-    style::{Color, Modifier, Style},
+    style::{Modifier, Style},
     symbols::border,
     widgets::{Block, Borders, Clear, Paragraph, Wrap},
 };
 
 use crate::client::AlgoClient;
 use crate::state::SearchType;
-use crate::theme::{BORDER_STYLE, MUTED_COLOR, PRIMARY_COLOR, SUCCESS_COLOR, WARNING_COLOR};
+use crate::theme::theme;
 use crate::ui::helpers::create_popup_block;
 use crate::ui::layout::centered_popup_area;
 
@@ -55,7 +55,7 @@ pub fn render(frame: &mut Frame, area: Rect, query: &str, search_type: SearchTyp
     let input_block = Block::default()
         .borders(Borders::ALL)
         .border_set(border::ROUNDED)
-        .border_style(BORDER_STYLE)
+        .border_style(theme().border_style())
         .title(" Enter search term ")
         .title_alignment(Alignment::Left);
 
@@ -93,11 +93,11 @@ pub fn render(frame: &mut Frame, area: Rect, query: &str, search_type: SearchTyp
         let is_selected = *t == search_type;
         let button_style = if is_selected {
             Style::default()
-                .bg(PRIMARY_COLOR)
-                .fg(Color::White)
+                .bg(theme().primary)
+                .fg(theme().text)
                 .add_modifier(Modifier::BOLD)
         } else {
-            Style::default().bg(Color::DarkGray).fg(Color::White)
+            Style::default().bg(theme().selection).fg(theme().text)
         };
 
         let button_rect = Rect::new(x_offset, selector_y, selector_width, selector_height);
@@ -117,22 +117,22 @@ pub fn render(frame: &mut Frame, area: Rect, query: &str, search_type: SearchTyp
     let suggestion = AlgoClient::get_search_suggestions(query, search_type);
 
     let suggestion_color = if suggestion.contains("Valid") {
-        SUCCESS_COLOR
+        theme().success
     } else if suggestion.contains("too short")
         || suggestion.contains("too long")
         || suggestion.contains("invalid")
     {
-        WARNING_COLOR
+        theme().warning
     } else if suggestion.contains("Enter") {
-        MUTED_COLOR
+        theme().muted
     } else {
-        PRIMARY_COLOR
+        theme().primary
     };
 
     let suggestions_block = Block::default()
         .borders(Borders::ALL)
         .border_set(border::ROUNDED)
-        .border_style(Style::default().fg(MUTED_COLOR))
+        .border_style(Style::default().fg(theme().muted))
         .title(" Suggestions ")
         .title_alignment(Alignment::Left);
 
@@ -154,11 +154,11 @@ pub fn render(frame: &mut Frame, area: Rect, query: &str, search_type: SearchTyp
     let help_area2 = Rect::new(inner_area.x + 2, suggestions_y + 6, inner_area.width - 4, 1);
 
     let help_msg1 = Paragraph::new(help_text1)
-        .style(Style::default().fg(MUTED_COLOR))
+        .style(Style::default().fg(theme().muted))
         .alignment(Alignment::Center);
 
     let help_msg2 = Paragraph::new(help_text2)
-        .style(Style::default().fg(MUTED_COLOR))
+        .style(Style::default().fg(theme().muted))
         .alignment(Alignment::Center);
 
     frame.render_widget(help_msg1, help_area1);
@@ -173,7 +173,7 @@ pub fn render(frame: &mut Frame, area: Rect, query: &str, search_type: SearchTyp
     );
 
     let control_msg = Paragraph::new(control_text)
-        .style(Style::default().fg(MUTED_COLOR))
+        .style(Style::default().fg(theme().muted))
         .alignment(Alignment::Center);
 
     frame.render_widget(control_msg, control_area);
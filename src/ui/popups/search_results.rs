@@ -6,16 +6,13 @@
 use ratatui::{
     Frame,
     layout::{Alignment, Rect},
-    style::{Color, Modifier, Style},
+    style::{Modifier, Style},
     text::{Line, Span},
     widgets::{Block, Clear, List, ListItem, Paragraph},
 };
 
 use crate::domain::{SearchResultItem, TxnType};
-use crate::theme::{
-    ACCENT_COLOR, HIGHLIGHT_STYLE, MUTED_COLOR, PRIMARY_COLOR, SECONDARY_COLOR, SELECTED_STYLE,
-    SUCCESS_COLOR, WARNING_COLOR,
-};
+use crate::theme::theme;
 use crate::ui::helpers::create_popup_block;
 use crate::ui::layout::centered_popup_area;
 
@@ -78,12 +75,12 @@ pub fn render(frame: &mut Frame, area: Rect, results: &[(usize, SearchResultItem
                 let id_span = Span::styled(
                     txn.id.clone(),
                     Style::default()
-                        .fg(SECONDARY_COLOR)
+                        .fg(theme().secondary)
                         .add_modifier(Modifier::BOLD),
                 );
                 let type_span = Span::styled(
                     format!("[{}]", txn.txn_type.as_str()),
-                    Style::default().fg(txn.txn_type.color()),
+                    Style::default().fg(theme().txn_color(txn.txn_type)),
                 );
 
                 let line1 = Line::from(vec![
@@ -93,18 +90,18 @@ pub fn render(frame: &mut Frame, area: Rect, results: &[(usize, SearchResultItem
                     type_span,
                 ]);
                 let line2 = Line::from(vec![
-                    Span::styled("  From: ", Style::default().fg(MUTED_COLOR)),
-                    Span::styled(txn.from.clone(), Style::default().fg(WARNING_COLOR)),
+                    Span::styled("  From: ", Style::default().fg(theme().muted)),
+                    Span::styled(txn.from.clone(), Style::default().fg(theme().warning)),
                 ]);
                 let line3 = Line::from(vec![
-                    Span::styled("  To:   ", Style::default().fg(MUTED_COLOR)),
-                    Span::styled(txn.to.clone(), Style::default().fg(PRIMARY_COLOR)),
+                    Span::styled("  To:   ", Style::default().fg(theme().muted)),
+                    Span::styled(txn.to.clone(), Style::default().fg(theme().primary)),
                 ]);
                 let line4 = Line::from(vec![
                     "  ".into(),
-                    Span::styled(txn.timestamp.clone(), Style::default().fg(MUTED_COLOR)),
+                    Span::styled(txn.timestamp.clone(), Style::default().fg(theme().muted)),
                     "  ".into(),
-                    Span::styled(amount_text, Style::default().fg(SUCCESS_COLOR)),
+                    Span::styled(amount_text, Style::default().fg(theme().success)),
                 ]);
                 vec![line1, line2, line3, line4, Line::from("")]
             }
@@ -112,10 +109,10 @@ pub fn render(frame: &mut Frame, area: Rect, results: &[(usize, SearchResultItem
                 let id_span = Span::styled(
                     format!("Block # {}", block.id),
                     Style::default()
-                        .fg(PRIMARY_COLOR)
+                        .fg(theme().primary)
                         .add_modifier(Modifier::BOLD),
                 );
-                let type_span = Span::styled("[Block]", Style::default().fg(Color::White));
+                let type_span = Span::styled("[Block]", Style::default().fg(theme().text));
 
                 let line1 = Line::from(vec![
                     Span::raw(format!("{} ", selection_indicator)),
@@ -124,19 +121,22 @@ pub fn render(frame: &mut Frame, area: Rect, results: &[(usize, SearchResultItem
                     type_span,
                 ]);
                 let line2 = Line::from(vec![
-                    Span::styled("  Time: ", Style::default().fg(MUTED_COLOR)),
-                    Span::styled(block.timestamp.clone(), Style::default().fg(WARNING_COLOR)),
+                    Span::styled("  Time: ", Style::default().fg(theme().muted)),
+                    Span::styled(
+                        block.timestamp.clone(),
+                        Style::default().fg(theme().warning),
+                    ),
                 ]);
                 let line3 = Line::from(vec![
-                    Span::styled("  Txns: ", Style::default().fg(MUTED_COLOR)),
+                    Span::styled("  Txns: ", Style::default().fg(theme().muted)),
                     Span::styled(
                         format!("{}", block.txn_count),
-                        Style::default().fg(SUCCESS_COLOR),
+                        Style::default().fg(theme().success),
                     ),
                 ]);
                 let line4 = Line::from(vec![
-                    Span::styled("  Proposer: ", Style::default().fg(MUTED_COLOR)),
-                    Span::styled(block.proposer.clone(), Style::default().fg(ACCENT_COLOR)),
+                    Span::styled("  Proposer: ", Style::default().fg(theme().muted)),
+                    Span::styled(block.proposer.clone(), Style::default().fg(theme().accent)),
                 ]);
                 vec![line1, line2, line3, line4, Line::from("")]
             }
@@ -144,10 +144,10 @@ pub fn render(frame: &mut Frame, area: Rect, results: &[(usize, SearchResultItem
                 let id_span = Span::styled(
                     account.address.clone(),
                     Style::default()
-                        .fg(WARNING_COLOR)
+                        .fg(theme().warning)
                         .add_modifier(Modifier::BOLD),
                 );
-                let type_span = Span::styled("[Account]", Style::default().fg(WARNING_COLOR));
+                let type_span = Span::styled("[Account]", Style::default().fg(theme().warning));
                 let balance_text = format!("{:.6} Algos", account.balance as f64 / 1_000_000.0);
 
                 let line1 = Line::from(vec![
@@ -157,18 +157,18 @@ pub fn render(frame: &mut Frame, area: Rect, results: &[(usize, SearchResultItem
                     type_span,
                 ]);
                 let line2 = Line::from(vec![
-                    Span::styled("  Balance: ", Style::default().fg(MUTED_COLOR)),
-                    Span::styled(balance_text, Style::default().fg(SUCCESS_COLOR)),
+                    Span::styled("  Balance: ", Style::default().fg(theme().muted)),
+                    Span::styled(balance_text, Style::default().fg(theme().success)),
                 ]);
                 let line3 = Line::from(vec![
-                    Span::styled("  Status: ", Style::default().fg(MUTED_COLOR)),
-                    Span::styled(account.status.clone(), Style::default().fg(PRIMARY_COLOR)),
+                    Span::styled("  Status: ", Style::default().fg(theme().muted)),
+                    Span::styled(account.status.clone(), Style::default().fg(theme().primary)),
                 ]);
                 let line4 = Line::from(vec![
-                    Span::styled("  Assets: ", Style::default().fg(MUTED_COLOR)),
+                    Span::styled("  Assets: ", Style::default().fg(theme().muted)),
                     Span::styled(
                         format!("{}", account.assets_count),
-                        Style::default().fg(ACCENT_COLOR),
+                        Style::default().fg(theme().accent),
                     ),
                 ]);
                 vec![line1, line2, line3, line4, Line::from("")]
@@ -177,10 +177,10 @@ pub fn render(frame: &mut Frame, area: Rect, results: &[(usize, SearchResultItem
                 let id_span = Span::styled(
                     format!("Asset # {}", asset.id),
                     Style::default()
-                        .fg(SUCCESS_COLOR)
+                        .fg(theme().success)
                         .add_modifier(Modifier::BOLD),
                 );
-                let type_span = Span::styled("[Asset]", Style::default().fg(SUCCESS_COLOR));
+                let type_span = Span::styled("[Asset]", Style::default().fg(theme().success));
                 let name = if asset.name.is_empty() {
                     "<unnamed>".to_string()
                 } else {
@@ -200,19 +200,19 @@ pub fn render(frame: &mut Frame, area: Rect, results: &[(usize, SearchResultItem
                     type_span,
                 ]);
                 let line2 = Line::from(vec![
-                    Span::styled("  Name: ", Style::default().fg(MUTED_COLOR)),
+                    Span::styled("  Name: ", Style::default().fg(theme().muted)),
                     Span::styled(
                         format!("{}{}", name, unit),
-                        Style::default().fg(PRIMARY_COLOR),
+                        Style::default().fg(theme().primary),
                     ),
                 ]);
                 let line3 = Line::from(vec![
-                    Span::styled("  Creator: ", Style::default().fg(MUTED_COLOR)),
-                    Span::styled(asset.creator.clone(), Style::default().fg(WARNING_COLOR)),
+                    Span::styled("  Creator: ", Style::default().fg(theme().muted)),
+                    Span::styled(asset.creator.clone(), Style::default().fg(theme().warning)),
                 ]);
                 let line4 = Line::from(vec![
-                    Span::styled("  Total: ", Style::default().fg(MUTED_COLOR)),
-                    Span::styled(total_supply, Style::default().fg(ACCENT_COLOR)),
+                    Span::styled("  Total: ", Style::default().fg(theme().muted)),
+                    Span::styled(total_supply, Style::default().fg(theme().accent)),
                 ]);
                 vec![line1, line2, line3, line4, Line::from("")]
             }
@@ -220,15 +220,15 @@ pub fn render(frame: &mut Frame, area: Rect, results: &[(usize, SearchResultItem
                 let id_span = Span::styled(
                     format!("App # {}", app.app_id),
                     Style::default()
-                        .fg(Color::Blue)
+                        .fg(theme().secondary)
                         .add_modifier(Modifier::BOLD),
                 );
-                let type_span = Span::styled("[App]", Style::default().fg(Color::Blue));
+                let type_span = Span::styled("[App]", Style::default().fg(theme().secondary));
                 let status = if app.deleted { "Deleted" } else { "Active" };
                 let status_color = if app.deleted {
-                    Color::Red
+                    theme().error
                 } else {
-                    SUCCESS_COLOR
+                    theme().success
                 };
 
                 let line1 = Line::from(vec![
@@ -238,11 +238,11 @@ pub fn render(frame: &mut Frame, area: Rect, results: &[(usize, SearchResultItem
                     type_span,
                 ]);
                 let line2 = Line::from(vec![
-                    Span::styled("  Creator: ", Style::default().fg(MUTED_COLOR)),
-                    Span::styled(app.creator.clone(), Style::default().fg(WARNING_COLOR)),
+                    Span::styled("  Creator: ", Style::default().fg(theme().muted)),
+                    Span::styled(app.creator.clone(), Style::default().fg(theme().warning)),
                 ]);
                 let line3 = Line::from(vec![
-                    Span::styled("  Status: ", Style::default().fg(MUTED_COLOR)),
+                    Span::styled("  Status: ", Style::default().fg(theme().muted)),
                     Span::styled(status, Style::default().fg(status_color)),
                 ]);
                 let line4 = Line::from("");
@@ -251,7 +251,7 @@ pub fn render(frame: &mut Frame, area: Rect, results: &[(usize, SearchResultItem
         };
 
         list_items.push(ListItem::new(list_item).style(if is_selected {
-            SELECTED_STYLE
+            theme().selected_style()
         } else {
             Style::default()
         }));
@@ -259,7 +259,7 @@ pub fn render(frame: &mut Frame, area: Rect, results: &[(usize, SearchResultItem
 
     let txn_list = List::new(list_items)
         .block(Block::default())
-        .highlight_style(HIGHLIGHT_STYLE);
+        .highlight_style(theme().highlight_style());
 
     frame.render_widget(txn_list, inner_area);
 
@@ -272,7 +272,7 @@ pub fn render(frame: &mut Frame, area: Rect, results: &[(usize, SearchResultItem
     );
 
     let help_msg = Paragraph::new(help_text)
-        .style(Style::default().fg(MUTED_COLOR))
+        .style(Style::default().fg(theme().muted))
         .alignment(Alignment::Center);
 
     frame.render_widget(help_msg, help_area);
@@ -325,6 +325,7 @@ mod tests {
             rekey_to: None,
             group: None,
             inner_transactions: Vec::new(),
+            signature: None,
             details: TransactionDetails::None,
         };
 
@@ -0,0 +1,129 @@
+//! Simulation prompt rendering.
+//!
+//! This module provides the prompt for the transaction group to simulate:
+//! a path to a `.txn`/`.stxn`/`.tx` file, or the group pasted as base64.
+
+use ratatui::{
+    Frame,
+    layout::{Alignment, Rect},
+    style::Style,
+    symbols::border,
+    widgets::{Block, Borders, Clear, Paragraph},
+};
+
+use crate::domain::export::expand_path;
+use crate::theme::theme;
+use crate::ui::helpers::create_popup_block;
+use crate::ui::layout::centered_popup_area;
+
+// ============================================================================
+// Public API
+// ============================================================================
+
+/// Renders the simulation prompt.
+///
+/// Pasted groups are longer than the input box, so only their end is shown.
+///
+/// # Arguments
+///
+/// * `frame` - The Ratatui frame to render to
+/// * `area` - The terminal area to render within
+/// * `input` - The path or base64 typed so far
+pub fn render(frame: &mut Frame, area: Rect, input: &str) {
+    let popup_area = centered_popup_area(area, 72, 9);
+
+    let popup_block = create_popup_block("Simulate Transactions");
+    frame.render_widget(Clear, popup_area);
+    frame.render_widget(popup_block.clone(), popup_area);
+
+    let inner_area = popup_block.inner(popup_area);
+
+    let input_block = Block::default()
+        .borders(Borders::ALL)
+        .border_set(border::ROUNDED)
+        .border_style(theme().border_style())
+        .title(" Transaction file or base64 group ")
+        .title_alignment(Alignment::Left);
+
+    let input_area = Rect::new(inner_area.x + 1, inner_area.y + 1, inner_area.width - 2, 3);
+    frame.render_widget(input_block.clone(), input_area);
+
+    let text_area = input_block.inner(input_area);
+    let max_chars = (text_area.width as usize).saturating_sub(1);
+    let char_count = input.chars().count();
+    let visible: String = input
+        .chars()
+        .skip(char_count.saturating_sub(max_chars))
+        .collect();
+    let input_text = Paragraph::new(format!("{visible}▏")).alignment(Alignment::Left);
+    frame.render_widget(input_text, text_area);
+
+    let trimmed = input.trim();
+    let (hint, color) = if trimmed.is_empty() {
+        (
+            "Enter a .txn, .stxn or .tx file, or paste the group as base64".to_string(),
+            theme().muted,
+        )
+    } else if expand_path(trimmed).is_file() {
+        ("File found".to_string(), theme().success)
+    } else {
+        (
+            format!("Not a file; reading {char_count} characters as base64"),
+            theme().muted,
+        )
+    };
+    let hint_area = Rect::new(inner_area.x + 1, input_area.y + 3, inner_area.width - 2, 1);
+    frame.render_widget(
+        Paragraph::new(hint)
+            .style(Style::default().fg(color))
+            .alignment(Alignment::Center),
+        hint_area,
+    );
+
+    let control_area = Rect::new(
+        inner_area.x,
+        inner_area.y + inner_area.height - 1,
+        inner_area.width,
+        1,
+    );
+    frame.render_widget(
+        Paragraph::new("Enter:Simulate  Esc:Close")
+            .style(Style::default().fg(theme().muted))
+            .alignment(Alignment::Center),
+        control_area,
+    );
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::{Terminal, backend::TestBackend};
+
+    #[test]
+    fn test_simulate_popup_shows_end_of_long_input() {
+        let backend = TestBackend::new(80, 24);
+        let mut terminal = Terminal::new(backend).unwrap();
+        let input = format!("{}TAILEND", "A".repeat(200));
+
+        terminal
+            .draw(|frame| {
+                render(frame, frame.area(), &input);
+            })
+            .unwrap();
+
+        let content: String = terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .map(|cell| cell.symbol())
+            .collect();
+        assert!(content.contains("Simulate Transactions"));
+        assert!(content.contains("TAILEND▏"));
+        assert!(content.contains("reading 207 characters as base64"));
+    }
+}
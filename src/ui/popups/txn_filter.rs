@@ -0,0 +1,123 @@
+//! Transaction feed filter popup rendering.
+//!
+//! This module provides the prompt opened from the main view for editing
+//! the query that filters the live transactions panel.
+
+use ratatui::{
+    Frame,
+    layout::{Alignment, Rect},
+    style::Style,
+    symbols::border,
+    widgets::{Block, Borders, Clear, Paragraph},
+};
+
+use crate::domain::TxnFilter;
+use crate::theme::theme;
+use crate::ui::helpers::create_popup_block;
+use crate::ui::layout::centered_popup_area;
+
+/// Filter terms shown under the input.
+const SYNTAX_HINT: &str = "type: asset: app: from: to: addr: amount>N (also >= < <= =)";
+
+// ============================================================================
+// Public API
+// ============================================================================
+
+/// Renders the transaction feed filter prompt.
+///
+/// # Arguments
+///
+/// * `frame` - The Ratatui frame to render to
+/// * `area` - The terminal area to render within
+/// * `query` - The query typed so far
+pub fn render(frame: &mut Frame, area: Rect, query: &str) {
+    let popup_area = centered_popup_area(area, 72, 10);
+
+    let popup_block = create_popup_block("Filter Transactions");
+    frame.render_widget(Clear, popup_area);
+    frame.render_widget(popup_block.clone(), popup_area);
+
+    let inner_area = popup_block.inner(popup_area);
+
+    let input_block = Block::default()
+        .borders(Borders::ALL)
+        .border_set(border::ROUNDED)
+        .border_style(theme().border_style())
+        .title(" Query ")
+        .title_alignment(Alignment::Left);
+
+    let input_area = Rect::new(inner_area.x + 1, inner_area.y + 1, inner_area.width - 2, 3);
+    frame.render_widget(input_block.clone(), input_area);
+
+    let input = Paragraph::new(format!("{query}▏")).alignment(Alignment::Left);
+    frame.render_widget(input, input_block.inner(input_area));
+
+    let (hint, color) = if query.trim().is_empty() {
+        ("Empty query clears the filter".to_string(), theme().muted)
+    } else {
+        match TxnFilter::parse(query) {
+            Ok(_) => ("Valid filter".to_string(), theme().success),
+            Err(e) => (e.to_string(), theme().warning),
+        }
+    };
+    let hint_area = Rect::new(inner_area.x + 1, input_area.y + 3, inner_area.width - 2, 1);
+    frame.render_widget(
+        Paragraph::new(hint)
+            .style(Style::default().fg(color))
+            .alignment(Alignment::Center),
+        hint_area,
+    );
+
+    let syntax_area = Rect::new(inner_area.x + 1, hint_area.y + 1, inner_area.width - 2, 1);
+    frame.render_widget(
+        Paragraph::new(SYNTAX_HINT)
+            .style(Style::default().fg(theme().muted))
+            .alignment(Alignment::Center),
+        syntax_area,
+    );
+
+    let control_area = Rect::new(
+        inner_area.x,
+        inner_area.y + inner_area.height - 1,
+        inner_area.width,
+        1,
+    );
+    frame.render_widget(
+        Paragraph::new("Enter:Apply  Esc:Close")
+            .style(Style::default().fg(theme().muted))
+            .alignment(Alignment::Center),
+        control_area,
+    );
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::{Terminal, backend::TestBackend};
+
+    #[test]
+    fn test_txn_filter_popup_renders() {
+        let backend = TestBackend::new(80, 24);
+        let mut terminal = Terminal::new(backend).unwrap();
+
+        terminal
+            .draw(|frame| {
+                render(frame, frame.area(), "type:nope");
+            })
+            .unwrap();
+
+        let content: String = terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .map(|cell| cell.symbol())
+            .collect();
+        assert!(content.contains("Filter Transactions"));
+        assert!(content.contains("Unknown transaction type 'nope'"));
+    }
+}
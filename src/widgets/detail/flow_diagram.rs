@@ -6,12 +6,13 @@
 use ratatui::{
     buffer::Buffer,
     layout::Rect,
-    style::{Color, Modifier, Style},
+    style::{Modifier, Style},
     text::{Line, Span},
     widgets::Widget,
 };
 
 use crate::domain::{Transaction, TxnType};
+use crate::theme::theme;
 use crate::widgets::helpers::{
     MICROALGOS_PER_ALGO, format_with_commas, truncate_address, txn_type_icon,
 };
@@ -147,7 +148,7 @@ impl<'a> TxnFlowDiagram<'a> {
         let transfer_line1 = transfer_lines.first().copied().unwrap_or("");
         let transfer_line2 = transfer_lines.get(1).copied().unwrap_or("");
 
-        let color = self.txn.txn_type.color();
+        let color = theme().txn_color(self.txn.txn_type);
 
         // Center text in box
         let center = |s: &str, w: usize| -> String {
@@ -179,7 +180,7 @@ impl<'a> TxnFlowDiagram<'a> {
             Span::styled(
                 sender_centered,
                 Style::default()
-                    .fg(Color::Yellow)
+                    .fg(theme().warning)
                     .add_modifier(Modifier::BOLD),
             ),
             Span::raw("│"),
@@ -191,7 +192,7 @@ impl<'a> TxnFlowDiagram<'a> {
             Span::styled(
                 receiver_centered,
                 Style::default()
-                    .fg(Color::Cyan)
+                    .fg(theme().primary)
                     .add_modifier(Modifier::BOLD),
             ),
             Span::raw("│"),
@@ -203,11 +204,11 @@ impl<'a> TxnFlowDiagram<'a> {
         let transfer_centered = center(transfer_line1, gap);
         let line3 = Line::from(vec![
             Span::raw("  │"),
-            Span::styled(from_centered, Style::default().fg(Color::Yellow)),
+            Span::styled(from_centered, Style::default().fg(theme().warning)),
             Span::raw("│"),
-            Span::styled(transfer_centered, Style::default().fg(Color::Green)),
+            Span::styled(transfer_centered, Style::default().fg(theme().success)),
             Span::raw("│"),
-            Span::styled(to_centered, Style::default().fg(Color::Cyan)),
+            Span::styled(to_centered, Style::default().fg(theme().primary)),
             Span::raw("│"),
         ]);
 
@@ -218,7 +219,7 @@ impl<'a> TxnFlowDiagram<'a> {
             Span::raw("  │"),
             Span::styled(empty_box.clone(), Style::default()),
             Span::raw("│"),
-            Span::styled(transfer2_centered, Style::default().fg(Color::DarkGray)),
+            Span::styled(transfer2_centered, Style::default().fg(theme().border)),
             Span::raw("│"),
             Span::styled(empty_box, Style::default()),
             Span::raw("│"),
@@ -288,6 +289,7 @@ mod tests {
             group: None,
             details: TransactionDetails::default(),
             inner_transactions: Vec::new(),
+            signature: None,
         }
     }
 
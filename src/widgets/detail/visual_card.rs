@@ -3,13 +3,14 @@
 use ratatui::{
     buffer::Buffer,
     layout::Rect,
-    style::{Color, Modifier, Style},
+    style::{Modifier, Style},
     text::{Line, Span},
     widgets::Widget,
 };
 
 use super::TxnFlowDiagram;
 use crate::domain::{Transaction, TransactionDetails};
+use crate::theme::theme;
 use crate::widgets::helpers::{
     format_algo_amount, format_with_commas, truncate_address, txn_type_icon,
 };
@@ -73,13 +74,13 @@ impl<'a> TxnVisualCard<'a> {
             Span::styled(
                 "TXN: ",
                 Style::default()
-                    .fg(Color::Gray)
+                    .fg(theme().muted)
                     .add_modifier(Modifier::BOLD),
             ),
             Span::styled(
                 id_display,
                 Style::default()
-                    .fg(Color::Blue)
+                    .fg(theme().secondary)
                     .add_modifier(Modifier::BOLD),
             ),
         ]));
@@ -87,12 +88,12 @@ impl<'a> TxnVisualCard<'a> {
         // Type badge inline
         let icon = txn_type_icon(self.txn.txn_type);
         let type_name = self.txn.txn_type.as_str();
-        let color = self.txn.txn_type.color();
+        let color = theme().txn_color(self.txn.txn_type);
         lines.push(Line::from(vec![
             Span::styled(
                 "Type: ",
                 Style::default()
-                    .fg(Color::Gray)
+                    .fg(theme().muted)
                     .add_modifier(Modifier::BOLD),
             ),
             Span::styled(
@@ -120,12 +121,12 @@ impl<'a> TxnVisualCard<'a> {
                         Span::styled(
                             "Amount: ",
                             Style::default()
-                                .fg(Color::Gray)
+                                .fg(theme().muted)
                                 .add_modifier(Modifier::BOLD),
                         ),
                         Span::styled(
                             format_algo_amount(self.txn.amount),
-                            Style::default().fg(Color::Green),
+                            Style::default().fg(theme().success),
                         ),
                     ]));
 
@@ -135,12 +136,12 @@ impl<'a> TxnVisualCard<'a> {
                             Span::styled(
                                 "Close To: ",
                                 Style::default()
-                                    .fg(Color::Gray)
+                                    .fg(theme().muted)
                                     .add_modifier(Modifier::BOLD),
                             ),
                             Span::styled(
                                 truncate_address(close_to, 30),
-                                Style::default().fg(Color::Yellow),
+                                Style::default().fg(theme().warning),
                             ),
                         ]));
                     }
@@ -149,12 +150,12 @@ impl<'a> TxnVisualCard<'a> {
                             Span::styled(
                                 "Close Amount: ",
                                 Style::default()
-                                    .fg(Color::Gray)
+                                    .fg(theme().muted)
                                     .add_modifier(Modifier::BOLD),
                             ),
                             Span::styled(
                                 format_algo_amount(close_amount),
-                                Style::default().fg(Color::Green),
+                                Style::default().fg(theme().success),
                             ),
                         ]));
                     }
@@ -174,10 +175,10 @@ impl<'a> TxnVisualCard<'a> {
                         Span::styled(
                             "Amount: ",
                             Style::default()
-                                .fg(Color::Gray)
+                                .fg(theme().muted)
                                 .add_modifier(Modifier::BOLD),
                         ),
-                        Span::styled(amount_str, Style::default().fg(Color::Yellow)),
+                        Span::styled(amount_str, Style::default().fg(theme().warning)),
                     ]));
 
                     // Clawback info
@@ -186,12 +187,12 @@ impl<'a> TxnVisualCard<'a> {
                             Span::styled(
                                 "Clawback From: ",
                                 Style::default()
-                                    .fg(Color::Gray)
+                                    .fg(theme().muted)
                                     .add_modifier(Modifier::BOLD),
                             ),
                             Span::styled(
                                 truncate_address(asset_sender, 30),
-                                Style::default().fg(Color::Magenta),
+                                Style::default().fg(theme().accent),
                             ),
                         ]));
                     }
@@ -201,12 +202,12 @@ impl<'a> TxnVisualCard<'a> {
                             Span::styled(
                                 "Close To: ",
                                 Style::default()
-                                    .fg(Color::Gray)
+                                    .fg(theme().muted)
                                     .add_modifier(Modifier::BOLD),
                             ),
                             Span::styled(
                                 truncate_address(close_to, 30),
-                                Style::default().fg(Color::Yellow),
+                                Style::default().fg(theme().warning),
                             ),
                         ]));
                     }
@@ -226,10 +227,10 @@ impl<'a> TxnVisualCard<'a> {
                         Span::styled(
                             "Action: ",
                             Style::default()
-                                .fg(Color::Gray)
+                                .fg(theme().muted)
                                 .add_modifier(Modifier::BOLD),
                         ),
-                        Span::styled(action.to_string(), Style::default().fg(Color::Cyan)),
+                        Span::styled(action.to_string(), Style::default().fg(theme().primary)),
                     ]));
 
                     // Asset details for creation
@@ -238,10 +239,10 @@ impl<'a> TxnVisualCard<'a> {
                             Span::styled(
                                 "Asset Name: ",
                                 Style::default()
-                                    .fg(Color::Gray)
+                                    .fg(theme().muted)
                                     .add_modifier(Modifier::BOLD),
                             ),
-                            Span::styled(name.clone(), Style::default().fg(Color::White)),
+                            Span::styled(name.clone(), Style::default().fg(theme().text)),
                         ]));
                     }
                     if let Some(unit) = &acfg_details.unit_name {
@@ -249,10 +250,10 @@ impl<'a> TxnVisualCard<'a> {
                             Span::styled(
                                 "Unit: ",
                                 Style::default()
-                                    .fg(Color::Gray)
+                                    .fg(theme().muted)
                                     .add_modifier(Modifier::BOLD),
                             ),
-                            Span::styled(unit.clone(), Style::default().fg(Color::White)),
+                            Span::styled(unit.clone(), Style::default().fg(theme().text)),
                         ]));
                     }
                     if let Some(total) = acfg_details.total {
@@ -260,12 +261,12 @@ impl<'a> TxnVisualCard<'a> {
                             Span::styled(
                                 "Total: ",
                                 Style::default()
-                                    .fg(Color::Gray)
+                                    .fg(theme().muted)
                                     .add_modifier(Modifier::BOLD),
                             ),
                             Span::styled(
                                 format_with_commas(total),
-                                Style::default().fg(Color::Green),
+                                Style::default().fg(theme().success),
                             ),
                         ]));
                     }
@@ -274,12 +275,12 @@ impl<'a> TxnVisualCard<'a> {
                             Span::styled(
                                 "Decimals: ",
                                 Style::default()
-                                    .fg(Color::Gray)
+                                    .fg(theme().muted)
                                     .add_modifier(Modifier::BOLD),
                             ),
                             Span::styled(
                                 format!("{}", decimals),
-                                Style::default().fg(Color::White),
+                                Style::default().fg(theme().text),
                             ),
                         ]));
                     }
@@ -289,7 +290,7 @@ impl<'a> TxnVisualCard<'a> {
                         Span::styled(
                             "Action: ",
                             Style::default()
-                                .fg(Color::Gray)
+                                .fg(theme().muted)
                                 .add_modifier(Modifier::BOLD),
                         ),
                         Span::styled(
@@ -300,9 +301,9 @@ impl<'a> TxnVisualCard<'a> {
                             }
                             .to_string(),
                             Style::default().fg(if afrz_details.frozen {
-                                Color::Red
+                                theme().error
                             } else {
-                                Color::Green
+                                theme().success
                             }),
                         ),
                     ]));
@@ -310,12 +311,12 @@ impl<'a> TxnVisualCard<'a> {
                         Span::styled(
                             "Target: ",
                             Style::default()
-                                .fg(Color::Gray)
+                                .fg(theme().muted)
                                 .add_modifier(Modifier::BOLD),
                         ),
                         Span::styled(
                             truncate_address(&afrz_details.freeze_target, 30),
-                            Style::default().fg(Color::Yellow),
+                            Style::default().fg(theme().warning),
                         ),
                     ]));
                 }
@@ -325,12 +326,12 @@ impl<'a> TxnVisualCard<'a> {
                         Span::styled(
                             "On-Complete: ",
                             Style::default()
-                                .fg(Color::Gray)
+                                .fg(theme().muted)
                                 .add_modifier(Modifier::BOLD),
                         ),
                         Span::styled(
                             app_details.on_complete.as_str().to_string(),
-                            Style::default().fg(Color::Cyan),
+                            Style::default().fg(theme().primary),
                         ),
                     ]));
 
@@ -340,12 +341,12 @@ impl<'a> TxnVisualCard<'a> {
                             Span::styled(
                                 "Created App: ",
                                 Style::default()
-                                    .fg(Color::Gray)
+                                    .fg(theme().muted)
                                     .add_modifier(Modifier::BOLD),
                             ),
                             Span::styled(
                                 format!("#{}", created_id),
-                                Style::default().fg(Color::Green),
+                                Style::default().fg(theme().success),
                             ),
                         ]));
                     }
@@ -356,12 +357,12 @@ impl<'a> TxnVisualCard<'a> {
                             Span::styled(
                                 "App Args: ",
                                 Style::default()
-                                    .fg(Color::Gray)
+                                    .fg(theme().muted)
                                     .add_modifier(Modifier::BOLD),
                             ),
                             Span::styled(
                                 format!("{}", app_details.app_args.len()),
-                                Style::default().fg(Color::White),
+                                Style::default().fg(theme().text),
                             ),
                         ]));
                     }
@@ -372,12 +373,12 @@ impl<'a> TxnVisualCard<'a> {
                             Span::styled(
                                 "Foreign Apps: ",
                                 Style::default()
-                                    .fg(Color::Gray)
+                                    .fg(theme().muted)
                                     .add_modifier(Modifier::BOLD),
                             ),
                             Span::styled(
                                 format!("{}", app_details.foreign_apps.len()),
-                                Style::default().fg(Color::White),
+                                Style::default().fg(theme().text),
                             ),
                         ]));
                     }
@@ -386,12 +387,12 @@ impl<'a> TxnVisualCard<'a> {
                             Span::styled(
                                 "Foreign Assets: ",
                                 Style::default()
-                                    .fg(Color::Gray)
+                                    .fg(theme().muted)
                                     .add_modifier(Modifier::BOLD),
                             ),
                             Span::styled(
                                 format!("{}", app_details.foreign_assets.len()),
-                                Style::default().fg(Color::White),
+                                Style::default().fg(theme().text),
                             ),
                         ]));
                     }
@@ -400,12 +401,12 @@ impl<'a> TxnVisualCard<'a> {
                             Span::styled(
                                 "Box Refs: ",
                                 Style::default()
-                                    .fg(Color::Gray)
+                                    .fg(theme().muted)
                                     .add_modifier(Modifier::BOLD),
                             ),
                             Span::styled(
                                 format!("{}", app_details.boxes.len()),
-                                Style::default().fg(Color::White),
+                                Style::default().fg(theme().text),
                             ),
                         ]));
                     }
@@ -416,12 +417,12 @@ impl<'a> TxnVisualCard<'a> {
                             Span::styled(
                                 "Status: ",
                                 Style::default()
-                                    .fg(Color::Gray)
+                                    .fg(theme().muted)
                                     .add_modifier(Modifier::BOLD),
                             ),
                             Span::styled(
                                 "Going Offline".to_string(),
-                                Style::default().fg(Color::Red),
+                                Style::default().fg(theme().error),
                             ),
                         ]));
                     } else if keyreg_details.vote_key.is_some() {
@@ -429,12 +430,12 @@ impl<'a> TxnVisualCard<'a> {
                             Span::styled(
                                 "Status: ",
                                 Style::default()
-                                    .fg(Color::Gray)
+                                    .fg(theme().muted)
                                     .add_modifier(Modifier::BOLD),
                             ),
                             Span::styled(
                                 "Going Online".to_string(),
-                                Style::default().fg(Color::Green),
+                                Style::default().fg(theme().success),
                             ),
                         ]));
                         if let (Some(first), Some(last)) =
@@ -444,12 +445,12 @@ impl<'a> TxnVisualCard<'a> {
                                 Span::styled(
                                     "Valid Rounds: ",
                                     Style::default()
-                                        .fg(Color::Gray)
+                                        .fg(theme().muted)
                                         .add_modifier(Modifier::BOLD),
                                 ),
                                 Span::styled(
                                     format!("{} - {}", first, last),
-                                    Style::default().fg(Color::White),
+                                    Style::default().fg(theme().text),
                                 ),
                             ]));
                         }
@@ -458,12 +459,12 @@ impl<'a> TxnVisualCard<'a> {
                                 Span::styled(
                                     "Key Dilution: ",
                                     Style::default()
-                                        .fg(Color::Gray)
+                                        .fg(theme().muted)
                                         .add_modifier(Modifier::BOLD),
                                 ),
                                 Span::styled(
                                     format!("{}", dilution),
-                                    Style::default().fg(Color::White),
+                                    Style::default().fg(theme().text),
                                 ),
                             ]));
                         }
@@ -475,10 +476,10 @@ impl<'a> TxnVisualCard<'a> {
                             Span::styled(
                                 "Proof Type: ",
                                 Style::default()
-                                    .fg(Color::Gray)
+                                    .fg(theme().muted)
                                     .add_modifier(Modifier::BOLD),
                             ),
-                            Span::styled(format!("{}", sp_type), Style::default().fg(Color::White)),
+                            Span::styled(format!("{}", sp_type), Style::default().fg(theme().text)),
                         ]));
                     }
                 }
@@ -488,12 +489,12 @@ impl<'a> TxnVisualCard<'a> {
                             Span::styled(
                                 "HB Address: ",
                                 Style::default()
-                                    .fg(Color::Gray)
+                                    .fg(theme().muted)
                                     .add_modifier(Modifier::BOLD),
                             ),
                             Span::styled(
                                 truncate_address(hb_addr, 30),
-                                Style::default().fg(Color::Cyan),
+                                Style::default().fg(theme().primary),
                             ),
                         ]));
                     }
@@ -505,12 +506,12 @@ impl<'a> TxnVisualCard<'a> {
                             Span::styled(
                                 "Amount: ",
                                 Style::default()
-                                    .fg(Color::Gray)
+                                    .fg(theme().muted)
                                     .add_modifier(Modifier::BOLD),
                             ),
                             Span::styled(
                                 format!("{}", self.txn.amount),
-                                Style::default().fg(Color::White),
+                                Style::default().fg(theme().text),
                             ),
                         ]));
                     }
@@ -525,10 +526,10 @@ impl<'a> TxnVisualCard<'a> {
                 Span::styled(
                     "Fee: ",
                     Style::default()
-                        .fg(Color::Gray)
+                        .fg(theme().muted)
                         .add_modifier(Modifier::BOLD),
                 ),
-                Span::styled(fee_formatted, Style::default().fg(Color::Red)),
+                Span::styled(fee_formatted, Style::default().fg(theme().error)),
             ]));
 
             // Block
@@ -536,12 +537,12 @@ impl<'a> TxnVisualCard<'a> {
                 Span::styled(
                     "Block: ",
                     Style::default()
-                        .fg(Color::Gray)
+                        .fg(theme().muted)
                         .add_modifier(Modifier::BOLD),
                 ),
                 Span::styled(
                     format!("#{}", self.txn.block),
-                    Style::default().fg(Color::Cyan),
+                    Style::default().fg(theme().primary),
                 ),
             ]));
 
@@ -550,10 +551,13 @@ impl<'a> TxnVisualCard<'a> {
                 Span::styled(
                     "Time: ",
                     Style::default()
-                        .fg(Color::Gray)
+                        .fg(theme().muted)
                         .add_modifier(Modifier::BOLD),
                 ),
-                Span::styled(self.txn.timestamp.clone(), Style::default().fg(Color::Gray)),
+                Span::styled(
+                    self.txn.timestamp.clone(),
+                    Style::default().fg(theme().muted),
+                ),
             ]));
         }
 
@@ -10,7 +10,7 @@
 use std::collections::HashMap;
 
 use crate::constants::MICROALGOS_PER_ALGO;
-use crate::domain::{Transaction, TransactionDetails, TxnType};
+use crate::domain::{Transaction, TransactionDetails, TxnType, app_spec};
 
 use super::txn_graph::TxnGraph;
 use super::types::{GraphColumn, GraphEntityType, GraphRepresentation, GraphRow};
@@ -30,7 +30,6 @@ impl TxnGraph {
     }
 
     /// Build a graph from multiple transactions (e.g., a transaction group).
-    #[allow(dead_code)]
     #[must_use]
     pub fn from_transactions(transactions: &[Transaction]) -> Self {
         let mut graph = Self::new();
@@ -61,6 +60,7 @@ impl TxnGraph {
 
         // Create the row
         let label = self.create_row_label(txn);
+        let annotation = Self::create_row_annotation(txn);
         let has_children = !txn.inner_transactions.is_empty();
         let current_row_index = self.rows.len();
 
@@ -83,6 +83,7 @@ impl TxnGraph {
             has_children,
             is_last_child,
             rekey_col,
+            annotation,
         };
 
         self.rows.push(row);
@@ -261,6 +262,30 @@ impl TxnGraph {
         index
     }
 
+    /// Create the trailing annotation for a row, summarizing app state changes.
+    ///
+    /// Returns e.g. `Δ global 2, local 1`, or `None` when nothing changed.
+    fn create_row_annotation(txn: &Transaction) -> Option<String> {
+        let TransactionDetails::AppCall(details) = &txn.details else {
+            return None;
+        };
+
+        let local_count: usize = details
+            .local_state_delta
+            .iter()
+            .map(|local| local.deltas.len())
+            .sum();
+        let mut parts = Vec::new();
+        if !details.global_state_delta.is_empty() {
+            parts.push(format!("global {}", details.global_state_delta.len()));
+        }
+        if local_count > 0 {
+            parts.push(format!("local {}", local_count));
+        }
+
+        (!parts.is_empty()).then(|| format!("Δ {}", parts.join(", ")))
+    }
+
     /// Create a display label for a transaction row.
     fn create_row_label(&self, txn: &Transaction) -> String {
         match txn.txn_type {
@@ -287,7 +312,13 @@ impl TxnGraph {
             }
             TxnType::AppCall => {
                 if let TransactionDetails::AppCall(details) = &txn.details {
-                    details.on_complete.as_str().to_string()
+                    // Prefer the ABI method name when an app spec decodes the call
+                    app_spec::specs()
+                        .decode_call(details, &txn.from)
+                        .map_or_else(
+                            || details.on_complete.as_str().to_string(),
+                            |call| call.method,
+                        )
                 } else {
                     "call".to_string()
                 }
@@ -13,6 +13,7 @@ use ratatui::{
 
 use super::txn_graph::TxnGraph;
 use super::types::{GraphRepresentation, GraphRow};
+use crate::theme::theme;
 
 /// Widget that renders a transaction graph as ASCII art
 #[derive(Debug, Clone)]
@@ -75,7 +76,7 @@ impl<'a> TxnGraphWidget<'a> {
         if self.graph.columns.is_empty() || self.graph.rows.is_empty() {
             lines.push(Line::from(Span::styled(
                 "No graph data",
-                Style::default().fg(Color::Gray),
+                Style::default().fg(theme().muted),
             )));
             return lines;
         }
@@ -112,7 +113,7 @@ impl<'a> TxnGraphWidget<'a> {
         // Indicator column - fixed width with separator
         spans.push(Span::styled(
             " ".repeat(Self::SENDER_INDICATOR_WIDTH),
-            Style::default().fg(Color::DarkGray),
+            Style::default().fg(theme().border),
         ));
 
         // Tree prefix padding
@@ -125,7 +126,7 @@ impl<'a> TxnGraphWidget<'a> {
             if col_idx > 0 {
                 spans.push(Span::styled(
                     "·".repeat(col_spacing),
-                    Style::default().fg(Color::DarkGray),
+                    Style::default().fg(theme().border),
                 ));
             }
             let col_content = format!(
@@ -135,7 +136,7 @@ impl<'a> TxnGraphWidget<'a> {
             );
             spans.push(Span::styled(
                 col_content,
-                Style::default().fg(Color::DarkGray),
+                Style::default().fg(theme().border),
             ));
         }
 
@@ -197,7 +198,7 @@ impl<'a> TxnGraphWidget<'a> {
             );
             number_spans.push(Span::styled(
                 padded_num,
-                Style::default().fg(Color::Rgb(122, 162, 247)), // Tokyo Night blue
+                Style::default().fg(theme().secondary),
             ));
         }
         lines.push(Line::from(number_spans));
@@ -209,7 +210,7 @@ impl<'a> TxnGraphWidget<'a> {
         header_spans.push(Span::styled(
             format!("{:<width$}", "Type", width = Self::SENDER_INDICATOR_WIDTH),
             Style::default()
-                .fg(Color::DarkGray)
+                .fg(theme().border)
                 .add_modifier(Modifier::DIM),
         ));
 
@@ -270,7 +271,7 @@ impl<'a> TxnGraphWidget<'a> {
             subtitle_spans.push(Span::styled(
                 padded_type,
                 Style::default()
-                    .fg(Color::DarkGray)
+                    .fg(theme().border)
                     .add_modifier(Modifier::DIM),
             ));
         }
@@ -282,14 +283,14 @@ impl<'a> TxnGraphWidget<'a> {
         // Indicator column underline
         underline_spans.push(Span::styled(
             "─".repeat(Self::SENDER_INDICATOR_WIDTH),
-            Style::default().fg(Color::DarkGray),
+            Style::default().fg(theme().border),
         ));
 
         // Add padding to match tree prefix width
         if prefix_padding > 0 {
             underline_spans.push(Span::styled(
                 "─".repeat(prefix_padding),
-                Style::default().fg(Color::DarkGray),
+                Style::default().fg(theme().border),
             ));
         }
 
@@ -297,7 +298,7 @@ impl<'a> TxnGraphWidget<'a> {
             if i > 0 {
                 underline_spans.push(Span::styled(
                     "─".repeat(col_spacing),
-                    Style::default().fg(Color::DarkGray),
+                    Style::default().fg(theme().border),
                 ));
             }
 
@@ -308,7 +309,7 @@ impl<'a> TxnGraphWidget<'a> {
                 "─".repeat(marker_padding),
                 "─".repeat(col_width.saturating_sub(marker_padding + 1))
             );
-            underline_spans.push(Span::styled(marker, Style::default().fg(Color::DarkGray)));
+            underline_spans.push(Span::styled(marker, Style::default().fg(theme().border)));
         }
         lines.push(Line::from(underline_spans));
 
@@ -330,7 +331,7 @@ impl<'a> TxnGraphWidget<'a> {
                 " ".repeat(marker_padding),
                 " ".repeat(col_width.saturating_sub(marker_padding + 1))
             );
-            connector_spans.push(Span::styled(marker, Style::default().fg(Color::DarkGray)));
+            connector_spans.push(Span::styled(marker, Style::default().fg(theme().border)));
         }
         lines.push(Line::from(connector_spans));
 
@@ -344,7 +345,7 @@ impl<'a> TxnGraphWidget<'a> {
         max_prefix_width: usize,
     ) -> Vec<Line<'static>> {
         let mut lines = Vec::new();
-        let color = row.txn_type.color();
+        let color = theme().txn_color(row.txn_type);
 
         // Generate tree prefix based on depth and position
         let tree_prefix = self.generate_tree_prefix(row);
@@ -394,6 +395,18 @@ impl<'a> TxnGraphWidget<'a> {
             }
         }
 
+        // Trailing annotation after the row (state changes, etc.)
+        if self.show_labels
+            && let Some(annotation) = &row.annotation
+            && let Some(line) = lines.last_mut()
+        {
+            line.spans.push(Span::raw(" "));
+            line.spans.push(Span::styled(
+                annotation.clone(),
+                Style::default().fg(theme().border),
+            ));
+        }
+
         // No spacing between rows - compact layout
         lines
     }
@@ -610,7 +623,7 @@ impl<'a> TxnGraphWidget<'a> {
                 );
                 spans.push(Span::styled(
                     col_content,
-                    Style::default().fg(Color::DarkGray),
+                    Style::default().fg(theme().border),
                 ));
             }
         }
@@ -652,7 +665,7 @@ impl<'a> TxnGraphWidget<'a> {
         if !tree_prefix.is_empty() {
             spans.push(Span::styled(
                 tree_prefix.to_string(),
-                Style::default().fg(Color::DarkGray),
+                Style::default().fg(theme().border),
             ));
         }
 
@@ -696,7 +709,7 @@ impl<'a> TxnGraphWidget<'a> {
                 );
                 spans.push(Span::styled(
                     col_content,
-                    Style::default().fg(Color::DarkGray),
+                    Style::default().fg(theme().border),
                 ));
             }
         }
@@ -729,7 +742,7 @@ impl<'a> TxnGraphWidget<'a> {
         if !tree_prefix.is_empty() {
             spans.push(Span::styled(
                 tree_prefix.to_string(),
-                Style::default().fg(Color::DarkGray),
+                Style::default().fg(theme().border),
             ));
         }
 
@@ -773,7 +786,7 @@ impl<'a> TxnGraphWidget<'a> {
                 );
                 spans.push(Span::styled(
                     col_content,
-                    Style::default().fg(Color::DarkGray),
+                    Style::default().fg(theme().border),
                 ));
             }
         }
@@ -35,6 +35,7 @@ fn create_test_payment() -> Transaction {
         group: None,
         details: TransactionDetails::default(),
         inner_transactions: Vec::new(),
+        signature: None,
     }
 }
 
@@ -54,6 +55,7 @@ fn create_test_app_call() -> Transaction {
         group: None,
         details: TransactionDetails::default(),
         inner_transactions: Vec::new(),
+        signature: None,
     }
 }
 
@@ -187,6 +189,7 @@ fn test_txn_graph_transaction_types() {
                 group: None,
                 details: TransactionDetails::default(),
                 inner_transactions: Vec::new(),
+                signature: None,
             },
             expected_columns: 1,
             expected_rows: 1,
@@ -360,6 +363,7 @@ fn create_mock_txn(
         group: None,
         details,
         inner_transactions: Vec::new(),
+        signature: None,
     }
 }
 
@@ -389,6 +393,58 @@ fn test_snapshot_payment_with_rekey() {
     assert_snapshot!("payment_with_rekey", terminal.backend());
 }
 
+/// Snapshot test: App call annotated with its state changes
+#[test]
+fn test_snapshot_app_call_state_changes() {
+    use crate::domain::{AppCallDetails, LocalStateDelta, StateDelta, StateDeltaAction};
+
+    let txn = create_mock_txn(
+        TxnType::AppCall,
+        "CALLER7AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA4A",
+        "12345",
+        0,
+        None,
+        None,
+        TransactionDetails::AppCall(AppCallDetails {
+            app_id: 12345,
+            global_state_delta: vec![
+                StateDelta {
+                    key: "counter".to_string(),
+                    action: StateDeltaAction::SetUint(2),
+                },
+                StateDelta {
+                    key: "owner".to_string(),
+                    action: StateDeltaAction::Delete,
+                },
+            ],
+            local_state_delta: vec![LocalStateDelta {
+                address: "CALLER7AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA4A".to_string(),
+                deltas: vec![StateDelta {
+                    key: "balance".to_string(),
+                    action: StateDeltaAction::SetUint(10),
+                }],
+            }],
+            ..Default::default()
+        }),
+    );
+
+    let graph = TxnGraph::from_transaction(&txn);
+    assert_eq!(
+        graph.rows[0].annotation.as_deref(),
+        Some("Δ global 2, local 1")
+    );
+
+    let widget = TxnGraphWidget::new(&graph);
+    let mut terminal = Terminal::new(TestBackend::new(70, 8)).unwrap();
+    terminal
+        .draw(|frame| {
+            frame.render_widget(widget, frame.area());
+        })
+        .unwrap();
+
+    assert_snapshot!("app_call_state_changes", terminal.backend());
+}
+
 /// Snapshot test: Asset opt-in (self-transfer with 0 amount)
 #[test]
 fn test_snapshot_asset_opt_in() {
@@ -532,8 +588,12 @@ fn test_snapshot_app_call_with_inner_txns() {
             global_state_schema: None,
             local_state_schema: None,
             extra_program_pages: None,
+            logs: Vec::new(),
+            global_state_delta: Vec::new(),
+            local_state_delta: Vec::new(),
         }),
         inner_transactions: vec![inner_payment, inner_asset],
+        signature: None,
     };
     // Ensure outer has inner transactions
     assert_eq!(outer_txn.inner_transactions.len(), 2);
@@ -4,6 +4,7 @@
 //! transaction visualization graph. Building logic is in the `builders` module.
 
 use super::types::{GraphColumn, GraphRow};
+use crate::theme::{hex, theme};
 
 // ============================================================================
 // TxnGraph
@@ -110,18 +111,18 @@ impl TxnGraph {
         const COL_WIDTH: usize = 100;
         const COL_SPACING: usize = 60;
 
-        // Tokyo Night colors
-        const BG_COLOR: &str = "#1a1b26";
-        const TEXT_COLOR: &str = "#c0caf5";
-        const HEADER_COLOR: &str = "#7aa2f7";
-        const LABEL_COLOR: &str = "#9ece6a";
-        const TREE_COLOR: &str = "#565f89";
-        const ARROW_PAYMENT: &str = "#9ece6a";
-        const ARROW_ASSET: &str = "#bb9af7";
-        const ARROW_APPCALL: &str = "#7dcfff";
-        const POINT_COLOR: &str = "#f7768e";
-        const GRID_COLOR: &str = "#24283b";
-        const REKEY_COLOR: &str = "#e0af68";
+        // Colors from the active theme
+        let theme = theme();
+        let bg_color = hex(theme.background);
+        let text_color = hex(theme.text);
+        let header_color = hex(theme.secondary);
+        let label_color = hex(theme.success);
+        let tree_color = hex(theme.border);
+        let arrow_payment = hex(theme.payment);
+        let arrow_asset = hex(theme.asset_transfer);
+        let arrow_appcall = hex(theme.app_call);
+        let grid_color = hex(theme.surface);
+        let rekey_color = hex(theme.warning);
 
         let num_cols = self.columns.len();
         let num_rows = self.rows.len();
@@ -155,11 +156,11 @@ impl TxnGraph {
             total_height,
             total_width,
             total_height,
-            ARROW_PAYMENT,
-            ARROW_ASSET,
-            ARROW_APPCALL,
-            REKEY_COLOR,
-            BG_COLOR
+            arrow_payment,
+            arrow_asset,
+            arrow_appcall,
+            rekey_color,
+            bg_color
         ));
 
         // Draw vertical grid lines for columns
@@ -171,7 +172,7 @@ impl TxnGraph {
                 HEADER_HEIGHT,
                 x,
                 total_height - PADDING,
-                GRID_COLOR
+                grid_color
             ));
             svg.push('\n');
         }
@@ -190,7 +191,7 @@ impl TxnGraph {
             };
             svg.push_str(&format!(
                 r#"<text x="{}" y="{}" fill="{}" font-family="monospace" font-size="16" text-anchor="middle">{}</text>"#,
-                x, y, HEADER_COLOR, num
+                x, y, header_color, num
             ));
             svg.push('\n');
 
@@ -200,7 +201,7 @@ impl TxnGraph {
                 r#"<text x="{}" y="{}" fill="{}" font-family="monospace" font-size="12" text-anchor="middle">{}</text>"#,
                 x,
                 y + 20,
-                TEXT_COLOR,
+                text_color,
                 Self::escape_xml(&label)
             ));
             svg.push('\n');
@@ -211,7 +212,7 @@ impl TxnGraph {
                 r#"<text x="{}" y="{}" fill="{}" font-family="monospace" font-size="10" text-anchor="middle" opacity="0.7">{}</text>"#,
                 x,
                 y + 35,
-                TEXT_COLOR,
+                text_color,
                 type_label
             ));
             svg.push('\n');
@@ -228,7 +229,7 @@ impl TxnGraph {
                     r#"<text x="{}" y="{}" fill="{}" font-family="monospace" font-size="12">{}</text>"#,
                     PADDING,
                     y + 4,
-                    TREE_COLOR,
+                    tree_color,
                     Self::escape_xml(&tree_prefix)
                 ));
                 svg.push('\n');
@@ -241,7 +242,7 @@ impl TxnGraph {
                 r#"<text x="{}" y="{}" fill="{}" font-family="monospace" font-size="11">{}</text>"#,
                 label_x,
                 y + 4,
-                LABEL_COLOR,
+                label_color,
                 Self::escape_xml(&label)
             ));
             svg.push('\n');
@@ -259,7 +260,7 @@ impl TxnGraph {
                 // Dashed line
                 svg.push_str(&format!(
                     r#"<line x1="{}" y1="{}" x2="{}" y2="{}" stroke="{}" stroke-width="2" stroke-dasharray="4,2" marker-end="url(#arrowhead-rekey)"/>"#,
-                    x1, rekey_y, x2, rekey_y, REKEY_COLOR
+                    x1, rekey_y, x2, rekey_y, rekey_color
                 ));
                 svg.push('\n');
 
@@ -267,7 +268,7 @@ impl TxnGraph {
                 let key_x = (x1 + x2) / 2;
                 svg.push_str(&format!(
                     r#"<text x="{}" y="{}" fill="{}" font-family="monospace" font-size="10" text-anchor="middle" font-weight="bold">KEY</text>"#,
-                    key_x, rekey_y - 2, REKEY_COLOR
+                    key_x, rekey_y - 2, rekey_color
                 ));
                 svg.push('\n');
             }
@@ -291,10 +292,11 @@ impl TxnGraph {
         use super::types::GraphRepresentation;
         use crate::domain::TxnType;
 
-        const ARROW_PAYMENT: &str = "#9ece6a";
-        const ARROW_ASSET: &str = "#bb9af7";
-        const ARROW_APPCALL: &str = "#7dcfff";
-        const POINT_COLOR: &str = "#f7768e";
+        let theme = theme();
+        let arrow_payment = hex(theme.payment);
+        let arrow_asset = hex(theme.asset_transfer);
+        let arrow_appcall = hex(theme.app_call);
+        let point_color = hex(theme.error);
 
         match row.representation {
             GraphRepresentation::Vector => {
@@ -303,12 +305,12 @@ impl TxnGraph {
                     let x2 = label_width + to * (col_width + col_spacing) + col_width / 2;
 
                     let arrow_color = match row.txn_type {
-                        TxnType::Payment => ARROW_PAYMENT,
+                        TxnType::Payment => &arrow_payment,
                         TxnType::AssetTransfer | TxnType::AssetConfig | TxnType::AssetFreeze => {
-                            ARROW_ASSET
+                            &arrow_asset
                         }
-                        TxnType::AppCall => ARROW_APPCALL,
-                        _ => ARROW_PAYMENT,
+                        TxnType::AppCall => &arrow_appcall,
+                        _ => &arrow_payment,
                     };
 
                     let marker_id = match row.txn_type {
@@ -330,10 +332,10 @@ impl TxnGraph {
                 if let Some(col) = row.from_col {
                     let cx = label_width + col * (col_width + col_spacing) + col_width / 2;
                     let arrow_color = match row.txn_type {
-                        TxnType::Payment => ARROW_PAYMENT,
-                        TxnType::AssetTransfer => ARROW_ASSET,
-                        TxnType::AppCall => ARROW_APPCALL,
-                        _ => ARROW_PAYMENT,
+                        TxnType::Payment => &arrow_payment,
+                        TxnType::AssetTransfer => &arrow_asset,
+                        TxnType::AppCall => &arrow_appcall,
+                        _ => &arrow_payment,
                     };
 
                     // Draw a small loop arc
@@ -370,7 +372,7 @@ impl TxnGraph {
                     let cx = label_width + col * (col_width + col_spacing) + col_width / 2;
                     svg.push_str(&format!(
                         r#"<circle cx="{}" cy="{}" r="6" fill="{}"/>"#,
-                        cx, y, POINT_COLOR
+                        cx, y, point_color
                     ));
                     svg.push('\n');
                 }
@@ -380,13 +382,16 @@ impl TxnGraph {
 
     /// Generate an empty SVG with a message
     fn empty_svg() -> String {
-        r##"<?xml version="1.0" encoding="UTF-8"?>
+        format!(
+            r#"<?xml version="1.0" encoding="UTF-8"?>
 <svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 400 100" width="400" height="100">
-<rect width="100%" height="100%" fill="#1a1b26"/>
-<text x="200" y="50" fill="#c0caf5" font-family="monospace" font-size="14" text-anchor="middle">No graph data</text>
+<rect width="100%" height="100%" fill="{}"/>
+<text x="200" y="50" fill="{}" font-family="monospace" font-size="14" text-anchor="middle">No graph data</text>
 </svg>
-"##
-        .to_string()
+"#,
+            hex(theme().background),
+            hex(theme().text)
+        )
     }
 
     /// Build tree prefix string for a row (├─, └─, │, etc.)
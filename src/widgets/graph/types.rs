@@ -7,6 +7,7 @@
 use ratatui::style::Color;
 
 use crate::domain::TxnType;
+use crate::theme::theme;
 use crate::widgets::helpers::truncate_address;
 
 // ============================================================================
@@ -65,11 +66,11 @@ impl GraphEntityType {
     ///
     /// The ratatui Color to use for this entity type's header
     #[must_use]
-    pub const fn header_color(&self) -> Color {
+    pub fn header_color(&self) -> Color {
         match self {
-            Self::Account => Color::Yellow,
-            Self::Application => Color::Cyan,
-            Self::Asset => Color::Magenta,
+            Self::Account => theme().account,
+            Self::Application => theme().application,
+            Self::Asset => theme().asset,
         }
     }
 }
@@ -248,6 +249,8 @@ pub struct GraphRow {
     pub is_last_child: bool,
     /// Column index for rekey target (if transaction is a rekey)
     pub rekey_col: Option<usize>,
+    /// Trailing annotation (e.g. state changes made by an app call)
+    pub annotation: Option<String>,
 }
 
 // ============================================================================